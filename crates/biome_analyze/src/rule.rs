@@ -219,6 +219,8 @@ pub enum RuleSource<'a> {
     EslintTypescriptSortKeys(&'a str),
     /// Rules from [markdownlint](https://github.com/DavidAnson/markdownlint)
     MarkdownLint(&'a str, &'a str),
+    /// Rules from [yamllint](https://github.com/adrienverge/yamllint)
+    Yamllint(&'a str),
 }
 
 impl<'a> std::fmt::Display for RuleSource<'a> {
@@ -286,6 +288,7 @@ impl<'a> std::fmt::Display for RuleSource<'a> {
             Self::Sherif(_) => write!(f, "Sherif"),
             Self::EslintTypescriptSortKeys(_) => write!(f, "eslint-plugin-typescript-sort-keys"),
             Self::MarkdownLint(_, _) => write!(f, "markdownlint"),
+            Self::Yamllint(_) => write!(f, "yamllint"),
         }
     }
 }
@@ -375,6 +378,7 @@ impl<'a> RuleSource<'a> {
             | Self::EslintDrizzle(rule_name)
             | Self::EslintTypescriptSortKeys(rule_name)
             | Self::MarkdownLint(_, rule_name)
+            | Self::Yamllint(rule_name)
             | Self::Sherif(rule_name) => rule_name,
             Self::SortPackageJson => "sort-package-json",
         }
@@ -389,7 +393,8 @@ impl<'a> RuleSource<'a> {
             | Self::MarkdownLint(_, _)
             | Self::SortPackageJson
             | Self::Stylelint(_)
-            | Self::Sherif(_) => "",
+            | Self::Sherif(_)
+            | Self::Yamllint(_) => "",
             Self::EslintBarrelFiles(_) => "barrel-files",
             Self::EslintGraphql(_) => "@graphql-eslint",
             Self::EslintImport(_) => "import",
@@ -511,6 +516,7 @@ impl<'a> RuleSource<'a> {
             Self::SortPackageJson => "https://github.com/keithamus/sort-package-json".to_string(),
             Self::Sherif(rule_name) => format!("https://github.com/QuiiBz/sherif#{rule_name}"),
             Self::EslintTypescriptSortKeys(rule_name) => format!("https://github.com/infctr/eslint-plugin-typescript-sort-keys/blob/master/docs/rules/{rule_name}.md"),
+            Self::Yamllint(rule_name) => format!("https://yamllint.readthedocs.io/en/stable/rules.html#module-yamllint.rules.{}", rule_name.replace('-', "_")),
         }
     }
 
@@ -535,6 +541,7 @@ impl<'a> RuleSource<'a> {
                 | Self::SortPackageJson
                 | Self::Sherif(_)
                 | Self::MarkdownLint(_, _)
+                | Self::Yamllint(_)
         )
     }
 
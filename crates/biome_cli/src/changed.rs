@@ -1,31 +1,20 @@
 use crate::CliDiagnostic;
 use biome_configuration::Configuration;
-use biome_fs::FileSystem;
-use camino::Utf8Path;
+use biome_configuration::vcs::VcsClientKind;
+use biome_fs::{FileSystem, GitClient, JujutsuClient, MercurialClient, VcsClient};
+use biome_rowan::{TextRange, TextSize};
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
 use std::ffi::OsString;
+use std::ops::Range;
 
 pub(crate) fn get_changed_files(
     fs: &dyn FileSystem,
     configuration: &Configuration,
     since: Option<&str>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since, default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => {
-            return Err(CliDiagnostic::incompatible_end_configuration(
-                "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
-            ));
-        }
-    };
-
-    let changed_files = fs.get_changed_files(base)?;
+    let base = changed_base(configuration, since)?;
+    let changed_files = fs.get_changed_files(vcs_client(configuration), base)?;
 
     // Filter out files that no longer exist (e.g., deleted or renamed in the working directory)
     let filtered_changed_files = changed_files
@@ -37,8 +26,19 @@ pub(crate) fn get_changed_files(
     Ok(filtered_changed_files)
 }
 
-pub(crate) fn get_staged_files(fs: &dyn FileSystem) -> Result<Vec<OsString>, CliDiagnostic> {
-    let staged_files = fs.get_staged_files()?;
+pub(crate) fn get_staged_files(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let client_kind = vcs_client_kind(configuration);
+    if !client_kind.has_staging_area() {
+        return Err(CliDiagnostic::incompatible_end_configuration(format!(
+            "The `--staged` flag was set, but {} doesn't have a staging area. Use the --changed flag instead.",
+            client_kind.name()
+        )));
+    }
+
+    let staged_files = fs.get_staged_files(vcs_client(configuration))?;
 
     // Filter out files that no longer exist (e.g., deleted or renamed in the working directory)
     let filtered_staged_files = staged_files
@@ -49,3 +49,270 @@ pub(crate) fn get_staged_files(fs: &dyn FileSystem) -> Result<Vec<OsString>, Cli
 
     Ok(filtered_staged_files)
 }
+
+/// Returns the lines added or modified in the files returned by [get_changed_files].
+pub(crate) fn get_changed_lines(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+    since: Option<&str>,
+) -> Result<ChangedLines, CliDiagnostic> {
+    check_line_diff(configuration)?;
+    let base = changed_base(configuration, since)?;
+    let diff = fs.get_changed_diff(vcs_client(configuration), base)?;
+
+    Ok(ChangedLines::from_unified_diff(
+        &diff,
+        fs.working_directory().as_deref(),
+    ))
+}
+
+/// Returns the lines added or modified in the files returned by [get_staged_files].
+pub(crate) fn get_staged_lines(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<ChangedLines, CliDiagnostic> {
+    check_line_diff(configuration)?;
+    let diff = fs.get_staged_diff(vcs_client(configuration))?;
+
+    Ok(ChangedLines::from_unified_diff(
+        &diff,
+        fs.working_directory().as_deref(),
+    ))
+}
+
+/// The lines that were added or modified in each file, according to the version control system.
+#[derive(Debug, Default)]
+pub(crate) struct ChangedLines {
+    /// The zero-based line ranges of each file, sorted by their start
+    files: FxHashMap<Utf8PathBuf, Vec<Range<u32>>>,
+}
+
+impl ChangedLines {
+    /// Parses a unified diff without context lines, such as the output of `git diff --unified=0`.
+    ///
+    /// The paths of the diff are relative to `working_directory`. Removed lines are ignored,
+    /// so a file whose lines were only removed is present but has no changed lines.
+    pub(crate) fn from_unified_diff(diff: &str, working_directory: Option<&Utf8Path>) -> Self {
+        let mut files: FxHashMap<Utf8PathBuf, Vec<Range<u32>>> = FxHashMap::default();
+        let mut current_file = None;
+        // The lines of the current hunk that are left to skip. They are counted because an
+        // added line can look like a file header.
+        let mut remaining_lines = 0;
+
+        for line in diff.lines() {
+            if remaining_lines > 0 {
+                if line.starts_with(['+', '-']) {
+                    remaining_lines -= 1;
+                }
+                continue;
+            }
+
+            if let Some(path) = line.strip_prefix("+++ ") {
+                // Git appends a tab to the paths that contain spaces
+                let path = path.trim_end_matches('\t');
+                current_file = (path != "/dev/null").then(|| {
+                    let path = working_directory
+                        .map_or_else(|| Utf8PathBuf::from(path), |directory| directory.join(path));
+                    files.entry(path.clone()).or_default();
+                    path
+                });
+            } else if let Some(header) = line.strip_prefix("@@ ") {
+                let Some((removed, added)) = parse_hunk_header(header) else {
+                    continue;
+                };
+                remaining_lines = removed.len() + added.len();
+                if let Some(lines) = current_file.as_ref().and_then(|path| files.get_mut(path))
+                    && !added.is_empty()
+                {
+                    lines.push(added);
+                }
+            }
+        }
+
+        Self { files }
+    }
+
+    /// Whether any line of `lines` was added or modified in the file at `path`.
+    pub(crate) fn overlaps(&self, path: &Utf8Path, lines: Range<u32>) -> bool {
+        self.files.get(path).is_some_and(|changed| {
+            changed
+                .iter()
+                .any(|changed| changed.start < lines.end && lines.start < changed.end)
+        })
+    }
+
+    /// Whether the lines covered by `range` in `content` overlap the changed lines of `path`.
+    pub(crate) fn overlaps_range(&self, path: &Utf8Path, content: &str, range: TextRange) -> bool {
+        let line_of = |offset: TextSize| {
+            let offset = usize::from(offset).min(content.len());
+            content.as_bytes()[..offset]
+                .iter()
+                .filter(|byte| **byte == b'\n')
+                .count() as u32
+        };
+        let start = line_of(range.start());
+        let end = line_of(range.end()).max(start);
+        self.overlaps(path, start..end + 1)
+    }
+
+    /// Returns the ranges of `content` that hold the changed lines of the file at `path`.
+    pub(crate) fn text_ranges(&self, path: &Utf8Path, content: &str) -> Vec<TextRange> {
+        let Some(changed) = self.files.get(path) else {
+            return Vec::new();
+        };
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        let line_start = |line: u32| {
+            line_starts
+                .get(line as usize)
+                .copied()
+                .unwrap_or(content.len())
+        };
+
+        changed
+            .iter()
+            .filter_map(|lines| {
+                let start = line_start(lines.start);
+                let end = line_start(lines.end);
+                (start < end).then(|| {
+                    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parses the line ranges of a hunk header such as `-1,2 +1,3 @@`, and returns the removed and
+/// added lines.
+fn parse_hunk_header(header: &str) -> Option<(Range<u32>, Range<u32>)> {
+    let mut ranges = header.split_whitespace();
+    let removed = parse_hunk_range(ranges.next()?.strip_prefix('-')?)?;
+    let added = parse_hunk_range(ranges.next()?.strip_prefix('+')?)?;
+    Some((removed, added))
+}
+
+/// Parses a range such as `12,3`, where the count defaults to one, into zero-based lines.
+fn parse_hunk_range(range: &str) -> Option<Range<u32>> {
+    let (start, count) = match range.split_once(',') {
+        Some((start, count)) => (start.parse::<u32>().ok()?, count.parse::<u32>().ok()?),
+        None => (range.parse::<u32>().ok()?, 1),
+    };
+    let start = start.saturating_sub(1);
+    Some(start..start + count)
+}
+
+/// Returns the reference that `--changed` compares against.
+fn changed_base<'a>(
+    configuration: &'a Configuration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), _) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
+        )),
+    }
+}
+
+fn check_line_diff(configuration: &Configuration) -> Result<(), CliDiagnostic> {
+    let client_kind = vcs_client_kind(configuration);
+    if client_kind.has_line_diff() {
+        Ok(())
+    } else {
+        Err(CliDiagnostic::incompatible_end_configuration(format!(
+            "The `--changed-lines` flag was set, but Biome can't list the changed lines with {} yet.",
+            client_kind.name()
+        )))
+    }
+}
+
+/// Returns the client configured in `vcs.clientKind`. Git is used when no client is configured.
+fn vcs_client_kind(configuration: &Configuration) -> VcsClientKind {
+    configuration
+        .vcs
+        .as_ref()
+        .and_then(|vcs| vcs.client_kind)
+        .unwrap_or_default()
+}
+
+fn vcs_client(configuration: &Configuration) -> &'static dyn VcsClient {
+    match vcs_client_kind(configuration) {
+        VcsClientKind::Git => &GitClient,
+        VcsClientKind::Jujutsu => &JujutsuClient,
+        VcsClientKind::Mercurial => &MercurialClient,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/src/a.js b/src/a.js
+index 1111111..2222222 100644
+--- src/a.js
++++ src/a.js
+@@ -2 +2 @@ function a() {
+-    return 1;
++    return 2;
+@@ -10,2 +9,0 @@ function b() {
+-    b();
+-    b();
+@@ -20,0 +19,3 @@
++++ not a header
++c();
++c();
+diff --git a/with space.js b/with space.js
+new file mode 100644
+--- /dev/null
++++ with space.js\t
+@@ -0,0 +1 @@
++d();
+";
+
+    #[test]
+    fn parses_the_added_lines_of_each_file() {
+        let changed = ChangedLines::from_unified_diff(DIFF, None);
+        assert_eq!(changed.files[Utf8Path::new("src/a.js")], [1..2, 18..21]);
+        assert_eq!(changed.files[Utf8Path::new("with space.js")], [0..1]);
+        assert_eq!(changed.files.len(), 2);
+    }
+
+    #[test]
+    fn joins_the_paths_to_the_working_directory() {
+        let changed = ChangedLines::from_unified_diff(DIFF, Some(Utf8Path::new("/project")));
+        assert!(changed.overlaps(Utf8Path::new("/project/src/a.js"), 0..2));
+        assert!(!changed.overlaps(Utf8Path::new("src/a.js"), 0..2));
+    }
+
+    #[test]
+    fn overlaps_the_changed_lines() {
+        let changed = ChangedLines::from_unified_diff(DIFF, None);
+        let path = Utf8Path::new("src/a.js");
+        assert!(changed.overlaps(path, 1..2));
+        assert!(changed.overlaps(path, 0..30));
+        assert!(!changed.overlaps(path, 2..18));
+        assert!(!changed.overlaps(Utf8Path::new("b.js"), 0..30));
+
+        let content = "a\nb\nc\n";
+        assert!(changed.overlaps_range(path, content, TextRange::new(2.into(), 3.into())));
+        assert!(!changed.overlaps_range(path, content, TextRange::new(4.into(), 5.into())));
+    }
+
+    #[test]
+    fn returns_the_text_ranges_of_the_changed_lines() {
+        let changed = ChangedLines::from_unified_diff(DIFF, None);
+        let content = "a\nb\nc\n";
+        assert_eq!(
+            changed.text_ranges(Utf8Path::new("src/a.js"), content),
+            [TextRange::new(2.into(), 4.into())]
+        );
+    }
+}
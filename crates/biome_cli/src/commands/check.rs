@@ -1,6 +1,11 @@
-use super::{FixFileModeOptions, determine_fix_file_mode, get_files_to_process_with_cli_options};
+use super::{
+    FixFileModeOptions, check_changed_lines_arguments, determine_fix_file_mode,
+    get_changed_lines_with_cli_options, get_files_to_process_with_cli_options,
+};
 use crate::CliDiagnostic;
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
+use crate::runner::baseline::BaselineOptions;
 use crate::runner::execution::{AnalyzerSelectors, Execution, VcsTargeted};
 use crate::runner::impls::commands::traversal::{LoadEditorConfig, TraversalCommand};
 use crate::runner::impls::executions::summary_verb::SummaryVerbExecution;
@@ -40,6 +45,10 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) baseline: Option<String>,
+    pub(crate) write_baseline: bool,
+    pub(crate) cache: bool,
     pub(crate) format_with_errors: Option<FormatWithErrorsEnabled>,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
@@ -326,7 +335,40 @@ impl TraversalCommand for CheckCommandPayload {
         Ok(paths)
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
+    fn use_cache(&self) -> bool {
+        self.cache
+    }
+
+    fn baseline_options(&self) -> Option<BaselineOptions> {
+        Some(BaselineOptions {
+            path: self.baseline.clone(),
+            write: self.write_baseline,
+        })
+    }
+
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        check_changed_lines_arguments(
+            self.changed_lines,
+            self.changed,
+            self.staged,
+            self.is_watch_mode(),
+        )?;
+
         if self.is_watch_mode() {
             if self.fix {
                 return Err(CliDiagnostic::incompatible_arguments(
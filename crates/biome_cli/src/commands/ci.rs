@@ -1,6 +1,7 @@
 use crate::CliDiagnostic;
-use crate::changed::get_changed_files;
+use crate::changed::{ChangedLines, get_changed_files, get_changed_lines};
 use crate::cli_options::{CliOptions, CliReporter, CliReporterKind};
+use crate::runner::baseline::BaselineOptions;
 use crate::runner::execution::{AnalyzerSelectors, Execution, ExecutionEnvironment, VcsTargeted};
 use crate::runner::impls::commands::traversal::{LoadEditorConfig, TraversalCommand};
 use crate::runner::impls::executions::summary_verb::SummaryVerbExecution;
@@ -35,6 +36,10 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: Option<Configuration>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) baseline: Option<String>,
+    pub(crate) write_baseline: bool,
+    pub(crate) cache: bool,
     pub(crate) format_with_errors: Option<FormatWithErrorsEnabled>,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
@@ -252,6 +257,33 @@ impl TraversalCommand for CiCommandPayload {
         }
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        if self.changed_lines {
+            Ok(Some(get_changed_lines(
+                fs,
+                configuration,
+                self.since.as_deref(),
+            )?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn use_cache(&self) -> bool {
+        self.cache
+    }
+
+    fn baseline_options(&self) -> Option<BaselineOptions> {
+        Some(BaselineOptions {
+            path: self.baseline.clone(),
+            write: self.write_baseline,
+        })
+    }
+
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
         if self.formatter_enabled.is_some_and(|v| !v.value())
             && self.linter_enabled.is_some_and(|v| !v.value())
@@ -268,6 +300,13 @@ impl TraversalCommand for CiCommandPayload {
                 "In order to use --since, you must also use --changed.",
             ));
         }
+        if self.changed_lines && !self.changed {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--changed-lines",
+                "--changed",
+                "In order to use --changed-lines, you must also use --changed.",
+            ));
+        }
         Ok(())
     }
 }
@@ -1,9 +1,10 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::runner::cache::CACHE_FILE_PREFIX;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{ConsoleExt, markup};
 use biome_flags::biome_env;
 use camino::Utf8PathBuf;
-use std::fs::{create_dir, remove_dir_all};
+use std::fs::{create_dir, read_dir, remove_dir_all, remove_file};
 
 /// Runs the clean command
 pub fn clean(cli_session: CliSession) -> Result<(), CliDiagnostic> {
@@ -15,5 +16,21 @@ pub fn clean(cli_session: CliSession) -> Result<(), CliDiagnostic> {
     console.log(markup! {
       <Info>"Successfully cleaned the folder "{&logs_path.to_string()}</Info>
     });
+
+    // Removes the cache created by `--cache`
+    let cache_dir = biome_fs::ensure_cache_dir();
+    for entry in read_dir(&cache_dir)? {
+        let entry = entry?;
+        let is_cache_file = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(CACHE_FILE_PREFIX));
+        if is_cache_file {
+            remove_file(entry.path())?;
+        }
+    }
+    console.log(markup! {
+      <Info>"Successfully removed the cache from the folder "{&cache_dir.to_string()}</Info>
+    });
     Ok(())
 }
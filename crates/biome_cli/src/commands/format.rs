@@ -1,6 +1,10 @@
 use crate::CliDiagnostic;
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::get_files_to_process_with_cli_options;
+use crate::commands::{
+    check_changed_lines_arguments, get_changed_lines_with_cli_options,
+    get_files_to_process_with_cli_options,
+};
 use crate::runner::execution::{AnalyzerSelectors, Execution, VcsTargeted};
 use crate::runner::impls::commands::traversal::{LoadEditorConfig, TraversalCommand};
 use crate::runner::impls::executions::summary_verb::SummaryVerbExecution;
@@ -40,6 +44,8 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
     pub(crate) json_parser: Option<JsonParserConfiguration>,
     pub(crate) css_parser: Option<CssParserConfiguration>,
     pub(crate) watch: bool,
@@ -237,7 +243,33 @@ impl TraversalCommand for FormatCommandPayload {
         Ok(paths)
     }
 
+    fn use_cache(&self) -> bool {
+        self.cache
+    }
+
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        check_changed_lines_arguments(
+            self.changed_lines,
+            self.changed,
+            self.staged,
+            self.is_watch_mode(),
+        )?;
+
         if self.is_watch_mode() {
             if self.fix {
                 return Err(CliDiagnostic::incompatible_arguments(
@@ -1,5 +1,5 @@
 use crate::{CliDiagnostic, CliSession};
-use biome_configuration::vcs::{GIT_IGNORE_FILE_NAME, IGNORE_FILE_NAME, VcsConfiguration};
+use biome_configuration::vcs::{VcsClientKind, VcsConfiguration};
 use biome_configuration::{Configuration, FilesConfiguration};
 use biome_console::fmt::{Display, Formatter};
 use biome_console::{ConsoleExt, markup};
@@ -13,11 +13,20 @@ pub(crate) fn init(session: CliSession, emit_jsonc: bool) -> Result<(), CliDiagn
     let mut config = Configuration::init();
     let mut vcs_enabled = false;
     let mut dist_enabled = false;
-    if fs.path_exists(&working_directory.join(IGNORE_FILE_NAME))
-        || fs.path_exists(&working_directory.join(GIT_IGNORE_FILE_NAME))
+    // Jujutsu comes first, because its repositories are often colocated with Git
+    let client_kind = [VcsClientKind::Jujutsu, VcsClientKind::Mercurial]
+        .into_iter()
+        .find(|client_kind| {
+            fs.path_is_dir(&working_directory.join(client_kind.repository_directory()))
+        })
+        .unwrap_or_default();
+    if client_kind
+        .ignore_files()
+        .iter()
+        .any(|file_name| fs.path_exists(&working_directory.join(file_name)))
     {
         vcs_enabled = true;
-        config.vcs = Some(VcsConfiguration::new_git_ignore());
+        config.vcs = Some(VcsConfiguration::new_ignore(client_kind));
     }
 
     if fs.path_exists(&working_directory.join("dist")) {
@@ -137,6 +137,9 @@ impl CustomExecutionCmd for MigrateCommandPayload {
             paths: _,
             configuration_files,
             duration: _,
+            cache: _,
+            baseline: _,
+            changed_lines: _,
         } = configured_workspace;
 
         let payload = crate::execute::migrate::MigratePayload {
@@ -1,4 +1,6 @@
-use crate::changed::{get_changed_files, get_staged_files};
+use crate::changed::{
+    ChangedLines, get_changed_files, get_changed_lines, get_staged_files, get_staged_lines,
+};
 use crate::cli_options::{CliOptions, CliReporterKind, ColorsArg, cli_options};
 use crate::logging::log_options;
 use crate::logging::{LogOptions, LoggingKind};
@@ -50,6 +52,7 @@ pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
+pub(crate) mod plugin;
 pub(crate) mod rage;
 pub(crate) mod search;
 pub(crate) mod upgrade;
@@ -198,6 +201,23 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Reports only the diagnostics on the lines added or modified in the files selected by `--changed` or `--staged`.
+        /// Requires `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Hides the diagnostics recorded in the baseline file at `PATH`. Without this option, Biome uses `biome-baseline.json` in the project directory when it exists.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+
+        /// Records the diagnostics of the processed files in the baseline file instead of reporting them.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+
+        /// Reuses the results of the previous run for the files that didn't change since then. Run `biome clean` to remove the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// Runs only the given lint rule, assist action, group of rules and actions, or domain. If a
         /// selected rule's severity is `off`, Biome sets it to `error` for a recommended rule or
         /// `warn` otherwise. The `plugin` group runs only analyzer plugins.
@@ -336,6 +356,24 @@ pub enum BiomeCommand {
         /// Sets the base reference used by `--changed`, overriding `vcs.defaultBranch`. Requires `--changed`.
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+
+        /// Reports only the diagnostics on the lines added or modified in the files selected by `--changed` or `--staged`.
+        /// Requires `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Hides the diagnostics recorded in the baseline file at `PATH`. Without this option, Biome uses `biome-baseline.json` in the project directory when it exists.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+
+        /// Records the diagnostics of the processed files in the baseline file instead of reporting them.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+
+        /// Reuses the results of the previous run for the files that didn't change since then. Run `biome clean` to remove the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// Reports how long each rule takes to run. It excludes the time spent preparing the analysis,
         /// such as building the semantic model.
         #[bpaf(long("profile-rules"), switch)]
@@ -427,6 +465,15 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Formats only the lines added or modified in the files selected by `--changed` or `--staged`.
+        /// Files that don't support range formatting are formatted entirely. Requires `--changed` or `--staged`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Reuses the results of the previous run for the files that didn't change since then. Run `biome clean` to remove the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// After the initial run, watches the selected paths and reprocesses files modified afterward.
         #[bpaf(long("watch"), switch)]
         watch: bool,
@@ -481,6 +528,23 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Reports only the diagnostics on the lines added or modified in the files selected by `--changed`.
+        /// Requires `--changed`.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Hides the diagnostics recorded in the baseline file at `PATH`. Without this option, Biome uses `biome-baseline.json` in the project directory when it exists.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<String>,
+
+        /// Records the diagnostics of the processed files in the baseline file instead of reporting them.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
+
+        /// Reuses the results of the previous run for the files that didn't change since then. Run `biome clean` to remove the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
         /// Sets the number of threads to use. This is useful in environments with limited resources, such as CI.
         #[bpaf(
             long("threads"),
@@ -568,11 +632,17 @@ pub enum BiomeCommand {
     /// Put the query inside single quotes when the shell supports them. This prevents the shell
     /// from interpreting GritQL's backticks as commands.
     ///
-    /// ### Example
+    /// With `--rewrite`, the rewrites of the pattern are printed as diffs, and `--write` applies them.
+    ///
+    /// ### Examples
     ///
     /// ```shell
     /// biome search '`console.log($message)`' ./src
     /// ```
+    ///
+    /// ```shell
+    /// biome search --rewrite --write '`console.log($message)` => `logger.info($message)`' ./src
+    /// ```
     #[bpaf(command)]
     Search {
         #[bpaf(external, hide_usage)]
@@ -591,16 +661,29 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide)]
         stdin_file_path: Option<String>,
 
-        /// Selects the language grammar used for the pattern and searched code: CSS, JavaScript, or JSON.
+        /// Selects the language grammar used for the pattern and searched code: CSS, GraphQL, HTML,
+        /// JavaScript, JSON, Markdown, or YAML.
         ///
         /// GritQL patterns are specific to their target grammar, so a search cannot target multiple
         /// languages at once.
         ///
         /// Defaults to `javascript`.
-        #[bpaf(long("language"), short('l'), argument("css|javascript|json"))]
+        #[bpaf(
+            long("language"),
+            short('l'),
+            argument("css|graphql|html|javascript|json|markdown|yaml")
+        )]
         language: Option<SearchLanguage>,
 
-        /// The GritQL query to find. Rewrite queries aren't supported because `biome search` is read-only.
+        /// Prints the rewrites of the pattern as diffs instead of reporting the matches.
+        #[bpaf(long("rewrite"), switch)]
+        rewrite: bool,
+
+        /// Applies the rewrites of the pattern. Requires `--rewrite`.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// The GritQL query to find. Rewrite queries, such as `` `$a` => `$b` ``, are only applied with `--rewrite`.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -609,6 +692,29 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Tools for the authors of Biome plugins.
+    ///
+    /// ### Examples
+    ///
+    /// ```shell
+    /// biome plugin test ./my-plugin
+    /// ```
+    ///
+    /// ```shell
+    /// biome plugin test --update ./my-plugin/noFoo.grit ./my-plugin/tests
+    /// ```
+    #[bpaf(command)]
+    Plugin {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external, hide_usage)]
+        log_options: LogOptions,
+
+        #[bpaf(external(plugin_sub_command))]
+        sub_command: PluginSubCommand,
+    },
+
     /// Prints documentation for a lint rule or the path to the daemon server log directory.
     ///
     /// ### Examples
@@ -628,7 +734,7 @@ pub enum BiomeCommand {
     },
 
     #[bpaf(command)]
-    /// Removes the Biome daemon server log files.
+    /// Removes the Biome daemon server log files and the cache created by `--cache`.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -664,6 +770,41 @@ pub enum MigrateSubCommand {
         #[bpaf(long("include-nursery"))]
         include_nursery: bool,
     },
+    /// Imports a Stylelint configuration and ignore settings from the current working directory into the Biome configuration.
+    #[bpaf(command)]
+    Stylelint {
+        /// Includes Biome rules inspired by Stylelint rules in the migration.
+        #[bpaf(long("include-inspired"))]
+        include_inspired: bool,
+        /// Includes nursery rules in the migration.
+        #[bpaf(long("include-nursery"))]
+        include_nursery: bool,
+    },
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum PluginSubCommand {
+    /// Runs a plugin against fixture files, and compares the emitted diagnostics and fixes with
+    /// the snapshot stored next to each fixture.
+    ///
+    /// Fixtures whose name, or the name of their directory, contains `valid` must not emit any
+    /// diagnostic, and the ones containing `invalid` must emit at least one.
+    #[bpaf(command)]
+    Test {
+        /// Writes the snapshots that are missing or don't match the emitted diagnostics, instead
+        /// of reporting them.
+        #[bpaf(long("update"), switch)]
+        update: bool,
+
+        /// The plugin to test: a GritQL or JavaScript file, or a directory with a `biome-manifest.jsonc` file.
+        #[bpaf(positional("PLUGIN"))]
+        plugin: String,
+
+        /// The file or directory containing the fixtures. Defaults to the `tests` directory next
+        /// to the plugin.
+        #[bpaf(positional("FIXTURES"), optional)]
+        fixtures: Option<String>,
+    },
 }
 
 impl MigrateSubCommand {
@@ -682,7 +823,8 @@ impl BiomeCommand {
             | Self::Ci { cli_options, .. }
             | Self::Format { cli_options, .. }
             | Self::Migrate { cli_options, .. }
-            | Self::Search { cli_options, .. } => Some(cli_options),
+            | Self::Search { cli_options, .. }
+            | Self::Plugin { cli_options, .. } => Some(cli_options),
             Self::LspProxy { .. }
             | Self::Upgrade
             | Self::Start { .. }
@@ -704,7 +846,8 @@ impl BiomeCommand {
             | Self::Format { log_options, .. }
             | Self::Migrate { log_options, .. }
             | Self::Rage(_, log_options, ..)
-            | Self::Search { log_options, .. } => Some(log_options),
+            | Self::Search { log_options, .. }
+            | Self::Plugin { log_options, .. } => Some(log_options),
             Self::Version(_)
             | Self::Upgrade
             | Self::LspProxy { .. }
@@ -899,7 +1042,54 @@ fn get_files_to_process_with_cli_options(
         }
         Ok(Some(get_changed_files(fs, configuration, since)?))
     } else if staged {
-        Ok(Some(get_staged_files(fs)?))
+        Ok(Some(get_staged_files(fs, configuration)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Checks that `--changed-lines` is used with the arguments that select the changed files.
+fn check_changed_lines_arguments(
+    changed_lines: bool,
+    changed: bool,
+    staged: bool,
+    watch: bool,
+) -> Result<(), CliDiagnostic> {
+    if !changed_lines {
+        return Ok(());
+    }
+    if !changed && !staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed-lines",
+            "--changed",
+            "In order to use --changed-lines, you must also use --changed or --staged.",
+        ));
+    }
+    if watch {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed-lines",
+            "The changed lines are computed once, so they can't be used in watch mode.",
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_changed_lines_with_cli_options(
+    since: Option<&str>,
+    changed: bool,
+    staged: bool,
+    changed_lines: bool,
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Option<ChangedLines>, CliDiagnostic> {
+    if !changed_lines {
+        Ok(None)
+    } else if changed {
+        Ok(Some(get_changed_lines(fs, configuration, since)?))
+    } else if staged {
+        Ok(Some(get_staged_lines(fs, configuration)?))
     } else {
         Ok(None)
     }
@@ -0,0 +1,412 @@
+use crate::CliDiagnostic;
+use crate::cli_options::CliOptions;
+use crate::runner::crawler::CrawlerContext;
+use crate::runner::diagnostics::{
+    InvalidFixtureDiagnostic, MissingSnapshotDiagnostic, ResultExt, ResultIoExt,
+    UpdatedSnapshotDiagnostic, ValidFixtureDiagnostic,
+};
+use crate::runner::execution::{AnalyzerSelectors, Execution};
+use crate::runner::impls::commands::traversal::TraversalCommand;
+use crate::runner::impls::executions::summary_verb::SummaryVerbExecution;
+use crate::runner::process_file::{
+    DiffKind, FileStatus, Message, ProcessFile, ProcessStdinFilePayload, WorkspaceFile,
+};
+use biome_analyze::RuleCategoriesBuilder;
+use biome_configuration::analyzer::presets::PresetConfig;
+use biome_configuration::{Configuration, FilesConfiguration, LinterConfiguration, Rules};
+use biome_console::{Console, MarkupBuf, markup};
+use biome_diagnostics::display::markup_to_string;
+use biome_diagnostics::{Category, DiagnosticExt, Error, PrintDiagnostic, Severity, category};
+use biome_fs::{FileSystem, FileSystemExt};
+use biome_plugin_loader::{PluginConfiguration, Plugins};
+use biome_service::workspace::{
+    FeatureKind, FeatureName, FeaturesBuilder, FeaturesSupported, ScanKind, SupportKind,
+};
+use biome_service::{Workspace, WorkspaceError};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::time::Duration;
+
+pub(crate) struct PluginTestCommandPayload {
+    /// The path of the tested plugin, as passed to the command
+    pub(crate) plugin: String,
+    /// The file or directory containing the fixtures
+    pub(crate) fixtures: Option<String>,
+    /// Whether the snapshots are written instead of compared
+    pub(crate) update: bool,
+}
+
+impl PluginTestCommandPayload {
+    /// The fixtures are looked up in the `tests` directory next to the plugin,
+    /// when they aren't passed to the command.
+    fn fixtures_path(&self, fs: &dyn FileSystem) -> Utf8PathBuf {
+        if let Some(fixtures) = &self.fixtures {
+            return Utf8PathBuf::from(fixtures);
+        }
+
+        let plugin = Utf8Path::new(&self.plugin);
+        if fs.path_is_dir(&resolve_path(fs, plugin)) {
+            plugin.join("tests")
+        } else {
+            plugin
+                .parent()
+                .unwrap_or_else(|| Utf8Path::new(""))
+                .join("tests")
+        }
+    }
+}
+
+struct PluginTestExecution {
+    /// Whether the snapshots are written instead of compared
+    update: bool,
+}
+
+impl Execution for PluginTestExecution {
+    fn wanted_features(&self) -> FeatureName {
+        FeaturesBuilder::new().with_linter().build()
+    }
+
+    fn not_requested_features(&self) -> FeatureName {
+        FeaturesBuilder::new()
+            .with_formatter()
+            .with_assist()
+            .with_search()
+            .build()
+    }
+
+    fn can_handle(&self, features: FeaturesSupported) -> bool {
+        features.supports_lint()
+    }
+
+    fn is_vcs_targeted(&self) -> bool {
+        false
+    }
+
+    fn supports_kind(&self, file_features: &FeaturesSupported) -> Option<SupportKind> {
+        Some(file_features.support_kind_for(FeatureKind::Lint))
+    }
+
+    fn get_stdin_file_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn as_diagnostic_category(&self) -> &'static Category {
+        category!("plugin")
+    }
+
+    fn requires_write_access(&self) -> bool {
+        false
+    }
+
+    fn analyzer_selectors(&self) -> AnalyzerSelectors {
+        AnalyzerSelectors::default()
+    }
+
+    fn is_plugin_test(&self) -> bool {
+        true
+    }
+
+    fn should_update_snapshots(&self) -> bool {
+        self.update
+    }
+
+    fn summary_phrase(&self, files: usize, duration: &Duration) -> MarkupBuf {
+        SummaryVerbExecution.summary_verb("Tested", files, duration)
+    }
+}
+
+/// What a fixture expects from the plugin, following the naming conventions of
+/// the spec tests.
+#[derive(Debug, Eq, PartialEq)]
+enum FixtureExpectation {
+    /// The plugin must not emit any diagnostic
+    Valid,
+    /// The plugin must emit at least one diagnostic
+    Invalid,
+}
+
+impl FixtureExpectation {
+    /// Infers the expectation from the name of the fixture, or from the name
+    /// of its directory.
+    ///
+    /// The name must start with `valid` or `invalid`, e.g. `invalid.js`,
+    /// `valid-arrow.js` or `invalidArrow.js`.
+    fn from_path(path: &Utf8Path) -> Option<Self> {
+        [
+            path.file_name(),
+            path.parent().and_then(Utf8Path::file_name),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(Self::from_name)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let (expectation, rest) = if let Some(rest) = name.strip_prefix("invalid") {
+            (Self::Invalid, rest)
+        } else if let Some(rest) = name.strip_prefix("valid") {
+            (Self::Valid, rest)
+        } else {
+            return None;
+        };
+        // `validation.js` isn't a valid fixture.
+        let is_prefix = rest.chars().next().is_none_or(|c| !c.is_ascii_lowercase());
+        is_prefix.then_some(expectation)
+    }
+}
+
+pub(crate) struct PluginTestProcessFile;
+
+impl PluginTestProcessFile {
+    /// Prints the snapshot of a fixture, in the format of the analyzer spec tests.
+    fn print_snapshot(input: &str, diagnostics: &[String], language: &str) -> String {
+        let mut snapshot = String::new();
+
+        writeln!(snapshot, "# Input").unwrap();
+        writeln!(snapshot, "```{language}").unwrap();
+        writeln!(snapshot, "{input}").unwrap();
+        writeln!(snapshot, "```").unwrap();
+        writeln!(snapshot).unwrap();
+
+        if !diagnostics.is_empty() {
+            writeln!(snapshot, "# Diagnostics").unwrap();
+            for diagnostic in diagnostics {
+                writeln!(snapshot, "```").unwrap();
+                writeln!(snapshot, "{diagnostic}").unwrap();
+                writeln!(snapshot, "```").unwrap();
+                writeln!(snapshot).unwrap();
+            }
+        }
+
+        snapshot
+    }
+}
+
+impl ProcessFile for PluginTestProcessFile {
+    fn process_file<Ctx>(
+        ctx: &Ctx,
+        workspace_file: &mut WorkspaceFile,
+        _features_supported: &FeaturesSupported,
+        _max_diagnostics: u32,
+        _diagnostic_level: Severity,
+    ) -> Result<FileStatus, Message>
+    where
+        Ctx: CrawlerContext,
+    {
+        let execution = ctx.execution();
+        let file_path = workspace_file.path.to_string();
+
+        // The snapshots contain all the diagnostics, regardless of the
+        // `--max-diagnostics` and `--diagnostic-level` options.
+        let result = workspace_file
+            .guard()
+            .pull_diagnostics(
+                RuleCategoriesBuilder::default()
+                    .with_syntax()
+                    .with_lint()
+                    .build(),
+                Vec::new(),
+                Vec::new(),
+                true,
+                None,
+                Severity::Hint,
+                false,
+            )
+            .with_file_path_and_code(file_path.clone(), category!("plugin"))?;
+
+        let input = workspace_file.input()?;
+        let file_name = workspace_file.path.file_name().unwrap_or_default();
+        let diagnostics: Vec<String> = result
+            .diagnostics
+            .into_iter()
+            .filter_map(|diagnostic| {
+                let error = Error::from(diagnostic)
+                    .with_file_path(file_name)
+                    .with_file_source_code(input.as_str());
+                markup_to_string(&markup! {{PrintDiagnostic::verbose(&error)}}.to_owned())
+            })
+            .collect();
+
+        let mut failed = false;
+        match FixtureExpectation::from_path(workspace_file.path.as_path()) {
+            Some(FixtureExpectation::Valid) if !diagnostics.is_empty() => {
+                ctx.push_message(Message::from(
+                    ValidFixtureDiagnostic.with_file_path(file_path.clone()),
+                ));
+                failed = true;
+            }
+            Some(FixtureExpectation::Invalid) if diagnostics.is_empty() => {
+                ctx.push_message(Message::from(
+                    InvalidFixtureDiagnostic.with_file_path(file_path.clone()),
+                ));
+                failed = true;
+            }
+            _ => {}
+        }
+
+        let language = workspace_file.path.extension().unwrap_or_default();
+        let snapshot = Self::print_snapshot(&input, &diagnostics, language);
+        let snapshot_path = Utf8PathBuf::from(format!("{}.snap", workspace_file.path));
+        let stored_snapshot = ctx
+            .fs()
+            .path_is_file(&snapshot_path)
+            .then(|| ctx.fs().read_file_from_path(&snapshot_path))
+            .transpose()?;
+
+        if stored_snapshot.as_deref() != Some(snapshot.as_str()) {
+            if execution.should_update_snapshots() {
+                ctx.fs()
+                    .create(&snapshot_path)
+                    .and_then(|mut file| file.set_content(snapshot.as_bytes()))
+                    .with_file_path(snapshot_path.to_string())?;
+                ctx.push_message(Message::from(
+                    UpdatedSnapshotDiagnostic.with_file_path(file_path),
+                ));
+            } else if let Some(stored_snapshot) = stored_snapshot {
+                return Ok(FileStatus::Message(Message::Diff {
+                    file_name: file_path,
+                    old: stored_snapshot,
+                    new: snapshot,
+                    diff_kind: DiffKind::Snapshot,
+                }));
+            } else {
+                ctx.push_message(Message::from(
+                    MissingSnapshotDiagnostic.with_file_path(file_path),
+                ));
+                failed = true;
+            }
+        }
+
+        if failed {
+            Ok(FileStatus::Message(Message::Failure))
+        } else {
+            Ok(FileStatus::Unchanged)
+        }
+    }
+
+    fn process_std_in(_payload: ProcessStdinFilePayload) -> Result<(), CliDiagnostic> {
+        Ok(())
+    }
+}
+
+impl TraversalCommand for PluginTestCommandPayload {
+    type ProcessFile = PluginTestProcessFile;
+
+    fn command_name(&self) -> &'static str {
+        "plugin test"
+    }
+
+    fn minimal_scan_kind(&self) -> Option<ScanKind> {
+        Some(ScanKind::KnownFiles)
+    }
+
+    fn get_execution(
+        &self,
+        _cli_options: &CliOptions,
+        _console: &mut dyn Console,
+        _workspace: &dyn Workspace,
+    ) -> Result<Box<dyn Execution>, CliDiagnostic> {
+        Ok(Box::new(PluginTestExecution {
+            update: self.update,
+        }))
+    }
+
+    /// The fixtures are tested in isolation: the configuration of the project
+    /// is replaced by one that only enables the tested plugin.
+    fn merge_configuration(
+        &mut self,
+        _loaded_configuration: Configuration,
+        _loaded_directory: Option<Utf8PathBuf>,
+        _loaded_file: Option<Utf8PathBuf>,
+        fs: &dyn FileSystem,
+        _console: &mut dyn Console,
+    ) -> Result<Configuration, WorkspaceError> {
+        let plugin_path = resolve_path(fs, Utf8Path::new(&self.plugin));
+
+        Ok(Configuration {
+            files: Some(FilesConfiguration {
+                ignore_unknown: Some(true.into()),
+                ..Default::default()
+            }),
+            linter: Some(LinterConfiguration {
+                enabled: Some(true.into()),
+                rules: Some(Rules {
+                    preset: Some(PresetConfig::None),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            plugins: Some(Plugins(vec![PluginConfiguration::Path(
+                plugin_path.to_string(),
+            )])),
+            ..Default::default()
+        })
+    }
+
+    fn get_files_to_process(
+        &self,
+        fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Vec<OsString>, CliDiagnostic> {
+        Ok(vec![self.fixtures_path(fs).into()])
+    }
+}
+
+/// Resolves a path passed to the command against the working directory.
+fn resolve_path(fs: &dyn FileSystem, path: &Utf8Path) -> Utf8PathBuf {
+    match fs.working_directory() {
+        Some(working_directory) => working_directory.join(path),
+        None => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixtureExpectation;
+    use camino::Utf8Path;
+
+    #[test]
+    fn infers_fixture_expectation() {
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/valid.js")),
+            Some(FixtureExpectation::Valid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/invalid.js")),
+            Some(FixtureExpectation::Invalid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/invalid/arrow.js")),
+            Some(FixtureExpectation::Invalid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/valid/arrow.js")),
+            Some(FixtureExpectation::Valid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/invalidArrow.js")),
+            Some(FixtureExpectation::Invalid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/valid-arrow.js")),
+            Some(FixtureExpectation::Valid)
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/arrow.js")),
+            None
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/validation.js")),
+            None
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("tests/unvalidated.js")),
+            None
+        );
+        assert_eq!(
+            FixtureExpectation::from_path(Utf8Path::new("invalid_inputs/arrow.js")),
+            Some(FixtureExpectation::Invalid)
+        );
+    }
+}
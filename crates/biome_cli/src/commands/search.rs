@@ -6,7 +6,7 @@ use crate::runner::execution::{AnalyzerSelectors, Execution};
 use crate::runner::impls::commands::traversal::TraversalCommand;
 use crate::runner::impls::executions::summary_verb::SummaryVerbExecution;
 use crate::runner::process_file::{
-    FileStatus, Message, ProcessFile, ProcessStdinFilePayload, WorkspaceFile,
+    DiffKind, FileStatus, Message, ProcessFile, ProcessStdinFilePayload, WorkspaceFile,
 };
 use biome_configuration::vcs::VcsConfiguration;
 use biome_configuration::{Configuration, FilesConfiguration};
@@ -30,16 +30,19 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) pattern: String,
     pub(crate) language: Option<SearchLanguage>,
+    pub(crate) rewrite: bool,
+    pub(crate) write: bool,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
 }
 
 struct SearchExecution {
-    /// The required `PATTERN` argument is the GritQL pattern to find. It must be a search pattern
-    /// because rewrites are not supported.
+    /// The required `PATTERN` argument is the GritQL pattern to find. Its rewrites are only
+    /// applied with `--rewrite`.
     pattern: PatternId,
 
-    /// Selects the language grammar used for the pattern and searched code: CSS, JavaScript, or JSON.
+    /// Selects the language grammar used for the pattern and searched code: CSS, GraphQL, HTML,
+    /// JavaScript, JSON, Markdown, or YAML.
     ///
     /// GritQL patterns are specific to their target grammar, so a search cannot target multiple
     /// languages at once.
@@ -47,6 +50,12 @@ struct SearchExecution {
     /// Defaults to `javascript`.
     language: Option<SearchLanguage>,
 
+    /// Whether the rewrites of the pattern are printed as diffs instead of the matches
+    rewrite: bool,
+
+    /// Whether the rewrites of the pattern are written to disk
+    write: bool,
+
     stdin_file_path: Option<String>,
 }
 
@@ -90,7 +99,7 @@ impl Execution for SearchExecution {
     }
 
     fn requires_write_access(&self) -> bool {
-        false
+        self.write
     }
 
     fn analyzer_selectors(&self) -> AnalyzerSelectors {
@@ -105,8 +114,16 @@ impl Execution for SearchExecution {
         Some(&self.pattern)
     }
 
+    fn is_search_rewrite(&self) -> bool {
+        self.rewrite
+    }
+
     fn summary_phrase(&self, files: usize, duration: &Duration) -> MarkupBuf {
-        SummaryVerbExecution.summary_verb("Searched", files, duration)
+        if self.requires_write_access() {
+            SummaryVerbExecution.summary_verb("Rewrote", files, duration)
+        } else {
+            SummaryVerbExecution.summary_verb("Searched", files, duration)
+        }
     }
 }
 
@@ -127,6 +144,18 @@ impl SearchProcessFile {
             SearchLanguage::Json => {
                 matches!(file_source, DocumentFileSource::Json(_))
             }
+            SearchLanguage::Graphql => {
+                matches!(file_source, DocumentFileSource::Graphql(_))
+            }
+            SearchLanguage::Html => {
+                matches!(file_source, DocumentFileSource::Html(_))
+            }
+            SearchLanguage::Markdown => {
+                matches!(file_source, DocumentFileSource::Markdown(_))
+            }
+            SearchLanguage::Yaml => {
+                matches!(file_source, DocumentFileSource::Yaml(_))
+            }
         }
     }
 }
@@ -162,6 +191,25 @@ impl ProcessFile for SearchProcessFile {
         let file_name = workspace_file.path.to_string();
         let matches_len = result.matches.len();
 
+        if execution.is_search_rewrite() {
+            let Some(rewritten) = result.rewritten.filter(|rewritten| rewritten != &input) else {
+                return Ok(FileStatus::Unchanged);
+            };
+            ctx.increment_matches(matches_len);
+            return if execution.requires_write_access() {
+                Ok(FileStatus::Changed(
+                    workspace_file.write_to_disk(rewritten)?,
+                ))
+            } else {
+                Ok(FileStatus::Message(Message::Diff {
+                    file_name,
+                    old: input,
+                    new: rewritten,
+                    diff_kind: DiffKind::Rewrite,
+                }))
+            };
+        }
+
         let search_results = Message::Diagnostics {
             file_path: file_name,
             content: input,
@@ -209,10 +257,23 @@ impl TraversalCommand for SearchCommandPayload {
         Ok(Box::new(SearchExecution {
             stdin_file_path: self.stdin_file_path.clone(),
             language: self.language.clone(),
+            rewrite: self.rewrite,
+            write: self.write,
             pattern,
         }))
     }
 
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.write && !self.rewrite {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--write",
+                "--rewrite",
+                "In order to use --write, you must also use --rewrite.",
+            ));
+        }
+        Ok(())
+    }
+
     fn merge_configuration(
         &mut self,
         mut loaded_configuration: Configuration,
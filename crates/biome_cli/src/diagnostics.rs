@@ -6,6 +6,7 @@ use biome_diagnostics::{
     category,
 };
 use biome_diagnostics::{BpafError, IoError, SerdeJsonError};
+use biome_rowan::TextRange;
 use biome_service::WorkspaceError;
 use std::process::{ExitCode, Termination};
 use std::{env::current_exe, fmt::Debug};
@@ -259,6 +260,17 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "migrate",
+	severity = Warning,
+	message = "This part of the ESLint configuration cannot be evaluated statically, so it was ignored.",
+)]
+pub struct UnsupportedEslintConfigDiagnostic {
+    #[location(span)]
+    pub span: TextRange,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "upgrade",
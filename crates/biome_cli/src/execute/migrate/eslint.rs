@@ -1,5 +1,5 @@
 use crate::CliDiagnostic;
-use crate::diagnostics::MigrationDiagnostic;
+use crate::diagnostics::{MigrationDiagnostic, UnsupportedEslintConfigDiagnostic};
 use biome_console::{Console, ConsoleExt, markup};
 use biome_deserialize::Merge;
 use biome_deserialize::json::deserialize_from_json_str;
@@ -11,6 +11,7 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
 use super::eslint_eslint;
+use super::eslint_flat_config::{self, FlatConfigItem};
 use super::node;
 
 /// This modules includes implementations for loading and deserializing an eslint configuration.
@@ -26,11 +27,14 @@ use super::node;
 /// ESLint flat configuration filenames.
 ///
 /// See https://eslint.org/docs/latest/use/configure/configuration-files-new
-const FLAT_CONFIG_FILES: [&str; 3] = [
+const FLAT_CONFIG_FILES: [&str; 6] = [
     // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
     "./eslint.config.js",
     "./eslint.config.mjs",
     "./eslint.config.cjs",
+    "./eslint.config.ts",
+    "./eslint.config.mts",
+    "./eslint.config.cts",
 ];
 
 /// List of ESLint **legacy** configuration filenames.
@@ -69,9 +73,11 @@ pub(crate) const IGNORE_FILE: &str = ".eslintignore";
 /// Deserialization errors are reported using `console`.
 /// Other errors (File Not found, unsupported config format, ...) are directly returned.
 ///
-/// We extract the ESLint configuration from a JavaScript file, by invoking `node`.
+/// A flat configuration is evaluated statically when possible.
+/// Otherwise, and for legacy configurations written in JavaScript,
+/// we extract the ESLint configuration by invoking `node`.
 ///
-/// The `extends` field is recursively resolved.
+/// The `extends` field and the presets of flat configurations are recursively resolved.
 pub(crate) fn read_eslint_config(
     fs: &dyn FileSystem,
     console: &mut dyn Console,
@@ -79,7 +85,7 @@ pub(crate) fn read_eslint_config(
     for config_path_str in FLAT_CONFIG_FILES {
         let path = Utf8Path::new(config_path_str);
         if fs.path_exists(path) {
-            return load_flat_config_data(path, console).map(|data| Config {
+            return load_flat_config_data(fs, path, console).map(|data| Config {
                 path: config_path_str,
                 data: data.into(),
             });
@@ -114,7 +120,64 @@ pub(crate) struct Config {
 
 /// Load an ESlint Flat config
 /// See https://eslint.org/docs/latest/use/configure/configuration-files-new
+///
+/// The config is evaluated statically, see [eslint_flat_config].
+/// If it uses constructs that cannot be evaluated statically, it is evaluated by `node`.
+/// When `node` fails, the parts of the config that were evaluated statically are used.
 fn load_flat_config_data(
+    fs: &dyn FileSystem,
+    path: &Utf8Path,
+    console: &mut dyn Console,
+) -> Result<eslint_eslint::FlatConfigData, CliDiagnostic> {
+    let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    let static_config = match eslint_flat_config::evaluate_flat_config(path, &content) {
+        Ok(static_config) if static_config.unsupported.is_empty() => static_config,
+        Ok(static_config) => match load_flat_config_data_with_node(path, console) {
+            Ok(data) => return Ok(data),
+            Err(diagnostic) => {
+                console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                for span in &static_config.unsupported {
+                    let diagnostic = UnsupportedEslintConfigDiagnostic { span: *span }
+                        .with_file_path(path.to_string())
+                        .with_file_source_code(content.as_str());
+                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                }
+                static_config
+            }
+        },
+        Err(_) => return load_flat_config_data_with_node(path, console),
+    };
+    let mut data = eslint_eslint::FlatConfigData::default();
+    for item in static_config.items {
+        match item {
+            FlatConfigItem::Object(object) => data.0.push(object),
+            FlatConfigItem::Preset { name, files } => match load_eslint_extends_config(&name) {
+                Ok(mut preset) => {
+                    while !preset.extends.is_empty() {
+                        resolve_extends(&mut preset, console);
+                    }
+                    for mut object in preset.into_flat_config_objects() {
+                        // `{ ...js.configs.recommended, files }` restricts the preset to `files`.
+                        // The overrides of the preset keep their own files.
+                        if object.files.is_empty() && !object.is_global_ignores() {
+                            object.files = files.clone().into();
+                        }
+                        data.0.push(object);
+                    }
+                }
+                Err(diagnostic) => {
+                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                }
+            },
+        }
+    }
+    Ok(data)
+}
+
+/// Load an ESlint Flat config by importing it with `node`.
+fn load_flat_config_data_with_node(
     path: &Utf8Path,
     console: &mut dyn Console,
 ) -> Result<eslint_eslint::FlatConfigData, CliDiagnostic> {
@@ -71,7 +71,13 @@ impl Merge for FlatConfigObject {
         self.files.extend(other.files);
         self.ignores.extend(other.ignores);
         self.language_options.merge_with(other.language_options);
-        self.rules.merge_with(other.rules);
+        // The rules of the config objects that come later take precedence.
+        if let Some(mut rules) = other.rules {
+            if let Some(previous_rules) = self.rules.take() {
+                rules.merge_with(previous_rules);
+            }
+            self.rules = Some(rules);
+        }
     }
 }
 
@@ -113,6 +119,47 @@ impl Merge for LegacyConfigData {
         self.overrides.append(&mut other.overrides);
     }
 }
+impl LegacyConfigData {
+    /// Converts a resolved legacy config into flat config objects.
+    ///
+    /// The config becomes a global config object,
+    /// and each override becomes a config object restricted to the files of the override.
+    pub(crate) fn into_flat_config_objects(self) -> Vec<FlatConfigObject> {
+        let mut objects = Vec::with_capacity(self.overrides.len() + 2);
+        if !self.ignore_patterns.is_empty() {
+            objects.push(FlatConfigObject {
+                ignores: self
+                    .ignore_patterns
+                    .into_iter()
+                    .map(|pattern| pattern.0)
+                    .collect(),
+                ..Default::default()
+            });
+        }
+        objects.push(FlatConfigObject {
+            language_options: (!self.globals.is_empty()).then_some(FlatLanguageOptions {
+                globals: self.globals,
+            }),
+            rules: Some(self.rules),
+            ..Default::default()
+        });
+        objects.extend(
+            self.overrides
+                .into_iter()
+                .map(|override_data| FlatConfigObject {
+                    files: override_data.files.into_iter().collect::<Vec<_>>().into(),
+                    ignores: override_data.excluded_files.into_iter().collect(),
+                    language_options: (!override_data.globals.is_empty()).then_some(
+                        FlatLanguageOptions {
+                            globals: override_data.globals,
+                        },
+                    ),
+                    rules: Some(override_data.rules),
+                }),
+        );
+        objects
+    }
+}
 
 #[derive(Debug, Default)]
 pub(crate) struct IgnorePattern(pub(crate) Box<str>);
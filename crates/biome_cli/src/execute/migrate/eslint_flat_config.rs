@@ -0,0 +1,748 @@
+//! Statically evaluates an ESLint flat configuration written as a JavaScript or
+//! TypeScript module, without running it.
+//!
+//! Only the subset of JavaScript commonly used in configuration files is understood:
+//! - the default export, `module.exports = ...` and `export = ...`;
+//! - array, object and literal expressions;
+//! - spread elements, `defineConfig()`, `globalIgnores()` and `tseslint.config()`;
+//! - references to top-level `const` declarations;
+//! - presets of imported packages, such as `js.configs.recommended`.
+//!
+//! Presets are translated to their legacy name, e.g. `eslint:recommended`,
+//! so they can be resolved like the `extends` field of a legacy configuration.
+//!
+//! See https://eslint.org/docs/latest/use/configure/configuration-files
+
+use crate::CliDiagnostic;
+use crate::diagnostics::MigrationDiagnostic;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern,
+    AnyJsCallArgument, AnyJsExportClause, AnyJsExpression, AnyJsImportSpecifier,
+    AnyJsMemberExpression, AnyJsModuleItem, AnyJsObjectMember, AnyJsRoot, AnyJsStatement, JsImport,
+    JsVariableDeclaration, static_value::StaticValue,
+};
+use biome_json_parser::JsonParserOptions;
+use biome_languages::JsFileSource;
+use biome_rowan::{AstNode, TextRange};
+use camino::Utf8Path;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
+
+use super::eslint_eslint::FlatConfigObject;
+
+/// An element of a flat configuration array.
+#[derive(Debug)]
+pub(crate) enum FlatConfigItem {
+    /// A configuration object written in the configuration file.
+    Object(FlatConfigObject),
+    /// A preset provided by a package, e.g. `eslint:recommended` or
+    /// `plugin:@typescript-eslint/recommended`.
+    ///
+    /// `files` restricts the preset when it's spread in a configuration object,
+    /// e.g. `{ ...js.configs.recommended, files: ["**/*.js"] }`.
+    Preset { name: String, files: Vec<Box<str>> },
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StaticFlatConfig {
+    pub(crate) items: Vec<FlatConfigItem>,
+    /// Ranges of the expressions that cannot be evaluated statically.
+    /// They are ignored.
+    pub(crate) unsupported: Vec<TextRange>,
+}
+
+/// Returns the flat configuration exported by the module `source` located at `path`.
+pub(crate) fn evaluate_flat_config(
+    path: &Utf8Path,
+    source: &str,
+) -> Result<StaticFlatConfig, CliDiagnostic> {
+    let file_source = JsFileSource::try_from(path).unwrap_or_else(|_| JsFileSource::js_module());
+    let parsed = parse(source, file_source, JsParserOptions::default());
+    if parsed.has_errors() {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!("The ESLint configuration file {path} contains syntax errors."),
+        }));
+    }
+    let mut evaluator = FlatConfigEvaluator::default();
+    let Some(exported) = evaluator.collect_module(&parsed.tree()) else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: format!(
+                "The ESLint configuration file {path} doesn't have a default export or a `module.exports` assignment."
+            ),
+        }));
+    };
+    evaluator.push_config_array(&exported);
+    Ok(evaluator.config)
+}
+
+/// A top-level binding of the configuration module.
+enum Binding {
+    /// A binding imported from `source`.
+    /// `imported` is `None` for a default or a namespace import.
+    Import {
+        source: String,
+        imported: Option<String>,
+    },
+    /// A constant initialized with an expression.
+    Constant(AnyJsExpression),
+}
+
+#[derive(Default)]
+struct FlatConfigEvaluator {
+    bindings: FxHashMap<String, Binding>,
+    /// Names of the constants being evaluated.
+    /// Used to stop at self-referencing constants, such as `const files = [...files]`.
+    resolving: RefCell<FxHashSet<String>>,
+    config: StaticFlatConfig,
+}
+
+impl FlatConfigEvaluator {
+    /// Collects the top-level bindings of `root` and returns its exported expression.
+    fn collect_module(&mut self, root: &AnyJsRoot) -> Option<AnyJsExpression> {
+        let mut exported = None;
+        match root {
+            AnyJsRoot::JsModule(module) => {
+                for item in module.items() {
+                    match item {
+                        AnyJsModuleItem::JsImport(import) => self.collect_import(&import),
+                        AnyJsModuleItem::JsExport(export) => match export.export_clause() {
+                            Ok(AnyJsExportClause::JsExportDefaultExpressionClause(clause)) => {
+                                exported = clause.expression().ok();
+                            }
+                            Ok(AnyJsExportClause::TsExportAssignmentClause(clause)) => {
+                                exported = clause.expression().ok();
+                            }
+                            Ok(AnyJsExportClause::AnyJsDeclarationClause(clause)) => {
+                                if let Some(clause) = clause.as_js_variable_declaration_clause()
+                                    && let Ok(declaration) = clause.declaration()
+                                {
+                                    self.collect_declaration(&declaration);
+                                }
+                            }
+                            _ => {}
+                        },
+                        AnyJsModuleItem::AnyJsStatement(statement) => {
+                            if let Some(expression) = self.collect_statement(&statement) {
+                                exported = Some(expression);
+                            }
+                        }
+                    }
+                }
+            }
+            AnyJsRoot::JsScript(script) => {
+                for statement in script.statements() {
+                    if let Some(expression) = self.collect_statement(&statement) {
+                        exported = Some(expression);
+                    }
+                }
+            }
+            _ => {}
+        }
+        exported
+    }
+
+    /// Collects the bindings declared by `statement`,
+    /// and returns the expression assigned to `module.exports` if any.
+    fn collect_statement(&mut self, statement: &AnyJsStatement) -> Option<AnyJsExpression> {
+        match statement {
+            AnyJsStatement::JsVariableStatement(statement) => {
+                self.collect_declaration(&statement.declaration().ok()?);
+                None
+            }
+            AnyJsStatement::JsExpressionStatement(statement) => {
+                let expression = statement.expression().ok()?;
+                let assignment = expression.as_js_assignment_expression()?;
+                let AnyJsAssignmentPattern::AnyJsAssignment(
+                    AnyJsAssignment::JsStaticMemberAssignment(left),
+                ) = assignment.left().ok()?
+                else {
+                    return None;
+                };
+                let object = left.object().ok()?;
+                let object = object.as_js_identifier_expression()?.name().ok()?;
+                let member = left.member().ok()?;
+                let member = member.as_js_name()?.value_token().ok()?;
+                (object.has_name("module") && member.text_trimmed() == "exports")
+                    .then(|| assignment.right().ok())
+                    .flatten()
+            }
+            _ => None,
+        }
+    }
+
+    fn collect_declaration(&mut self, declaration: &JsVariableDeclaration) {
+        for declarator in declaration.declarators().into_iter().flatten() {
+            let Ok(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(id))) =
+                declarator.id()
+            else {
+                continue;
+            };
+            let (Ok(name), Some(Ok(expression))) = (
+                id.name_token(),
+                declarator
+                    .initializer()
+                    .map(|initializer| initializer.expression()),
+            ) else {
+                continue;
+            };
+            let name = name.text_trimmed().to_string();
+            // `const js = require("@eslint/js")`
+            let binding = if let Some(source) = required_module(&expression) {
+                Binding::Import {
+                    source,
+                    imported: None,
+                }
+            } else {
+                Binding::Constant(expression)
+            };
+            self.bindings.insert(name, binding);
+        }
+    }
+
+    fn collect_import(&mut self, import: &JsImport) {
+        let Ok(clause) = import.import_clause() else {
+            return;
+        };
+        let Some(source) = clause
+            .source()
+            .ok()
+            .and_then(|source| source.inner_string_text().ok())
+        else {
+            return;
+        };
+        for specifier in import
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsImportSpecifier::cast)
+        {
+            let Some(local_name) = specifier
+                .local_name()
+                .ok()
+                .and_then(|binding| binding.as_js_identifier_binding()?.name_token().ok())
+            else {
+                continue;
+            };
+            let local_name = local_name.text_trimmed().to_string();
+            let imported = match &specifier {
+                AnyJsImportSpecifier::JsNamedImportSpecifier(specifier) => specifier
+                    .name()
+                    .ok()
+                    .and_then(|name| name.inner_string_text().ok())
+                    .map(|name| name.to_string()),
+                AnyJsImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+                    Some(local_name.clone())
+                }
+                AnyJsImportSpecifier::JsNamespaceImportSpecifier(_)
+                | AnyJsImportSpecifier::JsDefaultImportSpecifier(_) => None,
+            };
+            self.bindings.insert(
+                local_name,
+                Binding::Import {
+                    source: source.to_string(),
+                    imported,
+                },
+            );
+        }
+    }
+
+    /// Pushes the configuration objects of `expression`, which is expected to evaluate to an
+    /// array of configuration objects, or to a single configuration object.
+    fn push_config_array(&mut self, expression: &AnyJsExpression) {
+        let expression = omit_type_expressions(expression.clone());
+        match &expression {
+            AnyJsExpression::JsArrayExpression(array) => {
+                for element in array.elements().into_iter().flatten() {
+                    match element {
+                        AnyJsArrayElement::AnyJsExpression(element) => {
+                            self.push_config_array(&element)
+                        }
+                        AnyJsArrayElement::JsSpread(spread) => {
+                            if let Ok(argument) = spread.argument() {
+                                self.push_config_array(&argument);
+                            }
+                        }
+                        AnyJsArrayElement::JsArrayHole(_) => {}
+                    }
+                }
+            }
+            AnyJsExpression::JsObjectExpression(_) => {
+                if let Some(object) = self.evaluate_config_object(&expression) {
+                    self.config.items.push(FlatConfigItem::Object(object));
+                }
+            }
+            AnyJsExpression::JsCallExpression(call) => {
+                let callee = call
+                    .callee()
+                    .ok()
+                    .and_then(|callee| self.import_of(&callee))
+                    .map(|(source, path)| (source.to_string(), path));
+                let mut arguments = call
+                    .arguments()
+                    .ok()
+                    .into_iter()
+                    .flat_map(|arguments| arguments.args())
+                    .flatten();
+                match callee {
+                    // `defineConfig(...)` from `eslint/config` and `tseslint.config(...)`
+                    Some((source, path))
+                        if (source == "eslint/config" && path == ["defineConfig"])
+                            || (source == "typescript-eslint" && path == ["config"]) =>
+                    {
+                        for argument in arguments {
+                            let argument = match argument {
+                                AnyJsCallArgument::AnyJsExpression(argument) => argument,
+                                AnyJsCallArgument::JsSpread(spread) => {
+                                    let Ok(argument) = spread.argument() else {
+                                        continue;
+                                    };
+                                    argument
+                                }
+                            };
+                            self.push_config_array(&argument);
+                        }
+                    }
+                    // `globalIgnores([...])` from `eslint/config`
+                    Some((source, path))
+                        if source == "eslint/config" && path == ["globalIgnores"] =>
+                    {
+                        let ignores = arguments
+                            .next()
+                            .and_then(|argument| argument.as_any_js_expression().cloned())
+                            .and_then(|argument| self.to_json(&argument));
+                        match ignores {
+                            Some(ignores) => {
+                                let object = serde_json::json!({ "ignores": ignores });
+                                if let Some(object) = deserialize_config_object(&object) {
+                                    self.config.items.push(FlatConfigItem::Object(object));
+                                }
+                            }
+                            None => self.config.unsupported.push(expression.range()),
+                        }
+                    }
+                    _ => self.config.unsupported.push(expression.range()),
+                }
+            }
+            AnyJsExpression::JsIdentifierExpression(_)
+            | AnyJsExpression::JsStaticMemberExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_) => {
+                if let Some(name) = self.preset_of(&expression) {
+                    self.config.items.push(FlatConfigItem::Preset {
+                        name,
+                        files: Vec::new(),
+                    });
+                } else if let Some((name, constant)) = self.constant_of(&expression)
+                    && self.resolving.get_mut().insert(name.clone())
+                {
+                    self.push_config_array(&constant);
+                    self.resolving.get_mut().remove(&name);
+                } else {
+                    self.config.unsupported.push(expression.range());
+                }
+            }
+            _ => self.config.unsupported.push(expression.range()),
+        }
+    }
+
+    /// Evaluates a configuration object.
+    ///
+    /// The properties that cannot be evaluated statically, such as `plugins` or
+    /// `languageOptions.parser`, are ignored, because they don't have a Biome equivalent.
+    fn evaluate_config_object(&mut self, expression: &AnyJsExpression) -> Option<FlatConfigObject> {
+        let AnyJsExpression::JsObjectExpression(object) = expression else {
+            return None;
+        };
+        let mut json = serde_json::Map::new();
+        let mut presets = Vec::new();
+        for member in object.members().into_iter().flatten() {
+            if let AnyJsObjectMember::JsSpread(spread) = &member {
+                // `{ ...js.configs.recommended, files: ["**/*.js"] }`
+                let argument = spread.argument().ok()?;
+                match self.preset_of(&argument) {
+                    Some(preset) => presets.push(preset),
+                    None => self.config.unsupported.push(argument.range()),
+                }
+                continue;
+            }
+            let (Some(name), Some(value)) = (member.name(), self.member_to_json(&member)) else {
+                continue;
+            };
+            json.insert(name.to_string(), value);
+        }
+        let object = deserialize_config_object(&serde_json::Value::Object(json));
+        let files = object
+            .as_ref()
+            .map(|object| object.files.to_vec())
+            .unwrap_or_default();
+        for name in presets {
+            self.config.items.push(FlatConfigItem::Preset {
+                name,
+                files: files.clone(),
+            });
+        }
+        object
+    }
+
+    /// Converts the value of an object member to JSON.
+    fn member_to_json(&self, member: &AnyJsObjectMember) -> Option<serde_json::Value> {
+        match member {
+            AnyJsObjectMember::JsPropertyObjectMember(member) => {
+                self.to_json(&member.value().ok()?)
+            }
+            AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+                let name = member.name().ok()?.value_token().ok()?;
+                self.constant_to_json(name.text_trimmed())
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a static expression to JSON.
+    ///
+    /// Returns `None` if the expression cannot be evaluated statically.
+    /// Object properties and array elements that cannot be evaluated are skipped.
+    fn to_json(&self, expression: &AnyJsExpression) -> Option<serde_json::Value> {
+        let expression = omit_type_expressions(expression.clone());
+        if let Some(value) = expression.as_static_value() {
+            return match value {
+                StaticValue::Boolean(token) => {
+                    Some(serde_json::Value::Bool(token.text_trimmed() == "true"))
+                }
+                StaticValue::Null(_) => Some(serde_json::Value::Null),
+                StaticValue::Number(_) => serde_json::from_str(value.text()).ok(),
+                StaticValue::String(_) | StaticValue::EmptyString(_) => {
+                    Some(serde_json::Value::String(unescape(value.text())))
+                }
+                StaticValue::Undefined(_) | StaticValue::BigInt(_) => None,
+            };
+        }
+        match &expression {
+            AnyJsExpression::JsUnaryExpression(unary) => {
+                let argument = unary.argument().ok()?;
+                let number = argument
+                    .as_any_js_literal_expression()?
+                    .as_js_number_literal_expression()?
+                    .value_token()
+                    .ok()?;
+                match unary.operator_token().ok()?.text_trimmed() {
+                    "-" => serde_json::from_str(&format!("-{}", number.text_trimmed())).ok(),
+                    _ => None,
+                }
+            }
+            AnyJsExpression::JsArrayExpression(array) => {
+                let mut values = Vec::new();
+                for element in array.elements().into_iter().flatten() {
+                    match element {
+                        AnyJsArrayElement::AnyJsExpression(element) => {
+                            values.extend(self.to_json(&element));
+                        }
+                        AnyJsArrayElement::JsSpread(spread) => {
+                            if let Some(serde_json::Value::Array(spread)) =
+                                self.to_json(&spread.argument().ok()?)
+                            {
+                                values.extend(spread);
+                            }
+                        }
+                        AnyJsArrayElement::JsArrayHole(_) => {}
+                    }
+                }
+                Some(serde_json::Value::Array(values))
+            }
+            AnyJsExpression::JsObjectExpression(object) => {
+                let mut values = serde_json::Map::new();
+                for member in object.members().into_iter().flatten() {
+                    if let AnyJsObjectMember::JsSpread(spread) = &member {
+                        if let Some(serde_json::Value::Object(spread)) =
+                            self.to_json(&spread.argument().ok()?)
+                        {
+                            values.extend(spread);
+                        }
+                        continue;
+                    }
+                    let (Some(name), Some(value)) = (member.name(), self.member_to_json(&member))
+                    else {
+                        continue;
+                    };
+                    values.insert(name.to_string(), value);
+                }
+                Some(serde_json::Value::Object(values))
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                self.constant_to_json(name.text_trimmed())
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts the constant `name` to JSON.
+    ///
+    /// Returns `None` if the constant references itself, e.g. `const files = [...files]`.
+    fn constant_to_json(&self, name: &str) -> Option<serde_json::Value> {
+        let expression = self.constant_named(name)?;
+        if !self.resolving.borrow_mut().insert(name.to_string()) {
+            return None;
+        }
+        let value = self.to_json(&expression);
+        self.resolving.borrow_mut().remove(name);
+        value
+    }
+
+    /// Returns the name and the expression of the constant referenced by `expression`.
+    fn constant_of(&self, expression: &AnyJsExpression) -> Option<(String, AnyJsExpression)> {
+        let name = expression
+            .as_js_identifier_expression()?
+            .name()
+            .ok()?
+            .value_token()
+            .ok()?;
+        let name = name.text_trimmed();
+        Some((name.to_string(), self.constant_named(name)?))
+    }
+
+    /// Returns the expression of the constant `name`.
+    fn constant_named(&self, name: &str) -> Option<AnyJsExpression> {
+        match self.bindings.get(name)? {
+            Binding::Constant(expression) => Some(expression.clone()),
+            Binding::Import { .. } => None,
+        }
+    }
+
+    /// Returns the module and the path of the member accessed by `expression`.
+    ///
+    /// For example, `tseslint.configs.recommended` returns
+    /// `("typescript-eslint", ["configs", "recommended"])`
+    /// when `tseslint` is the default import of `typescript-eslint`.
+    fn import_of(&self, expression: &AnyJsExpression) -> Option<(&str, Vec<String>)> {
+        let mut path = Vec::new();
+        let mut expression = omit_type_expressions(expression.clone());
+        loop {
+            match expression {
+                AnyJsExpression::JsStaticMemberExpression(_)
+                | AnyJsExpression::JsComputedMemberExpression(_) => {
+                    let member = AnyJsMemberExpression::cast(expression.into_syntax())?;
+                    path.push(member.member_name()?.text().to_string());
+                    expression = omit_type_expressions(member.object().ok()?);
+                }
+                AnyJsExpression::JsIdentifierExpression(identifier) => {
+                    let name = identifier.name().ok()?.value_token().ok()?;
+                    let Binding::Import { source, imported } =
+                        self.bindings.get(name.text_trimmed())?
+                    else {
+                        return None;
+                    };
+                    path.extend(imported.clone());
+                    path.reverse();
+                    return Some((source, path));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    /// Returns the legacy name of the preset referenced by `expression`.
+    ///
+    /// For example, `js.configs.recommended` returns `eslint:recommended`
+    /// when `js` is the default import of `@eslint/js`.
+    fn preset_of(&self, expression: &AnyJsExpression) -> Option<String> {
+        let (source, path) = self.import_of(expression)?;
+        let [configs, config_name @ ..] = path.as_slice() else {
+            return None;
+        };
+        if configs != "configs" || config_name.is_empty() {
+            return None;
+        }
+        // Plugins usually prefix their flat presets with `flat/` or `flat.`,
+        // e.g. `react.configs.flat.recommended` or `unicorn.configs["flat/recommended"]`.
+        let config_name = config_name.last()?;
+        let config_name = config_name.strip_prefix("flat/").unwrap_or(config_name);
+        match source {
+            "@eslint/js" => Some(format!("eslint:{config_name}")),
+            // The legacy presets of `typescript-eslint` are in kebab case,
+            // e.g. `recommendedTypeChecked` is `recommended-type-checked`.
+            "typescript-eslint" => {
+                let config_name = config_name
+                    .chars()
+                    .flat_map(|c| {
+                        let separator = c.is_ascii_uppercase().then_some('-');
+                        separator.into_iter().chain(c.to_lowercase())
+                    })
+                    .collect::<String>();
+                Some(format!("plugin:@typescript-eslint/{config_name}"))
+            }
+            _ => Some(format!("plugin:{source}/{config_name}")),
+        }
+    }
+}
+
+/// Returns `source` if `expression` is `require("<source>")`.
+fn required_module(expression: &AnyJsExpression) -> Option<String> {
+    let call = expression.as_js_call_expression()?;
+    let callee = call.callee().ok()?;
+    let callee = callee.as_js_identifier_expression()?.name().ok()?;
+    if !callee.has_name("require") {
+        return None;
+    }
+    let argument = call.arguments().ok()?.args().into_iter().next()?.ok()?;
+    let argument = argument.as_any_js_expression()?.as_static_value()?;
+    matches!(argument, StaticValue::String(_)).then(|| argument.text().to_string())
+}
+
+/// Unescapes the content of a string literal.
+///
+/// JavaScript escape sequences are a superset of the JSON ones, so they are unescaped by the JSON parser.
+/// The content is returned as is when it contains an escape sequence unknown to JSON.
+fn unescape(text: &str) -> String {
+    if !text.contains('\\') {
+        return text.to_string();
+    }
+    serde_json::from_str(&format!("\"{}\"", text.replace("\\'", "'")))
+        .unwrap_or_else(|_| text.to_string())
+}
+
+/// Removes parentheses and type assertions such as `satisfies Linter.Config[]`.
+fn omit_type_expressions(expression: AnyJsExpression) -> AnyJsExpression {
+    let mut expression = expression.omit_parentheses();
+    loop {
+        let inner = match &expression {
+            AnyJsExpression::TsAsExpression(expression) => expression.expression(),
+            AnyJsExpression::TsSatisfiesExpression(expression) => expression.expression(),
+            AnyJsExpression::TsNonNullAssertionExpression(expression) => expression.expression(),
+            _ => return expression,
+        };
+        let Ok(inner) = inner else {
+            return expression;
+        };
+        expression = inner.omit_parentheses();
+    }
+}
+
+fn deserialize_config_object(json: &serde_json::Value) -> Option<FlatConfigObject> {
+    deserialize_from_json_str::<FlatConfigObject>(
+        &json.to_string(),
+        JsonParserOptions::default(),
+        "",
+    )
+    .into_deserialized()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(path: &str, source: &str) -> StaticFlatConfig {
+        evaluate_flat_config(Utf8Path::new(path), source).unwrap()
+    }
+
+    #[test]
+    fn evaluate_define_config() {
+        let config = evaluate(
+            "eslint.config.ts",
+            r#"
+import js from "@eslint/js";
+import tseslint from "typescript-eslint";
+import { defineConfig, globalIgnores } from "eslint/config";
+import type { Linter } from "eslint";
+
+const files = ["src/**/*.ts"];
+
+export default defineConfig([
+    globalIgnores(["dist/**"]),
+    js.configs.recommended,
+    ...tseslint.configs.recommended,
+    {
+        files,
+        ignores: ["**/*.test.ts"],
+        plugins: { ts: tseslint.plugin },
+        rules: { eqeqeq: ["error", "smart"], "no-console": 2 },
+    } satisfies Linter.Config,
+]);
+"#,
+        );
+        assert!(config.unsupported.is_empty());
+        let [
+            FlatConfigItem::Object(global_ignores),
+            FlatConfigItem::Preset { name: js, .. },
+            FlatConfigItem::Preset { name: ts, .. },
+            FlatConfigItem::Object(object),
+        ] = config.items.as_slice()
+        else {
+            panic!("unexpected items: {:?}", config.items);
+        };
+        assert!(global_ignores.is_global_ignores());
+        assert_eq!(js, "eslint:recommended");
+        assert_eq!(ts, "plugin:@typescript-eslint/recommended");
+        assert_eq!(object.files.len(), 1);
+        assert_eq!(object.ignores.len(), 1);
+        assert_eq!(object.rules.as_ref().map(|rules| rules.len()), Some(2));
+    }
+
+    #[test]
+    fn evaluate_module_exports() {
+        let config = evaluate(
+            "eslint.config.cjs",
+            r#"
+const unicorn = require("eslint-plugin-unicorn");
+
+module.exports = [
+    unicorn.configs["flat/recommended"],
+    { rules: { "no-debugger": "off" } },
+    someHelper(),
+];
+"#,
+        );
+        assert_eq!(config.unsupported.len(), 1);
+        let [
+            FlatConfigItem::Preset { name: unicorn, .. },
+            FlatConfigItem::Object(object),
+        ] = config.items.as_slice()
+        else {
+            panic!("unexpected items: {:?}", config.items);
+        };
+        assert_eq!(unicorn, "plugin:eslint-plugin-unicorn/recommended");
+        assert!(object.is_global_config());
+    }
+
+    #[test]
+    fn evaluate_spread_preset_with_files() {
+        let config = evaluate(
+            "eslint.config.mjs",
+            r#"
+import js from "@eslint/js";
+
+export default [{ ...js.configs.recommended, files: ["src/**/*.js"] }];
+"#,
+        );
+        assert!(config.unsupported.is_empty());
+        let [
+            FlatConfigItem::Preset { name, files },
+            FlatConfigItem::Object(object),
+        ] = config.items.as_slice()
+        else {
+            panic!("unexpected items: {:?}", config.items);
+        };
+        assert_eq!(name, "eslint:recommended");
+        assert_eq!(files.as_slice(), [Box::<str>::from("src/**/*.js")]);
+        assert_eq!(object.files.len(), 1);
+    }
+
+    #[test]
+    fn evaluate_self_referencing_constants() {
+        let config = evaluate(
+            "eslint.config.mjs",
+            r#"
+const files = [...files, "**/*.js"];
+const config = [config, { files, rules: { eqeqeq: "error" } }];
+
+export default config;
+"#,
+        );
+        assert_eq!(config.unsupported.len(), 1);
+        let [FlatConfigItem::Object(object)] = config.items.as_slice() else {
+            panic!("unexpected items: {:?}", config.items);
+        };
+        assert_eq!(object.files.len(), 1);
+        assert_eq!(object.rules.as_ref().map(|rules| rules.len()), Some(1));
+    }
+}
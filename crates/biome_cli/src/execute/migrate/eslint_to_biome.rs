@@ -1247,6 +1247,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flat_config_later_rules_take_precedence() {
+        let flat_config = FlatConfigData(vec![
+            FlatConfigObject {
+                files: vec![].into(),
+                ignores: vec!["*.test.js".into()],
+                language_options: None,
+                rules: None,
+            },
+            FlatConfigObject {
+                files: vec![].into(),
+                ignores: vec![],
+                language_options: None,
+                rules: Some(Rules(
+                    [Rule::Any(Cow::Borrowed("eqeqeq"), Severity::Error)]
+                        .into_iter()
+                        .collect(),
+                )),
+            },
+            FlatConfigObject {
+                files: vec![].into(),
+                ignores: vec![],
+                language_options: None,
+                rules: Some(Rules(
+                    [Rule::Any(Cow::Borrowed("eqeqeq"), Severity::Off)]
+                        .into_iter()
+                        .collect(),
+                )),
+            },
+        ]);
+        let (biome_config, _) = flat_config.into_biome_config(&MigrationOptions::default());
+
+        assert_eq!(
+            biome_config
+                .linter
+                .unwrap()
+                .rules
+                .unwrap()
+                .suspicious
+                .unwrap()
+                .unwrap_group()
+                .no_double_equals,
+            Some(biome_config::RuleFixConfiguration::Plain(
+                biome_config::RulePlainConfiguration::Off
+            ))
+        );
+    }
+
     #[test]
     fn sanity_check_unsupported_rule_lookup() {
         assert_eq!(
@@ -0,0 +1,609 @@
+//! This modules includes implementations for reading and deserializing a Stylelint configuration.
+//!
+//! The defined types follow the Stylelint configuration described at
+//! <https://stylelint.io/user-guide/configure>.
+//!
+//! See [super::stylelint_to_biome] for converting a Stylelint config to a Biome config.
+
+use super::yaml::yaml_to_json;
+use super::{eslint_eslint::ShorthandVec, node};
+use crate::CliDiagnostic;
+use crate::diagnostics::MigrationDiagnostic;
+use biome_console::{Console, ConsoleExt, markup};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationContext, DeserializationDiagnostic, DeserializationVisitor, MapMembers, Merge,
+    Text,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use camino::Utf8Path;
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+/// A Stylelint config can be embedded in `package.json`
+const PACKAGE_JSON: &str = "package.json";
+
+/// Stylelint config files ordered by precedence.
+///
+/// See https://stylelint.io/user-guide/configure
+const CONFIG_FILES: [&str; 10] = [
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./stylelint.config.js",
+    "./stylelint.config.mjs",
+    "./stylelint.config.cjs",
+    "./.stylelintrc.js",
+    "./.stylelintrc.mjs",
+    "./.stylelintrc.cjs",
+    ".stylelintrc.json",
+    ".stylelintrc.yaml",
+    ".stylelintrc.yml",
+    ".stylelintrc",
+];
+
+/// Stylelint Ignore file. Use the same syntax as gitignore.
+pub(crate) const IGNORE_FILE: &str = ".stylelintignore";
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the Stylelint config file
+    pub(crate) path: &'static str,
+    /// Resolved Stylelint config
+    pub(crate) data: StylelintConfigData,
+}
+
+/// Returns the Stylelint configuration file in the working directory with the highest priority.
+///
+/// Configurations written in JavaScript are extracted by invoking `node`.
+/// The `extends` field is recursively resolved.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, unsupported config format, ...) are directly returned.
+pub(crate) fn read_stylelint_config(
+    fs: &dyn FileSystem,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    for config_path_str in CONFIG_FILES {
+        let path = Utf8Path::new(config_path_str);
+        if fs.path_exists(path) {
+            return load_config_data(fs, path, console).map(|data| Config {
+                path: config_path_str,
+                data,
+            });
+        }
+    }
+    // We don't report an error if Stylelint config is not embedded in `PACKAGE_JSON`.
+    if let Ok(data) = load_config_data(fs, Utf8Path::new(PACKAGE_JSON), console) {
+        return Ok(Config {
+            path: PACKAGE_JSON,
+            data,
+        });
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: "The default Stylelint configuration file `.stylelintrc[.*]` was not found in the working directory.".to_string(),
+    }))
+}
+
+fn load_config_data(
+    fs: &dyn FileSystem,
+    path: &Utf8Path,
+    console: &mut dyn Console,
+) -> Result<StylelintConfigData, CliDiagnostic> {
+    let (deserialized, diagnostics) = match path.extension() {
+        None | Some("json") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            if path.file_name().is_some_and(|name| name == PACKAGE_JSON) {
+                let (deserialized, diagnostics) =
+                    deserialize_from_json_str::<StylelintPackageJson>(
+                        &content,
+                        JsonParserOptions::default()
+                            .with_allow_trailing_commas()
+                            .with_allow_comments(),
+                        "",
+                    )
+                    .consume();
+                (
+                    deserialized.and_then(|packagejson| packagejson.stylelint),
+                    diagnostics,
+                )
+            } else {
+                let (deserialized, diagnostics) = deserialize_from_json_str::<StylelintConfigData>(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_trailing_commas()
+                        .with_allow_comments(),
+                    "",
+                )
+                .consume();
+                // `.stylelintrc` may be a YAML file.
+                if deserialized.is_none() && path.extension().is_none() {
+                    deserialize_from_yaml_str(&content)
+                } else {
+                    (deserialized, diagnostics)
+                }
+            }
+        }
+        Some("yaml" | "yml") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            deserialize_from_yaml_str(&content)
+        }
+        Some("js" | "mjs" | "cjs") => {
+            let node::Resolution { content, .. } = node::load_config(path.as_ref())?;
+            deserialize_from_json_str::<StylelintConfigData>(
+                &content,
+                JsonParserOptions::default(),
+                "",
+            )
+            .consume()
+        }
+        Some(ext) => {
+            return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "Stylelint configuration ending with the extension `{ext}` are not supported."
+                ),
+            }));
+        }
+    };
+    let path_str = path.to_string();
+    for diagnostic in diagnostics.into_iter().filter(|diag| {
+        matches!(
+            diag.severity(),
+            biome_diagnostics::Severity::Fatal
+                | biome_diagnostics::Severity::Error
+                | biome_diagnostics::Severity::Warning
+        )
+    }) {
+        let diagnostic = diagnostic.with_file_path(path_str.clone());
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+    }
+    if let Some(mut result) = deserialized {
+        // recursively resolve the `extends` field.
+        while !result.extends.is_empty() {
+            resolve_extends(&mut result, console);
+        }
+        Ok(result)
+    } else {
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file".to_string(),
+        }))
+    }
+}
+
+/// Deserializes a Stylelint configuration written in YAML.
+///
+/// The configuration is converted to JSON first. YAML that can't be converted
+/// is reported as a configuration that can't be deserialized.
+fn deserialize_from_yaml_str(content: &str) -> (Option<StylelintConfigData>, Vec<Error>) {
+    match yaml_to_json(content) {
+        Some(json) => deserialize_from_json_str::<StylelintConfigData>(
+            &json.to_string(),
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume(),
+        None => (None, Vec::new()),
+    }
+}
+
+/// Load and merge the shared configurations of `config.extends`.
+///
+/// The rules of `config` take precedence over the rules of the shared configurations.
+/// Shared configurations that cannot be loaded are reported and ignored.
+/// `config.extends` is replaced by the `extends` of the shared configurations.
+fn resolve_extends(config: &mut StylelintConfigData, console: &mut dyn Console) {
+    let mut resolved = StylelintConfigData::default();
+    for name in std::mem::take(&mut *config.extends) {
+        match load_stylelint_extends_config(&name) {
+            Ok(extended) => resolved.merge_with(extended),
+            Err(diagnostic) => {
+                console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+            }
+        }
+    }
+    let extends = std::mem::take(&mut resolved.extends);
+    resolved.merge_with(std::mem::take(config));
+    *config = resolved;
+    config.extends = extends;
+}
+
+/// Returns the shared configuration `name` by importing it with `node`.
+fn load_stylelint_extends_config(name: &str) -> Result<StylelintConfigData, CliDiagnostic> {
+    let node::Resolution { content, .. } = node::load_config(name)?;
+    deserialize_from_json_str::<StylelintConfigData>(&content, JsonParserOptions::default(), "")
+        .into_deserialized()
+        .ok_or_else(|| {
+            CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "The Stylelint configuration of the module '{name}' cannot be extracted."
+                ),
+            })
+        })
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintPackageJson {
+    pub(crate) stylelint: Option<StylelintConfigData>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintConfigData {
+    pub(crate) extends: ShorthandVec<String>,
+    /// The severity of the rules that don't specify a severity.
+    pub(crate) default_severity: Option<Severity>,
+    /// The glob patterns of the ignored files.
+    pub(crate) ignore_files: ShorthandVec<Box<str>>,
+    pub(crate) rules: Rules,
+    pub(crate) overrides: Vec<OverrideConfigData>,
+}
+impl Merge for StylelintConfigData {
+    fn merge_with(&mut self, mut other: Self) {
+        self.extends.merge_with(other.extends);
+        if other.default_severity.is_some() {
+            self.default_severity = other.default_severity;
+        }
+        self.ignore_files.merge_with(other.ignore_files);
+        self.rules.merge_with(other.rules);
+        self.overrides.append(&mut other.overrides);
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct OverrideConfigData {
+    /// The glob patterns for target files.
+    pub(crate) files: ShorthandVec<Box<str>>,
+    pub(crate) rules: Rules,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+impl Deserializable for Severity {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        let text = Text::deserialize(ctx, value, name)?;
+        match text.text() {
+            "warning" => Some(Self::Warning),
+            "error" => Some(Self::Error),
+            _ => {
+                ctx.report(DeserializationDiagnostic::new_unknown_value(
+                    text.text(),
+                    value.range(),
+                    &["warning", "error"],
+                ));
+                None
+            }
+        }
+    }
+}
+impl From<Severity> for biome_configuration::RulePlainConfiguration {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Off => Self::Off,
+            Severity::Warning => Self::Warn,
+            Severity::Error => Self::Error,
+        }
+    }
+}
+
+/// Secondary options shared by all rules.
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct CommonSecondaryOptions {
+    severity: Option<Severity>,
+}
+
+/// Configuration of a rule.
+///
+/// See https://stylelint.io/user-guide/configure#rules
+#[derive(Debug)]
+pub(crate) enum RuleConf<T = (), U = ()> {
+    /// `null` turns off the rule.
+    Off,
+    /// `true`, a primary option, or `[<primary option>, <secondary options>]`.
+    On {
+        primary: Option<T>,
+        secondary: Option<U>,
+        /// Severity set in the secondary options.
+        severity: Option<Severity>,
+    },
+}
+impl<T, U> RuleConf<T, U> {
+    /// Returns the severity of the rule.
+    /// `default_severity` is used when the rule doesn't specify its severity.
+    pub(crate) fn severity(&self, default_severity: Severity) -> Severity {
+        match self {
+            Self::Off => Severity::Off,
+            Self::On { severity, .. } => severity.unwrap_or(default_severity),
+        }
+    }
+}
+impl<T: Deserializable + 'static, U: Deserializable + 'static> Deserializable for RuleConf<T, U> {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor<T, U>(PhantomData<(T, U)>);
+        impl<T: Deserializable + 'static, U: Deserializable + 'static> DeserializationVisitor
+            for Visitor<T, U>
+        {
+            type Output = RuleConf<T, U>;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::ARRAY;
+            fn visit_array(
+                self,
+                ctx: &mut dyn DeserializationContext,
+                values: &mut dyn ExactSizeIterator<Item = Option<Box<dyn DeserializableValue>>>,
+                range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                let mut values = values.flatten();
+                let Some(primary) = values.next() else {
+                    ctx.report(
+                        DeserializationDiagnostic::new("A primary option is expected.")
+                            .with_range(range),
+                    );
+                    return None;
+                };
+                if primary.visitable_type()? == DeserializableType::Null {
+                    return Some(RuleConf::Off);
+                }
+                let primary = if TypeId::of::<T>() == TypeId::of::<()>() {
+                    None
+                } else {
+                    T::deserialize(ctx, &primary, "")
+                };
+                let Some(secondary) = values.next() else {
+                    return Some(RuleConf::On {
+                        primary,
+                        secondary: None,
+                        severity: None,
+                    });
+                };
+                if secondary.visitable_type()? != DeserializableType::Map {
+                    // The primary option of some rules is an array
+                    return Some(RuleConf::On {
+                        primary,
+                        secondary: None,
+                        severity: None,
+                    });
+                }
+                let severity = CommonSecondaryOptions::deserialize(ctx, &secondary, "")
+                    .and_then(|options| options.severity);
+                let secondary = if TypeId::of::<U>() == TypeId::of::<()>() {
+                    None
+                } else {
+                    U::deserialize(ctx, &secondary, "")
+                };
+                Some(RuleConf::On {
+                    primary,
+                    secondary,
+                    severity,
+                })
+            }
+        }
+        match value.visitable_type()? {
+            DeserializableType::Null => Some(RuleConf::Off),
+            DeserializableType::Array => value.deserialize(ctx, Visitor(PhantomData), name),
+            _ => Some(RuleConf::On {
+                primary: if TypeId::of::<T>() == TypeId::of::<()>() {
+                    None
+                } else {
+                    T::deserialize(ctx, value, name)
+                },
+                secondary: None,
+                severity: None,
+            }),
+        }
+    }
+}
+
+/// The rules of a configuration, in their declaration order.
+#[derive(Debug, Default)]
+pub(crate) struct Rules(pub(crate) Vec<Rule>);
+impl Merge for Rules {
+    /// The rules of `other` take precedence.
+    fn merge_with(&mut self, other: Self) {
+        self.0
+            .retain(|rule| !other.0.iter().any(|other| other.name() == rule.name()));
+        self.0.extend(other.0);
+    }
+}
+impl Deserializable for Rules {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = Rules;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::MAP;
+            fn visit_map(
+                self,
+                ctx: &mut dyn DeserializationContext,
+                members: &mut MapMembers<'_>,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                let mut result = Vec::new();
+                for (key, value) in members.flatten() {
+                    let Some(rule_name) = Text::deserialize(ctx, &key, "") else {
+                        continue;
+                    };
+                    let rule =
+                        match rule_name.text() {
+                            // Stylelint rules with options that we handle
+                            "at-rule-no-unknown" => {
+                                RuleConf::deserialize(ctx, &value, name).map(Rule::AtRuleNoUnknown)
+                            }
+                            "function-no-unknown" => RuleConf::deserialize(ctx, &value, name)
+                                .map(Rule::FunctionNoUnknown),
+                            "media-type-no-deprecated" => RuleConf::deserialize(ctx, &value, name)
+                                .map(Rule::MediaTypeNoDeprecated),
+                            "property-no-unknown" => RuleConf::deserialize(ctx, &value, name)
+                                .map(Rule::PropertyNoUnknown),
+                            "selector-max-class" => {
+                                RuleConf::deserialize(ctx, &value, name).map(Rule::SelectorMaxClass)
+                            }
+                            "selector-pseudo-class-no-unknown" => {
+                                RuleConf::deserialize(ctx, &value, name)
+                                    .map(Rule::SelectorPseudoClassNoUnknown)
+                            }
+                            "selector-pseudo-element-no-unknown" => {
+                                RuleConf::deserialize(ctx, &value, name)
+                                    .map(Rule::SelectorPseudoElementNoUnknown)
+                            }
+                            // Other rules
+                            rule_name => RuleConf::<()>::deserialize(ctx, &value, name)
+                                .map(|conf| Rule::Any(rule_name.into(), conf)),
+                        };
+                    if let Some(rule) = rule {
+                        result.push(rule);
+                    }
+                }
+                Some(Rules(result))
+            }
+        }
+        value.deserialize(ctx, Visitor, name)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Rule {
+    /// Any rule without its options.
+    Any(Box<str>, RuleConf),
+    // Rules with options that we handle
+    AtRuleNoUnknown(RuleConf<(), IgnoreOptions<IgnoreAtRules>>),
+    FunctionNoUnknown(RuleConf<(), IgnoreOptions<IgnoreFunctions>>),
+    MediaTypeNoDeprecated(RuleConf<(), IgnoreOptions<IgnoreMediaTypes>>),
+    PropertyNoUnknown(RuleConf<(), IgnoreOptions<IgnoreProperties>>),
+    SelectorMaxClass(RuleConf<u16>),
+    SelectorPseudoClassNoUnknown(RuleConf<(), IgnoreOptions<IgnorePseudoClasses>>),
+    SelectorPseudoElementNoUnknown(RuleConf<(), IgnoreOptions<IgnorePseudoElements>>),
+}
+impl Rule {
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::Any(name, _) => name,
+            Self::AtRuleNoUnknown(_) => "at-rule-no-unknown",
+            Self::FunctionNoUnknown(_) => "function-no-unknown",
+            Self::MediaTypeNoDeprecated(_) => "media-type-no-deprecated",
+            Self::PropertyNoUnknown(_) => "property-no-unknown",
+            Self::SelectorMaxClass(_) => "selector-max-class",
+            Self::SelectorPseudoClassNoUnknown(_) => "selector-pseudo-class-no-unknown",
+            Self::SelectorPseudoElementNoUnknown(_) => "selector-pseudo-element-no-unknown",
+        }
+    }
+}
+
+/// Name of the secondary option that lists ignored names.
+pub(crate) trait IgnoreOptionName {
+    const NAME: &'static str;
+}
+#[derive(Debug)]
+pub(crate) struct IgnoreAtRules;
+impl IgnoreOptionName for IgnoreAtRules {
+    const NAME: &'static str = "ignoreAtRules";
+}
+#[derive(Debug)]
+pub(crate) struct IgnoreFunctions;
+impl IgnoreOptionName for IgnoreFunctions {
+    const NAME: &'static str = "ignoreFunctions";
+}
+#[derive(Debug)]
+pub(crate) struct IgnoreMediaTypes;
+impl IgnoreOptionName for IgnoreMediaTypes {
+    const NAME: &'static str = "ignoreMediaTypes";
+}
+#[derive(Debug)]
+pub(crate) struct IgnoreProperties;
+impl IgnoreOptionName for IgnoreProperties {
+    const NAME: &'static str = "ignoreProperties";
+}
+#[derive(Debug)]
+pub(crate) struct IgnorePseudoClasses;
+impl IgnoreOptionName for IgnorePseudoClasses {
+    const NAME: &'static str = "ignorePseudoClasses";
+}
+#[derive(Debug)]
+pub(crate) struct IgnorePseudoElements;
+impl IgnoreOptionName for IgnorePseudoElements {
+    const NAME: &'static str = "ignorePseudoElements";
+}
+
+/// Secondary options of the rules that accept a list of ignored names,
+/// such as `{ "ignoreProperties": ["composes"] }` for `property-no-unknown`.
+///
+/// Regular expressions such as `"/^my-/"` are not supported by Biome and are dropped.
+#[derive(Debug)]
+pub(crate) struct IgnoreOptions<N> {
+    pub(crate) names: Vec<String>,
+    /// `true` if some ignored names are regular expressions.
+    pub(crate) has_regex: bool,
+    _name: PhantomData<N>,
+}
+impl<N: IgnoreOptionName + 'static> Deserializable for IgnoreOptions<N> {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor<N>(PhantomData<N>);
+        impl<N: IgnoreOptionName> DeserializationVisitor for Visitor<N> {
+            type Output = IgnoreOptions<N>;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::MAP;
+            fn visit_map(
+                self,
+                ctx: &mut dyn DeserializationContext,
+                members: &mut MapMembers<'_>,
+                _range: TextRange,
+                _name: &str,
+            ) -> Option<Self::Output> {
+                let mut names = Vec::new();
+                let mut has_regex = false;
+                for (key, value) in members.flatten() {
+                    let Some(key) = Text::deserialize(ctx, &key, "") else {
+                        continue;
+                    };
+                    if key.text() != N::NAME {
+                        continue;
+                    }
+                    let Some(values) = ShorthandVec::<String>::deserialize(ctx, &value, N::NAME)
+                    else {
+                        continue;
+                    };
+                    for value in values {
+                        if value.len() > 1 && value.starts_with('/') && value.ends_with('/') {
+                            has_regex = true;
+                        } else {
+                            names.push(value);
+                        }
+                    }
+                }
+                Some(IgnoreOptions {
+                    names,
+                    has_regex,
+                    _name: PhantomData,
+                })
+            }
+        }
+        value.deserialize(ctx, Visitor(PhantomData), name)
+    }
+}
@@ -0,0 +1,588 @@
+//! This modules includes implementations for converting a Stylelint config to a Biome config.
+//!
+//! The conversion relies on:
+//! - the generated [super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule]
+//!   function that relies on Biome's rule metadata to determine
+//!   the equivalent Biome's rule of a Stylelint rule
+//! - hand-written handling of Biome rules that have options in the current module.
+
+use std::collections::BTreeSet;
+
+use super::stylelint::{self, IgnoreOptionName, IgnoreOptions, RuleConf, Severity};
+use super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule;
+use biome_configuration::analyzer::SeverityOrGroup;
+use biome_configuration::{self as biome_config};
+use biome_console::markup;
+use biome_deserialize::Merge;
+use biome_diagnostics::Location;
+use biome_rule_options::{
+    no_deprecated_media_type, no_excessive_selector_classes, no_unknown_at_rules,
+    no_unknown_function, no_unknown_property, no_unknown_pseudo_class, no_unknown_pseudo_element,
+};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MigrationOptions {
+    /// Migrate inspired rules from Stylelint?
+    pub(crate) include_inspired: bool,
+    /// Migrate nursery rules from Stylelint?
+    pub(crate) include_nursery: bool,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum RuleMigrationResult {
+    /// A rule that has been migrated.
+    Migrated,
+    /// A rule that could be migrated if `--include-inspired` was passed
+    Inspired,
+    /// A rule that could be migrated if `--include-nursery` was passed
+    Nursery,
+    /// An unsupported rule
+    Unsupported,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MigrationResults {
+    /// Path to the migrated Stylelint configuration
+    pub(crate) stylelint_path: Option<Box<str>>,
+    /// Is the Biome configuration updated?
+    pub(crate) write: bool,
+    // Contains inspired rules that were not migrated because `include_inspired` is disabled
+    pub(crate) inspired: BTreeSet<Box<str>>,
+    pub(crate) nursery: BTreeSet<Box<str>>,
+    pub(crate) migrated: BTreeSet<Box<str>>,
+    pub(crate) unsupported: BTreeSet<Box<str>>,
+    /// Migrated rules with options that are not supported by Biome, such as regular expressions.
+    pub(crate) dropped_options: BTreeSet<Box<str>>,
+}
+impl MigrationResults {
+    pub(crate) fn add(&mut self, stylelint_name: &str, status: RuleMigrationResult) {
+        let stylelint_name = stylelint_name.into();
+        match status {
+            RuleMigrationResult::Migrated => {
+                self.migrated.insert(stylelint_name);
+            }
+            RuleMigrationResult::Inspired => {
+                self.inspired.insert(stylelint_name);
+            }
+            RuleMigrationResult::Nursery => {
+                self.nursery.insert(stylelint_name);
+            }
+            RuleMigrationResult::Unsupported => {
+                self.unsupported.insert(stylelint_name);
+            }
+        }
+    }
+
+    pub(crate) fn rule_count(&self) -> usize {
+        self.migrated.len() + self.inspired.len() + self.nursery.len() + self.unsupported.len()
+    }
+}
+impl biome_diagnostics::Diagnostic for MigrationResults {
+    fn category(&self) -> Option<&'static biome_diagnostics::Category> {
+        Some(biome_diagnostics::category!("migrate"))
+    }
+
+    fn severity(&self) -> biome_diagnostics::Severity {
+        biome_diagnostics::Severity::Information
+    }
+
+    fn location(&self) -> Location<'_> {
+        let mut builder = Location::builder();
+        if let Some(path) = self.stylelint_path.as_ref() {
+            builder = builder.resource(path);
+        }
+        builder.build()
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        let count = self.rule_count();
+        if count == 0 {
+            return fmt.write_markup(markup! { "No rules to migrate." });
+        }
+        let migrated_count = self.migrated.len();
+        let inspired_count = self.inspired.len();
+        let nursery_count = self.nursery.len();
+        let covered_count = migrated_count + inspired_count + nursery_count;
+        let covered_percent = (covered_count * 100).checked_div(count).unwrap_or(0);
+
+        fmt.write_markup(markup! { <Emphasis>{count}" Stylelint rules found\n"</Emphasis> })?;
+        if self.write {
+            fmt.write_markup(markup! { "- "<Emphasis><Success>{migrated_count}</Success>" have been migrated"</Emphasis>" to Biome's rules\n" })?;
+        } else {
+            fmt.write_markup(markup! { "- "<Emphasis><Success>{migrated_count}</Success>" can be migrated"</Emphasis>" to Biome's rules (run with --write to migrate)\n" })?;
+            if inspired_count > 0 {
+                fmt.write_markup(markup! { "  - "<Emphasis><Success>"+"{inspired_count}</Success></Emphasis>" with --include-inspired\n" })?;
+            }
+            if nursery_count > 0 {
+                fmt.write_markup(markup! { "  - "<Emphasis><Success>"+"{nursery_count}</Success></Emphasis>" with --include-nursery (experimental rules)\n" })?;
+            }
+        }
+        fmt.write_markup(markup! {
+            "- "<Emphasis><Success>{covered_percent}"% ("{covered_count}")"</Success>" of your Stylelint rules are covered by Biome\n"</Emphasis>
+        })
+    }
+
+    fn advices(&self, visitor: &mut dyn biome_diagnostics::Visit) -> std::io::Result<()> {
+        if !self.migrated.is_empty() && self.migrated.len() != self.rule_count() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &if self.write {
+                    markup! { "Migrated rules:" }
+                } else {
+                    markup! { "Rules that can be migrated:" }
+                },
+            )?;
+            record_rule_list(visitor, &self.migrated)?;
+        }
+        if !self.inspired.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Rules that can be migrated to an inspired rule using "<Emphasis>"--include-inspired"</Emphasis>":" },
+            )?;
+            record_rule_list(visitor, &self.inspired)?;
+        }
+        if !self.nursery.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Rules that can be migrated to a nursery rule using "<Emphasis>"--include-nursery"</Emphasis>":" },
+            )?;
+            record_rule_list(visitor, &self.nursery)?;
+        }
+        if !self.unsupported.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Info,
+                &markup! { "Rules that cannot be migrated because Biome has no equivalent rule:" },
+            )?;
+            record_rule_list(visitor, &self.unsupported)?;
+        }
+        if !self.dropped_options.is_empty() {
+            visitor.record_log(
+                biome_diagnostics::LogCategory::Warn,
+                &markup! { "The regular expressions in the options of these rules are not supported and were not migrated:" },
+            )?;
+            record_rule_list(visitor, &self.dropped_options)?;
+        }
+        Ok(())
+    }
+}
+
+fn record_rule_list(
+    visitor: &mut dyn biome_diagnostics::Visit,
+    rules: &BTreeSet<Box<str>>,
+) -> std::io::Result<()> {
+    let list: Vec<_> = rules
+        .iter()
+        .map(|item| item as &dyn biome_console::fmt::Display)
+        .collect();
+    visitor.record_list(list.as_slice())
+}
+
+pub(crate) fn merge_biome_config_with_stylelint(
+    mut biome_config: biome_config::Configuration,
+    stylelint_config: stylelint::StylelintConfigData,
+    options: &MigrationOptions,
+) -> (biome_config::Configuration, MigrationResults) {
+    let (stylelint_biome_config, results) = stylelint_config.into_biome_config(options);
+    biome_config.merge_with(stylelint_biome_config);
+    (biome_config, results)
+}
+
+impl stylelint::StylelintConfigData {
+    pub(crate) fn into_biome_config(
+        self,
+        options: &MigrationOptions,
+    ) -> (biome_config::Configuration, MigrationResults) {
+        let mut results = MigrationResults::default();
+        let mut biome_config = biome_config::Configuration::default();
+        let default_severity = self.default_severity.unwrap_or(Severity::Error);
+        let rules = self
+            .rules
+            .into_biome_rules(default_severity, options, &mut results);
+        if rules != biome_config::Rules::default() {
+            biome_config.linter = Some(biome_config::LinterConfiguration {
+                rules: Some(rules),
+                ..Default::default()
+            });
+        }
+        let mut overrides = biome_config::Overrides::default();
+        let ignored_files = to_biome_globs(&self.ignore_files);
+        if !ignored_files.is_empty() {
+            overrides.0.push(css_linter_ignore_override(ignored_files));
+        }
+        for override_elt in self.overrides {
+            let mut override_pattern = biome_config::OverridePattern::default();
+            let includes = to_biome_globs(&override_elt.files);
+            override_pattern.includes = (!includes.is_empty())
+                .then_some(biome_config::OverrideGlobs::Globs(includes.into()));
+            if !override_elt.rules.0.is_empty() {
+                override_pattern.linter = Some(biome_config::OverrideLinterConfiguration {
+                    rules: Some(override_elt.rules.into_biome_rules(
+                        default_severity,
+                        options,
+                        &mut results,
+                    )),
+                    ..Default::default()
+                });
+            }
+            overrides.0.push(override_pattern);
+        }
+        if !overrides.0.is_empty() {
+            biome_config.overrides = Some(overrides);
+        }
+        (biome_config, results)
+    }
+}
+
+impl stylelint::Rules {
+    pub(crate) fn into_biome_rules(
+        self,
+        default_severity: Severity,
+        options: &MigrationOptions,
+        results: &mut MigrationResults,
+    ) -> biome_config::Rules {
+        let mut rules = biome_config::Rules::default();
+        for stylelint_rule in self.0 {
+            migrate_stylelint_rule(
+                &mut rules,
+                stylelint_rule,
+                default_severity,
+                options,
+                results,
+            );
+        }
+        rules
+    }
+}
+
+/// Returns an override that disables the CSS linter for `ignored_files`.
+///
+/// Stylelint only lints stylesheets:
+/// ignored files are excluded from the CSS linter rather than from the whole linter.
+pub(crate) fn css_linter_ignore_override(
+    ignored_files: Vec<biome_glob::NormalizedGlob>,
+) -> biome_config::OverridePattern {
+    biome_config::OverridePattern {
+        includes: Some(biome_config::OverrideGlobs::Globs(ignored_files.into())),
+        css: Some(biome_config::CssConfiguration {
+            linter: Some(biome_config::css::CssLinterConfiguration {
+                enabled: Some(false.into()),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Returns the valid globs of `globs`.
+fn to_biome_globs(globs: &[Box<str>]) -> Vec<biome_glob::NormalizedGlob> {
+    globs.iter().filter_map(|glob| glob.parse().ok()).collect()
+}
+
+/// Returns the names ignored by `secondary`.
+/// Records `name` in `results` if some ignored names cannot be migrated.
+fn ignored_names<N: IgnoreOptionName>(
+    name: &str,
+    secondary: Option<IgnoreOptions<N>>,
+    results: &mut MigrationResults,
+) -> Vec<String> {
+    let Some(secondary) = secondary else {
+        return Vec::new();
+    };
+    if secondary.has_regex {
+        results.dropped_options.insert(name.into());
+    }
+    secondary.names
+}
+
+/// Look for an equivalent Biome rule for Stylelint `rule`,
+/// and then mutate `rules` if an equivalent rule is found.
+/// Also, takes care of Biome's rules with options.
+fn migrate_stylelint_rule(
+    rules: &mut biome_config::Rules,
+    rule: stylelint::Rule,
+    default_severity: Severity,
+    opts: &MigrationOptions,
+    results: &mut MigrationResults,
+) {
+    let name = rule.name().to_string();
+    match rule {
+        stylelint::Rule::Any(name, conf) => {
+            let _ = migrate_stylelint_any_rule(
+                rules,
+                &name,
+                conf.severity(default_severity),
+                opts,
+                results,
+            );
+        }
+        stylelint::Rule::AtRuleNoUnknown(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let ignore = ignored_names(&name, secondary, results);
+                let group = rules.suspicious.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_at_rules = Some(biome_config::RuleConfiguration::WithOptions(
+                        biome_config::RuleWithOptions {
+                            level: severity.into(),
+                            options: no_unknown_at_rules::NoUnknownAtRulesOptions { ignore },
+                        },
+                    ));
+                }
+            }
+        }
+        stylelint::Rule::FunctionNoUnknown(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let ignore = ignored_names(&name, secondary, results);
+                let group = rules.correctness.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_function = Some(biome_config::RuleConfiguration::WithOptions(
+                        biome_config::RuleWithOptions {
+                            level: severity.into(),
+                            options: no_unknown_function::NoUnknownFunctionOptions { ignore },
+                        },
+                    ));
+                }
+            }
+        }
+        stylelint::Rule::MediaTypeNoDeprecated(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let allow = ignored_names(&name, secondary, results);
+                let group = rules.suspicious.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_deprecated_media_type =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options: no_deprecated_media_type::NoDeprecatedMediaTypeOptions {
+                                    allow: (!allow.is_empty()).then(|| allow.into()),
+                                },
+                            },
+                        ));
+                }
+            }
+        }
+        stylelint::Rule::PropertyNoUnknown(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let ignore = ignored_names(&name, secondary, results);
+                let group = rules.correctness.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_property = Some(biome_config::RuleConfiguration::WithOptions(
+                        biome_config::RuleWithOptions {
+                            level: severity.into(),
+                            options: no_unknown_property::NoUnknownPropertyOptions { ignore },
+                        },
+                    ));
+                }
+            }
+        }
+        stylelint::Rule::SelectorMaxClass(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On {
+                    primary: Some(max_classes),
+                    ..
+                } = conf
+            {
+                let group = rules.nursery.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_excessive_selector_classes =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options:
+                                    no_excessive_selector_classes::NoExcessiveSelectorClassesOptions {
+                                        max_classes: Some(max_classes),
+                                    },
+                            },
+                        ));
+                }
+            }
+        }
+        stylelint::Rule::SelectorPseudoClassNoUnknown(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let ignore = ignored_names(&name, secondary, results);
+                let group = rules.correctness.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_pseudo_class =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options: no_unknown_pseudo_class::NoUnknownPseudoClassOptions {
+                                    ignore,
+                                },
+                            },
+                        ));
+                }
+            }
+        }
+        stylelint::Rule::SelectorPseudoElementNoUnknown(conf) => {
+            let severity = conf.severity(default_severity);
+            if migrate_stylelint_any_rule(rules, &name, severity, opts, results)
+                && let RuleConf::On { secondary, .. } = conf
+            {
+                let ignore = ignored_names(&name, secondary, results);
+                let group = rules.correctness.get_or_insert_with(Default::default);
+                if let SeverityOrGroup::Group(group) = group {
+                    group.no_unknown_pseudo_element =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options: no_unknown_pseudo_element::NoUnknownPseudoElementOptions {
+                                    ignore,
+                                },
+                            },
+                        ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_deserialize::json::deserialize_from_json_str;
+    use biome_json_parser::JsonParserOptions;
+
+    fn deserialize_config(input: &str) -> stylelint::StylelintConfigData {
+        let (config, diagnostics) = deserialize_from_json_str::<stylelint::StylelintConfigData>(
+            input,
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume();
+        assert!(
+            diagnostics.is_empty(),
+            "unexpected diagnostics: {diagnostics:#?}"
+        );
+        config.expect("Stylelint config should deserialize")
+    }
+
+    #[test]
+    fn rules_and_severities() {
+        let config = deserialize_config(
+            r#"{
+                "defaultSeverity": "warning",
+                "rules": {
+                    "block-no-empty": true,
+                    "color-no-hex": [true, { "severity": "error" }],
+                    "unit-no-unknown": null,
+                    "selector-class-pattern": "^[a-z]+$"
+                }
+            }"#,
+        );
+        let (biome_config, results) = config.into_biome_config(&MigrationOptions::default());
+
+        assert_eq!(results.migrated.len(), 3);
+        assert!(results.unsupported.contains("selector-class-pattern"));
+        let rules = biome_config.linter.unwrap().rules.unwrap();
+        assert!(rules.preset.is_none());
+        assert_eq!(
+            rules
+                .suspicious
+                .unwrap()
+                .unwrap_group()
+                .no_empty_block
+                .unwrap()
+                .level(),
+            biome_config::RulePlainConfiguration::Warn
+        );
+        assert_eq!(
+            rules
+                .style
+                .unwrap()
+                .unwrap_group()
+                .no_hex_colors
+                .unwrap()
+                .level(),
+            biome_config::RulePlainConfiguration::Error
+        );
+        assert_eq!(
+            rules
+                .correctness
+                .unwrap()
+                .unwrap_group()
+                .no_unknown_unit
+                .unwrap()
+                .level(),
+            biome_config::RulePlainConfiguration::Off
+        );
+    }
+
+    #[test]
+    fn rule_options() {
+        let config = deserialize_config(
+            r#"{
+                "rules": {
+                    "property-no-unknown": [true, { "ignoreProperties": ["composes", "/^my-/"] }],
+                    "selector-max-class": 2
+                }
+            }"#,
+        );
+        let (biome_config, results) = config.into_biome_config(&MigrationOptions {
+            include_inspired: false,
+            include_nursery: true,
+        });
+
+        assert!(results.dropped_options.contains("property-no-unknown"));
+        let rules = biome_config.linter.unwrap().rules.unwrap();
+        let Some(biome_config::RuleConfiguration::WithOptions(property_no_unknown)) = rules
+            .correctness
+            .unwrap()
+            .unwrap_group()
+            .no_unknown_property
+        else {
+            panic!("noUnknownProperty should have options");
+        };
+        assert_eq!(property_no_unknown.options.ignore, ["composes"]);
+        let Some(biome_config::RuleConfiguration::WithOptions(selector_max_class)) = rules
+            .nursery
+            .unwrap()
+            .unwrap_group()
+            .no_excessive_selector_classes
+        else {
+            panic!("noExcessiveSelectorClasses should have options");
+        };
+        assert_eq!(selector_max_class.options.max_classes, Some(2));
+    }
+
+    #[test]
+    fn ignore_files_and_overrides() {
+        let config = deserialize_config(
+            r#"{
+                "ignoreFiles": ["dist/**"],
+                "overrides": [{
+                    "files": ["legacy/**/*.css"],
+                    "rules": { "declaration-no-important": null }
+                }]
+            }"#,
+        );
+        let (biome_config, _) = config.into_biome_config(&MigrationOptions::default());
+
+        assert!(biome_config.linter.is_none());
+        let overrides = biome_config.overrides.unwrap();
+        assert_eq!(overrides.0.len(), 2);
+        assert_eq!(
+            overrides.0[0].includes,
+            Some(biome_config::OverrideGlobs::Globs(
+                ["dist/**".parse().unwrap()].into()
+            ))
+        );
+        assert!(overrides.0[0].css.is_some());
+        assert!(overrides.0[1].linter.is_some());
+    }
+}
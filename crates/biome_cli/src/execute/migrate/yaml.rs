@@ -0,0 +1,296 @@
+//! Conversion of YAML configuration files to JSON.
+//!
+//! Some tools accept configuration files written in YAML. They're converted to
+//! JSON, so they can be deserialized like the JSON configuration files.
+
+use biome_rowan::AstSeparatedList;
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockContent, AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlFlowMapEntry,
+    AnyYamlFlowNode, AnyYamlFlowSequenceEntry, AnyYamlJsonContent, AnyYamlMappingImplicitKey,
+    YamlBlockMapping, YamlBlockSequence, YamlFlowMapping, YamlFlowSequence,
+};
+use serde_json::{Map, Number, Value};
+
+/// Converts the first document of the YAML `source` to a JSON value.
+///
+/// Returns `None` if the source contains syntax errors or uses a feature that
+/// can't be represented in JSON, such as aliases and block scalars.
+pub(crate) fn yaml_to_json(source: &str) -> Option<Value> {
+    let parsed = parse_yaml(source);
+    if parsed.has_errors() {
+        return None;
+    }
+
+    let document = parsed
+        .tree()
+        .documents()
+        .into_iter()
+        .find_map(|document| document.as_yaml_document().cloned())?;
+    match document.node() {
+        Some(node) => block_node_to_json(&node),
+        None => Some(Value::Null),
+    }
+}
+
+fn block_node_to_json(node: &AnyYamlBlockNode) -> Option<Value> {
+    match node {
+        AnyYamlBlockNode::YamlBlockInBlockNode(node) => match node.content().ok()? {
+            AnyYamlBlockInBlockContent::YamlBlockMapping(mapping) => {
+                block_mapping_to_json(&mapping)
+            }
+            AnyYamlBlockInBlockContent::YamlBlockSequence(sequence) => {
+                block_sequence_to_json(&sequence)
+            }
+            AnyYamlBlockInBlockContent::YamlFoldedScalar(_)
+            | AnyYamlBlockInBlockContent::YamlLiteralScalar(_) => None,
+        },
+        AnyYamlBlockNode::YamlFlowInBlockNode(node) => flow_node_to_json(&node.flow().ok()?),
+        AnyYamlBlockNode::YamlBogusBlockNode(_) => None,
+    }
+}
+
+fn block_mapping_to_json(mapping: &YamlBlockMapping) -> Option<Value> {
+    let mut object = Map::new();
+    for entry in mapping.entries() {
+        let (key, value) = match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+                (implicit_key_to_string(&entry.key()?)?, entry.value())
+            }
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                let Value::String(key) = block_node_to_json(&entry.key()?)? else {
+                    return None;
+                };
+                (key, entry.value())
+            }
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => return None,
+        };
+        let value = match value {
+            Some(value) => block_node_to_json(&value)?,
+            None => Value::Null,
+        };
+        object.insert(key, value);
+    }
+
+    Some(Value::Object(object))
+}
+
+fn block_sequence_to_json(sequence: &YamlBlockSequence) -> Option<Value> {
+    sequence
+        .entries()
+        .into_iter()
+        .map(
+            |entry| match entry.as_yaml_block_sequence_entry()?.value() {
+                Some(value) => block_node_to_json(&value),
+                None => Some(Value::Null),
+            },
+        )
+        .collect::<Option<_>>()
+        .map(Value::Array)
+}
+
+fn flow_node_to_json(node: &AnyYamlFlowNode) -> Option<Value> {
+    match node {
+        AnyYamlFlowNode::YamlFlowYamlNode(node) => match node.content() {
+            Some(scalar) => Some(plain_scalar_to_json(
+                scalar.value_token().ok()?.text_trimmed(),
+            )),
+            None => Some(Value::Null),
+        },
+        AnyYamlFlowNode::YamlFlowJsonNode(node) => json_content_to_json(&node.content().ok()?),
+        AnyYamlFlowNode::YamlAliasNode(_) | AnyYamlFlowNode::YamlBogusFlowNode(_) => None,
+    }
+}
+
+fn json_content_to_json(content: &AnyYamlJsonContent) -> Option<Value> {
+    match content {
+        AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar) => Some(Value::String(
+            double_quoted_to_string(scalar.value_token().ok()?.text_trimmed()),
+        )),
+        AnyYamlJsonContent::YamlSingleQuotedScalar(scalar) => Some(Value::String(
+            single_quoted_to_string(scalar.value_token().ok()?.text_trimmed()),
+        )),
+        AnyYamlJsonContent::YamlFlowMapping(mapping) => flow_mapping_to_json(mapping),
+        AnyYamlJsonContent::YamlFlowSequence(sequence) => flow_sequence_to_json(sequence),
+    }
+}
+
+fn flow_mapping_to_json(mapping: &YamlFlowMapping) -> Option<Value> {
+    let mut object = Map::new();
+    for entry in mapping.entries() {
+        let (key, value) = flow_map_entry_to_json(&entry.ok()?)?;
+        object.insert(key, value);
+    }
+
+    Some(Value::Object(object))
+}
+
+fn flow_map_entry_to_json(entry: &AnyYamlFlowMapEntry) -> Option<(String, Value)> {
+    let (key, value) = match entry {
+        AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => (entry.key(), entry.value()),
+        AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => (entry.key(), entry.value()),
+    };
+    let value = match value {
+        Some(value) => flow_node_to_json(&value)?,
+        None => Value::Null,
+    };
+
+    Some((implicit_key_to_string(&key?)?, value))
+}
+
+fn flow_sequence_to_json(sequence: &YamlFlowSequence) -> Option<Value> {
+    sequence
+        .entries()
+        .iter()
+        .map(|entry| match entry.ok()? {
+            AnyYamlFlowSequenceEntry::AnyYamlFlowNode(node) => flow_node_to_json(&node),
+            AnyYamlFlowSequenceEntry::AnyYamlFlowMapEntry(entry) => {
+                let (key, value) = flow_map_entry_to_json(&entry)?;
+                Some(Value::Object(Map::from_iter([(key, value)])))
+            }
+        })
+        .collect::<Option<_>>()
+        .map(Value::Array)
+}
+
+/// Returns the text of a mapping key. Keys that aren't strings, such as
+/// `true` or `1`, are converted to their text, like JSON does.
+fn implicit_key_to_string(key: &AnyYamlMappingImplicitKey) -> Option<String> {
+    match key {
+        AnyYamlMappingImplicitKey::YamlFlowYamlNode(node) => Some(fold_plain_scalar(
+            node.content()?.value_token().ok()?.text_trimmed(),
+        )),
+        AnyYamlMappingImplicitKey::YamlFlowJsonNode(node) => {
+            match json_content_to_json(&node.content().ok()?)? {
+                Value::String(key) => Some(key),
+                _ => None,
+            }
+        }
+        AnyYamlMappingImplicitKey::YamlAliasNode(_) => None,
+    }
+}
+
+/// Converts a plain scalar to `null`, a boolean, a number or a string, as
+/// the YAML core schema does.
+fn plain_scalar_to_json(text: &str) -> Value {
+    match text {
+        "~" | "null" | "Null" | "NULL" => return Value::Null,
+        "true" | "True" | "TRUE" => return Value::Bool(true),
+        "false" | "False" | "FALSE" => return Value::Bool(false),
+        _ => {}
+    }
+
+    if let Ok(integer) = text.parse::<i64>() {
+        return Value::Number(integer.into());
+    }
+    if text
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E'))
+        && let Some(number) = text.parse::<f64>().ok().and_then(Number::from_f64)
+    {
+        return Value::Number(number);
+    }
+
+    Value::String(fold_plain_scalar(text))
+}
+
+/// Folds the lines of a plain scalar as YAML does: the whitespace around each
+/// line is removed, a line break between two lines becomes a space, and each
+/// empty line becomes a line feed. The spaces inside a line are kept.
+fn fold_plain_scalar(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    let mut empty_lines = 0;
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim_matches([' ', '\t']);
+        if index == 0 {
+            folded.push_str(line);
+        } else if line.is_empty() {
+            empty_lines += 1;
+        } else {
+            if empty_lines == 0 {
+                folded.push(' ');
+            }
+            for _ in 0..empty_lines {
+                folded.push('\n');
+            }
+            empty_lines = 0;
+            folded.push_str(line);
+        }
+    }
+
+    folded
+}
+
+fn double_quoted_to_string(text: &str) -> String {
+    // The escape sequences of JSON strings are a subset of the YAML ones.
+    serde_json::from_str(text).unwrap_or_else(|_| text[1..text.len() - 1].to_string())
+}
+
+fn single_quoted_to_string(text: &str) -> String {
+    text[1..text.len() - 1].replace("''", "'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_plain_scalar, yaml_to_json};
+    use serde_json::json;
+
+    #[test]
+    fn converts_block_collections() {
+        let source = r#"
+extends:
+  - stylelint-config-standard
+rules:
+  block-no-empty: true
+  color-no-hex:
+    - true
+    - severity: warning
+  selector-class-pattern: "^[a-z]+$"
+  max-nesting-depth: 3
+  unit-allowed-list: null
+"#;
+
+        assert_eq!(
+            yaml_to_json(source),
+            Some(json!({
+                "extends": ["stylelint-config-standard"],
+                "rules": {
+                    "block-no-empty": true,
+                    "color-no-hex": [true, { "severity": "warning" }],
+                    "selector-class-pattern": "^[a-z]+$",
+                    "max-nesting-depth": 3,
+                    "unit-allowed-list": null
+                }
+            }))
+        );
+    }
+
+    #[test]
+    fn converts_flow_collections() {
+        let source = "rules: { color-no-hex: [true, { severity: 'it''s a warning' }] }";
+
+        assert_eq!(
+            yaml_to_json(source),
+            Some(json!({
+                "rules": { "color-no-hex": [true, { "severity": "it's a warning" }] }
+            }))
+        );
+    }
+
+    #[test]
+    fn folds_only_the_line_breaks_of_plain_scalars() {
+        assert_eq!(fold_plain_scalar("a  b"), "a  b");
+        assert_eq!(fold_plain_scalar("a  b\n    c\td"), "a  b c\td");
+        assert_eq!(fold_plain_scalar("a \r\n  b"), "a b");
+        assert_eq!(fold_plain_scalar("a\n\n  b\n\n\n  c"), "a\nb\n\nc");
+        assert_eq!(
+            yaml_to_json("rules:\n  message: keep  these   spaces\n    and fold this line\n"),
+            Some(json!({ "rules": { "message": "keep  these   spaces and fold this line" } }))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_yaml() {
+        assert_eq!(yaml_to_json("rules: [true"), None);
+    }
+}
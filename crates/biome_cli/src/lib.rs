@@ -25,11 +25,13 @@ pub(crate) mod runner;
 mod service;
 
 use crate::cli_options::ColorsArg;
+use crate::commands::PluginSubCommand;
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
 use crate::commands::migrate::MigrateCommandPayload;
+use crate::commands::plugin::PluginTestCommandPayload;
 pub use crate::commands::{BiomeCommand, biome_command};
 pub use crate::logging::{LoggingLevel, setup_cli_subscriber};
 use crate::runner::impls::commands::custom_execution::CustomExecutionCmdImpl;
@@ -95,6 +97,10 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                baseline,
+                write_baseline,
+                cache,
                 format_with_errors,
                 json_parser,
                 css_parser,
@@ -122,6 +128,10 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    baseline,
+                    write_baseline,
+                    cache,
                     format_with_errors,
                     json_parser,
                     css_parser,
@@ -149,6 +159,10 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                baseline,
+                write_baseline,
+                cache,
                 css_linter,
                 javascript_linter,
                 json_linter,
@@ -179,6 +193,10 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    baseline,
+                    write_baseline,
+                    cache,
                     css_linter,
                     javascript_linter,
                     json_linter,
@@ -200,6 +218,10 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines,
+                baseline,
+                write_baseline,
+                cache,
                 format_with_errors,
                 css_parser,
                 json_parser,
@@ -220,6 +242,10 @@ impl<'app> CliSession<'app> {
                     paths,
                     changed,
                     since,
+                    changed_lines,
+                    baseline,
+                    write_baseline,
+                    cache,
                     format_with_errors,
                     css_parser,
                     json_parser,
@@ -244,6 +270,8 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                cache,
                 css_parser,
                 json_parser,
                 log_options,
@@ -268,6 +296,8 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
                     css_parser,
                     json_parser,
                     watch,
@@ -305,6 +335,8 @@ impl<'app> CliSession<'app> {
                 paths,
                 pattern,
                 language,
+                rewrite,
+                write,
                 stdin_file_path,
                 vcs_configuration,
             } => run_command(
@@ -316,10 +348,31 @@ impl<'app> CliSession<'app> {
                     paths,
                     pattern,
                     language,
+                    rewrite,
+                    write,
                     stdin_file_path,
                     vcs_configuration,
                 }),
             ),
+            BiomeCommand::Plugin {
+                cli_options,
+                log_options,
+                sub_command:
+                    PluginSubCommand::Test {
+                        update,
+                        plugin,
+                        fixtures,
+                    },
+            } => run_command(
+                self,
+                &log_options,
+                &cli_options,
+                TraversalCommandImpl(PluginTestCommandPayload {
+                    plugin,
+                    fixtures,
+                    update,
+                }),
+            ),
             BiomeCommand::RunServer {
                 stop_on_disconnect,
                 watcher_options,
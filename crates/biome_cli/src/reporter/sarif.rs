@@ -11,6 +11,7 @@ use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_markdown_syntax::MarkdownLanguage;
 use biome_rowan::Language;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use serde::Serialize;
 use std::collections::{BTreeMap, HashSet};
@@ -55,6 +56,7 @@ impl<'a> SarifReporterVisitor<'a> {
         biome_json_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
         biome_markdown_analyze::visit_registry(&mut visitor);
+        biome_yaml_analyze::visit_registry(&mut visitor);
 
         visitor
     }
@@ -135,6 +137,16 @@ impl RegistryVisitor<MarkdownLanguage> for SarifReporterVisitor<'_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for SarifReporterVisitor<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, YamlLanguage>();
+    }
+}
+
 impl ReporterVisitor for SarifReporterVisitor<'_> {
     fn report_summary(
         &mut self,
@@ -140,7 +140,7 @@ impl ReporterVisitor for ConsoleReporterVisitor {
         _working_directory: Option<&Utf8Path>,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            if execution.is_search() && !execution.is_search_rewrite() {
                 writer.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -179,7 +179,7 @@ impl fmt::Display for SummaryDetail<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let Self(execution, files) = self;
 
-        if execution.is_search() {
+        if (execution.is_search() && !execution.is_search_rewrite()) || execution.is_plugin_test() {
             return Ok(());
         }
         // For now, we'll assume all executions except search can have fixes
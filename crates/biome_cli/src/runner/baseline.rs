@@ -0,0 +1,587 @@
+//! Baseline of the diagnostics of a project, written with `--write-baseline`.
+//!
+//! The baseline records, for each file, the diagnostics emitted by each rule. Diagnostics are
+//! identified by a fingerprint computed from the rule, the path of the file, the message and the
+//! code they highlight, so they still match after the code around them moves.
+//!
+//! When a baseline exists, the diagnostics it records are removed from the report. The files
+//! that emit fewer diagnostics than the baseline records are reported, so the baseline can be
+//! written again and shrink as the code is fixed.
+//!
+//! When `--only` or `--skip` restrict the rules that run, only the entries of the rules that run
+//! are written or reported as fixed. The entries of the other rules are kept as they are.
+
+use crate::CliDiagnostic;
+use crate::runner::diagnostics::{FixedBaselineAdvice, FixedBaselineDiagnostic};
+use crate::runner::execution::AnalyzerSelectors;
+use crate::runner::process_file::Message;
+use biome_analyze::{Queryable, RegistryVisitor, Rule, RuleGroup};
+use biome_configuration::analyzer::AnalyzerSelector;
+use biome_diagnostics::{DiagnosticExt, Error, PrintDescription, Severity};
+use biome_fs::{BiomePath, FileSystem, FileSystemExt};
+use biome_rowan::Language;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use tracing::debug;
+
+/// Name of the baseline file in the project directory, when `--baseline` isn't used.
+pub(crate) const BASELINE_FILE_NAME: &str = "biome-baseline.json";
+
+/// The baseline options of the commands that report lint diagnostics.
+#[derive(Debug, Default)]
+pub(crate) struct BaselineOptions {
+    /// The path given with `--baseline`
+    pub(crate) path: Option<String>,
+    /// Whether `--write-baseline` is used
+    pub(crate) write: bool,
+}
+
+/// The number of occurrences of each fingerprint, by rule.
+type FileEntry = BTreeMap<String, BTreeMap<String, u32>>;
+
+/// The content of a baseline file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineFile {
+    /// The entries of the files, by path relative to the project directory
+    files: BTreeMap<String, FileEntry>,
+}
+
+/// Baseline shared by the threads of a traversal.
+pub(crate) struct Baseline {
+    /// Where the baseline is stored
+    path: Utf8PathBuf,
+    /// The directory the paths of the baseline are relative to
+    root: Utf8PathBuf,
+    /// Whether the diagnostics of this run are recorded in the baseline
+    write: bool,
+    /// The rules that run during this run
+    scope: RuleScope,
+    /// Entries loaded from the baseline file
+    previous: BTreeMap<String, FileEntry>,
+    /// Entries of the files processed during this run
+    current: papaya::HashMap<String, FileEntry>,
+}
+
+impl Baseline {
+    /// Loads the baseline stored at `path`.
+    ///
+    /// Returns `None` when the file doesn't exist, unless the baseline is written during this run.
+    /// `required` emits an error instead, and should be used when the path was given explicitly.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        path: Utf8PathBuf,
+        root: Utf8PathBuf,
+        write: bool,
+        required: bool,
+        scope: RuleScope,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        let previous = match fs.read_file_from_path(&path) {
+            Ok(content) => {
+                serde_json::from_str::<BaselineFile>(&content)
+                    .map_err(|error| {
+                        CliDiagnostic::io_error(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("The baseline {path} couldn't be parsed: {error}"),
+                        ))
+                    })?
+                    .files
+            }
+            Err(_) if write => BTreeMap::new(),
+            Err(_) if required => {
+                return Err(CliDiagnostic::io_error(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "The baseline {path} doesn't exist. Use --write-baseline to create it."
+                    ),
+                )));
+            }
+            Err(_) => return Ok(None),
+        };
+        debug!("Loaded {} files from the baseline {path}", previous.len());
+        Ok(Some(Self {
+            path,
+            root,
+            write,
+            scope,
+            previous,
+            current: papaya::HashMap::default(),
+        }))
+    }
+
+    /// The path of the baseline file.
+    pub(crate) fn path(&self) -> &Utf8Path {
+        &self.path
+    }
+
+    /// Records the diagnostics of a message sent while a file is processed, and removes the ones
+    /// that are recorded in the baseline. When the baseline is written, all of them are removed.
+    pub(crate) fn filter_message(&self, message: Message) -> Message {
+        let Message::Diagnostics {
+            file_path,
+            content,
+            diagnostics,
+            skipped_diagnostics,
+            mut errors,
+            mut warnings,
+            mut infos,
+        } = message
+        else {
+            return message;
+        };
+        let key = self.relative_path(&file_path);
+        let baselined = self.previous.get(&key);
+        let current = self.current.pin();
+        let mut entry = current.get(&key).cloned().unwrap_or_default();
+
+        let mut reported = Vec::with_capacity(diagnostics.len());
+        for diagnostic in diagnostics {
+            let Some(rule) = rule_name(&diagnostic) else {
+                reported.push(diagnostic);
+                continue;
+            };
+            let fingerprint = fingerprint(&diagnostic, rule, &key, &content);
+            let count = entry
+                .entry(rule.to_string())
+                .or_default()
+                .entry(fingerprint.clone())
+                .or_default();
+            *count += 1;
+
+            let is_baselined = self.write
+                || baselined
+                    .and_then(|entry| entry.get(rule))
+                    .and_then(|fingerprints| fingerprints.get(&fingerprint))
+                    .is_some_and(|allowed| *count <= *allowed);
+            if is_baselined {
+                match diagnostic.severity() {
+                    Severity::Error | Severity::Fatal => errors = errors.saturating_sub(1),
+                    Severity::Warning => warnings = warnings.saturating_sub(1),
+                    Severity::Information => infos = infos.saturating_sub(1),
+                    Severity::Hint => {}
+                }
+            } else {
+                reported.push(diagnostic);
+            }
+        }
+        current.insert(key, entry);
+
+        Message::Diagnostics {
+            file_path,
+            content,
+            diagnostics: reported,
+            skipped_diagnostics,
+            errors,
+            warnings,
+            infos,
+        }
+    }
+
+    /// Marks `path` as processed. Returns a diagnostic when the file emitted fewer diagnostics
+    /// than the baseline records for it.
+    pub(crate) fn finish(&self, path: &BiomePath) -> Option<Message> {
+        let key = self.relative_path(path.as_str());
+        let current = self.current.pin();
+        if self.write {
+            current.get_or_insert_with(key, FileEntry::default);
+            return None;
+        }
+        // The entry is removed, so the file can be processed again in watch mode
+        let entry = current.remove(&key).cloned().unwrap_or_default();
+
+        let fixed: Vec<_> = self
+            .previous
+            .get(&key)?
+            .iter()
+            .filter(|(rule, _)| self.scope.contains(rule))
+            .filter_map(|(rule, fingerprints)| {
+                let emitted = entry.get(rule);
+                let count: u32 = fingerprints
+                    .iter()
+                    .map(|(fingerprint, allowed)| {
+                        let emitted = emitted
+                            .and_then(|emitted| emitted.get(fingerprint))
+                            .copied()
+                            .unwrap_or_default();
+                        allowed.saturating_sub(emitted)
+                    })
+                    .sum();
+                (count > 0).then(|| (rule.clone(), count))
+            })
+            .collect();
+        if fixed.is_empty() {
+            return None;
+        }
+
+        Some(Message::from(
+            FixedBaselineDiagnostic {
+                advice: FixedBaselineAdvice { fixed },
+            }
+            .with_file_path(path.to_string()),
+        ))
+    }
+
+    /// Writes the baseline to disk, if it's written during this run, and returns the number of
+    /// diagnostics it records. The entries of the files that weren't processed during this run
+    /// are kept, as long as the files still exist.
+    pub(crate) fn save(self, fs: &dyn FileSystem) -> io::Result<Option<u32>> {
+        if !self.write {
+            return Ok(None);
+        }
+        let mut files: BTreeMap<_, _> = self
+            .previous
+            .into_iter()
+            .filter(|(path, _)| fs.path_is_file(&self.root.join(path)))
+            .collect();
+        for (path, entry) in self.current.pin().iter() {
+            // The entries of the rules that didn't run are kept
+            let mut merged = files.remove(path).unwrap_or_default();
+            merged.retain(|rule, _| !self.scope.contains(rule));
+            merged.extend(entry.clone());
+            if !merged.is_empty() {
+                files.insert(path.clone(), merged);
+            }
+        }
+        let count = files
+            .values()
+            .flat_map(|entry| entry.values())
+            .flat_map(|fingerprints| fingerprints.values())
+            .sum();
+
+        let mut content =
+            serde_json::to_string_pretty(&BaselineFile { files }).map_err(io::Error::other)?;
+        content.push('\n');
+        fs.create(&self.path)?.set_content(content.as_bytes())?;
+        Ok(Some(count))
+    }
+
+    /// Returns the path of a file relative to the project directory, with `/` separators so the
+    /// baseline can be shared across platforms.
+    fn relative_path(&self, path: &str) -> String {
+        let path = Utf8Path::new(path);
+        let path = path.strip_prefix(&self.root).unwrap_or(path);
+        path.components()
+            .map(|component| component.as_str())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// The rules that run during a traversal, which `--only` and `--skip` can restrict.
+pub(crate) enum RuleScope {
+    /// All the rules enabled by the configuration run
+    All,
+    /// Only the given rules run
+    Rules {
+        /// The rules that run, as `<group>/<rule>`
+        rules: FxHashSet<String>,
+        /// Whether the analyzer plugins run
+        plugins: bool,
+    },
+}
+
+impl RuleScope {
+    pub(crate) fn new(selectors: &AnalyzerSelectors) -> Self {
+        let AnalyzerSelectors { only, skip } = selectors;
+        if only.is_empty() && skip.is_empty() {
+            return Self::All;
+        }
+
+        let mut visitor = RuleScopeVisitor {
+            only,
+            skip,
+            rules: FxHashSet::default(),
+        };
+        biome_css_analyze::visit_registry(&mut visitor);
+        biome_graphql_analyze::visit_registry(&mut visitor);
+        biome_html_analyze::visit_registry(&mut visitor);
+        biome_js_analyze::visit_registry(&mut visitor);
+        biome_json_analyze::visit_registry(&mut visitor);
+        biome_markdown_analyze::visit_registry(&mut visitor);
+        biome_yaml_analyze::visit_registry(&mut visitor);
+
+        let plugins = (only.is_empty() || only.contains(&AnalyzerSelector::Plugin))
+            && !skip.contains(&AnalyzerSelector::Plugin);
+        Self::Rules {
+            rules: visitor.rules,
+            plugins,
+        }
+    }
+
+    /// Whether `rule`, the category of the diagnostics of a baseline entry, runs.
+    fn contains(&self, rule: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Rules { rules, plugins } => match rule.split_once('/') {
+                Some((_, name)) => rules.contains(name),
+                None => *plugins && rule == "plugin",
+            },
+        }
+    }
+}
+
+/// Collects the rules selected by `--only` and not skipped by `--skip`.
+struct RuleScopeVisitor<'a> {
+    only: &'a [AnalyzerSelector],
+    skip: &'a [AnalyzerSelector],
+    rules: FxHashSet<String>,
+}
+
+impl<L: Language> RegistryVisitor<L> for RuleScopeVisitor<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Query: Queryable<Language = L, Output: Clone>> + 'static,
+    {
+        let is_selected =
+            self.only.is_empty() || self.only.iter().any(|selector| selector.match_rule::<R>());
+        if is_selected && !self.skip.iter().any(|selector| selector.match_rule::<R>()) {
+            self.rules.insert(format!(
+                "{}/{}",
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name
+            ));
+        }
+    }
+}
+
+/// Returns the name of the rule that emitted `diagnostic`, if any.
+fn rule_name(diagnostic: &Error) -> Option<&'static str> {
+    let name = diagnostic.category()?.name();
+    (name.starts_with("lint/") || name.starts_with("assist/") || name == "plugin").then_some(name)
+}
+
+/// Computes a fingerprint of `diagnostic` that doesn't depend on its position in the file.
+///
+/// The fingerprint is the 64-bit FNV-1a hash of the rule, the path of the file, the message and
+/// the highlighted code with its whitespace collapsed. The output of FNV-1a is fixed by its
+/// specification, so the baseline stays valid across versions of Biome and platforms.
+fn fingerprint(diagnostic: &Error, rule: &str, path: &str, content: &str) -> String {
+    let mut hasher = Fnv1a::default();
+    hasher.write_field(rule);
+    hasher.write_field(path);
+    hasher.write_field(&PrintDescription(diagnostic.as_ref()).to_string());
+    let text = diagnostic
+        .location()
+        .span
+        .and_then(|span| content.get(std::ops::Range::<usize>::from(span)));
+    // The whitespace is ignored, so reformatting the code doesn't change the fingerprint
+    let text = text
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ");
+    hasher.write_field(&text);
+    format!("{:016x}", hasher.0)
+}
+
+/// The 64-bit FNV-1a hash function.
+///
+/// See <http://www.isthe.com/chongo/tech/comp/fnv/index.html>.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    /// Hashes `field`, followed by a byte that can't appear in UTF-8 to separate it from the next
+    /// field.
+    fn write_field(&mut self, field: &str) {
+        for byte in field.bytes().chain([0xff]) {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::Diagnostic;
+    use biome_fs::MemoryFileSystem;
+    use biome_rowan::TextRange;
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(
+        category = "lint/suspicious/noDebugger",
+        severity = Error,
+        message = "This is an unexpected use of the debugger statement."
+    )]
+    struct TestDiagnostic {
+        #[location(span)]
+        span: TextRange,
+    }
+
+    fn baseline_path() -> Utf8PathBuf {
+        Utf8PathBuf::from("/project/biome-baseline.json")
+    }
+
+    fn diagnostics_message(content: &str) -> Message {
+        let spans = content.match_indices("debugger").map(|(start, text)| {
+            TextRange::new((start as u32).into(), ((start + text.len()) as u32).into())
+        });
+        let diagnostics: Vec<_> = spans
+            .map(|span| Error::from(TestDiagnostic { span }))
+            .collect();
+        Message::Diagnostics {
+            file_path: "/project/a.js".to_string(),
+            content: content.to_string(),
+            errors: diagnostics.len(),
+            diagnostics,
+            skipped_diagnostics: 0,
+            warnings: 0,
+            infos: 0,
+        }
+    }
+
+    fn write_baseline(fs: &MemoryFileSystem, content: &str) {
+        let path = BiomePath::new("/project/a.js");
+        let baseline = Baseline::load(
+            fs,
+            baseline_path(),
+            "/project".into(),
+            true,
+            false,
+            RuleScope::All,
+        )
+        .unwrap()
+        .unwrap();
+        baseline.filter_message(diagnostics_message(content));
+        assert!(baseline.finish(&path).is_none());
+        assert_eq!(baseline.save(fs).unwrap(), Some(1));
+    }
+
+    fn reported_errors(message: Message) -> usize {
+        let Message::Diagnostics {
+            diagnostics,
+            errors,
+            ..
+        } = message
+        else {
+            panic!("expected diagnostics");
+        };
+        assert_eq!(diagnostics.len(), errors);
+        errors
+    }
+
+    #[test]
+    fn removes_the_diagnostics_recorded_in_the_baseline() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "debugger;");
+        write_baseline(&fs, "debugger;");
+        let content = fs.read_file_from_path(&baseline_path()).unwrap();
+        assert!(content.contains("\"a.js\""), "{content}");
+        assert!(content.contains("lint/suspicious/noDebugger"), "{content}");
+
+        let path = BiomePath::new("/project/a.js");
+        let baseline = Baseline::load(
+            &fs,
+            baseline_path(),
+            "/project".into(),
+            false,
+            true,
+            RuleScope::All,
+        )
+        .unwrap()
+        .unwrap();
+        // The diagnostic moved, and a new one was added
+        let message = baseline.filter_message(diagnostics_message("\n\n  debugger;\ndebugger;"));
+        assert_eq!(reported_errors(message), 1);
+        assert!(baseline.finish(&path).is_none());
+        assert_eq!(baseline.save(&fs).unwrap(), None);
+    }
+
+    #[test]
+    fn reports_the_fixed_entries_of_the_baseline() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "debugger;");
+        write_baseline(&fs, "debugger;");
+
+        let path = BiomePath::new("/project/a.js");
+        let baseline = Baseline::load(
+            &fs,
+            baseline_path(),
+            "/project".into(),
+            false,
+            true,
+            RuleScope::All,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(baseline.finish(&path).is_some());
+    }
+
+    #[test]
+    fn fingerprints_are_stable() {
+        // The fingerprints are stored in the baseline, so they must not change across versions
+        let diagnostic = Error::from(TestDiagnostic {
+            span: TextRange::new(0.into(), 8.into()),
+        });
+        assert_eq!(
+            fingerprint(
+                &diagnostic,
+                "lint/suspicious/noDebugger",
+                "a.js",
+                "debugger;"
+            ),
+            "5b0d26d5813c05c7"
+        );
+    }
+
+    #[test]
+    fn loads_a_missing_baseline() {
+        let fs = MemoryFileSystem::default();
+        let load = |write, required| {
+            Baseline::load(
+                &fs,
+                baseline_path(),
+                "/project".into(),
+                write,
+                required,
+                RuleScope::All,
+            )
+        };
+        assert!(load(false, false).unwrap().is_none());
+        assert!(load(false, true).is_err());
+        assert!(load(true, true).unwrap().is_some());
+    }
+
+    #[test]
+    fn keeps_the_entries_of_files_that_werent_processed() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "debugger;");
+        write_baseline(&fs, "debugger;");
+
+        let baseline = Baseline::load(
+            &fs,
+            baseline_path(),
+            "/project".into(),
+            true,
+            false,
+            RuleScope::All,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(baseline.save(&fs).unwrap(), Some(1));
+
+        fs.remove(Utf8Path::new("/project/a.js"));
+        let baseline = Baseline::load(
+            &fs,
+            baseline_path(),
+            "/project".into(),
+            true,
+            false,
+            RuleScope::All,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(baseline.save(&fs).unwrap(), Some(0));
+    }
+}
@@ -0,0 +1,410 @@
+//! On-disk cache of the results of a CLI run, enabled with `--cache`.
+//!
+//! The cache stores, for each file that was processed without changes, the hash of its content
+//! and the diagnostics it emitted. The next run skips the files whose content didn't change and
+//! replays their diagnostics instead of analyzing them again.
+//!
+//! The whole cache is discarded when the Biome version, the command, its arguments or the
+//! resolved settings change. The hashes are computed with XXH3-128, whose output is fixed by its
+//! specification, so they stay valid across builds and platforms. Since the results of a file can't depend on other files, the cache
+//! is disabled when the project is scanned for cross-file analysis, and when plugins are
+//! configured.
+
+use crate::cli_options::CliOptions;
+use crate::runner::execution::Execution;
+use crate::runner::process_file::Message;
+use biome_configuration::{Configuration, VERSION};
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, FileSystem, FileSystemExt};
+use biome_service::workspace::ScanKind;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use xxhash_rust::xxh3::{Xxh3, xxh3_128};
+
+/// Prefix of the names of the cache files, one per project.
+pub(crate) const CACHE_FILE_PREFIX: &str = "biome-cache-";
+
+/// Returns the path of the cache file of the project located at `project_dir`.
+pub(crate) fn cache_file_path(project_dir: &Utf8Path) -> Utf8PathBuf {
+    biome_fs::ensure_cache_dir().join(format!(
+        "{CACHE_FILE_PREFIX}{:032x}.json",
+        xxh3_128(project_dir.as_str().as_bytes())
+    ))
+}
+
+/// Whether the results of `execution` only depend on the content of each file and its settings.
+pub(crate) fn is_cacheable(
+    execution: &dyn Execution,
+    scan_kind: &ScanKind,
+    configuration: &Configuration,
+) -> bool {
+    (execution.is_check() || execution.is_lint() || execution.is_format() || execution.is_ci())
+        // Skipped fixes and skipped files aren't reported as diagnostics of the file
+        && execution.as_fix_file_mode().is_none()
+        && !execution.should_skip_parse_errors()
+        && !execution.is_rule_profiling_enabled()
+        && !execution.is_type_inference_profiling_enabled()
+        && !scan_kind.is_project()
+        && !scan_kind.is_type_aware()
+        && configuration
+            .plugins
+            .as_ref()
+            .is_none_or(|plugins| plugins.is_empty())
+}
+
+/// Hashes the resolved configuration and the configurations it extends.
+///
+/// It must be computed before the configuration is sent to the workspace.
+pub(crate) fn hash_settings(
+    configuration: &Configuration,
+    extended_configurations: &[(Utf8PathBuf, Configuration)],
+) -> u128 {
+    let mut hasher = CacheHasher::default();
+    hasher.write_field(&serde_json::to_string(configuration).unwrap_or_default());
+    for (path, configuration) in extended_configurations {
+        hasher.write_field(path.as_str());
+        hasher.write_field(&serde_json::to_string(configuration).unwrap_or_default());
+    }
+    hasher.finish()
+}
+
+/// Computes the key that invalidates the whole cache when it changes.
+///
+/// The key starts with the version of Biome, followed by the hash of the settings, the command
+/// and its arguments.
+pub(crate) fn cache_key(
+    settings_hash: u128,
+    execution: &dyn Execution,
+    cli_options: &CliOptions,
+    configuration_files: &[BiomePath],
+    fs: &dyn FileSystem,
+) -> String {
+    let mut hasher = CacheHasher::default();
+    hasher.write_field(&format!("{settings_hash:032x}"));
+    hasher.write_field(execution.as_diagnostic_category().name());
+    hasher.write_field(&execution.requires_write_access().to_string());
+    hasher.write_field(&execution.should_enforce_assist().to_string());
+    hasher.write_field(&format!("{:?}", execution.analyzer_selectors()));
+    hasher.write_field(&format!("{:?}", execution.get_max_diagnostics(cli_options)));
+    hasher.write_field(&format!("{:?}", cli_options.diagnostic_level));
+    // Nested configuration files aren't part of the resolved configuration
+    let mut configuration_files: Vec<_> = configuration_files.iter().collect();
+    configuration_files.sort_unstable();
+    for path in configuration_files {
+        hasher.write_field(path.as_str());
+        hasher.write_field(&fs.read_file_from_path(path).unwrap_or_default());
+    }
+    format!("{VERSION}-{:032x}", hasher.finish())
+}
+
+/// Returns the hash of the content of a file.
+fn content_hash(content: &str) -> String {
+    format!("{:032x}", xxh3_128(content.as_bytes()))
+}
+
+/// Hashes a sequence of fields with XXH3-128.
+#[derive(Default)]
+struct CacheHasher(Xxh3);
+
+impl CacheHasher {
+    /// Hashes `field`, followed by a byte that can't appear in UTF-8 to separate it from the next
+    /// field.
+    fn write_field(&mut self, field: &str) {
+        self.0.update(field.as_bytes());
+        self.0.update(&[0xff]);
+    }
+
+    fn finish(&self) -> u128 {
+        self.0.digest128()
+    }
+}
+
+/// The content of a cache file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    key: String,
+    files: FxHashMap<String, CacheEntry>,
+}
+
+/// The results of a file that was processed without changes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CacheEntry {
+    content_hash: String,
+    /// The diagnostics sent by the file, if any
+    diagnostics: Option<CachedDiagnostics>,
+}
+
+impl CacheEntry {
+    /// Returns the message to replay for the file, if it emitted one.
+    pub(crate) fn to_message(&self, path: &BiomePath, content: &str) -> Option<Message> {
+        let CachedDiagnostics {
+            diagnostics,
+            skipped_diagnostics,
+            errors,
+            warnings,
+            infos,
+        } = self.diagnostics.clone()?;
+        Some(Message::Diagnostics {
+            file_path: path.to_string(),
+            content: if diagnostics.is_empty() {
+                String::new()
+            } else {
+                content.to_string()
+            },
+            diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+            skipped_diagnostics,
+            errors,
+            warnings,
+            infos,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDiagnostics {
+    diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+    skipped_diagnostics: u32,
+    errors: usize,
+    warnings: usize,
+    infos: usize,
+}
+
+/// Cache shared by the threads of a traversal.
+pub(crate) struct RunCache {
+    /// Where the cache is stored
+    path: Utf8PathBuf,
+    key: String,
+    /// Entries loaded from the previous run
+    previous: FxHashMap<String, CacheEntry>,
+    /// Entries of the files processed during this run
+    current: papaya::HashMap<String, CacheEntry>,
+    /// Diagnostics sent by the files that are still being processed
+    pending: papaya::HashMap<String, CachedDiagnostics>,
+}
+
+impl RunCache {
+    /// Loads the cache stored at `path`. The previous entries are discarded if they were
+    /// computed with a different `key`.
+    pub(crate) fn load(fs: &dyn FileSystem, path: Utf8PathBuf, key: String) -> Self {
+        let previous = fs
+            .read_file_from_path(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| cache_file.key == key)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+        debug!("Loaded {} entries from the cache {path}", previous.len());
+        Self {
+            path,
+            key,
+            previous,
+            current: papaya::HashMap::default(),
+            pending: papaya::HashMap::default(),
+        }
+    }
+
+    /// Returns the entry of `path`, if its content didn't change since the previous run.
+    pub(crate) fn get(&self, path: &BiomePath, content: &str) -> Option<&CacheEntry> {
+        self.previous
+            .get(path.as_str())
+            .filter(|entry| entry.content_hash == content_hash(content))
+    }
+
+    /// Records the diagnostics of a message sent while a file is processed, and returns the
+    /// message unchanged.
+    pub(crate) fn record_message(&self, message: Message) -> Message {
+        let Message::Diagnostics {
+            file_path,
+            content,
+            diagnostics,
+            skipped_diagnostics,
+            errors,
+            warnings,
+            infos,
+        } = message
+        else {
+            return message;
+        };
+        let diagnostics: Vec<_> = diagnostics
+            .into_iter()
+            .map(biome_diagnostics::serde::Diagnostic::new)
+            .collect();
+        self.pending.pin().insert(
+            file_path.clone(),
+            CachedDiagnostics {
+                diagnostics: diagnostics.clone(),
+                skipped_diagnostics,
+                errors,
+                warnings,
+                infos,
+            },
+        );
+        Message::Diagnostics {
+            file_path,
+            content,
+            diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+            skipped_diagnostics,
+            errors,
+            warnings,
+            infos,
+        }
+    }
+
+    /// Stores the results of `path` once it has been processed. Only the files that didn't
+    /// produce any change are stored.
+    pub(crate) fn finish(&self, path: &BiomePath, content: &str, unchanged: bool) {
+        let diagnostics = self.pending.pin().remove(path.as_str()).cloned();
+        if unchanged {
+            self.current.pin().insert(
+                path.to_string(),
+                CacheEntry {
+                    content_hash: content_hash(content),
+                    diagnostics,
+                },
+            );
+        }
+    }
+
+    /// Writes the cache to disk. The entries of the files that weren't processed during this
+    /// run are kept, as long as the files still exist.
+    pub(crate) fn save(self, fs: &dyn FileSystem) {
+        let mut files: FxHashMap<_, _> = self
+            .previous
+            .into_iter()
+            .filter(|(path, _)| fs.path_is_file(Utf8Path::new(path)))
+            .collect();
+        files.extend(
+            self.current
+                .pin()
+                .iter()
+                .map(|(path, entry)| (path.clone(), entry.clone())),
+        );
+        let cache_file = CacheFile {
+            key: self.key,
+            files,
+        };
+        let result = serde_json::to_string(&cache_file)
+            .map_err(std::io::Error::other)
+            .and_then(|content| fs.create(&self.path)?.set_content(content.as_bytes()));
+        if let Err(error) = result {
+            warn!("Couldn't write the cache {}: {error}", self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::{DiagnosticExt, Severity};
+    use biome_fs::MemoryFileSystem;
+
+    fn cache_path() -> Utf8PathBuf {
+        Utf8PathBuf::from("/cache/biome-cache-test.json")
+    }
+
+    fn key(settings_hash: u128) -> String {
+        format!("{VERSION}-{settings_hash:032x}")
+    }
+
+    fn diagnostics_message(path: &str) -> Message {
+        Message::Diagnostics {
+            file_path: path.to_string(),
+            content: "let a".to_string(),
+            diagnostics: vec![
+                biome_diagnostics::serde::Diagnostic::new(
+                    biome_diagnostics::IoError::from(std::io::Error::other("failed"))
+                        .with_severity(Severity::Warning),
+                )
+                .into(),
+            ],
+            skipped_diagnostics: 1,
+            errors: 0,
+            warnings: 1,
+            infos: 0,
+        }
+    }
+
+    #[test]
+    fn replays_the_diagnostics_of_unchanged_files() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "let a");
+        let path = BiomePath::new("/project/a.js");
+
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        assert!(cache.get(&path, "let a").is_none());
+        cache.record_message(diagnostics_message("/project/a.js"));
+        cache.finish(&path, "let a", true);
+        cache.save(&fs);
+
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        let entry = cache.get(&path, "let a").expect("the file to be cached");
+        let Some(Message::Diagnostics {
+            diagnostics,
+            skipped_diagnostics,
+            warnings,
+            ..
+        }) = entry.to_message(&path, "let a")
+        else {
+            panic!("expected the diagnostics to be replayed");
+        };
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(skipped_diagnostics, 1);
+        assert_eq!(warnings, 1);
+
+        assert!(cache.get(&path, "let b").is_none());
+    }
+
+    #[test]
+    fn discards_the_entries_of_another_key() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "let a");
+        let path = BiomePath::new("/project/a.js");
+
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        cache.finish(&path, "let a", true);
+        cache.save(&fs);
+
+        let cache = RunCache::load(&fs, cache_path(), key(2));
+        assert!(cache.get(&path, "let a").is_none());
+    }
+
+    #[test]
+    fn doesnt_store_changed_and_deleted_files() {
+        let fs = MemoryFileSystem::default();
+        fs.insert("/project/a.js".into(), "let a");
+        fs.insert("/project/b.js".into(), "let b");
+        let a = BiomePath::new("/project/a.js");
+        let b = BiomePath::new("/project/b.js");
+
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        cache.record_message(diagnostics_message("/project/a.js"));
+        cache.finish(&a, "let a", false);
+        cache.finish(&b, "let b", true);
+        cache.save(&fs);
+
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        assert!(cache.get(&a, "let a").is_none());
+        assert!(cache.get(&b, "let b").is_some());
+
+        // The entry of a file that wasn't processed is kept until the file is deleted
+        cache.save(&fs);
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        assert!(cache.get(&b, "let b").is_some());
+        fs.remove(Utf8Path::new("/project/b.js"));
+        cache.save(&fs);
+        let cache = RunCache::load(&fs, cache_path(), key(1));
+        assert!(cache.get(&b, "let b").is_none());
+    }
+
+    #[test]
+    fn content_hashes_are_stable() {
+        // Reference value of the XXH3-128 specification for an empty input
+        assert_eq!(content_hash(""), "99aa06d3014798d86001c324468d497f");
+        assert_ne!(content_hash("let a"), content_hash("let b"));
+    }
+}
@@ -1,4 +1,7 @@
 use crate::CliDiagnostic;
+use crate::changed::ChangedLines;
+use crate::runner::baseline::Baseline;
+use crate::runner::cache::RunCache;
 use crate::runner::collector::Collector;
 use crate::runner::execution::Execution;
 use crate::runner::handler::Handler;
@@ -46,6 +49,9 @@ pub trait Crawler<Output> {
         collector: Self::Collector,
         max_diagnostics: u32,
         diagnostic_level: Severity,
+        cache: Option<&RunCache>,
+        baseline: Option<&Baseline>,
+        changed_lines: Option<&ChangedLines>,
     ) -> Result<Output, CliDiagnostic> {
         let (interner, recv_files) = PathInterner::new();
         let (sender, receiver) = unbounded();
@@ -66,6 +72,9 @@ pub trait Crawler<Output> {
                 execution,
                 max_diagnostics,
                 diagnostic_level,
+                cache,
+                baseline,
+                changed_lines,
             );
             let (elapsed, evaluated_paths) = Self::crawl_inputs(fs, inputs, &ctx);
 
@@ -144,6 +153,12 @@ pub trait CrawlerContext: TraversalContext {
     fn execution(&self) -> &dyn Execution;
     fn insert_file_features(&self, path: BiomePath, features: FeaturesSupported);
     fn get_file_features(&self, path: &BiomePath) -> Option<FeaturesSupported>;
+    /// The cache of the results of the previous run, when `--cache` is used
+    fn cache(&self) -> Option<&RunCache>;
+    /// The baseline of the diagnostics, when one exists or `--write-baseline` is used
+    fn baseline(&self) -> Option<&Baseline>;
+    /// The lines added or modified in the files, when `--changed-lines` is used
+    fn changed_lines(&self) -> Option<&ChangedLines>;
 }
 
 /// Context object shared between directory traversal tasks
@@ -178,6 +193,12 @@ pub(crate) struct CrawlerOptions<'ctx, 'app, H, P> {
     pub(crate) max_diagnostics: u32,
     /// Minimum severity for diagnostics to be included.
     pub(crate) diagnostic_level: Severity,
+    /// Cache of the results of the previous run
+    cache: Option<&'app RunCache>,
+    /// Baseline of the diagnostics
+    baseline: Option<&'app Baseline>,
+    /// Lines added or modified in the files
+    changed_lines: Option<&'app ChangedLines>,
 
     execution: &'app dyn Execution,
 
@@ -218,6 +239,14 @@ where
 
     /// Send a message to the display thread
     fn push_message(&self, msg: Message) {
+        let msg = match self.baseline {
+            Some(baseline) => baseline.filter_message(msg),
+            None => msg,
+        };
+        let msg = match self.cache {
+            Some(cache) => cache.record_message(msg),
+            None => msg,
+        };
         self.messages.send(msg).ok();
     }
 
@@ -244,6 +273,18 @@ where
     fn get_file_features(&self, path: &BiomePath) -> Option<FeaturesSupported> {
         self.file_features.pin().get(path).cloned()
     }
+
+    fn cache(&self) -> Option<&RunCache> {
+        self.cache
+    }
+
+    fn baseline(&self) -> Option<&Baseline> {
+        self.baseline
+    }
+
+    fn changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines
+    }
 }
 
 impl<'ctx, 'app, I, P> CrawlerOptions<'ctx, 'app, I, P>
@@ -262,6 +303,9 @@ where
         execution: &'app dyn Execution,
         max_diagnostics: u32,
         diagnostic_level: Severity,
+        cache: Option<&'app RunCache>,
+        baseline: Option<&'app Baseline>,
+        changed_lines: Option<&'app ChangedLines>,
     ) -> Self {
         Self {
             fs,
@@ -281,6 +325,9 @@ where
             execution,
             max_diagnostics,
             diagnostic_level,
+            cache,
+            baseline,
+            changed_lines,
             _p: PhantomData::<P>,
         }
     }
@@ -1,5 +1,7 @@
+use biome_console::fmt::Display;
+use biome_console::markup;
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Visit,
+    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, LogCategory, Visit,
 };
 use biome_diagnostics::{IoError, StdError};
 use biome_text_edit::TextEdit;
@@ -82,6 +84,99 @@ pub(crate) struct SkippedDiagnostic;
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would have rewritten the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Error,
+    message = "The snapshot of the fixture doesn't match the output of the plugin:"
+)]
+pub(crate) struct SnapshotDiffDiagnostic {
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Error,
+    message = "The fixture doesn't have a snapshot. Run the command with --update to create it."
+)]
+pub(crate) struct MissingSnapshotDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Information,
+    message = "The snapshot of the fixture has been updated."
+)]
+pub(crate) struct UpdatedSnapshotDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Error,
+    message = "The fixture is expected to be valid, but the plugin emitted diagnostics."
+)]
+pub(crate) struct ValidFixtureDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Error,
+    message = "The fixture is expected to be invalid, but the plugin didn't emit any diagnostic."
+)]
+pub(crate) struct InvalidFixtureDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Information,
+    message = "Some diagnostics recorded in the baseline have been fixed."
+)]
+pub(crate) struct FixedBaselineDiagnostic {
+    #[advice]
+    pub(crate) advice: FixedBaselineAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct FixedBaselineAdvice {
+    /// The rules whose diagnostics have been fixed, and the number of fixed diagnostics
+    pub(crate) fixed: Vec<(String, u32)>,
+}
+
+impl Advices for FixedBaselineAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &"The following rules emit fewer diagnostics than the baseline records:",
+        )?;
+        let fixed: Vec<_> = self
+            .fixed
+            .iter()
+            .map(|(rule, count)| format!("{rule} ({count})"))
+            .collect();
+        let list: Vec<_> = fixed.iter().map(|item| item as &dyn Display).collect();
+        visitor.record_list(&list)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "Run the command with "<Emphasis>"--write-baseline"</Emphasis>" to remove them from the baseline."
+            },
+        )
+    }
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
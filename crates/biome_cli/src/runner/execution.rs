@@ -168,6 +168,21 @@ pub(crate) trait Execution: Send + Sync + std::panic::RefUnwindSafe {
         None
     }
 
+    /// The value of `biome search --rewrite`
+    fn is_search_rewrite(&self) -> bool {
+        false
+    }
+
+    /// `biome plugin test` command
+    fn is_plugin_test(&self) -> bool {
+        false
+    }
+
+    /// The value of `biome plugin test --update`
+    fn should_update_snapshots(&self) -> bool {
+        false
+    }
+
     /// Used when printing summary
     fn summary_phrase(&self, files: usize, duration: &Duration) -> MarkupBuf;
 
@@ -15,6 +15,7 @@ use std::panic::catch_unwind;
 pub const TRAVERSAL_IGNORE_ENTRIES: &[&[u8]] = &[
     b".git",
     b".hg",
+    b".jj",
     b".svn",
     b".yarn",
     b".DS_Store",
@@ -1,13 +1,17 @@
+use crate::changed::ChangedLines;
 use crate::runner::collector::Collector;
-use crate::runner::diagnostics::{CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic};
+use crate::runner::diagnostics::{
+    CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic, RewriteDiffDiagnostic,
+    SnapshotDiffDiagnostic,
+};
 use crate::runner::execution::Execution;
 use crate::runner::process_file::{DiffKind, Message, MessageStat};
 use biome_diagnostics::{DiagnosticExt, DiagnosticTags, Error, Resource, Severity};
 use camino::{Utf8Path, Utf8PathBuf};
 use crossbeam::channel::Receiver;
 use rustc_hash::FxHashSet;
-use std::sync::RwLock;
 use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 pub(crate) struct DefaultCollector {
@@ -44,6 +48,9 @@ pub(crate) struct DefaultCollector {
 
     diagnostics_to_print: RwLock<Vec<Error>>,
 
+    /// The lines added or modified in the files, when only their diagnostics are reported
+    changed_lines: Option<Arc<ChangedLines>>,
+
     changed: AtomicUsize,
     unchanged: AtomicUsize,
     matches: AtomicUsize,
@@ -65,6 +72,7 @@ impl DefaultCollector {
             total_skipped_suggested_fixes: AtomicU32::new(0),
             working_directory: working_directory.map(|wd| wd.to_path_buf()),
             diagnostics_to_print: RwLock::default(),
+            changed_lines: None,
             changed: AtomicUsize::new(0),
             unchanged: AtomicUsize::new(0),
             matches: AtomicUsize::new(0),
@@ -92,6 +100,11 @@ impl DefaultCollector {
         self
     }
 
+    pub(crate) fn with_changed_lines(mut self, value: Option<Arc<ChangedLines>>) -> Self {
+        self.changed_lines = value;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -112,6 +125,18 @@ impl DefaultCollector {
         self.total_skipped_suggested_fixes.load(Ordering::Relaxed)
     }
 
+    /// Whether the diagnostic must be skipped because it's outside the changed lines of its file.
+    /// Diagnostics without a span are always reported.
+    fn is_outside_changed_lines(&self, file_path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(changed_lines) = self.changed_lines.as_deref() else {
+            return false;
+        };
+        let Some(span) = diagnostic.location().span else {
+            return false;
+        };
+        !changed_lines.overlaps_range(Utf8Path::new(file_path), content, span)
+    }
+
     fn to_relative_file_path(&self, path: &str) -> String {
         let file_path = Utf8Path::new(&path);
         self.working_directory
@@ -216,7 +241,7 @@ impl Collector for DefaultCollector {
                 }
 
                 Message::Diagnostics {
-                    file_path,
+                    file_path: original_file_path,
                     content,
                     diagnostics,
                     skipped_diagnostics,
@@ -229,15 +254,18 @@ impl Collector for DefaultCollector {
                     let mut actual_infos = infos as u32;
                     // we transform the file string into a path object so we can correctly strip
                     // the working directory without having leading slash in the file name
-                    let file_path = self.to_relative_file_path(&file_path);
+                    let file_path = self.to_relative_file_path(&original_file_path);
                     self.not_printed_diagnostics
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
                     for diag in diagnostics {
                         let severity = diag.severity();
-                        if self.should_skip_diagnostic(severity, diag.tags()) {
+                        if self.should_skip_diagnostic(severity, diag.tags())
+                            || self.is_outside_changed_lines(&original_file_path, &content, &diag)
+                        {
                             // Adjust workspace counts for diagnostics skipped
-                            // by the verbose filter (diagnostic_level filtering
-                            // is already handled in the workspace).
+                            // by the verbose filter or outside the changed lines
+                            // (diagnostic_level filtering is already handled in
+                            // the workspace).
                             match severity {
                                 Severity::Error => actual_errors = actual_errors.saturating_sub(1),
                                 Severity::Warning => {
@@ -272,6 +300,7 @@ impl Collector for DefaultCollector {
                     let file_path = self.to_relative_file_path(&file_name);
                     // A diff is an error in CI mode and in format/check command in non-write mode
                     let is_error = execution.is_ci()
+                        || matches!(diff_kind, DiffKind::Snapshot)
                         || (execution.is_format()
                             || execution.is_check() && !execution.requires_write_access());
                     if is_error {
@@ -280,6 +309,9 @@ impl Collector for DefaultCollector {
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if matches!(diff_kind, DiffKind::Rewrite) {
+                        // The rewrites are the output of the search, they must be printed
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -318,6 +350,29 @@ impl Collector for DefaultCollector {
                                     self.push_diagnostic(diag);
                                 }
                             }
+                            DiffKind::Rewrite => {
+                                let diag = RewriteDiffDiagnostic {
+                                    diff: ContentDiffAdvice {
+                                        old: old.clone(),
+                                        new: new.clone(),
+                                    },
+                                }
+                                .with_severity(severity)
+                                .with_file_source_code(old.as_str())
+                                .with_file_path(file_path.clone());
+                                self.push_diagnostic(diag);
+                            }
+                            DiffKind::Snapshot => {
+                                let diag = SnapshotDiffDiagnostic {
+                                    diff: ContentDiffAdvice {
+                                        old: old.clone(),
+                                        new: new.clone(),
+                                    },
+                                }
+                                .with_file_source_code(old.as_str())
+                                .with_file_path(file_path.clone());
+                                self.push_diagnostic(diag);
+                            }
                         }
                     }
                 }
@@ -1,3 +1,4 @@
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
 use crate::runner::execution::Execution;
 use crate::runner::{CommandRunner, ConfiguredWorkspace};
@@ -10,6 +11,7 @@ use biome_service::{Workspace, WorkspaceError};
 use camino::Utf8PathBuf;
 use std::ffi::OsString;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 /// A command that doesn't require crawling but requires a custom execution
 pub(crate) struct CustomExecutionCmdImpl<C>(pub C)
@@ -104,7 +106,14 @@ where
         self.deref().minimal_scan_kind()
     }
 
-    fn collector(&self, _: &dyn FileSystem, _: &dyn Execution, _: &CliOptions) -> Self::Collector {}
+    fn collector(
+        &self,
+        _: &dyn FileSystem,
+        _: &dyn Execution,
+        _: &CliOptions,
+        _: Option<Arc<ChangedLines>>,
+    ) -> Self::Collector {
+    }
 
     fn merge_configuration(
         &mut self,
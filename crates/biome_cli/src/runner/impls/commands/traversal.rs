@@ -1,5 +1,7 @@
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
 use crate::runner::CommandRunner;
+use crate::runner::baseline::BaselineOptions;
 use crate::runner::execution::Execution;
 use crate::runner::impls::collectors::default::DefaultCollector;
 use crate::runner::impls::crawlers::default::DefaultCrawler;
@@ -18,6 +20,7 @@ use biome_service::{Workspace, WorkspaceError};
 use camino::Utf8PathBuf;
 use std::ffi::OsString;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 pub trait LoadEditorConfig: TraversalCommand {
     /// Whether this command should load the `.editorconfig` file.
@@ -128,6 +131,25 @@ pub(crate) trait TraversalCommand {
         configuration: &Configuration,
     ) -> Result<Vec<OsString>, CliDiagnostic>;
 
+    /// Alias of [CommandRunner::get_changed_lines]
+    fn get_changed_lines(
+        &self,
+        _fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        Ok(None)
+    }
+
+    /// Alias of [CommandRunner::use_cache]
+    fn use_cache(&self) -> bool {
+        false
+    }
+
+    /// Alias of [CommandRunner::baseline_options]
+    fn baseline_options(&self) -> Option<BaselineOptions> {
+        None
+    }
+
     /// Alias of [CommandRunner::check_incompatible_arguments]
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
         Ok(())
@@ -169,11 +191,13 @@ where
         fs: &dyn FileSystem,
         execution: &dyn Execution,
         cli_options: &CliOptions,
+        changed_lines: Option<Arc<ChangedLines>>,
     ) -> Self::Collector {
         DefaultCollector::new(fs.working_directory().as_deref())
             .with_verbose(cli_options.verbose)
             .with_diagnostic_level(cli_options.diagnostic_level)
             .with_max_diagnostics(execution.get_max_diagnostics(cli_options))
+            .with_changed_lines(changed_lines)
     }
 
     fn merge_configuration(
@@ -201,6 +225,22 @@ where
         self.deref().get_files_to_process(fs, configuration)
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        self.deref().get_changed_lines(fs, configuration)
+    }
+
+    fn use_cache(&self) -> bool {
+        self.deref().use_cache()
+    }
+
+    fn baseline_options(&self) -> Option<BaselineOptions> {
+        self.deref().baseline_options()
+    }
+
     fn get_execution(
         &self,
         cli_options: &CliOptions,
@@ -1,4 +1,5 @@
 use crate::CliDiagnostic;
+use crate::changed::ChangedLines;
 use crate::diagnostics::StdinDiagnostic;
 use crate::runner::crawler::CrawlerContext;
 use crate::runner::diagnostics::{ResultExt, SkippedDiagnostic};
@@ -9,13 +10,20 @@ use crate::runner::process_file::{
 use biome_analyze::RuleCategoriesBuilder;
 use biome_console::{ConsoleExt, markup};
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, PrintDiagnostic, Severity, category};
+use biome_formatter::Printed;
+use biome_rowan::{TextRange, TextSize};
 use biome_service::WorkspaceError;
 use biome_service::workspace::{
-    FeaturesBuilder, FeaturesSupported, FileContent, FileFeaturesResult, ProcessFileParams,
-    SupportsFeatureParams,
+    FeaturesBuilder, FeaturesSupported, FileContent, FileFeaturesResult, FormatRangeParams,
+    ProcessFileParams, SupportsFeatureParams,
 };
+use std::ops::Range;
 use tracing::debug;
 
+/// Extensions of the files whose scripts are formatted on their own. The ranges formatted by
+/// `format_range` are relative to the script, so these files are formatted entirely.
+const EMBEDDED_SCRIPT_EXTENSIONS: &[&str] = &["astro", "svelte", "vue"];
+
 pub(crate) struct FormatProcessFile;
 
 impl ProcessFile for FormatProcessFile {
@@ -95,6 +103,17 @@ impl ProcessFile for FormatProcessFile {
             ));
         }
 
+        let output = match (output, ctx.changed_lines()) {
+            (Some(output), Some(changed_lines)) => {
+                let input = workspace_file.input()?;
+                match format_changed_lines(ctx, workspace_file, changed_lines, &input)? {
+                    Some(formatted) => (formatted != input).then_some(formatted),
+                    None => Some(output),
+                }
+            }
+            (output, _) => output,
+        };
+
         debug!(
             "Format output is different from input: {}",
             output.is_some()
@@ -199,3 +218,85 @@ impl ProcessFile for FormatProcessFile {
         }
     }
 }
+
+/// Formats only the changed lines of the file, starting from the last ones so that the ranges
+/// of the previous lines stay valid.
+///
+/// The formatted ranges cover the nodes that enclose the changed lines, so the ranges of nearby
+/// changed lines can overlap. In this case, their union is formatted at once.
+///
+/// Returns `None` when the file doesn't support range formatting.
+fn format_changed_lines<Ctx>(
+    ctx: &Ctx,
+    workspace_file: &WorkspaceFile,
+    changed_lines: &ChangedLines,
+    input: &str,
+) -> Result<Option<String>, Message>
+where
+    Ctx: CrawlerContext,
+{
+    if workspace_file
+        .path
+        .extension()
+        .is_some_and(|extension| EMBEDDED_SCRIPT_EXTENSIONS.contains(&extension))
+    {
+        return Ok(None);
+    }
+
+    let format_range = |range: TextRange| -> Result<Option<Printed>, Message> {
+        match ctx.workspace().format_range(FormatRangeParams {
+            project_key: ctx.project_key(),
+            path: workspace_file.path.clone(),
+            range,
+            inline_config: None,
+        }) {
+            Err(WorkspaceError::SourceFileNotSupported(_)) => Ok(None),
+            result => Ok(Some(result.with_file_path_and_code(
+                workspace_file.path.to_string(),
+                category!("format"),
+            )?)),
+        }
+    };
+    let printed_range = |printed: &Printed| {
+        printed
+            .range()
+            .unwrap_or_else(|| TextRange::up_to(TextSize::of(input)))
+    };
+
+    let mut output = input.to_string();
+    // The last range that was formatted. It's applied once the range of the previous changed
+    // lines is known not to overlap it.
+    let mut pending: Option<(TextRange, Printed)> = None;
+    for mut range in changed_lines
+        .text_ranges(workspace_file.path.as_path(), input)
+        .into_iter()
+        .rev()
+    {
+        let Some(mut printed) = format_range(range)? else {
+            return Ok(None);
+        };
+        if let Some((pending_range, pending_printed)) = pending.take() {
+            if printed_range(&printed).end() > printed_range(&pending_printed).start() {
+                range = range.cover(pending_range);
+                let Some(union) = format_range(range)? else {
+                    return Ok(None);
+                };
+                printed = union;
+            } else {
+                output.replace_range(
+                    Range::<usize>::from(printed_range(&pending_printed)),
+                    pending_printed.as_code(),
+                );
+            }
+        }
+        pending = Some((range, printed));
+    }
+    if let Some((_, printed)) = pending {
+        output.replace_range(
+            Range::<usize>::from(printed_range(&printed)),
+            printed.as_code(),
+        );
+    }
+
+    Ok(Some(output))
+}
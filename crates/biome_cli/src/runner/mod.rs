@@ -105,6 +105,8 @@
 //!
 //! ## Module Organization
 //!
+//! - [`baseline`]: Baseline of the diagnostics that aren't reported
+//! - [`cache`]: On-disk cache of the results of the previous run
 //! - [`collector`]: Diagnostic collection during traversal
 //! - [`crawler`]: File system traversal orchestration
 //! - [`execution`]: Feature requirements and capability checks
@@ -120,6 +122,8 @@
 //!   - [`impls::handlers`]: Handler implementations
 //!   - [`impls::process_file`]: ProcessFile implementations
 
+pub(crate) mod baseline;
+pub(crate) mod cache;
 pub(crate) mod collector;
 pub(crate) mod crawler;
 pub(crate) mod diagnostics;
@@ -132,12 +136,15 @@ pub(crate) mod run;
 pub(crate) mod scan_kind;
 pub(crate) mod watcher;
 
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
 use crate::commands::{
     print_diagnostics_from_workspace_result, validate_configuration_diagnostics,
 };
 use crate::diagnostics::StdinDiagnostic;
 use crate::logging::LogOptions;
+use crate::runner::baseline::{BASELINE_FILE_NAME, Baseline, BaselineOptions, RuleScope};
+use crate::runner::cache::{RunCache, cache_file_path, cache_key, hash_settings, is_cacheable};
 use crate::runner::collector::Collector;
 use crate::runner::crawler::{CrawlPath, Crawler};
 use crate::runner::execution::{Execution, Stdin};
@@ -165,6 +172,7 @@ use biome_service::workspace::{
 use biome_service::{Workspace, WorkspaceError};
 use camino::{Utf8Path, Utf8PathBuf};
 use std::ffi::OsString;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::info;
 
@@ -219,6 +227,7 @@ pub(crate) trait CommandRunner {
         fs: &dyn FileSystem,
         execution: &dyn Execution,
         cli_options: &CliOptions,
+        changed_lines: Option<Arc<ChangedLines>>,
     ) -> Self::Collector;
 
     fn validated_paths_for_execution(
@@ -273,6 +282,18 @@ pub(crate) trait CommandRunner {
             ));
         }
 
+        if self.is_watch_mode()
+            && self
+                .baseline_options()
+                .is_some_and(|baseline_options| baseline_options.write)
+        {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--watch",
+                "--write-baseline",
+                "The baseline can't be written in watch mode.",
+            ));
+        }
+
         let watcher_factory = session.watcher_factory.take();
 
         let console = &mut *session.app.console;
@@ -292,6 +313,9 @@ pub(crate) trait CommandRunner {
             duration,
             configuration_files: _,
             project_key,
+            cache,
+            baseline,
+            changed_lines,
         } = configured_workspace;
 
         if let Some(stdin) = self.get_stdin(console, execution.as_ref())? {
@@ -323,7 +347,7 @@ pub(crate) trait CommandRunner {
             });
         }
 
-        let collector = self.collector(fs, execution.as_ref(), cli_options);
+        let collector = self.collector(fs, execution.as_ref(), cli_options, changed_lines.clone());
         let mut output: Self::CrawlerOutput = Self::Crawler::crawl(
             execution.as_ref(),
             workspace,
@@ -337,8 +361,23 @@ pub(crate) trait CommandRunner {
             collector,
             execution.get_max_diagnostics(cli_options),
             cli_options.diagnostic_level,
+            cache.as_ref(),
+            baseline.as_ref(),
+            changed_lines.as_deref(),
         )?;
 
+        if let Some(cache) = cache {
+            cache.save(fs);
+        }
+
+        let baseline_path = baseline
+            .as_ref()
+            .map(|baseline| baseline.path().to_path_buf());
+        let baseline_count = match &baseline {
+            Some(baseline) if !self.is_watch_mode() => baseline.save(fs)?,
+            _ => None,
+        };
+
         Self::Finalizer::before_finalize(project_key, fs, workspace, &mut output)?;
 
         let res = Self::Finalizer::finalize(FinalizePayload {
@@ -351,6 +390,14 @@ pub(crate) trait CommandRunner {
             paths: paths.clone(),
         });
 
+        if let (Some(path), Some(count)) = (baseline_path, baseline_count)
+            && !is_not_default_reporter
+        {
+            console.log(markup! {
+                <Info>"The baseline "<Emphasis>{path.as_str()}</Emphasis>" records "{count}" diagnostics."</Info>
+            });
+        }
+
         if self.is_watch_mode() {
             let mut watcher: Box<dyn Watcher> = match &watcher_factory {
                 Some(factory) => factory(),
@@ -365,7 +412,12 @@ pub(crate) trait CommandRunner {
             while let Some(event) = watcher.poll() {
                 match event {
                     WatcherEvent::Changed(paths) => {
-                        let collector = self.collector(fs, execution.as_ref(), cli_options);
+                        let collector = self.collector(
+                            fs,
+                            execution.as_ref(),
+                            cli_options,
+                            changed_lines.clone(),
+                        );
                         let mut output: Self::CrawlerOutput = Self::Crawler::crawl(
                             execution.as_ref(),
                             workspace,
@@ -379,6 +431,9 @@ pub(crate) trait CommandRunner {
                             collector,
                             execution.get_max_diagnostics(cli_options),
                             cli_options.diagnostic_level,
+                            None,
+                            baseline.as_ref(),
+                            changed_lines.as_deref(),
                         )?;
 
                         Self::Finalizer::before_finalize(project_key, fs, workspace, &mut output)?;
@@ -459,6 +514,18 @@ pub(crate) trait CommandRunner {
 
         let execution = self.get_execution(cli_options, console, workspace)?;
 
+        let baseline_options = self.baseline_options();
+        if let Some(baseline_options) = &baseline_options
+            && baseline_options.write
+            && execution.as_fix_file_mode().is_some()
+        {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--write-baseline",
+                "--write",
+                "The baseline records the diagnostics of the files as they are, so it can't be written while fixes are applied.",
+            ));
+        }
+
         let root_configuration_dir = directory_path
             .clone()
             .unwrap_or_else(|| working_dir.clone());
@@ -484,8 +551,27 @@ pub(crate) trait CommandRunner {
             })
         }
 
+        let baseline = match baseline_options {
+            Some(baseline_options) => {
+                let path = baseline_options.path.as_ref().map_or_else(
+                    || project_dir.join(BASELINE_FILE_NAME),
+                    |path| working_dir.join(path),
+                );
+                Baseline::load(
+                    fs,
+                    path,
+                    project_dir.clone(),
+                    baseline_options.write,
+                    baseline_options.path.is_some(),
+                    RuleScope::new(&execution.analyzer_selectors()),
+                )?
+            }
+            None => None,
+        };
+
         let paths = self.get_files_to_process(fs, &configuration)?;
         let paths = self.validated_paths_for_execution(paths, &working_dir, execution.as_ref())?;
+        let changed_lines = self.get_changed_lines(fs, &configuration)?.map(Arc::new);
 
         // Open the project
         let open_project_result = workspace.open_project(OpenProjectParams {
@@ -510,6 +596,15 @@ pub(crate) trait CommandRunner {
         let scan_kind =
             execution.compute_scan_kind(paths.as_slice(), working_dir.as_path(), scan_kind);
 
+        // The settings are hashed before the configuration is moved to the workspace
+        // The cached diagnostics depend on the baseline and the changed lines
+        let cache_settings_hash = (self.use_cache()
+            && !self.is_watch_mode()
+            && baseline.is_none()
+            && changed_lines.is_none()
+            && is_cacheable(execution.as_ref(), &scan_kind, &configuration))
+        .then(|| hash_settings(&configuration, &extended_configurations));
+
         // Update the settings of the project
         let result = workspace.update_settings(UpdateSettingsParams {
             project_key: open_project_result.project_key,
@@ -545,12 +640,26 @@ pub(crate) trait CommandRunner {
             )?;
         }
 
+        let cache = cache_settings_hash.map(|settings_hash| {
+            let key = cache_key(
+                settings_hash,
+                execution.as_ref(),
+                cli_options,
+                &result.configuration_files,
+                fs,
+            );
+            RunCache::load(fs, cache_file_path(project_dir), key)
+        });
+
         Ok(ConfiguredWorkspace {
             execution,
             paths,
             duration: Some(result.duration),
             configuration_files: result.configuration_files,
             project_key: open_project_result.project_key,
+            cache,
+            baseline,
+            changed_lines,
         })
     }
 
@@ -603,6 +712,27 @@ pub(crate) trait CommandRunner {
         configuration: &Configuration,
     ) -> Result<Vec<OsString>, CliDiagnostic>;
 
+    /// Returns the lines that changed in the files to process, when only the diagnostics on those
+    /// lines should be reported.
+    fn get_changed_lines(
+        &self,
+        _fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        Ok(None)
+    }
+
+    /// Whether the command reuses the results of the previous run, with `--cache`.
+    fn use_cache(&self) -> bool {
+        false
+    }
+
+    /// Returns the baseline options of the command, when its diagnostics can be recorded in a
+    /// baseline.
+    fn baseline_options(&self) -> Option<BaselineOptions> {
+        None
+    }
+
     /// Returns the [Execution] mode.
     fn get_execution(
         &self,
@@ -666,4 +796,10 @@ pub(crate) struct ConfiguredWorkspace {
     pub configuration_files: Vec<BiomePath>,
     /// The unique identifier of the project
     pub project_key: ProjectKey,
+    /// The cache of the previous run, when `--cache` is used
+    pub cache: Option<RunCache>,
+    /// The baseline of the diagnostics, when one exists or `--write-baseline` is used
+    pub baseline: Option<Baseline>,
+    /// The lines added or modified in the files to process, when `--changed-lines` is used
+    pub changed_lines: Option<Arc<ChangedLines>>,
 }
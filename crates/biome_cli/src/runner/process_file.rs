@@ -83,6 +83,10 @@ pub(crate) enum Message {
 #[derive(Debug)]
 pub(crate) enum DiffKind {
     Format,
+    /// The rewrites of a GritQL pattern
+    Rewrite,
+    /// The stored snapshot of a plugin fixture, compared to the output of the plugin
+    Snapshot,
 }
 
 impl<D> From<D> for Message
@@ -215,6 +219,21 @@ pub(crate) trait ProcessFile: Send + Sync + std::panic::RefUnwindSafe {
             };
         }
 
+        // Files that can't be read aren't cached, the error is reported when they are opened
+        let cache = ctx.cache().and_then(|cache| {
+            let content = ctx.fs().read_file_from_path(biome_path).ok()?;
+            Some((cache, content))
+        });
+        if let Some((cache, content)) = &cache
+            && let Some(entry) = cache.get(biome_path, content)
+        {
+            if let Some(message) = entry.to_message(biome_path, content) {
+                ctx.push_message(message);
+            }
+            cache.finish(biome_path, content, true);
+            return Ok(FileStatus::Unchanged);
+        }
+
         let mut workspace_file = WorkspaceFile::new(ctx, biome_path.clone())?;
         let result = workspace_file.guard().check_file_size()?;
         if result.is_too_large() {
@@ -226,13 +245,44 @@ pub(crate) trait ProcessFile: Send + Sync + std::panic::RefUnwindSafe {
             return Ok(FileStatus::Ignored);
         }
 
-        Self::process_file(
+        // The diagnostics recorded in the baseline, or outside the changed lines, are only removed
+        // once they have been pulled, so they must not count towards the maximum
+        let max_diagnostics = if ctx.baseline().is_some() || ctx.changed_lines().is_some() {
+            u32::MAX
+        } else {
+            max_diagnostics
+        };
+        // The baseline records the diagnostics of every severity, so `--diagnostic-level` doesn't
+        // remove entries from it. The collector still hides the diagnostics below the level.
+        let diagnostic_level = if ctx.baseline().is_some() {
+            Severity::Hint
+        } else {
+            diagnostic_level
+        };
+        let result = Self::process_file(
             ctx,
             &mut workspace_file,
             &file_features,
             max_diagnostics,
             diagnostic_level,
-        )
+        );
+        if let Some(baseline) = ctx.baseline()
+            && matches!(
+                result,
+                Ok(FileStatus::Changed(_) | FileStatus::Unchanged | FileStatus::Message(_))
+            )
+            && let Some(message) = baseline.finish(biome_path)
+        {
+            ctx.push_message(message);
+        }
+        if let Some((cache, content)) = &cache {
+            cache.finish(
+                biome_path,
+                content,
+                matches!(result, Ok(FileStatus::Unchanged)),
+            );
+        }
+        result
     }
 
     fn should_skip_ignore_check(biome_path: &BiomePath, workspace: &dyn Workspace) -> bool {
@@ -329,6 +379,9 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
 #[cfg(test)]
 mod tests {
     use super::{ChangedFile, FileStatus, ProcessFile, WorkspaceFile};
+    use crate::changed::ChangedLines;
+    use crate::runner::baseline::Baseline;
+    use crate::runner::cache::RunCache;
     use crate::runner::crawler::CrawlerContext;
     use crate::runner::execution::{AnalyzerSelectors, Execution};
     use crate::runner::process_file::Message;
@@ -440,6 +493,18 @@ mod tests {
         fn get_file_features(&self, path: &BiomePath) -> Option<FeaturesSupported> {
             self.file_features.pin().get(path).cloned()
         }
+
+        fn cache(&self) -> Option<&RunCache> {
+            None
+        }
+
+        fn baseline(&self) -> Option<&Baseline> {
+            None
+        }
+
+        fn changed_lines(&self) -> Option<&ChangedLines> {
+            None
+        }
     }
 
     impl TraversalContext for TestContext<'_> {
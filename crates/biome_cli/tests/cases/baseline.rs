@@ -0,0 +1,151 @@
+use crate::snap_test::markup_to_string;
+use crate::{LINT_ERROR, run_cli};
+use biome_console::{BufferConsole, markup};
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const BASELINE_PATH: &str = "biome-baseline.json";
+
+fn lint(fs: MemoryFileSystem, arguments: &[&str]) -> (MemoryFileSystem, bool, String) {
+    let mut console = BufferConsole::default();
+    let mut all_arguments = vec!["lint"];
+    all_arguments.extend_from_slice(arguments);
+    all_arguments.push("check.js");
+
+    let (fs, result) = run_cli(fs, &mut console, Args::from(all_arguments.as_slice()));
+    let output = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{ message.content }}))
+        .collect();
+    (fs, result.is_ok(), output)
+}
+
+#[test]
+fn write_baseline_records_the_diagnostics() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline"]);
+    assert!(is_ok, "{output}");
+    assert!(output.contains("records 1 diagnostics"), "{output}");
+
+    let baseline = fs
+        .read_file_from_path(Utf8Path::new(BASELINE_PATH))
+        .unwrap();
+    assert!(baseline.contains("\"check.js\""), "{baseline}");
+    assert!(
+        baseline.contains("lint/correctness/noConstantCondition"),
+        "{baseline}"
+    );
+}
+
+#[test]
+fn baseline_hides_the_recorded_diagnostics() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline"]);
+    assert!(is_ok, "{output}");
+
+    let (fs, is_ok, output) = lint(fs, &[]);
+    assert!(is_ok, "{output}");
+    assert!(!output.contains("noConstantCondition"), "{output}");
+
+    // The recorded diagnostic moved, and the same diagnostic was added
+    fs.insert(
+        Utf8Path::new("check.js").into(),
+        format!("\n{LINT_ERROR}{LINT_ERROR}").as_bytes(),
+    );
+    let (_, is_ok, output) = lint(fs, &[]);
+    assert!(!is_ok, "{output}");
+    assert!(output.contains("Found 1 error."), "{output}");
+}
+
+#[test]
+fn baseline_reports_the_fixed_diagnostics() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline"]);
+    assert!(is_ok, "{output}");
+
+    fs.insert(Utf8Path::new("check.js").into(), "foo();\n".as_bytes());
+    let (fs, is_ok, output) = lint(fs, &[]);
+    assert!(is_ok, "{output}");
+    assert!(
+        output.contains("Some diagnostics recorded in the baseline have been fixed."),
+        "{output}"
+    );
+    assert!(
+        output.contains("lint/correctness/noConstantCondition (1)"),
+        "{output}"
+    );
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline"]);
+    assert!(is_ok, "{output}");
+    assert!(output.contains("records 0 diagnostics"), "{output}");
+    let baseline = fs
+        .read_file_from_path(Utf8Path::new(BASELINE_PATH))
+        .unwrap();
+    assert!(!baseline.contains("check.js"), "{baseline}");
+}
+
+#[test]
+fn write_baseline_keeps_the_entries_of_the_rules_that_didnt_run() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8Path::new("check.js").into(),
+        format!("debugger;\n{LINT_ERROR}").as_bytes(),
+    );
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline"]);
+    assert!(is_ok, "{output}");
+    assert!(output.contains("records 2 diagnostics"), "{output}");
+
+    let (fs, is_ok, output) = lint(
+        fs,
+        &["--write-baseline", "--only=correctness/noConstantCondition"],
+    );
+    assert!(is_ok, "{output}");
+    assert!(output.contains("records 2 diagnostics"), "{output}");
+    let baseline = fs
+        .read_file_from_path(Utf8Path::new(BASELINE_PATH))
+        .unwrap();
+    assert!(
+        baseline.contains("lint/suspicious/noDebugger"),
+        "{baseline}"
+    );
+    assert!(
+        baseline.contains("lint/correctness/noConstantCondition"),
+        "{baseline}"
+    );
+
+    // The entries of the rules that didn't run aren't reported as fixed
+    let (_, is_ok, output) = lint(fs, &["--only=correctness/noConstantCondition"]);
+    assert!(is_ok, "{output}");
+    assert!(!output.contains("have been fixed"), "{output}");
+}
+
+#[test]
+fn baseline_must_exist_when_its_path_is_given() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let (_, is_ok, output) = lint(fs, &["--baseline=missing.json"]);
+    assert!(!is_ok, "{output}");
+}
+
+#[test]
+fn write_baseline_is_incompatible_with_write() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let (fs, is_ok, output) = lint(fs, &["--write-baseline", "--write"]);
+    assert!(!is_ok, "{output}");
+    assert!(
+        fs.read_file_from_path(Utf8Path::new(BASELINE_PATH))
+            .is_err()
+    );
+}
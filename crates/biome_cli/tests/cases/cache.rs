@@ -0,0 +1,97 @@
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use crate::{LINT_ERROR, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::{Utf8Path, Utf8PathBuf};
+
+/// Returns the cache files written by `--cache`.
+fn cache_files(fs: &MemoryFileSystem) -> Vec<Utf8PathBuf> {
+    fs.files
+        .read()
+        .keys()
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.starts_with("biome-cache-"))
+        })
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn cache_replays_diagnostics_of_unchanged_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache_files = cache_files(&fs);
+    assert_eq!(cache_files.len(), 1);
+    let cache = fs.read_file_from_path(&cache_files[0]).unwrap();
+    assert!(cache.contains("check.js"), "{cache}");
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The path of the cache depends on the machine
+    fs.remove(&cache_files[0]);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_replays_diagnostics_of_unchanged_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_processes_changed_files() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "foo();\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    for path in cache_files(&fs) {
+        fs.remove(&path);
+    }
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_processes_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,138 @@
+use crate::snap_test::markup_to_string;
+use crate::{LINT_ERROR, run_cli};
+use biome_console::{BufferConsole, markup};
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const FILE_PATH: &str = "check.js";
+
+/// A diff where the second line of the file was added.
+const SECOND_LINE_ADDED: &str = "\
+diff --git a/check.js b/check.js
+--- check.js
++++ check.js
+@@ -1,0 +2 @@
++for(;true;);
+";
+
+fn run(fs: MemoryFileSystem, arguments: &[&str]) -> (MemoryFileSystem, bool, String) {
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(fs, &mut console, Args::from(arguments));
+    let output = console
+        .out_buffer
+        .iter()
+        .chain(console.err_buffer.iter())
+        .map(|message| markup_to_string(markup! {{ message.content }}))
+        .collect();
+    (fs, result.is_ok(), output)
+}
+
+fn changed_fs(content: &str) -> MemoryFileSystem {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new(FILE_PATH).into(), content.as_bytes());
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from(FILE_PATH)]));
+    fs.set_on_get_changed_diff(Box::new(|| String::from(SECOND_LINE_ADDED)));
+    fs
+}
+
+#[test]
+fn reports_only_the_diagnostics_on_changed_lines() {
+    let fs = changed_fs(&format!("{LINT_ERROR}{LINT_ERROR}"));
+
+    let (_, is_ok, output) = run(
+        fs,
+        &["lint", "--changed", "--since=main", "--changed-lines"],
+    );
+    assert!(!is_ok, "{output}");
+    assert!(output.contains("Found 1 error."), "{output}");
+    assert!(output.contains("check.js:2:"), "{output}");
+    assert!(!output.contains("check.js:1:"), "{output}");
+}
+
+#[test]
+fn reports_nothing_when_the_changed_lines_have_no_diagnostics() {
+    let fs = changed_fs(&format!("{LINT_ERROR}foo();\n"));
+
+    let (_, is_ok, output) = run(
+        fs,
+        &["lint", "--changed", "--since=main", "--changed-lines"],
+    );
+    assert!(is_ok, "{output}");
+    assert!(!output.contains("noConstantCondition"), "{output}");
+}
+
+#[test]
+fn reports_only_the_diagnostics_on_staged_lines() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8Path::new(FILE_PATH).into(),
+        format!("{LINT_ERROR}{LINT_ERROR}").as_bytes(),
+    );
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from(FILE_PATH)]));
+    fs.set_on_get_staged_diff(Box::new(|| String::from(SECOND_LINE_ADDED)));
+
+    let (_, is_ok, output) = run(fs, &["lint", "--staged", "--changed-lines"]);
+    assert!(!is_ok, "{output}");
+    assert!(output.contains("Found 1 error."), "{output}");
+}
+
+#[test]
+fn formats_only_the_changed_lines() {
+    let fs = changed_fs("a(  );\nb(  );\n");
+
+    let (fs, is_ok, output) = run(
+        fs,
+        &[
+            "format",
+            "--write",
+            "--changed",
+            "--since=main",
+            "--changed-lines",
+        ],
+    );
+    assert!(is_ok, "{output}");
+    let content = fs.read_file_from_path(Utf8Path::new(FILE_PATH)).unwrap();
+    assert_eq!(content, "a(  );\nb();\n");
+}
+
+#[test]
+fn formats_the_changed_lines_of_the_same_statement() {
+    let mut fs = changed_fs("call(\n  a  ,\n  b  ,\n  c  ,\n);\n");
+    fs.set_on_get_changed_diff(Box::new(|| {
+        String::from(
+            "\
+diff --git a/check.js b/check.js
+--- check.js
++++ check.js
+@@ -1,0 +2 @@
++  a  ,
+@@ -2,0 +4 @@
++  c  ,
+",
+        )
+    }));
+
+    let (fs, is_ok, output) = run(
+        fs,
+        &[
+            "format",
+            "--write",
+            "--changed",
+            "--since=main",
+            "--changed-lines",
+        ],
+    );
+    assert!(is_ok, "{output}");
+    let content = fs.read_file_from_path(Utf8Path::new(FILE_PATH)).unwrap();
+    assert!(!content.contains("a  ,"), "{content}");
+    assert!(!content.contains("c  ,"), "{content}");
+}
+
+#[test]
+fn changed_lines_requires_changed_or_staged() {
+    let fs = changed_fs(LINT_ERROR);
+
+    let (_, is_ok, output) = run(fs, &["lint", "--changed-lines", FILE_PATH]);
+    assert!(!is_ok, "{output}");
+}
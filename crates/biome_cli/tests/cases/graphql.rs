@@ -103,3 +103,107 @@ fn lint_single_rule() {
         result,
     ));
 }
+
+const SCHEMA: &str = r#"type Query {
+  hero: Character
+}
+
+type Character {
+  name: String
+}
+"#;
+
+#[test]
+fn lint_operations_against_schema() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "graphql": {
+        "schema": ["schema.graphql"]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(Utf8Path::new("schema.graphql").into(), SCHEMA.as_bytes());
+
+    let file_path = Utf8Path::new("file.graphql");
+    fs.insert(
+        file_path.into(),
+        "query Hero {\n  hero {\n    name\n    age\n  }\n}\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=nursery/noUnknownGraphqlField",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_operations_against_schema",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_embedded_operations_against_schema() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "graphql": {
+        "schema": ["schema.graphql"]
+    },
+    "javascript": {
+        "experimentalEmbeddedSnippetsEnabled": true
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(Utf8Path::new("schema.graphql").into(), SCHEMA.as_bytes());
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "export const HERO = gql`query Hero { hero { name age } }`;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=nursery/noUnknownGraphqlField",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_embedded_operations_against_schema",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2,7 +2,10 @@
 //! case that affects many commands
 
 mod assist;
+mod baseline;
 mod biome_json_support;
+mod cache;
+mod changed_lines;
 mod config_extends;
 mod config_path;
 mod configuration;
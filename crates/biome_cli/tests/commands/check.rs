@@ -3413,6 +3413,55 @@ fn check_json_plugin() {
     ));
 }
 
+#[test]
+fn check_yaml_plugin() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8PathBuf::from("biome.json"),
+        br#"{
+    "plugins": ["noLatestTag.grit"],
+    "formatter": {
+        "enabled": false
+    }
+}"#,
+    );
+
+    fs.insert(
+        Utf8PathBuf::from("noLatestTag.grit"),
+        br#"language yaml
+
+YamlPlainScalar() as $val where {
+    $val <: r"latest",
+    register_diagnostic(span = $val, message = "Pin the image to a specific tag.", severity = "error")
+}
+"#,
+    );
+
+    let file_path = "compose.yaml";
+    fs.insert(
+        file_path.into(),
+        br#"image: latest
+ports: 8080
+"#,
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["check", file_path].as_slice()),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_yaml_plugin",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn check_js_plugin_with_native_field_names() {
     let fs = MemoryFileSystem::default();
@@ -42,6 +42,26 @@ fn enables_vcs_and_ignore_dist() {
     ));
 }
 
+#[test]
+fn enables_vcs_for_jujutsu() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(".gitignore".into(), "".as_bytes());
+    fs.insert(".jj/repo/store/type".into(), "git".as_bytes());
+
+    let (fs, result) = run_cli(fs, &mut console, Args::from(["init"].as_slice()));
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "enables_vcs_for_jujutsu",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn creates_config_jsonc_file() {
     let fs = MemoryFileSystem::default();
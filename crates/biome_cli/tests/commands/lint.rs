@@ -2550,6 +2550,39 @@ fn should_error_if_changed_flag_and_staged_flag_are_active_at_the_same_time() {
     ));
 }
 
+#[test]
+fn should_error_if_staged_flag_is_used_with_jujutsu() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("file.js")]));
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "clientKind": "jujutsu" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        r#"console.log('file');"#.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--staged"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_staged_flag_is_used_with_jujutsu",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_only_processes_staged_files_when_staged_flag_is_set() {
     let mut console = BufferConsole::default();
@@ -1013,3 +1013,47 @@ fn migrate_vitest_consistent_test_it_with_options() {
         result,
     ));
 }
+
+#[test]
+fn migrate_eslint_flat_config_ts() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let eslintconfig = r#"import { defineConfig } from "eslint/config";
+
+export default defineConfig([
+    {
+        rules: {
+            eqeqeq: "warn",
+            "no-eval": "error",
+        },
+    },
+    {
+        files: ["scripts/**/*.ts"],
+        rules: {
+            eqeqeq: "off",
+        },
+    },
+]);"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Utf8Path::new("./eslint.config.ts").into(),
+        eslintconfig.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["migrate", "eslint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslint_flat_config_ts",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -7,6 +7,8 @@ mod lint;
 mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
+mod migrate_stylelint;
+mod plugin;
 mod rage;
 mod search;
 mod version;
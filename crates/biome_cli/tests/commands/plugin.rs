@@ -0,0 +1,108 @@
+use crate::run_cli_with_server_workspace;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::{Utf8Path, Utf8PathBuf};
+
+const PLUGIN_CONTENT: &[u8] = br#"language js
+
+`console.log($msg)` as $call where {
+    register_diagnostic(span = $call, message = "Use console.info instead of console.log.", severity = "warn", fix_kind = "safe"),
+    $call => `console.info($msg)`
+}
+"#;
+
+const VALID_SNAPSHOT: &str = "# Input
+```js
+console.info(\"hello\");
+
+```
+
+";
+
+#[test]
+fn plugin_test_updates_snapshots() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8PathBuf::from("useConsoleInfo.grit"), PLUGIN_CONTENT);
+    fs.insert(
+        Utf8PathBuf::from("tests/invalid.js"),
+        b"console.log(\"hello\");\n",
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["plugin", "test", "--update", "useConsoleInfo.grit"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_test_updates_snapshots",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_test_reports_missing_snapshots() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8PathBuf::from("useConsoleInfo.grit"), PLUGIN_CONTENT);
+    fs.insert(
+        Utf8PathBuf::from("tests/valid.js"),
+        b"console.info(\"hello\");\n",
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["plugin", "test", "useConsoleInfo.grit"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_test_reports_missing_snapshots",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_test_passes_with_matching_snapshots() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8PathBuf::from("useConsoleInfo.grit"), PLUGIN_CONTENT);
+    fs.insert(
+        Utf8PathBuf::from("fixtures/valid.js"),
+        b"console.info(\"hello\");\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("fixtures/valid.js.snap"),
+        VALID_SNAPSHOT.as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["plugin", "test", "useConsoleInfo.grit", "fixtures"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, Utf8Path::new("fixtures/valid.js.snap"), VALID_SNAPSHOT);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_test_passes_with_matching_snapshots",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -107,6 +107,7 @@ pub enum RuleName {
     NoAutofocus,
     NoAwaitInLoops,
     NoBannedTypes,
+    NoBareUrls,
     NoBarrelFile,
     NoBaseToString,
     NoBeforeInteractiveScriptOutsideDocument,
@@ -163,8 +164,10 @@ pub enum RuleName {
     NoDuplicateFields,
     NoDuplicateFontNames,
     NoDuplicateGraphqlOperationName,
+    NoDuplicateHeadings,
     NoDuplicateInputFieldNames,
     NoDuplicateJsxProps,
+    NoDuplicateMappingKeys,
     NoDuplicateObjectKeys,
     NoDuplicateParameters,
     NoDuplicateProperties,
@@ -233,7 +236,9 @@ pub enum RuleName {
     NoInvalidBuiltinInstantiation,
     NoInvalidConstructorSuper,
     NoInvalidDirectionInLinearGradient,
+    NoInvalidGraphqlArgumentValue,
     NoInvalidGridAreas,
+    NoInvalidLinkFragments,
     NoInvalidPositionAtImportRule,
     NoInvalidPropertyInitValue,
     NoInvalidUseBeforeDeclaration,
@@ -345,16 +350,24 @@ pub enum RuleName {
     NoThenProperty,
     NoThisInStatic,
     NoTopLevelLiterals,
+    NoTrailingSpaces,
+    NoTruthyScalars,
     NoTsIgnore,
     NoUnassignedVariables,
+    NoUndeclaredAliases,
     NoUndeclaredClasses,
     NoUndeclaredCustomProperties,
     NoUndeclaredDependencies,
     NoUndeclaredEnvVars,
     NoUndeclaredVariables,
+    NoUndefinedGraphqlFragment,
+    NoUndefinedGraphqlVariable,
     NoUnknownAtRules,
     NoUnknownAttribute,
     NoUnknownFunction,
+    NoUnknownGraphqlArgument,
+    NoUnknownGraphqlField,
+    NoUnknownGraphqlType,
     NoUnknownMediaFeatureName,
     NoUnknownProperty,
     NoUnknownPseudoClass,
@@ -365,6 +378,7 @@ pub enum RuleName {
     NoUnnecessaryConditions,
     NoUnnecessaryTemplateExpression,
     NoUnreachable,
+    NoUnreachableFiles,
     NoUnreachableSuper,
     NoUnresolvedImports,
     NoUnsafeDeclarationMerging,
@@ -375,6 +389,8 @@ pub enum RuleName {
     NoUnsafeTypeAssertion,
     NoUntrustedLicenses,
     NoUnusedClasses,
+    NoUnusedDependencies,
+    NoUnusedExports,
     NoUnusedExpressions,
     NoUnusedFunctionParameters,
     NoUnusedImports,
@@ -455,6 +471,7 @@ pub enum RuleName {
     UseConsistentEnumValueType,
     UseConsistentGraphqlDescriptions,
     UseConsistentHeadingLevel,
+    UseConsistentListMarker,
     UseConsistentMemberAccessibility,
     UseConsistentMethodSignatures,
     UseConsistentObjectDefinitions,
@@ -484,6 +501,7 @@ pub enum RuleName {
     UseExponentiationOperator,
     UseExportType,
     UseExportsLast,
+    UseFencedCodeLanguage,
     UseFilenamingConvention,
     UseFlatMap,
     UseFocusableInteractive,
@@ -496,6 +514,7 @@ pub enum RuleName {
     UseGoogleFontPreconnect,
     UseGraphqlNamedOperations,
     UseGraphqlNamingConvention,
+    UseGraphqlRequiredArguments,
     UseGroupedAccessorPairs,
     UseGuardForIn,
     UseHeadingContent,
@@ -503,6 +522,7 @@ pub enum RuleName {
     UseHtmlLang,
     UseIframeSandbox,
     UseIframeTitle,
+    UseImageAltText,
     UseImageSize,
     UseImportExtensions,
     UseImportType,
@@ -637,6 +657,7 @@ impl RuleName {
             Self::NoAutofocus => "noAutofocus",
             Self::NoAwaitInLoops => "noAwaitInLoops",
             Self::NoBannedTypes => "noBannedTypes",
+            Self::NoBareUrls => "noBareUrls",
             Self::NoBarrelFile => "noBarrelFile",
             Self::NoBaseToString => "noBaseToString",
             Self::NoBeforeInteractiveScriptOutsideDocument => {
@@ -695,8 +716,10 @@ impl RuleName {
             Self::NoDuplicateFields => "noDuplicateFields",
             Self::NoDuplicateFontNames => "noDuplicateFontNames",
             Self::NoDuplicateGraphqlOperationName => "noDuplicateGraphqlOperationName",
+            Self::NoDuplicateHeadings => "noDuplicateHeadings",
             Self::NoDuplicateInputFieldNames => "noDuplicateInputFieldNames",
             Self::NoDuplicateJsxProps => "noDuplicateJsxProps",
+            Self::NoDuplicateMappingKeys => "noDuplicateMappingKeys",
             Self::NoDuplicateObjectKeys => "noDuplicateObjectKeys",
             Self::NoDuplicateParameters => "noDuplicateParameters",
             Self::NoDuplicateProperties => "noDuplicateProperties",
@@ -767,7 +790,9 @@ impl RuleName {
             Self::NoInvalidBuiltinInstantiation => "noInvalidBuiltinInstantiation",
             Self::NoInvalidConstructorSuper => "noInvalidConstructorSuper",
             Self::NoInvalidDirectionInLinearGradient => "noInvalidDirectionInLinearGradient",
+            Self::NoInvalidGraphqlArgumentValue => "noInvalidGraphqlArgumentValue",
             Self::NoInvalidGridAreas => "noInvalidGridAreas",
+            Self::NoInvalidLinkFragments => "noInvalidLinkFragments",
             Self::NoInvalidPositionAtImportRule => "noInvalidPositionAtImportRule",
             Self::NoInvalidPropertyInitValue => "noInvalidPropertyInitValue",
             Self::NoInvalidUseBeforeDeclaration => "noInvalidUseBeforeDeclaration",
@@ -881,16 +906,24 @@ impl RuleName {
             Self::NoThenProperty => "noThenProperty",
             Self::NoThisInStatic => "noThisInStatic",
             Self::NoTopLevelLiterals => "noTopLevelLiterals",
+            Self::NoTrailingSpaces => "noTrailingSpaces",
+            Self::NoTruthyScalars => "noTruthyScalars",
             Self::NoTsIgnore => "noTsIgnore",
             Self::NoUnassignedVariables => "noUnassignedVariables",
+            Self::NoUndeclaredAliases => "noUndeclaredAliases",
             Self::NoUndeclaredClasses => "noUndeclaredClasses",
             Self::NoUndeclaredCustomProperties => "noUndeclaredCustomProperties",
             Self::NoUndeclaredDependencies => "noUndeclaredDependencies",
             Self::NoUndeclaredEnvVars => "noUndeclaredEnvVars",
             Self::NoUndeclaredVariables => "noUndeclaredVariables",
+            Self::NoUndefinedGraphqlFragment => "noUndefinedGraphqlFragment",
+            Self::NoUndefinedGraphqlVariable => "noUndefinedGraphqlVariable",
             Self::NoUnknownAtRules => "noUnknownAtRules",
             Self::NoUnknownAttribute => "noUnknownAttribute",
             Self::NoUnknownFunction => "noUnknownFunction",
+            Self::NoUnknownGraphqlArgument => "noUnknownGraphqlArgument",
+            Self::NoUnknownGraphqlField => "noUnknownGraphqlField",
+            Self::NoUnknownGraphqlType => "noUnknownGraphqlType",
             Self::NoUnknownMediaFeatureName => "noUnknownMediaFeatureName",
             Self::NoUnknownProperty => "noUnknownProperty",
             Self::NoUnknownPseudoClass => "noUnknownPseudoClass",
@@ -901,6 +934,7 @@ impl RuleName {
             Self::NoUnnecessaryConditions => "noUnnecessaryConditions",
             Self::NoUnnecessaryTemplateExpression => "noUnnecessaryTemplateExpression",
             Self::NoUnreachable => "noUnreachable",
+            Self::NoUnreachableFiles => "noUnreachableFiles",
             Self::NoUnreachableSuper => "noUnreachableSuper",
             Self::NoUnresolvedImports => "noUnresolvedImports",
             Self::NoUnsafeDeclarationMerging => "noUnsafeDeclarationMerging",
@@ -911,6 +945,8 @@ impl RuleName {
             Self::NoUnsafeTypeAssertion => "noUnsafeTypeAssertion",
             Self::NoUntrustedLicenses => "noUntrustedLicenses",
             Self::NoUnusedClasses => "noUnusedClasses",
+            Self::NoUnusedDependencies => "noUnusedDependencies",
+            Self::NoUnusedExports => "noUnusedExports",
             Self::NoUnusedExpressions => "noUnusedExpressions",
             Self::NoUnusedFunctionParameters => "noUnusedFunctionParameters",
             Self::NoUnusedImports => "noUnusedImports",
@@ -991,6 +1027,7 @@ impl RuleName {
             Self::UseConsistentEnumValueType => "useConsistentEnumValueType",
             Self::UseConsistentGraphqlDescriptions => "useConsistentGraphqlDescriptions",
             Self::UseConsistentHeadingLevel => "useConsistentHeadingLevel",
+            Self::UseConsistentListMarker => "useConsistentListMarker",
             Self::UseConsistentMemberAccessibility => "useConsistentMemberAccessibility",
             Self::UseConsistentMethodSignatures => "useConsistentMethodSignatures",
             Self::UseConsistentObjectDefinitions => "useConsistentObjectDefinitions",
@@ -1020,6 +1057,7 @@ impl RuleName {
             Self::UseExponentiationOperator => "useExponentiationOperator",
             Self::UseExportType => "useExportType",
             Self::UseExportsLast => "useExportsLast",
+            Self::UseFencedCodeLanguage => "useFencedCodeLanguage",
             Self::UseFilenamingConvention => "useFilenamingConvention",
             Self::UseFlatMap => "useFlatMap",
             Self::UseFocusableInteractive => "useFocusableInteractive",
@@ -1032,6 +1070,7 @@ impl RuleName {
             Self::UseGoogleFontPreconnect => "useGoogleFontPreconnect",
             Self::UseGraphqlNamedOperations => "useGraphqlNamedOperations",
             Self::UseGraphqlNamingConvention => "useGraphqlNamingConvention",
+            Self::UseGraphqlRequiredArguments => "useGraphqlRequiredArguments",
             Self::UseGroupedAccessorPairs => "useGroupedAccessorPairs",
             Self::UseGuardForIn => "useGuardForIn",
             Self::UseHeadingContent => "useHeadingContent",
@@ -1039,6 +1078,7 @@ impl RuleName {
             Self::UseHtmlLang => "useHtmlLang",
             Self::UseIframeSandbox => "useIframeSandbox",
             Self::UseIframeTitle => "useIframeTitle",
+            Self::UseImageAltText => "useImageAltText",
             Self::UseImageSize => "useImageSize",
             Self::UseImportExtensions => "useImportExtensions",
             Self::UseImportType => "useImportType",
@@ -1175,6 +1215,7 @@ impl RuleName {
             Self::NoAutofocus => RuleGroup::A11y,
             Self::NoAwaitInLoops => RuleGroup::Performance,
             Self::NoBannedTypes => RuleGroup::Complexity,
+            Self::NoBareUrls => RuleGroup::Nursery,
             Self::NoBarrelFile => RuleGroup::Performance,
             Self::NoBaseToString => RuleGroup::Nursery,
             Self::NoBeforeInteractiveScriptOutsideDocument => RuleGroup::Correctness,
@@ -1231,8 +1272,10 @@ impl RuleName {
             Self::NoDuplicateFields => RuleGroup::Suspicious,
             Self::NoDuplicateFontNames => RuleGroup::Suspicious,
             Self::NoDuplicateGraphqlOperationName => RuleGroup::Suspicious,
+            Self::NoDuplicateHeadings => RuleGroup::Nursery,
             Self::NoDuplicateInputFieldNames => RuleGroup::Correctness,
             Self::NoDuplicateJsxProps => RuleGroup::Suspicious,
+            Self::NoDuplicateMappingKeys => RuleGroup::Nursery,
             Self::NoDuplicateObjectKeys => RuleGroup::Suspicious,
             Self::NoDuplicateParameters => RuleGroup::Suspicious,
             Self::NoDuplicateProperties => RuleGroup::Suspicious,
@@ -1301,7 +1344,9 @@ impl RuleName {
             Self::NoInvalidBuiltinInstantiation => RuleGroup::Correctness,
             Self::NoInvalidConstructorSuper => RuleGroup::Correctness,
             Self::NoInvalidDirectionInLinearGradient => RuleGroup::Correctness,
+            Self::NoInvalidGraphqlArgumentValue => RuleGroup::Nursery,
             Self::NoInvalidGridAreas => RuleGroup::Correctness,
+            Self::NoInvalidLinkFragments => RuleGroup::Nursery,
             Self::NoInvalidPositionAtImportRule => RuleGroup::Correctness,
             Self::NoInvalidPropertyInitValue => RuleGroup::Nursery,
             Self::NoInvalidUseBeforeDeclaration => RuleGroup::Correctness,
@@ -1413,16 +1458,24 @@ impl RuleName {
             Self::NoThenProperty => RuleGroup::Suspicious,
             Self::NoThisInStatic => RuleGroup::Complexity,
             Self::NoTopLevelLiterals => RuleGroup::Nursery,
+            Self::NoTrailingSpaces => RuleGroup::Nursery,
+            Self::NoTruthyScalars => RuleGroup::Nursery,
             Self::NoTsIgnore => RuleGroup::Suspicious,
             Self::NoUnassignedVariables => RuleGroup::Suspicious,
+            Self::NoUndeclaredAliases => RuleGroup::Nursery,
             Self::NoUndeclaredClasses => RuleGroup::Nursery,
             Self::NoUndeclaredCustomProperties => RuleGroup::Nursery,
             Self::NoUndeclaredDependencies => RuleGroup::Correctness,
             Self::NoUndeclaredEnvVars => RuleGroup::Suspicious,
             Self::NoUndeclaredVariables => RuleGroup::Correctness,
+            Self::NoUndefinedGraphqlFragment => RuleGroup::Nursery,
+            Self::NoUndefinedGraphqlVariable => RuleGroup::Nursery,
             Self::NoUnknownAtRules => RuleGroup::Suspicious,
             Self::NoUnknownAttribute => RuleGroup::Suspicious,
             Self::NoUnknownFunction => RuleGroup::Correctness,
+            Self::NoUnknownGraphqlArgument => RuleGroup::Nursery,
+            Self::NoUnknownGraphqlField => RuleGroup::Nursery,
+            Self::NoUnknownGraphqlType => RuleGroup::Nursery,
             Self::NoUnknownMediaFeatureName => RuleGroup::Correctness,
             Self::NoUnknownProperty => RuleGroup::Correctness,
             Self::NoUnknownPseudoClass => RuleGroup::Correctness,
@@ -1433,6 +1486,7 @@ impl RuleName {
             Self::NoUnnecessaryConditions => RuleGroup::Suspicious,
             Self::NoUnnecessaryTemplateExpression => RuleGroup::Nursery,
             Self::NoUnreachable => RuleGroup::Correctness,
+            Self::NoUnreachableFiles => RuleGroup::Nursery,
             Self::NoUnreachableSuper => RuleGroup::Correctness,
             Self::NoUnresolvedImports => RuleGroup::Correctness,
             Self::NoUnsafeDeclarationMerging => RuleGroup::Suspicious,
@@ -1443,6 +1497,8 @@ impl RuleName {
             Self::NoUnsafeTypeAssertion => RuleGroup::Nursery,
             Self::NoUntrustedLicenses => RuleGroup::Nursery,
             Self::NoUnusedClasses => RuleGroup::Nursery,
+            Self::NoUnusedDependencies => RuleGroup::Nursery,
+            Self::NoUnusedExports => RuleGroup::Nursery,
             Self::NoUnusedExpressions => RuleGroup::Suspicious,
             Self::NoUnusedFunctionParameters => RuleGroup::Correctness,
             Self::NoUnusedImports => RuleGroup::Correctness,
@@ -1523,6 +1579,7 @@ impl RuleName {
             Self::UseConsistentEnumValueType => RuleGroup::Style,
             Self::UseConsistentGraphqlDescriptions => RuleGroup::Style,
             Self::UseConsistentHeadingLevel => RuleGroup::Nursery,
+            Self::UseConsistentListMarker => RuleGroup::Nursery,
             Self::UseConsistentMemberAccessibility => RuleGroup::Style,
             Self::UseConsistentMethodSignatures => RuleGroup::Style,
             Self::UseConsistentObjectDefinitions => RuleGroup::Style,
@@ -1552,6 +1609,7 @@ impl RuleName {
             Self::UseExponentiationOperator => RuleGroup::Style,
             Self::UseExportType => RuleGroup::Style,
             Self::UseExportsLast => RuleGroup::Style,
+            Self::UseFencedCodeLanguage => RuleGroup::Nursery,
             Self::UseFilenamingConvention => RuleGroup::Style,
             Self::UseFlatMap => RuleGroup::Complexity,
             Self::UseFocusableInteractive => RuleGroup::A11y,
@@ -1564,6 +1622,7 @@ impl RuleName {
             Self::UseGoogleFontPreconnect => RuleGroup::Performance,
             Self::UseGraphqlNamedOperations => RuleGroup::Correctness,
             Self::UseGraphqlNamingConvention => RuleGroup::Style,
+            Self::UseGraphqlRequiredArguments => RuleGroup::Nursery,
             Self::UseGroupedAccessorPairs => RuleGroup::Style,
             Self::UseGuardForIn => RuleGroup::Suspicious,
             Self::UseHeadingContent => RuleGroup::A11y,
@@ -1571,6 +1630,7 @@ impl RuleName {
             Self::UseHtmlLang => RuleGroup::A11y,
             Self::UseIframeSandbox => RuleGroup::Nursery,
             Self::UseIframeTitle => RuleGroup::A11y,
+            Self::UseImageAltText => RuleGroup::Nursery,
             Self::UseImageSize => RuleGroup::Correctness,
             Self::UseImportExtensions => RuleGroup::Correctness,
             Self::UseImportType => RuleGroup::Style,
@@ -1708,6 +1768,7 @@ impl std::str::FromStr for RuleName {
             "noAutofocus" => Ok(Self::NoAutofocus),
             "noAwaitInLoops" => Ok(Self::NoAwaitInLoops),
             "noBannedTypes" => Ok(Self::NoBannedTypes),
+            "noBareUrls" => Ok(Self::NoBareUrls),
             "noBarrelFile" => Ok(Self::NoBarrelFile),
             "noBaseToString" => Ok(Self::NoBaseToString),
             "noBeforeInteractiveScriptOutsideDocument" => {
@@ -1768,8 +1829,10 @@ impl std::str::FromStr for RuleName {
             "noDuplicateFields" => Ok(Self::NoDuplicateFields),
             "noDuplicateFontNames" => Ok(Self::NoDuplicateFontNames),
             "noDuplicateGraphqlOperationName" => Ok(Self::NoDuplicateGraphqlOperationName),
+            "noDuplicateHeadings" => Ok(Self::NoDuplicateHeadings),
             "noDuplicateInputFieldNames" => Ok(Self::NoDuplicateInputFieldNames),
             "noDuplicateJsxProps" => Ok(Self::NoDuplicateJsxProps),
+            "noDuplicateMappingKeys" => Ok(Self::NoDuplicateMappingKeys),
             "noDuplicateObjectKeys" => Ok(Self::NoDuplicateObjectKeys),
             "noDuplicateParameters" => Ok(Self::NoDuplicateParameters),
             "noDuplicateProperties" => Ok(Self::NoDuplicateProperties),
@@ -1840,7 +1903,9 @@ impl std::str::FromStr for RuleName {
             "noInvalidBuiltinInstantiation" => Ok(Self::NoInvalidBuiltinInstantiation),
             "noInvalidConstructorSuper" => Ok(Self::NoInvalidConstructorSuper),
             "noInvalidDirectionInLinearGradient" => Ok(Self::NoInvalidDirectionInLinearGradient),
+            "noInvalidGraphqlArgumentValue" => Ok(Self::NoInvalidGraphqlArgumentValue),
             "noInvalidGridAreas" => Ok(Self::NoInvalidGridAreas),
+            "noInvalidLinkFragments" => Ok(Self::NoInvalidLinkFragments),
             "noInvalidPositionAtImportRule" => Ok(Self::NoInvalidPositionAtImportRule),
             "noInvalidPropertyInitValue" => Ok(Self::NoInvalidPropertyInitValue),
             "noInvalidUseBeforeDeclaration" => Ok(Self::NoInvalidUseBeforeDeclaration),
@@ -1954,16 +2019,24 @@ impl std::str::FromStr for RuleName {
             "noThenProperty" => Ok(Self::NoThenProperty),
             "noThisInStatic" => Ok(Self::NoThisInStatic),
             "noTopLevelLiterals" => Ok(Self::NoTopLevelLiterals),
+            "noTrailingSpaces" => Ok(Self::NoTrailingSpaces),
+            "noTruthyScalars" => Ok(Self::NoTruthyScalars),
             "noTsIgnore" => Ok(Self::NoTsIgnore),
             "noUnassignedVariables" => Ok(Self::NoUnassignedVariables),
+            "noUndeclaredAliases" => Ok(Self::NoUndeclaredAliases),
             "noUndeclaredClasses" => Ok(Self::NoUndeclaredClasses),
             "noUndeclaredCustomProperties" => Ok(Self::NoUndeclaredCustomProperties),
             "noUndeclaredDependencies" => Ok(Self::NoUndeclaredDependencies),
             "noUndeclaredEnvVars" => Ok(Self::NoUndeclaredEnvVars),
             "noUndeclaredVariables" => Ok(Self::NoUndeclaredVariables),
+            "noUndefinedGraphqlFragment" => Ok(Self::NoUndefinedGraphqlFragment),
+            "noUndefinedGraphqlVariable" => Ok(Self::NoUndefinedGraphqlVariable),
             "noUnknownAtRules" => Ok(Self::NoUnknownAtRules),
             "noUnknownAttribute" => Ok(Self::NoUnknownAttribute),
             "noUnknownFunction" => Ok(Self::NoUnknownFunction),
+            "noUnknownGraphqlArgument" => Ok(Self::NoUnknownGraphqlArgument),
+            "noUnknownGraphqlField" => Ok(Self::NoUnknownGraphqlField),
+            "noUnknownGraphqlType" => Ok(Self::NoUnknownGraphqlType),
             "noUnknownMediaFeatureName" => Ok(Self::NoUnknownMediaFeatureName),
             "noUnknownProperty" => Ok(Self::NoUnknownProperty),
             "noUnknownPseudoClass" => Ok(Self::NoUnknownPseudoClass),
@@ -1974,6 +2047,7 @@ impl std::str::FromStr for RuleName {
             "noUnnecessaryConditions" => Ok(Self::NoUnnecessaryConditions),
             "noUnnecessaryTemplateExpression" => Ok(Self::NoUnnecessaryTemplateExpression),
             "noUnreachable" => Ok(Self::NoUnreachable),
+            "noUnreachableFiles" => Ok(Self::NoUnreachableFiles),
             "noUnreachableSuper" => Ok(Self::NoUnreachableSuper),
             "noUnresolvedImports" => Ok(Self::NoUnresolvedImports),
             "noUnsafeDeclarationMerging" => Ok(Self::NoUnsafeDeclarationMerging),
@@ -1984,6 +2058,8 @@ impl std::str::FromStr for RuleName {
             "noUnsafeTypeAssertion" => Ok(Self::NoUnsafeTypeAssertion),
             "noUntrustedLicenses" => Ok(Self::NoUntrustedLicenses),
             "noUnusedClasses" => Ok(Self::NoUnusedClasses),
+            "noUnusedDependencies" => Ok(Self::NoUnusedDependencies),
+            "noUnusedExports" => Ok(Self::NoUnusedExports),
             "noUnusedExpressions" => Ok(Self::NoUnusedExpressions),
             "noUnusedFunctionParameters" => Ok(Self::NoUnusedFunctionParameters),
             "noUnusedImports" => Ok(Self::NoUnusedImports),
@@ -2064,6 +2140,7 @@ impl std::str::FromStr for RuleName {
             "useConsistentEnumValueType" => Ok(Self::UseConsistentEnumValueType),
             "useConsistentGraphqlDescriptions" => Ok(Self::UseConsistentGraphqlDescriptions),
             "useConsistentHeadingLevel" => Ok(Self::UseConsistentHeadingLevel),
+            "useConsistentListMarker" => Ok(Self::UseConsistentListMarker),
             "useConsistentMemberAccessibility" => Ok(Self::UseConsistentMemberAccessibility),
             "useConsistentMethodSignatures" => Ok(Self::UseConsistentMethodSignatures),
             "useConsistentObjectDefinitions" => Ok(Self::UseConsistentObjectDefinitions),
@@ -2093,6 +2170,7 @@ impl std::str::FromStr for RuleName {
             "useExponentiationOperator" => Ok(Self::UseExponentiationOperator),
             "useExportType" => Ok(Self::UseExportType),
             "useExportsLast" => Ok(Self::UseExportsLast),
+            "useFencedCodeLanguage" => Ok(Self::UseFencedCodeLanguage),
             "useFilenamingConvention" => Ok(Self::UseFilenamingConvention),
             "useFlatMap" => Ok(Self::UseFlatMap),
             "useFocusableInteractive" => Ok(Self::UseFocusableInteractive),
@@ -2105,6 +2183,7 @@ impl std::str::FromStr for RuleName {
             "useGoogleFontPreconnect" => Ok(Self::UseGoogleFontPreconnect),
             "useGraphqlNamedOperations" => Ok(Self::UseGraphqlNamedOperations),
             "useGraphqlNamingConvention" => Ok(Self::UseGraphqlNamingConvention),
+            "useGraphqlRequiredArguments" => Ok(Self::UseGraphqlRequiredArguments),
             "useGroupedAccessorPairs" => Ok(Self::UseGroupedAccessorPairs),
             "useGuardForIn" => Ok(Self::UseGuardForIn),
             "useHeadingContent" => Ok(Self::UseHeadingContent),
@@ -2112,6 +2191,7 @@ impl std::str::FromStr for RuleName {
             "useHtmlLang" => Ok(Self::UseHtmlLang),
             "useIframeSandbox" => Ok(Self::UseIframeSandbox),
             "useIframeTitle" => Ok(Self::UseIframeTitle),
+            "useImageAltText" => Ok(Self::UseImageAltText),
             "useImageSize" => Ok(Self::UseImageSize),
             "useImportExtensions" => Ok(Self::UseImportExtensions),
             "useImportType" => Ok(Self::UseImportType),
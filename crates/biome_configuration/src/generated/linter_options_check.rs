@@ -81,6 +81,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noBannedTypes",
         TypeId::of::<biome_rule_options::no_banned_types::NoBannedTypesOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noBareUrls",
+        TypeId::of::<biome_rule_options::no_bare_urls::NoBareUrlsOptions>(),
+    ));
     result.push((
         "performance",
         "noBarrelFile",
@@ -366,6 +371,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         TypeId::of::<biome_rule_options::no_duplicate_font_names::NoDuplicateFontNamesOptions>(),
     ));
     result.push(("suspicious", "noDuplicateGraphqlOperationName", TypeId::of::<biome_rule_options::no_duplicate_graphql_operation_name::NoDuplicateGraphqlOperationNameOptions>()));
+    result.push((
+        "nursery",
+        "noDuplicateHeadings",
+        TypeId::of::<biome_rule_options::no_duplicate_headings::NoDuplicateHeadingsOptions>(),
+    ));
     result.push((
         "correctness",
         "noDuplicateInputFieldNames",
@@ -378,6 +388,12 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noDuplicateJsxProps",
         TypeId::of::<biome_rule_options::no_duplicate_jsx_props::NoDuplicateJsxPropsOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noDuplicateMappingKeys",
+        TypeId::of::<biome_rule_options::no_duplicate_mapping_keys::NoDuplicateMappingKeysOptions>(
+        ),
+    ));
     result.push((
         "suspicious",
         "noDuplicateObjectKeys",
@@ -692,11 +708,22 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         >(),
     ));
     result.push(("correctness", "noInvalidDirectionInLinearGradient", TypeId::of::<biome_rule_options::no_invalid_direction_in_linear_gradient::NoInvalidDirectionInLinearGradientOptions>()));
+    result.push((
+        "nursery",
+        "noInvalidGraphqlArgumentValue",
+        TypeId::of::<biome_rule_options::no_invalid_graphql_argument_value::NoInvalidGraphqlArgumentValueOptions>(),
+    ));
     result.push((
         "correctness",
         "noInvalidGridAreas",
         TypeId::of::<biome_rule_options::no_invalid_grid_areas::NoInvalidGridAreasOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noInvalidLinkFragments",
+        TypeId::of::<biome_rule_options::no_invalid_link_fragments::NoInvalidLinkFragmentsOptions>(
+        ),
+    ));
     result.push(("correctness", "noInvalidPositionAtImportRule", TypeId::of::<biome_rule_options::no_invalid_position_at_import_rule::NoInvalidPositionAtImportRuleOptions>()));
     result.push((
         "nursery",
@@ -1217,6 +1244,16 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noTopLevelLiterals",
         TypeId::of::<biome_rule_options::no_top_level_literals::NoTopLevelLiteralsOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noTrailingSpaces",
+        TypeId::of::<biome_rule_options::no_trailing_spaces::NoTrailingSpacesOptions>(),
+    ));
+    result.push((
+        "nursery",
+        "noTruthyScalars",
+        TypeId::of::<biome_rule_options::no_truthy_scalars::NoTruthyScalarsOptions>(),
+    ));
     result.push((
         "suspicious",
         "noTsIgnore",
@@ -1227,6 +1264,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noUnassignedVariables",
         TypeId::of::<biome_rule_options::no_unassigned_variables::NoUnassignedVariablesOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noUndeclaredAliases",
+        TypeId::of::<biome_rule_options::no_undeclared_aliases::NoUndeclaredAliasesOptions>(),
+    ));
     result.push((
         "nursery",
         "noUndeclaredClasses",
@@ -1244,6 +1286,20 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noUndeclaredVariables",
         TypeId::of::<biome_rule_options::no_undeclared_variables::NoUndeclaredVariablesOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noUndefinedGraphqlFragment",
+        TypeId::of::<
+            biome_rule_options::no_undefined_graphql_fragment::NoUndefinedGraphqlFragmentOptions,
+        >(),
+    ));
+    result.push((
+        "nursery",
+        "noUndefinedGraphqlVariable",
+        TypeId::of::<
+            biome_rule_options::no_undefined_graphql_variable::NoUndefinedGraphqlVariableOptions,
+        >(),
+    ));
     result.push((
         "suspicious",
         "noUnknownAtRules",
@@ -1259,6 +1315,23 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noUnknownFunction",
         TypeId::of::<biome_rule_options::no_unknown_function::NoUnknownFunctionOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noUnknownGraphqlArgument",
+        TypeId::of::<
+            biome_rule_options::no_unknown_graphql_argument::NoUnknownGraphqlArgumentOptions,
+        >(),
+    ));
+    result.push((
+        "nursery",
+        "noUnknownGraphqlField",
+        TypeId::of::<biome_rule_options::no_unknown_graphql_field::NoUnknownGraphqlFieldOptions>(),
+    ));
+    result.push((
+        "nursery",
+        "noUnknownGraphqlType",
+        TypeId::of::<biome_rule_options::no_unknown_graphql_type::NoUnknownGraphqlTypeOptions>(),
+    ));
     result.push((
         "correctness",
         "noUnknownMediaFeatureName",
@@ -1311,6 +1384,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noUnreachable",
         TypeId::of::<biome_rule_options::no_unreachable::NoUnreachableOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noUnreachableFiles",
+        TypeId::of::<biome_rule_options::no_unreachable_files::NoUnreachableFilesOptions>(),
+    ));
     result.push((
         "correctness",
         "noUnreachableSuper",
@@ -1365,6 +1443,16 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "noUnusedClasses",
         TypeId::of::<biome_rule_options::no_unused_classes::NoUnusedClassesOptions>(),
     ));
+    result.push((
+        "nursery",
+        "noUnusedDependencies",
+        TypeId::of::<biome_rule_options::no_unused_dependencies::NoUnusedDependenciesOptions>(),
+    ));
+    result.push((
+        "nursery",
+        "noUnusedExports",
+        TypeId::of::<biome_rule_options::no_unused_exports::NoUnusedExportsOptions>(),
+    ));
     result.push((
         "suspicious",
         "noUnusedExpressions",
@@ -1731,6 +1819,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
             biome_rule_options::use_consistent_heading_level::UseConsistentHeadingLevelOptions,
         >(),
     ));
+    result.push((
+        "nursery",
+        "useConsistentListMarker",
+        TypeId::of::<biome_rule_options::use_consistent_list_marker::UseConsistentListMarkerOptions>(),
+    ));
     result.push(("style", "useConsistentMemberAccessibility", TypeId::of::<biome_rule_options::use_consistent_member_accessibility::UseConsistentMemberAccessibilityOptions>()));
     result.push(("style", "useConsistentMethodSignatures", TypeId::of::<biome_rule_options::use_consistent_method_signatures::UseConsistentMethodSignaturesOptions>()));
     result.push(("style", "useConsistentObjectDefinitions", TypeId::of::<biome_rule_options::use_consistent_object_definitions::UseConsistentObjectDefinitionsOptions>()));
@@ -1866,6 +1959,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "useExportsLast",
         TypeId::of::<biome_rule_options::use_exports_last::UseExportsLastOptions>(),
     ));
+    result.push((
+        "nursery",
+        "useFencedCodeLanguage",
+        TypeId::of::<biome_rule_options::use_fenced_code_language::UseFencedCodeLanguageOptions>(),
+    ));
     result.push((
         "style",
         "useFilenamingConvention",
@@ -1928,6 +2026,13 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
             biome_rule_options::use_graphql_naming_convention::UseGraphqlNamingConventionOptions,
         >(),
     ));
+    result.push((
+        "nursery",
+        "useGraphqlRequiredArguments",
+        TypeId::of::<
+            biome_rule_options::use_graphql_required_arguments::UseGraphqlRequiredArgumentsOptions,
+        >(),
+    ));
     result.push(("style", "useGroupedAccessorPairs", TypeId::of::<biome_rule_options::use_grouped_accessor_pairs::UseGroupedAccessorPairsOptions>()));
     result.push((
         "suspicious",
@@ -1959,6 +2064,11 @@ pub fn config_side_rule_options_types() -> Vec<(&'static str, &'static str, Type
         "useIframeTitle",
         TypeId::of::<biome_rule_options::use_iframe_title::UseIframeTitleOptions>(),
     ));
+    result.push((
+        "nursery",
+        "useImageAltText",
+        TypeId::of::<biome_rule_options::use_image_alt_text::UseImageAltTextOptions>(),
+    ));
     result.push((
         "correctness",
         "useImageSize",
@@ -36,6 +36,15 @@ pub struct GraphqlConfiguration {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assist: Option<GraphqlAssistConfiguration>,
+
+    /// Paths to the schema of the GraphQL service, relative to the configuration file.
+    ///
+    /// Files with the `.json` extension contain the result of an introspection query,
+    /// other files contain SDL. Operations are validated against the schema by the
+    /// schema-aware lint rules.
+    #[cfg_attr(feature = "cli", bpaf(pure(Default::default()), hide))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Vec<Box<str>>>,
 }
 
 pub type GraphqlFormatterEnabled = Bool<true>;
@@ -119,6 +119,7 @@ pub const DEFAULT_SCANNER_IGNORE_ENTRIES: &[&[u8]] = &[
     b".cache",
     b".git",
     b".hg",
+    b".jj",
     b".netlify",
     b".output",
     b".svn",
@@ -11,6 +11,7 @@ use std::str::FromStr;
 // NOTE: when adding a new ignore file, update [DocumentFileSource::try_from_path]
 pub const GIT_IGNORE_FILE_NAME: &str = ".gitignore";
 pub const IGNORE_FILE_NAME: &str = ".ignore";
+pub const HG_IGNORE_FILE_NAME: &str = ".hgignore";
 
 pub type VcsUseIgnoreFile = Bool<false>;
 pub type VcsEnabled = Bool<false>;
@@ -30,14 +31,17 @@ pub struct VcsConfiguration {
     /// The version control client.
     #[cfg_attr(
         feature = "cli",
-        bpaf(long("vcs-client-kind"), argument("git"), optional)
+        bpaf(long("vcs-client-kind"), argument("git|jujutsu|mercurial"), optional)
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[deserializable(bail_on_error)]
     pub client_kind: Option<VcsClientKind>,
 
-    /// When `true`, Biome ignores files listed in `.gitignore`, `.ignore`, and Git's local
-    /// exclude file.
+    /// When `true`, Biome ignores files listed in the ignore files of the version control client.
+    ///
+    /// With Git, these are `.gitignore`, `.ignore`, and Git's local exclude file. With Jujutsu,
+    /// these are `.gitignore` and, in colocated repositories, Git's local exclude file. With
+    /// Mercurial, this is the `.hgignore` file at the root of the repository.
     #[cfg_attr(
         feature = "cli",
         bpaf(long("vcs-use-ignore-file"), argument("true|false"))
@@ -76,9 +80,14 @@ impl VcsConfiguration {
         self.use_ignore_file.unwrap_or_default().into()
     }
     pub fn new_git_ignore() -> Self {
+        Self::new_ignore(VcsClientKind::Git)
+    }
+
+    /// Enables the VCS integration with the given client and its ignore files.
+    pub fn new_ignore(client_kind: VcsClientKind) -> Self {
         Self {
             enabled: Some(true.into()),
-            client_kind: Some(VcsClientKind::Git),
+            client_kind: Some(client_kind),
             use_ignore_file: Some(true.into()),
             root: None,
             default_branch: None,
@@ -117,12 +126,53 @@ pub enum VcsClientKind {
     #[default]
     /// Integration with Git as the version control client.
     Git,
+    /// Integration with Jujutsu as the version control client.
+    Jujutsu,
+    /// Integration with Mercurial as the version control client.
+    Mercurial,
 }
 
 impl VcsClientKind {
     pub const fn ignore_files(&self) -> &[&str] {
         match self {
             Self::Git => &[GIT_IGNORE_FILE_NAME, IGNORE_FILE_NAME],
+            Self::Jujutsu => &[GIT_IGNORE_FILE_NAME],
+            Self::Mercurial => &[HG_IGNORE_FILE_NAME],
+        }
+    }
+
+    /// The name of the directory that marks the root of a repository.
+    pub const fn repository_directory(&self) -> &str {
+        match self {
+            Self::Git => ".git",
+            Self::Jujutsu => ".jj",
+            Self::Mercurial => ".hg",
+        }
+    }
+
+    /// Whether the client has a staging area, which is required by `--staged`.
+    pub const fn has_staging_area(&self) -> bool {
+        matches!(self, Self::Git)
+    }
+
+    /// Whether the client can list the changed lines of the files, which is required by
+    /// `--changed-lines`.
+    pub const fn has_line_diff(&self) -> bool {
+        matches!(self, Self::Git)
+    }
+
+    /// Whether the client reads Git's local exclude file, `$GIT_DIR/info/exclude`.
+    ///
+    /// Jujutsu reads it in repositories that are colocated with Git.
+    pub const fn uses_git_info_exclude(&self) -> bool {
+        matches!(self, Self::Git | Self::Jujutsu)
+    }
+
+    pub const fn name(&self) -> &str {
+        match self {
+            Self::Git => "Git",
+            Self::Jujutsu => "Jujutsu",
+            Self::Mercurial => "Mercurial",
         }
     }
 }
@@ -133,6 +183,8 @@ impl FromStr for VcsClientKind {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "git" => Ok(Self::Git),
+            "jujutsu" => Ok(Self::Jujutsu),
+            "mercurial" => Ok(Self::Mercurial),
             _ => Err("Value not supported for clientKind."),
         }
     }
@@ -59,6 +59,8 @@ fn collect_lint_rules() -> LintRulesVisitor {
     biome_html_analyze::visit_registry(&mut lint_visitor);
     #[cfg(feature = "lang_md")]
     biome_markdown_analyze::visit_registry(&mut lint_visitor);
+    #[cfg(feature = "lang_yaml")]
+    biome_yaml_analyze::visit_registry(&mut lint_visitor);
 
     lint_visitor
 }
@@ -106,6 +108,8 @@ fn collect_assist_rules() -> AssistActionsVisitor {
     biome_html_analyze::visit_registry(&mut assist_visitor);
     #[cfg(feature = "lang_md")]
     biome_markdown_analyze::visit_registry(&mut assist_visitor);
+    #[cfg(feature = "lang_yaml")]
+    biome_yaml_analyze::visit_registry(&mut assist_visitor);
 
     assist_visitor
 }
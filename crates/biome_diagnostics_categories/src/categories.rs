@@ -201,6 +201,7 @@ define_categories! {
     "lint/correctness/useVueValidVText": "https://biomejs.dev/linter/rules/use-vue-valid-v-text",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/noAstroSetHtmlDirective": "https://biomejs.dev/linter/rules/no-astro-set-html-directive",
+    "lint/nursery/noBareUrls": "https://biomejs.dev/linter/rules/no-bare-urls",
     "lint/nursery/noBaseToString": "https://biomejs.dev/linter/rules/no-base-to-string",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
     "lint/nursery/noComponentHookFactories": "https://biomejs.dev/linter/rules/no-component-hook-factories",
@@ -208,6 +209,8 @@ define_categories! {
     "lint/nursery/noDrizzleDeleteWithoutWhere": "https://biomejs.dev/linter/rules/no-drizzle-delete-without-where",
     "lint/nursery/noDrizzleUpdateWithoutWhere": "https://biomejs.dev/linter/rules/no-drizzle-update-without-where",
     "lint/nursery/noDuplicateFieldDefinitionNames": "https://biomejs.dev/linter/rules/no-duplicate-field-definition-names",
+    "lint/nursery/noDuplicateHeadings": "https://biomejs.dev/linter/rules/no-duplicate-headings",
+    "lint/nursery/noDuplicateMappingKeys": "https://biomejs.dev/linter/rules/no-duplicate-mapping-keys",
     "lint/nursery/noDuplicateSelectors": "https://biomejs.dev/linter/rules/no-duplicate-selectors",
     "lint/nursery/noEmptyObjectKeys": "https://biomejs.dev/linter/rules/no-empty-object-keys",
     "lint/nursery/noExcessiveNestedCallbacks": "https://biomejs.dev/linter/rules/no-excessive-nested-callbacks",
@@ -218,6 +221,8 @@ define_categories! {
     "lint/nursery/noImplicitCoercion": "https://biomejs.dev/linter/rules/no-implicit-coercion",
     "lint/nursery/noImpliedEval": "https://biomejs.dev/linter/rules/no-implied-eval",
     "lint/nursery/noInlineStyles": "https://biomejs.dev/linter/rules/no-inline-styles",
+    "lint/nursery/noInvalidGraphqlArgumentValue": "https://biomejs.dev/linter/rules/no-invalid-graphql-argument-value",
+    "lint/nursery/noInvalidLinkFragments": "https://biomejs.dev/linter/rules/no-invalid-link-fragments",
     "lint/nursery/noInvalidPropertyInitValue": "https://biomejs.dev/linter/rules/no-invalid-property-init-value",
     "lint/nursery/noJsRestrictedProperties": "https://biomejs.dev/linter/rules/no-js-restricted-properties",
     "lint/nursery/noJsxLeakedDollar": "https://biomejs.dev/linter/rules/no-jsx-leaked-dollar",
@@ -247,13 +252,24 @@ define_categories! {
     "lint/nursery/noSvelteUnnecessaryStateWrap": "https://biomejs.dev/linter/rules/no-svelte-unnecessary-state-wrap",
     "lint/nursery/noTailwindArbitraryValue": "https://biomejs.dev/linter/rules/no-tailwind-arbitrary-value",
     "lint/nursery/noTopLevelLiterals": "https://biomejs.dev/linter/rules/no-top-level-literals",
+    "lint/nursery/noTrailingSpaces": "https://biomejs.dev/linter/rules/no-trailing-spaces",
+    "lint/nursery/noTruthyScalars": "https://biomejs.dev/linter/rules/no-truthy-scalars",
+    "lint/nursery/noUndeclaredAliases": "https://biomejs.dev/linter/rules/no-undeclared-aliases",
     "lint/nursery/noUndeclaredClasses": "https://biomejs.dev/linter/rules/no-undeclared-classes",
     "lint/nursery/noUndeclaredCustomProperties": "https://biomejs.dev/linter/rules/no-undeclared-custom-properties",
+    "lint/nursery/noUndefinedGraphqlFragment": "https://biomejs.dev/linter/rules/no-undefined-graphql-fragment",
+    "lint/nursery/noUndefinedGraphqlVariable": "https://biomejs.dev/linter/rules/no-undefined-graphql-variable",
+    "lint/nursery/noUnknownGraphqlArgument": "https://biomejs.dev/linter/rules/no-unknown-graphql-argument",
+    "lint/nursery/noUnknownGraphqlField": "https://biomejs.dev/linter/rules/no-unknown-graphql-field",
+    "lint/nursery/noUnknownGraphqlType": "https://biomejs.dev/linter/rules/no-unknown-graphql-type",
     "lint/nursery/noUnnecessaryTemplateExpression": "https://biomejs.dev/linter/rules/no-unnecessary-template-expression",
+    "lint/nursery/noUnreachableFiles": "https://biomejs.dev/linter/rules/no-unreachable-files",
     "lint/nursery/noUnsafePlusOperands": "https://biomejs.dev/linter/rules/no-unsafe-plus-operands",
     "lint/nursery/noUnsafeTypeAssertion": "https://biomejs.dev/linter/rules/no-unsafe-type-assertion",
     "lint/nursery/noUntrustedLicenses": "https://biomejs.dev/linter/rules/no-untrusted-licenses",
     "lint/nursery/noUnusedClasses": "https://biomejs.dev/linter/rules/no-unused-classes",
+    "lint/nursery/noUnusedDependencies": "https://biomejs.dev/linter/rules/no-unused-dependencies",
+    "lint/nursery/noUnusedExports": "https://biomejs.dev/linter/rules/no-unused-exports",
     "lint/nursery/noUnwantedPolyfillio": "https://biomejs.dev/linter/rules/no-unwanted-polyfillio",
     "lint/nursery/noUselessBackrefInRegex": "https://biomejs.dev/linter/rules/no-useless-backref-in-regex",
     "lint/nursery/noUselessTypeConversion": "https://biomejs.dev/linter/rules/no-useless-type-conversion",
@@ -266,6 +282,7 @@ define_categories! {
     "lint/nursery/useBaseline": "https://biomejs.dev/linter/rules/use-baseline",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
     "lint/nursery/useConsistentHeadingLevel": "https://biomejs.dev/linter/rules/use-consistent-heading-level",
+    "lint/nursery/useConsistentListMarker": "https://biomejs.dev/linter/rules/use-consistent-list-marker",
     "lint/nursery/useConsistentObjectDefinition": "https://biomejs.dev/linter/rules/use-consistent-object-definition",
     "lint/nursery/useConsistentTestIt": "https://biomejs.dev/linter/rules/use-consistent-test-it",
     "lint/nursery/useControlLabel": "https://biomejs.dev/linter/rules/use-control-label",
@@ -277,9 +294,12 @@ define_categories! {
     "lint/nursery/useExplicitFunctionReturnType": "https://biomejs.dev/linter/rules/use-explicit-type",
     "lint/nursery/useExplicitReturnType": "https://biomejs.dev/linter/rules/use-explicit-return-type",
     "lint/nursery/useExplicitType": "https://biomejs.dev/linter/rules/use-explicit-type",
+    "lint/nursery/useFencedCodeLanguage": "https://biomejs.dev/linter/rules/use-fenced-code-language",
     "lint/nursery/useFind": "https://biomejs.dev/linter/rules/use-find",
     "lint/nursery/useGlobalThis": "https://biomejs.dev/linter/rules/use-global-this",
+    "lint/nursery/useGraphqlRequiredArguments": "https://biomejs.dev/linter/rules/use-graphql-required-arguments",
     "lint/nursery/useIframeSandbox": "https://biomejs.dev/linter/rules/use-iframe-sandbox",
+    "lint/nursery/useImageAltText": "https://biomejs.dev/linter/rules/use-image-alt-text",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
     "lint/nursery/useImportsFirst": "https://biomejs.dev/linter/rules/use-imports-first",
     "lint/nursery/useIncludes": "https://biomejs.dev/linter/rules/use-includes",
@@ -594,6 +614,7 @@ define_categories! {
     "init",
     "configuration",
     "assist",
+    "baseline",
     "migrate",
     "upgrade",
     "deserialize",
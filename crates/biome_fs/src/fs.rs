@@ -1,4 +1,4 @@
-use crate::{BiomePath, PathInterner};
+use crate::{BiomePath, PathInterner, VcsClient};
 use biome_diagnostics::{Advices, Diagnostic, IoError, LogCategory, Visit, console};
 use biome_diagnostics::{Error, Severity};
 use camino::{Utf8Path, Utf8PathBuf};
@@ -281,9 +281,19 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Returns the resolution of a symbolic link.
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf>;
 
-    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
+    /// Returns the files changed since `base`, using the given version control client.
+    fn get_changed_files(&self, client: &dyn VcsClient, base: &str) -> io::Result<Vec<String>>;
 
-    fn get_staged_files(&self) -> io::Result<Vec<String>>;
+    /// Returns the files in the staging area, using the given version control client.
+    fn get_staged_files(&self, client: &dyn VcsClient) -> io::Result<Vec<String>>;
+
+    /// Returns the unified diff of the files changed since `base`, using the given version
+    /// control client.
+    fn get_changed_diff(&self, client: &dyn VcsClient, base: &str) -> io::Result<String>;
+
+    /// Returns the unified diff of the files in the staging area, using the given version
+    /// control client.
+    fn get_staged_diff(&self, client: &dyn VcsClient) -> io::Result<String>;
 }
 
 /// Result of the auto search
@@ -488,12 +498,20 @@ where
         T::symlink_path_kind(self, path)
     }
 
-    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        T::get_changed_files(self, base)
+    fn get_changed_files(&self, client: &dyn VcsClient, base: &str) -> io::Result<Vec<String>> {
+        T::get_changed_files(self, client, base)
+    }
+
+    fn get_staged_files(&self, client: &dyn VcsClient) -> io::Result<Vec<String>> {
+        T::get_staged_files(self, client)
+    }
+
+    fn get_changed_diff(&self, client: &dyn VcsClient, base: &str) -> io::Result<String> {
+        T::get_changed_diff(self, client, base)
     }
 
-    fn get_staged_files(&self) -> io::Result<Vec<String>> {
-        T::get_staged_files(self)
+    fn get_staged_diff(&self, client: &dyn VcsClient) -> io::Result<String> {
+        T::get_staged_diff(self, client)
     }
 
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf> {
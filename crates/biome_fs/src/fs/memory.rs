@@ -10,7 +10,7 @@ use std::str;
 use std::sync::Arc;
 
 use crate::fs::OpenOptions;
-use crate::{BiomePath, FileSystem, TraversalContext, TraversalScope};
+use crate::{BiomePath, FileSystem, TraversalContext, TraversalScope, VcsClient};
 
 use super::{BoxedTraversal, File, FileSystemDiagnostic, FsErrorKind, PathKind};
 
@@ -22,6 +22,14 @@ type OnGetChangedFiles = Option<
     >,
 >;
 
+type OnGetDiff = Option<
+    Arc<
+        AssertUnwindSafe<
+            Mutex<Option<Box<dyn FnOnce() -> String + Send + 'static + RefUnwindSafe>>>,
+        >,
+    >,
+>;
+
 type Files = Arc<RwLock<FxHashMap<Utf8PathBuf, FileEntry>>>;
 
 /// Fully in-memory file system, stores the content of all known files in a hashmap
@@ -31,6 +39,8 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    on_get_staged_diff: OnGetDiff,
+    on_get_changed_diff: OnGetDiff,
 }
 
 impl Default for MemoryFileSystem {
@@ -45,6 +55,12 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            on_get_staged_diff: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                String::new,
+            )))))),
+            on_get_changed_diff: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                String::new,
+            )))))),
         }
     }
 }
@@ -125,6 +141,20 @@ impl MemoryFileSystem {
     ) {
         self.on_get_staged_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    pub fn set_on_get_changed_diff(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> String + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_changed_diff = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+
+    pub fn set_on_get_staged_diff(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> String + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_staged_diff = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -225,7 +255,7 @@ impl FileSystem for MemoryFileSystem {
         self.path_kind(path)
     }
 
-    fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
+    fn get_changed_files(&self, _client: &dyn VcsClient, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
         let mut cb_guard = cb_arc.lock();
@@ -235,7 +265,7 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
-    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+    fn get_staged_files(&self, _client: &dyn VcsClient) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_staged_files.as_ref().unwrap().clone();
 
         let mut cb_guard = cb_arc.lock();
@@ -245,6 +275,26 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_changed_diff(&self, _client: &dyn VcsClient, _base: &str) -> io::Result<String> {
+        let cb_arc = self.on_get_changed_diff.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
+    fn get_staged_diff(&self, _client: &dyn VcsClient) -> io::Result<String> {
+        let cb_arc = self.on_get_staged_diff.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
     fn read_link(&self, _path: &Utf8Path) -> io::Result<Utf8PathBuf> {
         Err(io::Error::new(
             io::ErrorKind::Unsupported,
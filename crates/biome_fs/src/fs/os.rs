@@ -2,7 +2,6 @@
 
 use std::env::temp_dir;
 use std::fs::{FileType, Metadata};
-use std::process::Command;
 use std::{
     env, fs,
     io::{self, Read, Seek, Write},
@@ -18,7 +17,7 @@ use tracing::instrument;
 use crate::expand_symbolic_link;
 use crate::fs::OpenOptions;
 use crate::{
-    BiomePath, FileSystem, MemoryFileSystem,
+    BiomePath, FileSystem, MemoryFileSystem, VcsClient,
     fs::{TraversalContext, TraversalScope},
 };
 
@@ -35,6 +34,14 @@ impl OsFileSystem {
             working_directory: Some(working_directory),
         }
     }
+
+    /// The directory where version control commands run. Paths returned by the version control
+    /// client are relative to it.
+    fn vcs_directory(&self) -> &Utf8Path {
+        self.working_directory
+            .as_deref()
+            .unwrap_or_else(|| Utf8Path::new("."))
+    }
 }
 
 impl Default for OsFileSystem {
@@ -97,44 +104,20 @@ impl FileSystem for OsFileSystem {
         path.read_link_utf8()
     }
 
-    fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .arg(format!("{base}...HEAD"))
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
-    }
-
-    fn get_staged_files(&self) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            .arg("--staged")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+    fn get_changed_files(&self, client: &dyn VcsClient, base: &str) -> io::Result<Vec<String>> {
+        client.changed_files(self.vcs_directory(), base)
+    }
+
+    fn get_staged_files(&self, client: &dyn VcsClient) -> io::Result<Vec<String>> {
+        client.staged_files(self.vcs_directory())
+    }
+
+    fn get_changed_diff(&self, client: &dyn VcsClient, base: &str) -> io::Result<String> {
+        client.changed_diff(self.vcs_directory(), base)
+    }
+
+    fn get_staged_diff(&self, client: &dyn VcsClient) -> io::Result<String> {
+        client.staged_diff(self.vcs_directory())
     }
 }
 
@@ -4,6 +4,7 @@ mod fs;
 mod interner;
 mod path;
 mod utils;
+mod vcs;
 
 pub use fs::{
     AutoSearchResult, ConfigName, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
@@ -13,3 +14,4 @@ pub use fs::{
 pub use interner::{PathInterner, PathInternerSet};
 pub use path::{BiomePath, is_node_modules_path};
 pub use utils::*;
+pub use vcs::{GitClient, JujutsuClient, MercurialClient, VcsClient};
@@ -158,7 +158,7 @@ impl BiomePath {
                 | "pnpm-workspace.yaml"
         ) {
             FileKinds::Manifest
-        } else if matches!(file_name, ".gitignore" | ".ignore") {
+        } else if matches!(file_name, ".gitignore" | ".ignore" | ".hgignore") {
             FileKinds::Ignore
         } else {
             FileKinds::Handleable
@@ -368,6 +368,7 @@ mod test {
         assert_eq!(BiomePath::priority("biome.jsonc"), FileKinds::Config);
         assert_eq!(BiomePath::priority(".gitignore"), FileKinds::Ignore);
         assert_eq!(BiomePath::priority(".ignore"), FileKinds::Ignore);
+        assert_eq!(BiomePath::priority(".hgignore"), FileKinds::Ignore);
     }
 
     #[test]
@@ -0,0 +1,397 @@
+//! Clients that query a version control system for the files that changed in a repository.
+//!
+//! Each client shells out to the command line tool of its VCS. The paths that are returned are
+//! relative to the directory where the command runs, and only the files inside that directory
+//! are listed.
+
+use camino::Utf8Path;
+use std::fmt::Debug;
+use std::io;
+use std::process::Command;
+
+/// A version control client that can list the files changed in a repository.
+pub trait VcsClient: Debug + Send + Sync {
+    /// The name of the version control system, used in diagnostics.
+    fn name(&self) -> &'static str;
+
+    /// Returns the files that were added, copied, modified or renamed between the common
+    /// ancestor of `base` and the current revision.
+    fn changed_files(&self, directory: &Utf8Path, base: &str) -> io::Result<Vec<String>>;
+
+    /// Returns the files that were added, copied, modified or renamed in the staging area.
+    fn staged_files(&self, directory: &Utf8Path) -> io::Result<Vec<String>>;
+
+    /// Returns the unified diff, without context lines, of the files returned by
+    /// [VcsClient::changed_files]. The paths of the new files follow `+++ ` without prefix.
+    fn changed_diff(&self, directory: &Utf8Path, base: &str) -> io::Result<String>;
+
+    /// Returns the unified diff, without context lines, of the files returned by
+    /// [VcsClient::staged_files]. The paths of the new files follow `+++ ` without prefix.
+    fn staged_diff(&self, directory: &Utf8Path) -> io::Result<String>;
+}
+
+/// Client for [Git](https://git-scm.com/).
+#[derive(Debug, Default)]
+pub struct GitClient;
+
+impl VcsClient for GitClient {
+    fn name(&self) -> &'static str {
+        "Git"
+    }
+
+    fn changed_files(&self, directory: &Utf8Path, base: &str) -> io::Result<Vec<String>> {
+        run(Command::new("git")
+            .current_dir(directory)
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--relative")
+            // A: added
+            // C: copied
+            // M: modified
+            // R: renamed
+            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
+            .arg("--diff-filter=ACMR")
+            .arg(format!("{base}...HEAD")))
+    }
+
+    fn staged_files(&self, directory: &Utf8Path) -> io::Result<Vec<String>> {
+        run(Command::new("git")
+            .current_dir(directory)
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--relative")
+            .arg("--staged")
+            // A: added
+            // C: copied
+            // M: modified
+            // R: renamed
+            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
+            .arg("--diff-filter=ACMR"))
+    }
+
+    fn changed_diff(&self, directory: &Utf8Path, base: &str) -> io::Result<String> {
+        run_output(
+            git_diff(directory)
+                .arg("--diff-filter=ACMR")
+                .arg(format!("{base}...HEAD")),
+        )
+    }
+
+    fn staged_diff(&self, directory: &Utf8Path) -> io::Result<String> {
+        run_output(
+            git_diff(directory)
+                .arg("--staged")
+                .arg("--diff-filter=ACMR"),
+        )
+    }
+}
+
+/// Creates a `git diff` command whose output doesn't depend on the user configuration.
+fn git_diff(directory: &Utf8Path) -> Command {
+    let mut command = Command::new("git");
+    command
+        .current_dir(directory)
+        // Prints paths with non-ASCII characters verbatim
+        .arg("-c")
+        .arg("core.quotePath=false")
+        .arg("diff")
+        .arg("--unified=0")
+        .arg("--relative")
+        .arg("--no-prefix")
+        .arg("--no-color")
+        .arg("--no-ext-diff");
+    command
+}
+
+/// Client for [Jujutsu](https://jj-vcs.github.io/jj/).
+///
+/// The current revision is the working-copy commit `@`, so changes that aren't described yet
+/// are listed too.
+#[derive(Debug, Default)]
+pub struct JujutsuClient;
+
+impl VcsClient for JujutsuClient {
+    fn name(&self) -> &'static str {
+        "Jujutsu"
+    }
+
+    fn changed_files(&self, directory: &Utf8Path, base: &str) -> io::Result<Vec<String>> {
+        // Deleted files are listed too. Callers filter out the paths that don't exist anymore.
+        run(Command::new("jj")
+            .current_dir(directory)
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--from")
+            .arg(format!("heads(::({base}) & ::@)"))
+            .arg("--to")
+            .arg("@")
+            // Only list the files inside the current directory, like `git diff --relative`
+            .arg("."))
+    }
+
+    fn staged_files(&self, _directory: &Utf8Path) -> io::Result<Vec<String>> {
+        Err(no_staging_area(self))
+    }
+
+    fn changed_diff(&self, _directory: &Utf8Path, _base: &str) -> io::Result<String> {
+        Err(no_line_diff(self))
+    }
+
+    fn staged_diff(&self, _directory: &Utf8Path) -> io::Result<String> {
+        Err(no_staging_area(self))
+    }
+}
+
+/// Client for [Mercurial](https://www.mercurial-scm.org/).
+#[derive(Debug, Default)]
+pub struct MercurialClient;
+
+impl VcsClient for MercurialClient {
+    fn name(&self) -> &'static str {
+        "Mercurial"
+    }
+
+    fn changed_files(&self, directory: &Utf8Path, base: &str) -> io::Result<Vec<String>> {
+        run(Command::new("hg")
+            .current_dir(directory)
+            // Disables the user configuration that could change the output
+            .env("HGPLAIN", "1")
+            .arg("status")
+            .arg("--rev")
+            .arg(format!("ancestor({base}, .)"))
+            .arg("--rev")
+            .arg(".")
+            // Renamed and copied files are reported as added
+            .arg("--added")
+            .arg("--modified")
+            .arg("--no-status")
+            // Passing a pattern makes Mercurial print paths relative to the current directory
+            .arg("."))
+    }
+
+    fn staged_files(&self, _directory: &Utf8Path) -> io::Result<Vec<String>> {
+        Err(no_staging_area(self))
+    }
+
+    fn changed_diff(&self, _directory: &Utf8Path, _base: &str) -> io::Result<String> {
+        Err(no_line_diff(self))
+    }
+
+    fn staged_diff(&self, _directory: &Utf8Path) -> io::Result<String> {
+        Err(no_staging_area(self))
+    }
+}
+
+fn no_staging_area(client: &dyn VcsClient) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("{} doesn't have a staging area.", client.name()),
+    )
+}
+
+fn no_line_diff(client: &dyn VcsClient) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "Listing the changed lines isn't supported with {} yet.",
+            client.name()
+        ),
+    )
+}
+
+/// Runs the command and returns the lines of its standard output.
+///
+/// The standard error is returned when the command fails.
+fn run(command: &mut Command) -> io::Result<Vec<String>> {
+    Ok(run_output(command)?
+        .lines()
+        .map(|l| l.to_string())
+        .collect())
+}
+
+/// Runs the command and returns its standard output.
+///
+/// The standard error is returned when the command fails.
+fn run_output(command: &mut Command) -> io::Result<String> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TemporaryFs;
+
+    /// Runs a command in `directory`, or returns `None` if the program isn't installed.
+    fn try_run(directory: &Utf8Path, program: &str, args: &[&str]) -> Option<()> {
+        let output = Command::new(program)
+            .current_dir(directory)
+            .args(args)
+            .env("HGPLAIN", "1")
+            .env("HGUSER", "Biome <biome@example.com>")
+            .env("JJ_USER", "Biome")
+            .env("JJ_EMAIL", "biome@example.com")
+            .env("GIT_AUTHOR_NAME", "Biome")
+            .env("GIT_AUTHOR_EMAIL", "biome@example.com")
+            .env("GIT_COMMITTER_NAME", "Biome")
+            .env("GIT_COMMITTER_EMAIL", "biome@example.com")
+            .output()
+            .ok()?;
+        assert!(
+            output.status.success(),
+            "`{program} {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Some(())
+    }
+
+    fn sorted(mut files: Vec<String>) -> Vec<String> {
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn git_changed_and_staged_files() {
+        let mut fs = TemporaryFs::new("vcs_git_changed_and_staged_files");
+        let root = fs.working_directory.clone();
+        fs.create_file("a.js", "a");
+        if try_run(&root, "git", &["init", "--quiet", "--initial-branch=main"]).is_none() {
+            return;
+        }
+        try_run(&root, "git", &["add", "."]);
+        try_run(&root, "git", &["commit", "--quiet", "--message", "base"]);
+        try_run(&root, "git", &["switch", "--quiet", "--create", "feature"]);
+        fs.create_file("a.js", "b");
+        fs.create_file("src/b.js", "b");
+        try_run(&root, "git", &["add", "."]);
+        try_run(&root, "git", &["commit", "--quiet", "--message", "feature"]);
+        fs.create_file("c.js", "c");
+        try_run(&root, "git", &["add", "c.js"]);
+
+        let changed = GitClient.changed_files(&root, "main").unwrap();
+        assert_eq!(sorted(changed), ["a.js", "src/b.js"]);
+
+        let staged = GitClient.staged_files(&root).unwrap();
+        assert_eq!(staged, ["c.js"]);
+
+        let changed = GitClient.changed_files(&root.join("src"), "main").unwrap();
+        assert_eq!(changed, ["b.js"]);
+    }
+
+    #[test]
+    fn git_changed_and_staged_diff() {
+        let mut fs = TemporaryFs::new("vcs_git_changed_and_staged_diff");
+        let root = fs.working_directory.clone();
+        fs.create_file("a.js", "a\nb\nc\n");
+        if try_run(&root, "git", &["init", "--quiet", "--initial-branch=main"]).is_none() {
+            return;
+        }
+        try_run(&root, "git", &["add", "."]);
+        try_run(&root, "git", &["commit", "--quiet", "--message", "base"]);
+        try_run(&root, "git", &["switch", "--quiet", "--create", "feature"]);
+        fs.create_file("a.js", "a\nB\nc\n");
+        try_run(
+            &root,
+            "git",
+            &["commit", "--quiet", "--all", "--message", "feature"],
+        );
+        fs.create_file("src/b.js", "b\n");
+        try_run(&root, "git", &["add", "."]);
+
+        let diff = GitClient.changed_diff(&root, "main").unwrap();
+        assert!(diff.contains("+++ a.js\n@@ -2 +2 @@"), "{diff}");
+
+        let diff = GitClient.staged_diff(&root.join("src")).unwrap();
+        assert!(diff.contains("+++ b.js\n@@ -0,0 +1 @@"), "{diff}");
+    }
+
+    #[test]
+    fn jujutsu_changed_files() {
+        let mut fs = TemporaryFs::new("vcs_jujutsu_changed_files");
+        let root = fs.working_directory.clone();
+        fs.create_file("a.js", "a");
+        if try_run(&root, "jj", &["git", "init", "--quiet"]).is_none() {
+            return;
+        }
+        try_run(&root, "jj", &["commit", "--quiet", "--message", "base"]);
+        try_run(
+            &root,
+            "jj",
+            &["bookmark", "create", "--quiet", "main", "-r", "@-"],
+        );
+        fs.create_file("a.js", "b");
+        fs.create_file("src/b.js", "b");
+        try_run(&root, "jj", &["commit", "--quiet", "--message", "feature"]);
+        fs.create_file("c.js", "c");
+
+        // The working-copy commit is part of the changes
+        let changed = JujutsuClient.changed_files(&root, "main").unwrap();
+        assert_eq!(sorted(changed), ["a.js", "c.js", "src/b.js"]);
+
+        let changed = JujutsuClient
+            .changed_files(&root.join("src"), "main")
+            .unwrap();
+        assert_eq!(changed, ["b.js"]);
+
+        let error = JujutsuClient.staged_files(&root).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+
+        let error = JujutsuClient.changed_diff(&root, "main").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn mercurial_changed_files() {
+        let mut fs = TemporaryFs::new("vcs_mercurial_changed_files");
+        let root = fs.working_directory.clone();
+        fs.create_file("a.js", "a");
+        if try_run(&root, "hg", &["init"]).is_none() {
+            return;
+        }
+        try_run(&root, "hg", &["commit", "--addremove", "--message", "base"]);
+        try_run(&root, "hg", &["bookmark", "main"]);
+        try_run(&root, "hg", &["bookmark", "--inactive"]);
+        fs.create_file("a.js", "b");
+        fs.create_file("src/b.js", "b");
+        try_run(
+            &root,
+            "hg",
+            &["commit", "--addremove", "--message", "feature"],
+        );
+        fs.create_file("c.js", "c");
+        try_run(&root, "hg", &["add", "c.js"]);
+
+        // Unlike Jujutsu, uncommitted changes aren't part of the current revision
+        let changed = MercurialClient.changed_files(&root, "main").unwrap();
+        assert_eq!(sorted(changed), ["a.js", "src/b.js"]);
+
+        let changed = MercurialClient
+            .changed_files(&root.join("src"), "main")
+            .unwrap();
+        assert_eq!(changed, ["b.js"]);
+
+        let error = MercurialClient.staged_files(&root).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn failing_command_returns_stderr() {
+        let fs = TemporaryFs::new("vcs_failing_command_returns_stderr");
+        let root = fs.working_directory.clone();
+        if try_run(&root, "git", &["init", "--quiet"]).is_none() {
+            return;
+        }
+
+        let error = GitClient
+            .changed_files(&root, "missing-branch")
+            .unwrap_err();
+        assert!(!error.to_string().is_empty());
+    }
+}
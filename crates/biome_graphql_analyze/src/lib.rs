@@ -3,21 +3,23 @@
 mod assist;
 mod lint;
 mod registry;
+pub mod services;
 mod suppression_action;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    BatchPluginVisitor, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases,
+    PluginTargetLanguage, RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_deserialize::TextRange;
 use biome_diagnostics::Error;
+use biome_graphql_semantic::GraphqlSchema;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type GraphqlRuleAction = RuleAction<GraphqlLanguage>;
 
@@ -27,6 +29,19 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     metadata
 });
 
+#[derive(Debug, Default)]
+pub struct GraphqlAnalyzerServices {
+    /// The schema that operations are validated against
+    pub schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl GraphqlAnalyzerServices {
+    pub fn with_schema(mut self, schema: Option<Arc<GraphqlSchema>>) -> Self {
+        self.schema = schema;
+        self
+    }
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
@@ -34,13 +49,23 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<GraphqlLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    services: GraphqlAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        services,
+        plugins,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -54,6 +79,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    graphql_services: GraphqlAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -90,13 +117,15 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    services.insert_service(graphql_services.schema);
+
     let mut analyzer = biome_analyze::Analyzer::new(
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
@@ -109,6 +138,22 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    let graphql_plugins: Vec<_> = plugins
+        .iter()
+        .filter(|p| p.language() == PluginTargetLanguage::Graphql)
+        .cloned()
+        .collect();
+
+    if filter.match_plugins() && !graphql_plugins.is_empty() {
+        // SAFETY: All plugins have been verified to target GraphQL above.
+        unsafe {
+            analyzer.add_visitor(
+                Phases::Syntax,
+                Box::new(BatchPluginVisitor::new_unchecked(&graphql_plugins)),
+            );
+        }
+    }
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -122,7 +167,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::analyze;
+    use crate::{GraphqlAnalyzerServices, analyze};
     use biome_analyze::{
         ActionFilter, AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleFilter,
     };
@@ -160,6 +205,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            GraphqlAnalyzerServices::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
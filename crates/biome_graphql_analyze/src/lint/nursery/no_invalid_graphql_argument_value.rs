@@ -0,0 +1,423 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_semantic::{GraphqlSchema, SchemaTypeKind, SchemaTypeRef};
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlArgument, GraphqlArguments, GraphqlObjectValue,
+    GraphqlOperationDefinition, GraphqlVariableReference,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+use biome_rule_options::no_invalid_graphql_argument_value::NoInvalidGraphqlArgumentValueOptions;
+
+declare_lint_rule! {
+    /// Disallow argument values that don't match the type of the argument.
+    ///
+    /// The values passed as arguments to fields and directives must be valid for the
+    /// type declared by the schema:
+    /// - literal values must be coercible to the type of the argument;
+    /// - enum values must be members of the enum;
+    /// - input objects must only contain the fields of the input type, and all its required fields;
+    /// - variables must have a type that can be used where the argument is expected.
+    ///
+    /// The values of custom scalars aren't validated, because their format is
+    /// only known to the service.
+    ///
+    /// This rule validates documents against the schema configured with the
+    /// [`graphql.schema`](https://biomejs.dev/reference/configuration/#graphqlschema) option,
+    /// and doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   reviews(episode: Episode!, first: Int): [Review]
+    ///   search(filter: SearchFilter): [String]
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// input SearchFilter {
+    ///   text: String!
+    ///   limit: Int
+    /// }
+    ///
+    /// type Review {
+    ///   stars: Int
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   reviews(episode: JEDI) {
+    ///     stars
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   search(filter: { limit: 10 })
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query Reviews($first: String) {
+    ///   reviews(episode: EMPIRE, first: $first) {
+    ///     stars
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   reviews(episode: Episode!, first: Int): [Review]
+    ///   search(filter: SearchFilter): [String]
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// input SearchFilter {
+    ///   text: String!
+    ///   limit: Int
+    /// }
+    ///
+    /// type Review {
+    ///   stars: Int
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query Reviews($first: Int!) {
+    ///   reviews(episode: EMPIRE, first: $first) {
+    ///     stars
+    ///   }
+    ///   search(filter: { text: "hope", limit: 10 })
+    /// }
+    /// ```
+    ///
+    pub NoInvalidGraphqlArgumentValue {
+        version: "next",
+        name: "noInvalidGraphqlArgumentValue",
+        language: "graphql",
+        recommended: false,
+        sources: &[
+            RuleSource::EslintGraphql("value-literals-of-correct-type").same(),
+            RuleSource::EslintGraphql("variables-in-allowed-position").same(),
+        ],
+    }
+}
+
+pub struct InvalidValue {
+    range: TextRange,
+    kind: InvalidValueKind,
+}
+
+pub enum InvalidValueKind {
+    /// The value can't be coerced to the expected type
+    Mismatch { expected: String },
+    /// The input object contains a field that its type doesn't define
+    UnknownInputField { name: String, ty: Box<str> },
+    /// The input object lacks a required field of its type
+    MissingInputField { name: Box<str>, ty: Box<str> },
+    /// The variable has a type that can't be used where the value is expected
+    VariableMismatch {
+        name: String,
+        ty: String,
+        expected: String,
+    },
+}
+
+impl Rule for NoInvalidGraphqlArgumentValue {
+    type Query = Schema<GraphqlArgument>;
+    type State = InvalidValue;
+    type Signals = Box<[Self::State]>;
+    type Options = NoInvalidGraphqlArgumentValueOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut invalid_values = Vec::new();
+        if let Some(schema) = ctx.schema() {
+            check_argument(schema, ctx.query(), &mut invalid_values);
+        }
+        invalid_values.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match &state.kind {
+            InvalidValueKind::Mismatch { expected } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This value isn't a valid "<Emphasis>{expected}</Emphasis>"."
+                },
+            ),
+            InvalidValueKind::UnknownInputField { name, ty } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The field "<Emphasis>{name}</Emphasis>" isn't defined by the input type "<Emphasis>{&**ty}</Emphasis>"."
+                },
+            ),
+            InvalidValueKind::MissingInputField { name, ty } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The required field "<Emphasis>{&**name}</Emphasis>" of the input type "<Emphasis>{&**ty}</Emphasis>" is missing."
+                },
+            ),
+            InvalidValueKind::VariableMismatch { name, ty, expected } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The variable "<Emphasis>{name}</Emphasis>" of type "<Emphasis>{ty}</Emphasis>" is used where a value of type "<Emphasis>{expected}</Emphasis>" is expected."
+                },
+            ),
+        };
+
+        Some(diagnostic.note(markup! {
+            "The values of arguments must match the types declared by the schema."
+        }))
+    }
+}
+
+fn check_argument(
+    schema: &GraphqlSchema,
+    argument: &GraphqlArgument,
+    invalid_values: &mut Vec<InvalidValue>,
+) -> Option<()> {
+    let arguments = GraphqlArguments::cast(argument.syntax().grand_parent()?)?;
+    let name = argument.name().ok()?.value_token().ok()?;
+    let definition = schema
+        .arguments_definition(&arguments)?
+        .iter()
+        .find(|definition| definition.name() == name.text_trimmed())?;
+
+    let checker = ValueChecker {
+        schema,
+        operation: argument
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlOperationDefinition::cast),
+    };
+    checker.check(
+        &argument.value().ok()?,
+        definition.ty(),
+        definition.has_default(),
+        invalid_values,
+    );
+
+    Some(())
+}
+
+struct ValueChecker<'a> {
+    schema: &'a GraphqlSchema,
+    /// The operation that defines the variables used by the value, if the value
+    /// isn't part of a fragment
+    operation: Option<GraphqlOperationDefinition>,
+}
+
+impl ValueChecker<'_> {
+    fn check(
+        &self,
+        value: &AnyGraphqlValue,
+        ty: &SchemaTypeRef,
+        has_default: bool,
+        invalid_values: &mut Vec<InvalidValue>,
+    ) {
+        let mismatch = || InvalidValue {
+            range: value.range(),
+            kind: InvalidValueKind::Mismatch {
+                expected: ty.to_string(),
+            },
+        };
+
+        match value {
+            AnyGraphqlValue::GraphqlBogusValue(_) => {}
+            AnyGraphqlValue::GraphqlVariableReference(variable) => {
+                self.check_variable(variable, ty, has_default, invalid_values);
+            }
+            AnyGraphqlValue::GraphqlNullValue(_) => {
+                if ty.is_non_null() {
+                    invalid_values.push(mismatch());
+                }
+            }
+            _ => match ty.nullable() {
+                SchemaTypeRef::List(item_type) => {
+                    if let AnyGraphqlValue::GraphqlListValue(list) = value {
+                        for element in list.elements().iter() {
+                            self.check(&element, item_type, false, invalid_values);
+                        }
+                    } else {
+                        // A single value is coerced to a list of one item
+                        self.check(value, item_type, false, invalid_values);
+                    }
+                }
+                SchemaTypeRef::Named(name) => {
+                    let Some(named_type) = self.schema.get_type(name) else {
+                        return;
+                    };
+                    let is_valid = match named_type.kind() {
+                        SchemaTypeKind::Scalar => is_valid_scalar(value, name),
+                        SchemaTypeKind::Enum => match value {
+                            AnyGraphqlValue::GraphqlEnumValue(enum_value) => enum_value
+                                .value()
+                                .and_then(|name| name.value_token())
+                                .is_ok_and(|token| named_type.has_enum_value(token.text_trimmed())),
+                            _ => false,
+                        },
+                        SchemaTypeKind::InputObject => match value {
+                            AnyGraphqlValue::GraphqlObjectValue(object) => {
+                                self.check_input_object(object, name, invalid_values);
+                                true
+                            }
+                            _ => false,
+                        },
+                        // Not an input type, which is an error of the schema itself
+                        SchemaTypeKind::Object
+                        | SchemaTypeKind::Interface
+                        | SchemaTypeKind::Union => true,
+                    };
+                    if !is_valid {
+                        invalid_values.push(mismatch());
+                    }
+                }
+                SchemaTypeRef::NonNull(_) => {}
+            },
+        }
+    }
+
+    fn check_input_object(
+        &self,
+        object: &GraphqlObjectValue,
+        type_name: &str,
+        invalid_values: &mut Vec<InvalidValue>,
+    ) -> Option<()> {
+        let input_type = self.schema.get_type(type_name)?;
+        let mut provided = Vec::new();
+
+        for member in object.members().iter() {
+            let Some(name) = member.name().ok().and_then(|name| name.value_token().ok()) else {
+                continue;
+            };
+            match input_type.input_field(name.text_trimmed()) {
+                Some(field) => {
+                    if let Ok(value) = member.value() {
+                        self.check(&value, field.ty(), field.has_default(), invalid_values);
+                    }
+                }
+                None => invalid_values.push(InvalidValue {
+                    range: name.text_trimmed_range(),
+                    kind: InvalidValueKind::UnknownInputField {
+                        name: name.text_trimmed().to_string(),
+                        ty: type_name.into(),
+                    },
+                }),
+            }
+            provided.push(name);
+        }
+
+        for field in input_type.input_fields() {
+            if field.is_required()
+                && !provided
+                    .iter()
+                    .any(|name| name.text_trimmed() == field.name())
+            {
+                invalid_values.push(InvalidValue {
+                    range: object.range(),
+                    kind: InvalidValueKind::MissingInputField {
+                        name: field.name().into(),
+                        ty: type_name.into(),
+                    },
+                });
+            }
+        }
+
+        Some(())
+    }
+
+    /// Checks that a variable is used in an allowed position.
+    ///
+    /// See <https://spec.graphql.org/October2021/#IsVariableUsageAllowed()>
+    fn check_variable(
+        &self,
+        variable: &GraphqlVariableReference,
+        location_type: &SchemaTypeRef,
+        location_has_default: bool,
+        invalid_values: &mut Vec<InvalidValue>,
+    ) -> Option<()> {
+        let name = variable.name().ok()?.value_token().ok()?;
+        let definition = self
+            .operation
+            .as_ref()?
+            .variables()?
+            .elements()
+            .iter()
+            .find(|definition| {
+                definition
+                    .variable()
+                    .and_then(|binding| binding.name())
+                    .and_then(|name| name.value_token())
+                    .is_ok_and(|token| token.text_trimmed() == name.text_trimmed())
+            })?;
+        let variable_type = SchemaTypeRef::from_type(&definition.ty().ok()?)?;
+
+        let has_non_null_default = definition
+            .default()
+            .and_then(|default| default.value().ok())
+            .is_some_and(|value| !matches!(value, AnyGraphqlValue::GraphqlNullValue(_)));
+        let location_type = if location_type.is_non_null()
+            && !variable_type.is_non_null()
+            && (has_non_null_default || location_has_default)
+        {
+            location_type.nullable()
+        } else {
+            location_type
+        };
+
+        if !variable_type.is_compatible_with(location_type) {
+            invalid_values.push(InvalidValue {
+                range: variable.range(),
+                kind: InvalidValueKind::VariableMismatch {
+                    name: variable.syntax().text_trimmed().to_string(),
+                    ty: variable_type.to_string(),
+                    expected: location_type.to_string(),
+                },
+            });
+        }
+
+        Some(())
+    }
+}
+
+/// Whether a literal value can be coerced to the scalar called `name`.
+fn is_valid_scalar(value: &AnyGraphqlValue, name: &str) -> bool {
+    match name {
+        "Int" => match value {
+            AnyGraphqlValue::GraphqlIntValue(int) => int
+                .graphql_int_literal_token()
+                .is_ok_and(|token| token.text_trimmed().parse::<i32>().is_ok()),
+            _ => false,
+        },
+        "Float" => matches!(
+            value,
+            AnyGraphqlValue::GraphqlIntValue(_) | AnyGraphqlValue::GraphqlFloatValue(_)
+        ),
+        "String" => matches!(value, AnyGraphqlValue::GraphqlStringValue(_)),
+        "Boolean" => matches!(value, AnyGraphqlValue::GraphqlBooleanValue(_)),
+        "ID" => matches!(
+            value,
+            AnyGraphqlValue::GraphqlStringValue(_) | AnyGraphqlValue::GraphqlIntValue(_)
+        ),
+        // The format of custom scalars is only known to the service
+        _ => true,
+    }
+}
@@ -0,0 +1,78 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlFragmentSpread, GraphqlSyntaxToken};
+use biome_rowan::AstNode;
+use biome_rule_options::no_undefined_graphql_fragment::NoUndefinedGraphqlFragmentOptions;
+
+declare_lint_rule! {
+    /// Disallow spreads of fragments that aren't defined in the document.
+    ///
+    /// A fragment can only be spread if the document defines it. Fragments defined in
+    /// other files aren't known to this rule.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     ...HeroDetails
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero {
+    ///     ...HeroDetails
+    ///   }
+    /// }
+    ///
+    /// fragment HeroDetails on Character {
+    ///   name
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedGraphqlFragment {
+        version: "next",
+        name: "noUndefinedGraphqlFragment",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("known-fragment-names").same()],
+    }
+}
+
+impl Rule for NoUndefinedGraphqlFragment {
+    type Query = Semantic<GraphqlFragmentSpread>;
+    type State = GraphqlSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = NoUndefinedGraphqlFragmentOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let name = ctx.query().name().ok()?;
+        if ctx.model().binding(&name).is_some() {
+            return None;
+        }
+
+        name.value_token().ok()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The fragment "<Emphasis>{name.text_trimmed()}</Emphasis>" isn't defined in this document."
+                },
+            )
+            .note(markup! {
+                "Define the fragment, or check the spelling of its name."
+            }),
+        )
+    }
+}
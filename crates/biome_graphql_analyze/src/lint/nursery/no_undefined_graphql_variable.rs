@@ -0,0 +1,119 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlOperationDefinition, GraphqlVariableReference};
+use biome_rowan::AstNode;
+use biome_rule_options::no_undefined_graphql_variable::NoUndefinedGraphqlVariableOptions;
+
+declare_lint_rule! {
+    /// Disallow variables that aren't defined by the operation using them.
+    ///
+    /// Variables must be defined by the operation that uses them, either directly or
+    /// through the fragments that it spreads. A fragment that isn't spread by any
+    /// operation of the document isn't reported, because the operations using it are unknown.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query Hero {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query Hero {
+    ///   hero {
+    ///     ...HeroFriends
+    ///   }
+    /// }
+    ///
+    /// fragment HeroFriends on Character {
+    ///   friends(first: $first) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query Hero($episode: Episode, $first: Int) {
+    ///   hero(episode: $episode) {
+    ///     ...HeroFriends
+    ///   }
+    /// }
+    ///
+    /// fragment HeroFriends on Character {
+    ///   friends(first: $first) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedGraphqlVariable {
+        version: "next",
+        name: "noUndefinedGraphqlVariable",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("no-undefined-variables").same()],
+    }
+}
+
+impl Rule for NoUndefinedGraphqlVariable {
+    type Query = Semantic<GraphqlVariableReference>;
+    /// The operations that don't define the variable
+    type State = GraphqlOperationDefinition;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUndefinedGraphqlVariableOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let range = ctx.query().syntax().text_range_with_trivia();
+        ctx.model()
+            .all_unresolved_variable_references()
+            .filter(|reference| *reference.range() == range)
+            .filter_map(|reference| reference.referenced_operation())
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, operation: &Self::State) -> Option<RuleDiagnostic> {
+        let variable = ctx.query();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            variable.range(),
+            markup! {
+                "The variable "<Emphasis>{variable.syntax().text_trimmed().to_string()}</Emphasis>" isn't defined by the operation."
+            },
+        );
+
+        // The variable is used by a fragment spread in the operation
+        let diagnostic = if operation
+            .syntax()
+            .text_range()
+            .contains_range(variable.range())
+        {
+            diagnostic
+        } else {
+            let name = operation
+                .name()
+                .and_then(|name| name.value_token().ok())
+                .map_or_else(
+                    || "anonymous".to_string(),
+                    |name| name.text_trimmed().to_string(),
+                );
+            diagnostic.detail(
+                operation.ty().ok()?.range(),
+                markup! {
+                    "The fragment using the variable is spread in the "<Emphasis>{name}</Emphasis>" operation."
+                },
+            )
+        };
+
+        Some(diagnostic.note(markup! {
+            "Add the variable to the variable definitions of the operation."
+        }))
+    }
+}
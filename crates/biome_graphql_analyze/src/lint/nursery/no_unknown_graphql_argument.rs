@@ -0,0 +1,130 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlArgument, GraphqlArguments};
+use biome_rowan::{AstNode, TokenText};
+use biome_rule_options::no_unknown_graphql_argument::NoUnknownGraphqlArgumentOptions;
+
+declare_lint_rule! {
+    /// Disallow arguments that aren't defined on the field or directive they're passed to.
+    ///
+    /// The arguments passed to a field or a directive must be declared by its
+    /// definition in the schema.
+    ///
+    /// This rule validates documents against the schema configured with the
+    /// [`graphql.schema`](https://biomejs.dev/reference/configuration/#graphqlschema) option,
+    /// and doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero(episode: String): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero(season: "EMPIRE") {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     name @include(unless: false)
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero(episode: String): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero(episode: "EMPIRE") {
+    ///     name @include(if: true)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownGraphqlArgument {
+        version: "next",
+        name: "noUnknownGraphqlArgument",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("known-argument-names").same()],
+    }
+}
+
+impl Rule for NoUnknownGraphqlArgument {
+    type Query = Schema<GraphqlArgument>;
+    type State = TokenText;
+    type Signals = Option<Self::State>;
+    type Options = NoUnknownGraphqlArgumentOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.schema()?;
+        let argument = ctx.query();
+        let arguments = GraphqlArguments::cast(argument.syntax().grand_parent()?)?;
+        let definitions = schema.arguments_definition(&arguments)?;
+
+        let name = argument.name().ok()?.value_token().ok()?;
+        if definitions
+            .iter()
+            .any(|definition| definition.name() == name.text_trimmed())
+        {
+            return None;
+        }
+
+        Some(name.token_text_trimmed())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let schema = ctx.schema()?;
+        let arguments = GraphqlArguments::cast(ctx.query().syntax().grand_parent()?)?;
+        let definitions = schema.arguments_definition(&arguments)?;
+
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().name().ok()?.range(),
+            markup! {
+                "The argument "<Emphasis>{name.text()}</Emphasis>" isn't defined in the schema."
+            },
+        );
+
+        let diagnostic = if definitions.is_empty() {
+            diagnostic.note(markup! {
+                "This field or directive doesn't accept any argument."
+            })
+        } else {
+            let accepted = definitions
+                .iter()
+                .map(|definition| definition.name())
+                .collect::<Vec<_>>()
+                .join(", ");
+            diagnostic.note(markup! {
+                "The accepted arguments are: "<Emphasis>{accepted}</Emphasis>"."
+            })
+        };
+
+        Some(diagnostic)
+    }
+}
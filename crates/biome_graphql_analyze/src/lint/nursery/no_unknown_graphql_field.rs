@@ -0,0 +1,147 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, TokenText};
+use biome_rule_options::no_unknown_graphql_field::NoUnknownGraphqlFieldOptions;
+
+declare_lint_rule! {
+    /// Disallow fields that aren't defined on the type they're selected on.
+    ///
+    /// A field can only be selected if the schema defines it on the object or the
+    /// interface of the selection set. The fields of the members of a union must be
+    /// selected with fragments, and only `__typename` can be selected on the union itself.
+    ///
+    /// This rule validates documents against the schema configured with the
+    /// [`graphql.schema`](https://biomejs.dev/reference/configuration/#graphqlschema) option,
+    /// and doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero: Character
+    ///   search: SearchResult
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    ///
+    /// type Starship {
+    ///   length: Float
+    /// }
+    ///
+    /// union SearchResult = Character | Starship
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   hero {
+    ///     age
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   search {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero: Character
+    ///   search: SearchResult
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    ///
+    /// type Starship {
+    ///   length: Float
+    /// }
+    ///
+    /// union SearchResult = Character | Starship
+    /// ```
+    ///
+    /// ```graphql
+    /// query {
+    ///   hero {
+    ///     name
+    ///   }
+    ///   search {
+    ///     __typename
+    ///     ... on Starship {
+    ///       length
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownGraphqlField {
+        version: "next",
+        name: "noUnknownGraphqlField",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type").same()],
+    }
+}
+
+pub struct UnknownField {
+    name: TokenText,
+    parent_type: Box<str>,
+}
+
+impl Rule for NoUnknownGraphqlField {
+    type Query = Schema<GraphqlField>;
+    type State = UnknownField;
+    type Signals = Option<Self::State>;
+    type Options = NoUnknownGraphqlFieldOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.schema()?;
+        let field = ctx.query();
+        let name = field.name().ok()?.value_token().ok()?;
+        let parent_type = schema.parent_type(field)?;
+
+        // The meta-fields of the introspection system aren't part of the schema
+        match name.text_trimmed() {
+            "__typename" => return None,
+            "__schema" | "__type" if schema.is_query_type(parent_type.name()) => return None,
+            _ => {}
+        }
+
+        if parent_type.field(name.text_trimmed()).is_some() {
+            return None;
+        }
+
+        Some(UnknownField {
+            name: name.token_text_trimmed(),
+            parent_type: parent_type.name().into(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The field "<Emphasis>{state.name.text()}</Emphasis>" isn't defined on the type "<Emphasis>{&*state.parent_type}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the field, or select it in a fragment on a type that defines it."
+            }),
+        )
+    }
+}
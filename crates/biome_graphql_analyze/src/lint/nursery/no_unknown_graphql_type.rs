@@ -0,0 +1,137 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlNameReference, GraphqlSyntaxKind, GraphqlSyntaxToken};
+use biome_rowan::AstNode;
+use biome_rule_options::no_unknown_graphql_type::NoUnknownGraphqlTypeOptions;
+
+declare_lint_rule! {
+    /// Disallow types that aren't defined in the GraphQL schema.
+    ///
+    /// The types used by the type conditions of fragments and by the definitions of
+    /// variables must be defined in the schema.
+    ///
+    /// This rule validates documents against the schema configured with the
+    /// [`graphql.schema`](https://biomejs.dev/reference/configuration/#graphqlschema) option,
+    /// and doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero(episode: Episode): Character
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment HeroName on Hero {
+    ///   name
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query HeroQuery($episode: Episod) {
+    ///   hero(episode: $episode) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   hero(episode: Episode): Character
+    /// }
+    ///
+    /// enum Episode {
+    ///   NEWHOPE
+    ///   EMPIRE
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query HeroQuery($episode: Episode) {
+    ///   hero(episode: $episode) {
+    ///     ...HeroName
+    ///   }
+    /// }
+    ///
+    /// fragment HeroName on Character {
+    ///   name
+    /// }
+    /// ```
+    ///
+    pub NoUnknownGraphqlType {
+        version: "next",
+        name: "noUnknownGraphqlType",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("known-type-names").same()],
+    }
+}
+
+impl Rule for NoUnknownGraphqlType {
+    type Query = Schema<GraphqlNameReference>;
+    type State = GraphqlSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = NoUnknownGraphqlTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.schema()?;
+        let reference = ctx.query();
+
+        // Only the type references of operations and fragments are validated:
+        // the type system definitions are the schema itself.
+        let context = reference.syntax().ancestors().skip(1).find(|node| {
+            !matches!(
+                node.kind(),
+                GraphqlSyntaxKind::GRAPHQL_LIST_TYPE | GraphqlSyntaxKind::GRAPHQL_NON_NULL_TYPE
+            )
+        })?;
+        if !matches!(
+            context.kind(),
+            GraphqlSyntaxKind::GRAPHQL_TYPE_CONDITION
+                | GraphqlSyntaxKind::GRAPHQL_VARIABLE_DEFINITION
+        ) {
+            return None;
+        }
+
+        let name = reference.value_token().ok()?;
+        if schema.get_type(name.text_trimmed()).is_some() {
+            return None;
+        }
+
+        Some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The type "<Emphasis>{name.text_trimmed()}</Emphasis>" isn't defined in the schema."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the type, or add it to the schema if it's missing."
+            }),
+        )
+    }
+}
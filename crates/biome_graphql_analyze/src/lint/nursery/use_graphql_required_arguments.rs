@@ -0,0 +1,172 @@
+use crate::services::schema::Schema;
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlArguments, GraphqlDirective, GraphqlField};
+use biome_rowan::{AstNode, AstNodeList, TextRange, declare_node_union};
+use biome_rule_options::use_graphql_required_arguments::UseGraphqlRequiredArgumentsOptions;
+
+declare_lint_rule! {
+    /// Require the arguments that a field or a directive can't be used without.
+    ///
+    /// An argument is required when its type is non-null and it has no default value.
+    /// Fields and directives must be passed all their required arguments.
+    ///
+    /// This rule validates documents against the schema configured with the
+    /// [`graphql.schema`](https://biomejs.dev/reference/configuration/#graphqlschema) option,
+    /// and doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   character(id: ID!, first: Int! = 10): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   character {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   character(id: "1000") {
+    ///     name @skip
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,file=schema.graphql
+    /// type Query {
+    ///   character(id: ID!, first: Int! = 10): Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query {
+    ///   character(id: "1000") {
+    ///     name @skip(if: false)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseGraphqlRequiredArguments {
+        version: "next",
+        name: "useGraphqlRequiredArguments",
+        language: "graphql",
+        recommended: false,
+        sources: &[RuleSource::EslintGraphql("provided-required-arguments").same()],
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlArgumentsOwner = GraphqlField | GraphqlDirective
+}
+
+impl AnyGraphqlArgumentsOwner {
+    fn arguments(&self) -> Option<GraphqlArguments> {
+        match self {
+            Self::GraphqlField(field) => field.arguments(),
+            Self::GraphqlDirective(directive) => directive.arguments(),
+        }
+    }
+
+    /// The range of the name of the field or the directive
+    fn name_range(&self) -> Option<TextRange> {
+        match self {
+            Self::GraphqlField(field) => Some(field.name().ok()?.range()),
+            Self::GraphqlDirective(directive) => Some(
+                directive
+                    .at_token()
+                    .ok()?
+                    .text_trimmed_range()
+                    .cover(directive.name().ok()?.range()),
+            ),
+        }
+    }
+}
+
+pub struct MissingArgument {
+    name: Box<str>,
+    ty: String,
+}
+
+impl Rule for UseGraphqlRequiredArguments {
+    type Query = Schema<AnyGraphqlArgumentsOwner>;
+    type State = MissingArgument;
+    type Signals = Box<[Self::State]>;
+    type Options = UseGraphqlRequiredArgumentsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.schema() else {
+            return Box::default();
+        };
+        let owner = ctx.query();
+        let definitions = match owner {
+            AnyGraphqlArgumentsOwner::GraphqlField(field) => {
+                schema.field(field).map(|field| field.arguments())
+            }
+            AnyGraphqlArgumentsOwner::GraphqlDirective(directive) => schema
+                .directive(directive)
+                .map(|directive| directive.arguments()),
+        };
+        let Some(definitions) = definitions else {
+            return Box::default();
+        };
+
+        let provided = owner
+            .arguments()
+            .map(|arguments| {
+                arguments
+                    .arguments()
+                    .iter()
+                    .filter_map(|argument| argument.name().ok()?.value_token().ok())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        definitions
+            .iter()
+            .filter(|definition| definition.is_required())
+            .filter(|definition| {
+                !provided
+                    .iter()
+                    .any(|name| name.text_trimmed() == definition.name())
+            })
+            .map(|definition| MissingArgument {
+                name: definition.name().into(),
+                ty: definition.ty().to_string(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().name_range()?,
+                markup! {
+                    "The required argument "<Emphasis>{&*state.name}</Emphasis>" is missing."
+                },
+            )
+            .note(markup! {
+                "The argument has the non-null type "<Emphasis>{&state.ty}</Emphasis>" and no default value, so it must be provided."
+            }),
+        )
+    }
+}
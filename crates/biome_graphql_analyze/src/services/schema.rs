@@ -0,0 +1,75 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_graphql_semantic::GraphqlSchema;
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+/// Service providing access to the schema that operations are validated against.
+///
+/// The schema is only available when the project configures `graphql.schema`.
+/// Rules using this service should emit no diagnostics when it's missing.
+#[derive(Clone, Debug)]
+pub struct SchemaServices {
+    schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl SchemaServices {
+    pub fn schema(&self) -> Option<&GraphqlSchema> {
+        self.schema.as_deref()
+    }
+}
+
+impl FromServices for SchemaServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        let schema: &Option<Arc<GraphqlSchema>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["GraphqlSchema"]))?;
+
+        Ok(Self {
+            schema: schema.clone(),
+        })
+    }
+}
+
+impl Phase for SchemaServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type for GraphQL lint rules that validate nodes against the [GraphqlSchema].
+///
+/// Use `type Query = Schema<GraphqlField>` to query AST nodes while also having
+/// access to the schema via `ctx.schema()`.
+#[derive(Clone)]
+pub struct Schema<N>(pub N);
+
+impl<N> Queryable for Schema<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    type Input = GraphqlSyntaxNode;
+    type Output = N;
+
+    type Language = GraphqlLanguage;
+    type Services = SchemaServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<GraphqlLanguage>, _root: &GraphqlRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
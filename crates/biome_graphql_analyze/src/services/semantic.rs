@@ -0,0 +1,123 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
+};
+use biome_graphql_semantic::{SemanticEventExtractor, SemanticModel, SemanticModelBuilder};
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
+use biome_rowan::{AstNode, WalkEvent};
+
+pub struct SemanticServices {
+    model: SemanticModel,
+}
+
+impl SemanticServices {
+    pub fn model(&self) -> &SemanticModel {
+        &self.model
+    }
+}
+
+impl FromServices for SemanticServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        let model: &SemanticModel = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["SemanticModel"]))?;
+
+        Ok(Self {
+            model: model.clone(),
+        })
+    }
+}
+
+impl Phase for SemanticServices {
+    fn phase() -> Phases {
+        Phases::Semantic
+    }
+}
+
+pub struct SemanticModelBuilderVisitor {
+    extractor: SemanticEventExtractor,
+    builder: SemanticModelBuilder,
+}
+
+impl SemanticModelBuilderVisitor {
+    pub(crate) fn new(root: &GraphqlRoot) -> Self {
+        Self {
+            extractor: SemanticEventExtractor::default(),
+            builder: SemanticModelBuilder::new(root.clone()),
+        }
+    }
+}
+
+impl Visitor for SemanticModelBuilderVisitor {
+    type Language = GraphqlLanguage;
+
+    fn visit(
+        &mut self,
+        event: &WalkEvent<GraphqlSyntaxNode>,
+        _ctx: VisitorContext<Self::Language>,
+    ) {
+        match event {
+            WalkEvent::Enter(node) => {
+                self.builder.push_node(node);
+                self.extractor.enter(node);
+            }
+            WalkEvent::Leave(node) => self.extractor.leave(node),
+        }
+
+        while let Some(event) = self.extractor.pop() {
+            self.builder.push_event(event);
+        }
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<Self::Language>) {
+        let model = self.builder.build();
+        ctx.services.insert_service(model);
+    }
+}
+
+/// The [Semantic] type usable by lint rules **that use the semantic model** to match on specific [AstNode] types
+///
+/// ```ignore
+/// impl Rule for SampleGraphqlLintRule {
+///    type Query = Semantic<GraphqlFragmentSpread>;
+///    type State = ();
+///    type Signals = Option<Self::State>;
+///    type Options = ();
+///    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+///     let node = ctx.query();
+///     // The model holds the bindings and the references of the document.
+///     let model = ctx.model();
+///     //.....//
+///    }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Semantic<N>(pub N);
+
+impl<N> Queryable for Semantic<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    type Input = GraphqlSyntaxNode;
+    type Output = N;
+
+    type Language = GraphqlLanguage;
+    type Services = SemanticServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<GraphqlLanguage>, root: &GraphqlRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -1,6 +1,8 @@
 use biome_analyze::{ActionFilter, AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_graphql_analyze::GraphqlAnalyzerServices;
 use biome_graphql_parser::parse_graphql;
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_languages::GraphqlFileSource;
 use biome_rowan::AstNode;
@@ -12,6 +14,7 @@ use biome_test_utils::{
 };
 use camino::Utf8Path;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{graphql,json,jsonc}", crate::run_test, "module"}
@@ -104,38 +107,41 @@ pub(crate) fn analyze_and_snap(
     let options =
         create_analyzer_options::<GraphqlLanguage>(input_file, working_directory, &mut diagnostics);
 
-    let (_, errors) = biome_graphql_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions(ActionFilter::all()) {
-                if check_action_type.is_suppression() {
-                    if action.is_suppression() {
+    let services = GraphqlAnalyzerServices::default().with_schema(Some(load_schema()));
+
+    let (_, errors) =
+        biome_graphql_analyze::analyze(&root, filter, &options, services, &[], |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions(ActionFilter::all()) {
+                    if check_action_type.is_suppression() {
+                        if action.is_suppression() {
+                            check_code_action(input_file, input_code, source_type, &action);
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(input_file, input_code, source_type, &action);
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, source_type, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
-            return ControlFlow::Continue(());
-        }
+                diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions(ActionFilter::all()) {
-            if check_action_type.is_suppression() {
-                if action.category.matches("quickfix.suppressRule") {
+            for action in event.actions(ActionFilter::all()) {
+                if check_action_type.is_suppression() {
+                    if action.category.matches("quickfix.suppressRule") {
+                        check_code_action(input_file, input_code, source_type, &action);
+                        code_fixes.push(code_fix_to_string(input_code, action));
+                    }
+                } else if !action.category.matches("quickfix.suppressRule") {
                     check_code_action(input_file, input_code, source_type, &action);
                     code_fixes.push(code_fix_to_string(input_code, action));
                 }
-            } else if !action.category.matches("quickfix.suppressRule") {
-                check_code_action(input_file, input_code, source_type, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -153,6 +159,17 @@ pub(crate) fn analyze_and_snap(
     assert_diagnostics_expectation_comment(input_file, root.syntax(), diagnostics);
 }
 
+/// Loads the schema that operations are validated against. The tests of every
+/// schema-aware rule share the `tests/schema.gql` fixture.
+fn load_schema() -> Arc<GraphqlSchema> {
+    let path = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/schema.gql");
+    let source =
+        read_to_string(&path).unwrap_or_else(|err| panic!("failed to read {path:?}: {err:?}"));
+    let mut builder = GraphqlSchemaBuilder::default();
+    builder.add_document(&parse_graphql(&source).tree());
+    Arc::new(builder.build())
+}
+
 fn check_code_action(
     path: &Utf8Path,
     source: &str,
@@ -2,6 +2,7 @@
 #[rustfmt::skip]
 mod tests;
 
+use crate::parser::GraphqlParserOptions;
 use biome_graphql_syntax::{GraphqlSyntaxKind, GraphqlSyntaxKind::*, T, TextLen, TextSize};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
@@ -31,6 +32,8 @@ pub struct GraphqlLexer<'src> {
     current_flags: TokenFlags,
 
     diagnostics: Vec<ParseDiagnostic>,
+
+    options: GraphqlParserOptions,
 }
 
 impl<'src> Lexer<'src> for GraphqlLexer<'src> {
@@ -146,9 +149,14 @@ impl<'src> GraphqlLexer<'src> {
             position: 0,
             diagnostics: vec![],
             unicode_bom_length: 0,
+            options: GraphqlParserOptions::default(),
         }
     }
 
+    pub(crate) fn with_options(self, options: GraphqlParserOptions) -> Self {
+        Self { options, ..self }
+    }
+
     /// Bumps the current byte and creates a lexed token of the passed in kind
     fn consume_byte(&mut self, tok: GraphqlSyntaxKind) -> GraphqlSyntaxKind {
         self.advance(1);
@@ -181,6 +189,9 @@ impl<'src> GraphqlLexer<'src> {
             b'#' => self.consume_comment(),
             _ if is_name_start(current) => self.consume_name(current),
             _ if is_number_start(current) => self.consume_number(current),
+            _ if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
+                self.consume_metavariable()
+            }
             _ if self.position == 0 => {
                 if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
                     self.unicode_bom_length = bom_size;
@@ -307,6 +318,55 @@ impl<'src> GraphqlLexer<'src> {
         }
     }
 
+    /// Check if the current position is at the start of a GritQL metavariable (µ prefix).
+    fn is_metavariable_start(&self) -> bool {
+        let current_char = self.current_char_unchecked();
+        if current_char == 'µ' {
+            let current_char_length = current_char.len_utf8();
+            // µ[a-zA-Z_][a-zA-Z0-9_]*
+            if self.byte_at(current_char_length).is_some_and(is_name_start) {
+                return true;
+            }
+
+            // µ... (spread operator)
+            if self.byte_at(current_char_length) == Some(b'.')
+                && self.byte_at(current_char_length + 1) == Some(b'.')
+                && self.byte_at(current_char_length + 2) == Some(b'.')
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Consume a GritQL metavariable (µ[a-zA-Z_][a-zA-Z0-9_]*|µ...).
+    ///
+    /// GraphQL has no dedicated metavariable syntax, so metavariables are
+    /// lexed as names. This allows them to appear anywhere a name is expected.
+    fn consume_metavariable(&mut self) -> GraphqlSyntaxKind {
+        debug_assert!(self.is_metavariable_start());
+
+        // SAFETY: We know the current character is µ.
+        let current_char = self.current_char_unchecked();
+        self.advance(current_char.len_utf8());
+
+        if self.current_byte() == Some(b'.') {
+            // SAFETY: We know that the current token is µ...
+            self.advance(3);
+        } else {
+            while let Some(byte) = self.current_byte() {
+                if is_name_continue(byte) {
+                    self.advance(1);
+                } else {
+                    break;
+                }
+            }
+        }
+
+        T![ident]
+    }
+
     #[inline]
     fn consume_unexpected_character(&mut self) -> GraphqlSyntaxKind {
         self.assert_current_char_boundary();
@@ -2,6 +2,7 @@
 #![expect(unused_mut, unused_variables)]
 
 use super::{GraphqlLexer, TextSize};
+use crate::parser::GraphqlParserOptions;
 use biome_graphql_syntax::GraphqlSyntaxKind::{self, EOF};
 use biome_parser::lexer::Lexer;
 use quickcheck_macros::quickcheck;
@@ -341,6 +342,31 @@ fn name() {
     }
 }
 
+#[test]
+fn metavariable() {
+    assert_lex! {
+        r#"µname"#,
+        ERROR_TOKEN:2,
+        IDENT:4,
+    }
+
+    let options = GraphqlParserOptions::default().allow_metavariables();
+    let mut lexer = GraphqlLexer::from_str("µname µ...").with_options(options);
+    let mut tokens = vec![];
+    while lexer.next_token(()) != EOF {
+        tokens.push((lexer.current(), lexer.current_range().len()));
+    }
+
+    assert_eq!(
+        tokens,
+        vec![
+            (GraphqlSyntaxKind::IDENT, TextSize::from(6)),
+            (GraphqlSyntaxKind::WHITESPACE, TextSize::from(1)),
+            (GraphqlSyntaxKind::IDENT, TextSize::from(5)),
+        ]
+    );
+}
+
 #[test]
 fn dot() {
     assert_lex! {
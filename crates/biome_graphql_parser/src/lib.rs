@@ -9,6 +9,8 @@ use biome_parser::{AnyParse, EmbeddedNodeParse, NodeParse};
 use biome_rowan::{AstNode, NodeCache, SyntaxNodeWithOffset};
 use parser::{GraphqlParser, parse_root};
 
+pub use parser::GraphqlParserOptions;
+
 mod lexer;
 mod parser;
 mod token_source;
@@ -26,7 +28,23 @@ pub fn parse_graphql(source: &str) -> GraphqlParse {
 
 /// Parses the provided string as Graphql program using the provided node cache.
 pub fn parse_graphql_with_cache(source: &str, cache: &mut NodeCache) -> GraphqlParse {
-    let mut parser = GraphqlParser::new(source);
+    parse_graphql_with_options_and_cache(source, GraphqlParserOptions::default(), cache)
+}
+
+/// Parses the provided string as Graphql program using the given parser options.
+pub fn parse_graphql_with_options(source: &str, options: GraphqlParserOptions) -> GraphqlParse {
+    let mut cache = NodeCache::default();
+    parse_graphql_with_options_and_cache(source, options, &mut cache)
+}
+
+/// Parses the provided string as Graphql program using the given parser
+/// options and node cache.
+pub fn parse_graphql_with_options_and_cache(
+    source: &str,
+    options: GraphqlParserOptions,
+    cache: &mut NodeCache,
+) -> GraphqlParse {
+    let mut parser = GraphqlParser::new(source, options);
 
     parse_root(&mut parser);
 
@@ -151,7 +169,7 @@ pub fn parse_graphql_with_offset_and_cache(
     base_offset: biome_rowan::TextSize,
     cache: &mut NodeCache,
 ) -> GraphqlOffsetParse {
-    let mut parser = GraphqlParser::new(source);
+    let mut parser = GraphqlParser::new(source, GraphqlParserOptions::default());
 
     parse_root(&mut parser);
 
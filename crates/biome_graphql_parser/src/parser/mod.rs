@@ -61,16 +61,34 @@ const GRAPHQL_POTENTIAL_NAME_SET: TokenSet<GraphqlSyntaxKind> = token_set![
     INPUT_FIELD_DEFINITION_KW,
 ];
 
+#[derive(Debug, Default, Copy, Clone)]
+pub struct GraphqlParserOptions {
+    /// Enables parsing of GritQL metavariables as names.
+    pub allow_metavariables: bool,
+}
+
+impl GraphqlParserOptions {
+    #[must_use]
+    pub fn allow_metavariables(mut self) -> Self {
+        self.allow_metavariables = true;
+        self
+    }
+
+    pub fn is_metavariable_enabled(&self) -> bool {
+        self.allow_metavariables
+    }
+}
+
 pub(crate) struct GraphqlParser<'source> {
     context: ParserContext<GraphqlSyntaxKind>,
     source: GraphqlTokenSource<'source>,
 }
 
 impl<'source> GraphqlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: GraphqlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: GraphqlTokenSource::from_str(source),
+            source: GraphqlTokenSource::from_str(source, options),
         }
     }
 
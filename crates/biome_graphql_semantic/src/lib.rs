@@ -1,9 +1,11 @@
 #![deny(clippy::use_self)]
 
 mod events;
+mod schema;
 mod semantic_model;
 
 pub use events::*;
+pub use schema::*;
 pub use semantic_model::*;
 
 #[cfg(test)]
@@ -0,0 +1,353 @@
+use super::{
+    BUILT_IN_SCALARS, GraphqlSchema, OperationKind, SchemaDirective, SchemaField, SchemaInputValue,
+    SchemaType, SchemaTypeKind, SchemaTypeRef,
+};
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlTypeDefinition, AnyGraphqlTypeExtension,
+    GraphqlArgumentsDefinition, GraphqlEnumValuesDefinition, GraphqlFieldsDefinition,
+    GraphqlInputFieldsDefinition, GraphqlInputValueDefinition, GraphqlRoot,
+    GraphqlRootOperationTypes, GraphqlSyntaxToken, GraphqlUnionMemberTypes,
+};
+use biome_rowan::{AstNodeList, AstSeparatedList};
+
+/// Builds a [GraphqlSchema] from SDL documents and introspection results.
+///
+/// A type extension can be added before or after the definition of the type it
+/// extends. Once all the sources are added, [GraphqlSchemaBuilder::build] adds the
+/// built-in scalars and directives, and resolves the default root operation types.
+///
+/// ```rust
+/// use biome_graphql_parser::parse_graphql;
+/// use biome_graphql_semantic::{GraphqlSchemaBuilder, OperationKind};
+///
+/// let parsed = parse_graphql("type Query { hero: String }");
+/// let mut builder = GraphqlSchemaBuilder::default();
+/// builder.add_document(&parsed.tree());
+/// let schema = builder.build();
+///
+/// let query = schema.root_type(OperationKind::Query).unwrap();
+/// assert!(query.field("hero").is_some());
+/// ```
+#[derive(Debug, Default)]
+pub struct GraphqlSchemaBuilder {
+    pub(crate) schema: GraphqlSchema,
+}
+
+impl GraphqlSchemaBuilder {
+    /// Adds the type system definitions and extensions of an SDL document.
+    ///
+    /// Executable definitions, such as operations and fragments, are ignored.
+    pub fn add_document(&mut self, root: &GraphqlRoot) {
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(definition) => {
+                    self.add_type_definition(definition);
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(extension) => {
+                    self.add_type_extension(extension);
+                }
+                AnyGraphqlDefinition::GraphqlDirectiveDefinition(definition) => {
+                    if let Some(name) = definition.name().and_then(|name| name.value_token()).ok() {
+                        self.schema.directives.insert(
+                            text(&name),
+                            SchemaDirective {
+                                arguments: arguments(definition.arguments()),
+                            },
+                        );
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    if let Ok(root_operation_types) = definition.root_operation_types() {
+                        self.add_root_operation_types(&root_operation_types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    if let Some(root_operation_types) = extension.root_operation_types() {
+                        self.add_root_operation_types(&root_operation_types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlBogusDefinition(_)
+                | AnyGraphqlDefinition::GraphqlFragmentDefinition(_)
+                | AnyGraphqlDefinition::GraphqlOperationDefinition(_)
+                | AnyGraphqlDefinition::GraphqlSelectionSet(_) => {}
+            }
+        }
+    }
+
+    /// Returns the schema built from the added sources.
+    pub fn build(mut self) -> GraphqlSchema {
+        for scalar in BUILT_IN_SCALARS {
+            self.type_entry(scalar.into(), SchemaTypeKind::Scalar);
+        }
+
+        let built_in_directives = [
+            (
+                "deprecated",
+                vec![built_in_argument("reason", "String", false, true)],
+            ),
+            (
+                "include",
+                vec![built_in_argument("if", "Boolean", true, false)],
+            ),
+            ("oneOf", vec![]),
+            (
+                "skip",
+                vec![built_in_argument("if", "Boolean", true, false)],
+            ),
+            (
+                "specifiedBy",
+                vec![built_in_argument("url", "String", true, false)],
+            ),
+        ];
+        for (name, arguments) in built_in_directives {
+            self.schema
+                .directives
+                .entry(name.into())
+                .or_insert(SchemaDirective { arguments });
+        }
+
+        let schema = &mut self.schema;
+        for (root_type, name) in [
+            (&mut schema.query_type, "Query"),
+            (&mut schema.mutation_type, "Mutation"),
+            (&mut schema.subscription_type, "Subscription"),
+        ] {
+            if root_type.is_none() && schema.types.contains_key(name) {
+                *root_type = Some(name.into());
+            }
+        }
+
+        self.schema
+    }
+
+    fn add_type_definition(&mut self, definition: AnyGraphqlTypeDefinition) {
+        let (name, kind) = match &definition {
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::Enum)
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::InputObject)
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::Interface)
+            }
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::Object)
+            }
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::Scalar)
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                (definition.name(), SchemaTypeKind::Union)
+            }
+        };
+        let Some(name) = name.and_then(|name| name.value_token()).ok() else {
+            return;
+        };
+        let ty = self.type_entry(text(&name), kind);
+        // The extensions of a type can be added before its definition
+        ty.kind = kind;
+
+        match definition {
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                add_enum_values(ty, definition.enum_values());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                add_input_fields(ty, definition.input_fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                add_fields(ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => {
+                add_fields(ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(_) => {}
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                add_union_members(ty, definition.union_members());
+            }
+        }
+    }
+
+    fn add_type_extension(&mut self, extension: AnyGraphqlTypeExtension) {
+        let (name, kind) = match &extension {
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::Enum)
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::InputObject)
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::Interface)
+            }
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::Object)
+            }
+            AnyGraphqlTypeExtension::GraphqlScalarTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::Scalar)
+            }
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(extension) => {
+                (extension.name(), SchemaTypeKind::Union)
+            }
+        };
+        let Some(name) = name.and_then(|name| name.value_token()).ok() else {
+            return;
+        };
+        let ty = self.type_entry(text(&name), kind);
+
+        match extension {
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                add_enum_values(ty, extension.enum_values());
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => {
+                add_input_fields(ty, extension.input_fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => {
+                add_fields(ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                add_fields(ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlScalarTypeExtension(_) => {}
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(extension) => {
+                add_union_members(ty, extension.union_members());
+            }
+        }
+    }
+
+    fn add_root_operation_types(&mut self, root_operation_types: &GraphqlRootOperationTypes) {
+        for root_operation_type in root_operation_types.root_operation_type() {
+            let Some(operation) = root_operation_type
+                .operation_type()
+                .ok()
+                .and_then(|operation_type| OperationKind::from_operation_type(&operation_type))
+            else {
+                continue;
+            };
+            let Some(name) = root_operation_type
+                .named_type()
+                .and_then(|name| name.value_token())
+                .ok()
+            else {
+                continue;
+            };
+            self.set_root_type(operation, text(&name));
+        }
+    }
+
+    pub(crate) fn set_root_type(&mut self, operation: OperationKind, name: Box<str>) {
+        let root_type = match operation {
+            OperationKind::Query => &mut self.schema.query_type,
+            OperationKind::Mutation => &mut self.schema.mutation_type,
+            OperationKind::Subscription => &mut self.schema.subscription_type,
+        };
+        *root_type = Some(name);
+    }
+
+    pub(crate) fn type_entry(&mut self, name: Box<str>, kind: SchemaTypeKind) -> &mut SchemaType {
+        self.schema
+            .types
+            .entry(name.clone())
+            .or_insert_with(|| SchemaType::new(name, kind))
+    }
+}
+
+fn text(token: &GraphqlSyntaxToken) -> Box<str> {
+    token.text_trimmed().into()
+}
+
+fn built_in_argument(
+    name: &str,
+    ty: &str,
+    is_non_null: bool,
+    has_default: bool,
+) -> SchemaInputValue {
+    let named = SchemaTypeRef::Named(ty.into());
+    SchemaInputValue {
+        name: name.into(),
+        ty: if is_non_null {
+            SchemaTypeRef::NonNull(Box::new(named))
+        } else {
+            named
+        },
+        has_default,
+    }
+}
+
+fn input_value(definition: &GraphqlInputValueDefinition) -> Option<SchemaInputValue> {
+    Some(SchemaInputValue {
+        name: text(&definition.name().ok()?.value_token().ok()?),
+        ty: SchemaTypeRef::from_type(&definition.ty().ok()?)?,
+        has_default: definition.default().is_some(),
+    })
+}
+
+fn arguments(definition: Option<GraphqlArgumentsDefinition>) -> Vec<SchemaInputValue> {
+    definition
+        .map(|definition| {
+            definition
+                .arguments()
+                .iter()
+                .filter_map(|argument| input_value(&argument))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn add_fields(ty: &mut SchemaType, fields: Option<GraphqlFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+    for field in fields.fields() {
+        let Some(name) = field.name().and_then(|name| name.value_token()).ok() else {
+            continue;
+        };
+        let Some(field_type) = field.ty().ok().and_then(|ty| SchemaTypeRef::from_type(&ty)) else {
+            continue;
+        };
+        ty.fields.insert(
+            text(&name),
+            SchemaField {
+                ty: field_type,
+                arguments: arguments(field.arguments()),
+            },
+        );
+    }
+}
+
+fn add_input_fields(ty: &mut SchemaType, fields: Option<GraphqlInputFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+    ty.input_fields.extend(
+        fields
+            .fields()
+            .iter()
+            .filter_map(|field| input_value(&field)),
+    );
+}
+
+fn add_enum_values(ty: &mut SchemaType, values: Option<GraphqlEnumValuesDefinition>) {
+    let Some(values) = values else {
+        return;
+    };
+    ty.enum_values.extend(
+        values
+            .values()
+            .iter()
+            .filter_map(|value| value.value().and_then(|value| value.value_token()).ok())
+            .map(|value| text(&value)),
+    );
+}
+
+fn add_union_members(ty: &mut SchemaType, members: Option<GraphqlUnionMemberTypes>) {
+    let Some(members) = members else {
+        return;
+    };
+    ty.members.extend(
+        members
+            .members()
+            .iter()
+            .filter_map(|member| member.and_then(|member| member.value_token()).ok())
+            .map(|member| text(&member)),
+    );
+}
@@ -0,0 +1,203 @@
+//! Support for the result of an [introspection query](https://spec.graphql.org/October2021/#sec-Introspection),
+//! as printed by most GraphQL tools when they download the schema of a service.
+
+use super::{
+    GraphqlSchemaBuilder, OperationKind, SchemaDirective, SchemaField, SchemaInputValue,
+    SchemaTypeKind, SchemaTypeRef,
+};
+use serde::Deserialize;
+
+/// The introspection result, either complete or without the `data` wrapper.
+#[derive(Deserialize)]
+struct IntrospectionResult {
+    data: Option<IntrospectionData>,
+    #[serde(rename = "__schema")]
+    schema: Option<IntrospectionSchema>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    query_type: Option<IntrospectionNamedType>,
+    mutation_type: Option<IntrospectionNamedType>,
+    subscription_type: Option<IntrospectionNamedType>,
+    types: Vec<IntrospectionType>,
+    #[serde(default)]
+    directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionNamedType {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: String,
+    name: String,
+    fields: Option<Vec<IntrospectionField>>,
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+    possible_types: Option<Vec<IntrospectionNamedType>>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionField {
+    name: String,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+    default_value: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionEnumValue {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionDirective {
+    name: String,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeRef {
+    kind: String,
+    name: Option<String>,
+    of_type: Option<Box<Self>>,
+}
+
+impl IntrospectionTypeRef {
+    fn to_type_ref(&self) -> Option<SchemaTypeRef> {
+        match self.kind.as_str() {
+            "NON_NULL" => Some(SchemaTypeRef::NonNull(Box::new(
+                self.of_type.as_ref()?.to_type_ref()?,
+            ))),
+            "LIST" => Some(SchemaTypeRef::List(Box::new(
+                self.of_type.as_ref()?.to_type_ref()?,
+            ))),
+            _ => Some(SchemaTypeRef::Named(self.name.as_deref()?.into())),
+        }
+    }
+}
+
+impl IntrospectionInputValue {
+    fn to_input_value(&self) -> Option<SchemaInputValue> {
+        Some(SchemaInputValue {
+            name: self.name.as_str().into(),
+            ty: self.ty.to_type_ref()?,
+            has_default: self.default_value.is_some(),
+        })
+    }
+}
+
+fn input_values(values: &[IntrospectionInputValue]) -> Vec<SchemaInputValue> {
+    values
+        .iter()
+        .filter_map(IntrospectionInputValue::to_input_value)
+        .collect()
+}
+
+impl GraphqlSchemaBuilder {
+    /// Adds the types and directives of the JSON result of an introspection query.
+    ///
+    /// Both the complete response, with its `data` property, and the bare
+    /// `__schema` object are accepted.
+    pub fn add_introspection(&mut self, json: &str) -> Result<(), serde_json::Error> {
+        let result: IntrospectionResult = serde_json::from_str(json)?;
+        let Some(schema) = result.data.map(|data| data.schema).or(result.schema) else {
+            return Err(serde::de::Error::missing_field("__schema"));
+        };
+
+        for (operation, root_type) in [
+            (OperationKind::Query, schema.query_type),
+            (OperationKind::Mutation, schema.mutation_type),
+            (OperationKind::Subscription, schema.subscription_type),
+        ] {
+            if let Some(root_type) = root_type {
+                self.set_root_type(operation, root_type.name.into());
+            }
+        }
+
+        for introspection_type in schema.types {
+            // The types of the introspection system itself aren't part of the SDL
+            // of a schema, and they're only reachable through `__schema` and `__type`.
+            if introspection_type.name.starts_with("__") {
+                continue;
+            }
+            let kind = match introspection_type.kind.as_str() {
+                "SCALAR" => SchemaTypeKind::Scalar,
+                "OBJECT" => SchemaTypeKind::Object,
+                "INTERFACE" => SchemaTypeKind::Interface,
+                "UNION" => SchemaTypeKind::Union,
+                "ENUM" => SchemaTypeKind::Enum,
+                "INPUT_OBJECT" => SchemaTypeKind::InputObject,
+                _ => continue,
+            };
+            let ty = self.type_entry(introspection_type.name.into(), kind);
+            ty.kind = kind;
+
+            for field in introspection_type.fields.unwrap_or_default() {
+                let Some(field_type) = field.ty.to_type_ref() else {
+                    continue;
+                };
+                ty.fields.insert(
+                    field.name.into(),
+                    SchemaField {
+                        ty: field_type,
+                        arguments: input_values(&field.args),
+                    },
+                );
+            }
+            ty.input_fields.extend(input_values(
+                &introspection_type.input_fields.unwrap_or_default(),
+            ));
+            ty.enum_values.extend(
+                introspection_type
+                    .enum_values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|value| value.name.into()),
+            );
+            if kind == SchemaTypeKind::Union {
+                ty.members.extend(
+                    introspection_type
+                        .possible_types
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|member| member.name.into()),
+                );
+            }
+        }
+
+        for directive in schema.directives {
+            self.schema.directives.insert(
+                directive.name.into(),
+                SchemaDirective {
+                    arguments: input_values(&directive.args),
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
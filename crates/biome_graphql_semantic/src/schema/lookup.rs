@@ -0,0 +1,82 @@
+use super::{
+    GraphqlSchema, OperationKind, SchemaDirective, SchemaField, SchemaInputValue, SchemaType,
+};
+use biome_graphql_syntax::{
+    GraphqlArguments, GraphqlDirective, GraphqlField, GraphqlFragmentDefinition,
+    GraphqlInlineFragment, GraphqlOperationDefinition, GraphqlSelectionSet, GraphqlSyntaxKind,
+    GraphqlSyntaxNode, GraphqlTypeCondition,
+};
+use biome_rowan::AstNode;
+
+impl GraphqlSchema {
+    /// Returns the type of the values that the selections of `selection_set` are made on.
+    ///
+    /// Returns [None] when the type can't be resolved, for example because one of the
+    /// enclosing fields doesn't exist in the schema.
+    pub fn selection_set_type(&self, selection_set: &GraphqlSelectionSet) -> Option<&SchemaType> {
+        let parent = selection_set.syntax().parent()?;
+        match parent.kind() {
+            GraphqlSyntaxKind::GRAPHQL_FIELD => {
+                let field = self.field(&GraphqlField::unwrap_cast(parent))?;
+                self.get_type(field.ty().named_type())
+            }
+            GraphqlSyntaxKind::GRAPHQL_INLINE_FRAGMENT => {
+                let fragment = GraphqlInlineFragment::unwrap_cast(parent);
+                match fragment.type_condition() {
+                    Some(type_condition) => self.type_condition_type(&type_condition),
+                    None => self.selection_set_type(&enclosing_selection_set(fragment.syntax())?),
+                }
+            }
+            GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => {
+                let fragment = GraphqlFragmentDefinition::unwrap_cast(parent);
+                self.type_condition_type(&fragment.type_condition().ok()?)
+            }
+            GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => {
+                let operation = GraphqlOperationDefinition::unwrap_cast(parent);
+                self.root_type(OperationKind::from_operation_type(&operation.ty().ok()?)?)
+            }
+            // The shorthand form of a query, `{ field }`
+            GraphqlSyntaxKind::GRAPHQL_DEFINITION_LIST => self.root_type(OperationKind::Query),
+            _ => None,
+        }
+    }
+
+    /// Returns the type that a field of an operation or a fragment is selected on.
+    pub fn parent_type(&self, field: &GraphqlField) -> Option<&SchemaType> {
+        self.selection_set_type(&enclosing_selection_set(field.syntax())?)
+    }
+
+    /// Returns the definition of a field selected in an operation or a fragment.
+    pub fn field(&self, field: &GraphqlField) -> Option<&SchemaField> {
+        self.parent_type(field)?
+            .field(field.name().ok()?.value_token().ok()?.text_trimmed())
+    }
+
+    /// Returns the definition of a directive used in a document.
+    pub fn directive(&self, directive: &GraphqlDirective) -> Option<&SchemaDirective> {
+        self.get_directive(directive.name().ok()?.value_token().ok()?.text_trimmed())
+    }
+
+    /// Returns the definitions of the arguments accepted by the field or the
+    /// directive that `arguments` are passed to.
+    pub fn arguments_definition(
+        &self,
+        arguments: &GraphqlArguments,
+    ) -> Option<&[SchemaInputValue]> {
+        let parent = arguments.syntax().parent()?;
+        if let Some(field) = GraphqlField::cast_ref(&parent) {
+            return self.field(&field).map(SchemaField::arguments);
+        }
+        self.directive(&GraphqlDirective::cast(parent)?)
+            .map(SchemaDirective::arguments)
+    }
+
+    fn type_condition_type(&self, type_condition: &GraphqlTypeCondition) -> Option<&SchemaType> {
+        self.get_type(type_condition.ty().ok()?.value_token().ok()?.text_trimmed())
+    }
+}
+
+/// Returns the selection set that contains the selection `node`.
+fn enclosing_selection_set(node: &GraphqlSyntaxNode) -> Option<GraphqlSelectionSet> {
+    node.parent()?.parent().and_then(GraphqlSelectionSet::cast)
+}
@@ -0,0 +1,322 @@
+mod builder;
+mod introspection;
+mod lookup;
+
+pub use builder::*;
+
+use biome_graphql_syntax::{
+    AnyGraphqlPrimitiveType, AnyGraphqlType, GraphqlOperationType, GraphqlSyntaxKind,
+};
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+
+/// The names of the scalars that every schema provides.
+pub const BUILT_IN_SCALARS: [&str; 5] = ["Boolean", "Float", "ID", "Int", "String"];
+
+/// The types and directives that a GraphQL service exposes.
+///
+/// The schema can be built from any number of SDL documents, and from the result
+/// of an introspection query, using a [GraphqlSchemaBuilder]. Operations of other
+/// documents can then be validated against it.
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    pub(crate) types: FxHashMap<Box<str>, SchemaType>,
+    pub(crate) directives: FxHashMap<Box<str>, SchemaDirective>,
+    pub(crate) query_type: Option<Box<str>>,
+    pub(crate) mutation_type: Option<Box<str>>,
+    pub(crate) subscription_type: Option<Box<str>>,
+}
+
+impl GraphqlSchema {
+    /// Returns the type called `name`.
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// Returns the directive called `name`, without the leading `@`.
+    pub fn get_directive(&self, name: &str) -> Option<&SchemaDirective> {
+        self.directives.get(name)
+    }
+
+    /// Returns the name of the root type of the operations of the given kind.
+    pub fn root_type_name(&self, operation: OperationKind) -> Option<&str> {
+        match operation {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        }
+    }
+
+    /// Returns the root type of the operations of the given kind.
+    pub fn root_type(&self, operation: OperationKind) -> Option<&SchemaType> {
+        self.get_type(self.root_type_name(operation)?)
+    }
+
+    /// Whether the type called `name` is the root type of query operations.
+    pub fn is_query_type(&self, name: &str) -> bool {
+        self.query_type.as_deref() == Some(name)
+    }
+}
+
+/// The kind of an operation, which selects the root type it starts from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+impl OperationKind {
+    pub fn from_operation_type(operation_type: &GraphqlOperationType) -> Option<Self> {
+        match operation_type.value_token().ok()?.kind() {
+            GraphqlSyntaxKind::QUERY_KW => Some(Self::Query),
+            GraphqlSyntaxKind::MUTATION_KW => Some(Self::Mutation),
+            GraphqlSyntaxKind::SUBSCRIPTION_KW => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of a named type of the schema.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+/// A named type of the schema.
+#[derive(Debug)]
+pub struct SchemaType {
+    pub(crate) name: Box<str>,
+    pub(crate) kind: SchemaTypeKind,
+    /// Fields of objects and interfaces
+    pub(crate) fields: FxHashMap<Box<str>, SchemaField>,
+    /// Fields of input objects, in the order of their declaration
+    pub(crate) input_fields: Vec<SchemaInputValue>,
+    /// Values of enums
+    pub(crate) enum_values: Vec<Box<str>>,
+    /// Members of unions
+    pub(crate) members: Vec<Box<str>>,
+}
+
+impl SchemaType {
+    pub(crate) fn new(name: Box<str>, kind: SchemaTypeKind) -> Self {
+        Self {
+            name,
+            kind,
+            fields: FxHashMap::default(),
+            input_fields: Vec::new(),
+            enum_values: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> SchemaTypeKind {
+        self.kind
+    }
+
+    /// Returns the field called `name` of an object or an interface.
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.get(name)
+    }
+
+    /// Returns the field called `name` of an input object.
+    pub fn input_field(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.input_fields.iter().find(|field| field.name() == name)
+    }
+
+    /// Returns the fields of an input object.
+    pub fn input_fields(&self) -> &[SchemaInputValue] {
+        &self.input_fields
+    }
+
+    /// Whether `value` is one of the values of an enum.
+    pub fn has_enum_value(&self, value: &str) -> bool {
+        self.enum_values
+            .iter()
+            .any(|enum_value| enum_value.as_ref() == value)
+    }
+
+    /// Returns the members of a union.
+    pub fn members(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(AsRef::as_ref)
+    }
+
+    /// Whether selections can be made on values of this type.
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            SchemaTypeKind::Object | SchemaTypeKind::Interface | SchemaTypeKind::Union
+        )
+    }
+
+    /// Whether values of this type can be used as arguments and variables.
+    pub fn is_input(&self) -> bool {
+        matches!(
+            self.kind,
+            SchemaTypeKind::Scalar | SchemaTypeKind::Enum | SchemaTypeKind::InputObject
+        )
+    }
+}
+
+/// A field of an object or an interface.
+#[derive(Debug)]
+pub struct SchemaField {
+    pub(crate) ty: SchemaTypeRef,
+    pub(crate) arguments: Vec<SchemaInputValue>,
+}
+
+impl SchemaField {
+    /// The type of the values of this field.
+    pub fn ty(&self) -> &SchemaTypeRef {
+        &self.ty
+    }
+
+    /// The arguments of this field, in the order of their declaration.
+    pub fn arguments(&self) -> &[SchemaInputValue] {
+        &self.arguments
+    }
+
+    /// Returns the argument called `name`.
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name() == name)
+    }
+}
+
+/// A directive of the schema.
+#[derive(Debug)]
+pub struct SchemaDirective {
+    pub(crate) arguments: Vec<SchemaInputValue>,
+}
+
+impl SchemaDirective {
+    /// The arguments of this directive, in the order of their declaration.
+    pub fn arguments(&self) -> &[SchemaInputValue] {
+        &self.arguments
+    }
+
+    /// Returns the argument called `name`.
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name() == name)
+    }
+}
+
+/// An argument, or a field of an input object.
+#[derive(Debug)]
+pub struct SchemaInputValue {
+    pub(crate) name: Box<str>,
+    pub(crate) ty: SchemaTypeRef,
+    pub(crate) has_default: bool,
+}
+
+impl SchemaInputValue {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &SchemaTypeRef {
+        &self.ty
+    }
+
+    /// Whether the argument or field has a default value.
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    /// Whether a value must be provided for this argument or field.
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default
+    }
+}
+
+/// A reference to a type, wrapped in lists and non-null types.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaTypeRef {
+    Named(Box<str>),
+    List(Box<Self>),
+    NonNull(Box<Self>),
+}
+
+impl SchemaTypeRef {
+    /// Creates a reference from a type of a GraphQL document, such as the type of
+    /// a variable definition.
+    pub fn from_type(ty: &AnyGraphqlType) -> Option<Self> {
+        match ty {
+            AnyGraphqlType::AnyGraphqlPrimitiveType(ty) => Self::from_primitive_type(ty),
+            AnyGraphqlType::GraphqlNonNullType(ty) => Some(Self::NonNull(Box::new(
+                Self::from_primitive_type(&ty.base().ok()?)?,
+            ))),
+            AnyGraphqlType::GraphqlBogusType(_) => None,
+        }
+    }
+
+    fn from_primitive_type(ty: &AnyGraphqlPrimitiveType) -> Option<Self> {
+        match ty {
+            AnyGraphqlPrimitiveType::GraphqlListType(list) => Some(Self::List(Box::new(
+                Self::from_type(&list.element().ok()?)?,
+            ))),
+            AnyGraphqlPrimitiveType::GraphqlNameReference(name) => {
+                Some(Self::Named(name.value_token().ok()?.text_trimmed().into()))
+            }
+        }
+    }
+
+    /// Returns the name of the type wrapped by this reference.
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+
+    pub fn is_non_null(&self) -> bool {
+        matches!(self, Self::NonNull(_))
+    }
+
+    /// Returns the reference without its non-null wrapper.
+    pub fn nullable(&self) -> &Self {
+        match self {
+            Self::NonNull(ty) => ty,
+            _ => self,
+        }
+    }
+
+    /// Whether a variable of this type can be used where a value of type
+    /// `location` is expected.
+    ///
+    /// See <https://spec.graphql.org/October2021/#AreTypesCompatible()>
+    pub fn is_compatible_with(&self, location: &Self) -> bool {
+        match (self, location) {
+            (Self::NonNull(variable), Self::NonNull(location)) => {
+                variable.is_compatible_with(location)
+            }
+            (_, Self::NonNull(_)) => false,
+            (Self::NonNull(variable), _) => variable.is_compatible_with(location),
+            (Self::List(variable), Self::List(location)) => variable.is_compatible_with(location),
+            (_, Self::List(_)) | (Self::List(_), _) => false,
+            (Self::Named(variable), Self::Named(location)) => variable == location,
+        }
+    }
+}
+
+impl Display for SchemaTypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{name}"),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
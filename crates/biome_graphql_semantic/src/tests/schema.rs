@@ -0,0 +1,356 @@
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::GraphqlArguments;
+use biome_graphql_syntax::GraphqlField;
+
+use crate::GraphqlSchema;
+use crate::GraphqlSchemaBuilder;
+use crate::OperationKind;
+use crate::SchemaTypeKind;
+use crate::SchemaTypeRef;
+
+use super::extract_node;
+use super::extract_node_by_name;
+
+fn build_schema(sources: &[&str]) -> GraphqlSchema {
+    let mut builder = GraphqlSchemaBuilder::default();
+    for source in sources {
+        builder.add_document(&parse_graphql(source).tree());
+    }
+    builder.build()
+}
+
+fn type_ref(src: &str) -> SchemaTypeRef {
+    let schema = build_schema(&[&format!("type Query {{ value: {src} }}")]);
+    let query = schema.root_type(OperationKind::Query).unwrap();
+    query.field("value").unwrap().ty().clone()
+}
+
+#[test]
+fn ok_schema_from_sdl() {
+    let schema = build_schema(&[r#"
+type Query {
+    hero(episode: Episode, first: Int! = 10, id: ID!): Character
+}
+
+interface Character {
+    name: String!
+}
+
+enum Episode {
+    NEWHOPE
+    EMPIRE
+}
+
+input ReviewInput {
+    stars: Int!
+    commentary: String
+}
+
+union SearchResult = Human | Droid
+
+type Human implements Character {
+    name: String!
+}
+
+type Droid implements Character {
+    name: String!
+}
+"#]);
+
+    let query = schema.root_type(OperationKind::Query).unwrap();
+    assert_eq!(query.name(), "Query");
+    assert!(schema.root_type(OperationKind::Mutation).is_none());
+
+    let hero = query.field("hero").unwrap();
+    assert_eq!(hero.ty().to_string(), "Character");
+    assert!(!hero.argument("episode").unwrap().is_required());
+    assert!(!hero.argument("first").unwrap().is_required());
+    assert!(hero.argument("id").unwrap().is_required());
+
+    let character = schema.get_type("Character").unwrap();
+    assert_eq!(character.kind(), SchemaTypeKind::Interface);
+    assert!(character.is_composite());
+
+    let episode = schema.get_type("Episode").unwrap();
+    assert!(episode.has_enum_value("EMPIRE"));
+    assert!(!episode.has_enum_value("JEDI"));
+
+    let review_input = schema.get_type("ReviewInput").unwrap();
+    assert!(review_input.is_input());
+    assert!(review_input.input_field("stars").unwrap().is_required());
+    assert!(
+        !review_input
+            .input_field("commentary")
+            .unwrap()
+            .is_required()
+    );
+
+    let search_result = schema.get_type("SearchResult").unwrap();
+    assert_eq!(
+        search_result.members().collect::<Vec<_>>(),
+        ["Human", "Droid"]
+    );
+
+    assert!(schema.get_type("Boolean").unwrap().is_input());
+    assert!(
+        schema
+            .get_directive("skip")
+            .unwrap()
+            .argument("if")
+            .unwrap()
+            .is_required()
+    );
+}
+
+#[test]
+fn ok_schema_with_extensions() {
+    let schema = build_schema(&[
+        r#"
+extend type Query {
+    droid: String
+}
+
+extend enum Episode {
+    JEDI
+}
+"#,
+        r#"
+type Query {
+    hero: String
+}
+
+enum Episode {
+    NEWHOPE
+}
+"#,
+    ]);
+
+    let query = schema.root_type(OperationKind::Query).unwrap();
+    assert!(query.field("hero").is_some());
+    assert!(query.field("droid").is_some());
+
+    let episode = schema.get_type("Episode").unwrap();
+    assert_eq!(episode.kind(), SchemaTypeKind::Enum);
+    assert!(episode.has_enum_value("NEWHOPE"));
+    assert!(episode.has_enum_value("JEDI"));
+}
+
+#[test]
+fn ok_schema_definition() {
+    let schema = build_schema(&[r#"
+schema {
+    query: RootQuery
+}
+
+extend schema {
+    mutation: RootMutation
+}
+
+type RootQuery {
+    hero: String
+}
+
+type RootMutation {
+    like: Boolean
+}
+
+type Query {
+    unused: String
+}
+"#]);
+
+    assert_eq!(
+        schema.root_type_name(OperationKind::Query),
+        Some("RootQuery")
+    );
+    assert_eq!(
+        schema.root_type_name(OperationKind::Mutation),
+        Some("RootMutation")
+    );
+    assert!(schema.is_query_type("RootQuery"));
+    assert!(!schema.is_query_type("Query"));
+}
+
+#[test]
+fn ok_schema_from_introspection() {
+    let json = r#"{
+  "data": {
+    "__schema": {
+      "queryType": { "name": "Query" },
+      "mutationType": null,
+      "subscriptionType": null,
+      "types": [
+        {
+          "kind": "OBJECT",
+          "name": "Query",
+          "fields": [
+            {
+              "name": "hero",
+              "args": [
+                {
+                  "name": "ids",
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {
+                      "kind": "LIST",
+                      "name": null,
+                      "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null }
+                    }
+                  },
+                  "defaultValue": null
+                }
+              ],
+              "type": { "kind": "ENUM", "name": "Episode", "ofType": null }
+            }
+          ],
+          "inputFields": null,
+          "enumValues": null,
+          "possibleTypes": null
+        },
+        {
+          "kind": "ENUM",
+          "name": "Episode",
+          "fields": null,
+          "inputFields": null,
+          "enumValues": [{ "name": "EMPIRE" }],
+          "possibleTypes": null
+        },
+        {
+          "kind": "OBJECT",
+          "name": "__Schema",
+          "fields": [],
+          "inputFields": null,
+          "enumValues": null,
+          "possibleTypes": null
+        }
+      ],
+      "directives": [
+        {
+          "name": "cached",
+          "args": [
+            {
+              "name": "ttl",
+              "type": { "kind": "SCALAR", "name": "Int", "ofType": null },
+              "defaultValue": "60"
+            }
+          ]
+        }
+      ]
+    }
+  }
+}"#;
+    let mut builder = GraphqlSchemaBuilder::default();
+    builder.add_introspection(json).unwrap();
+    let schema = builder.build();
+
+    let query = schema.root_type(OperationKind::Query).unwrap();
+    let hero = query.field("hero").unwrap();
+    assert_eq!(hero.argument("ids").unwrap().ty().to_string(), "[ID]!");
+    assert!(hero.argument("ids").unwrap().is_required());
+    assert!(schema.get_type("Episode").unwrap().has_enum_value("EMPIRE"));
+    assert!(schema.get_type("__Schema").is_none());
+    assert!(
+        !schema
+            .get_directive("cached")
+            .unwrap()
+            .argument("ttl")
+            .unwrap()
+            .is_required()
+    );
+}
+
+#[test]
+fn ok_schema_from_bare_introspection() {
+    let json = r#"{ "__schema": { "queryType": { "name": "Root" }, "types": [] } }"#;
+    let mut builder = GraphqlSchemaBuilder::default();
+    builder.add_introspection(json).unwrap();
+    let schema = builder.build();
+
+    assert_eq!(schema.root_type_name(OperationKind::Query), Some("Root"));
+}
+
+#[test]
+fn err_introspection_without_schema() {
+    let mut builder = GraphqlSchemaBuilder::default();
+    assert!(builder.add_introspection(r#"{ "data": null }"#).is_err());
+    assert!(builder.add_introspection("[]").is_err());
+}
+
+#[test]
+fn ok_compatible_types() {
+    assert!(type_ref("Int!").is_compatible_with(&type_ref("Int")));
+    assert!(type_ref("Int").is_compatible_with(&type_ref("Int")));
+    assert!(type_ref("[Int!]!").is_compatible_with(&type_ref("[Int]")));
+    assert!(!type_ref("Int").is_compatible_with(&type_ref("Int!")));
+    assert!(!type_ref("[Int]").is_compatible_with(&type_ref("[Int!]")));
+    assert!(!type_ref("Int").is_compatible_with(&type_ref("[Int]")));
+    assert!(!type_ref("String").is_compatible_with(&type_ref("Int")));
+}
+
+#[test]
+fn ok_lookup_fields_of_operations() {
+    let schema = build_schema(&[r#"
+type Query {
+    hero(episode: String): Character
+}
+
+interface Character {
+    friends: [Character!]!
+}
+
+type Droid implements Character {
+    friends: [Character!]!
+    primaryFunction: String
+}
+"#]);
+
+    let src = r#"
+query HeroQuery {
+    hero(episode: "EMPIRE") {
+        ...HeroFriends
+    }
+}
+
+fragment HeroFriends on Character {
+    friends {
+        ... on Droid {
+            primaryFunction
+        }
+        ... {
+            unknown
+        }
+    }
+}
+"#;
+    let parse_result = parse_graphql(src);
+
+    let hero = extract_node_by_name::<GraphqlField>(&parse_result, "hero");
+    assert_eq!(schema.field(&hero).unwrap().ty().to_string(), "Character");
+    let arguments = hero.arguments().unwrap();
+    assert_eq!(
+        schema.arguments_definition(&arguments).unwrap()[0].name(),
+        "episode"
+    );
+
+    let primary_function = extract_node_by_name::<GraphqlField>(&parse_result, "primaryFunction");
+    assert_eq!(
+        schema.field(&primary_function).unwrap().ty().to_string(),
+        "String"
+    );
+
+    let unknown = extract_node_by_name::<GraphqlField>(&parse_result, "unknown");
+    assert!(schema.field(&unknown).is_none());
+    let parent_type = schema.parent_type(&unknown).unwrap();
+    assert_eq!(parent_type.name(), "Character");
+}
+
+#[test]
+fn ok_lookup_directive_arguments() {
+    let schema = build_schema(&["type Query { hero: String }"]);
+    let parse_result = parse_graphql("{ hero @include(if: true) }");
+
+    let arguments = extract_node::<GraphqlArguments>(&parse_result);
+    let definition = schema.arguments_definition(&arguments).unwrap();
+    assert_eq!(definition[0].ty().to_string(), "Boolean!");
+}
@@ -764,7 +764,14 @@ impl SyntaxFactory for GritSyntaxFactory {
                 if let Some(element) = &current_element
                     && matches!(
                         element.kind(),
-                        T![js] | T![css] | T![json] | T![grit] | T![html]
+                        T![js]
+                            | T![css]
+                            | T![json]
+                            | T![grit]
+                            | T![html]
+                            | T![graphql]
+                            | T![markdown]
+                            | T![yaml]
                     )
                 {
                     slots.mark_present();
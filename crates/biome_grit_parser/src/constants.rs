@@ -54,10 +54,20 @@ pub(crate) const REGEX_SET: TokenSet<GritSyntaxKind> = token_set![GRIT_REGEX, GR
 pub(crate) const SUPPORTED_ENGINE_SET: TokenSet<GritSyntaxKind> =
     token_set![T![biome], T![marzano]];
 
-pub(crate) const SUPPORTED_LANGUAGE_SET: TokenSet<GritSyntaxKind> =
-    token_set![T![js], T![json], T![css], T![grit], T![html]];
-
-pub(crate) const SUPPORTED_LANGUAGE_SET_STR: &[&str] = &["js", "json", "css", "grit", "html"];
+pub(crate) const SUPPORTED_LANGUAGE_SET: TokenSet<GritSyntaxKind> = token_set![
+    T![js],
+    T![json],
+    T![css],
+    T![grit],
+    T![html],
+    T![graphql],
+    T![markdown],
+    T![yaml]
+];
+
+pub(crate) const SUPPORTED_LANGUAGE_SET_STR: &[&str] = &[
+    "js", "json", "css", "grit", "html", "graphql", "markdown", "yaml",
+];
 
 pub(crate) const SUPPORTED_LANGUAGE_FLAVOR_SET: TokenSet<GritSyntaxKind> =
     token_set![T![typescript], T![jsx]];
@@ -795,6 +795,9 @@ impl<'src> GritLexer<'src> {
                     b"css" => T![css],
                     b"grit" => T![grit],
                     b"html" => T![html],
+                    b"graphql" => T![graphql],
+                    b"markdown" => T![markdown],
+                    b"yaml" => T![yaml],
                     _ => {
                         self.diagnostics.push(
                             ParseDiagnostic::new(
@@ -847,6 +850,9 @@ impl<'src> GritLexer<'src> {
             b"json" => JSON_KW,
             b"grit" => GRIT_KW,
             b"html" => HTML_KW,
+            b"graphql" => GRAPHQL_KW,
+            b"markdown" => MARKDOWN_KW,
+            b"yaml" => YAML_KW,
             b"typescript" => TYPESCRIPT_KW,
             b"jsx" => JSX_KW,
             b"as" => AS_KW,
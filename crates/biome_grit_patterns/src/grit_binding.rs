@@ -9,7 +9,7 @@ use grit_pattern_matcher::{
     binding::Binding, constant::Constant, effects::Effect, pattern::FileRegistry,
 };
 use grit_util::{
-    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, Range,
+    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, Language, Range,
     error::{GritPatternError, GritResult},
 };
 use std::{borrow::Cow, collections::HashMap, path::Path};
@@ -169,6 +169,7 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
             Self::Node(node) => {
                 let source = node.source();
                 let range = node.code_range();
+                let skip_padding_ranges = language.get_skip_padding_ranges(node);
                 linearize_binding(
                     language,
                     effects,
@@ -176,6 +177,7 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
                     memo,
                     source,
                     range,
+                    &skip_padding_ranges,
                     distributed_indent,
                     logs,
                 )
@@ -189,6 +191,7 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
                     memo,
                     source,
                     range,
+                    &[],
                     distributed_indent,
                     logs,
                 )
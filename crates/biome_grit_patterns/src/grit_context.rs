@@ -2,6 +2,7 @@ use crate::grit_binding::GritBinding;
 use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
+use crate::grit_foreign_functions::GritForeignFunctionDefinition;
 use crate::grit_node_patterns::{GritLeafNodePattern, GritNodePattern};
 use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
@@ -53,6 +54,7 @@ pub struct GritExecContext<'a> {
     pub files: &'a FileOwners<GritTargetTree>,
     pub built_ins: &'a BuiltIns,
     pub functions: &'a [GritFunctionDefinition<GritQueryContext>],
+    pub foreign_functions: &'a [GritForeignFunctionDefinition],
     pub patterns: &'a [PatternDefinition<GritQueryContext>],
     pub predicates: &'a [PredicateDefinition<GritQueryContext>],
 
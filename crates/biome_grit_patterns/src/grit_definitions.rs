@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use crate::{
     CompileError,
     grit_context::GritQueryContext,
+    grit_foreign_functions::GritForeignFunctionDefinition,
     pattern_compiler::{
         FunctionDefinitionCompiler, PatternDefinitionCompiler, PredicateDefinitionCompiler,
         compilation_context::{DefinitionInfo, NodeCompilationContext},
@@ -21,6 +22,7 @@ pub struct Definitions {
     pub patterns: Vec<PatternDefinition<GritQueryContext>>,
     pub predicates: Vec<PredicateDefinition<GritQueryContext>>,
     pub functions: Vec<GritFunctionDefinition<GritQueryContext>>,
+    pub foreign_functions: Vec<GritForeignFunctionDefinition>,
 }
 
 /// Compiles all definitions.
@@ -33,6 +35,7 @@ pub fn compile_definitions(
     let mut patterns = Vec::new();
     let mut predicates = Vec::new();
     let mut functions = Vec::new();
+    let mut foreign_functions = Vec::new();
     for definition in definitions {
         match definition? {
             AnyGritDefinition::AnyGritPattern(_) => {} // Handled separately.
@@ -45,8 +48,21 @@ pub fn compile_definitions(
             AnyGritDefinition::GritFunctionDefinition(node) => {
                 functions.push(FunctionDefinitionCompiler::from_node(node, context)?);
             }
-            AnyGritDefinition::GritJavascriptFunctionDefinition(_)
-            | AnyGritDefinition::GritBogusDefinition(_) => {
+            AnyGritDefinition::GritJavascriptFunctionDefinition(node) => {
+                foreign_functions.push(GritForeignFunctionDefinition {
+                    name: node.name()?.to_trimmed_string().trim().to_owned(),
+                    parameters: collect_variables(node.args())?
+                        .into_iter()
+                        .map(|(name, _)| name)
+                        .collect(),
+                    body: node
+                        .grit_javascript_body_wrapper()?
+                        .value_token()?
+                        .text_trimmed()
+                        .to_owned(),
+                });
+            }
+            AnyGritDefinition::GritBogusDefinition(_) => {
                 unreachable!(); // Should be handled in `scan_definitions()`.
             }
         }
@@ -56,6 +72,7 @@ pub fn compile_definitions(
         patterns,
         predicates,
         functions,
+        foreign_functions,
     })
 }
 
@@ -63,6 +80,7 @@ pub struct ScannedDefinitionInfo {
     pub pattern_definition_info: BTreeMap<String, DefinitionInfo>,
     pub predicate_definition_info: BTreeMap<String, DefinitionInfo>,
     pub function_definition_info: BTreeMap<String, DefinitionInfo>,
+    pub foreign_function_definition_info: BTreeMap<String, DefinitionInfo>,
 }
 
 /// Finds all definitions so that we can allocate their scopes in preparation
@@ -79,6 +97,9 @@ pub fn scan_definitions(
     let mut function_definition_info = BTreeMap::new();
     let mut function_index = 0;
 
+    let mut foreign_function_definition_info = BTreeMap::new();
+    let mut foreign_function_index = 0;
+
     for definition in definitions {
         match definition? {
             AnyGritDefinition::AnyGritPattern(_) => {} // Handled separately.
@@ -119,7 +140,9 @@ pub fn scan_definitions(
             AnyGritDefinition::GritFunctionDefinition(node) => {
                 let name = node.name()?.to_trimmed_string();
                 let name = name.trim();
-                if function_definition_info.contains_key(name) {
+                if function_definition_info.contains_key(name)
+                    || foreign_function_definition_info.contains_key(name)
+                {
                     return Err(CompileError::DuplicateFunctionDefinition(name.to_owned()));
                 }
 
@@ -133,10 +156,28 @@ pub fn scan_definitions(
 
                 function_index += 1;
             }
-            AnyGritDefinition::GritJavascriptFunctionDefinition(func) => {
-                return Err(CompileError::UnsupportedFunctionDefinition(
-                    func.name()?.to_trimmed_string().trim().to_owned(),
-                ));
+            AnyGritDefinition::GritJavascriptFunctionDefinition(node) => {
+                let name = node.name()?.to_trimmed_string();
+                let name = name.trim();
+                if cfg!(not(feature = "js_functions")) {
+                    return Err(CompileError::UnsupportedFunctionDefinition(name.to_owned()));
+                }
+
+                if function_definition_info.contains_key(name)
+                    || foreign_function_definition_info.contains_key(name)
+                {
+                    return Err(CompileError::DuplicateFunctionDefinition(name.to_owned()));
+                }
+
+                foreign_function_definition_info.insert(
+                    name.to_owned(),
+                    DefinitionInfo {
+                        index: foreign_function_index,
+                        parameters: collect_variables(node.args())?,
+                    },
+                );
+
+                foreign_function_index += 1;
             }
             AnyGritDefinition::GritBogusDefinition(bogus) => {
                 return Err(CompileError::UnexpectedKind(
@@ -154,6 +195,7 @@ pub fn scan_definitions(
         pattern_definition_info,
         predicate_definition_info,
         function_definition_info,
+        foreign_function_definition_info,
     })
 }
 
@@ -0,0 +1,143 @@
+use grit_util::error::{GritPatternError, GritResult};
+
+/// A function whose body is written in JavaScript, rather than in GritQL.
+///
+/// ```grit
+/// function uppercase($text) js {
+///     return $text.text.toUpperCase();
+/// }
+/// ```
+///
+/// Every argument is passed to the function as an object with a `text`
+/// property holding the text of the argument. The return value is converted to
+/// a string, which becomes the result of the call.
+///
+/// Functions run with limits on loop iterations and recursion, so a function
+/// that never returns fails instead of blocking the analysis.
+#[derive(Clone, Debug)]
+pub struct GritForeignFunctionDefinition {
+    pub name: String,
+    pub parameters: Vec<String>,
+
+    /// The body of the function, including its surrounding braces.
+    pub body: String,
+}
+
+/// Stops functions that loop forever.
+#[cfg(feature = "js_functions")]
+const LOOP_ITERATION_LIMIT: u64 = 1_000_000;
+
+/// Stops functions that recurse forever.
+#[cfg(feature = "js_functions")]
+const RECURSION_LIMIT: usize = 512;
+
+/// A foreign function compiled in its own JavaScript context.
+#[cfg(feature = "js_functions")]
+struct CompiledForeignFunction {
+    context: boa_engine::Context,
+    function: boa_engine::object::builtins::JsFunction,
+}
+
+#[cfg(feature = "js_functions")]
+thread_local! {
+    /// The compiled functions of this thread, by source.
+    ///
+    /// Contexts can't be shared between threads, so every thread compiles a
+    /// definition once and reuses its context for the next calls. The map is
+    /// never dropped, because dropping a context while the thread exits would
+    /// access the garbage collector of boa after it has been destroyed.
+    static COMPILED_FUNCTIONS: std::mem::ManuallyDrop<
+        std::cell::RefCell<rustc_hash::FxHashMap<String, CompiledForeignFunction>>,
+    > = Default::default();
+}
+
+impl GritForeignFunctionDefinition {
+    #[cfg(feature = "js_functions")]
+    pub fn call(&self, args: &[&str]) -> GritResult<String> {
+        use boa_engine::object::ObjectInitializer;
+        use boa_engine::property::Attribute;
+        use boa_engine::{JsString, JsValue, js_string};
+
+        if args.len() != self.parameters.len() {
+            return Err(GritPatternError::new(format!(
+                "function {} expects {} arguments, but got {}",
+                self.name,
+                self.parameters.len(),
+                args.len()
+            )));
+        }
+
+        let source = format!("(function ({}) {})", self.parameters.join(", "), self.body);
+        COMPILED_FUNCTIONS.with(|compiled_functions| {
+            let mut compiled_functions = compiled_functions.borrow_mut();
+            let compiled = match compiled_functions.entry(source) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    let compiled = self.compile(entry.key())?;
+                    entry.insert(compiled)
+                }
+            };
+            let CompiledForeignFunction { context, function } = compiled;
+
+            let args: Vec<JsValue> = args
+                .iter()
+                .map(|text| {
+                    ObjectInitializer::new(context)
+                        .property(
+                            js_string!("text"),
+                            JsString::from(*text),
+                            Attribute::ENUMERABLE,
+                        )
+                        .build()
+                        .into()
+                })
+                .collect();
+
+            let result = function
+                .call(&JsValue::undefined(), &args, context)
+                .and_then(|result| result.to_string(context))
+                .map_err(|error| self.error(error))?;
+
+            Ok(result.to_std_string_lossy())
+        })
+    }
+
+    /// Compiles the function from its `source` in a new context, whose runtime
+    /// limits stop functions that never return.
+    #[cfg(feature = "js_functions")]
+    fn compile(&self, source: &str) -> GritResult<CompiledForeignFunction> {
+        use boa_engine::vm::RuntimeLimits;
+        use boa_engine::{Context, Source};
+
+        let mut context = Context::default();
+        let mut limits = RuntimeLimits::default();
+        limits.set_loop_iteration_limit(LOOP_ITERATION_LIMIT);
+        limits.set_recursion_limit(RECURSION_LIMIT);
+        context.set_runtime_limits(limits);
+
+        let function = context
+            .eval(Source::from_bytes(source))
+            .map_err(|error| self.error(error))?;
+        let Some(function) = function.as_function() else {
+            return Err(GritPatternError::new(format!(
+                "function {} could not be compiled",
+                self.name
+            )));
+        };
+
+        Ok(CompiledForeignFunction { context, function })
+    }
+
+    #[cfg(not(feature = "js_functions"))]
+    pub fn call(&self, _args: &[&str]) -> GritResult<String> {
+        Err(GritPatternError::new(format!(
+            "function {} cannot be called, because JavaScript functions are not supported by this build",
+            self.name
+        )))
+    }
+
+    #[cfg(feature = "js_functions")]
+    fn error(&self, error: impl std::fmt::Display) -> GritPatternError {
+        GritPatternError::new(format!("error in function {}: {error}", self.name))
+    }
+}
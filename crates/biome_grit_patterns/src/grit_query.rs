@@ -85,6 +85,7 @@ impl GritQuery {
             files: file_owners,
             built_ins: &self.built_ins,
             functions: &self.definitions.functions,
+            foreign_functions: &self.definitions.foreign_functions,
             patterns: &self.definitions.patterns,
             predicates: &self.definitions.predicates,
             diagnostics: Mutex::new(Vec::new()),
@@ -289,6 +290,7 @@ impl GritQuery {
             pattern_definition_info,
             predicate_definition_info,
             function_definition_info,
+            foreign_function_definition_info,
         } = scan_definitions(root.definitions())?;
 
         let mut built_ins = BuiltIns::default();
@@ -303,6 +305,7 @@ impl GritQuery {
             pattern_definition_info,
             predicate_definition_info,
             function_definition_info,
+            foreign_function_definition_info,
         };
 
         // Global variables are in scope 0, local pattern variables will be in scope 1.
@@ -893,4 +896,77 @@ mod tests {
             "no-match: optimized and full should produce identical effects"
         );
     }
+
+    #[test]
+    fn rewrite_aligns_multiline_bindings() {
+        let query = compile_js_query(
+            r#"`if (ready) { $body }` => `if (!ready) {
+    return;
+}
+$body`"#,
+        );
+        let code = r#"class Service {
+    run() {
+        if (ready) {
+            start();
+            if (verbose) {
+                log();
+            }
+        }
+    }
+}
+"#;
+        let expected = r#"class Service {
+    run() {
+        if (!ready) {
+            return;
+        }
+        start();
+        if (verbose) {
+            log();
+        }
+    }
+}
+"#;
+
+        let result = query.execute(make_js_file(code)).expect("execute failed");
+        let [GritQueryEffect::Rewrite(rewrite)] = result.effects.as_slice() else {
+            panic!("expected one rewrite effect, got {:?}", result.effects);
+        };
+        assert_eq!(rewrite.rewritten.content, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "js_functions")]
+    fn rewrite_with_javascript_function() {
+        let query = compile_js_query(
+            r#"function shout($text) js {
+    return $text.text.toUpperCase();
+}
+
+`console.log($msg)` where { $msg => shout($msg) }"#,
+        );
+        let code = r#"console.log("hello");"#;
+        let expected = r#"console.log("HELLO");"#;
+
+        let result = query.execute(make_js_file(code)).expect("execute failed");
+        let [GritQueryEffect::Rewrite(rewrite)] = result.effects.as_slice() else {
+            panic!("expected one rewrite effect, got {:?}", result.effects);
+        };
+        assert_eq!(rewrite.rewritten.content, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "js_functions")]
+    fn javascript_function_that_never_returns_fails() {
+        use crate::grit_foreign_functions::GritForeignFunctionDefinition;
+
+        let definition = GritForeignFunctionDefinition {
+            name: "spin".to_string(),
+            parameters: vec!["$text".to_string()],
+            body: "{ while (true) {} }".to_string(),
+        };
+
+        assert!(definition.call(&["hello"]).is_err());
+    }
 }
@@ -4,18 +4,19 @@ use crate::grit_context::GritExecContext;
 use crate::grit_file::GritFile;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
+use crate::linearization::line_padding;
 use crate::{grit_binding::GritBinding, grit_context::GritQueryContext};
 use grit_pattern_matcher::binding::Binding;
 use grit_pattern_matcher::constant::Constant;
 use grit_pattern_matcher::context::{ExecContext, QueryContext};
 use grit_pattern_matcher::effects::Effect;
 use grit_pattern_matcher::pattern::{
-    Accessor, DynamicPattern, DynamicSnippet, DynamicSnippetPart, File, FilePtr, FileRegistry,
-    GritCall, ListIndex, Pattern, PatternName, PatternOrResolved, ResolvedFile, ResolvedPattern,
-    ResolvedSnippet, State, to_unsigned,
+    Accessor, CallForeignFunction, DynamicPattern, DynamicSnippet, DynamicSnippetPart, File,
+    FilePtr, FileRegistry, GritCall, ListIndex, Pattern, PatternName, PatternOrResolved,
+    ResolvedFile, ResolvedPattern, ResolvedSnippet, State, to_unsigned,
 };
 use grit_util::error::{GritPatternError, GritResult};
-use grit_util::{AnalysisLogs, Ast, CodeRange, Range};
+use grit_util::{AnalysisLogs, Ast, CodeRange, Language, Range};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 
@@ -86,6 +87,31 @@ impl<'a> GritResolvedPattern<'a> {
             }
         }
     }
+
+    fn from_foreign_function_call(
+        call: &'a CallForeignFunction<GritQueryContext>,
+        state: &mut State<'a, GritQueryContext>,
+        context: &'a GritExecContext,
+        logs: &mut AnalysisLogs,
+    ) -> GritResult<Self> {
+        let function = context.foreign_functions.get(call.index).ok_or_else(|| {
+            GritPatternError::new(format!("unknown foreign function at index {}", call.index))
+        })?;
+
+        let mut args = Vec::with_capacity(call.args.len());
+        for arg in &call.args {
+            let text = match arg {
+                Some(pattern) => Self::from_pattern(pattern, state, context, logs)?
+                    .text(&state.files, context.language())?
+                    .into_owned(),
+                None => String::new(),
+            };
+            args.push(text);
+        }
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        Ok(Self::from_string(function.call(&args)?))
+    }
 }
 
 impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
@@ -191,7 +217,9 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
             }
             DynamicPattern::CallBuiltIn(built_in) => built_in.call(state, context, logs),
             DynamicPattern::CallFunction(func) => func.call(state, context, logs),
-            DynamicPattern::CallForeignFunction(_) => unimplemented!(),
+            DynamicPattern::CallForeignFunction(call) => {
+                Self::from_foreign_function_call(call, state, context, logs)
+            }
         }
     }
 
@@ -241,7 +269,9 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
             }) => Self::from_dynamic_pattern(pattern, state, context, logs),
             Pattern::CallBuiltIn(built_in) => built_in.call(state, context, logs),
             Pattern::CallFunction(func) => func.call(state, context, logs),
-            Pattern::CallForeignFunction(_) => unimplemented!(),
+            Pattern::CallForeignFunction(call) => {
+                Self::from_foreign_function_call(call, state, context, logs)
+            }
             Pattern::CallbackPattern(callback) => Err(GritPatternError::new(format!(
                 "cannot make resolved pattern from callback pattern {}",
                 callback.name()
@@ -600,12 +630,31 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
         logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
         match self {
-            Self::Snippets(snippets) => Ok(snippets
-                .iter()
-                .map(|snippet| snippet.linearized_text(language, effects, files, memo, None, logs))
-                .collect::<GritResult<Vec<_>>>()?
-                .join("")
-                .into()),
+            Self::Snippets(snippets) => {
+                let mut text = String::new();
+                for snippet in snippets {
+                    match snippet {
+                        // Bindings are dedented first, so they can be padded
+                        // to the indentation of the line they're inserted on.
+                        ResolvedSnippet::Binding(_) if should_pad_snippet => {
+                            let snippet_text = snippet.linearized_text(
+                                language,
+                                effects,
+                                files,
+                                memo,
+                                Some(0),
+                                logs,
+                            )?;
+                            let padding = line_padding(&text, text.len()).to_owned();
+                            text.push_str(&language.pad_snippet(&snippet_text, &padding));
+                        }
+                        _ => text.push_str(
+                            &snippet.linearized_text(language, effects, files, memo, None, logs)?,
+                        ),
+                    }
+                }
+                Ok(text.into())
+            }
             Self::Binding(bindings) => Ok(bindings
                 .last()
                 .ok_or_else(|| {
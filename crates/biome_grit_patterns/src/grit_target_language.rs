@@ -1,13 +1,23 @@
 mod css_target_language;
+mod graphql_target_language;
+mod html_target_language;
 mod js_target_language;
 mod json_target_language;
+mod markdown_target_language;
+mod yaml_target_language;
 
 pub use css_target_language::CssTargetLanguage;
+pub use graphql_target_language::GraphqlTargetLanguage;
+pub use html_target_language::HtmlTargetLanguage;
 pub use js_target_language::JsTargetLanguage;
 pub use json_target_language::JsonTargetLanguage;
+pub use markdown_target_language::MarkdownTargetLanguage;
+pub use yaml_target_language::YamlTargetLanguage;
 
 use camino::Utf8Path;
-use grit_util::{AnalysisLogs, Ast, CodeRange, EffectRange, Language, Parser, SnippetTree};
+use grit_util::{
+    AnalysisLogs, Ast, AstNode, CodeRange, EffectRange, Language, Parser, SnippetTree,
+};
 use std::borrow::Cow;
 use std::path::Path;
 use std::str::FromStr;
@@ -19,10 +29,15 @@ use biome_string_case::StrOnlyExtension;
 
 use crate::CompileError;
 use crate::grit_css_parser::GritCssParser;
+use crate::grit_graphql_parser::GritGraphqlParser;
+use crate::grit_html_parser::GritHtmlParser;
 use crate::grit_js_parser::GritJsParser;
 use crate::grit_json_parser::GritJsonParser;
+use crate::grit_markdown_parser::GritMarkdownParser;
 use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
 use crate::grit_tree::GritTargetTree;
+use crate::grit_yaml_parser::GritYamlParser;
+use crate::linearization::{AlignedText, pad_snippet};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum GritNodePatternSource {
@@ -161,6 +176,12 @@ macro_rules! generate_target_language {
                 }
             }
 
+            fn is_whitespace_sensitive_kind(&self, kind: GritTargetSyntaxKind) -> bool {
+                match self {
+                    $(Self::$language(_) => $language::is_whitespace_sensitive_kind(kind)),+
+                }
+            }
+
             pub fn is_disregarded_snippet_field(
                 &self,
                 kind: GritTargetSyntaxKind,
@@ -214,22 +235,41 @@ macro_rules! generate_target_language {
 
             fn align_padding<'a>(
                 &self,
-                _node: &Self::Node<'a>,
-                _range: &CodeRange,
-                _skip_ranges: &[CodeRange],
-                _new_padding: Option<usize>,
-                _offset: usize,
-                _substitutions: &mut [(EffectRange, String)],
+                node: &Self::Node<'a>,
+                range: &CodeRange,
+                skip_ranges: &[CodeRange],
+                new_padding: Option<usize>,
+                offset: usize,
+                substitutions: &mut [(EffectRange, String)],
             ) -> Cow<'a, str> {
-                todo!()
+                let aligned = match new_padding {
+                    Some(new_padding) => {
+                        AlignedText::new(node.source(), range, skip_ranges, new_padding)
+                    }
+                    None => AlignedText::unaligned(node.source(), range),
+                };
+
+                // Substitution ranges are relative to `offset`, which
+                // corresponds to the start of `range`.
+                for (effect_range, _) in substitutions.iter_mut() {
+                    let start = effect_range.range.start.saturating_sub(offset);
+                    let end = effect_range.range.end.saturating_sub(offset);
+                    effect_range.range =
+                        aligned.map_position(start) + offset..aligned.map_position(end) + offset;
+                }
+
+                aligned.text
             }
 
-            fn pad_snippet<'a>(&self, _snippet: &'a str, _padding: &str) -> Cow<'a, str> {
-                todo!()
+            fn pad_snippet<'a>(&self, snippet: &'a str, padding: &str) -> Cow<'a, str> {
+                pad_snippet(snippet, padding)
             }
 
-            fn get_skip_padding_ranges(&self, _node: &Self::Node<'_>) -> Vec<CodeRange> {
-                Vec::new()
+            fn get_skip_padding_ranges(&self, node: &Self::Node<'_>) -> Vec<CodeRange> {
+                node.descendants()
+                    .filter(|descendant| self.is_whitespace_sensitive_kind(descendant.kind()))
+                    .map(|descendant| descendant.code_range())
+                    .collect()
             }
         }
     }
@@ -237,8 +277,12 @@ macro_rules! generate_target_language {
 
 generate_target_language! {
     [CssTargetLanguage, GritCssParser, "CSS"],
+    [GraphqlTargetLanguage, GritGraphqlParser, "GraphQL"],
+    [HtmlTargetLanguage, GritHtmlParser, "HTML"],
     [JsTargetLanguage, GritJsParser, "JavaScript"],
-    [JsonTargetLanguage, GritJsonParser, "JSON"]
+    [JsonTargetLanguage, GritJsonParser, "JSON"],
+    [MarkdownTargetLanguage, GritMarkdownParser, "Markdown"],
+    [YamlTargetLanguage, GritYamlParser, "YAML"]
 }
 
 impl Default for GritTargetLanguage {
@@ -260,8 +304,14 @@ impl GritTargetLanguage {
             .kind()
         {
             GritSyntaxKind::CSS_KW => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            GritSyntaxKind::GRAPHQL_KW => Some(Self::GraphqlTargetLanguage(GraphqlTargetLanguage)),
+            GritSyntaxKind::HTML_KW => Some(Self::HtmlTargetLanguage(HtmlTargetLanguage)),
             GritSyntaxKind::JS_KW => Some(Self::JsTargetLanguage(JsTargetLanguage)),
             GritSyntaxKind::JSON_KW => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
+            GritSyntaxKind::MARKDOWN_KW => {
+                Some(Self::MarkdownTargetLanguage(MarkdownTargetLanguage))
+            }
+            GritSyntaxKind::YAML_KW => Some(Self::YamlTargetLanguage(YamlTargetLanguage)),
             _ => None,
         }
     }
@@ -270,10 +320,16 @@ impl GritTargetLanguage {
     pub fn from_extension(extension: &str) -> Option<Self> {
         match extension {
             "css" => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            "gql" | "graphql" => Some(Self::GraphqlTargetLanguage(GraphqlTargetLanguage)),
+            "astro" | "htm" | "html" | "svelte" | "vue" => {
+                Some(Self::HtmlTargetLanguage(HtmlTargetLanguage))
+            }
             "cjs" | "js" | "jsx" | "mjs" | "ts" | "tsx" => {
                 Some(Self::JsTargetLanguage(JsTargetLanguage))
             }
             "json" | "jsonc" => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
+            "markdown" | "md" => Some(Self::MarkdownTargetLanguage(MarkdownTargetLanguage)),
+            "yaml" | "yml" => Some(Self::YamlTargetLanguage(YamlTargetLanguage)),
             _ => None,
         }
     }
@@ -417,6 +473,16 @@ trait GritTargetLanguageImpl {
         false
     }
 
+    /// Returns whether leading whitespace is significant inside nodes of the
+    /// given kind.
+    ///
+    /// Lines starting inside such nodes are never re-indented when rewritten
+    /// code is aligned to its new location. For example, in JavaScript, this
+    /// is the case for the content of template strings.
+    fn is_whitespace_sensitive_kind(_kind: GritTargetSyntaxKind) -> bool {
+        false
+    }
+
     /// Ordinarily, we want to match on all possible fields, including the absence of nodes within a field.
     /// e.g., `my_function()` should not match `my_function(arg)`.
     ///
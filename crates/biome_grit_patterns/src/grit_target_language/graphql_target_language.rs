@@ -0,0 +1,116 @@
+mod constants;
+pub mod generated_mappings;
+
+use super::{DisregardedSlotCondition, GritNodePatternSource, GritTargetLanguageImpl};
+use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlSyntaxKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKindSet};
+use constants::DISREGARDED_SNIPPET_SLOTS;
+use generated_mappings::{kind_by_name, native_slots_for_name};
+
+const COMMENT_KINDS: SyntaxKindSet<GraphqlLanguage> =
+    SyntaxKindSet::from_raw(RawSyntaxKind(GraphqlSyntaxKind::COMMENT as u16));
+
+#[derive(Clone, Debug)]
+pub struct GraphqlTargetLanguage;
+
+impl GritTargetLanguageImpl for GraphqlTargetLanguage {
+    type Kind = GraphqlSyntaxKind;
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// Supports native Biome AST patterns for full language coverage.
+    fn native_kind_by_name(&self, node_name: &str) -> Option<GraphqlSyntaxKind> {
+        kind_by_name(node_name)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static str {
+        // TODO: See [super::JsTargetLanguage::name_for_kind()].
+        "(unknown node)"
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_node(
+        &self,
+        node_name: &str,
+        _kind: GritTargetSyntaxKind,
+        source: GritNodePatternSource,
+    ) -> &'static [(&'static str, u32)] {
+        match source {
+            GritNodePatternSource::LegacyTreeSitter => &[],
+            GritNodePatternSource::Native => native_slots_for_name(node_name),
+        }
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("{ ", " }"),
+            ("query GRIT_QUERY { GRIT_FIELD(", ") }"),
+            ("query GRIT_QUERY { GRIT_FIELD(GRIT_ARGUMENT: ", ") }"),
+        ]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_graphql_kind()
+            .is_some_and(|kind| COMMENT_KINDS.matches(kind))
+    }
+
+    /// GraphQL has no dedicated syntax for metavariables, so there is no node
+    /// of this kind in any GraphQL tree. Metavariables are recognised through
+    /// [Self::is_alternative_metavariable_kind()] instead.
+    fn metavariable_kind() -> Self::Kind {
+        GraphqlSyntaxKind::TOMBSTONE
+    }
+
+    /// Returns whether the given syntax kind is an "alternative" kind for
+    /// metavariables.
+    ///
+    /// Metavariables are lexed as names, so they show up as fields, enum
+    /// values or any of the name nodes, depending on where they're used.
+    fn is_alternative_metavariable_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_graphql_kind().is_some_and(|kind| {
+            matches!(
+                kind,
+                GraphqlSyntaxKind::GRAPHQL_ENUM_VALUE
+                    | GraphqlSyntaxKind::GRAPHQL_FIELD
+                    | GraphqlSyntaxKind::GRAPHQL_LITERAL_NAME
+                    | GraphqlSyntaxKind::GRAPHQL_NAME_BINDING
+                    | GraphqlSyntaxKind::GRAPHQL_NAME_REFERENCE
+            )
+        })
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        kind: GritTargetSyntaxKind,
+        slot_index: u32,
+        node: Option<GritTargetNode<'_>>,
+    ) -> bool {
+        DISREGARDED_SNIPPET_SLOTS.iter().any(
+            |(disregarded_kind, disregarded_slot_index, condition)| {
+                if GritTargetSyntaxKind::from(*disregarded_kind) != kind
+                    || *disregarded_slot_index != slot_index
+                {
+                    return false;
+                }
+
+                match condition {
+                    DisregardedSlotCondition::Always => true,
+                    DisregardedSlotCondition::OnlyIf(node_texts) => node_texts.iter().any(|text| {
+                        *text == node.as_ref().map(|node| node.text()).unwrap_or_default()
+                    }),
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,233 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_graphql_syntax as lang;
+use biome_rowan::AstNode;
+use lang::GraphqlSyntaxKind;
+
+/// Returns the syntax kind for a legacy TreeSitter node name.
+pub fn legacy_kind_by_name(_node_name: &str) -> Option<GraphqlSyntaxKind> {
+    None
+}
+
+/// Returns the syntax kind for a native Biome node name.
+pub fn native_kind_by_name(node_name: &str) -> Option<GraphqlSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "GraphqlAlias" => lang::GraphqlAlias::KIND_SET.iter().next(),
+        "GraphqlArgument" => lang::GraphqlArgument::KIND_SET.iter().next(),
+        "GraphqlArguments" => lang::GraphqlArguments::KIND_SET.iter().next(),
+        "GraphqlArgumentsDefinition" => lang::GraphqlArgumentsDefinition::KIND_SET.iter().next(),
+        "GraphqlBooleanValue" => lang::GraphqlBooleanValue::KIND_SET.iter().next(),
+        "GraphqlDefaultValue" => lang::GraphqlDefaultValue::KIND_SET.iter().next(),
+        "GraphqlDescription" => lang::GraphqlDescription::KIND_SET.iter().next(),
+        "GraphqlDirective" => lang::GraphqlDirective::KIND_SET.iter().next(),
+        "GraphqlDirectiveDefinition" => lang::GraphqlDirectiveDefinition::KIND_SET.iter().next(),
+        "GraphqlDirectiveLocation" => lang::GraphqlDirectiveLocation::KIND_SET.iter().next(),
+        "GraphqlEnumTypeDefinition" => lang::GraphqlEnumTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlEnumTypeExtension" => lang::GraphqlEnumTypeExtension::KIND_SET.iter().next(),
+        "GraphqlEnumValue" => lang::GraphqlEnumValue::KIND_SET.iter().next(),
+        "GraphqlEnumValueDefinition" => lang::GraphqlEnumValueDefinition::KIND_SET.iter().next(),
+        "GraphqlEnumValuesDefinition" => lang::GraphqlEnumValuesDefinition::KIND_SET.iter().next(),
+        "GraphqlField" => lang::GraphqlField::KIND_SET.iter().next(),
+        "GraphqlFieldDefinition" => lang::GraphqlFieldDefinition::KIND_SET.iter().next(),
+        "GraphqlFieldsDefinition" => lang::GraphqlFieldsDefinition::KIND_SET.iter().next(),
+        "GraphqlFloatValue" => lang::GraphqlFloatValue::KIND_SET.iter().next(),
+        "GraphqlFragmentDefinition" => lang::GraphqlFragmentDefinition::KIND_SET.iter().next(),
+        "GraphqlFragmentSpread" => lang::GraphqlFragmentSpread::KIND_SET.iter().next(),
+        "GraphqlImplementsInterfaces" => lang::GraphqlImplementsInterfaces::KIND_SET.iter().next(),
+        "GraphqlInlineFragment" => lang::GraphqlInlineFragment::KIND_SET.iter().next(),
+        "GraphqlInputFieldsDefinition" => {
+            lang::GraphqlInputFieldsDefinition::KIND_SET.iter().next()
+        }
+        "GraphqlInputObjectTypeDefinition" => lang::GraphqlInputObjectTypeDefinition::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlInputObjectTypeExtension" => lang::GraphqlInputObjectTypeExtension::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlInputValueDefinition" => lang::GraphqlInputValueDefinition::KIND_SET.iter().next(),
+        "GraphqlIntValue" => lang::GraphqlIntValue::KIND_SET.iter().next(),
+        "GraphqlInterfaceTypeDefinition" => {
+            lang::GraphqlInterfaceTypeDefinition::KIND_SET.iter().next()
+        }
+        "GraphqlInterfaceTypeExtension" => {
+            lang::GraphqlInterfaceTypeExtension::KIND_SET.iter().next()
+        }
+        "GraphqlLiteralName" => lang::GraphqlLiteralName::KIND_SET.iter().next(),
+        "GraphqlNameBinding" => lang::GraphqlNameBinding::KIND_SET.iter().next(),
+        "GraphqlNameReference" => lang::GraphqlNameReference::KIND_SET.iter().next(),
+        "GraphqlNonNullType" => lang::GraphqlNonNullType::KIND_SET.iter().next(),
+        "GraphqlNullValue" => lang::GraphqlNullValue::KIND_SET.iter().next(),
+        "GraphqlObjectField" => lang::GraphqlObjectField::KIND_SET.iter().next(),
+        "GraphqlObjectTypeDefinition" => lang::GraphqlObjectTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlObjectTypeExtension" => lang::GraphqlObjectTypeExtension::KIND_SET.iter().next(),
+        "GraphqlObjectValue" => lang::GraphqlObjectValue::KIND_SET.iter().next(),
+        "GraphqlOperationDefinition" => lang::GraphqlOperationDefinition::KIND_SET.iter().next(),
+        "GraphqlOperationType" => lang::GraphqlOperationType::KIND_SET.iter().next(),
+        "GraphqlRoot" => lang::GraphqlRoot::KIND_SET.iter().next(),
+        "GraphqlRootOperationTypeDefinition" => lang::GraphqlRootOperationTypeDefinition::KIND_SET
+            .iter()
+            .next(),
+        "GraphqlRootOperationTypes" => lang::GraphqlRootOperationTypes::KIND_SET.iter().next(),
+        "GraphqlScalarTypeDefinition" => lang::GraphqlScalarTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlScalarTypeExtension" => lang::GraphqlScalarTypeExtension::KIND_SET.iter().next(),
+        "GraphqlSchemaDefinition" => lang::GraphqlSchemaDefinition::KIND_SET.iter().next(),
+        "GraphqlSchemaExtension" => lang::GraphqlSchemaExtension::KIND_SET.iter().next(),
+        "GraphqlSelectionSet" => lang::GraphqlSelectionSet::KIND_SET.iter().next(),
+        "GraphqlStringValue" => lang::GraphqlStringValue::KIND_SET.iter().next(),
+        "GraphqlTypeCondition" => lang::GraphqlTypeCondition::KIND_SET.iter().next(),
+        "GraphqlUnionMemberTypes" => lang::GraphqlUnionMemberTypes::KIND_SET.iter().next(),
+        "GraphqlUnionTypeDefinition" => lang::GraphqlUnionTypeDefinition::KIND_SET.iter().next(),
+        "GraphqlUnionTypeExtension" => lang::GraphqlUnionTypeExtension::KIND_SET.iter().next(),
+        "GraphqlVariableBinding" => lang::GraphqlVariableBinding::KIND_SET.iter().next(),
+        "GraphqlVariableDefinition" => lang::GraphqlVariableDefinition::KIND_SET.iter().next(),
+        "GraphqlVariableDefinitions" => lang::GraphqlVariableDefinitions::KIND_SET.iter().next(),
+        "GraphqlVariableReference" => lang::GraphqlVariableReference::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<GraphqlSyntaxKind> {
+    legacy_kind_by_name(node_name).or_else(|| native_kind_by_name(node_name))
+}
+
+/// Returns the native Biome slot mappings for a node name.
+pub fn native_slots_for_name(node_name: &str) -> &'static [(&'static str, u32)] {
+    match node_name {
+        "GraphqlAlias" => &[("value", 0)],
+        "GraphqlArgument" => &[("name", 0), ("value", 2)],
+        "GraphqlArguments" => &[("arguments", 1)],
+        "GraphqlArgumentsDefinition" => &[("arguments", 1)],
+        "GraphqlDefaultValue" => &[("value", 1)],
+        "GraphqlDescription" => &[("graphql_string_value", 0)],
+        "GraphqlDirective" => &[("name", 1), ("arguments", 2)],
+        "GraphqlDirectiveDefinition" => &[
+            ("description", 0),
+            ("name", 3),
+            ("arguments", 4),
+            ("locations", 8),
+        ],
+        "GraphqlEnumTypeDefinition" => &[
+            ("description", 0),
+            ("name", 2),
+            ("directives", 3),
+            ("enum_values", 4),
+        ],
+        "GraphqlEnumTypeExtension" => &[("name", 2), ("directives", 3), ("enum_values", 4)],
+        "GraphqlEnumValue" => &[("value", 0)],
+        "GraphqlEnumValueDefinition" => &[("description", 0), ("value", 1), ("directives", 2)],
+        "GraphqlEnumValuesDefinition" => &[("values", 1)],
+        "GraphqlField" => &[
+            ("alias", 0),
+            ("name", 1),
+            ("arguments", 2),
+            ("directives", 3),
+            ("selection_set", 4),
+        ],
+        "GraphqlFieldDefinition" => &[
+            ("description", 0),
+            ("name", 1),
+            ("arguments", 2),
+            ("ty", 4),
+            ("directives", 5),
+        ],
+        "GraphqlFieldsDefinition" => &[("fields", 1)],
+        "GraphqlFragmentDefinition" => &[
+            ("name", 1),
+            ("type_condition", 2),
+            ("directives", 3),
+            ("selection_set", 4),
+        ],
+        "GraphqlFragmentSpread" => &[("name", 1), ("directives", 2)],
+        "GraphqlImplementsInterfaces" => &[("interfaces", 2)],
+        "GraphqlInlineFragment" => &[
+            ("type_condition", 1),
+            ("directives", 2),
+            ("selection_set", 3),
+        ],
+        "GraphqlInputFieldsDefinition" => &[("fields", 1)],
+        "GraphqlInputObjectTypeDefinition" => &[
+            ("description", 0),
+            ("name", 2),
+            ("directives", 3),
+            ("input_fields", 4),
+        ],
+        "GraphqlInputObjectTypeExtension" => &[("name", 2), ("directives", 3), ("input_fields", 4)],
+        "GraphqlInputValueDefinition" => &[
+            ("description", 0),
+            ("name", 1),
+            ("ty", 3),
+            ("default", 4),
+            ("directives", 5),
+        ],
+        "GraphqlInterfaceTypeDefinition" => &[
+            ("description", 0),
+            ("name", 2),
+            ("implements", 3),
+            ("directives", 4),
+            ("fields", 5),
+        ],
+        "GraphqlInterfaceTypeExtension" => &[
+            ("name", 2),
+            ("implements", 3),
+            ("directives", 4),
+            ("fields", 5),
+        ],
+        "GraphqlNonNullType" => &[("base", 0)],
+        "GraphqlObjectField" => &[("name", 0), ("value", 2)],
+        "GraphqlObjectTypeDefinition" => &[
+            ("description", 0),
+            ("name", 2),
+            ("implements", 3),
+            ("directives", 4),
+            ("fields", 5),
+        ],
+        "GraphqlObjectTypeExtension" => &[
+            ("name", 2),
+            ("implements", 3),
+            ("directives", 4),
+            ("fields", 5),
+        ],
+        "GraphqlObjectValue" => &[("members", 1)],
+        "GraphqlOperationDefinition" => &[
+            ("ty", 0),
+            ("name", 1),
+            ("variables", 2),
+            ("directives", 3),
+            ("selection_set", 4),
+        ],
+        "GraphqlRoot" => &[("definitions", 1)],
+        "GraphqlRootOperationTypeDefinition" => &[("operation_type", 0), ("named_type", 2)],
+        "GraphqlRootOperationTypes" => &[("root_operation_type", 1)],
+        "GraphqlScalarTypeDefinition" => &[("description", 0), ("name", 2), ("directives", 3)],
+        "GraphqlScalarTypeExtension" => &[("name", 2), ("directives", 3)],
+        "GraphqlSchemaDefinition" => &[
+            ("description", 0),
+            ("directives", 2),
+            ("root_operation_types", 3),
+        ],
+        "GraphqlSchemaExtension" => &[("directives", 2), ("root_operation_types", 3)],
+        "GraphqlSelectionSet" => &[("selections", 1)],
+        "GraphqlTypeCondition" => &[("ty", 1)],
+        "GraphqlUnionMemberTypes" => &[("members", 2)],
+        "GraphqlUnionTypeDefinition" => &[
+            ("description", 0),
+            ("name", 2),
+            ("directives", 3),
+            ("union_members", 4),
+        ],
+        "GraphqlUnionTypeExtension" => &[("name", 2), ("directives", 3), ("union_members", 4)],
+        "GraphqlVariableBinding" => &[("name", 1)],
+        "GraphqlVariableDefinition" => &[
+            ("variable", 0),
+            ("ty", 2),
+            ("default", 3),
+            ("directives", 4),
+        ],
+        "GraphqlVariableDefinitions" => &[("elements", 1)],
+        "GraphqlVariableReference" => &[("name", 1)],
+        _ => &[],
+    }
+}
@@ -0,0 +1,137 @@
+mod constants;
+pub mod generated_mappings;
+
+use super::{
+    DisregardedSlotCondition, GritNodePatternSource, GritTargetLanguageImpl, LeafEquivalenceClass,
+    LeafNormalizer, normalize_quoted_string,
+};
+use crate::{
+    CompileError,
+    grit_target_node::{GritTargetNode, GritTargetSyntaxKind},
+};
+use biome_html_syntax::{HtmlLanguage, HtmlSyntaxKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKindSet};
+use constants::DISREGARDED_SNIPPET_SLOTS;
+use generated_mappings::{kind_by_name, native_slots_for_name};
+
+const COMMENT_KINDS: SyntaxKindSet<HtmlLanguage> =
+    SyntaxKindSet::from_raw(RawSyntaxKind(HtmlSyntaxKind::COMMENT as u16));
+
+const EQUIVALENT_LEAF_NODES: &[&[LeafNormalizer]] = &[&[LeafNormalizer::new(
+    GritTargetSyntaxKind::HtmlSyntaxKind(HtmlSyntaxKind::HTML_STRING_LITERAL),
+    normalize_attribute_value,
+)]];
+
+#[derive(Clone, Debug)]
+pub struct HtmlTargetLanguage;
+
+impl GritTargetLanguageImpl for HtmlTargetLanguage {
+    type Kind = HtmlSyntaxKind;
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// Supports native Biome AST patterns for full language coverage.
+    fn native_kind_by_name(&self, node_name: &str) -> Option<HtmlSyntaxKind> {
+        kind_by_name(node_name)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static str {
+        // TODO: See [super::JsTargetLanguage::name_for_kind()].
+        "(unknown node)"
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_node(
+        &self,
+        node_name: &str,
+        _kind: GritTargetSyntaxKind,
+        source: GritNodePatternSource,
+    ) -> &'static [(&'static str, u32)] {
+        match source {
+            GritNodePatternSource::LegacyTreeSitter => &[],
+            GritNodePatternSource::Native => native_slots_for_name(node_name),
+        }
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[("", ""), ("<GRIT_ELEMENT ", " />")]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_html_kind()
+            .is_some_and(|kind| COMMENT_KINDS.matches(kind))
+    }
+
+    /// HTML has no dedicated syntax for metavariables, so there is no node
+    /// of this kind in any HTML tree. Metavariables are recognised through
+    /// [Self::is_alternative_metavariable_kind()] instead.
+    fn metavariable_kind() -> Self::Kind {
+        HtmlSyntaxKind::TOMBSTONE
+    }
+
+    /// Returns whether the given syntax kind is an "alternative" kind for
+    /// metavariables.
+    ///
+    /// Text content may consist of nothing but a metavariable, in which case
+    /// the metavariable captures the content as a whole.
+    fn is_alternative_metavariable_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_html_kind()
+            .is_some_and(|kind| kind == HtmlSyntaxKind::HTML_CONTENT)
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        kind: GritTargetSyntaxKind,
+        slot_index: u32,
+        node: Option<GritTargetNode<'_>>,
+    ) -> bool {
+        DISREGARDED_SNIPPET_SLOTS.iter().any(
+            |(disregarded_kind, disregarded_slot_index, condition)| {
+                if GritTargetSyntaxKind::from(*disregarded_kind) != kind
+                    || *disregarded_slot_index != slot_index
+                {
+                    return false;
+                }
+
+                match condition {
+                    DisregardedSlotCondition::Always => true,
+                    DisregardedSlotCondition::OnlyIf(node_texts) => node_texts.iter().any(|text| {
+                        *text == node.as_ref().map(|node| node.text()).unwrap_or_default()
+                    }),
+                }
+            },
+        )
+    }
+
+    fn get_equivalence_class(
+        &self,
+        kind: GritTargetSyntaxKind,
+        text: &str,
+    ) -> Result<Option<LeafEquivalenceClass>, CompileError> {
+        if let Some(class) = EQUIVALENT_LEAF_NODES
+            .iter()
+            .find(|v| v.iter().any(|normalizer| normalizer.kind() == kind))
+        {
+            LeafEquivalenceClass::new(text, kind, class)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Attribute values may be unquoted, in which case there's nothing to strip.
+fn normalize_attribute_value(value: &str) -> Option<&str> {
+    match value.as_bytes() {
+        [b'"', .., b'"'] | [b'\'', .., b'\''] => normalize_quoted_string(value),
+        _ => Some(value),
+    }
+}
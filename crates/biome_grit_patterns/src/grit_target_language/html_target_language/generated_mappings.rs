@@ -0,0 +1,274 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_html_syntax as lang;
+use biome_rowan::AstNode;
+use lang::HtmlSyntaxKind;
+
+/// Returns the syntax kind for a legacy TreeSitter node name.
+pub fn legacy_kind_by_name(_node_name: &str) -> Option<HtmlSyntaxKind> {
+    None
+}
+
+/// Returns the syntax kind for a native Biome node name.
+pub fn native_kind_by_name(node_name: &str) -> Option<HtmlSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "AngularBindingName" => lang::AngularBindingName::KIND_SET.iter().next(),
+        "AngularEventBinding" => lang::AngularEventBinding::KIND_SET.iter().next(),
+        "AngularPropertyBinding" => lang::AngularPropertyBinding::KIND_SET.iter().next(),
+        "AngularStructuralDirective" => lang::AngularStructuralDirective::KIND_SET.iter().next(),
+        "AngularTemplateRefVariable" => lang::AngularTemplateRefVariable::KIND_SET.iter().next(),
+        "AngularTwoWayBinding" => lang::AngularTwoWayBinding::KIND_SET.iter().next(),
+        "AstroClassDirective" => lang::AstroClassDirective::KIND_SET.iter().next(),
+        "AstroClientDirective" => lang::AstroClientDirective::KIND_SET.iter().next(),
+        "AstroClosingFragment" => lang::AstroClosingFragment::KIND_SET.iter().next(),
+        "AstroDefineDirective" => lang::AstroDefineDirective::KIND_SET.iter().next(),
+        "AstroDirectiveValue" => lang::AstroDirectiveValue::KIND_SET.iter().next(),
+        "AstroEmbeddedContent" => lang::AstroEmbeddedContent::KIND_SET.iter().next(),
+        "AstroFragment" => lang::AstroFragment::KIND_SET.iter().next(),
+        "AstroFrontmatterElement" => lang::AstroFrontmatterElement::KIND_SET.iter().next(),
+        "AstroIsDirective" => lang::AstroIsDirective::KIND_SET.iter().next(),
+        "AstroOpeningFragment" => lang::AstroOpeningFragment::KIND_SET.iter().next(),
+        "AstroServerDirective" => lang::AstroServerDirective::KIND_SET.iter().next(),
+        "AstroSetDirective" => lang::AstroSetDirective::KIND_SET.iter().next(),
+        "HtmlAttribute" => lang::HtmlAttribute::KIND_SET.iter().next(),
+        "HtmlAttributeDoubleTextExpression" => lang::HtmlAttributeDoubleTextExpression::KIND_SET
+            .iter()
+            .next(),
+        "HtmlAttributeInitializerClause" => {
+            lang::HtmlAttributeInitializerClause::KIND_SET.iter().next()
+        }
+        "HtmlAttributeName" => lang::HtmlAttributeName::KIND_SET.iter().next(),
+        "HtmlAttributeSingleTextExpression" => lang::HtmlAttributeSingleTextExpression::KIND_SET
+            .iter()
+            .next(),
+        "HtmlCdataSection" => lang::HtmlCdataSection::KIND_SET.iter().next(),
+        "HtmlClosingElement" => lang::HtmlClosingElement::KIND_SET.iter().next(),
+        "HtmlComponentName" => lang::HtmlComponentName::KIND_SET.iter().next(),
+        "HtmlContent" => lang::HtmlContent::KIND_SET.iter().next(),
+        "HtmlDirective" => lang::HtmlDirective::KIND_SET.iter().next(),
+        "HtmlDoubleTextExpression" => lang::HtmlDoubleTextExpression::KIND_SET.iter().next(),
+        "HtmlElement" => lang::HtmlElement::KIND_SET.iter().next(),
+        "HtmlEmbeddedContent" => lang::HtmlEmbeddedContent::KIND_SET.iter().next(),
+        "HtmlMemberName" => lang::HtmlMemberName::KIND_SET.iter().next(),
+        "HtmlOpeningElement" => lang::HtmlOpeningElement::KIND_SET.iter().next(),
+        "HtmlProcessingInstruction" => lang::HtmlProcessingInstruction::KIND_SET.iter().next(),
+        "HtmlRoot" => lang::HtmlRoot::KIND_SET.iter().next(),
+        "HtmlSelfClosingElement" => lang::HtmlSelfClosingElement::KIND_SET.iter().next(),
+        "HtmlSingleTextExpression" => lang::HtmlSingleTextExpression::KIND_SET.iter().next(),
+        "HtmlSpreadAttribute" => lang::HtmlSpreadAttribute::KIND_SET.iter().next(),
+        "HtmlString" => lang::HtmlString::KIND_SET.iter().next(),
+        "HtmlTagName" => lang::HtmlTagName::KIND_SET.iter().next(),
+        "HtmlTextExpression" => lang::HtmlTextExpression::KIND_SET.iter().next(),
+        "SvelteAnimateDirective" => lang::SvelteAnimateDirective::KIND_SET.iter().next(),
+        "SvelteAttachAttribute" => lang::SvelteAttachAttribute::KIND_SET.iter().next(),
+        "SvelteAwaitBlock" => lang::SvelteAwaitBlock::KIND_SET.iter().next(),
+        "SvelteAwaitCatchBlock" => lang::SvelteAwaitCatchBlock::KIND_SET.iter().next(),
+        "SvelteAwaitCatchClause" => lang::SvelteAwaitCatchClause::KIND_SET.iter().next(),
+        "SvelteAwaitClosingBlock" => lang::SvelteAwaitClosingBlock::KIND_SET.iter().next(),
+        "SvelteAwaitOpeningBlock" => lang::SvelteAwaitOpeningBlock::KIND_SET.iter().next(),
+        "SvelteAwaitThenBlock" => lang::SvelteAwaitThenBlock::KIND_SET.iter().next(),
+        "SvelteAwaitThenClause" => lang::SvelteAwaitThenClause::KIND_SET.iter().next(),
+        "SvelteBindDirective" => lang::SvelteBindDirective::KIND_SET.iter().next(),
+        "SvelteBindFunctionBindingExpression" => {
+            lang::SvelteBindFunctionBindingExpression::KIND_SET
+                .iter()
+                .next()
+        }
+        "SvelteBindFunctionBindingInitializerClause" => {
+            lang::SvelteBindFunctionBindingInitializerClause::KIND_SET
+                .iter()
+                .next()
+        }
+        "SvelteClassDirective" => lang::SvelteClassDirective::KIND_SET.iter().next(),
+        "SvelteConstBlock" => lang::SvelteConstBlock::KIND_SET.iter().next(),
+        "SvelteCurlyDestructuredName" => lang::SvelteCurlyDestructuredName::KIND_SET.iter().next(),
+        "SvelteDebugBlock" => lang::SvelteDebugBlock::KIND_SET.iter().next(),
+        "SvelteDeclarationBlock" => lang::SvelteDeclarationBlock::KIND_SET.iter().next(),
+        "SvelteDirectiveModifier" => lang::SvelteDirectiveModifier::KIND_SET.iter().next(),
+        "SvelteDirectiveValue" => lang::SvelteDirectiveValue::KIND_SET.iter().next(),
+        "SvelteEachAsKeyedItem" => lang::SvelteEachAsKeyedItem::KIND_SET.iter().next(),
+        "SvelteEachBlock" => lang::SvelteEachBlock::KIND_SET.iter().next(),
+        "SvelteEachClosingBlock" => lang::SvelteEachClosingBlock::KIND_SET.iter().next(),
+        "SvelteEachIndex" => lang::SvelteEachIndex::KIND_SET.iter().next(),
+        "SvelteEachKey" => lang::SvelteEachKey::KIND_SET.iter().next(),
+        "SvelteEachKeyedItem" => lang::SvelteEachKeyedItem::KIND_SET.iter().next(),
+        "SvelteEachOpeningBlock" => lang::SvelteEachOpeningBlock::KIND_SET.iter().next(),
+        "SvelteElseClause" => lang::SvelteElseClause::KIND_SET.iter().next(),
+        "SvelteElseIfClause" => lang::SvelteElseIfClause::KIND_SET.iter().next(),
+        "SvelteHtmlBlock" => lang::SvelteHtmlBlock::KIND_SET.iter().next(),
+        "SvelteIfBlock" => lang::SvelteIfBlock::KIND_SET.iter().next(),
+        "SvelteIfClosingBlock" => lang::SvelteIfClosingBlock::KIND_SET.iter().next(),
+        "SvelteIfOpeningBlock" => lang::SvelteIfOpeningBlock::KIND_SET.iter().next(),
+        "SvelteInDirective" => lang::SvelteInDirective::KIND_SET.iter().next(),
+        "SvelteKeyBlock" => lang::SvelteKeyBlock::KIND_SET.iter().next(),
+        "SvelteKeyClosingBlock" => lang::SvelteKeyClosingBlock::KIND_SET.iter().next(),
+        "SvelteKeyOpeningBlock" => lang::SvelteKeyOpeningBlock::KIND_SET.iter().next(),
+        "SvelteLiteral" => lang::SvelteLiteral::KIND_SET.iter().next(),
+        "SvelteMemberProperty" => lang::SvelteMemberProperty::KIND_SET.iter().next(),
+        "SvelteName" => lang::SvelteName::KIND_SET.iter().next(),
+        "SvelteOutDirective" => lang::SvelteOutDirective::KIND_SET.iter().next(),
+        "SvelteRenameBinding" => lang::SvelteRenameBinding::KIND_SET.iter().next(),
+        "SvelteRenderBlock" => lang::SvelteRenderBlock::KIND_SET.iter().next(),
+        "SvelteRestBinding" => lang::SvelteRestBinding::KIND_SET.iter().next(),
+        "SvelteSnippetBlock" => lang::SvelteSnippetBlock::KIND_SET.iter().next(),
+        "SvelteSnippetClosingBlock" => lang::SvelteSnippetClosingBlock::KIND_SET.iter().next(),
+        "SvelteSnippetOpeningBlock" => lang::SvelteSnippetOpeningBlock::KIND_SET.iter().next(),
+        "SvelteSquareDestructuredName" => {
+            lang::SvelteSquareDestructuredName::KIND_SET.iter().next()
+        }
+        "SvelteStyleDirective" => lang::SvelteStyleDirective::KIND_SET.iter().next(),
+        "SvelteTemplateAttributeValue" => {
+            lang::SvelteTemplateAttributeValue::KIND_SET.iter().next()
+        }
+        "SvelteTemplateChunkElement" => lang::SvelteTemplateChunkElement::KIND_SET.iter().next(),
+        "SvelteTransitionDirective" => lang::SvelteTransitionDirective::KIND_SET.iter().next(),
+        "SvelteUseDirective" => lang::SvelteUseDirective::KIND_SET.iter().next(),
+        "VueDirective" => lang::VueDirective::KIND_SET.iter().next(),
+        "VueDirectiveArgument" => lang::VueDirectiveArgument::KIND_SET.iter().next(),
+        "VueDynamicArgument" => lang::VueDynamicArgument::KIND_SET.iter().next(),
+        "VueModifier" => lang::VueModifier::KIND_SET.iter().next(),
+        "VueStaticArgument" => lang::VueStaticArgument::KIND_SET.iter().next(),
+        "VueVBindShorthandDirective" => lang::VueVBindShorthandDirective::KIND_SET.iter().next(),
+        "VueVForArrayBinding" => lang::VueVForArrayBinding::KIND_SET.iter().next(),
+        "VueVForIdentifierBinding" => lang::VueVForIdentifierBinding::KIND_SET.iter().next(),
+        "VueVForInOperator" => lang::VueVForInOperator::KIND_SET.iter().next(),
+        "VueVForObjectBinding" => lang::VueVForObjectBinding::KIND_SET.iter().next(),
+        "VueVForObjectPropertyBinding" => {
+            lang::VueVForObjectPropertyBinding::KIND_SET.iter().next()
+        }
+        "VueVForOfOperator" => lang::VueVForOfOperator::KIND_SET.iter().next(),
+        "VueVForRestBinding" => lang::VueVForRestBinding::KIND_SET.iter().next(),
+        "VueVForTupleBinding" => lang::VueVForTupleBinding::KIND_SET.iter().next(),
+        "VueVForTupleElement" => lang::VueVForTupleElement::KIND_SET.iter().next(),
+        "VueVForValue" => lang::VueVForValue::KIND_SET.iter().next(),
+        "VueVOnShorthandDirective" => lang::VueVOnShorthandDirective::KIND_SET.iter().next(),
+        "VueVSlotShorthandDirective" => lang::VueVSlotShorthandDirective::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<HtmlSyntaxKind> {
+    legacy_kind_by_name(node_name).or_else(|| native_kind_by_name(node_name))
+}
+
+/// Returns the native Biome slot mappings for a node name.
+pub fn native_slots_for_name(node_name: &str) -> &'static [(&'static str, u32)] {
+    match node_name {
+        "AngularEventBinding" => &[("name", 1), ("initializer", 3)],
+        "AngularPropertyBinding" => &[("name", 1), ("initializer", 3)],
+        "AngularStructuralDirective" => &[("name", 1), ("initializer", 2)],
+        "AngularTemplateRefVariable" => &[("name", 1), ("initializer", 2)],
+        "AngularTwoWayBinding" => &[("name", 1), ("initializer", 3)],
+        "AstroClassDirective" => &[("value", 1)],
+        "AstroClientDirective" => &[("value", 1)],
+        "AstroDefineDirective" => &[("value", 1)],
+        "AstroDirectiveValue" => &[("name", 1), ("initializer", 2)],
+        "AstroFragment" => &[
+            ("opening_fragment", 0),
+            ("children", 1),
+            ("closing_fragment", 2),
+        ],
+        "AstroFrontmatterElement" => &[("content", 1)],
+        "AstroIsDirective" => &[("value", 1)],
+        "AstroServerDirective" => &[("value", 1)],
+        "AstroSetDirective" => &[("value", 1)],
+        "HtmlAttribute" => &[("name", 0), ("initializer", 1)],
+        "HtmlAttributeDoubleTextExpression" => &[("expression", 1)],
+        "HtmlAttributeInitializerClause" => &[("value", 1)],
+        "HtmlAttributeSingleTextExpression" => &[("expression", 1)],
+        "HtmlClosingElement" => &[("name", 2)],
+        "HtmlDoubleTextExpression" => &[("expression", 1)],
+        "HtmlElement" => &[
+            ("opening_element", 0),
+            ("children", 1),
+            ("closing_element", 2),
+        ],
+        "HtmlMemberName" => &[("object", 0), ("member", 2)],
+        "HtmlOpeningElement" => &[("name", 1), ("attributes", 2)],
+        "HtmlProcessingInstruction" => &[("target", 1), ("attributes", 2)],
+        "HtmlRoot" => &[
+            ("frontmatter", 1),
+            ("processing_instruction", 2),
+            ("directive", 3),
+            ("html", 4),
+        ],
+        "HtmlSelfClosingElement" => &[("name", 1), ("attributes", 2)],
+        "HtmlSingleTextExpression" => &[("expression", 1)],
+        "HtmlSpreadAttribute" => &[("argument", 2)],
+        "SvelteAnimateDirective" => &[("value", 1)],
+        "SvelteAttachAttribute" => &[("expression", 2)],
+        "SvelteAwaitBlock" => &[("opening_block", 0), ("clauses", 1), ("closing_block", 2)],
+        "SvelteAwaitCatchBlock" => &[("name", 2), ("children", 4)],
+        "SvelteAwaitCatchClause" => &[("name", 1)],
+        "SvelteAwaitOpeningBlock" => &[
+            ("expression", 2),
+            ("then_clause", 3),
+            ("catch_clause", 4),
+            ("children", 6),
+        ],
+        "SvelteAwaitThenBlock" => &[("name", 2), ("children", 4)],
+        "SvelteAwaitThenClause" => &[("name", 1)],
+        "SvelteBindDirective" => &[("value", 1)],
+        "SvelteBindFunctionBindingExpression" => &[("get", 1), ("set", 3)],
+        "SvelteBindFunctionBindingInitializerClause" => &[("value", 1)],
+        "SvelteClassDirective" => &[("value", 1)],
+        "SvelteConstBlock" => &[("expression", 2)],
+        "SvelteCurlyDestructuredName" => &[("names", 1)],
+        "SvelteDebugBlock" => &[("bindings", 2)],
+        "SvelteDeclarationBlock" => &[("declaration", 1)],
+        "SvelteDirectiveModifier" => &[("name", 1)],
+        "SvelteDirectiveValue" => &[("property", 1), ("modifiers", 2), ("initializer", 3)],
+        "SvelteEachAsKeyedItem" => &[("name", 1), ("index", 2), ("key", 3)],
+        "SvelteEachBlock" => &[
+            ("opening_block", 0),
+            ("children", 1),
+            ("else_clause", 2),
+            ("closing_block", 3),
+        ],
+        "SvelteEachIndex" => &[("value", 1)],
+        "SvelteEachKey" => &[("expression", 1)],
+        "SvelteEachKeyedItem" => &[("index", 0)],
+        "SvelteEachOpeningBlock" => &[("list", 2), ("item", 3)],
+        "SvelteElseClause" => &[("children", 3)],
+        "SvelteElseIfClause" => &[("expression", 3), ("children", 5)],
+        "SvelteHtmlBlock" => &[("expression", 2)],
+        "SvelteIfBlock" => &[
+            ("opening_block", 0),
+            ("else_if_clauses", 1),
+            ("else_clause", 2),
+            ("closing_block", 3),
+        ],
+        "SvelteIfOpeningBlock" => &[("expression", 2), ("children", 4)],
+        "SvelteInDirective" => &[("value", 1)],
+        "SvelteKeyBlock" => &[("opening_block", 0), ("children", 1), ("closing_block", 2)],
+        "SvelteKeyOpeningBlock" => &[("expression", 2)],
+        "SvelteMemberProperty" => &[("object", 0), ("member", 2)],
+        "SvelteOutDirective" => &[("value", 1)],
+        "SvelteRenameBinding" => &[("key", 0), ("name", 2)],
+        "SvelteRenderBlock" => &[("expression", 2)],
+        "SvelteRestBinding" => &[("name", 1)],
+        "SvelteSnippetBlock" => &[("opening_block", 0), ("closing_block", 1)],
+        "SvelteSnippetOpeningBlock" => &[("expression", 2), ("children", 4)],
+        "SvelteSquareDestructuredName" => &[("names", 1)],
+        "SvelteStyleDirective" => &[("value", 1)],
+        "SvelteTemplateAttributeValue" => &[("elements", 1)],
+        "SvelteTransitionDirective" => &[("value", 1)],
+        "SvelteUseDirective" => &[("value", 1)],
+        "VueDirective" => &[("arg", 1), ("modifiers", 2), ("initializer", 3)],
+        "VueDirectiveArgument" => &[("arg", 1)],
+        "VueVBindShorthandDirective" => &[("arg", 0), ("modifiers", 1), ("initializer", 2)],
+        "VueVForArrayBinding" => &[("bindings", 1)],
+        "VueVForObjectBinding" => &[("bindings", 1)],
+        "VueVForObjectPropertyBinding" => &[("property", 0), ("binding", 2)],
+        "VueVForRestBinding" => &[("binding", 1)],
+        "VueVForTupleBinding" => &[("value", 1), ("second", 2), ("third", 3)],
+        "VueVForTupleElement" => &[("binding", 1)],
+        "VueVForValue" => &[("binding", 1), ("operator", 2), ("expression", 3)],
+        "VueVOnShorthandDirective" => &[("arg", 1), ("modifiers", 2), ("initializer", 3)],
+        "VueVSlotShorthandDirective" => &[("arg", 1), ("modifiers", 2), ("initializer", 3)],
+        _ => &[],
+    }
+}
@@ -132,6 +132,13 @@ impl GritTargetLanguageImpl for JsTargetLanguage {
         })
     }
 
+    fn is_whitespace_sensitive_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_js_kind().is_some_and(|kind| {
+            kind == JsSyntaxKind::JS_TEMPLATE_CHUNK_ELEMENT
+                || kind == JsSyntaxKind::TS_TEMPLATE_CHUNK_ELEMENT
+        })
+    }
+
     fn is_disregarded_snippet_field(
         &self,
         kind: GritTargetSyntaxKind,
@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::grit_target_language::DisregardedSlotCondition::{self};
+use biome_markdown_syntax::MarkdownSyntaxKind::{self};
+
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(
+    MarkdownSyntaxKind,
+    u32,
+    DisregardedSlotCondition,
+)] = &[];
@@ -0,0 +1,105 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Maps GritQL pattern names to Biome's internal syntax kinds.
+use biome_markdown_syntax as lang;
+use biome_rowan::AstNode;
+use lang::MarkdownSyntaxKind;
+
+/// Returns the syntax kind for a legacy TreeSitter node name.
+pub fn legacy_kind_by_name(_node_name: &str) -> Option<MarkdownSyntaxKind> {
+    None
+}
+
+/// Returns the syntax kind for a native Biome node name.
+pub fn native_kind_by_name(node_name: &str) -> Option<MarkdownSyntaxKind> {
+    match node_name {
+        // Native Biome AST patterns
+        "MdAutolink" => lang::MdAutolink::KIND_SET.iter().next(),
+        "MdBullet" => lang::MdBullet::KIND_SET.iter().next(),
+        "MdCodeContent" => lang::MdCodeContent::KIND_SET.iter().next(),
+        "MdContinuationIndent" => lang::MdContinuationIndent::KIND_SET.iter().next(),
+        "MdEntityReference" => lang::MdEntityReference::KIND_SET.iter().next(),
+        "MdFencedCodeBlock" => lang::MdFencedCodeBlock::KIND_SET.iter().next(),
+        "MdFrontmatter" => lang::MdFrontmatter::KIND_SET.iter().next(),
+        "MdFrontmatterContent" => lang::MdFrontmatterContent::KIND_SET.iter().next(),
+        "MdHardLine" => lang::MdHardLine::KIND_SET.iter().next(),
+        "MdHash" => lang::MdHash::KIND_SET.iter().next(),
+        "MdHeader" => lang::MdHeader::KIND_SET.iter().next(),
+        "MdHtmlBlock" => lang::MdHtmlBlock::KIND_SET.iter().next(),
+        "MdHtmlContent" => lang::MdHtmlContent::KIND_SET.iter().next(),
+        "MdIndentCodeBlock" => lang::MdIndentCodeBlock::KIND_SET.iter().next(),
+        "MdIndentToken" => lang::MdIndentToken::KIND_SET.iter().next(),
+        "MdInlineCode" => lang::MdInlineCode::KIND_SET.iter().next(),
+        "MdInlineEmphasis" => lang::MdInlineEmphasis::KIND_SET.iter().next(),
+        "MdInlineHtml" => lang::MdInlineHtml::KIND_SET.iter().next(),
+        "MdInlineImage" => lang::MdInlineImage::KIND_SET.iter().next(),
+        "MdInlineItalic" => lang::MdInlineItalic::KIND_SET.iter().next(),
+        "MdInlineLink" => lang::MdInlineLink::KIND_SET.iter().next(),
+        "MdLinkDestination" => lang::MdLinkDestination::KIND_SET.iter().next(),
+        "MdLinkLabel" => lang::MdLinkLabel::KIND_SET.iter().next(),
+        "MdLinkReferenceDefinition" => lang::MdLinkReferenceDefinition::KIND_SET.iter().next(),
+        "MdLinkTitle" => lang::MdLinkTitle::KIND_SET.iter().next(),
+        "MdNewline" => lang::MdNewline::KIND_SET.iter().next(),
+        "MdParagraph" => lang::MdParagraph::KIND_SET.iter().next(),
+        "MdQuote" => lang::MdQuote::KIND_SET.iter().next(),
+        "MdQuoteIndent" => lang::MdQuoteIndent::KIND_SET.iter().next(),
+        "MdQuotePrefix" => lang::MdQuotePrefix::KIND_SET.iter().next(),
+        "MdReferenceImage" => lang::MdReferenceImage::KIND_SET.iter().next(),
+        "MdReferenceLink" => lang::MdReferenceLink::KIND_SET.iter().next(),
+        "MdReferenceLinkLabel" => lang::MdReferenceLinkLabel::KIND_SET.iter().next(),
+        "MdRoot" => lang::MdRoot::KIND_SET.iter().next(),
+        "MdSetextHeader" => lang::MdSetextHeader::KIND_SET.iter().next(),
+        "MdTextual" => lang::MdTextual::KIND_SET.iter().next(),
+        "MdThematicBreakBlock" => lang::MdThematicBreakBlock::KIND_SET.iter().next(),
+        "MdThematicBreakChar" => lang::MdThematicBreakChar::KIND_SET.iter().next(),
+        _ => None,
+    }
+}
+
+/// Returns the syntax kind for a legacy or native node name.
+pub fn kind_by_name(node_name: &str) -> Option<MarkdownSyntaxKind> {
+    legacy_kind_by_name(node_name).or_else(|| native_kind_by_name(node_name))
+}
+
+/// Returns the native Biome slot mappings for a node name.
+pub fn native_slots_for_name(node_name: &str) -> &'static [(&'static str, u32)] {
+    match node_name {
+        "MdAutolink" => &[("value", 1)],
+        "MdBullet" => &[("prefix", 0), ("content", 1)],
+        "MdContinuationIndent" => &[("indent", 0)],
+        "MdFencedCodeBlock" => &[
+            ("indent", 0),
+            ("code_list", 2),
+            ("content", 3),
+            ("r_fence_indent", 4),
+        ],
+        "MdFrontmatter" => &[("content", 1)],
+        "MdHeader" => &[("indent", 0), ("before", 1), ("content", 2), ("after", 3)],
+        "MdHtmlBlock" => &[("indent", 0), ("content", 1)],
+        "MdIndentCodeBlock" => &[("content", 0)],
+        "MdInlineCode" => &[("content", 1)],
+        "MdInlineEmphasis" => &[("content", 1)],
+        "MdInlineImage" => &[("alt", 2), ("destination", 5), ("title", 6)],
+        "MdInlineItalic" => &[("content", 1)],
+        "MdInlineLink" => &[("text", 1), ("destination", 4), ("title", 5)],
+        "MdLinkDestination" => &[("content", 0)],
+        "MdLinkLabel" => &[("content", 0)],
+        "MdLinkReferenceDefinition" => &[
+            ("indent", 0),
+            ("label", 2),
+            ("destination", 5),
+            ("title", 6),
+        ],
+        "MdLinkTitle" => &[("content", 0)],
+        "MdParagraph" => &[("list", 0)],
+        "MdQuote" => &[("prefix", 0), ("content", 1)],
+        "MdQuotePrefix" => &[("pre_marker_indent", 0)],
+        "MdReferenceImage" => &[("alt", 2), ("label", 4)],
+        "MdReferenceLink" => &[("text", 1), ("label", 3)],
+        "MdReferenceLinkLabel" => &[("label", 1)],
+        "MdRoot" => &[("frontmatter", 1), ("value", 2)],
+        "MdSetextHeader" => &[("content", 0)],
+        "MdThematicBreakBlock" => &[("parts", 0)],
+        _ => &[],
+    }
+}
@@ -0,0 +1,109 @@
+mod constants;
+pub mod generated_mappings;
+
+use super::{DisregardedSlotCondition, GritNodePatternSource, GritTargetLanguageImpl};
+use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKindSet};
+use biome_yaml_syntax::{YamlLanguage, YamlSyntaxKind};
+use constants::DISREGARDED_SNIPPET_SLOTS;
+use generated_mappings::{kind_by_name, native_slots_for_name};
+
+const COMMENT_KINDS: SyntaxKindSet<YamlLanguage> =
+    SyntaxKindSet::from_raw(RawSyntaxKind(YamlSyntaxKind::COMMENT as u16));
+
+#[derive(Clone, Debug)]
+pub struct YamlTargetLanguage;
+
+impl GritTargetLanguageImpl for YamlTargetLanguage {
+    type Kind = YamlSyntaxKind;
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// Supports native Biome AST patterns for full language coverage.
+    fn native_kind_by_name(&self, node_name: &str) -> Option<YamlSyntaxKind> {
+        kind_by_name(node_name)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, _kind: GritTargetSyntaxKind) -> &'static str {
+        // TODO: See [super::JsTargetLanguage::name_for_kind()].
+        "(unknown node)"
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_node(
+        &self,
+        node_name: &str,
+        _kind: GritTargetSyntaxKind,
+        source: GritNodePatternSource,
+    ) -> &'static [(&'static str, u32)] {
+        match source {
+            GritNodePatternSource::LegacyTreeSitter => &[],
+            GritNodePatternSource::Native => native_slots_for_name(node_name),
+        }
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[("", ""), ("GRIT_KEY: ", "")]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_yaml_kind()
+            .is_some_and(|kind| COMMENT_KINDS.matches(kind))
+    }
+
+    /// YAML has no dedicated syntax for metavariables, so there is no node
+    /// of this kind in any YAML tree. Metavariables are recognised through
+    /// [Self::is_alternative_metavariable_kind()] instead.
+    fn metavariable_kind() -> Self::Kind {
+        YamlSyntaxKind::TOMBSTONE
+    }
+
+    /// Returns whether the given syntax kind is an "alternative" kind for
+    /// metavariables.
+    ///
+    /// Metavariables are parsed as plain scalars, so we also accept the flow
+    /// nodes wrapping them. This allows a metavariable to capture any value.
+    fn is_alternative_metavariable_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_yaml_kind().is_some_and(|kind| {
+            matches!(
+                kind,
+                YamlSyntaxKind::YAML_FLOW_IN_BLOCK_NODE
+                    | YamlSyntaxKind::YAML_FLOW_YAML_NODE
+                    | YamlSyntaxKind::YAML_PLAIN_SCALAR
+            )
+        })
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        kind: GritTargetSyntaxKind,
+        slot_index: u32,
+        node: Option<GritTargetNode<'_>>,
+    ) -> bool {
+        DISREGARDED_SNIPPET_SLOTS.iter().any(
+            |(disregarded_kind, disregarded_slot_index, condition)| {
+                if GritTargetSyntaxKind::from(*disregarded_kind) != kind
+                    || *disregarded_slot_index != slot_index
+                {
+                    return false;
+                }
+
+                match condition {
+                    DisregardedSlotCondition::Always => true,
+                    DisregardedSlotCondition::OnlyIf(node_texts) => node_texts.iter().any(|text| {
+                        *text == node.as_ref().map(|node| node.text()).unwrap_or_default()
+                    }),
+                }
+            },
+        )
+    }
+}
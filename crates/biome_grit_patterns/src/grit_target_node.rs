@@ -1,9 +1,13 @@
 use crate::grit_tree::GritTargetTree;
 use crate::util::TextRangeGritExt;
 use biome_css_syntax::{CssSyntaxKind, CssSyntaxNode, CssSyntaxToken};
+use biome_graphql_syntax::{GraphqlSyntaxKind, GraphqlSyntaxNode, GraphqlSyntaxToken};
+use biome_html_syntax::{HtmlSyntaxKind, HtmlSyntaxNode, HtmlSyntaxToken};
 use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
 use biome_json_syntax::{JsonSyntaxKind, JsonSyntaxNode, JsonSyntaxToken};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken};
 use biome_rowan::{NodeOrToken, SyntaxKind, SyntaxSlot, TextRange};
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxNode, YamlSyntaxToken};
 use grit_util::{AstCursor, AstNode as GritAstNode, ByteRange, CodeRange, error::GritResult};
 use std::{borrow::Cow, fmt::Debug, ops::Deref};
 
@@ -184,8 +188,12 @@ macro_rules! generate_target_node {
 
 generate_target_node! {
     [CssLanguage, CssSyntaxNode, CssSyntaxToken, CssSyntaxKind],
+    [GraphqlLanguage, GraphqlSyntaxNode, GraphqlSyntaxToken, GraphqlSyntaxKind],
+    [HtmlLanguage, HtmlSyntaxNode, HtmlSyntaxToken, HtmlSyntaxKind],
     [JsLanguage, JsSyntaxNode, JsSyntaxToken, JsSyntaxKind],
-    [JsonLanguage, JsonSyntaxNode, JsonSyntaxToken, JsonSyntaxKind]
+    [JsonLanguage, JsonSyntaxNode, JsonSyntaxToken, JsonSyntaxKind],
+    [MarkdownLanguage, MarkdownSyntaxNode, MarkdownSyntaxToken, MarkdownSyntaxKind],
+    [YamlLanguage, YamlSyntaxNode, YamlSyntaxToken, YamlSyntaxKind]
 }
 
 #[derive(Clone, PartialEq)]
@@ -397,6 +405,20 @@ impl GritTargetSyntaxKind {
         }
     }
 
+    pub fn as_graphql_kind(&self) -> Option<GraphqlSyntaxKind> {
+        match self {
+            Self::GraphqlSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub fn as_html_kind(&self) -> Option<HtmlSyntaxKind> {
+        match self {
+            Self::HtmlSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
     pub fn as_js_kind(&self) -> Option<JsSyntaxKind> {
         match self {
             Self::JsSyntaxKind(kind) => Some(*kind),
@@ -410,6 +432,20 @@ impl GritTargetSyntaxKind {
             _ => None,
         }
     }
+
+    pub fn as_markdown_kind(&self) -> Option<MarkdownSyntaxKind> {
+        match self {
+            Self::MarkdownSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub fn as_yaml_kind(&self) -> Option<YamlSyntaxKind> {
+        match self {
+            Self::YamlSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -10,8 +10,12 @@ mod grit_context;
 mod grit_css_parser;
 mod grit_definitions;
 mod grit_file;
+mod grit_foreign_functions;
+mod grit_graphql_parser;
+mod grit_html_parser;
 mod grit_js_parser;
 mod grit_json_parser;
+mod grit_markdown_parser;
 mod grit_node;
 mod grit_node_patterns;
 mod grit_query;
@@ -19,6 +23,7 @@ mod grit_resolved_pattern;
 mod grit_target_language;
 mod grit_target_node;
 mod grit_tree;
+mod grit_yaml_parser;
 mod linearization;
 mod pattern_compiler;
 mod source_location_ext;
@@ -38,7 +43,8 @@ pub use grit_query::{
 };
 pub use grit_resolved_pattern::GritResolvedPattern;
 pub use grit_target_language::{
-    CssTargetLanguage, GritTargetLanguage, JsTargetLanguage, JsonTargetLanguage,
+    CssTargetLanguage, GraphqlTargetLanguage, GritTargetLanguage, HtmlTargetLanguage,
+    JsTargetLanguage, JsonTargetLanguage, MarkdownTargetLanguage, YamlTargetLanguage,
 };
 pub use grit_target_node::{GritTargetLanguageNode, GritTargetNode, GritTargetSyntaxKind};
 
@@ -11,8 +11,10 @@ use std::collections::HashMap;
 /// Simplified linearization function that applies effects to produce rewritten
 /// source text.
 ///
-/// This is a simplified version of the upstream `linearize_binding` that skips
-/// padding/indent alignment (not yet implemented for Biome's target languages).
+/// This is a simplified version of the upstream `linearize_binding`. When a
+/// `distributed_indent` is given, the lines of the range are re-indented to it
+/// first. Multi-line replacements are then padded to the indentation of the
+/// line they're inserted on.
 #[expect(clippy::too_many_arguments)]
 pub(crate) fn linearize_binding<'a>(
     language: &GritTargetLanguage,
@@ -21,16 +23,20 @@ pub(crate) fn linearize_binding<'a>(
     memo: &mut HashMap<CodeRange, Option<String>>,
     source: &'a str,
     range: CodeRange,
-    _distributed_indent: Option<usize>,
+    skip_padding_ranges: &[CodeRange],
+    distributed_indent: Option<usize>,
     logs: &mut AnalysisLogs,
 ) -> GritResult<Cow<'a, str>> {
     // Get only top-level effects within this range.
     let top_level_effects = get_top_level_effects(effects, memo, &range, language, logs)?;
 
+    let aligned = match distributed_indent {
+        Some(padding) => AlignedText::new(source, &range, skip_padding_ranges, padding),
+        None => AlignedText::unaligned(source, &range),
+    };
+
     if top_level_effects.is_empty() {
-        return Ok(Cow::Borrowed(
-            &source[range.start as usize..range.end as usize],
-        ));
+        return Ok(aligned.text);
     }
 
     // For each effect, compute the linearized replacement text.
@@ -39,17 +45,26 @@ pub(crate) fn linearize_binding<'a>(
         let binding = &effect.binding;
         let binding_range = binding.code_range(language);
 
+        let byte_range = binding.range(language).ok_or_else(|| {
+            GritPatternError::new("failed to apply rewrite: binding has no byte range")
+        })?;
+        let (Some(start), Some(end)) = (
+            byte_range.start.checked_sub(range.start as usize),
+            byte_range.end.checked_sub(range.start as usize),
+        ) else {
+            return Err(GritPatternError::new(format!(
+                "failed to apply rewrite: binding starts before the linearized range: start={}, range_start={}",
+                byte_range.start, range.start
+            )));
+        };
+        let (start, end) = (aligned.map_position(start), aligned.map_position(end));
+
         if let Some(ref br) = binding_range {
             // Check memo cache for rewrites.
             if matches!(effect.kind, EffectKind::Rewrite) {
                 match memo.get(br) {
                     Some(Some(cached_text)) => {
-                        let byte_range = binding.range(language).ok_or_else(|| {
-                            GritPatternError::new(
-                                "failed to apply rewrite: binding has no byte range",
-                            )
-                        })?;
-                        replacements.push((byte_range.start, byte_range.end, cached_text.clone()));
+                        replacements.push((start, end, cached_text.clone()));
                         continue;
                     }
                     // `None` marks an in-progress rewrite; skip to avoid recursive re-entry.
@@ -62,10 +77,11 @@ pub(crate) fn linearize_binding<'a>(
             }
         }
 
-        // Recursively linearize the replacement pattern.
+        // Recursively linearize the replacement pattern. Its bindings are
+        // dedented, so it can be padded to wherever it's inserted.
         let res = effect
             .pattern
-            .linearized_text(language, effects, files, memo, false, logs)?;
+            .linearized_text(language, effects, files, memo, true, logs)?;
 
         if let Some(ref br) = binding_range
             && matches!(effect.kind, EffectKind::Rewrite)
@@ -73,18 +89,16 @@ pub(crate) fn linearize_binding<'a>(
             memo.insert(br.clone(), Some(res.to_string()));
         }
 
-        let byte_range = binding.range(language).ok_or_else(|| {
-            GritPatternError::new("failed to apply rewrite: binding has no byte range")
-        })?;
-        replacements.push((byte_range.start, byte_range.end, res.into_owned()));
+        replacements.push((start, end, res.into_owned()));
     }
 
     // Sort replacements by start offset.
     replacements.sort_by_key(|(start, _, _)| *start);
 
-    // Walk source, copying gaps and inserting replacements.
-    let mut result = String::with_capacity(range.end as usize - range.start as usize);
-    let mut cursor = range.start as usize;
+    // Walk the aligned text, copying gaps and inserting padded replacements.
+    let text = aligned.text;
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
 
     for (start, end, replacement) in &replacements {
         if *start < cursor {
@@ -93,21 +107,170 @@ pub(crate) fn linearize_binding<'a>(
             )));
         }
         if *start > cursor {
-            result.push_str(&source[cursor..*start]);
+            result.push_str(&text[cursor..*start]);
         }
-        result.push_str(replacement);
+        let padding = line_padding(&result, result.len()).to_owned();
+        result.push_str(&pad_snippet(replacement, &padding));
         cursor = *end;
     }
 
-    // Copy any remaining source after the last replacement.
-    if cursor < range.end as usize {
-        result.push_str(&source[cursor..range.end as usize]);
+    // Copy any remaining text after the last replacement.
+    if cursor < text.len() {
+        result.push_str(&text[cursor..]);
     }
 
     memo.insert(range, Some(result.clone()));
     Ok(Cow::Owned(result))
 }
 
+/// Text of a range of the source, with its lines re-indented.
+pub(crate) struct AlignedText<'a> {
+    pub text: Cow<'a, str>,
+
+    /// The lines whose indentation changed, in order.
+    shifts: Vec<LineShift>,
+}
+
+/// Change of indentation of a single line.
+struct LineShift {
+    /// Offset of the start of the line, relative to the start of the range.
+    start: usize,
+
+    /// Length of the padding removed from the line.
+    removed: usize,
+
+    /// Length of the padding added to the line.
+    added: usize,
+}
+
+impl<'a> AlignedText<'a> {
+    /// Re-indents the lines of `range` so that the line on which it starts
+    /// has `new_padding` spaces of indentation.
+    ///
+    /// The first line is kept as-is, since the range doesn't start at the
+    /// beginning of it. Following lines lose the padding of the first line,
+    /// and get the new padding instead, while preserving any additional
+    /// indentation they have. Lines that start inside one of the
+    /// `skip_ranges` are left untouched, because their whitespace is
+    /// significant.
+    pub(crate) fn new(
+        source: &'a str,
+        range: &CodeRange,
+        skip_ranges: &[CodeRange],
+        new_padding: usize,
+    ) -> Self {
+        let (range_start, range_end) = (range.start as usize, range.end as usize);
+        let text = &source[range_start..range_end];
+        let old_padding = line_padding(source, range_start);
+        let new_padding = " ".repeat(new_padding);
+        if old_padding == new_padding || !text.contains('\n') {
+            return Self::unaligned(source, range);
+        }
+
+        let mut lines = text.split('\n');
+        let mut result = String::with_capacity(text.len());
+        let mut shifts = Vec::new();
+        let first_line = lines.next().unwrap_or_default();
+        result.push_str(first_line);
+        let mut line_start = first_line.len() + 1;
+        for line in lines {
+            result.push('\n');
+            let position = range_start + line_start;
+            let is_skipped = skip_ranges.iter().any(|skip_range| {
+                (skip_range.start as usize) < position && position <= skip_range.end as usize
+            });
+            if is_skipped {
+                result.push_str(line);
+            } else {
+                let removed = line
+                    .bytes()
+                    .zip(old_padding.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                let content = &line[removed..];
+                let added = if content.trim().is_empty() {
+                    0
+                } else {
+                    new_padding.len()
+                };
+                result.push_str(&new_padding[..added]);
+                result.push_str(content);
+                if removed != added {
+                    shifts.push(LineShift {
+                        start: line_start,
+                        removed,
+                        added,
+                    });
+                }
+            }
+            line_start += line.len() + 1;
+        }
+
+        Self {
+            text: Cow::Owned(result),
+            shifts,
+        }
+    }
+
+    /// Returns the text of `range` without changing its indentation.
+    pub(crate) fn unaligned(source: &'a str, range: &CodeRange) -> Self {
+        Self {
+            text: Cow::Borrowed(&source[range.start as usize..range.end as usize]),
+            shifts: Vec::new(),
+        }
+    }
+
+    /// Maps an offset relative to the start of the original range to the
+    /// corresponding offset in the aligned text.
+    ///
+    /// Offsets inside removed padding are mapped to the start of the content
+    /// of their line.
+    pub(crate) fn map_position(&self, position: usize) -> usize {
+        let mut removed = 0;
+        let mut added = 0;
+        for shift in &self.shifts {
+            if position < shift.start {
+                break;
+            }
+            if position < shift.start + shift.removed {
+                return shift.start - removed + added + shift.added;
+            }
+            removed += shift.removed;
+            added += shift.added;
+        }
+        position - removed + added
+    }
+}
+
+/// Returns the indentation of the line on which `position` lies, up to
+/// `position`.
+pub(crate) fn line_padding(text: &str, position: usize) -> &str {
+    let line_start = text[..position].rfind('\n').map_or(0, |index| index + 1);
+    let line = &text[line_start..position];
+    let padding_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..padding_len]
+}
+
+/// Adds `padding` at the start of every non-blank line of `snippet`, except
+/// the first one.
+pub(crate) fn pad_snippet<'a>(snippet: &'a str, padding: &str) -> Cow<'a, str> {
+    if padding.is_empty() || !snippet.contains('\n') {
+        return Cow::Borrowed(snippet);
+    }
+
+    let mut lines = snippet.split('\n');
+    let mut result = String::with_capacity(snippet.len());
+    result.push_str(lines.next().unwrap_or_default());
+    for line in lines {
+        result.push('\n');
+        if !line.trim().is_empty() {
+            result.push_str(padding);
+        }
+        result.push_str(line);
+    }
+    Cow::Owned(result)
+}
+
 /// Simplified apply_effects: applies accumulated effects to produce rewritten
 /// source for a file. Returns the rewritten source as an owned String.
 pub(crate) fn apply_effects<'a>(
@@ -127,7 +290,15 @@ pub(crate) fn apply_effects<'a>(
     let range = CodeRange::new(0, len, source);
 
     let result = linearize_binding(
-        language, effects, files, &mut memo, source, range, None, logs,
+        language,
+        effects,
+        files,
+        &mut memo,
+        source,
+        range,
+        &[],
+        None,
+        logs,
     )?;
     Ok(result.into_owned())
 }
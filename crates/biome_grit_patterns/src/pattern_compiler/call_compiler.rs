@@ -4,7 +4,9 @@ use biome_grit_syntax::{
     AnyGritMaybeNamedArg, AnyGritPattern, GritNamedArgList, GritNodeLike, GritSyntaxKind,
 };
 use biome_rowan::AstNode;
-use grit_pattern_matcher::pattern::{Call, CallBuiltIn, CallFunction, FilePattern, Pattern};
+use grit_pattern_matcher::pattern::{
+    Call, CallBuiltIn, CallForeignFunction, CallFunction, FilePattern, Pattern,
+};
 use grit_util::{ByteRange, Language};
 use std::collections::BTreeMap;
 
@@ -60,6 +62,15 @@ pub(super) fn call_pattern_from_node_with_name(
         Ok(Pattern::CallFunction(Box::new(CallFunction::new(
             info.index, args,
         ))))
+    } else if let Some(info) = context
+        .compilation
+        .foreign_function_definition_info
+        .get(&name)
+    {
+        let args = match_args_to_params(&name, args, &collect_params(&info.parameters), lang)?;
+        Ok(Pattern::CallForeignFunction(Box::new(
+            CallForeignFunction::new(info.index, args),
+        )))
     } else if let Some(info) = context.compilation.pattern_definition_info.get(&name) {
         let args = match_args_to_params(&name, args, &collect_params(&info.parameters), lang)?;
         Ok(Pattern::Call(Box::new(Call::new(info.index, args))))
@@ -130,6 +141,12 @@ pub(super) fn named_args_from_node(
         )
     } else if let Some(info) = context.compilation.function_definition_info.get(name) {
         Some(collect_params(&info.parameters))
+    } else if let Some(info) = context
+        .compilation
+        .foreign_function_definition_info
+        .get(name)
+    {
+        Some(collect_params(&info.parameters))
     } else {
         context
             .compilation
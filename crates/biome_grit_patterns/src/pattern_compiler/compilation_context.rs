@@ -20,6 +20,7 @@ pub(crate) struct CompilationContext<'a> {
     pub pattern_definition_info: BTreeMap<String, DefinitionInfo>,
     pub predicate_definition_info: BTreeMap<String, DefinitionInfo>,
     pub function_definition_info: BTreeMap<String, DefinitionInfo>,
+    pub foreign_function_definition_info: BTreeMap<String, DefinitionInfo>,
 }
 
 impl<'a> CompilationContext<'a> {
@@ -36,6 +37,7 @@ impl<'a> CompilationContext<'a> {
             pattern_definition_info: Default::default(),
             predicate_definition_info: Default::default(),
             function_definition_info: Default::default(),
+            foreign_function_definition_info: Default::default(),
         }
     }
 }
@@ -0,0 +1,6 @@
+function main() {
+    const value = compute();
+    if (value) {
+        print(value);
+    }
+}
@@ -13,9 +13,9 @@ pub use crate::services::aria::{Aria, AriaServices};
 pub use crate::services::module_graph::{HtmlDbService, HtmlModuleGraph};
 use crate::suppression_action::HtmlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    BatchPluginVisitor, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases,
+    PluginTargetLanguage, RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_aria::AriaRoles;
 use biome_deserialize::TextRange;
@@ -75,6 +75,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: HtmlFileSource,
     html_services: HtmlAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -93,6 +94,7 @@ where
         options,
         source_type,
         html_services,
+        plugins,
         emit_signal,
     )
 }
@@ -110,6 +112,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: HtmlFileSource,
     html_services: HtmlAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -175,6 +178,22 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    let html_plugins: Vec<_> = plugins
+        .iter()
+        .filter(|p| p.language() == PluginTargetLanguage::Html)
+        .cloned()
+        .collect();
+
+    if filter.match_plugins() && !html_plugins.is_empty() {
+        // SAFETY: All plugins have been verified to target HTML above.
+        unsafe {
+            analyzer.add_visitor(
+                Phases::Syntax,
+                Box::new(BatchPluginVisitor::new_unchecked(&html_plugins)),
+            );
+        }
+    }
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -229,6 +248,7 @@ mod tests {
             &options,
             HtmlFileSource::html(),
             crate::HtmlAnalyzerServices::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
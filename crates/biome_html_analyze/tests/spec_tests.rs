@@ -161,6 +161,7 @@ pub(crate) fn analyze_and_snap(
         &options,
         source_type,
         html_services,
+        &[],
         |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions(ActionFilter::all()) {
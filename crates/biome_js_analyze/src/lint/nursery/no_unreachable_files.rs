@@ -0,0 +1,109 @@
+use crate::services::database::ResolvedImports;
+use biome_analyze::{Rule, RuleDiagnostic, RuleDomain, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_js_syntax::AnyJsRoot;
+use biome_module_graph::js_project_usage;
+use biome_rowan::TextRange;
+use biome_rule_options::no_unreachable_files::NoUnreachableFilesOptions;
+
+declare_lint_rule! {
+    /// Disallow files that can't be reached from any entry point of the package.
+    ///
+    /// A file that isn't imported, directly or transitively, by an entry point
+    /// of the package is never executed, and it can probably be removed.
+    ///
+    /// The entry points of the package are taken from the `main`, `exports`
+    /// and `bin` fields of the closest `package.json`. Static imports, dynamic
+    /// imports, `require()` calls and re-exports are followed. When the
+    /// package doesn't declare any entry point, this rule doesn't report
+    /// anything.
+    ///
+    /// Files that are only used by tools, such as tests and configuration
+    /// files, aren't reachable from the entry points either. Use the
+    /// `overrides` of the configuration to disable the rule for them.
+    ///
+    /// :::note
+    /// This rule requires the whole project to be scanned, so it's
+    /// computationally expensive.
+    /// :::
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "main": "./index.js"
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// export const answer = 42;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,file=legacy.js
+    /// export const question = "What do you get if you multiply six by nine?";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "main": "./index.js"
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// export { answer } from "./answer.js";
+    /// ```
+    ///
+    /// ```js,file=answer.js
+    /// export const answer = 42;
+    /// ```
+    ///
+    pub NoUnreachableFiles {
+        version: "next",
+        name: "noUnreachableFiles",
+        language: "js",
+        severity: Severity::Warning,
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoUnreachableFiles {
+    type Query = ResolvedImports<AnyJsRoot>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = NoUnreachableFilesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let file_path = ctx.file_path();
+        let entry_points = ctx.entry_points_for_path(file_path)?;
+        if !ctx.db().contains(file_path) {
+            return None;
+        }
+
+        let usage = js_project_usage(ctx.db(), entry_points);
+        (!usage.is_reachable(file_path)).then_some(())
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None as Option<TextRange>,
+                markup! {
+                    "This file isn't reachable from any entry point of the package."
+                },
+            )
+            .note(markup! {
+                "The entry points are declared by the "<Emphasis>"main"</Emphasis>", "<Emphasis>"exports"</Emphasis>" and "<Emphasis>"bin"</Emphasis>" fields of the "<Emphasis>"package.json"</Emphasis>"."
+            })
+            .note(markup! {
+                "Remove the file if it isn't used anymore, or import it from a module that is reachable."
+            }),
+        )
+    }
+}
@@ -0,0 +1,294 @@
+use crate::services::database::ResolvedImports;
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExpression,
+    AnyTsIdentifierBinding, AnyTsModuleName, JsExport, JsIdentifierBinding, JsSyntaxToken,
+    unescape_js_string,
+};
+use biome_module_graph::js_project_usage;
+use biome_rowan::{AstNode, AstSeparatedList, Text, TextRange};
+use biome_rule_options::no_unused_exports::NoUnusedExportsOptions;
+
+declare_lint_rule! {
+    /// Disallow exports that aren't imported by any other module of the project.
+    ///
+    /// An export that nothing imports is dead code: it can be removed, or the
+    /// `export` keyword can be dropped if the binding is still used locally.
+    ///
+    /// The entry points of the package are taken from the `main`, `exports`
+    /// and `bin` fields of the closest `package.json`. The exports of the entry
+    /// points are the public API of the package, so they're never reported.
+    /// Re-exports are followed, so an export that is only re-exported by a
+    /// module that nobody imports is still reported.
+    ///
+    /// Files that aren't reachable from any entry point are reported by
+    /// [`noUnreachableFiles`](https://biomejs.dev/linter/rules/no-unreachable-files)
+    /// instead. When the package doesn't declare any entry point, this rule
+    /// doesn't report anything.
+    ///
+    /// :::note
+    /// This rule requires the whole project to be scanned, so it's
+    /// computationally expensive.
+    /// :::
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "main": "./index.js"
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// import { sum } from "./math.js";
+    ///
+    /// export const total = sum(1, 2);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,file=math.js
+    /// export function sum(a, b) {
+    ///     return a + b;
+    /// }
+    ///
+    /// export function multiply(a, b) {
+    ///     return a * b;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "main": "./index.js"
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// import { sum } from "./math.js";
+    ///
+    /// export const total = sum(1, 2);
+    /// ```
+    ///
+    /// ```js,file=math.js
+    /// export function sum(a, b) {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-unused-modules").inspired()],
+        severity: Severity::Warning,
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+pub struct UnusedExport {
+    /// The exported name
+    name: Text,
+    /// The range of the exported name
+    range: TextRange,
+}
+
+impl Rule for NoUnusedExports {
+    type Query = ResolvedImports<JsExport>;
+    type State = UnusedExport;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUnusedExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let file_path = ctx.file_path();
+        let Some(entry_points) = ctx.entry_points_for_path(file_path) else {
+            return Box::default();
+        };
+
+        let usage = js_project_usage(ctx.db(), entry_points);
+        if !usage.is_reachable(file_path) {
+            return Box::default();
+        }
+
+        exported_names(ctx.query())
+            .into_iter()
+            .filter(|export| !usage.is_export_used(file_path, export.name.text()))
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The export "<Emphasis>{state.name.text()}</Emphasis>" isn't imported by any module of the project."
+                },
+            )
+            .note(markup! {
+                "Remove the export, or remove the "<Emphasis>"export"</Emphasis>" keyword if the binding is used in this module."
+            }),
+        )
+    }
+}
+
+/// Returns the names exported by `export`, together with the range of the
+/// name.
+///
+/// Blanket re-exports such as `export * from "./module.js"` don't export any
+/// name of their own, so they return nothing.
+fn exported_names(export: &JsExport) -> Vec<UnusedExport> {
+    let Ok(clause) = export.export_clause() else {
+        return Vec::new();
+    };
+
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => declaration_names(declaration),
+        AnyJsExportClause::TsExportDeclareClause(clause) => clause
+            .declaration()
+            .map(declaration_names)
+            .unwrap_or_default(),
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+            default_export_name(clause.default_token().ok())
+        }
+        AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+            default_export_name(clause.default_token().ok())
+        }
+        AnyJsExportClause::JsExportNamedClause(clause) => clause
+            .specifiers()
+            .iter()
+            .flatten()
+            .filter_map(|specifier| {
+                Some(UnusedExport {
+                    name: specifier.exported_name().ok()?,
+                    range: specifier.range(),
+                })
+            })
+            .collect(),
+        AnyJsExportClause::JsExportNamedFromClause(clause) => clause
+            .specifiers()
+            .iter()
+            .flatten()
+            .filter_map(|specifier| {
+                let name = match specifier.export_as() {
+                    Some(export_as) => export_as.exported_name().ok()?,
+                    None => specifier.source_name().ok()?,
+                };
+                Some(UnusedExport {
+                    name: unescape_js_string(name.inner_string_text().ok()?),
+                    range: specifier.range(),
+                })
+            })
+            .collect(),
+        AnyJsExportClause::JsExportFromClause(clause) => clause
+            .export_as()
+            .and_then(|export_as| {
+                let name = export_as.exported_name().ok()?;
+                Some(UnusedExport {
+                    name: unescape_js_string(name.inner_string_text().ok()?),
+                    range: name.range(),
+                })
+            })
+            .into_iter()
+            .collect(),
+        AnyJsExportClause::TsExportAsNamespaceClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => Vec::new(),
+    }
+}
+
+fn declaration_names(declaration: AnyJsDeclarationClause) -> Vec<UnusedExport> {
+    let binding = match declaration {
+        AnyJsDeclarationClause::JsClassDeclaration(node) => node.id().ok(),
+        AnyJsDeclarationClause::JsFunctionDeclaration(node) => node.id().ok(),
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(node) => node.id().ok(),
+        AnyJsDeclarationClause::TsEnumDeclaration(node) => node.id().ok(),
+        AnyJsDeclarationClause::JsVariableDeclarationClause(node) => {
+            let Ok(declaration) = node.declaration() else {
+                return Vec::new();
+            };
+            return declaration
+                .declarators()
+                .iter()
+                .flatten()
+                .filter_map(|declarator| declarator.id().ok())
+                .flat_map(|pattern| binding_pattern_names(&pattern))
+                .collect();
+        }
+        AnyJsDeclarationClause::TsInterfaceDeclaration(node) => {
+            return ts_binding_name(node.id().ok()).into_iter().collect();
+        }
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(node) => {
+            return ts_binding_name(node.binding_identifier().ok())
+                .into_iter()
+                .collect();
+        }
+        AnyJsDeclarationClause::TsModuleDeclaration(node) => {
+            return match node.name() {
+                Ok(AnyTsModuleName::AnyTsIdentifierBinding(binding)) => {
+                    ts_binding_name(Some(binding)).into_iter().collect()
+                }
+                _ => Vec::new(),
+            };
+        }
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+        | AnyJsDeclarationClause::TsGlobalDeclaration(_)
+        | AnyJsDeclarationClause::TsImportEqualsDeclaration(_) => None,
+    };
+
+    binding
+        .as_ref()
+        .and_then(AnyJsBinding::as_js_identifier_binding)
+        .and_then(identifier_binding_name)
+        .into_iter()
+        .collect()
+}
+
+/// Returns the names bound by `pattern`, skipping the bindings inside default
+/// values such as the parameters of `export const { fn = (a) => a } = object`.
+fn binding_pattern_names(pattern: &AnyJsBindingPattern) -> Vec<UnusedExport> {
+    pattern
+        .syntax()
+        .descendants()
+        .filter_map(JsIdentifierBinding::cast)
+        .filter(|binding| {
+            !binding
+                .syntax()
+                .ancestors()
+                .take_while(|ancestor| ancestor != pattern.syntax())
+                .any(|ancestor| AnyJsExpression::can_cast(ancestor.kind()))
+        })
+        .filter_map(|binding| identifier_binding_name(&binding))
+        .collect()
+}
+
+fn identifier_binding_name(binding: &JsIdentifierBinding) -> Option<UnusedExport> {
+    let token = binding.name_token().ok()?;
+    Some(UnusedExport {
+        name: token.token_text_trimmed().into(),
+        range: token.text_trimmed_range(),
+    })
+}
+
+fn ts_binding_name(binding: Option<AnyTsIdentifierBinding>) -> Option<UnusedExport> {
+    let token = binding?.as_ts_identifier_binding()?.name_token().ok()?;
+    Some(UnusedExport {
+        name: token.token_text_trimmed().into(),
+        range: token.text_trimmed_range(),
+    })
+}
+
+fn default_export_name(default_token: Option<JsSyntaxToken>) -> Vec<UnusedExport> {
+    default_token
+        .map(|token| UnusedExport {
+            name: Text::new_static("default"),
+            range: token.text_trimmed_range(),
+        })
+        .into_iter()
+        .collect()
+}
@@ -2,7 +2,9 @@ use biome_analyze::{
     AddVisitor, FromServices, Phase, Phases, QueryKey, QueryMatch, Queryable, RuleDomain, RuleKey,
     RuleMetadata, ServiceBag, ServicesDiagnostic, SyntaxVisitor,
 };
-use biome_module_graph::{JsModuleInfo, ModuleDb, ModuleInfo};
+use biome_module_graph::{
+    JsEntryPoints, JsModuleInfo, ModuleDb, ModuleInfo, js_entry_points_for_path,
+};
 use biome_project_layout::ProjectLayout;
 use biome_rowan::{AstNode, Language, SyntaxNode, TextRange};
 use camino::Utf8Path;
@@ -34,6 +36,15 @@ impl DbService {
     pub fn project_layout(&self) -> &ProjectLayout {
         self.1.as_ref()
     }
+
+    /// Returns the entry points of the package that contains `path`, as
+    /// declared by the `main`, `exports` and `bin` fields of its `package.json`.
+    ///
+    /// Only the entry points that are part of the module graph are returned.
+    /// Returns `None` when the package doesn't declare any of them.
+    pub fn entry_points_for_path(&self, path: &Utf8Path) -> Option<JsEntryPoints<'_>> {
+        js_entry_points_for_path(self.db(), self.project_layout(), path)
+    }
 }
 
 impl FromServices for DbService {
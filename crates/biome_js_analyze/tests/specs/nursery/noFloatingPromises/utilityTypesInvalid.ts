@@ -0,0 +1,18 @@
+/* should generate diagnostics */
+async function fetchUser(): Promise<string> {
+	return "user";
+}
+type Api = {
+	save: () => Promise<void>;
+};
+type Jobs = { [K in "build" | "test"]: () => Promise<void> };
+declare const maybeSave: (() => Promise<void>) | undefined;
+declare function loadUser(): ReturnType<typeof fetchUser>;
+declare const save: Api["save"];
+declare const definiteSave: NonNullable<typeof maybeSave>;
+declare const jobs: Jobs;
+
+loadUser();
+save();
+definiteSave();
+jobs.build();
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+import { helper } from "./used.js";
+
+export const run = () => helper();
@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+export const lazy = true;
@@ -0,0 +1,2 @@
+/* should generate diagnostics */
+export const legacy = true;
@@ -0,0 +1,8 @@
+/* should generate diagnostics */
+export function format(value) {
+    return String(value);
+}
+
+export function parse(value) {
+    return Number(value);
+}
@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+import { min, sum } from "./math.js";
+import * as strings from "./strings.js";
+
+export { format } from "./reexports.js";
+
+export const total = sum(1, min(2, 3));
+export const shout = strings.upper("hello");
@@ -0,0 +1,12 @@
+/* should generate diagnostics */
+export function sum(a, b) {
+    return a + b;
+}
+
+export function multiply(a, b) {
+    return a * b;
+}
+
+export const { min, max } = Math;
+
+export default sum;
@@ -0,0 +1,2 @@
+/* should generate diagnostics */
+export { format, parse } from "./format.js";
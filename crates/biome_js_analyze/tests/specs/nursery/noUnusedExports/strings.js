@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+export const upper = (value) => value.toUpperCase();
+export const lower = (value) => value.toLowerCase();
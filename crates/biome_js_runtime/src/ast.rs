@@ -74,15 +74,16 @@ impl JsAstNode {
         Some(node.node.text_trimmed_range())
     }
 
-    fn from_this(this: &JsValue) -> Option<JsSyntaxNode> {
-        let object = this.as_object()?;
+    /// Returns the syntax node wrapped by `value`, if it's an AST node.
+    pub(crate) fn to_syntax(value: &JsValue) -> Option<JsSyntaxNode> {
+        let object = value.as_object()?;
         let node = object.downcast_ref::<Self>()?;
 
         Some(node.node.clone())
     }
 
     fn get_kind(this: &JsValue, _args: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
-        let Some(node) = Self::from_this(this) else {
+        let Some(node) = Self::to_syntax(this) else {
             return Err(JsNativeError::typ()
                 .with_message("AST getter called with an invalid receiver")
                 .into());
@@ -92,7 +93,7 @@ impl JsAstNode {
     }
 
     fn get_text(this: &JsValue, _args: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
-        let Some(node) = Self::from_this(this) else {
+        let Some(node) = Self::to_syntax(this) else {
             return Err(JsNativeError::typ()
                 .with_message("AST getter called with an invalid receiver")
                 .into());
@@ -101,6 +102,19 @@ impl JsAstNode {
         Ok(JsString::from(node.text_trimmed().to_string()).into())
     }
 
+    fn get_range(this: &JsValue, _args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let Some(node) = Self::to_syntax(this) else {
+            return Err(JsNativeError::typ()
+                .with_message("AST getter called with an invalid receiver")
+                .into());
+        };
+
+        let range = node.text_trimmed_range();
+        let offsets = [range.start(), range.end()].map(|offset| JsValue::from(u32::from(offset)));
+
+        Ok(JsArray::from_iter(offsets, context).into())
+    }
+
     fn wrap_optional_node<N>(node: Option<N>, context: &mut Context) -> JsValue
     where
         N: AstNode<Language = JsLanguage>,
@@ -180,7 +194,7 @@ macro_rules! register_js_ast_fields {
                 |this: &JsValue, _args: &[JsValue], js_context: &mut Context| {
                     let $context = js_context;
                     let _ = &$context;
-                    let Some(syntax) = Self::from_this(this) else {
+                    let Some(syntax) = Self::to_syntax(this) else {
                         return Ok(JsValue::undefined());
                     };
                     if syntax.kind() != $node_kind {
@@ -212,10 +226,18 @@ impl Class for JsAstNode {
             NativeFunction::from_fn_ptr(Self::get_kind).to_js_function(class.context().realm());
         let text =
             NativeFunction::from_fn_ptr(Self::get_text).to_js_function(class.context().realm());
+        let range =
+            NativeFunction::from_fn_ptr(Self::get_range).to_js_function(class.context().realm());
 
         class
             .accessor(js_string!("kind"), Some(kind), None, Attribute::ENUMERABLE)
-            .accessor(js_string!("text"), Some(text), None, Attribute::ENUMERABLE);
+            .accessor(js_string!("text"), Some(text), None, Attribute::ENUMERABLE)
+            .accessor(
+                js_string!("range"),
+                Some(range),
+                None,
+                Attribute::ENUMERABLE,
+            );
 
         if !class.context().has_data::<JsAstPrototypeCache>() {
             let _ = class.context().insert_data(JsAstPrototypeCache::default());
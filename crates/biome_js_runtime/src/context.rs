@@ -9,13 +9,13 @@ use boa_engine::{
 };
 use camino::Utf8Path;
 
-use biome_analyze::RuleDiagnostic;
 use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode};
 use biome_resolver::FsWithResolverProxy;
 
 use crate::JsModuleLoader;
 use crate::ast::JsAstNode;
-use crate::plugin_api::JsPluginApi;
+use crate::plugin_api::{JsPluginApi, JsPluginDiagnostic};
+use crate::semantic::JsAnalyzedFile;
 use crate::source::read_module_source;
 
 #[cfg(target_arch = "wasm32")]
@@ -77,10 +77,23 @@ impl JsExecContext {
     }
 
     #[inline]
-    pub fn pull_diagnostics(&mut self) -> Vec<RuleDiagnostic> {
+    pub fn pull_diagnostics(&mut self) -> Vec<JsPluginDiagnostic> {
         self.api.pull_diagnostics()
     }
 
+    /// Sets the file the next rule invocations run on, so the plugins can
+    /// query its semantic model and types.
+    ///
+    /// The file should be cleared with [`Self::clear_analyzed_file`] once the
+    /// rules ran, so the context doesn't retain the module graph.
+    pub fn set_analyzed_file(&mut self, file: JsAnalyzedFile) {
+        self.api.set_analyzed_file(file);
+    }
+
+    pub fn clear_analyzed_file(&mut self) {
+        self.api.clear_analyzed_file();
+    }
+
     pub fn import_module(&mut self, path: impl AsRef<Utf8Path>) -> JsResult<Module> {
         let ctx = &mut self.ctx;
         let path = path.as_ref();
@@ -208,6 +221,12 @@ impl JsExecContext {
         JsAstNode::from_node(node, &mut self.ctx)
     }
 
+    /// Converts a JSON value, such as the options of a rule, to a value that can be passed to a
+    /// plugin function as an argument.
+    pub fn create_js_value(&mut self, value: &serde_json::Value) -> JsResult<JsValue> {
+        JsValue::from_json(value, &mut self.ctx)
+    }
+
     pub fn call_function(
         &mut self,
         function: &JsFunction,
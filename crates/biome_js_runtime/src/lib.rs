@@ -2,7 +2,10 @@ mod ast;
 mod context;
 mod module_loader;
 mod plugin_api;
+mod semantic;
 mod source;
 
 pub use context::{JsExecContext, JsPluginRule};
 pub use module_loader::JsModuleLoader;
+pub use plugin_api::{JsPluginDiagnostic, JsPluginFix, JsPluginTextEdit};
+pub use semantic::JsAnalyzedFile;
@@ -8,18 +8,55 @@ use boa_engine::property::Attribute;
 use boa_engine::{Context, JsNativeError, JsResult, JsValue, Module, NativeFunction, js_string};
 
 use biome_analyze::RuleDiagnostic;
-use biome_diagnostics::{Severity, category};
+use biome_diagnostics::{Applicability, Severity, category};
+use biome_text_size::{TextRange, TextSize};
 
 use crate::ast::JsAstNode;
+use crate::semantic::{JsAnalyzedFile, JsSemanticApi};
+
+/// A diagnostic registered by a plugin with `registerDiagnostic()`.
+#[derive(Debug)]
+pub struct JsPluginDiagnostic {
+    pub diagnostic: RuleDiagnostic,
+
+    /// The fix passed as the last argument of `registerDiagnostic()`, if any.
+    pub fix: Option<JsPluginFix>,
+}
+
+/// A code fix registered by a plugin along with a diagnostic.
+///
+/// The edits are only checked for their shape here: whether they fit in the
+/// analyzed file and don't overlap is validated by the caller, which has
+/// access to the whole file.
+#[derive(Debug)]
+pub struct JsPluginFix {
+    /// The message describing the fix, if the plugin provided one.
+    pub message: Option<String>,
+
+    /// Whether the fix is safe or unsafe. Fixes are unsafe unless the plugin
+    /// declares them as safe.
+    pub applicability: Applicability,
+
+    pub edits: Vec<JsPluginTextEdit>,
+}
+
+/// Replaces the text in `range` with `text`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct JsPluginTextEdit {
+    pub range: TextRange,
+    pub text: String,
+}
 
 pub(crate) struct JsPluginApi {
-    diagnostics: Rc<RefCell<Vec<RuleDiagnostic>>>,
+    diagnostics: Rc<RefCell<Vec<JsPluginDiagnostic>>>,
+    semantic: Rc<RefCell<JsSemanticApi>>,
 }
 
 impl JsPluginApi {
     pub(crate) fn new() -> Self {
         Self {
             diagnostics: Rc::new(RefCell::new(Vec::new())),
+            semantic: Rc::new(RefCell::new(JsSemanticApi::default())),
         }
     }
 
@@ -29,12 +66,16 @@ impl JsPluginApi {
         // SAFETY: The closure doesn't capture any GC-managed values.
         let register_diagnostic = FunctionObjectBuilder::new(context.realm(), unsafe {
             NativeFunction::from_closure(move |_this, args, context| {
-                let [node, severity, message] = args else {
-                    return Err(JsNativeError::typ()
-                        .with_message(
-                            "registerDiagnostic() expects an AST node, severity, and message",
-                        )
-                        .into());
+                let (node, severity, message, fix) = match args {
+                    [node, severity, message] => (node, severity, message, None),
+                    [node, severity, message, fix] => (node, severity, message, Some(fix)),
+                    _ => {
+                        return Err(JsNativeError::typ()
+                            .with_message(
+                                "registerDiagnostic() expects an AST node, severity, message, and an optional fix",
+                            )
+                            .into());
+                    }
                 };
 
                 let Some(range) = JsAstNode::text_range(node) else {
@@ -66,7 +107,14 @@ impl JsPluginApi {
                 )
                 .with_severity(severity);
 
-                diagnostics.borrow_mut().push(diagnostic);
+                let fix = match fix {
+                    Some(fix) => Self::parse_fix(fix, context)?,
+                    None => None,
+                };
+
+                diagnostics
+                    .borrow_mut()
+                    .push(JsPluginDiagnostic { diagnostic, fix });
 
                 Ok(JsValue::undefined())
             })
@@ -91,24 +139,71 @@ impl JsPluginApi {
         .name("defineRule")
         .build();
 
-        // TODO: more runtime APIs?
+        let semantic = self.semantic.clone();
+        // SAFETY: The closure doesn't capture any GC-managed values.
+        let get_binding = FunctionObjectBuilder::new(context.realm(), unsafe {
+            NativeFunction::from_closure(move |_this, args, context| {
+                semantic.borrow_mut().get_binding(args, context)
+            })
+        })
+        .length(1)
+        .name("getBinding")
+        .build();
+
+        let semantic = self.semantic.clone();
+        // SAFETY: The closure doesn't capture any GC-managed values.
+        let get_references = FunctionObjectBuilder::new(context.realm(), unsafe {
+            NativeFunction::from_closure(move |_this, args, context| {
+                semantic.borrow_mut().get_references(args, context)
+            })
+        })
+        .length(1)
+        .name("getReferences")
+        .build();
+
+        let semantic = self.semantic.clone();
+        // SAFETY: The closure doesn't capture any GC-managed values.
+        let get_type = FunctionObjectBuilder::new(context.realm(), unsafe {
+            NativeFunction::from_closure(move |_this, args, _context| {
+                semantic.borrow().get_type(args)
+            })
+        })
+        .length(1)
+        .name("getType")
+        .build();
 
         Module::synthetic(
             &[
                 js_string!("registerDiagnostic"),
                 js_string!("ast"),
                 js_string!("defineRule"),
+                js_string!("getBinding"),
+                js_string!("getReferences"),
+                js_string!("getType"),
             ],
             SyntheticModuleInitializer::from_copy_closure_with_captures(
-                |module, (register_diagnostic, ast, define_rule), _| {
+                |module,
+                 (register_diagnostic, ast, define_rule, get_binding, get_references, get_type),
+                 _| {
                     module.set_export(
                         &js_string!("registerDiagnostic"),
                         register_diagnostic.clone().into(),
                     )?;
                     module.set_export(&js_string!("ast"), ast.clone().into())?;
-                    module.set_export(&js_string!("defineRule"), define_rule.clone().into())
+                    module.set_export(&js_string!("defineRule"), define_rule.clone().into())?;
+                    module.set_export(&js_string!("getBinding"), get_binding.clone().into())?;
+                    module
+                        .set_export(&js_string!("getReferences"), get_references.clone().into())?;
+                    module.set_export(&js_string!("getType"), get_type.clone().into())
                 },
-                (register_diagnostic, ast, define_rule),
+                (
+                    register_diagnostic,
+                    ast,
+                    define_rule,
+                    get_binding,
+                    get_references,
+                    get_type,
+                ),
             ),
             None,
             None,
@@ -191,7 +286,149 @@ impl JsPluginApi {
         Ok(rule.clone())
     }
 
-    pub(crate) fn pull_diagnostics(&self) -> Vec<RuleDiagnostic> {
+    /// Reads the `fix` argument of `registerDiagnostic()`. `undefined` and
+    /// `null` mean the diagnostic has no fix.
+    fn parse_fix(fix: &JsValue, context: &mut Context) -> JsResult<Option<JsPluginFix>> {
+        if fix.is_null_or_undefined() {
+            return Ok(None);
+        }
+
+        let Some(fix) = fix.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("registerDiagnostic() expects the fix to be an object")
+                .into());
+        };
+
+        let message = fix.get(js_string!("message"), context)?;
+        let message = if message.is_undefined() {
+            None
+        } else {
+            let Some(message) = message.as_string() else {
+                return Err(JsNativeError::typ()
+                    .with_message("The message of a fix must be a string")
+                    .into());
+            };
+            Some(message.to_std_string_lossy())
+        };
+
+        let kind = fix.get(js_string!("kind"), context)?;
+        let applicability = if kind.is_undefined() {
+            Applicability::MaybeIncorrect
+        } else {
+            match kind
+                .as_string()
+                .map(|kind| kind.to_std_string_lossy())
+                .as_deref()
+            {
+                Some("safe") => Applicability::Always,
+                Some("unsafe") => Applicability::MaybeIncorrect,
+                _ => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Unexpected fix kind, expected one of: safe, unsafe")
+                        .into());
+                }
+            }
+        };
+
+        let Some(edits) = fix
+            .get(js_string!("edits"), context)?
+            .as_object()
+            .and_then(|edits| JsArray::from_object(edits).ok())
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("A fix expects an array of edits")
+                .into());
+        };
+
+        let length = edits.length(context)?;
+        if length == 0 {
+            return Err(JsNativeError::typ()
+                .with_message("A fix expects at least one edit")
+                .into());
+        }
+
+        let mut text_edits = Vec::with_capacity(length as usize);
+        for index in 0..length {
+            let edit = edits.at(index as i64, context)?;
+            text_edits.push(Self::parse_edit(&edit, context)?);
+        }
+
+        Ok(Some(JsPluginFix {
+            message,
+            applicability,
+            edits: text_edits,
+        }))
+    }
+
+    /// Reads an edit of a fix, either a node replacement `{ node, text }` or a
+    /// text edit `{ range: [start, end], text }`.
+    fn parse_edit(edit: &JsValue, context: &mut Context) -> JsResult<JsPluginTextEdit> {
+        let Some(edit) = edit.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("An edit must be an object")
+                .into());
+        };
+
+        let Some(text) = edit.get(js_string!("text"), context)?.as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("An edit expects the replacement text as a string")
+                .into());
+        };
+
+        let node = edit.get(js_string!("node"), context)?;
+        let range = if node.is_undefined() {
+            let range = edit.get(js_string!("range"), context)?;
+            let Some(range) = range
+                .as_object()
+                .and_then(|range| JsArray::from_object(range).ok())
+            else {
+                return Err(JsNativeError::typ()
+                    .with_message("An edit expects either an AST node or a range")
+                    .into());
+            };
+
+            let start = Self::parse_offset(&range.at(0, context)?)?;
+            let end = Self::parse_offset(&range.at(1, context)?)?;
+            if start > end {
+                return Err(JsNativeError::range()
+                    .with_message("The start of the range of an edit is after its end")
+                    .into());
+            }
+
+            TextRange::new(start, end)
+        } else {
+            JsAstNode::text_range(&node).ok_or_else(|| {
+                JsNativeError::typ().with_message("The node of an edit must be an AST node")
+            })?
+        };
+
+        Ok(JsPluginTextEdit {
+            range,
+            text: text.to_std_string_lossy(),
+        })
+    }
+
+    fn parse_offset(offset: &JsValue) -> JsResult<TextSize> {
+        offset
+            .as_number()
+            .filter(|offset| offset.fract() == 0.0 && (0.0..=f64::from(u32::MAX)).contains(offset))
+            .map(|offset| TextSize::from(offset as u32))
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("The offsets of a range must be non-negative integers")
+                    .into()
+            })
+    }
+
+    pub(crate) fn pull_diagnostics(&self) -> Vec<JsPluginDiagnostic> {
         std::mem::take(&mut self.diagnostics.borrow_mut())
     }
+
+    pub(crate) fn set_analyzed_file(&self, file: JsAnalyzedFile) {
+        self.semantic.borrow_mut().set_file(file);
+    }
+
+    pub(crate) fn clear_analyzed_file(&self) {
+        self.semantic.borrow_mut().clear_file();
+    }
 }
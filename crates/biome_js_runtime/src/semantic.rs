@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use boa_engine::object::ObjectInitializer;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::property::Attribute;
+use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, JsValue, js_string};
+use camino::Utf8PathBuf;
+
+use biome_js_semantic::{SemanticModel, SemanticModelOptions, semantic_model};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsIdentifierBinding, AnyJsIdentifierReference, AnyJsRoot,
+    JsIdentifierExpression, JsSyntaxNode,
+};
+use biome_js_type_info::format_inferred_type;
+use biome_languages::JsFileSource;
+use biome_module_graph::ModuleDb;
+use biome_module_graph::type_inference::{
+    NormalizedExpressionTypeRequest, TypeInferenceCaller, execute_type_inference_request,
+};
+use biome_rowan::AstNode;
+
+use crate::ast::JsAstNode;
+
+/// The file analyzed by the plugin rules, which gives them access to the
+/// bindings and the types of its nodes.
+pub struct JsAnalyzedFile {
+    root: AnyJsRoot,
+    source_type: JsFileSource,
+    path: Utf8PathBuf,
+
+    /// The module graph of the project. Types can't be inferred without it.
+    module_db: Option<Rc<dyn ModuleDb>>,
+}
+
+impl JsAnalyzedFile {
+    pub fn new(root: AnyJsRoot, source_type: JsFileSource, path: Utf8PathBuf) -> Self {
+        Self {
+            root,
+            source_type,
+            path,
+            module_db: None,
+        }
+    }
+
+    pub fn with_module_db(mut self, module_db: Rc<dyn ModuleDb>) -> Self {
+        self.module_db = Some(module_db);
+        self
+    }
+}
+
+/// Implements the functions of the plugin API querying the semantic model and
+/// the types of the analyzed file.
+#[derive(Default)]
+pub(crate) struct JsSemanticApi {
+    file: Option<JsAnalyzedFile>,
+
+    /// The semantic model of the last analyzed file. It's built by the first
+    /// query, and reused by the following rule invocations on the same file.
+    model: Option<SemanticModel>,
+}
+
+impl JsSemanticApi {
+    pub(crate) fn set_file(&mut self, file: JsAnalyzedFile) {
+        if self
+            .model
+            .as_ref()
+            .is_some_and(|model| model.root() != file.root)
+        {
+            self.model = None;
+        }
+
+        self.file = Some(file);
+    }
+
+    /// Releases the analyzed file, and the module graph with it. The semantic
+    /// model is kept, in case the next rule invocation is on the same file.
+    pub(crate) fn clear_file(&mut self) {
+        self.file = None;
+    }
+
+    /// Implements `getBinding(node)`: resolves an identifier to the binding
+    /// declaring it.
+    pub(crate) fn get_binding(
+        &mut self,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let node = node_argument("getBinding", args)?;
+        let model = self.model("getBinding")?;
+
+        let binding = if AnyJsIdentifierBinding::can_cast(node.kind()) {
+            model.as_binding_by_range(node.text_trimmed_range())
+        } else {
+            let reference = match JsIdentifierExpression::cast_ref(&node) {
+                Some(expression) => expression.name().ok().map(AnyJsIdentifierReference::from),
+                None => AnyJsIdentifierReference::cast(node),
+            };
+            let Some(reference) = reference else {
+                return Err(JsNativeError::typ()
+                    .with_message("getBinding() expects an identifier")
+                    .into());
+            };
+
+            model.binding(&reference)
+        };
+
+        Ok(binding.map_or_else(JsValue::undefined, |binding| {
+            JsAstNode::from_node(binding.syntax(), context)
+        }))
+    }
+
+    /// Implements `getReferences(binding)`: lists the references to a binding.
+    pub(crate) fn get_references(
+        &mut self,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let node = node_argument("getReferences", args)?;
+        if !AnyJsIdentifierBinding::can_cast(node.kind()) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "getReferences() expects a binding, like the ones returned by getBinding()",
+                )
+                .into());
+        }
+
+        let model = self.model("getReferences")?;
+        let references: Vec<_> = model
+            .as_binding_by_range(node.text_trimmed_range())
+            .into_iter()
+            .flat_map(|binding| binding.all_references())
+            .collect();
+
+        let references: Vec<_> = references
+            .into_iter()
+            .map(|reference| {
+                let kind = if reference.is_write() {
+                    js_string!("write")
+                } else {
+                    js_string!("read")
+                };
+                let node = JsAstNode::from_node(reference.syntax(), context);
+
+                ObjectInitializer::new(context)
+                    .property(js_string!("node"), node, Attribute::ENUMERABLE)
+                    .property(js_string!("kind"), kind, Attribute::ENUMERABLE)
+                    .build()
+                    .into()
+            })
+            .collect();
+
+        Ok(JsArray::from_iter(references, context).into())
+    }
+
+    /// Implements `getType(expression)`: formats the type inferred for an
+    /// expression. Returns `undefined` when the module graph isn't available, which
+    /// only happens when the project wasn't scanned with types.
+    pub(crate) fn get_type(&self, args: &[JsValue]) -> JsResult<JsValue> {
+        let node = node_argument("getType", args)?;
+        let Some(expression) = AnyJsExpression::cast(node) else {
+            return Err(JsNativeError::typ()
+                .with_message("getType() expects an expression")
+                .into());
+        };
+
+        let file = self.file("getType")?;
+        let Some(db) = file.module_db.as_deref() else {
+            return Ok(JsValue::undefined());
+        };
+        let Some(module) = db.module_for_path(&file.path) else {
+            return Ok(JsValue::undefined());
+        };
+
+        let ty = execute_type_inference_request(
+            db,
+            TypeInferenceCaller::new("plugin", "getType"),
+            NormalizedExpressionTypeRequest::new(module, expression.range()),
+        );
+
+        Ok(ty.map_or_else(JsValue::undefined, |ty| {
+            JsString::from(format_inferred_type(db, ty)).into()
+        }))
+    }
+
+    fn file(&self, function: &str) -> JsResult<&JsAnalyzedFile> {
+        self.file
+            .as_ref()
+            .ok_or_else(|| outside_of_rule_error(function))
+    }
+
+    fn model(&mut self, function: &str) -> JsResult<&SemanticModel> {
+        let Some(file) = self.file.as_ref() else {
+            return Err(outside_of_rule_error(function));
+        };
+
+        Ok(self.model.get_or_insert_with(|| {
+            semantic_model(&file.root, SemanticModelOptions::from(&file.source_type))
+        }))
+    }
+}
+
+fn outside_of_rule_error(function: &str) -> JsError {
+    JsNativeError::error()
+        .with_message(format!("{function}() can only be called while a rule runs"))
+        .into()
+}
+
+fn node_argument(function: &str, args: &[JsValue]) -> JsResult<JsSyntaxNode> {
+    args.first().and_then(JsAstNode::to_syntax).ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!("{function}() expects an AST node"))
+            .into()
+    })
+}
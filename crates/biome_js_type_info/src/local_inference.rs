@@ -25,10 +25,10 @@ use biome_js_syntax::{
     JsReferenceIdentifier, JsRestParameter, JsReturnStatement, JsSetterObjectMember, JsSyntaxKind,
     JsSyntaxNode, JsSyntaxToken, JsUnaryExpression, JsUnaryOperator, JsVariableDeclaration,
     JsVariableDeclarator, TsDeclareFunctionDeclaration, TsExternalModuleDeclaration,
-    TsInstantiationExpression, TsInterfaceDeclaration, TsModuleDeclaration,
+    TsInstantiationExpression, TsInterfaceDeclaration, TsMappedType, TsModuleDeclaration,
     TsPropertyParameterModifierList, TsReferenceType, TsReturnTypeAnnotation,
     TsTypeAliasDeclaration, TsTypeAnnotation, TsTypeArguments, TsTypeList, TsTypeParameter,
-    TsTypeParameters, TsTypeofType, inner_string_text, unescape_js_string,
+    TsTypeParameterName, TsTypeParameters, TsTypeofType, inner_string_text, unescape_js_string,
 };
 use biome_rowan::{AstNode, SyntaxResult, Text, TextRange, TokenText};
 
@@ -757,10 +757,13 @@ impl TypeData {
                 // TODO: Handle import types (`import("./module").T`).
                 Self::unknown()
             }
-            AnyTsType::TsIndexedAccessType(_) => {
-                // TODO: Handle type indexing (`T[U]`).
-                Self::unknown()
-            }
+            AnyTsType::TsIndexedAccessType(ty) => match (ty.object_type(), ty.index_type()) {
+                (Ok(object_type), Ok(index_type)) => {
+                    let object = TypeReference::from_any_ts_type(collector, scope_id, &object_type);
+                    Self::from_indexed_access(collector, object, &index_type)
+                }
+                _ => Self::unknown(),
+            },
             AnyTsType::TsInferType(_) => {
                 // TODO: Handle `infer T` syntax.
                 Self::unknown()
@@ -772,10 +775,7 @@ impl TypeData {
                     .map(|ty| TypeReference::from_any_ts_type(collector, scope_id, &ty))
                     .collect(),
             ))),
-            AnyTsType::TsMappedType(_) => {
-                // TODO: Handle mapped types (`type T<U> = { [K in keyof U]: V }`).
-                Self::unknown()
-            }
+            AnyTsType::TsMappedType(ty) => Self::from_ts_mapped_type(collector, scope_id, ty),
             AnyTsType::TsNeverType(_) => Self::NeverKeyword,
             AnyTsType::TsNonPrimitiveType(_) => Self::ObjectKeyword,
             AnyTsType::TsNullLiteralType(_) => Self::Null,
@@ -1339,6 +1339,128 @@ impl TypeData {
         Some(namespace.into())
     }
 
+    /// Creates the type of indexing the `object` type with the given
+    /// `index_type`, such as `T["key"]`.
+    ///
+    /// We support string literal indices for looking up members, number
+    /// literal indices for looking up tuple elements, `number` for looking up
+    /// array elements, and unions of these.
+    fn from_indexed_access(
+        collector: &mut dyn RawTypeCollector,
+        object: TypeReference,
+        index_type: &AnyTsType,
+    ) -> Self {
+        match index_type {
+            AnyTsType::TsNumberLiteralType(index) if index.minus_token().is_none() => index
+                .literal_token()
+                .ok()
+                .and_then(|token| token.text_trimmed().parse().ok())
+                .map_or_else(Self::unknown, |index| {
+                    Self::from(TypeofExpression::Index(TypeofIndexExpression {
+                        object,
+                        index,
+                    }))
+                }),
+            AnyTsType::TsNumberType(_) => Self::from(TypeofExpression::IterableValueOf(
+                TypeofIterableValueOfExpression { ty: object },
+            )),
+            AnyTsType::TsParenthesizedType(index) => match index.ty() {
+                Ok(index_type) => Self::from_indexed_access(collector, object, &index_type),
+                Err(_) => Self::unknown(),
+            },
+            AnyTsType::TsStringLiteralType(index) => match index.inner_string_text() {
+                Ok(member) => Self::from(TypeofExpression::StaticMember(
+                    TypeofStaticMemberExpression {
+                        object,
+                        member: member.into(),
+                    },
+                )),
+                Err(_) => Self::unknown(),
+            },
+            AnyTsType::TsUnionType(index) => Self::Union(Box::new(Union(
+                index
+                    .types()
+                    .into_iter()
+                    .flatten()
+                    .map(|index_type| {
+                        let data =
+                            Self::from_indexed_access(collector, object.clone(), &index_type);
+                        collector.reference_to_owned_data(data)
+                    })
+                    .collect(),
+            ))),
+            _ => Self::unknown(),
+        }
+    }
+
+    /// Creates the type for a mapped type, such as
+    /// `{ [K in keyof T]: Promise<T[K]> }`.
+    ///
+    /// Mapped types that map every key of `T` onto `T[K]` are inferred as `T`
+    /// itself. When the keys are string literals, we create an object with a
+    /// member for each of them. Otherwise, we don't know the keys, so we
+    /// create an object with a string index signature instead.
+    ///
+    /// We don't substitute the key in the value type, so value types that
+    /// depend on the key are mostly unknown.
+    pub fn from_ts_mapped_type(
+        collector: &mut dyn RawTypeCollector,
+        scope_id: ScopeId,
+        ty: &TsMappedType,
+    ) -> Self {
+        let (Ok(property_name), Ok(keys_type)) = (ty.property_name(), ty.keys_type()) else {
+            return Self::unknown();
+        };
+        let value_type = ty.mapped_type().and_then(|annotation| annotation.ty().ok());
+
+        if ty.as_clause().is_none()
+            && ty.optional_modifier().is_none()
+            && let Some(target) =
+                homomorphic_mapped_type_target(&property_name, &keys_type, value_type.as_ref())
+        {
+            return Self::from_any_ts_type(collector, scope_id, &target);
+        }
+
+        let value = value_type
+            .map(|ty| TypeReference::from_any_ts_type(collector, scope_id, &ty))
+            .unwrap_or_default();
+        let is_optional = ty.optional_modifier().is_some_and(|modifier| {
+            modifier
+                .operator_token()
+                .is_none_or(|token| token.text_trimmed() != "-")
+        });
+
+        let keys = if ty.as_clause().is_none() {
+            string_literal_keys_from_ts_type(&keys_type)
+        } else {
+            None
+        };
+        let members = match keys {
+            Some(keys) => {
+                let value = match is_optional {
+                    true => RawTypeId::Local(collector.optional(value)).into(),
+                    false => value,
+                };
+                keys.into_iter()
+                    .map(|key| TypeMember {
+                        kind: if is_optional {
+                            TypeMemberKind::NamedOptional(key)
+                        } else {
+                            TypeMemberKind::Named(key)
+                        },
+                        ty: value.clone(),
+                    })
+                    .collect()
+            }
+            None => Box::new([TypeMember {
+                kind: TypeMemberKind::IndexSignature(GLOBAL_STRING_ID.into()),
+                ty: value,
+            }]),
+        };
+
+        Self::object_with_members(members)
+    }
+
     pub fn from_ts_reference_type(
         collector: &mut dyn RawTypeCollector,
         scope_id: ScopeId,
@@ -2618,6 +2740,43 @@ impl TypeReferenceQualifier {
     }
 }
 
+/// Returns `T` if the mapped type with the given `property_name`, `keys_type`
+/// and `value_type` has the shape `{ [K in keyof T]: T[K] }`.
+fn homomorphic_mapped_type_target(
+    property_name: &TsTypeParameterName,
+    keys_type: &AnyTsType,
+    value_type: Option<&AnyTsType>,
+) -> Option<AnyTsType> {
+    let AnyTsType::TsTypeOperatorType(keys_type) = keys_type else {
+        return None;
+    };
+    if keys_type.operator_token().ok()?.text_trimmed() != "keyof" {
+        return None;
+    }
+    let target = keys_type.ty().ok()?;
+
+    let AnyTsType::TsIndexedAccessType(value_type) = value_type? else {
+        return None;
+    };
+    let AnyTsType::TsReferenceType(index_type) = value_type.index_type().ok()? else {
+        return None;
+    };
+    let key_name = property_name.ident_token().ok()?;
+    let is_key = index_type.type_arguments().is_none()
+        && index_type
+            .name()
+            .ok()?
+            .as_js_reference_identifier()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            == key_name.text_trimmed();
+    let is_target =
+        value_type.object_type().ok()?.syntax().text_trimmed() == target.syntax().text_trimmed();
+
+    (is_key && is_target).then_some(target)
+}
+
 fn is_direct_class_or_object_member(node: &JsSyntaxNode) -> bool {
     node.ancestors()
         .skip(1)
@@ -2945,6 +3104,22 @@ fn split_regex_literal(token: SyntaxResult<JsSyntaxToken>) -> Option<RegexpLiter
     })
 }
 
+/// Returns the keys of a type that consists of string literals only, such as
+/// `"a" | "b"`.
+fn string_literal_keys_from_ts_type(ty: &AnyTsType) -> Option<Box<[Text]>> {
+    match ty {
+        AnyTsType::TsParenthesizedType(ty) => string_literal_keys_from_ts_type(&ty.ty().ok()?),
+        AnyTsType::TsStringLiteralType(ty) => Some(Box::new([ty.inner_string_text().ok()?.into()])),
+        AnyTsType::TsUnionType(ty) => ty
+            .types()
+            .into_iter()
+            .map(|ty| string_literal_keys_from_ts_type(&ty.ok()?))
+            .collect::<Option<Vec<_>>>()
+            .map(|keys| keys.into_iter().flatten().collect()),
+        _ => None,
+    }
+}
+
 #[inline]
 fn text_from_any_js_name(name: AnyJsName) -> Option<Text> {
     match name {
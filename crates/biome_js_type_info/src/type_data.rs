@@ -1709,6 +1709,39 @@ impl TypeReferenceQualifier {
         self.path.is_identifier("Readonly")
     }
 
+    /// Checks whether this type qualifier references an `Awaited` type.
+    ///
+    /// This method simply checks whether the reference is for a literal
+    /// `Awaited`, without considering whether another symbol named `Awaited` is
+    /// in scope. It can be used _after_ type resolution has failed to find an
+    /// `Awaited` symbol in scope, but should not be used _instead of_ such type
+    /// resolution.
+    pub fn is_awaited(&self) -> bool {
+        self.path.is_identifier("Awaited")
+    }
+
+    /// Checks whether this type qualifier references a `ReturnType` type.
+    ///
+    /// This method simply checks whether the reference is for a literal
+    /// `ReturnType`, without considering whether another symbol named
+    /// `ReturnType` is in scope. It can be used _after_ type resolution has
+    /// failed to find a `ReturnType` symbol in scope, but should not be used
+    /// _instead of_ such type resolution.
+    pub fn is_return_type(&self) -> bool {
+        self.path.is_identifier("ReturnType")
+    }
+
+    /// Checks whether this type qualifier references a `NonNullable` type.
+    ///
+    /// This method simply checks whether the reference is for a literal
+    /// `NonNullable`, without considering whether another symbol named
+    /// `NonNullable` is in scope. It can be used _after_ type resolution has
+    /// failed to find a `NonNullable` symbol in scope, but should not be used
+    /// _instead of_ such type resolution.
+    pub fn is_non_nullable(&self) -> bool {
+        self.path.is_identifier("NonNullable")
+    }
+
     /// Checks whether this type qualifier references the `RegExp` type.
     ///
     /// This method simply checks whether the reference is for a literal
@@ -65,6 +65,7 @@ fn bench_analyzer(criterion: &mut Criterion) {
                                 file_source,
                                 configuration_provider: None,
                                 project_layout: None,
+                                module_db: None,
                             };
 
                             biome_json_analyze::analyze(
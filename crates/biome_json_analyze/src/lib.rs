@@ -20,9 +20,11 @@ use biome_analyze::{
 use biome_diagnostics::Error;
 use biome_json_syntax::{JsonLanguage, TextRange};
 use biome_languages::JsonFileSource;
+use biome_module_graph::ModuleDb;
 use biome_project_layout::ProjectLayout;
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
+use std::rc::Rc;
 use std::sync::{Arc, LazyLock};
 
 pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
@@ -42,6 +44,9 @@ pub struct JsonAnalyzeServices {
 
     /// The project layout, providing access to package manifests.
     pub project_layout: Option<Arc<ProjectLayout>>,
+
+    /// The module database, only available when the project has been scanned.
+    pub module_db: Option<Rc<dyn ModuleDb>>,
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -156,6 +161,9 @@ where
     services.insert_service(json_services.configuration_provider);
     services.insert_service(json_services.file_source);
     services.insert_service(json_services.project_layout);
+    if let Some(module_db) = json_services.module_db {
+        services.insert_service(module_db);
+    }
 
     (
         analyzer.run(biome_analyze::AnalyzerContext {
@@ -210,6 +218,7 @@ mod tests {
             file_source: JsonFileSource::json(),
             configuration_provider: None,
             project_layout: None,
+            module_db: None,
         };
         analyze(
             &parsed.tree(),
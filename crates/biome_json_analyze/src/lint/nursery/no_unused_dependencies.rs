@@ -0,0 +1,149 @@
+use crate::services::module_graph::JsonModuleGraph;
+use crate::utils::is_package_json;
+use biome_analyze::{Rule, RuleDiagnostic, RuleDomain, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_json_syntax::JsonMember;
+use biome_module_graph::{ModuleGraphGeneration, is_js_module_reachable, js_imported_packages};
+use biome_project_layout::ProjectLayout;
+use biome_rowan::AstNode;
+use biome_rule_options::no_unused_dependencies::NoUnusedDependenciesOptions;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow dependencies that aren't imported by any module of the package.
+    ///
+    /// A dependency that is never imported only makes installs slower and
+    /// bundles bigger. This rule reports the entries of the `dependencies`
+    /// field of a `package.json` that aren't imported by any JavaScript or
+    /// TypeScript module of the package. The modules of a nested package, with
+    /// its own `package.json`, belong to that package. Static imports, dynamic
+    /// imports, `require()` calls and type-only imports are taken into account.
+    ///
+    /// When the package declares entry points, with the `main`, `exports` or
+    /// `bin` fields, only the modules reachable from them are taken into
+    /// account, since the imports of the others are unused as well.
+    ///
+    /// Packages that only provide types, such as `@types/node`, are ignored.
+    /// The `devDependencies` and `peerDependencies` fields aren't checked,
+    /// because they usually contain tools that are run from the command line
+    /// rather than imported.
+    ///
+    /// :::note
+    /// This rule requires the whole project to be scanned, so it's
+    /// computationally expensive.
+    /// :::
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "dependencies": {
+    ///         "lodash": "^4.17.21"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// export const double = (value) => value * 2;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///     "dependencies": {
+    ///         "lodash": "^4.17.21"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,file=index.js
+    /// import { sum } from "lodash";
+    ///
+    /// export const total = sum([1, 2]);
+    /// ```
+    ///
+    pub NoUnusedDependencies {
+        version: "next",
+        name: "noUnusedDependencies",
+        language: "json",
+        severity: Severity::Warning,
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoUnusedDependencies {
+    type Query = JsonModuleGraph<JsonMember>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = NoUnusedDependenciesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let path = ctx.file_path();
+        if !is_package_json(path) {
+            return None;
+        }
+
+        let node = ctx.query();
+        let parent_member = node
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(JsonMember::cast)?;
+        let parent_member_name = parent_member.name().ok()?.inner_string_text()?;
+        if parent_member_name.text() != "dependencies"
+            || parent_member
+                .syntax()
+                .ancestors()
+                .skip(1)
+                .any(|ancestor| JsonMember::can_cast(ancestor.kind()))
+        {
+            return None;
+        }
+
+        let name = node.name().ok()?.inner_string_text()?;
+        if name.text().starts_with("@types/") {
+            return None;
+        }
+
+        let db = ctx.db();
+        let package_path = path.parent()?;
+        let project_layout = ctx
+            .get_service::<Option<Arc<ProjectLayout>>>()
+            .and_then(Option::as_deref);
+        let imported_packages = js_imported_packages(db, ModuleGraphGeneration::get(db));
+        let is_imported = imported_packages.is_imported_from(
+            name.text(),
+            package_path,
+            |path| {
+                project_layout
+                    .and_then(|layout| layout.find_node_manifest_for_path(path))
+                    .map(|(package_path, _)| package_path)
+            },
+            |path| project_layout.is_none_or(|layout| is_js_module_reachable(db, layout, path)),
+        );
+        (!is_imported).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        let name_text = name.inner_string_text()?;
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The dependency "<Emphasis>{name_text.text()}</Emphasis>" isn't imported by any module of the package."
+                },
+            )
+            .note(markup! {
+                "Remove the dependency, or move it to "<Emphasis>"devDependencies"</Emphasis>" if it's only used by tools."
+            }),
+        )
+    }
+}
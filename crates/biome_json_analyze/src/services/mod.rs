@@ -1,2 +1,3 @@
 pub(crate) mod config_source;
+pub(crate) mod module_graph;
 pub(crate) mod project_layout;
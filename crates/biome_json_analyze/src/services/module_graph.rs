@@ -0,0 +1,87 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleDomain, RuleKey,
+    RuleMetadata, ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_module_graph::ModuleDb;
+use biome_rowan::AstNode;
+use std::rc::Rc;
+
+/// Service providing access to the module database for JSON lint rules.
+///
+/// Only available for rules in the [`RuleDomain::Project`] domain.
+#[derive(Clone)]
+pub struct JsonDbService(Rc<dyn ModuleDb>);
+
+impl std::fmt::Debug for JsonDbService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonDbService").finish_non_exhaustive()
+    }
+}
+
+impl JsonDbService {
+    pub fn db(&self) -> &dyn ModuleDb {
+        self.0.as_ref()
+    }
+}
+
+impl FromServices for JsonDbService {
+    fn from_services(
+        rule_key: &RuleKey,
+        rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        if cfg!(debug_assertions) {
+            let has_project_domain = rule_metadata
+                .domains
+                .iter()
+                .any(|d| d == &RuleDomain::Project);
+            if !has_project_domain {
+                panic!(
+                    "The rule {rule_key} uses JsonDbService, but it is not in the Project domain."
+                );
+            }
+        }
+
+        let module_db: &Rc<dyn ModuleDb> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["ModuleDb"]))?;
+
+        Ok(Self(module_db.clone()))
+    }
+}
+
+impl Phase for JsonDbService {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type for JSON lint rules that require access to the [`JsonDbService`].
+///
+/// Use `type Query = JsonModuleGraph<JsonMember>` to query AST nodes while
+/// also having access to the module database via `ctx.db()`.
+#[derive(Clone)]
+pub struct JsonModuleGraph<N>(pub N);
+
+impl<N> Queryable for JsonModuleGraph<N>
+where
+    N: AstNode<Language = JsonLanguage> + 'static,
+{
+    type Input = JsonSyntaxNode;
+    type Output = N;
+    type Language = JsonLanguage;
+    type Services = JsonDbService;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsonLanguage>, _root: &JsonRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -107,6 +107,7 @@ fn apply_organize_action(input_code: &str) -> Result<String, String> {
         file_source: JsonFileSource::json(),
         configuration_provider: None,
         project_layout: None,
+        module_db: None,
     };
 
     let mut result_code = None;
@@ -1,4 +1,7 @@
-use biome_analyze::{ActionFilter, AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_analyze::{
+    ActionFilter, AnalysisFilter, AnalyzerAction, ControlFlow, Never, Queryable, RegistryVisitor,
+    Rule, RuleDomain, RuleFilter, RuleGroup,
+};
 use biome_configuration::{ConfigurationSource, ExtendedConfigurations};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_json_analyze::{ExtendedConfigurationProvider, JsonAnalyzeServices};
@@ -11,8 +14,8 @@ use biome_rowan::AstNode;
 use biome_test_utils::{
     CheckActionType, assert_diagnostics_expectation_comment, assert_errors_are_absent,
     code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_configuration_source, parse_test_path,
-    register_leak_checker, write_analyzer_snapshot,
+    has_bogus_nodes_or_empty_slots, load_configuration_source, module_graph_for_test_file,
+    parse_test_path, register_leak_checker, write_analyzer_snapshot,
 };
 use camino::{Utf8Path, Utf8PathBuf};
 use std::ops::Deref;
@@ -22,6 +25,44 @@ use std::{fs::read_to_string, slice};
 tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{json,jsonc}", crate::run_suppression_test, "module"}
 
+/// Checks if any of the enabled rules is in the project domain and requires the module graph.
+struct NeedsModuleGraph<'a> {
+    enabled_rules: Option<&'a [RuleFilter<'a>]>,
+    needs_module_graph: bool,
+}
+
+impl<'a> NeedsModuleGraph<'a> {
+    fn new(enabled_rules: Option<&'a [RuleFilter<'a>]>) -> Self {
+        Self {
+            enabled_rules,
+            needs_module_graph: false,
+        }
+    }
+
+    fn compute(mut self) -> bool {
+        biome_json_analyze::visit_registry(&mut self);
+        self.needs_module_graph
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for NeedsModuleGraph<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = JsonLanguage, Output: Clone>>
+            + 'static,
+    {
+        let filter = RuleFilter::Rule(<R::Group as RuleGroup>::NAME, R::METADATA.name);
+
+        if self
+            .enabled_rules
+            .is_some_and(|enabled_rules| enabled_rules.contains(&filter))
+            && R::METADATA.domains.contains(&RuleDomain::Project)
+        {
+            self.needs_module_graph = true;
+        }
+    }
+}
+
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
 
@@ -208,6 +249,12 @@ pub(crate) fn analyze_and_snap(
     let options =
         create_analyzer_options::<JsonLanguage>(input_file, working_directory, &mut diagnostics);
     let project_layout = project_layout_for_json_test(input_file);
+    let module_db = NeedsModuleGraph::new(filter.enabled_rules)
+        .compute()
+        .then(|| {
+            let project_layout = project_layout.clone().unwrap_or_default();
+            module_graph_for_test_file(input_file, &project_layout).rc_module_db()
+        });
     let services = JsonAnalyzeServices {
         file_source,
         configuration_provider: configuration_source.map(|(config, list)| {
@@ -217,6 +264,7 @@ pub(crate) fn analyze_and_snap(
             }) as Arc<dyn ExtendedConfigurationProvider>
         }),
         project_layout,
+        module_db,
     };
     let (_, errors) =
         biome_json_analyze::analyze(&root, filter, &options, services, &[], |event| {
@@ -0,0 +1,5 @@
+import { sum } from "lodash/fp";
+import fs from "node:fs";
+
+export const total = sum([1, 2]);
+export const exists = fs.existsSync("./index.js");
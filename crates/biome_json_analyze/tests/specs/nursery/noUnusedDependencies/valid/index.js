@@ -0,0 +1,4 @@
+import { sum } from "lodash/fp";
+
+export const total = sum([1, 2]);
+export const loadApi = () => import("@biomejs/js-api/nodejs");
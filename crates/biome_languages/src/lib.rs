@@ -45,6 +45,7 @@ pub use crate::db::LanguageDb;
 // NOTE: when adding a new ignore file, update [DocumentFileSource::try_from_path]
 pub const GIT_IGNORE_FILE_NAME: &str = ".gitignore";
 pub const IGNORE_FILE_NAME: &str = ".ignore";
+pub const HG_IGNORE_FILE_NAME: &str = ".hgignore";
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -321,7 +322,11 @@ impl DocumentFileSource {
         // and we may support more and more extensions with more than one dots.
         let extension = &match filename {
             // Ignore files are extensionless files, so they need to be handled in particular way
-            Some(filename) if filename == GIT_IGNORE_FILE_NAME || filename == IGNORE_FILE_NAME => {
+            Some(filename)
+                if filename == GIT_IGNORE_FILE_NAME
+                    || filename == IGNORE_FILE_NAME
+                    || filename == HG_IGNORE_FILE_NAME =>
+            {
                 return Ok(Self::Ignore);
             }
             Some(filename) if filename.ends_with(".d.ts") => Cow::Borrowed("d.ts"),
@@ -497,7 +502,7 @@ impl DocumentFileSource {
             #[cfg(feature = "lang_grit")]
             Self::Grit(_) => true,
             #[cfg(feature = "lang_md")]
-            Self::Markdown(_) => false, // it will, but not yet
+            Self::Markdown(_) => true,
             #[cfg(feature = "lang_yaml")]
             Self::Yaml(_) => false,
             #[cfg(feature = "lang_graphql")]
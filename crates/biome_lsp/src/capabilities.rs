@@ -4,9 +4,10 @@ use biome_lsp_converters::{PositionEncoding, negotiated_encoding};
 use biome_service::file_handlers::ON_TYPE_CHARS;
 use tower_lsp_server::ls_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    DocumentOnTypeFormattingOptions, OneOf, PositionEncodingKind, ServerCapabilities,
-    TextDocumentSyncCapability, TextDocumentSyncKind, WorkspaceFoldersServerCapabilities,
-    WorkspaceServerCapabilities,
+    DiagnosticOptions, DiagnosticServerCapabilities, DocumentOnTypeFormattingOptions,
+    HoverProviderCapability, OneOf, PositionEncodingKind, RenameOptions, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
+    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
 pub(crate) const DEFAULT_CODE_ACTION_CAPABILITIES: &[&str] = &[
@@ -27,6 +28,19 @@ pub(crate) const DEFAULT_CODE_ACTION_CAPABILITIES: &[&str] = &[
     "source.biome",
 ];
 
+/// The options of the `textDocument/diagnostic` and `workspace/diagnostic` requests.
+///
+/// The diagnostics of a file depend on other files, for example for project rules
+/// such as `noImportCycles`.
+pub(crate) fn pull_diagnostic_options() -> DiagnosticOptions {
+    DiagnosticOptions {
+        identifier: Some(String::from("biome")),
+        inter_file_dependencies: true,
+        workspace_diagnostics: true,
+        work_done_progress_options: WorkDoneProgressOptions::default(),
+    }
+}
+
 /// The capabilities to send from server as part of [`InitializeResult`]
 ///
 /// [`InitializeResult`]: tower_lsp_server::lsp::InitializeResult
@@ -114,6 +128,35 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
             }
         });
 
+    let rename_provider = capabilities
+        .text_document
+        .as_ref()
+        .and_then(|text_document| text_document.rename.as_ref())
+        .and_then(|rename| {
+            if rename.dynamic_registration.unwrap_or(false) {
+                None
+            } else {
+                Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }))
+            }
+        });
+
+    let diagnostic_provider = capabilities
+        .text_document
+        .as_ref()
+        .and_then(|text_document| text_document.diagnostic.as_ref())
+        .and_then(|diagnostic| {
+            if diagnostic.dynamic_registration.unwrap_or(false) {
+                None
+            } else {
+                Some(DiagnosticServerCapabilities::Options(
+                    pull_diagnostic_options(),
+                ))
+            }
+        });
+
     ServerCapabilities {
         position_encoding: Some(match negotiated_encoding(capabilities) {
             PositionEncoding::Utf8 => PositionEncodingKind::UTF8,
@@ -130,7 +173,12 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         document_on_type_formatting_provider: supports_on_type_formatter_dynamic_registration,
         code_action_provider,
         definition_provider,
-        rename_provider: None,
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        references_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        inlay_hint_provider: Some(OneOf::Left(true)),
+        rename_provider,
+        diagnostic_provider,
         workspace: Some(WorkspaceServerCapabilities {
             workspace_folders: Some(WorkspaceFoldersServerCapabilities {
                 supported: Some(true),
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use biome_configuration::Configuration;
 use biome_service::settings::{EditorFeature, EditorFeatures};
-use biome_service::workspace::ScanKind;
+use biome_service::workspace::{InlayHintKind, ScanKind};
 use camino::Utf8PathBuf;
 use serde::{Deserialize, Serialize};
 use serde_json::{Error, Value};
@@ -36,6 +36,9 @@ pub struct WorkspaceSettings {
 
     /// Enables the "go-to" features, by-passing the use of linting or assist. Disabled by default.
     pub go_to_definition: Option<bool>,
+
+    /// Inlay hints shown by the editor. All hints are disabled by default.
+    pub inlay_hints: Option<InlayHintsSettings>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -45,6 +48,19 @@ pub struct ExperimentalSettings {
     pub rename: Option<bool>,
 }
 
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsSettings {
+    /// Show the inferred type of variables without a type annotation
+    pub variable_types: Option<bool>,
+
+    /// Show the inferred return type of functions without a return type annotation
+    pub function_return_types: Option<bool>,
+
+    /// Show the name of the parameter that receives each argument of a call
+    pub parameter_names: Option<bool>,
+}
+
 /// The `biome.*` extension settings
 #[derive(Debug)]
 pub(crate) struct ExtensionSettings {
@@ -90,14 +106,49 @@ impl ExtensionSettings {
         if go_to_definition {
             features.insert(EditorFeature::GotoDefinition);
         }
+        if self.rename_enabled() {
+            features.insert(EditorFeature::Rename);
+        }
 
         features
     }
 
+    /// Whether symbol renaming is enabled, either with `experimental.rename`
+    /// or with the deprecated `rename` setting
+    pub(crate) fn rename_enabled(&self) -> bool {
+        self.settings
+            .experimental
+            .as_ref()
+            .and_then(|experimental| experimental.rename)
+            .or(self.settings.rename)
+            .unwrap_or_default()
+    }
+
+    /// The kinds of inlay hints enabled with `inlayHints`
+    pub(crate) fn inlay_hint_kinds(&self) -> Vec<InlayHintKind> {
+        let Some(settings) = self.settings.inlay_hints.as_ref() else {
+            return Vec::new();
+        };
+        [
+            (settings.variable_types, InlayHintKind::VariableType),
+            (settings.function_return_types, InlayHintKind::ReturnType),
+            (settings.parameter_names, InlayHintKind::ParameterName),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| enabled.unwrap_or_default())
+        .map(|(_, kind)| kind)
+        .collect()
+    }
+
     /// Which [ScanKind] is required for the current editor features
     pub(crate) fn scan_kind_from_editor_features(&self) -> ScanKind {
         let features = self.editor_features();
-        if features.contains(EditorFeature::GotoDefinition) {
+        // Renaming a symbol updates the modules that import it, and inlay
+        // hints infer types from them
+        if features.contains(EditorFeature::GotoDefinition)
+            || features.contains(EditorFeature::Rename)
+            || !self.inlay_hint_kinds().is_empty()
+        {
             ScanKind::Project
         } else {
             ScanKind::KnownFiles
@@ -1,4 +1,7 @@
 pub(crate) mod analysis;
+pub(crate) mod diagnostics;
 pub(crate) mod formatting;
+pub(crate) mod inlay_hints;
 pub(crate) mod navigation;
+pub(crate) mod rename;
 pub(crate) mod text_document;
@@ -0,0 +1,250 @@
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::uri_from_path;
+use crate::server::catch_lsp_operation;
+use crate::session::Session;
+use biome_fs::BiomePath;
+use biome_line_index::LineIndex;
+use biome_service::Workspace;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{GetFileContentParams, ListProjectFilesParams};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+use std::hash::Hasher;
+use tower_lsp_server::ls_types::notification::Notification;
+use tower_lsp_server::ls_types::*;
+use tracing::error;
+
+/// Number of files whose diagnostics are sent in each partial result of the
+/// `workspace/diagnostic` request.
+const PARTIAL_RESULT_SIZE: usize = 32;
+
+/// Handler for the `textDocument/diagnostic` request, used by clients that pull
+/// the diagnostics of a document instead of waiting for them to be published.
+pub(crate) fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReportResult, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(full_document_report(None, Vec::new()));
+    };
+
+    let workspace = session.workspace_for_request();
+    let content = workspace.get_file_content(GetFileContentParams {
+        project_key: doc.project_key,
+        path: session.file_path(&url)?,
+    })?;
+    let result_id = result_id(session, &content);
+
+    if params.previous_result_id.as_ref() == Some(&result_id) {
+        return Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            }),
+        ));
+    }
+
+    let diagnostics =
+        session.compute_diagnostics(&workspace, &url, doc.project_key, &doc.line_index)?;
+
+    Ok(full_document_report(Some(result_id), diagnostics))
+}
+
+/// Handler for the `workspace/diagnostic` request.
+///
+/// Reports the diagnostics of every file of the projects opened in the session,
+/// including the files that the client didn't open but the scanner indexed.
+/// This allows the client to show diagnostics of project rules, such as
+/// `noImportCycles`, in files that aren't open.
+///
+/// The files whose result id didn't change are reported as unchanged without
+/// being analyzed again. When the client provides a partial result token, the
+/// reports are sent in batches while the next files are analyzed.
+pub(crate) async fn workspace_diagnostic(
+    session: &Session,
+    params: WorkspaceDiagnosticParams,
+) -> Result<WorkspaceDiagnosticReportResult, LspError> {
+    let partial_result_token = params.partial_result_params.partial_result_token;
+    let previous_result_ids: FxHashMap<Uri, String> = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect();
+
+    let open_documents: FxHashMap<_, _> = session
+        .documents()
+        .into_iter()
+        .filter_map(|(url, doc)| Some((session.file_path(&url).ok()?, (url, doc.version))))
+        .collect();
+
+    let mut items = Vec::new();
+    for project_key in session.project_keys() {
+        let files = session
+            .workspace_for_request()
+            .list_project_files(ListProjectFilesParams { project_key })?;
+
+        for paths in files.paths.chunks(PARTIAL_RESULT_SIZE) {
+            let reports = workspace_document_reports(
+                session,
+                project_key,
+                paths,
+                &open_documents,
+                &previous_result_ids,
+            );
+            match &partial_result_token {
+                Some(token) => {
+                    session
+                        .client
+                        .send_notification::<PartialResultProgress>(PartialResultProgressParams {
+                            token: token.clone(),
+                            value: WorkspaceDiagnosticReportPartialResult { items: reports },
+                        })
+                        .await;
+                }
+                None => items.extend(reports),
+            }
+        }
+    }
+
+    Ok(WorkspaceDiagnosticReportResult::Report(
+        WorkspaceDiagnosticReport { items },
+    ))
+}
+
+/// Computes the reports of the files at `paths`.
+///
+/// A file that can't be analyzed shouldn't prevent the client from receiving
+/// the diagnostics of the other files, so it's skipped.
+fn workspace_document_reports(
+    session: &Session,
+    project_key: ProjectKey,
+    paths: &[BiomePath],
+    open_documents: &FxHashMap<BiomePath, (Uri, i32)>,
+    previous_result_ids: &FxHashMap<Uri, String>,
+) -> Vec<WorkspaceDocumentDiagnosticReport> {
+    let workspace = session.workspace_for_request();
+    paths
+        .iter()
+        .filter_map(|path| {
+            let result = catch_lsp_operation(|| {
+                workspace_document_report(
+                    session,
+                    &workspace,
+                    project_key,
+                    path,
+                    open_documents,
+                    previous_result_ids,
+                )
+            });
+            match result {
+                Ok(Ok(Ok(report))) => report,
+                Ok(Ok(Err(err))) => {
+                    error!("Failed to compute the diagnostics of {path}: {err}");
+                    None
+                }
+                Ok(Err(cancelled)) => {
+                    error!("Cancelled the diagnostics of {path}: {cancelled}");
+                    None
+                }
+                Err(err) => {
+                    error!("Failed to compute the diagnostics of {path}: {err}");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn workspace_document_report(
+    session: &Session,
+    workspace: &dyn Workspace,
+    project_key: ProjectKey,
+    path: &BiomePath,
+    open_documents: &FxHashMap<BiomePath, (Uri, i32)>,
+    previous_result_ids: &FxHashMap<Uri, String>,
+) -> Result<Option<WorkspaceDocumentDiagnosticReport>, LspError> {
+    let Ok(content) = workspace.get_file_content(GetFileContentParams {
+        project_key,
+        path: path.clone(),
+    }) else {
+        return Ok(None);
+    };
+    let (url, version) = match open_documents.get(path) {
+        Some((url, version)) => (url.clone(), Some(i64::from(*version))),
+        None => (uri_from_path(path)?, None),
+    };
+    let result_id = result_id(session, &content);
+
+    if previous_result_ids.get(&url) == Some(&result_id) {
+        return Ok(Some(WorkspaceDocumentDiagnosticReport::Unchanged(
+            WorkspaceUnchangedDocumentDiagnosticReport {
+                uri: url,
+                version,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            },
+        )));
+    }
+
+    let line_index = LineIndex::new(&content);
+    let diagnostics = session.compute_diagnostics(workspace, &url, project_key, &line_index)?;
+
+    Ok(Some(WorkspaceDocumentDiagnosticReport::Full(
+        WorkspaceFullDocumentDiagnosticReport {
+            uri: url,
+            version,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        },
+    )))
+}
+
+fn full_document_report(
+    result_id: Option<String>,
+    diagnostics: Vec<Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+        RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id,
+                items: diagnostics,
+            },
+        },
+    ))
+}
+
+/// Identifies the diagnostics of a file, so the client can be told that they
+/// didn't change since it last pulled them, without computing them again.
+///
+/// The diagnostics of a file only change with its content, or with the
+/// settings and the module graph, whose changes increment the
+/// [generation](Session::diagnostics_generation) of the session. The generation
+/// is read before the diagnostics are computed, so a change that happens in
+/// the meantime results in a new id.
+fn result_id(session: &Session, content: &str) -> String {
+    let mut hasher = FxHasher::default();
+    hasher.write(content.as_bytes());
+    format!("{}-{:x}", session.diagnostics_generation(), hasher.finish())
+}
+
+/// The `$/progress` notification that carries a partial result of the
+/// `workspace/diagnostic` request.
+enum PartialResultProgress {}
+
+impl Notification for PartialResultProgress {
+    type Params = PartialResultProgressParams;
+    const METHOD: &'static str = "$/progress";
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PartialResultProgressParams {
+    token: ProgressToken,
+    value: WorkspaceDiagnosticReportPartialResult,
+}
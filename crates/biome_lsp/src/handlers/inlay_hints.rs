@@ -0,0 +1,72 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::Workspace;
+use biome_service::workspace::{self, InlayHintsParams};
+use tower_lsp_server::ls_types::*;
+
+/// Handler for the `textDocument/inlayHint` request.
+///
+/// Shows the hints enabled with the `inlayHints` extension settings.
+pub(crate) fn inlay_hint(
+    session: &Session,
+    params: InlayHintParams,
+) -> Result<Option<Vec<InlayHint>>, LspError> {
+    let kinds = session.extension_settings.read().inlay_hint_kinds();
+    if kinds.is_empty() {
+        return Ok(None);
+    }
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access range {:?} in document {}",
+                params.range,
+                url.as_str()
+            )
+        })?;
+
+    let result = session
+        .workspace_for_request()
+        .inlay_hints(InlayHintsParams {
+            project_key: doc.project_key,
+            path,
+            range,
+            kinds,
+        });
+    // Files that don't support inlay hints aren't an error for the user.
+    let Ok(result) = result else {
+        return Ok(None);
+    };
+
+    let hints = result
+        .hints
+        .into_iter()
+        .map(|hint| {
+            let is_parameter = hint.kind == workspace::InlayHintKind::ParameterName;
+            Ok(InlayHint {
+                position: to_proto::position(&doc.line_index, hint.position, position_encoding)?,
+                label: InlayHintLabel::String(hint.label),
+                kind: Some(if is_parameter {
+                    InlayHintKind::PARAMETER
+                } else {
+                    InlayHintKind::TYPE
+                }),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: is_parameter.then_some(true),
+                data: None,
+            })
+        })
+        .collect::<Result<Vec<_>, LspError>>()?;
+
+    Ok(Some(hints))
+}
@@ -8,6 +8,7 @@ use biome_lsp_converters::{PositionEncoding, from_proto, to_proto};
 use biome_rowan::TextRange;
 use biome_service::Workspace;
 use biome_service::settings::EditorFeature;
+use biome_service::workspace::DocumentSymbolKind;
 use std::str::FromStr;
 use tower_lsp_server::ls_types::*;
 
@@ -98,6 +99,179 @@ pub(crate) fn goto_definition(
     }
 }
 
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position_params.position,
+        position_encoding,
+    )
+    .with_context(|| {
+        format!(
+            "failed to access position {:?} in document {}",
+            params.text_document_position_params.position,
+            url.as_str()
+        )
+    })?;
+
+    let result = session
+        .workspace()
+        .hover(biome_service::workspace::HoverParams {
+            project_key: doc.project_key,
+            path,
+            symbol_at,
+        });
+    // Files that don't support hovering aren't an error for the user.
+    let Ok(Some(result)) = result else {
+        return Ok(None);
+    };
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: result.contents,
+        }),
+        range: Some(to_proto::range(
+            &doc.line_index,
+            result.range,
+            position_encoding,
+        )?),
+    }))
+}
+
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position.position,
+        position_encoding,
+    )
+    .with_context(|| {
+        format!(
+            "failed to access position {:?} in document {}",
+            params.text_document_position.position,
+            url.as_str()
+        )
+    })?;
+
+    let result =
+        session
+            .workspace()
+            .find_references(biome_service::workspace::FindReferencesParams {
+                project_key: doc.project_key,
+                path: path.clone(),
+                symbol_at,
+                include_declaration: params.context.include_declaration,
+            });
+    let Ok(Some(result)) = result else {
+        return Ok(None);
+    };
+
+    let locations = result
+        .references
+        .iter()
+        .map(|(reference_path, range)| {
+            to_location(
+                session,
+                reference_path,
+                range,
+                &doc,
+                position_encoding,
+                &path,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(locations))
+}
+
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let result =
+        session
+            .workspace()
+            .document_symbols(biome_service::workspace::DocumentSymbolsParams {
+                project_key: doc.project_key,
+                path,
+            });
+    let Ok(result) = result else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbols = result
+        .symbols
+        .into_iter()
+        .map(|symbol| to_document_symbol(symbol, &doc.line_index, position_encoding))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+fn to_document_symbol(
+    symbol: biome_service::workspace::DocumentSymbol,
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+) -> Result<DocumentSymbol, LspError> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_document_symbol(child, line_index, position_encoding))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    #[expect(deprecated)]
+    Ok(DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: to_symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+fn to_symbol_kind(kind: DocumentSymbolKind) -> SymbolKind {
+    match kind {
+        DocumentSymbolKind::Class => SymbolKind::CLASS,
+        DocumentSymbolKind::Constant => SymbolKind::CONSTANT,
+        DocumentSymbolKind::Enum => SymbolKind::ENUM,
+        DocumentSymbolKind::EnumMember => SymbolKind::ENUM_MEMBER,
+        DocumentSymbolKind::Function => SymbolKind::FUNCTION,
+        DocumentSymbolKind::Interface => SymbolKind::INTERFACE,
+        DocumentSymbolKind::Method => SymbolKind::METHOD,
+        DocumentSymbolKind::Module => SymbolKind::MODULE,
+        DocumentSymbolKind::Property => SymbolKind::PROPERTY,
+        // LSP has no kind for type aliases. TypeScript's language server reports them as classes.
+        DocumentSymbolKind::TypeAlias => SymbolKind::CLASS,
+        DocumentSymbolKind::Variable => SymbolKind::VARIABLE,
+    }
+}
+
 fn to_location(
     session: &Session,
     definition_path: &BiomePath,
@@ -129,7 +303,7 @@ fn to_location(
     })
 }
 
-fn uri_from_path(path: &BiomePath) -> Result<Uri, LspError> {
+pub(crate) fn uri_from_path(path: &BiomePath) -> Result<Uri, LspError> {
     let url = url::Url::from_file_path(path.as_path()).map_err(|_| {
         LspError::from(anyhow::anyhow!(
             "failed to convert path to URL: {}",
@@ -0,0 +1,114 @@
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::uri_from_path;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{from_proto, to_proto};
+use std::collections::HashMap;
+use tower_lsp_server::ls_types::*;
+
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    if !session.extension_settings.read().rename_enabled() {
+        return Ok(None);
+    }
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(&doc.line_index, params.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {}",
+                params.position,
+                url.as_str()
+            )
+        })?;
+
+    let result =
+        session
+            .workspace()
+            .prepare_rename(biome_service::workspace::PrepareRenameParams {
+                project_key: doc.project_key,
+                path,
+                symbol_at,
+            })?;
+    let Some(result) = result else {
+        return Ok(None);
+    };
+
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range: to_proto::range(&doc.line_index, result.range, position_encoding)?,
+        placeholder: result.placeholder,
+    }))
+}
+
+pub(crate) fn rename(
+    session: &Session,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>, LspError> {
+    if !session.extension_settings.read().rename_enabled() {
+        return Ok(None);
+    }
+    let url = params.text_document_position.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbol_at = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position.position,
+        position_encoding,
+    )
+    .with_context(|| {
+        format!(
+            "failed to access position {:?} in document {}",
+            params.text_document_position.position,
+            url.as_str()
+        )
+    })?;
+
+    let result = session
+        .workspace()
+        .rename(biome_service::workspace::RenameParams {
+            project_key: doc.project_key,
+            path,
+            symbol_at,
+            new_name: params.new_name,
+        })?;
+
+    let mut changes = HashMap::new();
+    changes.insert(
+        url,
+        utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?,
+    );
+    for dependent in result.dependents {
+        // The edits are computed on the content known by the workspace, which
+        // may not be opened in the editor
+        let content = session.workspace_for_request().get_file_content(
+            biome_service::workspace::GetFileContentParams {
+                project_key: doc.project_key,
+                path: dependent.path.clone(),
+            },
+        )?;
+        let line_index = LineIndex::new(&content);
+        changes.insert(
+            uri_from_path(&dependent.path)?,
+            utils::text_edit(&line_index, dependent.indels, position_encoding, None)?,
+        );
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    }))
+}
@@ -295,10 +295,12 @@ pub(crate) async fn did_close(
         .workspace()
         .close_file(CloseFileParams { project_key, path })?;
 
-    session
-        .client
-        .publish_diagnostics(uri, Vec::new(), None)
-        .await;
+    if !session.supports_pull_diagnostics() {
+        session
+            .client
+            .publish_diagnostics(uri, Vec::new(), None)
+            .await;
+    }
 
     Ok(())
 }
@@ -1,4 +1,6 @@
-use crate::capabilities::{DEFAULT_CODE_ACTION_CAPABILITIES, server_capabilities};
+use crate::capabilities::{
+    DEFAULT_CODE_ACTION_CAPABILITIES, pull_diagnostic_options, server_capabilities,
+};
 use crate::diagnostics::{LspError, handle_lsp_error};
 use crate::requests::syntax_tree::{SYNTAX_TREE_REQUEST, SyntaxTreePayload};
 use crate::session::{
@@ -43,7 +45,9 @@ pub struct LSPServer {
 
 impl RefUnwindSafe for LSPServer {}
 
-fn catch_lsp_operation<F, T>(operation: F) -> Result<Result<T, salsa::Cancelled>, PanicError>
+pub(crate) fn catch_lsp_operation<F, T>(
+    operation: F,
+) -> Result<Result<T, salsa::Cancelled>, PanicError>
 where
     F: FnOnce() -> T,
 {
@@ -186,6 +190,13 @@ impl LSPServer {
                                 }),
                                 kind: Some(WatchKind::all()),
                             },
+                            FileSystemWatcher {
+                                glob_pattern: GlobPattern::Relative(RelativePattern {
+                                    pattern: "**/.hgignore".to_string(),
+                                    base_uri: OneOf::Left(folder.clone()),
+                                }),
+                                kind: Some(WatchKind::all()),
+                            },
                         ]
                     })
                     .collect();
@@ -233,6 +244,10 @@ impl LSPServer {
 
                             kind: Some(WatchKind::all()),
                         },
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String("**/.hgignore".to_string()),
+                            kind: Some(WatchKind::all()),
+                        },
                     ],
                 };
                 CapabilityStatus::Enable(Some(json!(value)))
@@ -313,6 +328,35 @@ impl LSPServer {
             },
         );
 
+        capabilities.add_capability(
+            "biome_rename",
+            "textDocument/rename",
+            if is_linting_and_formatting_disabled || !self.session.can_register_rename() {
+                CapabilityStatus::Disable
+            } else {
+                CapabilityStatus::Enable(Some(json!(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })))
+            },
+        );
+
+        capabilities.add_capability(
+            "biome_diagnostic",
+            "textDocument/diagnostic",
+            if self.session.can_register_pull_diagnostics() {
+                CapabilityStatus::Enable(Some(json!(DiagnosticRegistrationOptions {
+                    text_document_registration_options: TextDocumentRegistrationOptions {
+                        document_selector: None,
+                    },
+                    diagnostic_options: pull_diagnostic_options(),
+                    static_registration_options: StaticRegistrationOptions::default(),
+                })))
+            } else {
+                CapabilityStatus::Disable
+            },
+        );
+
         self.session.register_capabilities(capabilities).await;
     }
 
@@ -409,6 +453,7 @@ impl LanguageServer for LSPServer {
         self.session.load_workspace_settings(true).await;
         self.setup_capabilities().await;
         self.session.update_all_diagnostics().await;
+        self.session.refresh_inlay_hints().await;
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
@@ -429,7 +474,8 @@ impl LanguageServer for LSPServer {
                         || (watched_file.ends_with(".editorconfig"))
                         || watched_file.ends_with("pnpm-workspace.yaml")
                         || watched_file.ends_with(".gitignore")
-                        || watched_file.ends_with(".ignore"))
+                        || watched_file.ends_with(".ignore")
+                        || watched_file.ends_with(".hgignore"))
                 {
                     info!(
                         path = %watched_file.display(),
@@ -590,6 +636,104 @@ impl LanguageServer for LSPServer {
             Err(err) => Err(into_lsp_error(err)),
         }
     }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        let result =
+            catch_lsp_operation(move || handlers::navigation::hover(&self.session, params));
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result =
+            catch_lsp_operation(move || handlers::navigation::references(&self.session, params));
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        let result =
+            catch_lsp_operation(move || handlers::rename::prepare_rename(&self.session, params));
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        let result = catch_lsp_operation(move || handlers::rename::rename(&self.session, params));
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        let result = catch_lsp_operation(move || {
+            handlers::diagnostics::document_diagnostic(&self.session, params)
+        });
+
+        match result {
+            Ok(Ok(report)) => report.map_err(into_lsp_error),
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReportResult> {
+        // The diagnostics of each file are computed with `catch_lsp_operation`,
+        // because the handler sends the partial results between them
+        handlers::diagnostics::workspace_diagnostic(&self.session, params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        let result =
+            catch_lsp_operation(move || handlers::inlay_hints::inlay_hint(&self.session, params));
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let result = catch_lsp_operation(move || {
+            handlers::navigation::document_symbol(&self.session, params)
+        });
+
+        match result {
+            Ok(Ok(result)) => self.map_op_error(Ok(result)).await,
+            Ok(Err(cancelled)) => Err(cancelled_to_lsp_error(cancelled)),
+            Err(err) => Err(into_lsp_error(err)),
+        }
+    }
 }
 
 impl Drop for LSPServer {
@@ -896,10 +1040,12 @@ impl ServerFactory {
         workspace_method!(builder, close_project);
         workspace_method!(builder, open_file);
         workspace_method!(builder, file_exists);
+        workspace_method!(builder, list_project_files);
         workspace_method!(builder, get_syntax_tree);
         workspace_method!(builder, get_control_flow_graph);
         workspace_method!(builder, get_formatter_ir);
         workspace_method!(builder, get_module_graph);
+        workspace_method!(builder, get_unused_code);
         workspace_method!(builder, get_type_info);
         workspace_method!(builder, change_file);
         workspace_method!(builder, process_file);
@@ -912,8 +1058,13 @@ impl ServerFactory {
         workspace_method!(builder, format_range);
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
+        workspace_method!(builder, prepare_rename);
         workspace_method!(builder, rename);
         workspace_method!(builder, go_to_definition);
+        workspace_method!(builder, hover);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, document_symbols);
+        workspace_method!(builder, inlay_hints);
         workspace_method!(builder, parse_pattern);
         workspace_method!(builder, search_pattern);
         workspace_method!(builder, drop_pattern);
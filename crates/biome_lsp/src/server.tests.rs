@@ -4340,6 +4340,260 @@ async fn pull_diagnostics_monorepo() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_document_diagnostics_with_result_id() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize_with_pull_diagnostics().await?;
+    server.initialized().await?;
+
+    server.open_document("const a = 1; a = 2;").await?;
+
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "pull_document_diagnostics",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                identifier: Some(String::from("biome")),
+                previous_result_id: None,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        panic!("expected a full report, got {report:?}");
+    };
+    let report = report.full_document_diagnostic_report;
+    let codes: Vec<_> = report
+        .items
+        .iter()
+        .filter_map(|diagnostic| diagnostic.code.clone())
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            NumberOrString::String(String::from("lint/correctness/noUnusedVariables")),
+            NumberOrString::String(String::from("lint/correctness/noConstAssign")),
+        ]
+    );
+    let result_id = report.result_id.context("the report has no result id")?;
+
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "pull_document_diagnostics_again",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                identifier: Some(String::from("biome")),
+                previous_result_id: Some(result_id.clone()),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    assert_eq!(
+        report,
+        lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Unchanged(
+            lsp::RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: lsp::UnchangedDocumentDiagnosticReport {
+                    result_id
+                },
+            }
+        ))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_workspace_diagnostics_of_files_not_open() -> Result<()> {
+    let fs = MemoryFileSystem::default();
+
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("a.ts")),
+        r#"import { b } from "./b.ts";
+export const a = b;
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("b.ts")),
+        r#"import { a } from "./a.ts";
+export const b = a;
+"#,
+    );
+
+    let factory = ServerFactory::new_with_fs(Arc::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize_with_pull_diagnostics().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    // The project is scanned in the background after the configuration is loaded
+    let mut reports = Vec::new();
+    for _ in 0..30 {
+        let report: lsp::WorkspaceDiagnosticReportResult = server
+            .request(
+                "workspace/diagnostic",
+                "pull_workspace_diagnostics",
+                lsp::WorkspaceDiagnosticParams {
+                    identifier: Some(String::from("biome")),
+                    previous_result_ids: Vec::new(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                },
+            )
+            .await?
+            .context("workspace/diagnostic returned None")?;
+        let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+            panic!("expected a full report, got {report:?}");
+        };
+        reports = report
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                lsp::WorkspaceDocumentDiagnosticReport::Full(report)
+                    if report.uri.as_str().ends_with(".ts") =>
+                {
+                    Some(report)
+                }
+                _ => None,
+            })
+            .collect();
+        if reports.len() == 2 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let file_names: Vec<_> = reports
+        .iter()
+        .filter_map(|report| report.uri.as_str().rsplit('/').next())
+        .collect();
+    assert_eq!(file_names, vec!["a.ts", "b.ts"]);
+    for report in &reports {
+        assert_eq!(report.version, None);
+        let items = &report.full_document_diagnostic_report.items;
+        assert_eq!(items.len(), 1, "unexpected diagnostics: {items:?}");
+        assert_eq!(
+            items[0].code,
+            Some(NumberOrString::String(String::from(
+                "lint/suspicious/noImportCycles"
+            )))
+        );
+    }
+
+    // Nothing changed, so the client is told that its diagnostics are up to
+    // date. The end of the scan can still update the diagnostics once.
+    let mut previous_result_ids: Vec<_> = reports
+        .iter()
+        .map(|report| lsp::PreviousResultId {
+            uri: report.uri.clone(),
+            value: report
+                .full_document_diagnostic_report
+                .result_id
+                .clone()
+                .unwrap_or_default(),
+        })
+        .collect();
+    let mut unchanged = 0;
+    for _ in 0..30 {
+        let report: lsp::WorkspaceDiagnosticReportResult = server
+            .request(
+                "workspace/diagnostic",
+                "pull_workspace_diagnostics_again",
+                lsp::WorkspaceDiagnosticParams {
+                    identifier: Some(String::from("biome")),
+                    previous_result_ids: previous_result_ids.clone(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                },
+            )
+            .await?
+            .context("workspace/diagnostic returned None")?;
+        let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+            panic!("expected a full report, got {report:?}");
+        };
+        unchanged = report
+            .items
+            .iter()
+            .filter(|item| matches!(item, lsp::WorkspaceDocumentDiagnosticReport::Unchanged(_)))
+            .count();
+        if unchanged == 2 {
+            break;
+        }
+        previous_result_ids = report
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                lsp::WorkspaceDocumentDiagnosticReport::Full(report) => {
+                    Some(lsp::PreviousResultId {
+                        uri: report.uri,
+                        value: report.full_document_diagnostic_report.result_id?,
+                    })
+                }
+                lsp::WorkspaceDocumentDiagnosticReport::Unchanged(report) => {
+                    Some(lsp::PreviousResultId {
+                        uri: report.uri,
+                        value: report.unchanged_document_diagnostic_report.result_id,
+                    })
+                }
+            })
+            .filter(|previous| previous.uri.as_str().ends_with(".ts"))
+            .collect();
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(unchanged, 2);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn should_correctly_fix_all_astro_files() -> Result<()> {
     let factory = ServerFactory::default();
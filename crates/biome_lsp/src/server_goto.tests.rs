@@ -1,6 +1,8 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use crate::WorkspaceSettings;
+use crate::extension_settings::{ExperimentalSettings, InlayHintsSettings};
 use crate::server_test_utils::*;
 use anyhow::{Context, Result};
 use biome_fs::{MemoryFileSystem, TemporaryFs};
@@ -778,3 +780,463 @@ async fn goto_definition_node_modules_import_does_not_error() -> Result<()> {
 }
 
 // #endregion
+
+// #region NAVIGATION TESTS
+
+/// Opens a document with the given content, then sends the request built by
+/// `params` for this document.
+async fn navigation_single_file<P, R>(
+    file_name: &str,
+    source: &str,
+    method: &'static str,
+    params: impl FnOnce(lsp::Uri) -> P,
+) -> Result<R>
+where
+    P: serde::Serialize,
+    R: serde::de::DeserializeOwned,
+{
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let document_uri = test_uri(file_name);
+    server
+        .open_named_document(source, document_uri.clone(), "typescript")
+        .await?;
+
+    let res: R = server
+        .request(method, "navigation", params(document_uri))
+        .await?
+        .with_context(|| format!("{method} returned None"))?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(res)
+}
+
+fn text_document_position(uri: lsp::Uri, position: Position) -> lsp::TextDocumentPositionParams {
+    lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri },
+        position,
+    }
+}
+
+#[tokio::test]
+async fn hover_shows_declaration_and_jsdoc() -> Result<()> {
+    // Cursor on `answer` in `console.log(answer)` (line 2, character 12)
+    let res: Option<lsp::Hover> = navigation_single_file(
+        "document.ts",
+        "/** The answer */\nconst answer = 42;\nconsole.log(answer);\n",
+        "textDocument/hover",
+        |uri| lsp::HoverParams {
+            text_document_position_params: text_document_position(uri, pos(2, 12)),
+            work_done_progress_params: WorkDoneProgressParams {
+                work_done_token: None,
+            },
+        },
+    )
+    .await?;
+
+    let hover = res.expect("hover returned empty response");
+    let lsp::HoverContents::Markup(contents) = hover.contents else {
+        panic!("expected markup contents, got {:?}", hover.contents);
+    };
+    assert!(
+        contents.value.starts_with("```ts\nconst answer"),
+        "unexpected hover contents: {}",
+        contents.value
+    );
+    assert!(contents.value.contains("The answer"));
+    assert_eq!(hover.range, Some(range(2, 12, 2, 18)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn references_include_declaration() -> Result<()> {
+    // Cursor on the declaration of `count` (line 0, character 4)
+    let res: Option<Vec<lsp::Location>> = navigation_single_file(
+        "document.ts",
+        "let count = 0;\ncount += 1;\nconsole.log(count);\n",
+        "textDocument/references",
+        |uri| lsp::ReferenceParams {
+            text_document_position: text_document_position(uri, pos(0, 4)),
+            work_done_progress_params: WorkDoneProgressParams {
+                work_done_token: None,
+            },
+            partial_result_params: lsp::PartialResultParams {
+                partial_result_token: None,
+            },
+            context: lsp::ReferenceContext {
+                include_declaration: true,
+            },
+        },
+    )
+    .await?;
+
+    let ranges: Vec<_> = res
+        .expect("references returned empty response")
+        .into_iter()
+        .map(|location| location.range)
+        .collect();
+    assert_eq!(
+        ranges,
+        vec![range(0, 4, 0, 9), range(1, 0, 1, 5), range(2, 12, 2, 17)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_symbols_are_nested() -> Result<()> {
+    let res: Option<lsp::DocumentSymbolResponse> = navigation_single_file(
+        "document.ts",
+        "export class Counter {\n  count = 0;\n  increment() {}\n}\nconst LIMIT = 10;\ntype Step = number;\n",
+        "textDocument/documentSymbol",
+        |uri| lsp::DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri },
+            work_done_progress_params: WorkDoneProgressParams {
+                work_done_token: None,
+            },
+            partial_result_params: lsp::PartialResultParams {
+                partial_result_token: None,
+            },
+        },
+    )
+    .await?;
+
+    let Some(lsp::DocumentSymbolResponse::Nested(symbols)) = res else {
+        panic!("expected nested document symbols, got {res:?}");
+    };
+    let outline: Vec<_> = symbols
+        .iter()
+        .map(|symbol| {
+            let children: Vec<_> = symbol
+                .children
+                .iter()
+                .flatten()
+                .map(|child| (child.name.as_str(), child.kind))
+                .collect();
+            (symbol.name.as_str(), symbol.kind, children)
+        })
+        .collect();
+    assert_eq!(
+        outline,
+        vec![
+            (
+                "Counter",
+                lsp::SymbolKind::CLASS,
+                vec![
+                    ("count", lsp::SymbolKind::PROPERTY),
+                    ("increment", lsp::SymbolKind::METHOD)
+                ]
+            ),
+            ("LIMIT", lsp::SymbolKind::CONSTANT, vec![]),
+            ("Step", lsp::SymbolKind::CLASS, vec![]),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_returns_workspace_edit() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let settings = WorkspaceSettings {
+        experimental: Some(ExperimentalSettings { rename: Some(true) }),
+        ..Default::default()
+    };
+    let reader = tokio::spawn(client_handler_with_settings(stream, sink, sender, settings));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let document_uri = test_uri("document.ts");
+    server
+        .open_named_document(
+            "let count = 0;\ncount += 1;\n",
+            document_uri.clone(),
+            "typescript",
+        )
+        .await?;
+
+    // Cursor on the declaration of `count` (line 0, character 4)
+    let prepared: Option<lsp::PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            text_document_position(document_uri.clone(), pos(0, 4)),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+    assert_eq!(
+        prepared,
+        Some(lsp::PrepareRenameResponse::RangeWithPlaceholder {
+            range: range(0, 4, 0, 9),
+            placeholder: String::from("count"),
+        })
+    );
+
+    let res: Option<lsp::WorkspaceEdit> = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position: text_document_position(document_uri.clone(), pos(0, 4)),
+                new_name: String::from("total"),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    let changes = res
+        .and_then(|edit| edit.changes)
+        .expect("rename returned empty response");
+    assert_eq!(changes.len(), 1);
+    let ranges: Vec<_> = changes[&document_uri]
+        .iter()
+        .map(|edit| (edit.range, edit.new_text.as_str()))
+        .collect();
+    assert_eq!(
+        ranges,
+        vec![(range(0, 4, 0, 9), "total"), (range(1, 0, 1, 5), "total")]
+    );
+
+    Ok(())
+}
+
+// #endregion
+
+// #region INLAY HINT TESTS
+
+#[tokio::test]
+async fn inlay_hints_show_inferred_types_and_parameter_names() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let settings = WorkspaceSettings {
+        inlay_hints: Some(InlayHintsSettings {
+            variable_types: Some(true),
+            function_return_types: Some(true),
+            parameter_names: Some(true),
+        }),
+        ..Default::default()
+    };
+    let reader = tokio::spawn(client_handler_with_settings(stream, sink, sender, settings));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let document_uri = test_uri("document.ts");
+    server
+        .open_named_document(
+            "function add(a: number, b: number) {\n    return a + b;\n}\nconst sum = add(1, 2);\nconst b = sum;\nadd(sum, b);\n",
+            document_uri.clone(),
+            "typescript",
+        )
+        .await?;
+
+    let res: Option<Vec<lsp::InlayHint>> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: document_uri.clone(),
+                },
+                range: range(0, 0, 6, 0),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("inlayHint returned None")?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    let hints: Vec<_> = res
+        .expect("inlayHint returned empty response")
+        .into_iter()
+        .map(|hint| {
+            let lsp::InlayHintLabel::String(label) = hint.label else {
+                panic!("expected a string label, got {:?}", hint.label);
+            };
+            (hint.position, label, hint.kind)
+        })
+        .collect();
+    // The argument `b` has the name of the parameter, so it has no hint
+    assert_eq!(
+        hints,
+        vec![
+            (
+                pos(0, 34),
+                String::from(": number"),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                pos(3, 9),
+                String::from(": number"),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                pos(3, 16),
+                String::from("a:"),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+            (
+                pos(3, 19),
+                String::from("b:"),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+            (
+                pos(4, 7),
+                String::from(": number"),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                pos(5, 4),
+                String::from("a:"),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints_infer_types_from_imported_modules() -> Result<()> {
+    let mut fs = TemporaryFs::new("inlay_hints_infer_types_from_imported_modules");
+    fs.create_file("biome.json", "{}");
+    fs.create_file(
+        "helpers.ts",
+        "export function double(value: number): number {\n    return value * 2;\n}\n",
+    );
+    let source = "import { double } from './helpers.ts';\nconst result = double(21);\n";
+    fs.create_file("main.ts", source);
+
+    let root_uri = lsp::Uri::from_str(
+        url::Url::from_file_path(&fs.working_directory)
+            .unwrap()
+            .as_str(),
+    )
+    .unwrap();
+
+    let (watcher, instruction_channel) = Watcher::new(WatcherOptions::default())?;
+
+    let factory = ServerFactory::new(true, instruction_channel.sender.clone());
+
+    let workspace = factory.workspace();
+    let db_state = factory.db_state();
+    spawn_blocking(move || {
+        workspace.start_watcher(&db_state, watcher);
+    });
+
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let settings = WorkspaceSettings {
+        inlay_hints: Some(InlayHintsSettings {
+            variable_types: Some(true),
+            function_return_types: None,
+            parameter_names: Some(true),
+        }),
+        ..Default::default()
+    };
+    let reader = tokio::spawn(client_handler_with_settings(stream, sink, sender, settings));
+
+    // The project isn't scanned explicitly: the scan requested when the
+    // configuration is loaded must index `helpers.ts`.
+    server.initialize_with_root(root_uri).await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    let document_uri = file_uri(&fs, "main.ts");
+    server
+        .notify(
+            "textDocument/didOpen",
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: document_uri.clone(),
+                    language_id: String::from("typescript"),
+                    version: 0,
+                    text: String::from(source),
+                },
+            },
+        )
+        .await?;
+
+    let res: Option<Vec<lsp::InlayHint>> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier { uri: document_uri },
+                range: range(0, 0, 2, 0),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("inlayHint returned None")?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    let hints: Vec<_> = res
+        .expect("inlayHint returned empty response")
+        .into_iter()
+        .map(|hint| {
+            let lsp::InlayHintLabel::String(label) = hint.label else {
+                panic!("expected a string label, got {:?}", hint.label);
+            };
+            (hint.position, label, hint.kind)
+        })
+        .collect();
+    assert_eq!(
+        hints,
+        vec![
+            (
+                pos(1, 12),
+                String::from(": number"),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                pos(1, 22),
+                String::from("value:"),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+// #endregion
@@ -18,11 +18,12 @@ use tower_lsp_server::LspService;
 use tower_lsp_server::jsonrpc::{self, Request, Response};
 use tower_lsp_server::ls_types::{
     self as lsp, ClientCapabilities, CodeActionCapabilityResolveSupport,
-    CodeActionClientCapabilities, DidChangeConfigurationParams, DidChangeTextDocumentParams,
-    DidCloseTextDocumentParams, DidOpenTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, PublishDiagnosticsParams, ShowMessageParams, TextDocumentClientCapabilities,
-    TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem, Uri,
-    VersionedTextDocumentIdentifier, WorkspaceFolder,
+    CodeActionClientCapabilities, DiagnosticClientCapabilities, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    InitializeParams, InitializeResult, InitializedParams, PublishDiagnosticsParams,
+    ShowMessageParams, TextDocumentClientCapabilities, TextDocumentContentChangeEvent,
+    TextDocumentIdentifier, TextDocumentItem, Uri, VersionedTextDocumentIdentifier,
+    WorkspaceFolder,
 };
 
 /// Statically build an [Uri] instance that points to the file at `$path`
@@ -256,6 +257,39 @@ impl Server {
         Ok(())
     }
 
+    /// Like [`Self::initialize`] but advertises `textDocument/diagnostic` support,
+    /// so the client pulls diagnostics instead of receiving them.
+    #[expect(deprecated)]
+    pub async fn initialize_with_pull_diagnostics(&mut self) -> Result<()> {
+        let _res: InitializeResult = self
+            .request(
+                "initialize",
+                "_init",
+                InitializeParams {
+                    process_id: None,
+                    root_path: None,
+                    root_uri: Some(uri!("")),
+                    initialization_options: None,
+                    capabilities: ClientCapabilities {
+                        text_document: Some(TextDocumentClientCapabilities {
+                            diagnostic: Some(DiagnosticClientCapabilities::default()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    trace: None,
+                    workspace_folders: None,
+                    client_info: None,
+                    locale: None,
+                    work_done_progress_params: Default::default(),
+                },
+            )
+            .await?
+            .context("initialize returned None")?;
+
+        Ok(())
+    }
+
     pub(crate) async fn initialized(&mut self) -> Result<()> {
         self.notify("initialized", InitializedParams {}).await
     }
@@ -9,7 +9,7 @@ use biome_console::markup;
 use biome_deserialize::Merge;
 use biome_diagnostics::{PrintDescription, Severity};
 use biome_fs::{BiomePath, normalize_path};
-use biome_line_index::WideEncoding;
+use biome_line_index::{LineIndex, WideEncoding};
 use biome_lsp_converters::{PositionEncoding, negotiated_encoding};
 use biome_service::WorkspaceError;
 use biome_service::configuration::{
@@ -40,7 +40,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde_json::Value;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
-use std::sync::atomic::{AtomicBool, AtomicI32};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64};
 use std::time::Duration;
 use tokio::spawn;
 use tokio::sync::Notify;
@@ -142,6 +142,11 @@ pub(crate) struct Session {
     /// Pending and active diagnostic refreshes for open documents.
     diagnostics: HashMap<Uri, Arc<DiagnosticsEntry>, FxBuildHasher>,
 
+    /// Incremented when the settings or the module graph change. The
+    /// diagnostics of every file can change then, even if its content didn't,
+    /// so the generation is part of the result ids of the pulled diagnostics.
+    diagnostics_generation: AtomicU64,
+
     pub(crate) cancellation: Arc<Notify>,
 
     /// Receiver for service notifications.
@@ -266,6 +271,7 @@ impl Session {
             projects: Default::default(),
             documents: Default::default(),
             diagnostics: Default::default(),
+            diagnostics_generation: AtomicU64::new(0),
             extension_settings: config,
             cancellation,
             notified_broken_configuration: Default::default(),
@@ -420,6 +426,11 @@ impl Session {
             .map(|(_project_path, project_key)| *project_key)
     }
 
+    /// Returns the keys of the projects opened in this session.
+    pub(crate) fn project_keys(&self) -> Vec<ProjectKey> {
+        self.projects.pin().values().copied().collect()
+    }
+
     /// Registers an open project with its root path and scans the folder.
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn insert_and_scan_project(
@@ -452,6 +463,15 @@ impl Session {
         self.documents.pin().insert(url, document);
     }
 
+    /// Returns the [`Document`]s opened in this session, with their [`Uri`]
+    pub(crate) fn documents(&self) -> Vec<(Uri, Document)> {
+        self.documents
+            .pin()
+            .iter()
+            .map(|(url, doc)| (url.clone(), doc.clone()))
+            .collect()
+    }
+
     /// Remove the [`Document`] matching the provided [`Uri`]
     pub(crate) fn remove_document(&self, url: &Uri) -> Option<ProjectKey> {
         self.documents.pin().remove(url).map(|doc| doc.project_key)
@@ -590,6 +610,9 @@ impl Session {
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// When the client pulls diagnostics, nothing is published, because the
+    /// client requests them through [`handlers::diagnostics`] instead.
     #[tracing::instrument(level = "debug", skip_all, fields(url = display(url.as_str()), diagnostic_count), err)]
     async fn update_diagnostics_for_document(
         &self,
@@ -597,29 +620,68 @@ impl Session {
         doc: Document,
         entry: Option<&DiagnosticsEntry>,
     ) -> Result<(), LspError> {
-        let biome_path = self.file_path(&url)?;
-
-        let configuration_status = self.configuration_status(doc.project_key);
-        if !self.notified_broken_configuration(doc.project_key) {
-            if configuration_status.is_editorconfig_error() {
-                self.set_notified_broken_configuration(doc.project_key);
-                self.client
-                    .show_message(MessageType::WARNING, "The .editorconfig file has errors. Biome will report only parsing errors until the file is fixed or its usage is disabled.")
-                    .await
-            } else if configuration_status.is_error() {
-                self.set_notified_broken_configuration(doc.project_key);
-                self.client
-                    .show_message(MessageType::WARNING, "The configuration file has errors. Biome will report only parsing errors until the configuration is fixed.")
-                    .await;
-            } else if configuration_status.is_plugin_error() {
-                self.set_notified_broken_configuration(doc.project_key);
-                self.client.show_message(MessageType::WARNING, "The plugin loading has failed. Biome will report only parsing errors until the file is fixed or its usage is disabled.").await
-            }
+        self.notify_broken_configuration(doc.project_key).await;
+
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
+        let diagnostics =
+            self.compute_diagnostics(&self.workspace(), &url, doc.project_key, &doc.line_index)?;
+
+        if !self.can_publish_diagnostics(&url, doc.version, entry) {
+            return Ok(());
         }
+
+        self.client
+            .publish_diagnostics(url.clone(), diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Warns the user once when the configuration of the project has errors,
+    /// because only parsing errors are reported until it's fixed.
+    async fn notify_broken_configuration(&self, project_key: ProjectKey) {
+        if self.notified_broken_configuration(project_key) {
+            return;
+        }
+
+        let configuration_status = self.configuration_status(project_key);
+        if configuration_status.is_editorconfig_error() {
+            self.set_notified_broken_configuration(project_key);
+            self.client
+                .show_message(MessageType::WARNING, "The .editorconfig file has errors. Biome will report only parsing errors until the file is fixed or its usage is disabled.")
+                .await
+        } else if configuration_status.is_error() {
+            self.set_notified_broken_configuration(project_key);
+            self.client
+                .show_message(MessageType::WARNING, "The configuration file has errors. Biome will report only parsing errors until the configuration is fixed.")
+                .await;
+        } else if configuration_status.is_plugin_error() {
+            self.set_notified_broken_configuration(project_key);
+            self.client.show_message(MessageType::WARNING, "The plugin loading has failed. Biome will report only parsing errors until the file is fixed or its usage is disabled.").await
+        }
+    }
+
+    /// Computes the LSP diagnostics of a file of the project.
+    ///
+    /// The `line_index` must be built from the content of the file that is
+    /// stored in the workspace, which isn't necessarily an open [`Document`].
+    pub(crate) fn compute_diagnostics(
+        &self,
+        workspace: &dyn Workspace,
+        url: &Uri,
+        project_key: ProjectKey,
+        line_index: &LineIndex,
+    ) -> Result<Vec<Diagnostic>, LspError> {
+        let biome_path = self.file_path(url)?;
+        let configuration_status = self.configuration_status(project_key);
+
         let FileFeaturesResult {
             features_supported: file_features,
-        } = self.workspace().file_features(SupportsFeatureParams {
-            project_key: doc.project_key,
+        } = workspace.file_features(SupportsFeatureParams {
+            project_key,
             features: FeaturesBuilder::new().with_linter().with_assist().build(),
             path: biome_path.clone(),
             inline_config: self.inline_config(),
@@ -628,89 +690,74 @@ impl Session {
         })?;
 
         if !file_features.supports_lint() && !file_features.supports_assist() {
-            if !self.can_publish_diagnostics(&url, doc.version, entry) {
-                return Ok(());
-            }
-            self.client
-                .publish_diagnostics(url.clone(), vec![], Some(doc.version))
-                .await;
-            return Ok(());
+            return Ok(Vec::new());
         }
-        let diagnostics: Vec<Diagnostic> = {
-            let mut categories = RuleCategoriesBuilder::default().with_syntax();
-            if configuration_status.is_loaded() {
-                if file_features.supports_lint() {
-                    categories = categories.with_lint();
-                }
-                if file_features.supports_assist() {
-                    categories = categories.with_assist();
-                }
+
+        let mut categories = RuleCategoriesBuilder::default().with_syntax();
+        if configuration_status.is_loaded() {
+            if file_features.supports_lint() {
+                categories = categories.with_lint();
             }
-            let result = self.workspace().pull_diagnostics(PullDiagnosticsParams {
-                project_key: doc.project_key,
-                path: biome_path.clone(),
-                categories: categories.build(),
-                only: Vec::new(),
-                skip: Vec::new(),
-                enabled_rules: Vec::new(),
-                include_code_fix: false,
-                inline_config: self.inline_config(),
-                max_diagnostics: None,
-                diagnostic_level: Severity::Information,
-                enforce_assist: false,
-            })?;
-
-            let offset = if file_features.supports_full_html_support() {
-                None
-            } else {
-                let get_start: Option<fn(&str) -> Option<u32>> = match biome_path.extension() {
-                    Some("vue") => Some(VueFileHandler::start),
-                    Some("astro") => Some(AstroFileHandler::start),
-                    Some("svelte") => Some(SvelteFileHandler::start),
-                    _ => None,
-                };
-                get_start.and_then(|f| {
-                    let content = self
-                        .workspace()
-                        .get_file_content(GetFileContentParams {
-                            project_key: doc.project_key,
-                            path: biome_path.clone(),
-                        })
-                        .ok()?;
-                    f(content.as_str())
-                })
-            };
+            if file_features.supports_assist() {
+                categories = categories.with_assist();
+            }
+        }
+        let result = workspace.pull_diagnostics(PullDiagnosticsParams {
+            project_key,
+            path: biome_path.clone(),
+            categories: categories.build(),
+            only: Vec::new(),
+            skip: Vec::new(),
+            enabled_rules: Vec::new(),
+            include_code_fix: false,
+            inline_config: self.inline_config(),
+            max_diagnostics: None,
+            diagnostic_level: Severity::Information,
+            enforce_assist: false,
+        })?;
 
-            result
-                .diagnostics
-                .into_iter()
-                .filter_map(|d| {
-                    match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
-                        &doc.line_index,
-                        self.position_encoding(),
-                        offset,
-                    ) {
-                        Ok(diag) => Some(diag),
-                        Err(err) => {
-                            error!("failed to convert diagnostic to LSP: {err:?}");
-                            None
-                        }
-                    }
-                })
-                .collect()
+        let offset = if file_features.supports_full_html_support() {
+            None
+        } else {
+            let get_start: Option<fn(&str) -> Option<u32>> = match biome_path.extension() {
+                Some("vue") => Some(VueFileHandler::start),
+                Some("astro") => Some(AstroFileHandler::start),
+                Some("svelte") => Some(SvelteFileHandler::start),
+                _ => None,
+            };
+            get_start.and_then(|f| {
+                let content = workspace
+                    .get_file_content(GetFileContentParams {
+                        project_key,
+                        path: biome_path.clone(),
+                    })
+                    .ok()?;
+                f(content.as_str())
+            })
         };
 
-        if !self.can_publish_diagnostics(&url, doc.version, entry) {
-            return Ok(());
-        }
+        let diagnostics = result
+            .diagnostics
+            .into_iter()
+            .filter_map(|d| {
+                match utils::diagnostic_to_lsp(d, url, line_index, self.position_encoding(), offset)
+                {
+                    Ok(diag) => Some(diag),
+                    Err(err) => {
+                        error!("failed to convert diagnostic to LSP: {err:?}");
+                        None
+                    }
+                }
+            })
+            .collect();
 
-        self.client
-            .publish_diagnostics(url.clone(), diagnostics, Some(doc.version))
-            .await;
+        Ok(diagnostics)
+    }
 
-        Ok(())
+    /// Returns the generation of the settings and the module graph, which is
+    /// incremented every time the diagnostics of all the files are updated.
+    pub(crate) fn diagnostics_generation(&self) -> u64 {
+        self.diagnostics_generation.load(Ordering::Acquire)
     }
 
     fn is_document_current(&self, url: &Uri, version: i32) -> bool {
@@ -743,6 +790,16 @@ impl Session {
                 error!("Error while updating diagnostics: {}", e);
             }
         }
+
+        // Files that aren't open can be affected too, for example when the
+        // module graph changes, so the client must pull all diagnostics again.
+        // The result ids of their previous diagnostics are outdated.
+        self.diagnostics_generation.fetch_add(1, Ordering::AcqRel);
+        if self.supports_diagnostic_refresh()
+            && let Err(err) = self.client.workspace_diagnostic_refresh().await
+        {
+            error!("Failed to refresh the diagnostics of the workspace: {err}");
+        }
     }
 
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
@@ -816,6 +873,57 @@ impl Session {
         result
     }
 
+    /// Whether the client pulls diagnostics with `textDocument/diagnostic`,
+    /// rather than waiting for the server to publish them.
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .is_some_and(|c| c.diagnostic.is_some())
+    }
+
+    /// Whether the client can be asked to pull the diagnostics of all files again
+    /// with `workspace/diagnostic/refresh`.
+    pub(crate) fn supports_diagnostic_refresh(&self) -> bool {
+        self.supports_pull_diagnostics()
+            && self
+                .initialize_params
+                .get()
+                .and_then(|c| c.client_capabilities.workspace.as_ref())
+                .and_then(|c| c.diagnostic.as_ref())
+                .and_then(|c| c.refresh_support)
+                == Some(true)
+    }
+
+    /// Asks the client to request the inlay hints of the open documents again,
+    /// because the `inlayHints` settings may have changed.
+    pub(crate) async fn refresh_inlay_hints(&self) {
+        let supports_refresh = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.inlay_hint.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true);
+        if supports_refresh && let Err(err) = self.client.inlay_hint_refresh().await {
+            error!("Failed to refresh the inlay hints: {err}");
+        }
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub(crate) fn can_register_pull_diagnostics(&self) -> bool {
+        let result = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.dynamic_registration)
+            == Some(true);
+
+        info!("Can register diagnostic: {result}");
+        result
+    }
+
     /// Whether the client supports `codeAction/resolve` for deferred edit computation.
     /// Whether the client supports `codeAction/resolve` for deferred edit computation.
     ///
@@ -862,6 +970,24 @@ impl Session {
         result
     }
 
+    pub(crate) fn can_register_rename(&self) -> bool {
+        let result = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.rename.as_ref())
+            .and_then(|c| c.dynamic_registration)
+            == Some(true)
+            && self
+                .extension_settings
+                .read()
+                .editor_features()
+                .contains(EditorFeature::Rename);
+        info!("Can register rename: {result}");
+
+        result
+    }
+
     /// Get the current workspace folders
     pub(crate) fn get_workspace_folders(&self) -> Option<Vec<WorkspaceFolder>> {
         self.workspace_folders.read().clone()
@@ -3,21 +3,46 @@
 mod assist;
 mod lint;
 mod registry;
+mod services;
 mod suppression_action;
+mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::MarkdownSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    BatchPluginVisitor, ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases,
+    PluginTargetLanguage, RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_deserialize::TextRange;
 use biome_diagnostics::Error;
+use biome_fs::FileSystem;
 use biome_markdown_syntax::MarkdownLanguage;
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+
+/// Services available to Markdown lint rules.
+#[derive(Default)]
+pub struct MarkdownAnalyzerServices {
+    /// The file system, used to read the documents that links point to.
+    pub file_system: Option<Arc<dyn FileSystem>>,
+}
+
+impl MarkdownAnalyzerServices {
+    pub fn with_file_system(mut self, file_system: Arc<dyn FileSystem>) -> Self {
+        self.file_system = Some(file_system);
+        self
+    }
+}
+
+impl std::fmt::Debug for MarkdownAnalyzerServices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownAnalyzerServices")
+            .field("file_system", &self.file_system.as_ref().map(|_| "..."))
+            .finish()
+    }
+}
 
 pub(crate) type MarkdownRuleAction = RuleAction<MarkdownLanguage>;
 
@@ -34,13 +59,23 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<MarkdownLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    md_services: MarkdownAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<MarkdownLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        md_services,
+        plugins,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -54,6 +89,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    md_services: MarkdownAnalyzerServices,
+    plugins: AnalyzerPluginSlice<'a>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -90,13 +127,15 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    services.insert_service(md_services.file_system);
+
     let mut analyzer = biome_analyze::Analyzer::new(
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
@@ -109,6 +148,22 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    let markdown_plugins: Vec<_> = plugins
+        .iter()
+        .filter(|p| p.language() == PluginTargetLanguage::Markdown)
+        .cloned()
+        .collect();
+
+    if filter.match_plugins() && !markdown_plugins.is_empty() {
+        // SAFETY: All plugins have been verified to target Markdown above.
+        unsafe {
+            analyzer.add_visitor(
+                Phases::Syntax,
+                Box::new(BatchPluginVisitor::new_unchecked(&markdown_plugins)),
+            );
+        }
+    }
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -122,7 +177,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::analyze;
+    use crate::{MarkdownAnalyzerServices, analyze};
     use biome_analyze::{
         ActionFilter, AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleFilter,
     };
@@ -160,6 +215,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            MarkdownAnalyzerServices::default(),
+            &[],
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
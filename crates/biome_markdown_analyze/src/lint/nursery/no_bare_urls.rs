@@ -0,0 +1,209 @@
+use crate::MarkdownRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleAction, RuleDiagnostic, RuleSource, context::RuleContext,
+    declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_markdown_syntax::{
+    AnyMdCodeBlock, AnyMdInline, MarkdownSyntaxKind, MarkdownSyntaxToken, MdAutolink, MdHtmlBlock,
+    MdInlineCode, MdInlineHtml, MdInlineImage, MdInlineLink, MdLinkReferenceDefinition,
+    MdReferenceImage, MdReferenceLink, MdTextual,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange, TextSize};
+use biome_rule_options::no_bare_urls::NoBareUrlsOptions;
+
+declare_lint_rule! {
+    /// Disallow URLs that aren't written as links.
+    ///
+    /// CommonMark doesn't turn bare URLs into links, so depending on the renderer they
+    /// might be displayed as plain text. Wrap the URL in angle brackets to write an
+    /// autolink, or use a link with a description.
+    ///
+    /// Only the URLs that start with `http://` or `https://` are reported. URLs in
+    /// code spans, code blocks and HTML are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// Read the documentation at https://biomejs.dev for more details.
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// Read the documentation at <https://biomejs.dev> for more details.
+    /// ```
+    ///
+    /// ```md
+    /// Read the [documentation](https://biomejs.dev) for more details.
+    /// ```
+    ///
+    /// ```md
+    /// Run `curl https://biomejs.dev` to download the page.
+    /// ```
+    ///
+    pub NoBareUrls {
+        version: "next",
+        name: "noBareUrls",
+        language: "md",
+        recommended: true,
+        sources: &[RuleSource::MarkdownLint("md034", "no-bare-urls").same()],
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct BareUrl {
+    /// The range of the URL inside the document
+    range: TextRange,
+    /// Whether the URL ends inside the token, so it can be wrapped in angle brackets
+    can_wrap: bool,
+}
+
+impl Rule for NoBareUrls {
+    type Query = Ast<MdTextual>;
+    type State = BareUrl;
+    type Signals = Box<[Self::State]>;
+    type Options = NoBareUrlsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_inside_link_or_code(node) {
+            return Box::default();
+        }
+        let Ok(token) = node.value_token() else {
+            return Box::default();
+        };
+
+        let text = token.text_trimmed();
+        let start = token.text_trimmed_range().start();
+        let ends_line = ends_line(node);
+
+        find_urls(text)
+            .into_iter()
+            .map(|(url_start, url_end)| BareUrl {
+                range: TextRange::new(
+                    start + TextSize::from(url_start as u32),
+                    start + TextSize::from(url_end as u32),
+                ),
+                can_wrap: url_end < text.len() || ends_line,
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This URL isn't written as a link."
+                },
+            )
+            .note(markup! {
+                "Bare URLs aren't turned into links by all the renderers. Wrap the URL in angle brackets, or write a link with a description."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MarkdownRuleAction> {
+        if !state.can_wrap {
+            return None;
+        }
+        let token = ctx.query().value_token().ok()?;
+        let token_start = token.text_trimmed_range().start();
+        let url_start = usize::from(state.range.start() - token_start);
+        let url_end = usize::from(state.range.end() - token_start);
+        let text = token.text_trimmed();
+
+        let new_text = format!(
+            "{}<{}>{}",
+            &text[..url_start],
+            &text[url_start..url_end],
+            &text[url_end..]
+        );
+        let new_token = MarkdownSyntaxToken::new_detached(
+            MarkdownSyntaxKind::MD_TEXTUAL_LITERAL,
+            &new_text,
+            [],
+            [],
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token, new_token);
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            Applicability::MaybeIncorrect,
+            markup! { "Wrap the URL in angle brackets." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn is_inside_link_or_code(node: &MdTextual) -> bool {
+    node.syntax().ancestors().skip(1).any(|ancestor| {
+        let kind = ancestor.kind();
+        MdInlineLink::can_cast(kind)
+            || MdReferenceLink::can_cast(kind)
+            || MdAutolink::can_cast(kind)
+            || MdInlineImage::can_cast(kind)
+            || MdReferenceImage::can_cast(kind)
+            || MdInlineCode::can_cast(kind)
+            || MdInlineHtml::can_cast(kind)
+            || MdHtmlBlock::can_cast(kind)
+            || MdLinkReferenceDefinition::can_cast(kind)
+            || AnyMdCodeBlock::can_cast(kind)
+    })
+}
+
+/// Returns `true` when the textual node is the last content of its line.
+fn ends_line(node: &MdTextual) -> bool {
+    match node.syntax().next_sibling().and_then(AnyMdInline::cast) {
+        None | Some(AnyMdInline::MdHardLine(_)) => true,
+        Some(AnyMdInline::MdTextual(next)) => next.is_newline().unwrap_or(false),
+        Some(_) => false,
+    }
+}
+
+/// Returns the byte ranges of the `http://` and `https://` URLs of the text.
+///
+/// Trailing punctuation isn't considered part of the URL, so the period at the
+/// end of `See https://biomejs.dev.` is excluded.
+fn find_urls(text: &str) -> Vec<(usize, usize)> {
+    let mut urls = Vec::new();
+    let mut offset = 0;
+
+    while let Some(index) = text[offset..].find("http") {
+        let start = offset + index;
+        let rest = &text[start..];
+        let scheme_len = if rest.starts_with("https://") {
+            "https://".len()
+        } else if rest.starts_with("http://") {
+            "http://".len()
+        } else {
+            offset = start + "http".len();
+            continue;
+        };
+
+        let preceded_by_word = text[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '<' | '"' | '\'' | '/'));
+
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '`'))
+            .unwrap_or(rest.len());
+        let url = rest[..length].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']']);
+        let end = start + url.len();
+
+        if !preceded_by_word && url.len() > scheme_len {
+            urls.push((start, end));
+        }
+        offset = start + length.max(scheme_len);
+    }
+
+    urls
+}
@@ -0,0 +1,110 @@
+use crate::utils::AnyMdHeading;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_markdown_syntax::MdRoot;
+use biome_rowan::{AstNode, TextRange};
+use biome_rule_options::no_duplicate_headings::NoDuplicateHeadingsOptions;
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+
+declare_lint_rule! {
+    /// Disallow multiple headings with the same content.
+    ///
+    /// Headings with the same content are confusing for the readers of the document,
+    /// and generate anchors that can only be told apart by a numeric suffix.
+    ///
+    /// The content of the headings is compared after removing the inline markup,
+    /// so `## **Usage**` and `## Usage` are considered duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// # Changelog
+    ///
+    /// ## Features
+    ///
+    /// ## Features
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// # Changelog
+    ///
+    /// ## Features
+    ///
+    /// ## Bug fixes
+    /// ```
+    ///
+    pub NoDuplicateHeadings {
+        version: "next",
+        name: "noDuplicateHeadings",
+        language: "md",
+        recommended: false,
+        sources: &[RuleSource::MarkdownLint("md024", "no-duplicate-heading").same()],
+    }
+}
+
+pub struct DuplicateHeading {
+    /// The range of the duplicated heading
+    range: TextRange,
+    /// The range of the first heading with the same content
+    first_range: TextRange,
+}
+
+impl Rule for NoDuplicateHeadings {
+    type Query = Ast<MdRoot>;
+    type State = DuplicateHeading;
+    type Signals = Box<[Self::State]>;
+    type Options = NoDuplicateHeadingsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut seen: FxHashMap<String, TextRange> = FxHashMap::default();
+        let mut signals = Vec::new();
+
+        for heading in root.syntax().descendants().filter_map(AnyMdHeading::cast) {
+            let text = heading.text();
+            if text.is_empty() {
+                continue;
+            }
+            let range = heading.syntax().text_trimmed_range();
+            match seen.entry(text) {
+                Entry::Occupied(entry) => signals.push(DuplicateHeading {
+                    range,
+                    first_range: *entry.get(),
+                }),
+                Entry::Vacant(entry) => {
+                    entry.insert(range);
+                }
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This heading has the same content as a previous heading."
+                },
+            )
+            .detail(
+                state.first_range,
+                markup! {
+                    "The content was first used here."
+                },
+            )
+            .note(markup! {
+                "Give each heading a distinct content, so readers and links can tell the sections apart."
+            }),
+        )
+    }
+}
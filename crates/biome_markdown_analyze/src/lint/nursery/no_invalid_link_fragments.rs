@@ -0,0 +1,299 @@
+use crate::services::file_system::LinkedDocuments;
+use crate::utils::{AnyMdHeading, HeadingSlugger, inline_text};
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_fs::{FileSystem, normalize_path};
+use biome_languages::MdFileSource;
+use biome_markdown_parser::parse_markdown;
+use biome_markdown_syntax::{
+    MdHtmlBlock, MdInlineHtml, MdInlineItemList, MdInlineLink, MdLinkReferenceDefinition, MdRoot,
+};
+use biome_rowan::{AstNode, TextRange};
+use biome_rule_options::no_invalid_link_fragments::NoInvalidLinkFragmentsOptions;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+declare_lint_rule! {
+    /// Disallow links to fragments and Markdown files that don't exist.
+    ///
+    /// A link that starts with `#` points to a section of the same document.
+    /// The fragment must match the anchor generated for one of the headings of the
+    /// document, or the `id`/`name` attribute of an HTML element.
+    ///
+    /// Anchors are generated the same way GitHub does: the text of the heading is
+    /// lowercased, the punctuation is removed and the spaces are replaced with `-`.
+    /// Headings with the same text get a `-1`, `-2`, ... suffix. The `#top` fragment
+    /// is always valid.
+    ///
+    /// Relative links to other Markdown files, such as `./guide.md#install`, are
+    /// resolved from the directory of the document. The rule reports the links to
+    /// files that don't exist, and the fragments that don't match any heading of
+    /// the linked file. Absolute URLs, root-relative links such as `/docs/guide.md`,
+    /// and links to files that aren't Markdown documents aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// # Install
+    ///
+    /// See the [configuration](#configure) section.
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// # Install
+    ///
+    /// ## Configure the project
+    ///
+    /// See the [configuration](#configure-the-project) section.
+    /// ```
+    ///
+    pub NoInvalidLinkFragments {
+        version: "next",
+        name: "noInvalidLinkFragments",
+        language: "md",
+        recommended: true,
+        sources: &[RuleSource::MarkdownLint("md051", "link-fragments").same()],
+    }
+}
+
+pub enum InvalidLinkTarget {
+    /// The fragment doesn't match any anchor of the document.
+    MissingFragment {
+        range: TextRange,
+        fragment: Box<str>,
+    },
+    /// The fragment doesn't match any anchor of the linked document.
+    MissingFragmentInFile {
+        range: TextRange,
+        path: Box<str>,
+        fragment: Box<str>,
+    },
+    /// The linked document doesn't exist.
+    MissingFile { range: TextRange, path: Box<str> },
+}
+
+impl Rule for NoInvalidLinkFragments {
+    type Query = LinkedDocuments<MdRoot>;
+    type State = InvalidLinkTarget;
+    type Signals = Box<[Self::State]>;
+    type Options = NoInvalidLinkFragmentsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let anchors = collect_anchors(root);
+        let directory = ctx.file_path().parent().unwrap_or(Utf8Path::new(""));
+
+        // The anchors of the linked documents, or `None` when the document can't be read.
+        let mut linked_anchors: FxHashMap<Utf8PathBuf, Option<FxHashSet<String>>> =
+            FxHashMap::default();
+
+        let mut signals = Vec::new();
+        for node in root.syntax().descendants() {
+            let destination = if let Some(link) = MdInlineLink::cast_ref(&node) {
+                link.destination()
+            } else if let Some(definition) = MdLinkReferenceDefinition::cast_ref(&node) {
+                let Ok(destination) = definition.destination() else {
+                    continue;
+                };
+                destination.content()
+            } else {
+                continue;
+            };
+
+            let range = destination.syntax().text_trimmed_range();
+            let text = destination_text(&destination);
+            match LinkTarget::parse(&text) {
+                Some(LinkTarget::Fragment(fragment)) => {
+                    if !is_valid_fragment(fragment, &anchors) {
+                        signals.push(InvalidLinkTarget::MissingFragment {
+                            range,
+                            fragment: fragment.into(),
+                        });
+                    }
+                }
+                Some(LinkTarget::Document { path, fragment }) => {
+                    // Without a file system, the links to other documents can't be checked.
+                    let Some(file_system) = ctx.file_system() else {
+                        continue;
+                    };
+                    let document_path = normalize_path(&directory.join(path));
+                    let document_anchors = linked_anchors
+                        .entry(document_path)
+                        .or_insert_with_key(|path| read_anchors(file_system, path))
+                        .as_ref();
+
+                    match (document_anchors, fragment) {
+                        (None, _) => signals.push(InvalidLinkTarget::MissingFile {
+                            range,
+                            path: path.into(),
+                        }),
+                        (Some(document_anchors), Some(fragment))
+                            if !is_valid_fragment(fragment, document_anchors) =>
+                        {
+                            signals.push(InvalidLinkTarget::MissingFragmentInFile {
+                                range,
+                                path: path.into(),
+                                fragment: fragment.into(),
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                None => {}
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidLinkTarget::MissingFragment { range, fragment } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The fragment "<Emphasis>"#"{fragment.as_ref()}</Emphasis>" doesn't match any heading of this document."
+                },
+            )
+            .note(markup! {
+                "Links to missing fragments don't scroll to any section. Update the link, or the heading it points to."
+            }),
+            InvalidLinkTarget::MissingFragmentInFile {
+                range,
+                path,
+                fragment,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The fragment "<Emphasis>"#"{fragment.as_ref()}</Emphasis>" doesn't match any heading of "<Emphasis>{path.as_ref()}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Links to missing fragments don't scroll to any section. Update the link, or the heading it points to."
+            }),
+            InvalidLinkTarget::MissingFile { range, path } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The file "<Emphasis>{path.as_ref()}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "Links to missing files are broken. Update the link, or restore the file it points to."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+/// The target of a link that the rule can check.
+enum LinkTarget<'a> {
+    /// A fragment of the same document, such as `#install`.
+    Fragment(&'a str),
+    /// A relative link to a Markdown document, such as `./guide.md#install`.
+    Document {
+        path: &'a str,
+        fragment: Option<&'a str>,
+    },
+}
+
+impl<'a> LinkTarget<'a> {
+    fn parse(text: &'a str) -> Option<Self> {
+        if let Some(fragment) = text.strip_prefix('#') {
+            return Some(Self::Fragment(fragment));
+        }
+
+        // URLs point outside of the project, and root-relative links depend on where the
+        // documents are served from.
+        if text.starts_with('/') || has_scheme(text) {
+            return None;
+        }
+
+        let (path, fragment) = match text.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (text, None),
+        };
+        let path = path.split_once('?').map_or(path, |(path, _)| path);
+
+        // Percent-encoded paths would have to be decoded before being resolved.
+        if path.is_empty()
+            || path.contains('%')
+            || MdFileSource::try_from(Utf8Path::new(path)).is_err()
+        {
+            return None;
+        }
+
+        Some(Self::Document { path, fragment })
+    }
+}
+
+/// Returns whether the destination starts with a URL scheme, such as `https:` or `mailto:`.
+fn has_scheme(text: &str) -> bool {
+    text.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Returns the text of the destination, without the angle brackets that can wrap it.
+fn destination_text(destination: &MdInlineItemList) -> String {
+    let text = inline_text(destination);
+    let text = text.trim();
+    text.strip_prefix('<')
+        .and_then(|text| text.strip_suffix('>'))
+        .unwrap_or(text)
+        .to_string()
+}
+
+fn is_valid_fragment(fragment: &str, anchors: &FxHashSet<String>) -> bool {
+    fragment.is_empty() || fragment == "top" || anchors.contains(fragment)
+}
+
+/// Reads the document at the given path and collects its anchors.
+fn read_anchors(file_system: &dyn FileSystem, path: &Utf8Path) -> Option<FxHashSet<String>> {
+    let content = file_system.read_file_from_path(path).ok()?;
+    Some(collect_anchors(&parse_markdown(&content).tree()))
+}
+
+/// Collects the anchors generated by the headings and the HTML elements of the document.
+fn collect_anchors(root: &MdRoot) -> FxHashSet<String> {
+    let mut slugger = HeadingSlugger::default();
+    let mut anchors = FxHashSet::default();
+
+    for node in root.syntax().descendants() {
+        if let Some(heading) = AnyMdHeading::cast_ref(&node) {
+            anchors.insert(slugger.slug(&heading.text()));
+        } else if MdInlineHtml::can_cast(node.kind()) || MdHtmlBlock::can_cast(node.kind()) {
+            collect_html_anchors(&node.text_trimmed().to_string(), &mut anchors);
+        }
+    }
+
+    anchors
+}
+
+/// Collects the values of the `id` and `name` attributes of an HTML snippet.
+fn collect_html_anchors(html: &str, anchors: &mut FxHashSet<String>) {
+    for attribute in [" id=", " name="] {
+        let mut rest = html;
+        while let Some(index) = rest.find(attribute) {
+            rest = &rest[index + attribute.len()..];
+            let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                continue;
+            };
+            rest = &rest[1..];
+            if let Some(end) = rest.find(quote) {
+                anchors.insert(rest[..end].to_string());
+                rest = &rest[end + 1..];
+            }
+        }
+    }
+}
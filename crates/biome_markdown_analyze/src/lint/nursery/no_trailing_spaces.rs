@@ -0,0 +1,144 @@
+use crate::MarkdownRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleAction, RuleDiagnostic, RuleSource, context::RuleContext,
+    declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_markdown_syntax::{AnyMdCodeBlock, MarkdownSyntaxKind, MarkdownSyntaxToken, MdRoot};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange, TextSize};
+use biome_rule_options::no_trailing_spaces::NoTrailingSpacesOptions;
+
+declare_lint_rule! {
+    /// Disallow trailing whitespace at the end of lines.
+    ///
+    /// Trailing whitespace isn't visible in the rendered document, and it's easy to add
+    /// by mistake. The only exception are exactly two trailing spaces after some text,
+    /// which CommonMark renders as a hard line break.
+    ///
+    /// The content of code blocks isn't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    #[doc = " Some text followed by a tab\t"]
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// Some text
+    /// ```
+    ///
+    pub NoTrailingSpaces {
+        version: "next",
+        name: "noTrailingSpaces",
+        language: "md",
+        recommended: true,
+        sources: &[RuleSource::MarkdownLint("md009", "no-trailing-spaces").same()],
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for NoTrailingSpaces {
+    type Query = Ast<MdRoot>;
+    type State = TextRange;
+    type Signals = Box<[Self::State]>;
+    type Options = NoTrailingSpacesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut ignored_ranges: Vec<TextRange> = root
+            .syntax()
+            .descendants()
+            .filter_map(AnyMdCodeBlock::cast)
+            .map(|block| match block {
+                AnyMdCodeBlock::MdFencedCodeBlock(block) => block.content().syntax().text_range(),
+                AnyMdCodeBlock::MdIndentCodeBlock(block) => block.content().syntax().text_range(),
+            })
+            .collect();
+        if let Some(frontmatter) = root.frontmatter() {
+            ignored_ranges.push(frontmatter.syntax().text_range());
+        }
+
+        let text = root.syntax().text_with_trivia().to_string();
+        let mut signals = Vec::new();
+        let mut line_start = 0;
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            let trimmed = content.trim_end_matches([' ', '\t']);
+            let whitespace = &content[trimmed.len()..];
+            let is_hard_break = !trimmed.trim().is_empty() && whitespace == "  ";
+
+            if !whitespace.is_empty() && !is_hard_break {
+                let start = root.syntax().text_range().start()
+                    + TextSize::from((line_start + trimmed.len()) as u32);
+                let range = TextRange::at(start, TextSize::from(whitespace.len() as u32));
+                if !ignored_ranges
+                    .iter()
+                    .any(|ignored| ignored.contains(range.start()))
+                {
+                    signals.push(range);
+                }
+            }
+
+            line_start += line.len();
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *state,
+                markup! {
+                    "This line has trailing whitespace."
+                },
+            )
+            .note(markup! {
+                "Remove the whitespace. Use exactly two spaces, or a backslash, when you want a hard line break."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MarkdownRuleAction> {
+        let token = ctx
+            .root()
+            .syntax()
+            .token_at_offset(state.start())
+            .right_biased()?;
+        let token_range = token.text_trimmed_range();
+        if !token_range.contains_range(*state) {
+            return None;
+        }
+
+        let text = token.text_trimmed();
+        let start = usize::from(state.start() - token_range.start());
+        let end = usize::from(state.end() - token_range.start());
+        // Keeps the hard line break when the line ends with more than two spaces
+        let replacement = if token.kind() == MarkdownSyntaxKind::MD_HARD_LINE_LITERAL {
+            "  "
+        } else {
+            ""
+        };
+        let new_text = format!("{}{replacement}{}", &text[..start], &text[end..]);
+        if new_text.is_empty() {
+            return None;
+        }
+        let new_token = MarkdownSyntaxToken::new_detached(token.kind(), &new_text, [], []);
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token, new_token);
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            Applicability::Always,
+            markup! { "Remove the trailing whitespace." }.to_owned(),
+            mutation,
+        ))
+    }
+}
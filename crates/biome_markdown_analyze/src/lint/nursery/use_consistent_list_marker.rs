@@ -0,0 +1,132 @@
+use crate::MarkdownRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleAction, RuleDiagnostic, RuleSource, context::RuleContext,
+    declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_markdown_factory::make::token;
+use biome_markdown_syntax::list_ext::ListMarker;
+use biome_markdown_syntax::{MarkdownSyntaxToken, MdListMarkerPrefix, MdRoot, T};
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_rule_options::use_consistent_list_marker::UseConsistentListMarkerOptions;
+
+declare_lint_rule! {
+    /// Enforce the same marker for all the unordered lists of a document.
+    ///
+    /// Unordered list items can be introduced by `-`, `*` or `+`. Mixing them in the
+    /// same document makes the source harder to read, and adjacent items with different
+    /// markers are rendered as separate lists.
+    ///
+    /// The marker of the first unordered list item of the document is the one expected
+    /// for all the other items.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// - Apples
+    /// - Oranges
+    ///
+    /// * Bananas
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// - Apples
+    /// - Oranges
+    ///
+    /// - Bananas
+    /// ```
+    ///
+    pub UseConsistentListMarker {
+        version: "next",
+        name: "useConsistentListMarker",
+        language: "md",
+        recommended: false,
+        sources: &[RuleSource::MarkdownLint("md004", "ul-style").same()],
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct InconsistentListMarker {
+    marker: MarkdownSyntaxToken,
+    expected: ListMarker,
+}
+
+impl Rule for UseConsistentListMarker {
+    type Query = Ast<MdRoot>;
+    type State = InconsistentListMarker;
+    type Signals = Box<[Self::State]>;
+    type Options = UseConsistentListMarkerOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut expected = None;
+        let mut signals = Vec::new();
+
+        for prefix in root
+            .syntax()
+            .descendants()
+            .filter_map(MdListMarkerPrefix::cast)
+        {
+            let Ok(list_marker) = prefix.list_marker() else {
+                continue;
+            };
+            if list_marker.unordered_marker_text().is_none() {
+                continue;
+            }
+            let Ok(marker) = prefix.marker() else {
+                continue;
+            };
+
+            match expected {
+                None => expected = Some(list_marker),
+                Some(expected) if expected != list_marker => {
+                    signals.push(InconsistentListMarker { marker, expected });
+                }
+                Some(_) => {}
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let expected = state.expected.unordered_marker_text()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.marker.text_trimmed_range(),
+                markup! {
+                    "This list item uses a different marker than the rest of the document."
+                },
+            )
+            .note(markup! {
+                "The first unordered list of the document uses "<Emphasis>{expected}</Emphasis>". Use the same marker for all the unordered lists."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MarkdownRuleAction> {
+        let kind = match state.expected {
+            ListMarker::Minus => T![-],
+            ListMarker::Star => T![*],
+            ListMarker::Plus => T![+],
+            _ => return None,
+        };
+        let expected = state.expected.unordered_marker_text()?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(state.marker.clone(), token(kind));
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>{expected}</Emphasis>" as list marker." }.to_owned(),
+            mutation,
+        ))
+    }
+}
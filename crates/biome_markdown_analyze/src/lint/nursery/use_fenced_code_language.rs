@@ -0,0 +1,73 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_markdown_syntax::MdFencedCodeBlock;
+use biome_rowan::{AstNodeList, TextRange};
+use biome_rule_options::use_fenced_code_language::UseFencedCodeLanguageOptions;
+
+declare_lint_rule! {
+    /// Require a language for fenced code blocks.
+    ///
+    /// The language written after the opening fence is used by editors and renderers
+    /// to highlight the code, and by tools to format or check the code of the block.
+    ///
+    /// Use `text` for blocks that don't contain code.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ````md,expect_diagnostic
+    /// ```
+    /// const answer = 42;
+    /// ```
+    /// ````
+    ///
+    /// ### Valid
+    ///
+    /// ````md
+    /// ```js
+    /// const answer = 42;
+    /// ```
+    /// ````
+    ///
+    pub UseFencedCodeLanguage {
+        version: "next",
+        name: "useFencedCodeLanguage",
+        language: "md",
+        recommended: true,
+        sources: &[RuleSource::MarkdownLint("md040", "fenced-code-language").same()],
+    }
+}
+
+impl Rule for UseFencedCodeLanguage {
+    type Query = Ast<MdFencedCodeBlock>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = UseFencedCodeLanguageOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.code_list().is_empty() {
+            Some(node.l_fence().ok()?.text_trimmed_range())
+        } else {
+            None
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *state,
+                markup! {
+                    "This fenced code block doesn't specify a language."
+                },
+            )
+            .note(markup! {
+                "Add the language after the opening fence, for example "<Emphasis>"```js"</Emphasis>". Use "<Emphasis>"text"</Emphasis>" when the block doesn't contain code."
+            }),
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use crate::utils::inline_text;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_markdown_syntax::{MdInlineImage, MdInlineItemList, MdReferenceImage};
+use biome_rowan::{AstNode, declare_node_union};
+use biome_rule_options::use_image_alt_text::UseImageAltTextOptions;
+
+declare_lint_rule! {
+    /// Require an alternative text for images.
+    ///
+    /// The alternative text is read by screen readers, and displayed when the image
+    /// can't be loaded. Images without it are inaccessible to visually impaired users.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// ![](./logo.svg)
+    /// ```
+    ///
+    /// ```md,expect_diagnostic
+    /// ![][logo]
+    ///
+    /// [logo]: ./logo.svg
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// ![The Biome logo](./logo.svg)
+    /// ```
+    ///
+    pub UseImageAltText {
+        version: "next",
+        name: "useImageAltText",
+        language: "md",
+        recommended: true,
+        sources: &[RuleSource::MarkdownLint("md045", "no-alt-text").same()],
+    }
+}
+
+declare_node_union! {
+    pub AnyMdImage = MdInlineImage | MdReferenceImage
+}
+
+impl AnyMdImage {
+    fn alt(&self) -> MdInlineItemList {
+        match self {
+            Self::MdInlineImage(image) => image.alt(),
+            Self::MdReferenceImage(image) => image.alt(),
+        }
+    }
+}
+
+impl Rule for UseImageAltText {
+    type Query = Ast<AnyMdImage>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = UseImageAltTextOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let image = ctx.query();
+        inline_text(&image.alt()).trim().is_empty().then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let image = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                image.range(),
+                markup! {
+                    "This image doesn't have an alternative text."
+                },
+            )
+            .note(markup! {
+                "Describe the content of the image between the brackets, for example "<Emphasis>"![A screenshot of the settings](./settings.png)"</Emphasis>"."
+            }),
+        )
+    }
+}
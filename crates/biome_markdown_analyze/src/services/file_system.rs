@@ -0,0 +1,68 @@
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_fs::FileSystem;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdRoot};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+/// Service that provides access to the file system for Markdown rules.
+///
+/// This allows rules to read the documents that the links of a file point to.
+pub struct FileSystemService(Option<Arc<dyn FileSystem>>);
+
+impl FileSystemService {
+    /// Returns a reference to the file system, if available.
+    pub fn file_system(&self) -> Option<&dyn FileSystem> {
+        self.0.as_deref()
+    }
+}
+
+impl FromServices for FileSystemService {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> Result<Self, ServicesDiagnostic> {
+        let file_system: &Option<Arc<dyn FileSystem>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["FileSystem"]))?;
+
+        Ok(Self(file_system.clone()))
+    }
+}
+
+impl Phase for FileSystemService {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that need to read other documents** and
+/// matches on specific [AstNode] types.
+#[derive(Clone)]
+pub struct LinkedDocuments<N>(pub N);
+
+impl<N> Queryable for LinkedDocuments<N>
+where
+    N: AstNode<Language = MarkdownLanguage> + 'static,
+{
+    type Input = MarkdownSyntaxNode;
+    type Output = N;
+
+    type Language = MarkdownLanguage;
+    type Services = FileSystemService;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<MarkdownLanguage>, _: &MdRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -0,0 +1,120 @@
+use biome_markdown_syntax::{AnyMdInline, MdHeader, MdInlineItemList, MdSetextHeader};
+use biome_rowan::{AstNodeList, declare_node_union};
+use rustc_hash::FxHashMap;
+
+declare_node_union! {
+    pub AnyMdHeading = MdHeader | MdSetextHeader
+}
+
+impl AnyMdHeading {
+    /// The plain text of the heading, with inline markup removed and
+    /// whitespace collapsed.
+    ///
+    /// For example, the text of `## Hello **world**` is `Hello world`.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        match self {
+            Self::MdHeader(header) => {
+                if let Some(content) = header.content() {
+                    push_inline_text(&content.list(), &mut text);
+                }
+            }
+            Self::MdSetextHeader(header) => push_inline_text(&header.content(), &mut text),
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Returns the plain text of an inline list, with inline markup removed.
+pub fn inline_text(list: &MdInlineItemList) -> String {
+    let mut text = String::new();
+    push_inline_text(list, &mut text);
+    text
+}
+
+fn push_inline_text(list: &MdInlineItemList, text: &mut String) {
+    for item in list.iter() {
+        match item {
+            AnyMdInline::MdTextual(node) => {
+                if let Ok(token) = node.value_token() {
+                    text.push_str(token.text_trimmed());
+                }
+            }
+            AnyMdInline::MdEntityReference(node) => {
+                if let Ok(token) = node.value_token() {
+                    text.push_str(token.text_trimmed());
+                }
+            }
+            AnyMdInline::MdHardLine(_) => text.push(' '),
+            AnyMdInline::MdAutolink(node) => push_inline_text(&node.value(), text),
+            AnyMdInline::MdInlineCode(node) => push_inline_text(&node.content(), text),
+            AnyMdInline::MdInlineEmphasis(node) => push_inline_text(&node.content(), text),
+            AnyMdInline::MdInlineItalic(node) => push_inline_text(&node.content(), text),
+            AnyMdInline::MdInlineLink(node) => push_inline_text(&node.text(), text),
+            AnyMdInline::MdReferenceLink(node) => push_inline_text(&node.text(), text),
+            AnyMdInline::MdInlineImage(node) => push_inline_text(&node.alt(), text),
+            AnyMdInline::MdReferenceImage(node) => push_inline_text(&node.alt(), text),
+            AnyMdInline::MdCodeContent(_)
+            | AnyMdInline::MdHtmlBlock(_)
+            | AnyMdInline::MdIndentToken(_)
+            | AnyMdInline::MdInlineHtml(_)
+            | AnyMdInline::MdQuotePrefix(_) => {}
+        }
+    }
+}
+
+/// Generates the anchors of the headings of a document, the same way GitHub does.
+///
+/// The text of the heading is lowercased, the punctuation is removed and the
+/// spaces are replaced with `-`. When the same anchor was already generated,
+/// a `-1`, `-2`, ... suffix is appended.
+#[derive(Debug, Default)]
+pub struct HeadingSlugger {
+    occurrences: FxHashMap<String, usize>,
+}
+
+impl HeadingSlugger {
+    pub fn slug(&mut self, text: &str) -> String {
+        let base: String = text
+            .trim()
+            .chars()
+            .filter_map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '_' {
+                    Some(c.to_lowercase().collect::<String>())
+                } else if c == ' ' {
+                    Some(String::from("-"))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let count = self.occurrences.entry(base.clone()).or_default();
+        let slug = if *count == 0 {
+            base
+        } else {
+            format!("{base}-{count}")
+        };
+        *count += 1;
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeadingSlugger;
+
+    #[test]
+    fn slugs_like_github() {
+        let mut slugger = HeadingSlugger::default();
+        assert_eq!(slugger.slug("Hello World"), "hello-world");
+        assert_eq!(slugger.slug("What's new?"), "whats-new");
+        assert_eq!(slugger.slug("`biome check` options"), "biome-check-options");
+        assert_eq!(
+            slugger.slug("snake_case and kebab-case"),
+            "snake_case-and-kebab-case"
+        );
+        assert_eq!(slugger.slug("Hello World"), "hello-world-1");
+        assert_eq!(slugger.slug("Hello World"), "hello-world-2");
+    }
+}
@@ -5,7 +5,7 @@ use biome_diagnostics::{Diagnostic, DiagnosticExt, Severity, print_diagnostic_to
 use biome_markdown_parser::parse_markdown;
 use std::slice;
 
-use biome_markdown_analyze::analyze;
+use biome_markdown_analyze::{MarkdownAnalyzerServices, analyze};
 use biome_rowan::TextRange;
 
 // use this test check if your snippet produces the diagnostics you wish, without using a snapshot
@@ -29,6 +29,8 @@ d { font: 1em SF Mono, Liberation Mono, sans-serif; }
             ..AnalysisFilter::default()
         },
         &options,
+        MarkdownAnalyzerServices::default(),
+        &[],
         |signal| {
             if let Some(diag) = signal.diagnostic() {
                 error_ranges.push(diag.location().span.unwrap());
@@ -3,6 +3,8 @@ use biome_analyze::{
     Rule, RuleDomain, RuleFilter, RuleGroup,
 };
 use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_fs::OsFileSystem;
+use biome_markdown_analyze::MarkdownAnalyzerServices;
 use biome_markdown_parser::parse_markdown;
 use biome_markdown_syntax::MarkdownLanguage;
 use biome_rowan::AstNode;
@@ -14,6 +16,7 @@ use biome_test_utils::{
 };
 use camino::Utf8Path;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{md,json,jsonc}", crate::run_test, "module"}
@@ -142,38 +145,42 @@ pub(crate) fn analyze_and_snap(
 
     let needs_module_graph = NeedsModuleGraph::new(filter.enabled_rules).compute();
 
-    let (_, errors) = biome_markdown_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions(ActionFilter::all()) {
-                if check_action_type.is_suppression() {
-                    if action.is_suppression() {
+    let md_services = MarkdownAnalyzerServices::default()
+        .with_file_system(Arc::new(OsFileSystem::new(working_directory.to_path_buf())));
+
+    let (_, errors) =
+        biome_markdown_analyze::analyze(&root, filter, &options, md_services, &[], |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions(ActionFilter::all()) {
+                    if check_action_type.is_suppression() {
+                        if action.is_suppression() {
+                            check_code_action(input_file, input_code, &action);
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(input_file, input_code, &action);
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
-            return ControlFlow::Continue(());
-        }
+                diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions(ActionFilter::all()) {
-            if check_action_type.is_suppression() {
-                if action.category.matches("quickfix.suppressRule") {
+            for action in event.actions(ActionFilter::all()) {
+                if check_action_type.is_suppression() {
+                    if action.category.matches("quickfix.suppressRule") {
+                        check_code_action(input_file, input_code, &action);
+                        code_fixes.push(code_fix_to_string(input_code, action));
+                    }
+                } else if !action.category.matches("quickfix.suppressRule") {
                     check_code_action(input_file, input_code, &action);
                     code_fixes.push(code_fix_to_string(input_code, action));
                 }
-            } else if !action.category.matches("quickfix.suppressRule") {
-                check_code_action(input_file, input_code, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
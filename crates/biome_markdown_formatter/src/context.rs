@@ -5,6 +5,7 @@ use biome_formatter::{
     LineWidth, TrailingNewline, TransformSourceMap, comments::Comments, printer::PrinterOptions,
 };
 use biome_markdown_syntax::MarkdownLanguage;
+use biome_rowan::TextRange;
 use std::{fmt, rc::Rc, str::FromStr};
 
 pub type MarkdownComments = Comments<MarkdownLanguage>;
@@ -14,6 +15,11 @@ pub struct MarkdownFormatContext {
     source_map: Option<TransformSourceMap>,
     options: MdFormatOptions,
     comments: Rc<MarkdownComments>,
+
+    /// Ranges of fenced code blocks whose content was parsed as an embedded
+    /// language. Formatting them is delegated to the corresponding language
+    /// formatter, see [crate::format_node].
+    embedded_node_ranges: Vec<TextRange>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -159,6 +165,7 @@ impl MarkdownFormatContext {
             options,
             comments: Rc::new(MarkdownComments::default()),
             source_map: None,
+            embedded_node_ranges: Vec::new(),
         }
     }
 
@@ -167,9 +174,21 @@ impl MarkdownFormatContext {
         self
     }
 
+    pub fn with_embedded_node_ranges(mut self, embedded_node_ranges: Vec<TextRange>) -> Self {
+        self.embedded_node_ranges = embedded_node_ranges;
+        self
+    }
+
     pub fn comments(&self) -> &MarkdownComments {
         &self.comments
     }
+
+    /// Ranges of fenced code blocks whose content was parsed as an embedded
+    /// language, and whose formatting is therefore delegated to the
+    /// corresponding language formatter.
+    pub fn embedded_node_ranges(&self) -> &[TextRange] {
+        &self.embedded_node_ranges
+    }
 }
 
 impl FormatContext for MarkdownFormatContext {
@@ -19,18 +19,30 @@ use biome_formatter::{
     FormatContext, FormatLanguage, FormatResult, Formatted, TransformSourceMap, prelude::*, write,
 };
 use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode};
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, TextRange};
 
 pub(crate) type MarkdownFormatter<'buf> = Formatter<'buf, MarkdownFormatContext>;
 
 #[derive(Debug, Clone, Default)]
 pub struct MdFormatLanguage {
     options: MdFormatOptions,
+    /// Ranges of fenced code blocks whose content was parsed as an embedded
+    /// language. Formatting them is delegated to the corresponding language
+    /// formatter, see [crate::format_node].
+    embedded_node_ranges: Vec<TextRange>,
 }
 
 impl MdFormatLanguage {
     pub fn new(options: MdFormatOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            embedded_node_ranges: Vec::new(),
+        }
+    }
+
+    pub fn with_embedded_node_ranges(mut self, embedded_node_ranges: Vec<TextRange>) -> Self {
+        self.embedded_node_ranges = embedded_node_ranges;
+        self
     }
 }
 
@@ -43,9 +55,13 @@ impl FormatLanguage for MdFormatLanguage {
         self,
         _root: &MarkdownSyntaxNode,
         source_map: Option<TransformSourceMap>,
-        _delegate_fmt_embedded_nodes: bool,
+        delegate_fmt_embedded_nodes: bool,
     ) -> MarkdownFormatContext {
-        MarkdownFormatContext::new(self.options.clone()).with_source_map(source_map)
+        let mut ctx = MarkdownFormatContext::new(self.options.clone()).with_source_map(source_map);
+        if delegate_fmt_embedded_nodes {
+            ctx = ctx.with_embedded_node_ranges(self.embedded_node_ranges);
+        }
+        ctx
     }
 
     fn options(&self) -> &<Self::Context as FormatContext>::Options {
@@ -254,9 +270,21 @@ where
 }
 
 /// Main entry point for formatting a Markdown file
+///
+/// `embedded_node_ranges` contains the ranges of fenced code blocks that were
+/// parsed as embedded languages. Their content isn't formatted; instead, the
+/// formatter emits `StartEmbedded`/`EndEmbedded` tags that the caller must
+/// resolve via [Formatted::format_embedded]. Pass an empty [Vec] when
+/// embedded snippets aren't formatted separately.
 pub fn format_node(
     options: MdFormatOptions,
     root: &MarkdownSyntaxNode,
+    embedded_node_ranges: Vec<TextRange>,
 ) -> FormatResult<Formatted<MarkdownFormatContext>> {
-    biome_formatter::format_node(root, MdFormatLanguage::new(options), false)
+    let delegate_fmt_embedded_nodes = !embedded_node_ranges.is_empty();
+    biome_formatter::format_node(
+        root,
+        MdFormatLanguage::new(options).with_embedded_node_ranges(embedded_node_ranges),
+        delegate_fmt_embedded_nodes,
+    )
 }
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use biome_formatter::{FormatRuleWithOptions, write};
-use biome_markdown_syntax::{MdCodeContent, MdCodeContentFields};
-use biome_rowan::TextSize;
+use biome_markdown_syntax::{MarkdownSyntaxToken, MdCodeContent, MdCodeContentFields};
+use biome_rowan::{TextRange, TextSize};
 use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default)]
@@ -13,6 +13,17 @@ impl FormatNodeRule<MdCodeContent> for FormatMdCodeContent {
         let MdCodeContentFields { value_token } = node.as_fields();
         let value_token = value_token?;
 
+        if let Some(range) = embedded_content_range(&value_token, f) {
+            // The content is formatted later on by the formatter of the embedded
+            // language, so the token is tracked even though it isn't formatted
+            // during this pass.
+            f.state_mut().track_token(&value_token);
+            return f.write_elements(vec![
+                FormatElement::Tag(Tag::StartEmbedded(range)),
+                FormatElement::Tag(Tag::EndEmbedded),
+            ]);
+        }
+
         // The literal starts with the newline that ends the opening-fence
         // line. The fenced code block formatter writes that line break and
         // normalizes the optional fence indentation separately.
@@ -29,11 +40,7 @@ impl FormatNodeRule<MdCodeContent> for FormatMdCodeContent {
                 .with_literal_line_breaks()
                 .fmt(f)
             };
-            let mut line_start = match bytes {
-                [b'\r', b'\n', ..] => 2,
-                [b'\r' | b'\n', ..] => 1,
-                _ => 0,
-            };
+            let mut line_start = leading_line_break_len(text);
 
             while line_start < bytes.len() {
                 let mut content_start = line_start;
@@ -84,6 +91,37 @@ impl FormatNodeRule<MdCodeContent> for FormatMdCodeContent {
     }
 }
 
+/// Returns the range of the code when it was parsed as an embedded language.
+///
+/// The range excludes the line break that ends the opening-fence line, and
+/// matches the ranges registered by the embedding service.
+fn embedded_content_range(
+    value_token: &MarkdownSyntaxToken,
+    f: &MarkdownFormatter,
+) -> Option<TextRange> {
+    let embedded_node_ranges = f.context().embedded_node_ranges();
+    if embedded_node_ranges.is_empty() {
+        return None;
+    }
+
+    let token_range = value_token.text_range();
+    let leading_line_break = TextSize::from(leading_line_break_len(value_token.text()) as u32);
+    let range = TextRange::new(token_range.start() + leading_line_break, token_range.end());
+
+    // Only ranges that were registered by the embedding service are delegated.
+    // Marking any other block would emit StartEmbedded/EndEmbedded tags that
+    // never get resolved, losing the block's content.
+    embedded_node_ranges.contains(&range).then_some(range)
+}
+
+fn leading_line_break_len(text: &str) -> usize {
+    match text.as_bytes() {
+        [b'\r', b'\n', ..] => 2,
+        [b'\r' | b'\n', ..] => 1,
+        _ => 0,
+    }
+}
+
 pub(crate) struct FormatMdCodeContentOptions {
     pub(crate) opening_fence_indent: usize,
 }
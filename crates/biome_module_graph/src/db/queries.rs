@@ -10,6 +10,7 @@
 
 mod css;
 mod js_scc;
+mod js_usage;
 mod type_inference;
 
 use crate::{JsExport, JsExportedSymbolLookup, JsOwnExport, ModuleDb, ModuleInfo, ModuleInfoKind};
@@ -19,6 +20,7 @@ use biome_jsdoc_comment::JsdocComment;
 pub use crate::db::type_inference::InferredModuleTypes;
 pub use css::*;
 pub use js_scc::*;
+pub use js_usage::*;
 pub use type_inference::*;
 
 // #region EXPORTED TRACKED QUERIES
@@ -0,0 +1,557 @@
+use crate::{JsExport, JsImport, JsImportPath, ModuleDb, ModuleGraphGeneration, ModuleInfoKind};
+use biome_fs::{is_node_modules_path, normalize_path};
+use biome_js_type_info::ImportSymbol;
+use biome_project_layout::ProjectLayout;
+use biome_resolver::{is_builtin_node_module, is_relative_specifier};
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Entry points of a package, usually taken from the `main`, `exports` and
+/// `bin` fields of its `package.json`.
+///
+/// The paths are absolute, and they're expected to be sorted so that the same
+/// set of entry points is interned only once.
+#[salsa::interned]
+pub struct JsEntryPoints {
+    #[returns(ref)]
+    pub(crate) paths: Vec<Utf8PathBuf>,
+}
+
+/// Returns the entry points of the package that contains `path`, as declared
+/// by the `main`, `exports` and `bin` fields of its `package.json`.
+///
+/// Only the entry points that are part of the module graph are returned.
+/// Returns `None` when the package doesn't declare any of them.
+pub fn js_entry_points_for_path<'db>(
+    db: &'db dyn ModuleDb,
+    project_layout: &ProjectLayout,
+    path: &Utf8Path,
+) -> Option<JsEntryPoints<'db>> {
+    let (package_path, manifest) = project_layout.find_node_manifest_for_path(path)?;
+    let mut paths: Vec<Utf8PathBuf> = manifest
+        .entry_points()
+        .into_iter()
+        .map(|entry_point| normalize_path(&package_path.join(entry_point)))
+        .filter(|entry_point| db.contains(entry_point))
+        .collect();
+    if paths.is_empty() {
+        return None;
+    }
+    paths.sort_unstable();
+    paths.dedup();
+    Some(JsEntryPoints::new(db, paths))
+}
+
+/// Usage of the JavaScript modules of the project, as seen from a set of
+/// entry points.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct JsProjectUsage {
+    /// Modules that are imported, directly or transitively, by an entry point.
+    reachable_paths: FxHashSet<Utf8PathBuf>,
+
+    /// Exports of each module that are imported by another module, or that
+    /// belong to the public API of the package.
+    used_exports: FxHashMap<Utf8PathBuf, JsUsedExports>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum JsUsedExports {
+    /// All the exports are used, for instance because the module is imported
+    /// as a namespace or dynamically.
+    All,
+    Named(FxHashSet<String>),
+}
+
+/// A module export that is marked as used while computing [JsProjectUsage].
+enum JsExportUsage {
+    All,
+    Named(String),
+}
+
+impl JsExportUsage {
+    fn from_import(import: &JsImport) -> Self {
+        match &import.symbol {
+            ImportSymbol::All => Self::All,
+            ImportSymbol::Default => Self::Named("default".to_string()),
+            ImportSymbol::Named(name) => Self::Named(name.text().to_string()),
+        }
+    }
+}
+
+impl JsProjectUsage {
+    /// Returns whether the module at `path` is an entry point, or is imported
+    /// by one, directly or transitively.
+    pub fn is_reachable(&self, path: &Utf8Path) -> bool {
+        self.reachable_paths.contains(path)
+    }
+
+    /// Returns whether the export called `name` of the module at `path` is
+    /// imported by another module, or is exported by an entry point.
+    pub fn is_export_used(&self, path: &Utf8Path, name: &str) -> bool {
+        match self.used_exports.get(path) {
+            Some(JsUsedExports::All) => true,
+            Some(JsUsedExports::Named(names)) => names.contains(name),
+            None => false,
+        }
+    }
+
+    /// Marks `usage` as used in the module at `path`.
+    ///
+    /// Returns `false` when the export was already marked as used.
+    fn mark_used(&mut self, path: &Utf8Path, usage: &JsExportUsage) -> bool {
+        match (self.used_exports.get_mut(path), usage) {
+            (Some(JsUsedExports::All), _) => false,
+            (Some(used_exports), JsExportUsage::All) => {
+                *used_exports = JsUsedExports::All;
+                true
+            }
+            (Some(JsUsedExports::Named(names)), JsExportUsage::Named(name)) => {
+                names.insert(name.clone())
+            }
+            (None, JsExportUsage::All) => {
+                self.used_exports
+                    .insert(path.to_path_buf(), JsUsedExports::All);
+                true
+            }
+            (None, JsExportUsage::Named(name)) => {
+                self.used_exports.insert(
+                    path.to_path_buf(),
+                    JsUsedExports::Named(FxHashSet::from_iter([name.clone()])),
+                );
+                true
+            }
+        }
+    }
+}
+
+/// Returns which modules are reachable from the given `entry_points`, and
+/// which of their exports are used.
+///
+/// Modules inside `node_modules` aren't tracked. Re-exports are followed, so an
+/// export that is only re-exported by modules whose export is unused is
+/// reported as unused too.
+#[salsa::tracked(no_eq, returns(ref))]
+pub fn js_project_usage<'db>(
+    db: &'db dyn ModuleDb,
+    entry_points: JsEntryPoints<'db>,
+) -> JsProjectUsage {
+    let _ = ModuleGraphGeneration::get(db).value(db);
+
+    let mut modules = FxHashMap::default();
+    db.for_each_module(&mut |module| {
+        let path = module.path(db);
+        if let ModuleInfoKind::Js(module_info) = module.kind(db)
+            && !is_node_modules_path(path)
+        {
+            modules.insert(path.to_path_buf(), module_info.clone());
+        }
+    });
+
+    let mut usage = JsProjectUsage::default();
+
+    // Every module that is reached by following the imports of an entry point.
+    let mut queue: Vec<&Utf8Path> = entry_points
+        .paths(db)
+        .iter()
+        .map(Utf8PathBuf::as_path)
+        .filter(|path| modules.contains_key(*path))
+        .collect();
+    while let Some(path) = queue.pop() {
+        if !usage.reachable_paths.insert(path.to_path_buf()) {
+            continue;
+        }
+
+        let module_info = &modules[path];
+        let reexport_paths = module_info
+            .exports
+            .values()
+            .filter_map(|export| match export {
+                JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) => {
+                    reexport.import.resolved_path.as_path()
+                }
+                JsExport::Own(_) | JsExport::OwnType(_) => None,
+            })
+            .chain(
+                module_info
+                    .blanket_reexports
+                    .iter()
+                    .filter_map(|reexport| reexport.import.resolved_path.as_path()),
+            );
+        for dependency in module_info
+            .import_paths
+            .iter()
+            .filter_map(JsImportPath::as_path)
+            .chain(reexport_paths)
+        {
+            if let Some((dependency, _)) = modules.get_key_value(dependency) {
+                queue.push(dependency);
+            }
+        }
+    }
+
+    // Exports that are imported by a module, or exported by an entry point.
+    let mut pending = Vec::new();
+    for (path, module_info) in &modules {
+        for import in module_info.static_imports.values() {
+            if let Some(target) = import.resolved_path.as_path()
+                && target != path
+            {
+                pending.push((target, JsExportUsage::from_import(import)));
+            }
+        }
+        for JsImportPath {
+            resolved_path,
+            kind,
+            ..
+        } in module_info.import_paths.iter()
+        {
+            if kind.is_dynamic()
+                && let Some(target) = resolved_path.as_path()
+                && target != path
+            {
+                pending.push((target, JsExportUsage::All));
+            }
+        }
+    }
+    pending.extend(
+        entry_points
+            .paths(db)
+            .iter()
+            .map(|path| (path.as_path(), JsExportUsage::All)),
+    );
+
+    while let Some((path, export_usage)) = pending.pop() {
+        let Some(module_info) = modules.get(path) else {
+            continue;
+        };
+        if !usage.mark_used(path, &export_usage) {
+            continue;
+        }
+
+        match export_usage {
+            JsExportUsage::All => {
+                for export in module_info.exports.values() {
+                    push_reexport_usage(export, &mut pending);
+                }
+                for reexport in &module_info.blanket_reexports {
+                    if let Some(target) = reexport.import.resolved_path.as_path() {
+                        pending.push((target, JsExportUsage::All));
+                    }
+                }
+            }
+            JsExportUsage::Named(name) => match module_info.exports.get(name.as_str()) {
+                Some(export) => push_reexport_usage(export, &mut pending),
+                None => {
+                    for reexport in &module_info.blanket_reexports {
+                        if let Some(target) = reexport.import.resolved_path.as_path() {
+                            pending.push((target, JsExportUsage::Named(name.clone())));
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    usage
+}
+
+/// Marks the export that is re-exported by `export` as used in its source
+/// module.
+fn push_reexport_usage<'a>(export: &'a JsExport, pending: &mut Vec<(&'a Utf8Path, JsExportUsage)>) {
+    if let JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) = export
+        && let Some(target) = reexport.import.resolved_path.as_path()
+    {
+        pending.push((target, JsExportUsage::from_import(&reexport.import)));
+    }
+}
+
+/// Returns whether the module at `path` is reachable from the entry points of
+/// its package.
+///
+/// The modules of a package without entry points are always reachable, since
+/// there's no way to tell how they're used.
+pub fn is_js_module_reachable(
+    db: &dyn ModuleDb,
+    project_layout: &ProjectLayout,
+    path: &Utf8Path,
+) -> bool {
+    js_entry_points_for_path(db, project_layout, path)
+        .is_none_or(|entry_points| js_project_usage(db, entry_points).is_reachable(path))
+}
+
+/// Packages imported by the JavaScript modules of the project.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct JsImportedPackages {
+    /// Maps the name of each imported package to the modules importing it.
+    importers_by_package: FxHashMap<String, Vec<Utf8PathBuf>>,
+}
+
+impl JsImportedPackages {
+    /// Returns whether the package called `package_name` is imported by a
+    /// module of the package inside `package_path`.
+    ///
+    /// `closest_package_path` returns the directory of the closest
+    /// `package.json` of a module. The modules of the packages nested inside
+    /// `package_path` belong to those packages, so their imports aren't taken
+    /// into account. Neither are the imports of the modules for which
+    /// `is_reachable` returns `false`, since they're dead code themselves.
+    pub fn is_imported_from(
+        &self,
+        package_name: &str,
+        package_path: &Utf8Path,
+        closest_package_path: impl Fn(&Utf8Path) -> Option<Utf8PathBuf>,
+        is_reachable: impl Fn(&Utf8Path) -> bool,
+    ) -> bool {
+        self.importers_by_package
+            .get(package_name)
+            .is_some_and(|importers| {
+                importers.iter().any(|path| {
+                    path.starts_with(package_path)
+                        && closest_package_path(path)
+                            .is_none_or(|closest_path| closest_path == package_path)
+                        && is_reachable(path)
+                })
+            })
+    }
+}
+
+/// Returns the packages imported by the JavaScript modules of the project,
+/// including type-only and dynamic imports.
+///
+/// Modules inside `node_modules`, relative specifiers and Node.js built-in
+/// modules are ignored.
+#[salsa::tracked(no_eq, returns(ref))]
+pub fn js_imported_packages(
+    db: &dyn ModuleDb,
+    generation: ModuleGraphGeneration,
+) -> JsImportedPackages {
+    let _ = generation.value(db);
+
+    let mut imported_packages = JsImportedPackages::default();
+    db.for_each_module(&mut |module| {
+        let path = module.path(db);
+        let ModuleInfoKind::Js(module_info) = module.kind(db) else {
+            return;
+        };
+        if is_node_modules_path(path) {
+            return;
+        }
+
+        for (specifier, _) in module_info.import_paths.named_iter() {
+            if let Some(package_name) = package_name_from_specifier(specifier.text()) {
+                let importers = imported_packages
+                    .importers_by_package
+                    .entry(package_name.to_string())
+                    .or_default();
+                if importers.last().is_none_or(|last| last != path) {
+                    importers.push(path.to_path_buf());
+                }
+            }
+        }
+    });
+
+    imported_packages
+}
+
+/// Unused code of the packages of a project: the files, exports and
+/// dependencies that the rules of the `project` domain report one by one.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct JsUnusedCode {
+    /// Modules that aren't reachable from the entry points of their package.
+    pub unreachable_files: Vec<Utf8PathBuf>,
+
+    /// Exports that aren't imported by any module of the project, with the
+    /// path of the module exporting them.
+    pub unused_exports: Vec<(Utf8PathBuf, String)>,
+
+    /// Dependencies that aren't imported by any reachable module of their
+    /// package, with the path of the `package.json` declaring them.
+    ///
+    /// Only the `dependencies` field is checked: `devDependencies` and
+    /// `peerDependencies` usually contain tools that are run from the command
+    /// line rather than imported.
+    pub unused_dependencies: Vec<(Utf8PathBuf, String)>,
+}
+
+/// Returns the unused code of the packages inside `directory`.
+///
+/// The modules of a package without entry points are neither reported as
+/// unreachable nor as having unused exports, as in `noUnreachableFiles` and
+/// `noUnusedExports`. Packages that only provide types, such as `@types/node`,
+/// aren't reported as unused dependencies, and neither are the entries of
+/// `devDependencies` and `peerDependencies`.
+pub fn js_unused_code(
+    db: &dyn ModuleDb,
+    project_layout: &ProjectLayout,
+    directory: &Utf8Path,
+) -> JsUnusedCode {
+    let mut unused_code = JsUnusedCode::default();
+
+    db.for_each_module(&mut |module| {
+        let path = module.path(db);
+        let ModuleInfoKind::Js(module_info) = module.kind(db) else {
+            return;
+        };
+        if !path.starts_with(directory) || is_node_modules_path(path) {
+            return;
+        }
+        let Some(entry_points) = js_entry_points_for_path(db, project_layout, path) else {
+            return;
+        };
+
+        let usage = js_project_usage(db, entry_points);
+        if !usage.is_reachable(path) {
+            unused_code.unreachable_files.push(path.to_path_buf());
+            return;
+        }
+        for name in module_info.exports.keys() {
+            if !usage.is_export_used(path, name.text()) {
+                unused_code
+                    .unused_exports
+                    .push((path.to_path_buf(), name.text().to_string()));
+            }
+        }
+    });
+
+    let imported_packages = js_imported_packages(db, ModuleGraphGeneration::get(db));
+    let closest_package_path = |path: &Utf8Path| {
+        project_layout
+            .find_node_manifest_for_path(path)
+            .map(|(package_path, _)| package_path)
+    };
+    for package_path in project_layout.package_paths() {
+        if !package_path.starts_with(directory) || is_node_modules_path(&package_path) {
+            continue;
+        }
+        let Some(manifest) = project_layout.get_node_manifest_for_package(&package_path) else {
+            continue;
+        };
+        for (name, _) in manifest.dependencies.0.iter() {
+            if !name.starts_with("@types/")
+                && !imported_packages.is_imported_from(
+                    name,
+                    &package_path,
+                    closest_package_path,
+                    |path| is_js_module_reachable(db, project_layout, path),
+                )
+            {
+                unused_code
+                    .unused_dependencies
+                    .push((package_path.join("package.json"), name.to_string()));
+            }
+        }
+    }
+
+    unused_code.unreachable_files.sort_unstable();
+    unused_code.unused_exports.sort_unstable();
+    unused_code.unused_dependencies.sort_unstable();
+    unused_code
+}
+
+/// Returns the name of the package imported by `specifier`, such as `lodash`
+/// for `lodash/fp` or `@biomejs/js-api` for `@biomejs/js-api/nodejs`.
+///
+/// Returns `None` for relative and absolute specifiers, subpath imports, URLs
+/// and Node.js built-in modules.
+fn package_name_from_specifier(specifier: &str) -> Option<&str> {
+    if specifier.is_empty()
+        || is_relative_specifier(specifier)
+        || specifier.starts_with(['/', '#'])
+        || specifier.contains(':')
+    {
+        return None;
+    }
+
+    let mut segments = specifier.splitn(3, '/');
+    let first = segments.next()?;
+    let package_name = if first.starts_with('@') {
+        let second = segments.next().filter(|second| !second.is_empty())?;
+        &specifier[..first.len() + 1 + second.len()]
+    } else {
+        first
+    };
+
+    (!is_builtin_node_module(package_name)).then_some(package_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsImportedPackages, package_name_from_specifier};
+    use camino::{Utf8Path, Utf8PathBuf};
+    use rustc_hash::FxHashMap;
+
+    #[test]
+    fn imports_of_nested_packages_belong_to_them() {
+        let imported_packages = JsImportedPackages {
+            importers_by_package: FxHashMap::from_iter([(
+                "lodash".to_string(),
+                vec![Utf8PathBuf::from("/project/packages/app/index.js")],
+            )]),
+        };
+        let closest_package_path = |path: &Utf8Path| {
+            path.ancestors()
+                .find(|ancestor| ["/project", "/project/packages/app"].contains(&ancestor.as_str()))
+                .map(Utf8Path::to_path_buf)
+        };
+
+        assert!(imported_packages.is_imported_from(
+            "lodash",
+            Utf8Path::new("/project/packages/app"),
+            closest_package_path,
+            |_| true
+        ));
+        assert!(!imported_packages.is_imported_from(
+            "lodash",
+            Utf8Path::new("/project"),
+            closest_package_path,
+            |_| true
+        ));
+        assert!(imported_packages.is_imported_from(
+            "lodash",
+            Utf8Path::new("/project"),
+            |_| None,
+            |_| true
+        ));
+    }
+
+    #[test]
+    fn imports_of_unreachable_modules_are_ignored() {
+        let imported_packages = JsImportedPackages {
+            importers_by_package: FxHashMap::from_iter([(
+                "lodash".to_string(),
+                vec![Utf8PathBuf::from("/project/orphan.js")],
+            )]),
+        };
+
+        assert!(!imported_packages.is_imported_from(
+            "lodash",
+            Utf8Path::new("/project"),
+            |_| None,
+            |path| path != "/project/orphan.js"
+        ));
+    }
+
+    #[test]
+    fn package_name_from_bare_specifiers() {
+        assert_eq!(package_name_from_specifier("lodash"), Some("lodash"));
+        assert_eq!(package_name_from_specifier("lodash/fp"), Some("lodash"));
+        assert_eq!(
+            package_name_from_specifier("@biomejs/js-api"),
+            Some("@biomejs/js-api")
+        );
+        assert_eq!(
+            package_name_from_specifier("@biomejs/js-api/nodejs"),
+            Some("@biomejs/js-api")
+        );
+    }
+
+    #[test]
+    fn no_package_name_from_other_specifiers() {
+        assert_eq!(package_name_from_specifier("./lodash"), None);
+        assert_eq!(package_name_from_specifier("/lodash"), None);
+        assert_eq!(package_name_from_specifier("#internal"), None);
+        assert_eq!(package_name_from_specifier("node:fs"), None);
+        assert_eq!(package_name_from_specifier("fs"), None);
+        assert_eq!(package_name_from_specifier("@scope"), None);
+    }
+}
@@ -2195,7 +2195,7 @@ impl<'db> ResolutionCtx<'db, '_> {
         ConditionalType::Unknown
     }
 
-    fn filter_type_to_subset(
+    pub(in crate::db::type_inference) fn filter_type_to_subset(
         &mut self,
         ty: InferredTypeData<'db>,
         subset: ConditionalSubset,
@@ -1,10 +1,11 @@
 use super::resolver::ResolutionCtx;
 use crate::js_module_info::TsBindingReferenceExt;
 use biome_js_type_info::{
-    Path, TypeImportQualifier, TypeReference, TypeReferenceQualifier, TypeResolverLevel,
-    global_type_id_for_qualifier,
+    InferredType, Path, TypeImportQualifier, TypeReference, TypeReferenceQualifier,
+    TypeResolverLevel, global_type_id_for_qualifier,
     interned_types::{
-        Literal as InferredLiteral, LocalTypeHandle, LocalTypeId, TypeData as InferredTypeData,
+        ConditionalSubset, Literal as InferredLiteral, LocalTypeHandle, LocalTypeId,
+        ReturnType as InferredReturnType, TypeData as InferredTypeData,
         TypeMember as InferredTypeMember, TypeMemberKind as InferredTypeMemberKind,
         well_known_symbol_type,
     },
@@ -168,6 +169,24 @@ impl<'db> ResolutionCtx<'db, '_> {
             return self.resolve_readonly(qualifier);
         }
 
+        if qualifier.is_awaited() && qualifier.type_parameters.len() == 1 {
+            let target_ty = self.resolve(&qualifier.type_parameters[0]);
+            return self
+                .resolve_await_expression(target_ty)
+                .unwrap_or(InferredTypeData::Unknown);
+        }
+
+        if qualifier.is_return_type() && qualifier.type_parameters.len() == 1 {
+            return self.resolve_return_type(qualifier);
+        }
+
+        if qualifier.is_non_nullable() && qualifier.type_parameters.len() == 1 {
+            let target_ty = self.resolve(&qualifier.type_parameters[0]);
+            return self
+                .filter_type_to_subset(target_ty, ConditionalSubset::NonNullish)
+                .unwrap_or(InferredTypeData::NeverKeyword);
+        }
+
         if qualifier.is_array() && qualifier.has_known_type_parameters() {
             return InferredTypeData::array_instance(
                 self.db,
@@ -400,6 +419,29 @@ impl<'db> ResolutionCtx<'db, '_> {
             })
     }
 
+    /// Resolves `ReturnType<T>` to the return type of the function that `T`
+    /// describes.
+    ///
+    /// Type predicates return `boolean` and assertion signatures return
+    /// `void`. Returns `Unknown` when `T` isn't callable, or when it has more
+    /// than one call signature.
+    fn resolve_return_type(&mut self, qualifier: &TypeReferenceQualifier) -> InferredTypeData<'db> {
+        let db = self.db;
+        let target_ty = self.resolve(&qualifier.type_parameters[0]);
+        let Some(function) = InferredType::new(db, target_ty)
+            .callable_type_with(|ty| self.resolve_inferred_type(ty).expand_structural_global(db))
+            .and_then(|ty| ty.callable_function(db))
+        else {
+            return InferredTypeData::Unknown;
+        };
+
+        match function.return_type(db) {
+            InferredReturnType::Type(ty) => *ty,
+            InferredReturnType::Predicate(_) => InferredTypeData::Boolean,
+            InferredReturnType::Asserts(_) => InferredTypeData::VoidKeyword,
+        }
+    }
+
     fn own_members(&mut self, ty: InferredTypeData<'db>) -> Option<Vec<InferredTypeMember<'db>>> {
         let mut ty = ty;
 
@@ -1,6 +1,7 @@
 use crate::ImportPathMap;
 use crate::css_module_info::{CssClassDefinition, CssClassReference, CssModuleVisitor};
 use crate::html_module_info::{HtmlImport, HtmlModuleInfo};
+use crate::js_module_info::tsconfig_manifest;
 use crate::module_graph::ModuleGraphFsProxy;
 use biome_css_syntax::selector_ext::AnyCssPseudoClassFunctionSelector;
 use biome_css_syntax::{AnyCssRoot, CssClassSelector};
@@ -11,10 +12,12 @@ use biome_html_syntax::{
 use biome_js_syntax::{AnyJsImportLike, AnyJsRoot};
 use biome_languages::css::EmbeddingStyleApplicability;
 use biome_languages::{CssFileSource, LanguageDb};
-use biome_resolver::{ResolveOptions, ResolvedPath, resolve};
+use biome_package::TsConfigJson;
+use biome_resolver::{ResolveOptions, ResolvedPath, find_tsconfig_for_file, resolve};
 use biome_rowan::{AstNode, AstSeparatedList, Text, TextSize, TokenText, WalkEvent};
 use camino::{Utf8Path, Utf8PathBuf};
 use indexmap::IndexSet;
+use std::cell::OnceCell;
 
 pub const SUPPORTED_CSS_EXTENSIONS: &[&str] = &["css"];
 
@@ -38,6 +41,9 @@ pub(crate) struct HtmlModuleVisitor<'a> {
     file_path: Utf8PathBuf,
     directory: &'a Utf8Path,
     fs_proxy: &'a ModuleGraphFsProxy<'a>,
+    /// The `tsconfig.json` of the project the file belongs to, which is looked
+    /// up when the first script import is resolved.
+    tsconfig: OnceCell<Option<TsConfigJson>>,
 }
 
 impl<'a> HtmlModuleVisitor<'a> {
@@ -54,6 +60,7 @@ impl<'a> HtmlModuleVisitor<'a> {
             file_path,
             directory,
             fs_proxy,
+            tsconfig: OnceCell::new(),
         }
     }
 
@@ -290,6 +297,9 @@ impl<'a> HtmlModuleVisitor<'a> {
     /// Uses the same resolution options as `JsModuleVisitor::resolved_path_from_specifier`,
     /// plus framework-specific extensions (`.vue`, `.astro`, `.svelte`).
     fn resolved_js_path_from_specifier(&self, specifier: &str) -> ResolvedPath {
+        let tsconfig = self
+            .tsconfig
+            .get_or_init(|| find_tsconfig_for_file(&self.file_path, self.fs_proxy).ok());
         let options = ResolveOptions {
             condition_names: &["types", "import", "default"],
             default_files: &["index"],
@@ -297,6 +307,8 @@ impl<'a> HtmlModuleVisitor<'a> {
             extension_aliases: HTML_EXTENSION_ALIASES,
             resolve_node_builtins: true,
             resolve_types: true,
+            tsconfig: tsconfig_manifest(tsconfig.as_ref()),
+            workspace_condition_names: &["source", "types", "import", "default"],
             ..Default::default()
         };
         let resolved = resolve(specifier, self.directory, self.fs_proxy, &options);
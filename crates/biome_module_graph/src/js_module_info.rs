@@ -27,7 +27,7 @@ use crate::diagnostics::ModuleDiagnostic;
 pub(super) use binding::JsBindingData;
 pub use collector::TypeInferenceMode;
 pub use diagnostics::JsModuleInfoDiagnostic;
-pub(crate) use visitor::JsModuleVisitor;
+pub(crate) use visitor::{JsModuleVisitor, tsconfig_manifest};
 
 /// Information restricted to a single JS/TS module.
 #[derive(Clone, Debug)]
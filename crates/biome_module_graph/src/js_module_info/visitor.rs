@@ -9,9 +9,11 @@ use biome_js_syntax::{
     TsExportAssignmentClause, unescape_js_string,
 };
 use biome_js_type_info::{ImportSymbol, RawTypeCollector, TypeData, TypeReference};
-use biome_resolver::{ResolveOptions, resolve};
+use biome_package::TsConfigJson;
+use biome_resolver::{DiscoverableManifest, ResolveOptions, find_tsconfig_for_file, resolve};
 use biome_rowan::{AstNode, TokenText, WalkEvent};
 use camino::{Utf8Path, Utf8PathBuf};
+use std::cell::OnceCell;
 
 use crate::{
     JsImport, JsImportPhase, JsModuleInfo, JsReexport, SUPPORTED_EXTENSIONS,
@@ -36,6 +38,9 @@ pub(crate) struct JsModuleVisitor<'a> {
     fs_proxy: &'a ModuleGraphFsProxy<'a>,
     semantic_model: std::sync::Arc<biome_js_semantic::SemanticModel>,
     inference_mode: TypeInferenceMode,
+    /// The `tsconfig.json` of the project the file belongs to, which is looked
+    /// up when the first import is resolved.
+    tsconfig: OnceCell<Option<TsConfigJson>>,
 }
 
 impl<'a> JsModuleVisitor<'a> {
@@ -54,6 +59,7 @@ impl<'a> JsModuleVisitor<'a> {
             fs_proxy,
             semantic_model,
             inference_mode,
+            tsconfig: OnceCell::new(),
         }
     }
 
@@ -497,6 +503,9 @@ impl<'a> JsModuleVisitor<'a> {
     }
 
     fn resolved_path_from_specifier(&self, specifier: &str) -> ResolvedPath {
+        let tsconfig = self
+            .tsconfig
+            .get_or_init(|| find_tsconfig_for_file(&self.file_path, self.fs_proxy).ok());
         let options = ResolveOptions {
             condition_names: &["types", "import", "default"],
             default_files: &["index"],
@@ -504,6 +513,8 @@ impl<'a> JsModuleVisitor<'a> {
             extension_aliases: EXTENSION_ALIASES,
             resolve_node_builtins: true,
             resolve_types: true,
+            tsconfig: tsconfig_manifest(tsconfig.as_ref()),
+            workspace_condition_names: &["source", "types", "import", "default"],
             ..Default::default()
         };
         let resolved_path = resolve(specifier, self.directory, self.fs_proxy, &options);
@@ -511,6 +522,23 @@ impl<'a> JsModuleVisitor<'a> {
     }
 }
 
+/// Returns the manifest option for resolving imports with the given
+/// `tsconfig`, falling back to automatic discovery if there is none.
+pub(crate) fn tsconfig_manifest(
+    tsconfig: Option<&TsConfigJson>,
+) -> DiscoverableManifest<&TsConfigJson> {
+    match tsconfig {
+        Some(tsconfig) => DiscoverableManifest::Explicit {
+            package_path: tsconfig
+                .path
+                .parent()
+                .map_or_else(Utf8PathBuf::new, Utf8Path::to_path_buf),
+            manifest: tsconfig,
+        },
+        None => DiscoverableManifest::Auto,
+    }
+}
+
 fn get_name(binding_result: AnyJsBinding) -> Option<TokenText> {
     let name = binding_result
         .as_js_identifier_binding()?
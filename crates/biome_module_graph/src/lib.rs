@@ -27,7 +27,8 @@ pub use css_module_info::{
 };
 pub use db::queries::{
     BindingTypeInput, CallArgumentTypeInput, CallExpressionTypeInput, ExpressionTypeInput,
-    InferredModuleTypes, JsModuleSccs, LocalTypeInput, NormalizeTypeInput, SymbolFromModuleInfo,
+    InferredModuleTypes, JsEntryPoints, JsImportedPackages, JsModuleSccs, JsProjectUsage,
+    JsUnusedCode, LocalTypeInput, NormalizeTypeInput, SymbolFromModuleInfo,
     build_import_tree_for_html, build_import_tree_for_js, css_classes_for_module,
     css_property_definitions, find_css_class_definition, find_js_exported_symbol,
     find_jsdoc_for_exported_symbol, find_member_type, find_value_member_type,
@@ -36,7 +37,8 @@ pub use db::queries::{
     infer_expression_function_returns_promise, infer_expression_is_array_of_promises,
     infer_expression_is_promise, infer_expression_type, infer_local_type, infer_module_types,
     infer_module_types_bottom_up, is_array_of_promise_type, is_class_referenced_by_importers,
-    is_promise_type, js_module_sccs, normalize_type, resolve_callable_type,
+    is_js_module_reachable, is_promise_type, js_entry_points_for_path, js_imported_packages,
+    js_module_sccs, js_project_usage, js_unused_code, normalize_type, resolve_callable_type,
     transitive_importers_of, traverse_import_tree_for_classes,
     traverse_import_tree_for_html_classes,
 };
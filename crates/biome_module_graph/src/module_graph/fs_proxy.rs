@@ -50,8 +50,39 @@ impl ResolverFsProxy for ModuleGraphFsProxy<'_> {
     }
 
     fn read_tsconfig_json(&self, path: &Utf8Path) -> Result<TsConfigJson, ResolveError> {
+        // Only `tsconfig.json` files are indexed by the scanner. Other
+        // configurations, such as those that are extended or referenced, are
+        // read from the file system the first time they're needed, and then
+        // tracked by the project layout until they change.
+        if path.file_name() != Some("tsconfig.json") {
+            if let Some(tsconfig) = self.project_layout.get_tsconfig_file(path) {
+                return Ok(tsconfig);
+            }
+
+            let tsconfig = self.fs.read_tsconfig_json(path)?;
+            self.project_layout
+                .insert_tsconfig_file(path.to_path_buf(), tsconfig.clone());
+            return Ok(tsconfig);
+        }
+
         self.project_layout
             .get_tsconfig_json_for_package(path.parent().expect("path should have a parent"))
             .ok_or(ResolveError::ErrorLoadingManifest)
     }
+
+    fn find_tsconfig_json(&self, search_dir: &Utf8Path) -> Result<TsConfigJson, ResolveError> {
+        self.project_layout
+            .find_tsconfig_json_for_path(search_dir)
+            .ok_or(ResolveError::NotFound)
+    }
+
+    fn find_workspace_package(
+        &self,
+        search_dir: &Utf8Path,
+        package_name: &str,
+    ) -> Result<Utf8PathBuf, ResolveError> {
+        self.project_layout
+            .find_workspace_package(search_dir, package_name)
+            .ok_or(ResolveError::NotFound)
+    }
 }
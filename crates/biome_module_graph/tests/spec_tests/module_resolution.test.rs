@@ -235,6 +235,108 @@ fn test_package_typings_field_resolution() {
     );
 }
 
+#[test]
+fn test_resolve_workspace_package_to_source() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        "/packages/app/src/index.ts".into(),
+        "import { format } from '@acme/shared';",
+    );
+    fs.insert(
+        "/packages/shared/src/index.ts".into(),
+        "export function format(): string { return ''; }",
+    );
+    fs.insert(
+        "/node_modules/@acme/shared/dist/index.d.ts".into(),
+        "export declare function format(): string;",
+    );
+    let layout = ProjectLayout::default();
+    let deserialize = |json: &str| {
+        deserialize_from_json_str::<PackageJson>(json, Default::default(), "package.json")
+            .into_deserialized()
+            .unwrap()
+    };
+    layout.insert_node_manifest(
+        "/".into(),
+        deserialize(r#"{"name":"root","workspaces":["packages/*"]}"#),
+    );
+    layout.insert_node_manifest(
+        "/packages/app".into(),
+        deserialize(r#"{"name":"@acme/app","dependencies":{"@acme/shared":"workspace:*"}}"#),
+    );
+    let shared_manifest = r#"{
+        "name": "@acme/shared",
+        "exports": {
+            ".": {
+                "source": "./src/index.ts",
+                "types": "./dist/index.d.ts",
+                "default": "./dist/index.js"
+            }
+        }
+    }"#;
+    layout.insert_node_manifest("/packages/shared".into(), deserialize(shared_manifest));
+    layout.insert_node_manifest(
+        "/node_modules/@acme/shared".into(),
+        deserialize(shared_manifest),
+    );
+
+    let db = build_js_db(
+        &fs,
+        &layout,
+        &[BiomePath::new("/packages/app/src/index.ts")],
+        false,
+    );
+    assert_eq!(
+        db.js_module_info_for_path(Utf8Path::new("/packages/app/src/index.ts"))
+            .unwrap()
+            .static_imports
+            .get("format")
+            .unwrap()
+            .resolved_path
+            .as_path(),
+        Some(Utf8Path::new("/packages/shared/src/index.ts"))
+    );
+}
+
+#[test]
+fn test_import_through_path_alias_of_referenced_project() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { Hello } from '@components/Hello';",
+    );
+    fs.insert(
+        "/src/components/Hello.tsx".into(),
+        "export function Hello() {}",
+    );
+    fs.insert(
+        "/tsconfig.app.json".into(),
+        r#"{ "extends": "./tsconfig.base.json", "include": ["src"] }"#,
+    );
+    fs.insert(
+        "/tsconfig.base.json".into(),
+        r#"{"compilerOptions":{"paths":{"@components/*":["./src/components/*"]}}}"#,
+    );
+    let layout = ProjectLayout::default();
+    layout.insert_node_manifest("/".into(), PackageJson::new("app").with_version("1.0.0"));
+    let json = biome_json_parser::parse_json(
+        r#"{"files":[],"references":[{"path":"./tsconfig.app.json"}]}"#,
+        Default::default(),
+    );
+    layout.insert_serialized_tsconfig("/".into(), &json.syntax().as_send().unwrap());
+    let db = build_js_db(&fs, &layout, &[BiomePath::new("/src/index.ts")], true);
+    assert_eq!(
+        db.js_module_info_for_path(Utf8Path::new("/src/index.ts"))
+            .unwrap()
+            .static_imports
+            .get("Hello")
+            .unwrap()
+            .resolved_path
+            .as_path(),
+        Some(Utf8Path::new("/src/components/Hello.tsx"))
+    );
+}
+
 fn export_db(source: &str, barrel: &str) -> biome_service::db::WorkspaceDb {
     let fs = MemoryFileSystem::default();
     fs.insert("/src/source.ts".into(), source);
@@ -341,6 +341,109 @@ fn test_infer_module_types_resolves_utility_type_members_on_build() {
     );
 }
 
+#[test]
+fn test_infer_module_types_resolves_lib_utility_and_mapped_types_on_build() {
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"
+            async function fetchUser(): Promise<string> {
+                return "user";
+            }
+
+            type Api = {
+                name: string;
+                save: () => Promise<void>;
+            };
+
+            type Jobs = { [K in "build" | "test"]: Promise<void> };
+
+            type Handlers = { [K in keyof Api]: () => Promise<void> };
+
+            export function readAwaited(
+                value: Awaited<Promise<Promise<number>>>,
+            ): Awaited<Promise<Promise<number>>> {
+                return value;
+            }
+
+            export function readReturnType(): ReturnType<typeof fetchUser> {
+                return fetchUser();
+            }
+
+            export function readNonNullable(
+                value: NonNullable<string | null | undefined>,
+            ): NonNullable<string | null | undefined> {
+                return value;
+            }
+
+            export function readIndexedAccess(value: Api["name"]): Api["name"] {
+                return value;
+            }
+
+            export function readMapped(value: Jobs): Jobs {
+                return value;
+            }
+
+            export function readMappedKeyof(value: Handlers): Handlers {
+                return value;
+            }
+        "#,
+    );
+
+    let db = build_js_test_module_db(&fs, &["/src/index.ts"], true);
+    let index_module = db
+        .module_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let inferred = infer_module_types(&db, index_module).expect("types must be inferred");
+
+    let awaited_ty =
+        inferred_function_return_ty_by_name(&db, index_module, inferred, "readAwaited")
+            .expect("readAwaited return type must be inferred");
+    assert!(is_inferred_number(&db, awaited_ty));
+
+    let return_type_ty =
+        inferred_function_return_ty_by_name(&db, index_module, inferred, "readReturnType")
+            .expect("readReturnType return type must be inferred");
+    assert!(is_inferred_promise_instance(&db, return_type_ty));
+
+    let non_nullable_ty =
+        inferred_function_return_ty_by_name(&db, index_module, inferred, "readNonNullable")
+            .expect("readNonNullable return type must be inferred");
+    assert!(contains_inferred_string(&db, non_nullable_ty));
+    assert!(!contains_inferred_null(&db, non_nullable_ty));
+    assert!(!contains_inferred_undefined(&db, non_nullable_ty));
+
+    let indexed_access_ty =
+        inferred_function_return_ty_by_name(&db, index_module, inferred, "readIndexedAccess")
+            .expect("readIndexedAccess return type must be inferred");
+    assert!(contains_inferred_string(&db, indexed_access_ty));
+
+    let mapped_ty = inferred_function_return_ty_by_name(&db, index_module, inferred, "readMapped")
+        .expect("readMapped return type must be inferred");
+    let build_ty = inferred
+        .find_member_type(&db, mapped_ty, "build")
+        .expect("mapped type must have a member for each key");
+    assert!(is_inferred_promise_instance(&db, build_ty));
+    assert!(
+        inferred
+            .find_member_type(&db, mapped_ty, "deploy")
+            .is_none()
+    );
+
+    let mapped_keyof_ty =
+        inferred_function_return_ty_by_name(&db, index_module, inferred, "readMappedKeyof")
+            .expect("readMappedKeyof return type must be inferred");
+    let save_ty = inferred
+        .find_member_type(&db, mapped_keyof_ty, "save")
+        .expect("mapped type over unknown keys must have an index signature");
+    assert!(
+        inferred
+            .resolve_type(&db, save_ty)
+            .callable_function(&db)
+            .is_some()
+    );
+}
+
 #[test]
 fn test_infer_module_types_preserves_legacy_rhs_never_intersection_members() {
     let fs = MemoryFileSystem::default();
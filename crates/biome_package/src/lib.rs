@@ -12,7 +12,7 @@ pub use license::expression::{RejectReason, SpdxExpression, TrustConfig};
 pub use license::generated::*;
 pub use node_js_package::{
     Catalogs, CompilerOptions, Dependencies, NodeJsPackage, PackageJson, PackageType, TsConfigJson,
-    TurboJson, Version,
+    TurboJson, Version, Workspaces,
 };
 
 use std::any::TypeId;
@@ -9,7 +9,7 @@ use biome_diagnostics::Error;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use biome_json_value::JsonValue;
-use biome_rowan::AstNodeList;
+use biome_rowan::{AstNodeList, AstSeparatedList};
 use biome_text_size::TextRange;
 use biome_yaml_parser::parse_yaml;
 use biome_yaml_syntax::{
@@ -56,6 +56,24 @@ pub struct PackageJson {
     pub imports: Option<JsonValue>,
     pub main: Option<Box<str>>,
     pub types: Option<Box<str>>,
+
+    /// The "bin" field, either a single path or a map from command names to
+    /// paths.
+    ///
+    /// <https://docs.npmjs.com/cli/configuring-npm/package-json#bin>
+    pub bin: Option<JsonValue>,
+
+    /// The "workspaces" field, with the glob patterns matching the packages of
+    /// the workspace this package is the root of.
+    ///
+    /// <https://docs.npmjs.com/cli/using-npm/workspaces>
+    pub workspaces: Workspaces,
+
+    /// Optional glob patterns from the `packages` field of a
+    /// `pnpm-workspace.yaml` next to this manifest. Like [`Self::catalog`],
+    /// this field is populated by parsing the workspace file rather than
+    /// directly from `package.json`.
+    pub pnpm_workspace_packages: Option<Workspaces>,
 }
 
 static_assertions::assert_impl_all!(PackageJson: Send, Sync);
@@ -98,6 +116,19 @@ impl PackageJson {
             || self.peer_dependencies.contains(specifier)
     }
 
+    /// Returns the glob patterns matching the packages of the workspace this
+    /// package is the root of.
+    ///
+    /// pnpm ignores the `workspaces` field in favour of its
+    /// `pnpm-workspace.yaml`, so the patterns from the latter take precedence
+    /// when present. Returns an empty slice if this package is not the root of
+    /// a workspace.
+    pub fn workspace_patterns(&self) -> &[Box<str>] {
+        self.pnpm_workspace_packages
+            .as_ref()
+            .unwrap_or(&self.workspaces)
+    }
+
     /// Checks whether the `specifier` is defined in `dependencies`,
     /// `dev_dependencies` or `peer_dependencies`, and the `range` of matches
     /// the one of the manifest
@@ -123,6 +154,53 @@ impl PackageJson {
         false
     }
 
+    /// Returns the paths of the entry points declared by the package, relative
+    /// to the directory of the manifest.
+    ///
+    /// The paths are taken from the `main`, `exports` and `bin` fields.
+    /// Subpath patterns such as `"./features/*.js"` are skipped, because they
+    /// don't point to a single file.
+    pub fn entry_points(&self) -> Vec<&str> {
+        let mut entry_points = Vec::new();
+        if let Some(main) = self.main.as_deref() {
+            entry_points.push(main);
+        }
+        if let Some(exports) = &self.exports {
+            collect_entry_points(exports, &mut entry_points);
+        }
+        if let Some(bin) = &self.bin {
+            collect_entry_points(bin, &mut entry_points);
+        }
+        entry_points.retain(|path| !path.contains('*'));
+        entry_points.sort_unstable();
+        entry_points.dedup();
+        entry_points
+    }
+
+    /// Extracts the glob patterns of the workspace packages from the `packages`
+    /// field of a pnpm workspace file.
+    pub fn parse_pnpm_workspace_packages(source: &str) -> Option<Workspaces> {
+        let parsed = parse_yaml(source);
+        if parsed.has_errors() {
+            return None;
+        }
+
+        let root = parsed.tree();
+        let document = root
+            .documents()
+            .into_iter()
+            .find_map(|doc| doc.as_yaml_document().cloned())?;
+        let top_node = document.node()?;
+        let mapping = as_catalog_block_mapping(&top_node)?;
+        let packages = mapping.entries().into_iter().find_map(|entry| {
+            parse_catalog_mapping_entry(entry)
+                .filter(|(key, _)| key.as_ref() == "packages")
+                .map(|(_, value_node)| value_node)
+        })?;
+
+        Some(Workspaces(collect_scalar_sequence(&packages)?))
+    }
+
     /// Extract catalog entries from a pnpm workspace file, supporting both the
     /// default `catalog:` and named catalogs under `catalogs:`.
     pub fn parse_pnpm_workspace_catalog(source: &str) -> Option<Catalogs> {
@@ -184,6 +262,25 @@ impl PackageJson {
     }
 }
 
+/// Collects the string values of `value`, descending into the conditions and
+/// subpaths of objects and the fallbacks of arrays.
+fn collect_entry_points<'a>(value: &'a JsonValue, entry_points: &mut Vec<&'a str>) {
+    match value {
+        JsonValue::String(path) => entry_points.push(path.as_str()),
+        JsonValue::Array(fallbacks) => {
+            for fallback in fallbacks.iter() {
+                collect_entry_points(fallback, entry_points);
+            }
+        }
+        JsonValue::Object(object) => {
+            for value in object.values() {
+                collect_entry_points(value, entry_points);
+            }
+        }
+        JsonValue::Bool(_) | JsonValue::Null | JsonValue::Number(_) | JsonValue::Bogus => {}
+    }
+}
+
 /// Parsed catalogs from `pnpm-workspace.yaml`.
 ///
 /// Mapping from YAML to this type:
@@ -281,6 +378,37 @@ fn collect_catalog_dependencies(mapping: &YamlBlockMapping) -> Dependencies {
     Dependencies(deps.into_boxed_slice())
 }
 
+/// Collects the scalar strings of a YAML sequence, in either block or flow
+/// style. Returns `None` if the node isn't a sequence.
+fn collect_scalar_sequence(node: &AnyYamlBlockNode) -> Option<Box<[Box<str>]>> {
+    if let Some(sequence) = node
+        .as_yaml_block_in_block_node()
+        .and_then(|block| block.content().ok())
+        .and_then(|content| content.as_yaml_block_sequence().cloned())
+    {
+        let values = sequence
+            .entries()
+            .into_iter()
+            .filter_map(|entry| entry.as_yaml_block_sequence_entry()?.value())
+            .filter_map(|value| extract_catalog_scalar_from_block_node(&value))
+            .collect();
+        return Some(values);
+    }
+
+    let flow_node = node.as_yaml_flow_in_block_node()?.flow().ok()?;
+    let content = flow_node.as_yaml_flow_json_node()?.content().ok()?;
+    let AnyYamlJsonContent::YamlFlowSequence(sequence) = content else {
+        return None;
+    };
+    let values = sequence
+        .entries()
+        .iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| extract_catalog_scalar_from_flow_node(entry.as_any_yaml_flow_node()?))
+        .collect();
+    Some(values)
+}
+
 /// Extracts a scalar string from an implicit mapping key (flow YAML/JSON node).
 fn extract_catalog_scalar_from_implicit_key(key: &AnyYamlMappingImplicitKey) -> Option<Box<str>> {
     if let Some(flow_yaml) = key.as_yaml_flow_yaml_node() {
@@ -402,8 +530,9 @@ fn dependency_satisfies(
 }
 
 /// Resolves a dependency version, expanding pnpm `catalog:` references (default
-/// or named) using the provided `Catalogs`. Falls back to the literal version
-/// string if no catalog match is found.
+/// or named) using the provided `Catalogs`, and stripping the `workspace:`
+/// protocol from explicit ranges. Falls back to the literal version string if
+/// no catalog match is found.
 fn resolve_dependency_version<'a>(
     specifier: &str,
     version: &'a str,
@@ -421,7 +550,9 @@ fn resolve_dependency_version<'a>(
         }
     }
 
-    version
+    // The `workspace:` protocol only tells the package manager to link the
+    // package from the workspace; the range after it still applies.
+    version.strip_prefix("workspace:").unwrap_or(version)
 }
 
 impl Manifest for PackageJson {
@@ -570,6 +701,77 @@ impl Deserializable for BundleDependencies {
     }
 }
 
+/// The "workspaces" field is usually an array of glob patterns, but Yarn also
+/// accepts an object with the patterns in its `packages` field.
+#[derive(Debug, Default, Clone)]
+pub struct Workspaces(pub Box<[Box<str>]>);
+
+impl Deserializable for Workspaces {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        name: &str,
+    ) -> Option<Self> {
+        struct Visitor;
+
+        impl DeserializationVisitor for Visitor {
+            type Output = Workspaces;
+
+            const EXPECTED_TYPE: DeserializableTypes =
+                DeserializableTypes::ARRAY.union(DeserializableTypes::MAP);
+
+            fn visit_array(
+                self,
+                ctx: &mut dyn DeserializationContext,
+                items: &mut dyn ExactSizeIterator<Item = Option<Box<dyn DeserializableValue>>>,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                let values = items
+                    .filter_map(|item| {
+                        let item = item?;
+                        Deserializable::deserialize(ctx, &item, name)
+                    })
+                    .collect::<Vec<Box<str>>>();
+
+                Some(Workspaces(values.into_boxed_slice()))
+            }
+
+            fn visit_map(
+                self,
+                ctx: &mut dyn DeserializationContext,
+                members: &mut MapMembers<'_>,
+                _range: TextRange,
+                name: &str,
+            ) -> Option<Self::Output> {
+                let mut result = Workspaces::default();
+                for (key, value) in members.flatten() {
+                    let Some(key_text) = Text::deserialize(ctx, &key, name) else {
+                        continue;
+                    };
+                    if key_text.text() == "packages"
+                        && let Some(packages) = Deserializable::deserialize(ctx, &value, name)
+                    {
+                        result = packages;
+                    }
+                }
+
+                Some(result)
+            }
+        }
+
+        value.deserialize(ctx, Visitor, name)
+    }
+}
+
+impl Deref for Workspaces {
+    type Target = Box<[Box<str>]>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 impl BundleDependencies {
     pub fn contains(&self, specifier: &str) -> bool {
         self.0
@@ -722,6 +924,16 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.main = Some(value);
                     }
                 }
+                "bin" => {
+                    if let Some(value) = JsonValue::deserialize(ctx, &value, &key_text) {
+                        result.bin = Some(value);
+                    }
+                }
+                "workspaces" => {
+                    if let Some(value) = Deserializable::deserialize(ctx, &value, &key_text) {
+                        result.workspaces = value;
+                    }
+                }
                 _ => {}
             }
         }
@@ -915,6 +1127,86 @@ catalog:
         assert!(catalog.named.is_empty());
     }
 
+    #[test]
+    fn parse_pnpm_workspace_packages_block_sequence() {
+        let yaml = r#"
+packages:
+  - "packages/*"
+  - apps/web
+  - "!**/test/**"
+catalog:
+  react: 19.0.0
+"#;
+
+        let packages =
+            PackageJson::parse_pnpm_workspace_packages(yaml).expect("packages should be parsed");
+        assert_eq!(
+            packages.as_ref(),
+            ["packages/*".into(), "apps/web".into(), "!**/test/**".into()]
+        );
+    }
+
+    #[test]
+    fn parse_pnpm_workspace_packages_flow_sequence() {
+        let yaml = r#"packages: ["packages/*", 'tools/*']"#;
+
+        let packages =
+            PackageJson::parse_pnpm_workspace_packages(yaml).expect("packages should be parsed");
+        assert_eq!(packages.as_ref(), ["packages/*".into(), "tools/*".into()]);
+    }
+
+    #[test]
+    fn parse_package_json_workspaces_field() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "root",
+    "workspaces": ["packages/*", "apps/*"]
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(
+            package_json.workspace_patterns(),
+            ["packages/*".into(), "apps/*".into()]
+        );
+    }
+
+    #[test]
+    fn parse_package_json_workspaces_field_with_object() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "root",
+    "workspaces": {
+        "packages": ["packages/*"],
+        "nohoist": ["**/react-native"]
+    }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(package_json.workspace_patterns(), ["packages/*".into()]);
+    }
+
+    #[test]
+    fn resolve_dependency_version_strips_workspace_protocol() {
+        assert_eq!(
+            super::resolve_dependency_version("shared", "workspace:^1.2.0", None),
+            "^1.2.0"
+        );
+        assert_eq!(
+            super::resolve_dependency_version("shared", "workspace:*", None),
+            "*"
+        );
+    }
+
     #[test]
     fn parse_pnpm_workspace_catalog_named() {
         let yaml = r#"
@@ -1042,4 +1334,40 @@ catalogs:
         assert!(package_json.bundle_dependencies.0.is_empty());
         assert!(package_json.bundled_dependencies.0.is_empty());
     }
+
+    #[test]
+    fn entry_points_from_main_exports_and_bin() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "@shared/format",
+    "main": "./dist/index.cjs",
+    "exports": {
+        ".": {
+            "import": "./dist/index.js",
+            "require": "./dist/index.cjs"
+        },
+        "./cli": ["./dist/cli.js", null],
+        "./features/*": "./dist/features/*.js"
+    },
+    "bin": {
+        "format": "./bin/format.js"
+    }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(
+            package_json.entry_points(),
+            [
+                "./bin/format.js",
+                "./dist/cli.js",
+                "./dist/index.cjs",
+                "./dist/index.js"
+            ]
+        );
+    }
 }
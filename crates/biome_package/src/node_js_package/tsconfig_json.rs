@@ -9,6 +9,7 @@ use biome_deserialize::{DeserializableType, Deserialized};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Error;
 use biome_fs::normalize_path;
+use biome_glob::{CandidatePath, NormalizedGlob};
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
@@ -34,6 +35,23 @@ pub struct TsConfigJson {
 
     /// Project references.
     pub references: Vec<ProjectReference>,
+
+    /// See: https://www.typescriptlang.org/tsconfig/#files
+    pub files: Option<Vec<String>>,
+
+    /// See: https://www.typescriptlang.org/tsconfig/#include
+    pub include: Option<Vec<String>>,
+
+    /// See: https://www.typescriptlang.org/tsconfig/#exclude
+    pub exclude: Option<Vec<String>>,
+
+    /// The directory from where `files`, `include`, and `exclude` are
+    /// resolved.
+    ///
+    /// This is the directory of the `tsconfig.json` that defines them, which
+    /// may be an extended configuration.
+    #[deserializable(skip)]
+    pub files_base: Utf8PathBuf,
 }
 
 impl Manifest for TsConfigJson {
@@ -93,6 +111,7 @@ impl TsConfigJson {
 
         self.path = path.to_path_buf();
         let directory = path.parent();
+        self.files_base = directory.map_or_else(Default::default, Utf8Path::to_path_buf);
         if let Some(base_url) = self.compiler_options.base_url.as_ref() {
             self.compiler_options.base_url =
                 directory.map(|dir| normalize_path(&dir.join(base_url)));
@@ -105,6 +124,123 @@ impl TsConfigJson {
         }
     }
 
+    /// Returns the paths of the `tsconfig.json` files of the referenced
+    /// projects.
+    ///
+    /// References may point either to a directory containing a
+    /// `tsconfig.json`, or to a configuration file directly.
+    pub fn reference_paths(&self) -> impl Iterator<Item = Utf8PathBuf> + '_ {
+        let directory = self.path.parent().unwrap_or(Utf8Path::new(""));
+        self.references.iter().map(move |reference| {
+            let path = normalize_path(&directory.join(&reference.path));
+            if path.extension() == Some("json") {
+                path
+            } else {
+                path.join("tsconfig.json")
+            }
+        })
+    }
+
+    /// Returns whether this configuration only references other projects,
+    /// without including any files itself.
+    ///
+    /// See: https://www.typescriptlang.org/docs/handbook/project-references.html#overall-structure
+    pub fn is_solution(&self) -> bool {
+        !self.references.is_empty()
+            && self.include.is_none()
+            && self.files.as_ref().is_some_and(Vec::is_empty)
+    }
+
+    /// Returns whether the file at the given absolute `path` is part of the
+    /// project defined by this configuration, based on its `files`, `include`,
+    /// and `exclude` fields.
+    pub fn includes_path(&self, path: &Utf8Path) -> bool {
+        let Ok(relative_path) = path.strip_prefix(&self.files_base) else {
+            return false;
+        };
+
+        if self
+            .files
+            .iter()
+            .flatten()
+            .any(|file| normalize_path(&self.files_base.join(file)) == path)
+        {
+            return true;
+        }
+
+        let candidate = CandidatePath::new(relative_path);
+        let is_included = match (&self.include, &self.files) {
+            (Some(include), _) => include
+                .iter()
+                .filter_map(|pattern| tsconfig_glob(pattern))
+                .any(|glob| candidate.matches(glob)),
+            // Only the listed files are included if `include` is omitted.
+            (None, Some(_)) => false,
+            (None, None) => true,
+        };
+
+        let is_excluded = match &self.exclude {
+            Some(exclude) => exclude
+                .iter()
+                .filter_map(|pattern| tsconfig_glob(pattern))
+                .any(|glob| candidate.matches(glob)),
+            None => relative_path
+                .components()
+                .any(|component| component.as_str() == "node_modules"),
+        };
+
+        is_included && !is_excluded
+    }
+
+    /// Fills in the settings that are missing from this configuration with
+    /// the ones from the given `base` configuration, which this configuration
+    /// extends.
+    ///
+    /// Paths inside `base` are expected to be initialised already, so they
+    /// keep referring to the location of the base configuration.
+    pub fn inherit_from(&mut self, base: &Self) {
+        let options = &mut self.compiler_options;
+        let base_options = &base.compiler_options;
+
+        if options.base_url.is_none() {
+            options.base_url.clone_from(&base_options.base_url);
+            // Aliases are resolved relative to the effective `baseUrl`.
+            if options.paths.is_some()
+                && let Some(base_url) = &options.base_url
+            {
+                options.paths_base.clone_from(base_url);
+            }
+        }
+        if options.paths.is_none() {
+            options.paths.clone_from(&base_options.paths);
+            options.paths_base = options
+                .base_url
+                .clone()
+                .unwrap_or_else(|| base_options.paths_base.clone());
+        }
+        if options.type_roots.is_none() {
+            options.type_roots.clone_from(&base_options.type_roots);
+        }
+        if options.jsx_factory.is_none() {
+            options.jsx_factory.clone_from(&base_options.jsx_factory);
+        }
+        if options.jsx_fragment_factory.is_none() {
+            options
+                .jsx_fragment_factory
+                .clone_from(&base_options.jsx_fragment_factory);
+        }
+
+        // `files`, `include`, and `exclude` are resolved relative to the
+        // configuration that defines them, so we can only inherit them
+        // together.
+        if self.files.is_none() && self.include.is_none() && self.exclude.is_none() {
+            self.files.clone_from(&base.files);
+            self.include.clone_from(&base.include);
+            self.exclude.clone_from(&base.exclude);
+            self.files_base.clone_from(&base.files_base);
+        }
+    }
+
     /// Returns whether the given `path` matches a configured path alias.
     pub fn matches_path_alias(&self, path: &str) -> bool {
         self.compiler_options.paths.as_ref().is_some_and(|paths| {
@@ -171,6 +307,33 @@ pub enum ExtendsField {
     Multiple(Vec<String>),
 }
 
+impl ExtendsField {
+    /// Returns the specifiers of the extended configurations, in the order in
+    /// which they should be applied.
+    pub fn as_slice(&self) -> &[String] {
+        match self {
+            Self::Single(specifier) => std::slice::from_ref(specifier),
+            Self::Multiple(specifiers) => specifiers,
+        }
+    }
+}
+
+/// Converts a pattern from `include` or `exclude` into a glob.
+///
+/// Patterns whose last segment has neither a wildcard nor an extension refer
+/// to a directory, and match all the files inside of it.
+fn tsconfig_glob(pattern: &str) -> Option<NormalizedGlob> {
+    let pattern = pattern.trim_end_matches('/');
+    let last_segment = pattern.rsplit('/').next().unwrap_or(pattern);
+    if matches!(last_segment, "" | ".") {
+        "**/*".parse().ok()
+    } else if last_segment.contains(['*', '?', '.']) {
+        pattern.parse().ok()
+    } else {
+        format!("{pattern}/**/*").parse().ok()
+    }
+}
+
 impl Deserializable for ExtendsField {
     fn deserialize(
         ctx: &mut dyn DeserializationContext,
@@ -460,4 +623,91 @@ mod tests {
             "Whitespace should be trimmed from jsxFragmentFactory"
         );
     }
+
+    #[test]
+    fn test_includes_path() {
+        let json = r#"{
+            "include": ["src", "types/*.d.ts"],
+            "exclude": ["src/**/*.test.ts"]
+        }"#;
+
+        let (tsconfig, _) = TsConfigJson::parse(Utf8Path::new("/app/tsconfig.json"), json);
+
+        assert!(tsconfig.includes_path(Utf8Path::new("/app/src/index.ts")));
+        assert!(tsconfig.includes_path(Utf8Path::new("/app/src/nested/util.ts")));
+        assert!(tsconfig.includes_path(Utf8Path::new("/app/types/env.d.ts")));
+        assert!(!tsconfig.includes_path(Utf8Path::new("/app/src/index.test.ts")));
+        assert!(!tsconfig.includes_path(Utf8Path::new("/app/vite.config.ts")));
+        assert!(!tsconfig.includes_path(Utf8Path::new("/other/src/index.ts")));
+    }
+
+    #[test]
+    fn test_includes_path_defaults() {
+        let (tsconfig, _) = TsConfigJson::parse(Utf8Path::new("/app/tsconfig.json"), "{}");
+
+        assert!(tsconfig.includes_path(Utf8Path::new("/app/vite.config.ts")));
+        assert!(!tsconfig.includes_path(Utf8Path::new("/app/node_modules/pkg/index.d.ts")));
+
+        let json = r#"{
+            "files": [],
+            "references": [{ "path": "./tsconfig.app.json" }, { "path": "packages/shared" }]
+        }"#;
+
+        let (tsconfig, _) = TsConfigJson::parse(Utf8Path::new("/app/tsconfig.json"), json);
+
+        assert!(tsconfig.is_solution());
+        assert!(!tsconfig.includes_path(Utf8Path::new("/app/src/index.ts")));
+        assert_eq!(
+            tsconfig.reference_paths().collect::<Vec<_>>(),
+            [
+                Utf8PathBuf::from("/app/tsconfig.app.json"),
+                Utf8PathBuf::from("/app/packages/shared/tsconfig.json")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inherit_from() {
+        let base_json = r#"{
+            "compilerOptions": {
+                "baseUrl": ".",
+                "paths": { "@shared/*": ["shared/*"] }
+            },
+            "include": ["src"]
+        }"#;
+        let (base, _) = TsConfigJson::parse(Utf8Path::new("/repo/tsconfig.base.json"), base_json);
+
+        let (mut tsconfig, _) =
+            TsConfigJson::parse(Utf8Path::new("/repo/packages/app/tsconfig.json"), "{}");
+        tsconfig.inherit_from(&base);
+
+        assert_eq!(
+            tsconfig.compiler_options.base_url.as_deref(),
+            Some(Utf8Path::new("/repo"))
+        );
+        assert_eq!(
+            tsconfig.compiler_options.paths_base,
+            Utf8PathBuf::from("/repo")
+        );
+        assert!(tsconfig.compiler_options.paths.is_some());
+        assert!(tsconfig.includes_path(Utf8Path::new("/repo/src/index.ts")));
+        assert!(!tsconfig.includes_path(Utf8Path::new("/repo/packages/app/src/index.ts")));
+
+        let json = r#"{
+            "compilerOptions": {
+                "paths": { "@app/*": ["./src/*"] }
+            }
+        }"#;
+        let (mut tsconfig, _) =
+            TsConfigJson::parse(Utf8Path::new("/repo/packages/app/tsconfig.json"), json);
+        tsconfig.inherit_from(&base);
+
+        // Aliases are resolved relative to the inherited `baseUrl`.
+        assert_eq!(
+            tsconfig.compiler_options.paths_base,
+            Utf8PathBuf::from("/repo")
+        );
+        assert!(tsconfig.matches_path_alias("@app/index"));
+        assert!(!tsconfig.matches_path_alias("@shared/index"));
+    }
 }
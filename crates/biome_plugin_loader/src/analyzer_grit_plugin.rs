@@ -1,21 +1,26 @@
 use crate::{AnalyzerPlugin, PluginDiagnostic, file_matches_includes};
 use biome_analyze::{
-    PluginActionData, PluginDiagnosticEntry, PluginEvalResult, PluginTargetLanguage, RuleDiagnostic,
+    PluginActionData, PluginDiagnosticEntry, PluginEvalResult, PluginTargetLanguage,
+    RuleDiagnostic, ServiceBag,
 };
 use biome_console::markup;
 use biome_css_syntax::{CssRoot, CssSyntaxNode};
 use biome_diagnostics::{Applicability, Severity, category};
 use biome_fs::FileSystem;
 use biome_glob::NormalizedGlob;
+use biome_graphql_syntax::{GraphqlRoot, GraphqlSyntaxNode};
 use biome_grit_patterns::{
     BuiltInFunction, CompilePatternOptions, GritBinding, GritExecContext, GritPattern, GritQuery,
     GritQueryContext, GritQueryEffect, GritQueryState, GritResolvedPattern, GritTargetFile,
     GritTargetLanguage, compile_pattern_with_options,
 };
+use biome_html_syntax::{HtmlRoot, HtmlSyntaxNode};
 use biome_js_syntax::{AnyJsRoot, JsSyntaxNode};
 use biome_json_syntax::{JsonRoot, JsonSyntaxNode};
+use biome_markdown_syntax::{MarkdownSyntaxNode, MdRoot};
 use biome_parser::{AnyParse, NodeParse};
 use biome_rowan::{AnySyntaxNode, AstNode, RawSyntaxKind, SyntaxKind, TextRange};
+use biome_yaml_syntax::{YamlRoot, YamlSyntaxNode};
 use camino::{Utf8Path, Utf8PathBuf};
 use grit_pattern_matcher::{binding::Binding, pattern::ResolvedPattern};
 use grit_util::{AnalysisLogs, error::GritPatternError};
@@ -26,6 +31,13 @@ use std::{borrow::Cow, fmt::Debug, str::FromStr};
 pub struct AnalyzerGritPlugin {
     grit_query: GritQuery,
 
+    /// The name of the rule declared in the plugin manifest, which takes
+    /// precedence over the name of the query.
+    name: Option<String>,
+
+    /// Overrides the severity of the diagnostics registered by the query.
+    severity: Option<Severity>,
+
     /// Glob patterns that restrict which files this plugin runs on.
     /// `None` means the plugin runs on all files.
     /// `Some(&[])` (an empty list) means the plugin never runs on any file.
@@ -53,21 +65,40 @@ impl AnalyzerGritPlugin {
 
         Ok(Self {
             grit_query,
+            name: None,
+            severity: None,
             includes: includes.map(Into::into),
         })
     }
+
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Option<Severity>) -> Self {
+        self.severity = severity;
+        self
+    }
 }
 
 impl AnalyzerPlugin for AnalyzerGritPlugin {
     fn name(&self) -> &str {
-        self.grit_query.name.as_deref().unwrap_or("anonymous")
+        self.name
+            .as_deref()
+            .or(self.grit_query.name.as_deref())
+            .unwrap_or("anonymous")
     }
 
     fn language(&self) -> PluginTargetLanguage {
         match &self.grit_query.language {
             GritTargetLanguage::JsTargetLanguage(_) => PluginTargetLanguage::JavaScript,
             GritTargetLanguage::CssTargetLanguage(_) => PluginTargetLanguage::Css,
+            GritTargetLanguage::GraphqlTargetLanguage(_) => PluginTargetLanguage::Graphql,
+            GritTargetLanguage::HtmlTargetLanguage(_) => PluginTargetLanguage::Html,
             GritTargetLanguage::JsonTargetLanguage(_) => PluginTargetLanguage::Json,
+            GritTargetLanguage::MarkdownTargetLanguage(_) => PluginTargetLanguage::Markdown,
+            GritTargetLanguage::YamlTargetLanguage(_) => PluginTargetLanguage::Yaml,
         }
     }
 
@@ -80,10 +111,25 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
             PluginTargetLanguage::Css => {
                 CssRoot::KIND_SET.iter().map(|kind| kind.to_raw()).collect()
             }
+            PluginTargetLanguage::Graphql => GraphqlRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
+            PluginTargetLanguage::Html => HtmlRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
             PluginTargetLanguage::Json => JsonRoot::KIND_SET
                 .iter()
                 .map(|kind| kind.to_raw())
                 .collect(),
+            PluginTargetLanguage::Markdown => {
+                MdRoot::KIND_SET.iter().map(|kind| kind.to_raw()).collect()
+            }
+            PluginTargetLanguage::Yaml => YamlRoot::KIND_SET
+                .iter()
+                .map(|kind| kind.to_raw())
+                .collect(),
         }
     }
 
@@ -91,7 +137,12 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
         file_matches_includes(self.includes.as_deref(), path)
     }
 
-    fn evaluate(&self, node: AnySyntaxNode, path: Utf8PathBuf) -> PluginEvalResult {
+    fn evaluate(
+        &self,
+        node: AnySyntaxNode,
+        path: Utf8PathBuf,
+        _services: &ServiceBag,
+    ) -> PluginEvalResult {
         let name = self.name();
 
         let (root, source_range, original_text) = match self.language() {
@@ -111,6 +162,22 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
                     (node.as_send(), range, text)
                 })
                 .unwrap(),
+            PluginTargetLanguage::Graphql => node
+                .downcast_ref::<GraphqlSyntaxNode>()
+                .map(|node| {
+                    let range = node.text_range_with_trivia();
+                    let text = node.text_with_trivia().to_string();
+                    (node.as_send(), range, text)
+                })
+                .unwrap(),
+            PluginTargetLanguage::Html => node
+                .downcast_ref::<HtmlSyntaxNode>()
+                .map(|node| {
+                    let range = node.text_range_with_trivia();
+                    let text = node.text_with_trivia().to_string();
+                    (node.as_send(), range, text)
+                })
+                .unwrap(),
             PluginTargetLanguage::Json => node
                 .downcast_ref::<JsonSyntaxNode>()
                 .map(|node| {
@@ -119,6 +186,22 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
                     (node.as_send(), range, text)
                 })
                 .unwrap(),
+            PluginTargetLanguage::Markdown => node
+                .downcast_ref::<MarkdownSyntaxNode>()
+                .map(|node| {
+                    let range = node.text_range_with_trivia();
+                    let text = node.text_with_trivia().to_string();
+                    (node.as_send(), range, text)
+                })
+                .unwrap(),
+            PluginTargetLanguage::Yaml => node
+                .downcast_ref::<YamlSyntaxNode>()
+                .map(|node| {
+                    let range = node.text_range_with_trivia();
+                    let text = node.text_with_trivia().to_string();
+                    (node.as_send(), range, text)
+                })
+                .unwrap(),
         };
 
         let parse = AnyParse::Node(NodeParse::new(root.unwrap(), vec![]));
@@ -164,6 +247,10 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
                         if let Some(ref mut action) = action {
                             action.applicability = applicability;
                         }
+                        let diagnostic = match self.severity {
+                            Some(severity) => diagnostic.with_severity(severity),
+                            None => diagnostic,
+                        };
                         PluginDiagnosticEntry {
                             diagnostic: diagnostic.subcategory(name.to_string()),
                             action,
@@ -1,24 +1,30 @@
 use std::fmt::{Debug, Formatter};
 use std::ops::DerefMut;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use boa_engine::{JsNativeError, JsResult, JsValue};
 use camino::{Utf8Path, Utf8PathBuf};
 
 use biome_analyze::{
-    AnalyzerPlugin, PluginDiagnosticEntry, PluginEvalResult, PluginTargetLanguage, RuleDiagnostic,
+    AnalyzerPlugin, PluginActionData, PluginDiagnosticEntry, PluginEvalResult,
+    PluginTargetLanguage, RuleDiagnostic, ServiceBag,
 };
 use biome_console::markup;
 use biome_diagnostics::category;
 use biome_glob::NormalizedGlob;
-use biome_js_runtime::{JsExecContext, JsPluginRule};
-use biome_js_syntax::JsSyntaxNode;
+use biome_js_runtime::{JsAnalyzedFile, JsExecContext, JsPluginFix, JsPluginRule};
+use biome_js_syntax::{AnyJsRoot, JsSyntaxNode};
+use biome_languages::JsFileSource;
+use biome_module_graph::ModuleDb;
 use biome_resolver::FsWithResolverProxy;
-use biome_rowan::{AnySyntaxNode, RawSyntaxKind, SyntaxKind};
+use biome_rowan::{AnySyntaxNode, AstNode, RawSyntaxKind, SyntaxKind};
 use biome_text_size::TextRange;
+use rustc_hash::FxHashMap;
 
 use crate::PluginDiagnostic;
 use crate::file_matches_includes;
+use crate::rule_settings::PluginRuleSettings;
 use crate::thread_local::ThreadLocalCell;
 
 /// Already loaded plugin in a thread.
@@ -42,6 +48,12 @@ fn load_plugin(fs: Arc<dyn FsWithResolverProxy>, path: &Utf8Path) -> JsResult<Lo
     Ok(LoadedPlugin { ctx, rules })
 }
 
+/// The name of a rule of the plugin, and the syntax kinds it queries.
+struct RuleQuery {
+    name: String,
+    kinds: Vec<RawSyntaxKind>,
+}
+
 /// A JS analyzer plugin.
 /// As the JS engine is intended to run in single thread, plugins are lazily loaded in each thread
 /// just before executing it.
@@ -50,9 +62,15 @@ pub struct AnalyzerJsPlugin {
     path: Utf8PathBuf,
     loaded: ThreadLocalCell<LoadedPlugin>,
 
-    /// The union of the syntax kinds queried by the rules of the plugin.
-    /// Extracted once at load time, since `query()` can be called from threads
-    /// that haven't loaded the plugin yet.
+    /// The rules exported by the plugin. Extracted once at load time, since
+    /// `query()` can be called from threads that haven't loaded the plugin yet.
+    rules: Vec<RuleQuery>,
+
+    /// The settings of the enabled rules, by rule name.
+    /// `None` means all the rules are enabled, with their default settings.
+    settings: Option<FxHashMap<String, PluginRuleSettings>>,
+
+    /// The union of the syntax kinds queried by the enabled rules.
     kinds: Vec<RawSyntaxKind>,
 
     /// Glob patterns that restrict which files this plugin runs on.
@@ -79,22 +97,74 @@ impl AnalyzerJsPlugin {
         // and to extract the queried kinds.
         let plugin = load_plugin(fs.clone(), path)?;
 
-        let mut kinds: Vec<RawSyntaxKind> = plugin
+        let rules: Vec<_> = plugin
             .rules
             .iter()
-            .flat_map(|rule| &rule.kinds)
-            .map(|kind| kind.to_raw())
+            .map(|rule| RuleQuery {
+                name: rule.name.clone(),
+                kinds: rule.kinds.iter().map(|kind| kind.to_raw()).collect(),
+            })
             .collect();
-        kinds.sort_unstable_by_key(|kind| kind.0);
-        kinds.dedup();
 
-        Ok(Self {
+        let mut plugin = Self {
             fs,
             path: path.to_owned(),
             loaded: ThreadLocalCell::new(),
-            kinds,
+            rules,
+            settings: None,
+            kinds: Vec::new(),
             includes: includes.map(Into::into),
-        })
+        };
+        plugin.update_kinds();
+
+        Ok(plugin)
+    }
+
+    /// Returns the names of the rules exported by the plugin.
+    pub fn rule_names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|rule| rule.name.as_str())
+    }
+
+    /// Restricts the plugin to the rules in `settings`, which run with the
+    /// given settings.
+    pub fn with_rules(
+        mut self,
+        settings: FxHashMap<String, PluginRuleSettings>,
+    ) -> Result<Self, PluginDiagnostic> {
+        if let Some(name) = settings
+            .keys()
+            .find(|name| !self.rules.iter().any(|rule| &rule.name == *name))
+        {
+            return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                "The plugin "<Emphasis>{self.path.as_str()}</Emphasis>" doesn't export a rule named "
+                <Emphasis>{name}</Emphasis>"."
+            }));
+        }
+
+        self.settings = Some(settings);
+        self.update_kinds();
+
+        Ok(self)
+    }
+
+    fn rule_settings(&self, name: &str) -> Option<Option<&PluginRuleSettings>> {
+        match &self.settings {
+            Some(settings) => settings.get(name).map(Some),
+            None => Some(None),
+        }
+    }
+
+    fn update_kinds(&mut self) {
+        let mut kinds: Vec<RawSyntaxKind> = self
+            .rules
+            .iter()
+            .filter(|rule| self.rule_settings(&rule.name).is_some())
+            .flat_map(|rule| rule.kinds.iter().copied())
+            .collect();
+        kinds.sort_unstable_by_key(|kind| kind.0);
+        kinds.dedup();
+
+        self.kinds = kinds;
     }
 }
 
@@ -116,7 +186,12 @@ impl AnalyzerPlugin for AnalyzerJsPlugin {
         self.kinds.clone()
     }
 
-    fn evaluate(&self, node: AnySyntaxNode, _path: Utf8PathBuf) -> PluginEvalResult {
+    fn evaluate(
+        &self,
+        node: AnySyntaxNode,
+        path: Utf8PathBuf,
+        services: &ServiceBag,
+    ) -> PluginEvalResult {
         let mut plugin = match self
             .loaded
             .get_mut_or_try_init(|| load_plugin(self.fs.clone(), &self.path))
@@ -152,43 +227,148 @@ impl AnalyzerPlugin for AnalyzerJsPlugin {
         };
 
         let kind = node.kind();
+        let root = node.ancestors().last().unwrap_or_else(|| node.clone());
+        if let Some(root) = AnyJsRoot::cast(root.clone()) {
+            let source_type = services
+                .get_service::<JsFileSource>()
+                .copied()
+                .unwrap_or_default();
+            let mut file = JsAnalyzedFile::new(root, source_type, path);
+            if let Some(module_db) = services.get_service::<Rc<dyn ModuleDb>>() {
+                file = file.with_module_db(module_db.clone());
+            }
+            ctx.set_analyzed_file(file);
+        }
+
         let ast = ctx.create_js_ast(node);
         let mut entries = Vec::new();
 
         for rule in rules.iter().filter(|rule| rule.kinds.contains(&kind)) {
-            let result =
-                ctx.call_function(&rule.run, &JsValue::undefined(), std::slice::from_ref(&ast));
+            let Some(settings) = self.rule_settings(&rule.name) else {
+                continue;
+            };
+
+            let options = settings
+                .and_then(|settings| settings.options.clone())
+                .unwrap_or_else(|| serde_json::Value::Object(Default::default()));
+            let result = ctx.create_js_value(&options).and_then(|options| {
+                ctx.call_function(&rule.run, &JsValue::undefined(), &[ast.clone(), options])
+            });
 
             // Drain the diagnostics even on errors, so a failed rule can't leak
             // its diagnostics into the next one.
-            let mut diagnostics = ctx.pull_diagnostics();
+            let diagnostics = ctx.pull_diagnostics();
 
+            let mut errors = Vec::new();
             if let Err(err) = result {
-                diagnostics.push(RuleDiagnostic::new(
+                errors.push(RuleDiagnostic::new(
                     category!("plugin"),
                     None::<TextRange>,
                     markup!("Rule "<Emphasis>{rule.name}</Emphasis>" errored: "<Error>{err.to_string()}</Error>),
                 ));
             }
 
-            entries.extend(
-                diagnostics
-                    .into_iter()
-                    .map(|diagnostic| PluginDiagnosticEntry {
-                        diagnostic: diagnostic.subcategory(rule.name.clone()),
-                        action: None,
-                    }),
-            );
+            for diagnostic in diagnostics {
+                let action = match diagnostic
+                    .fix
+                    .map(|fix| fix_to_action(&root, fix, &rule.name))
+                {
+                    Some(Ok(action)) => Some(action),
+                    Some(Err(error)) => {
+                        errors.push(RuleDiagnostic::new(
+                            category!("plugin"),
+                            None::<TextRange>,
+                            markup!("Rule "<Emphasis>{rule.name}</Emphasis>" registered an invalid fix: "<Error>{error}</Error>),
+                        ));
+                        None
+                    }
+                    None => None,
+                };
+
+                let diagnostic = match settings.and_then(|settings| settings.severity) {
+                    Some(severity) => diagnostic.diagnostic.with_severity(severity),
+                    None => diagnostic.diagnostic,
+                };
+                entries.push(PluginDiagnosticEntry {
+                    diagnostic: diagnostic.subcategory(rule.name.clone()),
+                    action,
+                });
+            }
+
+            entries.extend(errors.into_iter().map(|diagnostic| PluginDiagnosticEntry {
+                diagnostic: diagnostic.subcategory(rule.name.clone()),
+                action: None,
+            }));
         }
 
+        ctx.clear_analyzed_file();
+
         PluginEvalResult { entries }
     }
 }
 
+/// Converts the fix registered by a plugin into an action rewriting the whole
+/// file, after checking that its edits fit in the file and don't overlap.
+fn fix_to_action(
+    root: &JsSyntaxNode,
+    fix: JsPluginFix,
+    rule_name: &str,
+) -> Result<PluginActionData, String> {
+    let source_range = root.text_range_with_trivia();
+    let original_text = root.text_with_trivia().to_string();
+
+    let mut edits = fix.edits;
+    edits.sort_by_key(|edit| (edit.range.start(), edit.range.end()));
+
+    let mut rewritten_text = String::with_capacity(original_text.len());
+    let mut offset = 0;
+    for edit in &edits {
+        if !source_range.contains_range(edit.range) {
+            return Err(format!(
+                "the edit at {:?} is outside of the file",
+                edit.range
+            ));
+        }
+
+        let start = usize::from(edit.range.start() - source_range.start());
+        let end = usize::from(edit.range.end() - source_range.start());
+        if start < offset {
+            return Err(format!(
+                "the edit at {:?} overlaps another edit",
+                edit.range
+            ));
+        }
+        if !original_text.is_char_boundary(start) || !original_text.is_char_boundary(end) {
+            return Err(format!(
+                "the edit at {:?} doesn't start or end at a character boundary",
+                edit.range
+            ));
+        }
+
+        rewritten_text.push_str(&original_text[offset..start]);
+        rewritten_text.push_str(&edit.text);
+        offset = end;
+    }
+    rewritten_text.push_str(&original_text[offset..]);
+
+    Ok(PluginActionData {
+        source_range,
+        original_text,
+        rewritten_text,
+        message: fix
+            .message
+            .unwrap_or_else(|| format!("Fix suggested by rule `{rule_name}`")),
+        applicability: fix.applicability,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use biome_diagnostics::{DiagnosticExt, Error, PrintDescription, print_diagnostic_to_string};
+    use biome_diagnostics::{
+        Applicability, Diagnostic, DiagnosticExt, Error, PrintDescription, Severity,
+        print_diagnostic_to_string,
+    };
     use biome_fs::MemoryFileSystem;
     use biome_js_parser::JsParserOptions;
     use biome_js_syntax::JsSyntaxKind;
@@ -305,7 +485,11 @@ mod tests {
             JsParserOptions::default(),
         );
 
-        let result = plugin.evaluate(parse.syntax().into(), "/file.js".into());
+        let result = plugin.evaluate(
+            parse.syntax().into(),
+            "/file.js".into(),
+            &ServiceBag::default(),
+        );
 
         let [entry] = result.entries.as_slice() else {
             panic!("expected a single diagnostic, got {result:?}");
@@ -412,7 +596,11 @@ mod tests {
         );
 
         let plugin = load_test_plugin_from_source("/plugin.js", source, None);
-        let result = plugin.evaluate(parse.syntax().into(), "/file.js".into());
+        let result = plugin.evaluate(
+            parse.syntax().into(),
+            "/file.js".into(),
+            &ServiceBag::default(),
+        );
 
         snap_diagnostics(
             "reports_top_level_var_declarations_using_ast_fields",
@@ -455,7 +643,7 @@ mod tests {
                 render_diagnostics(
                     "/file.js",
                     content,
-                    plugin.evaluate(node.into(), "/file.js".into()),
+                    plugin.evaluate(node.into(), "/file.js".into(), &ServiceBag::default()),
                 )
             })
             .collect();
@@ -463,6 +651,317 @@ mod tests {
         snap_diagnostics("dispatches_nodes_to_the_matching_rules", content_rendered);
     }
 
+    fn evaluate_on_module(plugin: &AnalyzerJsPlugin, content: &str) -> PluginEvalResult {
+        let parse = biome_js_parser::parse(
+            content,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let entries = parse
+            .syntax()
+            .descendants()
+            .filter(|node| plugin.query().contains(&node.kind().to_raw()))
+            .flat_map(|node| {
+                plugin
+                    .evaluate(node.into(), "/file.js".into(), &ServiceBag::default())
+                    .entries
+            })
+            .collect();
+
+        PluginEvalResult { entries }
+    }
+
+    fn messages(result: &PluginEvalResult) -> Vec<String> {
+        result
+            .entries
+            .iter()
+            .map(|entry| PrintDescription(&entry.diagnostic).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_bindings_and_references() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import {
+                ast,
+                defineRule,
+                getBinding,
+                getReferences,
+                registerDiagnostic,
+            } from "@biomejs/plugin-api";
+            export const references = defineRule({
+                query: ast("JS_IDENTIFIER_BINDING"),
+                run(binding) {
+                    const kinds = getReferences(binding).map((reference) => reference.kind);
+                    registerDiagnostic(binding, "information", `${binding.text}: ${kinds.join(", ") || "unused"}`);
+                },
+            });
+            export const bindings = defineRule({
+                query: ast("JS_CALL_EXPRESSION"),
+                run(call) {
+                    const binding = getBinding(call.callee);
+                    const message = binding
+                        ? `${call.callee.text} is declared at ${binding.range[0]}`
+                        : `${call.callee.text} is a global`;
+                    registerDiagnostic(call, "information", message);
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(
+            &plugin,
+            "import { used, unused } from \"mod\";\nused();\nlet x = 1;\nx = 2;\nglobalCall();\n",
+        );
+
+        assert_eq!(
+            messages(&result),
+            [
+                "used: read",
+                "unused: unused",
+                "used is declared at 9",
+                "x: write",
+                "globalCall is a global",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_non_binding_nodes_in_get_references() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, getReferences } from "@biomejs/plugin-api";
+            export const references = defineRule({
+                query: ast("JS_CALL_EXPRESSION"),
+                run(call) {
+                    getReferences(call);
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "call();");
+
+        let [message] = messages(&result).try_into().unwrap();
+        assert!(
+            message.contains("getReferences() expects a binding"),
+            "unexpected error: {message}"
+        );
+    }
+
+    /// Types are inferred from the module graph, which isn't available here.
+    #[test]
+    fn types_are_unavailable_without_module_graph() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, getType, registerDiagnostic } from "@biomejs/plugin-api";
+            export const types = defineRule({
+                query: ast("JS_CALL_EXPRESSION"),
+                run(call) {
+                    registerDiagnostic(call, "information", String(getType(call)));
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "call();");
+
+        assert_eq!(messages(&result), ["undefined"]);
+    }
+
+    #[test]
+    fn runs_enabled_rules_with_their_settings() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, registerDiagnostic } from "@biomejs/plugin-api";
+            export const useOptions = defineRule({
+                query: ast("JS_MODULE"),
+                run(root, options) {
+                    registerDiagnostic(root, "information", JSON.stringify(options));
+                },
+            });
+            export const useDefaults = defineRule({
+                query: ast("JS_MODULE"),
+                run(root, options) {
+                    registerDiagnostic(root, "information", JSON.stringify(options));
+                },
+            });
+            export const disabled = defineRule({
+                query: ast("JS_CALL_EXPRESSION"),
+                run(call) {
+                    registerDiagnostic(call, "information", "disabled");
+                },
+            });"#,
+            None,
+        );
+
+        let mut settings = FxHashMap::default();
+        settings.insert(
+            "useOptions".to_string(),
+            PluginRuleSettings {
+                severity: Some(Severity::Error),
+                options: Some(serde_json::json!({ "max": 2 })),
+            },
+        );
+        settings.insert("useDefaults".to_string(), PluginRuleSettings::default());
+        let plugin = plugin.with_rules(settings).unwrap();
+
+        let result = evaluate_on_module(&plugin, "call();");
+
+        // The rules run in the order of their export names.
+        assert_eq!(messages(&result), ["{}", r#"{"max":2}"#]);
+        let severities: Vec<_> = result
+            .entries
+            .iter()
+            .map(|entry| entry.diagnostic.severity())
+            .collect();
+        assert_eq!(severities, [Severity::Information, Severity::Error]);
+    }
+
+    #[test]
+    fn rejects_settings_of_unknown_rules() {
+        let mut settings = FxHashMap::default();
+        settings.insert("useUnknown".to_string(), PluginRuleSettings::default());
+
+        let error = load_test_plugin(None).with_rules(settings).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "The plugin /plugin.js doesn't export a rule named useUnknown."
+        );
+    }
+
+    /// Fixes can mix node replacements and text edits, and are converted to an
+    /// action rewriting the whole file.
+    #[test]
+    fn attaches_fixes_to_diagnostics() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, registerDiagnostic } from "@biomejs/plugin-api";
+            export const noVar = defineRule({
+                query: ast("JS_VARIABLE_STATEMENT"),
+                run(statement) {
+                    const declaration = statement.declaration;
+                    if (declaration?.kindToken !== "var") {
+                        return;
+                    }
+                    const [start] = declaration.range;
+                    registerDiagnostic(statement, "warning", "Use let instead of var.", {
+                        kind: "safe",
+                        message: "Use let instead.",
+                        edits: [
+                            { range: [start, start + 3], text: "let" },
+                            { node: declaration.declarators[0].id, text: "renamed" },
+                        ],
+                    });
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "const kept = 1;\nvar legacy = 2;\n");
+
+        let [entry] = result.entries.as_slice() else {
+            panic!("expected a single diagnostic, got {result:?}");
+        };
+        let action = entry.action.as_ref().expect("the diagnostic has a fix");
+        assert_eq!(action.original_text, "const kept = 1;\nvar legacy = 2;\n");
+        assert_eq!(action.rewritten_text, "const kept = 1;\nlet renamed = 2;\n");
+        assert_eq!(action.message, "Use let instead.");
+        assert_eq!(action.applicability, Applicability::Always);
+    }
+
+    #[test]
+    fn fixes_are_unsafe_by_default() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, registerDiagnostic } from "@biomejs/plugin-api";
+            export const noDebugger = defineRule({
+                query: ast("JS_DEBUGGER_STATEMENT"),
+                run(statement) {
+                    registerDiagnostic(statement, "warning", "Remove debugger.", {
+                        edits: [{ node: statement, text: "" }],
+                    });
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "debugger;");
+
+        let [entry] = result.entries.as_slice() else {
+            panic!("expected a single diagnostic, got {result:?}");
+        };
+        let action = entry.action.as_ref().expect("the diagnostic has a fix");
+        assert_eq!(action.rewritten_text, "");
+        assert_eq!(action.message, "Fix suggested by rule `noDebugger`");
+        assert_eq!(action.applicability, Applicability::MaybeIncorrect);
+    }
+
+    /// Invalid fixes are dropped, but the diagnostic is still reported.
+    #[test]
+    fn reports_invalid_fixes() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, registerDiagnostic } from "@biomejs/plugin-api";
+            export const noDebugger = defineRule({
+                query: ast("JS_DEBUGGER_STATEMENT"),
+                run(statement) {
+                    registerDiagnostic(statement, "warning", "Remove debugger.", {
+                        edits: [
+                            { node: statement, text: "" },
+                            { range: [0, 3], text: "" },
+                        ],
+                    });
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "debugger;");
+
+        let [diagnostic, error] = result.entries.as_slice() else {
+            panic!("expected two diagnostics, got {result:?}");
+        };
+        assert!(diagnostic.action.is_none());
+        assert_eq!(
+            PrintDescription(&error.diagnostic).to_string(),
+            "Rule noDebugger registered an invalid fix: the edit at 0..9 overlaps another edit"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_fixes() {
+        let plugin = load_test_plugin_from_source(
+            "/plugin.js",
+            r#"import { ast, defineRule, registerDiagnostic } from "@biomejs/plugin-api";
+            export const noDebugger = defineRule({
+                query: ast("JS_DEBUGGER_STATEMENT"),
+                run(statement) {
+                    registerDiagnostic(statement, "warning", "Remove debugger.", {
+                        kind: "maybe",
+                        edits: [{ node: statement, text: "" }],
+                    });
+                },
+            });"#,
+            None,
+        );
+
+        let result = evaluate_on_module(&plugin, "debugger;");
+
+        let [error] = result.entries.as_slice() else {
+            panic!("expected a single diagnostic, got {result:?}");
+        };
+        assert!(
+            PrintDescription(&error.diagnostic)
+                .to_string()
+                .contains("Unexpected fix kind"),
+            "unexpected error: {error:?}"
+        );
+    }
+
     /// Plugins can be written in TypeScript: the types are erased before the module is
     /// evaluated by the engine.
     #[test]
@@ -485,7 +984,11 @@ mod tests {
             JsParserOptions::default(),
         );
 
-        let result = plugin.evaluate(parse.syntax().into(), "/file.js".into());
+        let result = plugin.evaluate(
+            parse.syntax().into(),
+            "/file.js".into(),
+            &ServiceBag::default(),
+        );
 
         let [entry] = result.entries.as_slice() else {
             panic!("expected a single diagnostic, got {result:?}");
@@ -547,7 +1050,11 @@ mod tests {
                     JsParserOptions::default(),
                 );
 
-                plugin.evaluate(parse.syntax().into(), "/foo.js".into())
+                plugin.evaluate(
+                    parse.syntax().into(),
+                    "/foo.js".into(),
+                    &ServiceBag::default(),
+                )
             })
         };
 
@@ -561,7 +1068,11 @@ mod tests {
                     JsParserOptions::default(),
                 );
 
-                plugin.evaluate(parse.syntax().into(), "/bar.js".into())
+                plugin.evaluate(
+                    parse.syntax().into(),
+                    "/bar.js".into(),
+                    &ServiceBag::default(),
+                )
             })
         };
 
@@ -7,6 +7,7 @@ use biome_glob::NormalizedGlob;
 use camino::Utf8Path;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     ops::{Deref, DerefMut},
     str::FromStr,
 };
@@ -71,7 +72,8 @@ impl DerefMut for Plugins {
 /// {
 ///   "plugins": [
 ///     "simple-plugin.grit",
-///     { "path": "scoped-plugin.grit", "includes": ["src/**/*.ts"] }
+///     { "path": "scoped-plugin.grit", "includes": ["src/**/*.ts"] },
+///     { "path": "./my-plugin", "rules": { "noFoo": "warn", "useBar": { "level": "error", "options": { "max": 2 } } } }
 ///   ]
 /// }
 /// ```
@@ -102,6 +104,14 @@ impl PluginConfiguration {
             Self::PathWithOptions(opts) => opts.includes.as_deref(),
         }
     }
+
+    /// Returns the configuration of the rules of the plugin, if any.
+    pub fn rules(&self) -> Option<&PluginRules> {
+        match self {
+            Self::Path(_) => None,
+            Self::PathWithOptions(opts) => opts.rules.as_ref(),
+        }
+    }
 }
 
 impl Deserializable for PluginConfiguration {
@@ -131,6 +141,87 @@ pub struct PluginWithOptions {
     /// these patterns. Use negated globs (e.g., `!**/*.test.ts`) for exclusions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub includes: Option<Vec<NormalizedGlob>>,
+
+    /// The configuration of the rules of the plugin, by rule name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<PluginRules>,
+}
+
+/// The configuration of the rules of a plugin, by rule name.
+pub type PluginRules = BTreeMap<String, PluginRuleConfiguration>;
+
+/// Configuration of a plugin rule.
+///
+/// Can be either a severity, or an object with the severity and the options of
+/// the rule, like the configuration of the built-in rules.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
+pub enum PluginRuleConfiguration {
+    Plain(PluginRuleSeverity),
+    WithOptions(PluginRuleWithOptions),
+}
+
+impl PluginRuleConfiguration {
+    pub fn level(&self) -> PluginRuleSeverity {
+        match self {
+            Self::Plain(level) => *level,
+            Self::WithOptions(rule) => rule.level,
+        }
+    }
+
+    pub fn options(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::Plain(_) => None,
+            Self::WithOptions(rule) => rule.options.as_ref(),
+        }
+    }
+}
+
+impl Deserializable for PluginRuleConfiguration {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        rule_name: &str,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Deserializable::deserialize(ctx, value, rule_name).map(Self::Plain)
+        } else {
+            Deserializable::deserialize(ctx, value, rule_name).map(Self::WithOptions)
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, Hash, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PluginRuleSeverity {
+    Off,
+    /// Enables the rule using the default severity of the rule
+    #[default]
+    On,
+    /// Enables the rule, and it will emit a diagnostic with information severity
+    Info,
+    /// Enables the rule, and it will emit a diagnostic with warning severity
+    Warn,
+    /// Enables the rule, and it will emit a diagnostic with error severity
+    Error,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginRuleWithOptions {
+    /// The severity of the diagnostics of the rule.
+    #[deserializable(required)]
+    pub level: PluginRuleSeverity,
+
+    /// The options of the rule. They're validated against the options the
+    /// plugin manifest declares for the rule, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
 }
 
 #[cfg(test)]
@@ -178,6 +269,7 @@ mod tests {
             PluginWithOptions {
                 path: "./my-plugin.grit".into(),
                 includes: Some(vec!["src/**/*.ts".parse().unwrap()]),
+                rules: None,
             },
         )]);
 
@@ -226,6 +318,33 @@ mod tests {
         assert!(result.has_errors());
     }
 
+    #[test]
+    fn deserialize_object_with_rules() {
+        let source = r#"{
+            "path": "./my-plugin",
+            "rules": {
+                "noFoo": "off",
+                "useBar": { "level": "warn", "options": { "max": 2 } }
+            }
+        }"#;
+        let config = deserialize_from_json_str::<PluginConfiguration>(
+            source,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let rules = config.rules().unwrap();
+        assert_eq!(rules["noFoo"].level(), PluginRuleSeverity::Off);
+        assert_eq!(rules["noFoo"].options(), None);
+        assert_eq!(rules["useBar"].level(), PluginRuleSeverity::Warn);
+        assert_eq!(
+            rules["useBar"].options(),
+            Some(&serde_json::json!({ "max": 2 }))
+        );
+    }
+
     #[test]
     fn deserialize_plugins_list_mixed() {
         let plugins: Plugins = serde_json::from_str(
@@ -33,6 +33,10 @@ pub enum PluginDiagnostic {
     /// When something is wrong with the manifest.
     InvalidManifest(InvalidManifest),
 
+    /// When the configuration of a plugin rule doesn't match the rules of the
+    /// plugin, or the options they accept.
+    InvalidRuleConfiguration(InvalidRuleConfiguration),
+
     /// When an analyzer rule plugin uses an unsupported file format.
     UnsupportedRuleFormat(UnsupportedRuleFormat),
 
@@ -102,6 +106,12 @@ impl PluginDiagnostic {
         })
     }
 
+    pub fn invalid_rule_configuration(message: impl Display) -> Self {
+        Self::InvalidRuleConfiguration(InvalidRuleConfiguration {
+            message: MessageAndDescription::from(markup! {{message}}.to_owned()),
+        })
+    }
+
     pub fn unsupported_rule_format(message: impl Display) -> Self {
         Self::UnsupportedRuleFormat(UnsupportedRuleFormat {
             message: MessageAndDescription::from(markup! {{message}}.to_owned()),
@@ -169,6 +179,17 @@ pub struct InvalidManifest {
     source: Option<Error>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "plugin",
+    severity = Error,
+)]
+pub struct InvalidRuleConfiguration {
+    #[message]
+    #[description]
+    pub message: MessageAndDescription,
+}
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "plugin",
@@ -4,6 +4,7 @@ mod analyzer_grit_plugin;
 mod diagnostics;
 mod plugin_cache;
 mod plugin_manifest;
+mod rule_settings;
 
 #[cfg(feature = "js_plugin")]
 mod analyzer_js_plugin;
@@ -19,10 +20,11 @@ pub use analyzer_grit_plugin::AnalyzerGritPlugin;
 pub use configuration::*;
 pub use diagnostics::PluginDiagnostic;
 pub use plugin_cache::*;
+pub use rule_settings::PluginRuleSettings;
 
 use std::sync::Arc;
 
-use biome_analyze::{AnalyzerPlugin, AnalyzerPluginVec};
+use biome_analyze::{AnalyzerPlugin, AnalyzerPluginVec, PluginTargetLanguage};
 use biome_console::markup;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_fs::normalize_path;
@@ -30,7 +32,12 @@ use biome_glob::{CandidatePath, NormalizedGlob};
 use biome_json_parser::JsonParserOptions;
 use biome_resolver::FsWithResolverProxy;
 use camino::{Utf8Path, Utf8PathBuf};
-use plugin_manifest::PluginManifest;
+use plugin_manifest::{
+    PluginManifest, PluginManifestRule, PluginRuleDeclaration, PluginRuleLanguage,
+};
+#[cfg(feature = "js_plugin")]
+use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
 
 #[derive(Debug)]
 pub struct BiomePlugin {
@@ -43,13 +50,17 @@ impl BiomePlugin {
     /// The base path is used to resolve relative paths.
     /// The optional `includes` patterns restrict which files the plugin runs on.
     /// Note: `Some(&[])` (empty includes) means the plugin never matches any file.
+    /// The optional `rules` configure the severity and the options of the rules
+    /// of the plugin, by rule name.
     pub fn load(
         fs: Arc<dyn FsWithResolverProxy>,
         plugin_path: &str,
         base_path: &Utf8Path,
         includes: Option<&[NormalizedGlob]>,
+        rules: Option<&PluginRules>,
     ) -> Result<(Self, Utf8PathBuf), PluginDiagnostic> {
         let plugin_path = normalize_path(&base_path.join(plugin_path));
+        let mut loader = RuleLoader::new(fs.clone(), includes, rules);
 
         // If the plugin path references a `.grit` file directly, treat it as
         // a single-rule plugin instead of going through the manifest process:
@@ -57,28 +68,17 @@ impl BiomePlugin {
             .extension()
             .is_some_and(|extension| extension == "grit")
         {
-            let plugin = AnalyzerGritPlugin::load(fs.as_ref(), &plugin_path, includes)?;
-            return Ok((
-                Self {
-                    analyzer_plugins: vec![Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>)],
-                },
-                plugin_path,
-            ));
+            loader.load_grit_rule(&plugin_path, None)?;
+            return Ok((loader.finish()?, plugin_path));
         }
 
-        // TODO: plugin can have multiple analyser rules
         #[cfg(feature = "js_plugin")]
         if plugin_path
             .extension()
             .is_some_and(|extension| matches!(extension, "js" | "mjs" | "ts" | "mts"))
         {
-            let plugin = AnalyzerJsPlugin::load(fs.clone(), &plugin_path, includes)?;
-            return Ok((
-                Self {
-                    analyzer_plugins: vec![Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>)],
-                },
-                plugin_path,
-            ));
+            loader.load_js_rules(&plugin_path, true, &[])?;
+            return Ok((loader.finish()?, plugin_path));
         }
 
         let manifest_path = plugin_path.join("biome-manifest.jsonc");
@@ -101,30 +101,246 @@ impl BiomePlugin {
             ));
         };
 
-        let plugin = Self {
-            analyzer_plugins: manifest
-                .rules
-                .into_iter()
-                .map(|rule| Utf8PathBuf::from_path_buf(rule).unwrap())
-                .map(|rule| {
-                    if rule.as_os_str().as_encoded_bytes().ends_with(b".grit") {
-                        let plugin = AnalyzerGritPlugin::load(
-                            fs.as_ref(),
-                            &plugin_path.join(rule),
-                            includes,
-                        )?;
-                        Ok(Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>))
-                    } else {
-                        Err(PluginDiagnostic::unsupported_rule_format(markup!(
-                            "Unsupported rule format for plugin rule "
-                            <Emphasis>{rule.to_string()}</Emphasis>
-                        )))
+        // The rules of a JavaScript module are loaded together, since a module
+        // can export several rules.
+        #[cfg(feature = "js_plugin")]
+        let mut js_modules: Vec<JsModuleRules> = Vec::new();
+
+        for rule in &manifest.rules {
+            let path = Utf8PathBuf::from_path_buf(rule.path().clone()).unwrap();
+            let declaration = match rule {
+                PluginManifestRule::Path(_) => None,
+                PluginManifestRule::Declaration(declaration) => Some(declaration),
+            };
+
+            match path.extension() {
+                Some("grit") => loader.load_grit_rule(&plugin_path.join(path), declaration)?,
+                #[cfg(feature = "js_plugin")]
+                Some("js" | "mjs" | "ts" | "mts") => {
+                    let path = plugin_path.join(path);
+                    let index = match js_modules.iter().position(|module| module.path == path) {
+                        Some(index) => index,
+                        None => {
+                            js_modules.push(JsModuleRules {
+                                path,
+                                all_exports: false,
+                                declarations: Vec::new(),
+                            });
+                            js_modules.len() - 1
+                        }
+                    };
+
+                    let module = &mut js_modules[index];
+                    match declaration {
+                        Some(declaration) => module.declarations.push(declaration),
+                        // A plain path loads all the rules the module exports.
+                        None => module.all_exports = true,
                     }
-                })
-                .collect::<Result<_, _>>()?,
+                }
+                _ => {
+                    return Err(PluginDiagnostic::unsupported_rule_format(markup!(
+                        "Unsupported rule format for plugin rule "
+                        <Emphasis>{path.to_string()}</Emphasis>
+                    )));
+                }
+            }
+        }
+
+        #[cfg(feature = "js_plugin")]
+        for module in js_modules {
+            loader.load_js_rules(&module.path, module.all_exports, &module.declarations)?;
+        }
+
+        Ok((loader.finish()?, plugin_path))
+    }
+}
+
+/// Loads the rules of a plugin with the settings resolved from their
+/// declarations and the user configuration.
+struct RuleLoader<'a> {
+    fs: Arc<dyn FsWithResolverProxy>,
+    includes: Option<&'a [NormalizedGlob]>,
+    configuration: Option<&'a PluginRules>,
+
+    /// The names of the rules of the plugin, to report the configured rules
+    /// the plugin doesn't have and the rules declared twice.
+    rule_names: FxHashSet<String>,
+
+    analyzer_plugins: AnalyzerPluginVec,
+}
+
+impl<'a> RuleLoader<'a> {
+    fn new(
+        fs: Arc<dyn FsWithResolverProxy>,
+        includes: Option<&'a [NormalizedGlob]>,
+        configuration: Option<&'a PluginRules>,
+    ) -> Self {
+        Self {
+            fs,
+            includes,
+            configuration,
+            rule_names: FxHashSet::default(),
+            analyzer_plugins: Vec::new(),
+        }
+    }
+
+    /// Registers the rule `name`, and resolves its settings.
+    fn resolve_settings(
+        &mut self,
+        name: &str,
+        declaration: Option<&PluginRuleDeclaration>,
+    ) -> Result<Option<PluginRuleSettings>, PluginDiagnostic> {
+        if !self.rule_names.insert(name.to_string()) {
+            return Err(PluginDiagnostic::invalid_manifest(
+                markup!("The plugin declares the rule "<Emphasis>{name}</Emphasis>" more than once."),
+                None,
+            ));
+        }
+
+        let configuration = self.configuration.and_then(|rules| rules.get(name));
+        PluginRuleSettings::resolve(name, declaration, configuration)
+    }
+
+    fn load_grit_rule(
+        &mut self,
+        path: &Utf8Path,
+        declaration: Option<&PluginRuleDeclaration>,
+    ) -> Result<(), PluginDiagnostic> {
+        let mut plugin = AnalyzerGritPlugin::load(self.fs.as_ref(), path, self.includes)?;
+        if let Some(declaration) = declaration {
+            check_language(declaration, plugin.language())?;
+            plugin = plugin.with_name(declaration.name.clone());
+        }
+
+        let name = plugin.name().to_string();
+        let Some(settings) = self.resolve_settings(&name, declaration)? else {
+            return Ok(());
         };
+        if settings.options.is_some() {
+            return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                "The rule "<Emphasis>{name}</Emphasis>" doesn't accept options: GritQL rules can't read options."
+            }));
+        }
 
-        Ok((plugin, plugin_path))
+        let plugin = plugin.with_severity(settings.severity);
+        self.analyzer_plugins
+            .push(Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>));
+
+        Ok(())
+    }
+
+    /// Loads the rules exported by the JavaScript module at `path`: the
+    /// declared ones, and the others too when `all_exports` is `true`.
+    #[cfg(feature = "js_plugin")]
+    fn load_js_rules(
+        &mut self,
+        path: &Utf8Path,
+        all_exports: bool,
+        declarations: &[&PluginRuleDeclaration],
+    ) -> Result<(), PluginDiagnostic> {
+        let plugin = AnalyzerJsPlugin::load(self.fs.clone(), path, self.includes)?;
+
+        let mut settings = FxHashMap::default();
+        for declaration in declarations {
+            if !plugin.rule_names().any(|name| name == declaration.name) {
+                return Err(PluginDiagnostic::invalid_manifest(
+                    markup! {
+                        "The module "<Emphasis>{path.as_str()}</Emphasis>" doesn't export a rule named "
+                        <Emphasis>{declaration.name.as_str()}</Emphasis>"."
+                    },
+                    None,
+                ));
+            }
+            check_language(declaration, plugin.language())?;
+
+            if let Some(rule_settings) =
+                self.resolve_settings(&declaration.name, Some(declaration))?
+            {
+                settings.insert(declaration.name.clone(), rule_settings);
+            }
+        }
+
+        if all_exports {
+            let undeclared: Vec<String> = plugin
+                .rule_names()
+                .filter(|name| {
+                    !declarations
+                        .iter()
+                        .any(|declaration| declaration.name == *name)
+                })
+                .map(ToString::to_string)
+                .collect();
+            for name in undeclared {
+                if let Some(rule_settings) = self.resolve_settings(&name, None)? {
+                    settings.insert(name, rule_settings);
+                }
+            }
+        }
+
+        if !settings.is_empty() {
+            let plugin = plugin.with_rules(settings)?;
+            self.analyzer_plugins
+                .push(Arc::new(Box::new(plugin) as Box<dyn AnalyzerPlugin>));
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<BiomePlugin, PluginDiagnostic> {
+        if let Some(name) = self
+            .configuration
+            .into_iter()
+            .flat_map(|rules| rules.keys())
+            .find(|name| !self.rule_names.contains(*name))
+        {
+            return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                "The plugin doesn't have a rule named "<Emphasis>{name}</Emphasis>"."
+            }));
+        }
+
+        Ok(BiomePlugin {
+            analyzer_plugins: self.analyzer_plugins,
+        })
+    }
+}
+
+/// The rules loaded from a JavaScript module of a plugin.
+#[cfg(feature = "js_plugin")]
+struct JsModuleRules<'a> {
+    path: Utf8PathBuf,
+    all_exports: bool,
+    declarations: Vec<&'a PluginRuleDeclaration>,
+}
+
+/// Checks that the language declared for a rule in the manifest is the
+/// language of the loaded rule.
+fn check_language(
+    declaration: &PluginRuleDeclaration,
+    language: PluginTargetLanguage,
+) -> Result<(), PluginDiagnostic> {
+    let Some(declared_language) = declaration.language else {
+        return Ok(());
+    };
+
+    let matches = matches!(
+        (declared_language, language),
+        (PluginRuleLanguage::Js, PluginTargetLanguage::JavaScript)
+            | (PluginRuleLanguage::Css, PluginTargetLanguage::Css)
+            | (PluginRuleLanguage::Graphql, PluginTargetLanguage::Graphql)
+            | (PluginRuleLanguage::Html, PluginTargetLanguage::Html)
+            | (PluginRuleLanguage::Json, PluginTargetLanguage::Json)
+            | (PluginRuleLanguage::Markdown, PluginTargetLanguage::Markdown)
+            | (PluginRuleLanguage::Yaml, PluginTargetLanguage::Yaml)
+    );
+    if matches {
+        Ok(())
+    } else {
+        Err(PluginDiagnostic::invalid_manifest(
+            markup! {
+                "The rule "<Emphasis>{declaration.name.as_str()}</Emphasis>" is declared for another language than the one it targets."
+            },
+            None,
+        ))
     }
 }
 
@@ -172,7 +388,7 @@ mod test {
         fs.insert("/my-plugin/rules/1.grit".into(), r#"`hello`"#);
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None)
+        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, None)
             .expect("Couldn't load plugin");
         assert_eq!(plugin.analyzer_plugins.len(), 1);
     }
@@ -183,7 +399,7 @@ mod test {
         fs.insert("/my-plugin/rules/1.grit".into(), r#"`hello`"#);
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None)
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, None)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_without_manifest", error.into());
     }
@@ -200,7 +416,7 @@ mod test {
         );
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None)
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, None)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_with_wrong_version", error.into());
     }
@@ -212,23 +428,112 @@ mod test {
             "/my-plugin/biome-manifest.jsonc".into(),
             r#"{
     "version": 1,
-    "rules": ["rules/1.js"]
+    "rules": ["rules/1.txt"]
 }"#,
         );
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None)
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, None)
             .expect_err("Plugin loading should've failed");
         snap_diagnostic("load_plugin_with_wrong_rule_extension", error.into());
     }
 
+    fn rules_configuration(source: &str) -> PluginRules {
+        deserialize_from_json_str::<PluginRules>(source, JsonParserOptions::default(), "")
+            .into_deserialized()
+            .unwrap()
+    }
+
+    fn insert_declared_rules_plugin(fs: &MemoryFileSystem) {
+        fs.insert(
+            "/my-plugin/biome-manifest.jsonc".into(),
+            r#"{
+    "version": 1,
+    "rules": [
+        { "name": "noHello", "path": "rules/hello.grit", "language": "js", "severity": "warn" },
+        { "name": "noWorld", "path": "rules/world.grit" }
+    ]
+}"#,
+        );
+        fs.insert("/my-plugin/rules/hello.grit".into(), r#"`hello`"#);
+        fs.insert("/my-plugin/rules/world.grit".into(), r#"`world`"#);
+    }
+
+    #[test]
+    fn load_plugin_with_rule_declarations() {
+        let fs = MemoryFileSystem::default();
+        insert_declared_rules_plugin(&fs);
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let rules = rules_configuration(r#"{ "noWorld": "off" }"#);
+        let (plugin, _) =
+            BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, Some(&rules))
+                .expect("Couldn't load plugin");
+
+        let names: Vec<_> = plugin
+            .analyzer_plugins
+            .iter()
+            .map(|plugin| plugin.name())
+            .collect();
+        assert_eq!(names, ["noHello"]);
+    }
+
+    #[test]
+    fn load_plugin_with_unknown_configured_rule() {
+        let fs = MemoryFileSystem::default();
+        insert_declared_rules_plugin(&fs);
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let rules = rules_configuration(r#"{ "noFoo": "error" }"#);
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, Some(&rules))
+            .expect_err("Plugin loading should've failed");
+        snap_diagnostic("load_plugin_with_unknown_configured_rule", error.into());
+    }
+
+    #[test]
+    fn load_plugin_with_options_for_grit_rule() {
+        let fs = MemoryFileSystem::default();
+        insert_declared_rules_plugin(&fs);
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let rules =
+            rules_configuration(r#"{ "noHello": { "level": "error", "options": { "max": 1 } } }"#);
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, Some(&rules))
+            .expect_err("Plugin loading should've failed");
+        assert_eq!(
+            error.to_string(),
+            "The rule noHello doesn't accept options: GritQL rules can't read options."
+        );
+    }
+
+    #[test]
+    fn load_plugin_with_wrong_rule_language() {
+        let fs = MemoryFileSystem::default();
+        fs.insert(
+            "/my-plugin/biome-manifest.jsonc".into(),
+            r#"{
+    "version": 1,
+    "rules": [{ "name": "noHello", "path": "rules/hello.grit", "language": "css" }]
+}"#,
+        );
+        fs.insert("/my-plugin/rules/hello.grit".into(), r#"`hello`"#);
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let error = BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, None)
+            .expect_err("Plugin loading should've failed");
+        assert_eq!(
+            error.to_string(),
+            "The rule noHello is declared for another language than the one it targets."
+        );
+    }
+
     #[test]
     fn load_single_rule_plugin() {
         let fs = MemoryFileSystem::default();
         fs.insert("/my-plugin.grit".into(), r#"`hello`"#);
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.grit", Utf8Path::new("/"), None)
+        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.grit", Utf8Path::new("/"), None, None)
             .expect("Couldn't load plugin");
         assert_eq!(plugin.analyzer_plugins.len(), 1);
     }
@@ -248,7 +553,7 @@ mod test {
         );
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.ts", Utf8Path::new("/"), None)
+        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.ts", Utf8Path::new("/"), None, None)
             .expect("Couldn't load plugin");
 
         assert_eq!(plugin.analyzer_plugins.len(), 1);
@@ -268,9 +573,66 @@ mod test {
         );
 
         let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
-        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.js", Utf8Path::new("/"), None)
+        let (plugin, _) = BiomePlugin::load(fs, "./my-plugin.js", Utf8Path::new("/"), None, None)
             .expect("Couldn't load plugin");
 
         assert_eq!(plugin.analyzer_plugins.len(), 1);
     }
+
+    #[cfg(feature = "js_plugin")]
+    #[test]
+    fn load_multi_rule_js_plugin() {
+        let fs = MemoryFileSystem::default();
+        fs.insert(
+            "/my-plugin/biome-manifest.jsonc".into(),
+            r#"{
+    "version": 1,
+    "rules": [
+        {
+            "name": "useFoo",
+            "path": "rules/index.js",
+            "options": { "max": { "type": "number", "default": 1 } }
+        },
+        "rules/index.js"
+    ]
+}"#,
+        );
+        fs.insert(
+            "/my-plugin/rules/index.js".into(),
+            r#"import { ast, defineRule } from "@biomejs/plugin-api";
+            export const useFoo = defineRule({
+                query: ast("JS_MODULE"),
+                run(root, options) {},
+            });
+            export const useBar = defineRule({
+                query: ast("JS_MODULE"),
+                run(root) {},
+            });"#,
+        );
+
+        let fs = Arc::new(fs) as Arc<dyn FsWithResolverProxy>;
+        let rules =
+            rules_configuration(r#"{ "useFoo": { "level": "warn", "options": { "max": "2" } } }"#);
+        let error = BiomePlugin::load(
+            fs.clone(),
+            "./my-plugin",
+            Utf8Path::new("/"),
+            None,
+            Some(&rules),
+        )
+        .expect_err("Plugin loading should've failed");
+        assert_eq!(
+            error.to_string(),
+            "The option max of the rule useFoo must be of type number."
+        );
+
+        let rules =
+            rules_configuration(r#"{ "useFoo": { "level": "warn", "options": { "max": 2 } } }"#);
+        let (plugin, _) =
+            BiomePlugin::load(fs, "./my-plugin", Utf8Path::new("/"), None, Some(&rules))
+                .expect("Couldn't load plugin");
+
+        // Both rules of the module are loaded in the same plugin.
+        assert_eq!(plugin.analyzer_plugins.len(), 1);
+    }
 }
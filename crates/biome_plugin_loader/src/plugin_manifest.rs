@@ -1,16 +1,147 @@
 use biome_console::markup;
-use biome_deserialize::{DeserializationContext, DeserializationDiagnostic};
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableValue, DeserializationContext,
+    DeserializationDiagnostic,
+};
 use biome_deserialize_macros::Deserializable;
 use biome_rowan::TextRange;
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+use crate::PluginRuleSeverity;
+
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
 pub struct PluginManifest {
     #[deserializable(required, validate = "supported_version")]
     pub version: u8,
 
-    pub rules: Vec<PathBuf>,
+    pub rules: Vec<PluginManifestRule>,
+}
+
+/// A rule of the plugin.
+///
+/// Can be either the path of a rule file, or an object declaring the rule:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "rules": [
+///     "rules/no-foo.grit",
+///     {
+///       "name": "useBar",
+///       "path": "rules/index.js",
+///       "language": "js",
+///       "severity": "warn",
+///       "options": { "max": { "type": "number", "default": 1 } }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginManifestRule {
+    Path(PathBuf),
+    Declaration(PluginRuleDeclaration),
+}
+
+impl PluginManifestRule {
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            Self::Path(path) => path,
+            Self::Declaration(declaration) => &declaration.path,
+        }
+    }
+}
+
+impl Deserializable for PluginManifestRule {
+    fn deserialize(
+        ctx: &mut dyn DeserializationContext,
+        value: &impl DeserializableValue,
+        rule_name: &str,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Deserializable::deserialize(ctx, value, rule_name).map(Self::Path)
+        } else {
+            Deserializable::deserialize(ctx, value, rule_name).map(Self::Declaration)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+pub struct PluginRuleDeclaration {
+    /// The name of the rule. It's used to configure the rule, and to suppress
+    /// its diagnostics.
+    ///
+    /// For JavaScript plugins, it's the name of the export defining the rule.
+    #[deserializable(required)]
+    pub name: String,
+
+    /// The path of the file defining the rule, relative to the manifest.
+    #[deserializable(required)]
+    pub path: PathBuf,
+
+    /// The language the rule targets.
+    pub language: Option<PluginRuleLanguage>,
+
+    /// The severity of the diagnostics of the rule when the user doesn't
+    /// configure one.
+    pub severity: Option<PluginRuleSeverity>,
+
+    /// The options accepted by the rule, by option name.
+    pub options: Option<BTreeMap<String, PluginOptionDeclaration>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq)]
+pub enum PluginRuleLanguage {
+    #[default]
+    Js,
+    Css,
+    Graphql,
+    Html,
+    Json,
+    Markdown,
+    Yaml,
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+pub struct PluginOptionDeclaration {
+    #[deserializable(required, rename = "type")]
+    pub kind: PluginOptionType,
+
+    /// The value of the option when the user doesn't configure it.
+    pub default: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq)]
+pub enum PluginOptionType {
+    #[default]
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl PluginOptionType {
+    pub fn matches(self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
 }
 
 // There's only one manifest version now.
@@ -0,0 +1,216 @@
+use biome_console::markup;
+use biome_diagnostics::Severity;
+
+use crate::plugin_manifest::PluginRuleDeclaration;
+use crate::{PluginDiagnostic, PluginRuleConfiguration, PluginRuleSeverity};
+
+/// The settings a plugin rule runs with, resolved from the declaration of the
+/// rule in the plugin manifest and the user configuration.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PluginRuleSettings {
+    /// Overrides the severity of the diagnostics emitted by the rule.
+    pub severity: Option<Severity>,
+
+    /// The options of the rule, passed to the rule when it runs.
+    pub options: Option<serde_json::Value>,
+}
+
+impl PluginRuleSettings {
+    /// Resolves the settings of the rule `rule_name`.
+    ///
+    /// Returns `None` when the rule is turned off.
+    pub(crate) fn resolve(
+        rule_name: &str,
+        declaration: Option<&PluginRuleDeclaration>,
+        configuration: Option<&PluginRuleConfiguration>,
+    ) -> Result<Option<Self>, PluginDiagnostic> {
+        let default_level = declaration.and_then(|declaration| declaration.severity);
+        let level = configuration
+            .map(PluginRuleConfiguration::level)
+            .or(default_level)
+            .unwrap_or_default();
+
+        let severity = match level {
+            PluginRuleSeverity::Off => return Ok(None),
+            PluginRuleSeverity::On => default_level.and_then(severity_of_level),
+            level => severity_of_level(level),
+        };
+
+        let user_options = configuration.and_then(PluginRuleConfiguration::options);
+        if user_options.is_some_and(|options| !options.is_object()) {
+            return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                "The options of the rule "<Emphasis>{rule_name}</Emphasis>" must be an object."
+            }));
+        }
+
+        let Some(declared_options) =
+            declaration.and_then(|declaration| declaration.options.as_ref())
+        else {
+            return Ok(Some(Self {
+                severity,
+                options: user_options.cloned(),
+            }));
+        };
+
+        let mut options = serde_json::Map::new();
+        for (name, option) in declared_options {
+            if let Some(default) = &option.default {
+                if !option.kind.matches(default) {
+                    return Err(PluginDiagnostic::invalid_manifest(
+                        markup! {
+                            "The default value of the option "<Emphasis>{name}</Emphasis>" of the rule "
+                            <Emphasis>{rule_name}</Emphasis>" must be of type "{option.kind.as_str()}"."
+                        },
+                        None,
+                    ));
+                }
+                options.insert(name.clone(), default.clone());
+            }
+        }
+
+        for (name, value) in user_options
+            .and_then(serde_json::Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            let Some(option) = declared_options.get(name) else {
+                return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                    "The rule "<Emphasis>{rule_name}</Emphasis>" doesn't have an option named "
+                    <Emphasis>{name}</Emphasis>"."
+                }));
+            };
+            if !option.kind.matches(value) {
+                return Err(PluginDiagnostic::invalid_rule_configuration(markup! {
+                    "The option "<Emphasis>{name}</Emphasis>" of the rule "<Emphasis>{rule_name}</Emphasis>
+                    " must be of type "{option.kind.as_str()}"."
+                }));
+            }
+            options.insert(name.clone(), value.clone());
+        }
+
+        Ok(Some(Self {
+            severity,
+            options: Some(serde_json::Value::Object(options)),
+        }))
+    }
+}
+
+fn severity_of_level(level: PluginRuleSeverity) -> Option<Severity> {
+    match level {
+        PluginRuleSeverity::Off | PluginRuleSeverity::On => None,
+        PluginRuleSeverity::Info => Some(Severity::Information),
+        PluginRuleSeverity::Warn => Some(Severity::Warning),
+        PluginRuleSeverity::Error => Some(Severity::Error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PluginRuleWithOptions;
+    use crate::plugin_manifest::{PluginOptionDeclaration, PluginOptionType};
+
+    fn declaration() -> PluginRuleDeclaration {
+        PluginRuleDeclaration {
+            name: "useFoo".into(),
+            path: "rules/foo.js".into(),
+            language: None,
+            severity: Some(PluginRuleSeverity::Warn),
+            options: Some(
+                [
+                    (
+                        "max".to_string(),
+                        PluginOptionDeclaration {
+                            kind: PluginOptionType::Number,
+                            default: Some(serde_json::json!(1)),
+                        },
+                    ),
+                    (
+                        "ignore".to_string(),
+                        PluginOptionDeclaration {
+                            kind: PluginOptionType::Array,
+                            default: None,
+                        },
+                    ),
+                ]
+                .into(),
+            ),
+        }
+    }
+
+    fn with_options(
+        level: PluginRuleSeverity,
+        options: serde_json::Value,
+    ) -> PluginRuleConfiguration {
+        PluginRuleConfiguration::WithOptions(PluginRuleWithOptions {
+            level,
+            options: Some(options),
+        })
+    }
+
+    #[test]
+    fn uses_the_declared_defaults() {
+        let settings = PluginRuleSettings::resolve("useFoo", Some(&declaration()), None).unwrap();
+
+        assert_eq!(
+            settings,
+            Some(PluginRuleSettings {
+                severity: Some(Severity::Warning),
+                options: Some(serde_json::json!({ "max": 1 })),
+            })
+        );
+    }
+
+    #[test]
+    fn overrides_the_defaults_with_the_configuration() {
+        let configuration = with_options(
+            PluginRuleSeverity::Error,
+            serde_json::json!({ "ignore": ["bar"] }),
+        );
+        let settings =
+            PluginRuleSettings::resolve("useFoo", Some(&declaration()), Some(&configuration))
+                .unwrap();
+
+        assert_eq!(
+            settings,
+            Some(PluginRuleSettings {
+                severity: Some(Severity::Error),
+                options: Some(serde_json::json!({ "max": 1, "ignore": ["bar"] })),
+            })
+        );
+    }
+
+    #[test]
+    fn keeps_the_declared_severity_when_on() {
+        let configuration = PluginRuleConfiguration::Plain(PluginRuleSeverity::On);
+        let settings =
+            PluginRuleSettings::resolve("useFoo", Some(&declaration()), Some(&configuration))
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(settings.severity, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn turns_off_rules() {
+        let configuration = PluginRuleConfiguration::Plain(PluginRuleSeverity::Off);
+        let settings =
+            PluginRuleSettings::resolve("useFoo", Some(&declaration()), Some(&configuration))
+                .unwrap();
+
+        assert_eq!(settings, None);
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        let configuration = with_options(PluginRuleSeverity::On, serde_json::json!({ "min": 1 }));
+        let error =
+            PluginRuleSettings::resolve("useFoo", Some(&declaration()), Some(&configuration))
+                .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "The rule useFoo doesn't have an option named min."
+        );
+    }
+}
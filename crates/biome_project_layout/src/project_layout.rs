@@ -1,3 +1,4 @@
+use biome_glob::{CandidatePath, NormalizedGlob};
 use biome_package::{NodeJsPackage, Package, PackageJson, TsConfigJson, TurboJson};
 use biome_rowan::SendNode;
 use camino::{Utf8Path, Utf8PathBuf};
@@ -23,7 +24,20 @@ use std::sync::Arc;
 /// approach makes it very easy for us to invalidate part of the layout when
 /// there are file system changes.
 #[derive(Debug, Default)]
-pub struct ProjectLayout(HashMap<Utf8PathBuf, PackageData, FxBuildHasher>);
+pub struct ProjectLayout {
+    packages: HashMap<Utf8PathBuf, PackageData, FxBuildHasher>,
+
+    /// The workspaces of the layout, keyed by the path of their root package.
+    workspaces: HashMap<Utf8PathBuf, Workspace, FxBuildHasher>,
+
+    /// The TypeScript configurations that are extended or referenced by
+    /// `tsconfig.json` files, such as `tsconfig.base.json`, keyed by their
+    /// path.
+    ///
+    /// Unlike `tsconfig.json` files, they aren't tracked per package, because
+    /// they can have any name and live anywhere, including in dependencies.
+    tsconfig_files: HashMap<Utf8PathBuf, TsConfigJson, FxBuildHasher>,
+}
 
 /// The information tracked for each package.
 ///
@@ -37,6 +51,50 @@ pub struct PackageData {
     node_package: Option<NodeJsPackage>,
 }
 
+/// The packages of a workspace, indexed by name.
+///
+/// The index is built when the manifest of the root package is inserted, and
+/// updated when the manifest of one of its packages is inserted. Entries are
+/// not removed when a package is renamed or removed, so lookups must check that
+/// the package still has the name it is indexed with.
+#[derive(Debug)]
+struct Workspace {
+    /// The workspace patterns of the root package.
+    globs: Vec<NormalizedGlob>,
+
+    /// The paths of the workspace packages, keyed by their name.
+    packages: HashMap<Box<str>, Utf8PathBuf, FxBuildHasher>,
+}
+
+impl Workspace {
+    fn new(manifest: &PackageJson) -> Self {
+        Self {
+            globs: manifest
+                .workspace_patterns()
+                .iter()
+                .filter_map(|pattern| pattern.trim_end_matches('/').parse().ok())
+                .collect(),
+            packages: Default::default(),
+        }
+    }
+
+    /// Returns whether the package at `package_path` is part of the workspace
+    /// whose root is at `root_path`.
+    ///
+    /// Packages inside `node_modules` are never part of the workspace.
+    fn contains(&self, root_path: &Utf8Path, package_path: &Utf8Path) -> bool {
+        let Ok(relative_path) = package_path.strip_prefix(root_path) else {
+            return false;
+        };
+
+        !relative_path.as_str().is_empty()
+            && !relative_path
+                .components()
+                .any(|component| component.as_str() == "node_modules")
+            && CandidatePath::new(relative_path).matches_with_exceptions(&self.globs)
+    }
+}
+
 impl ProjectLayout {
     /// Returns the `package.json` that should be used for the given `path`,
     /// together with the absolute path of the package in which it was found.
@@ -47,7 +105,7 @@ impl ProjectLayout {
         &self,
         path: &Utf8Path,
     ) -> Option<(Utf8PathBuf, PackageJson)> {
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         path.ancestors().find_map(|package_path| {
             packages
                 .get(package_path)
@@ -63,7 +121,7 @@ impl ProjectLayout {
     /// hierarchy, but only returns the one that is stored in the layout for
     /// the given `package_path`.
     pub fn get_node_manifest_for_package(&self, package_path: &Utf8Path) -> Option<PackageJson> {
-        self.0
+        self.packages
             .pin()
             .get(package_path)
             .and_then(|data| data.node_package.as_ref())
@@ -95,13 +153,73 @@ impl ProjectLayout {
         None
     }
 
+    /// Returns the `package.json` of the workspace root that the given `path`
+    /// belongs to, together with the absolute path of the root package.
+    ///
+    /// The workspace root is the closest ancestor package that declares
+    /// workspace patterns, either through the `workspaces` field of its
+    /// `package.json` or through a `pnpm-workspace.yaml`.
+    pub fn find_workspace_root_for_path(
+        &self,
+        path: &Utf8Path,
+    ) -> Option<(Utf8PathBuf, PackageJson)> {
+        let packages = self.packages.pin();
+        path.ancestors().find_map(|package_path| {
+            packages
+                .get(package_path)
+                .and_then(|data| data.node_package.as_ref())
+                .and_then(|node_package| node_package.manifest.as_ref())
+                .filter(|manifest| !manifest.workspace_patterns().is_empty())
+                .map(|manifest| (package_path.to_path_buf(), manifest.clone()))
+        })
+    }
+
+    /// Returns the path of the workspace package with the given
+    /// `package_name`, from the workspace that the given `path` belongs to.
+    pub fn find_workspace_package(
+        &self,
+        path: &Utf8Path,
+        package_name: &str,
+    ) -> Option<Utf8PathBuf> {
+        let workspaces = self.workspaces.pin();
+        let workspace = path
+            .ancestors()
+            .find_map(|root_path| workspaces.get(root_path))?;
+        let package_path = workspace.packages.pin().get(package_name)?.clone();
+
+        let has_name = self
+            .packages
+            .pin()
+            .get(&package_path)
+            .and_then(|data| data.node_package.as_ref())
+            .and_then(|node_package| node_package.manifest.as_ref())
+            .is_some_and(|manifest| manifest.name.as_deref() == Some(package_name));
+        has_name.then_some(package_path)
+    }
+
+    /// Returns the `tsconfig.json` that is closest to the given `path`.
+    ///
+    /// This function will look for the closest `tsconfig.json` file in the
+    /// ancestors of the given `path`, and returns the first one it finds.
+    /// Note that the returned configuration may not include the file at
+    /// `path`, in which case one of its references might.
+    pub fn find_tsconfig_json_for_path(&self, path: &Utf8Path) -> Option<TsConfigJson> {
+        let packages = self.packages.pin();
+        path.ancestors().find_map(|package_path| {
+            packages
+                .get(package_path)
+                .and_then(|data| data.node_package.as_ref())
+                .and_then(|node_package| node_package.tsconfig.clone())
+        })
+    }
+
     /// Returns the `tsconfig.json` inside the given `package_path`.
     ///
     /// This function does not look for the closest `tsconfig.json` file in the
     /// hierarchy, but only returns the one that is stored in the layout for
     /// the given `package_path`.
     pub fn get_tsconfig_json_for_package(&self, package_path: &Utf8Path) -> Option<TsConfigJson> {
-        self.0
+        self.packages
             .pin()
             .get(package_path)
             .and_then(|data| data.node_package.as_ref())
@@ -109,6 +227,18 @@ impl ProjectLayout {
             .cloned()
     }
 
+    /// Returns the TypeScript configuration at the given `path`, if it is
+    /// tracked as an extended or referenced configuration.
+    pub fn get_tsconfig_file(&self, path: &Utf8Path) -> Option<TsConfigJson> {
+        self.tsconfig_files.pin().get(path).cloned()
+    }
+
+    /// Returns whether the file at the given `path` is tracked as an extended
+    /// or referenced TypeScript configuration.
+    pub fn is_tsconfig_file(&self, path: &Utf8Path) -> bool {
+        self.tsconfig_files.pin().contains_key(path)
+    }
+
     /// Returns the `turbo.json` that should be used for the given `path`,
     /// together with the absolute path of the package in which it was found.
     ///
@@ -118,7 +248,7 @@ impl ProjectLayout {
         &self,
         path: &Utf8Path,
     ) -> Option<(Utf8PathBuf, Arc<TurboJson>)> {
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         path.ancestors().find_map(|package_path| {
             packages
                 .get(package_path)
@@ -137,7 +267,7 @@ impl ProjectLayout {
     /// This function returns all turbo.json files found in the ancestors of
     /// the given path, ordered from closest (package-level) to furthest (root).
     pub fn find_all_turbo_json_for_path(&self, path: &Utf8Path) -> Vec<Arc<TurboJson>> {
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         path.ancestors()
             .filter_map(|package_path| {
                 packages
@@ -155,7 +285,7 @@ impl ProjectLayout {
     /// hierarchy, but only returns the one that is stored in the layout for
     /// the given `package_path`.
     pub fn get_turbo_json_for_package(&self, package_path: &Utf8Path) -> Option<Arc<TurboJson>> {
-        self.0
+        self.packages
             .pin()
             .get(package_path)
             .and_then(|data| data.node_package.as_ref())
@@ -168,8 +298,8 @@ impl ProjectLayout {
     /// `path` refers to the package directory, not the `package.json` file
     /// itself.
     pub fn insert_node_manifest(&self, path: Utf8PathBuf, manifest: PackageJson) {
-        self.0.pin().update_or_insert_with(
-            path,
+        self.packages.pin().update_or_insert_with(
+            path.clone(),
             |data| {
                 let node_js_package = NodeJsPackage {
                     manifest: Some(manifest.clone()),
@@ -199,6 +329,7 @@ impl ProjectLayout {
                 }
             },
         );
+        self.index_workspace_package(&path);
     }
 
     /// Inserts a `tsconfig.json` manifest for the package at the given `path`.
@@ -206,7 +337,7 @@ impl ProjectLayout {
     /// `path` refers to the package directory, not the `package.json` file
     /// itself.
     pub fn insert_tsconfig(&self, path: Utf8PathBuf, tsconfig: TsConfigJson) {
-        self.0.pin().update_or_insert_with(
+        self.packages.pin().update_or_insert_with(
             path,
             |data| {
                 let node_js_package = NodeJsPackage {
@@ -239,13 +370,29 @@ impl ProjectLayout {
         );
     }
 
+    /// Inserts a TypeScript configuration that is extended or referenced by
+    /// a `tsconfig.json`.
+    ///
+    /// `path` refers to the configuration file itself.
+    pub fn insert_tsconfig_file(&self, path: Utf8PathBuf, tsconfig: TsConfigJson) {
+        self.tsconfig_files.pin().insert(path, tsconfig);
+    }
+
+    /// Removes the extended or referenced TypeScript configuration at the
+    /// given `path`.
+    ///
+    /// Returns whether the configuration was tracked.
+    pub fn remove_tsconfig_file(&self, path: &Utf8Path) -> bool {
+        self.tsconfig_files.pin().remove(path).is_some()
+    }
+
     /// Inserts a `turbo.json` manifest for the package at the given `path`.
     ///
     /// `path` refers to the package directory, not the `turbo.json` file
     /// itself.
     pub fn insert_turbo_json(&self, path: Utf8PathBuf, turbo_json: TurboJson) {
         let turbo_json = Arc::new(turbo_json);
-        self.0.pin().update_or_insert_with(
+        self.packages.pin().update_or_insert_with(
             path,
             |data| {
                 let node_js_package = NodeJsPackage {
@@ -283,7 +430,7 @@ impl ProjectLayout {
     ///
     /// See also [Self::insert_node_manifest()].
     pub fn insert_serialized_node_manifest(&self, path: Utf8PathBuf, manifest: &SendNode) {
-        self.0.pin().update_or_insert_with(
+        self.packages.pin().update_or_insert_with(
             path.clone(),
             |data| {
                 let mut node_js_package = NodeJsPackage {
@@ -319,12 +466,74 @@ impl ProjectLayout {
                 }
             },
         );
+        self.index_workspace_package(&path);
+    }
+
+    /// Updates the workspace index after the `package.json` of the package at
+    /// the given `path` was inserted.
+    ///
+    /// If the package is the root of a workspace, the packages of its
+    /// workspace are indexed again. If it is part of a workspace, it is
+    /// indexed under its name.
+    fn index_workspace_package(&self, path: &Utf8Path) {
+        let packages = self.packages.pin();
+        let Some(manifest) = packages
+            .get(path)
+            .and_then(|data| data.node_package.as_ref())
+            .and_then(|node_package| node_package.manifest.as_ref())
+        else {
+            return;
+        };
+
+        let workspaces = self.workspaces.pin();
+        if manifest.workspace_patterns().is_empty() {
+            workspaces.remove(path);
+        } else {
+            // The workspace is inserted before its packages are indexed, so
+            // that packages inserted in the meantime index themselves.
+            workspaces.insert(path.to_path_buf(), Workspace::new(manifest));
+            let Some(workspace) = workspaces.get(path) else {
+                return;
+            };
+            let workspace_packages = workspace.packages.pin();
+            for (package_path, data) in packages.iter() {
+                let name = data
+                    .node_package
+                    .as_ref()
+                    .and_then(|node_package| node_package.manifest.as_ref())
+                    .and_then(|manifest| manifest.name.as_deref());
+                if let Some(name) = name
+                    && workspace.contains(path, package_path)
+                {
+                    workspace_packages.insert(name.into(), package_path.clone());
+                }
+            }
+        }
+
+        let Some(name) = manifest.name.as_deref() else {
+            return;
+        };
+        let enclosing_workspace = path.parent().and_then(|parent| {
+            parent.ancestors().find_map(|root_path| {
+                workspaces
+                    .get(root_path)
+                    .map(|workspace| (root_path, workspace))
+            })
+        });
+        if let Some((root_path, workspace)) = enclosing_workspace
+            && workspace.contains(root_path, path)
+        {
+            workspace
+                .packages
+                .pin()
+                .insert(name.into(), path.to_path_buf());
+        }
     }
 
     /// Inserts a `tsconfig.json` manifest for the package at the given `path`,
     /// parsing the manifest on demand.
     pub fn insert_serialized_tsconfig(&self, path: Utf8PathBuf, manifest: &SendNode) {
-        self.0.pin().update_or_insert_with(
+        self.packages.pin().update_or_insert_with(
             path.clone(),
             |data| {
                 let mut node_js_package = NodeJsPackage {
@@ -372,7 +581,7 @@ impl ProjectLayout {
         manifest: &SendNode,
         filename: &str,
     ) {
-        self.0.pin().update_or_insert_with(
+        self.packages.pin().update_or_insert_with(
             path.clone(),
             |data| {
                 let mut node_js_package = NodeJsPackage {
@@ -417,7 +626,7 @@ impl ProjectLayout {
     pub fn is_indexed(&self, path: &Utf8Path) -> bool {
         path.parent()
             .and_then(|package_path| {
-                self.0
+                self.packages
                     .pin()
                     .get(package_path)
                     .and_then(|data| data.node_package.as_ref())
@@ -433,7 +642,7 @@ impl ProjectLayout {
 
     /// Returns all package paths currently tracked in the layout.
     pub fn package_paths(&self) -> Vec<Utf8PathBuf> {
-        self.0.pin().keys().cloned().collect()
+        self.packages.pin().keys().cloned().collect()
     }
 
     /// Searches for the `tsconfig.json` file nearest to `path` and calls
@@ -445,7 +654,7 @@ impl ProjectLayout {
         F: Fn(&TsConfigJson) -> R,
     {
         let query = &query;
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         path.ancestors().find_map(|package_path| {
             packages
                 .get(package_path)
@@ -458,12 +667,14 @@ impl ProjectLayout {
     /// Removes a `tsconfig.json` manifest from the package with the given
     /// `path`.
     pub fn remove_tsconfig_from_package(&self, path: &Utf8Path) {
-        self.0.pin().update(path.to_path_buf(), |data| PackageData {
-            node_package: data
-                .node_package
-                .as_ref()
-                .map(NodeJsPackage::without_tsconfig),
-        });
+        self.packages
+            .pin()
+            .update(path.to_path_buf(), |data| PackageData {
+                node_package: data
+                    .node_package
+                    .as_ref()
+                    .map(NodeJsPackage::without_tsconfig),
+            });
     }
 
     /// Searches for the `turbo.json` file nearest to `path` and calls
@@ -475,7 +686,7 @@ impl ProjectLayout {
         F: Fn(&TurboJson) -> R,
     {
         let query = &query;
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         path.ancestors().find_map(|package_path| {
             packages
                 .get(package_path)
@@ -488,26 +699,43 @@ impl ProjectLayout {
     /// Removes a `turbo.json` manifest from the package with the given
     /// `path`.
     pub fn remove_turbo_json_from_package(&self, path: &Utf8Path) {
-        self.0.pin().update(path.to_path_buf(), |data| PackageData {
-            node_package: data
-                .node_package
-                .as_ref()
-                .map(NodeJsPackage::without_turbo_json),
-        });
+        self.packages
+            .pin()
+            .update(path.to_path_buf(), |data| PackageData {
+                node_package: data
+                    .node_package
+                    .as_ref()
+                    .map(NodeJsPackage::without_turbo_json),
+            });
     }
 
     /// Removes a package and its metadata from the project layout.
     pub fn remove_package(&self, path: &Utf8Path) {
-        self.0.pin().remove(path);
+        self.packages.pin().remove(path);
+        self.workspaces.pin().remove(path);
     }
 
     /// Unloads all paths from the graph within the given `path`.
     pub fn unload_folder(&self, path: &Utf8Path) {
-        let packages = self.0.pin();
+        let packages = self.packages.pin();
         for package_path in packages.keys() {
             if package_path.starts_with(path) {
                 packages.remove(package_path);
             }
         }
+
+        let workspaces = self.workspaces.pin();
+        for root_path in workspaces.keys() {
+            if root_path.starts_with(path) {
+                workspaces.remove(root_path);
+            }
+        }
+
+        let tsconfig_files = self.tsconfig_files.pin();
+        for tsconfig_path in tsconfig_files.keys() {
+            if tsconfig_path.starts_with(path) {
+                tsconfig_files.remove(tsconfig_path);
+            }
+        }
     }
 }
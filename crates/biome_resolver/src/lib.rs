@@ -3,6 +3,7 @@
 mod errors;
 mod node_builtins;
 mod resolver_fs_proxy;
+mod tsconfig;
 
 use std::{borrow::Cow, cmp::Ordering, ops::Deref, sync::Arc};
 
@@ -14,6 +15,7 @@ use camino::{Utf8Path, Utf8PathBuf};
 pub use errors::*;
 pub use node_builtins::is_builtin_node_module;
 pub use resolver_fs_proxy::*;
+pub use tsconfig::{find_tsconfig_for_file, load_tsconfig};
 
 /// Resolves the given `specifier` from the given `base_dir`.
 ///
@@ -220,9 +222,9 @@ fn resolve_module_with_package_json(
     // `tsconfig.json` may only be found in directories containing a
     // `package.json`, so this is the only place we need to attempt to use it.
     let tsconfig = match &options.tsconfig {
-        DiscoverableManifest::Auto => fs
-            .read_tsconfig_json(&package_path.join("tsconfig.json"))
-            .map(Cow::Owned),
+        DiscoverableManifest::Auto => {
+            load_tsconfig(&package_path.join("tsconfig.json"), fs).map(Cow::Owned)
+        }
         DiscoverableManifest::Explicit { manifest, .. } => Ok(Cow::Borrowed(*manifest)),
         DiscoverableManifest::Off => Err(ResolveError::NotFound),
     };
@@ -567,6 +569,17 @@ fn resolve_dependency(
 ) -> Result<Utf8PathBuf, ResolveError> {
     let (package_name, subpath) = parse_package_specifier(specifier)?;
 
+    // Packages of the same workspace are resolved to their source, rather than
+    // to whatever build output ended up in `node_modules/`.
+    if let Ok(package_path) = fs.find_workspace_package(base_dir, package_name) {
+        let options = options.for_workspace_package();
+        match resolve_package_path(&package_path, subpath, fs, &options) {
+            Ok(path) => return Ok(path),
+            Err(ResolveError::NotFound) => { /* continue */ }
+            Err(error) => return Err(error),
+        }
+    }
+
     for type_root in options.type_roots.explicit_roots() {
         let package_path = base_dir.join(type_root).join(package_name);
         match resolve_package_path(&package_path, subpath, fs, options) {
@@ -919,6 +932,16 @@ pub struct ResolveOptions<'a> {
     ///
     /// Only used when [`Self::resolve_types`] is `true`.
     pub type_roots: TypeRoots<'a>,
+
+    /// Condition names to accept for the `exports` field of packages that are
+    /// part of the same workspace as the importing module.
+    ///
+    /// Workspace packages are often imported before they have been built, so
+    /// this allows conditions such as `"source"` to point the resolver at their
+    /// source files instead of their build output.
+    ///
+    /// If empty, [`Self::condition_names`] is used for workspace packages too.
+    pub workspace_condition_names: &'a [&'a str],
 }
 
 impl Default for ResolveOptions<'_> {
@@ -941,6 +964,7 @@ impl<'a> ResolveOptions<'a> {
             resolve_types: false,
             tsconfig: DiscoverableManifest::Auto,
             type_roots: TypeRoots::Auto,
+            workspace_condition_names: &[],
         }
     }
 
@@ -1016,6 +1040,24 @@ impl<'a> ResolveOptions<'a> {
         self
     }
 
+    /// Sets [`Self::workspace_condition_names`] and returns this instance.
+    pub const fn with_workspace_condition_names(
+        mut self,
+        workspace_condition_names: &'a [&'a str],
+    ) -> Self {
+        self.workspace_condition_names = workspace_condition_names;
+        self
+    }
+
+    /// Returns the options to use for resolving inside a workspace package.
+    fn for_workspace_package(&self) -> Self {
+        let mut options = self.clone();
+        if !self.workspace_condition_names.is_empty() {
+            options.condition_names = self.workspace_condition_names;
+        }
+        options
+    }
+
     fn with_type_roots_and_without_manifests(&self, type_roots: TypeRoots<'a>) -> Self {
         Self {
             assume_relative: self.assume_relative,
@@ -1028,6 +1070,7 @@ impl<'a> ResolveOptions<'a> {
             resolve_types: self.resolve_types,
             tsconfig: DiscoverableManifest::Off,
             type_roots,
+            workspace_condition_names: self.workspace_condition_names,
         }
     }
 
@@ -1043,6 +1086,7 @@ impl<'a> ResolveOptions<'a> {
             resolve_types: self.resolve_types,
             tsconfig: DiscoverableManifest::Off,
             type_roots: self.type_roots,
+            workspace_condition_names: self.workspace_condition_names,
         }
     }
 }
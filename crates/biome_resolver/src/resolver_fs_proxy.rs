@@ -55,6 +55,29 @@ pub trait ResolverFsProxy {
 
     /// Reads the `tsconfig.json` manifest at the given path.
     fn read_tsconfig_json(&self, path: &Utf8Path) -> Result<TsConfigJson, ResolveError>;
+
+    /// Finds the `tsconfig.json` in `search_dir` or one of its parents.
+    ///
+    /// The configurations it extends are not applied.
+    fn find_tsconfig_json(&self, search_dir: &Utf8Path) -> Result<TsConfigJson, ResolveError> {
+        search_dir
+            .ancestors()
+            .find_map(|dir| self.read_tsconfig_json(&dir.join("tsconfig.json")).ok())
+            .ok_or(ResolveError::NotFound)
+    }
+
+    /// Finds the package named `package_name` among the packages of the
+    /// workspace that `search_dir` belongs to, and returns its path.
+    ///
+    /// Discovering workspace packages requires knowledge of the entire
+    /// workspace, so the default implementation doesn't find any.
+    fn find_workspace_package(
+        &self,
+        _search_dir: &Utf8Path,
+        _package_name: &str,
+    ) -> Result<Utf8PathBuf, ResolveError> {
+        Err(ResolveError::NotFound)
+    }
 }
 
 pub trait FsWithResolverProxy: FileSystem + ResolverFsProxy {}
@@ -0,0 +1,123 @@
+use biome_package::TsConfigJson;
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::{DiscoverableManifest, ResolveError, ResolveOptions, ResolverFsProxy, resolve};
+
+/// Maximum length of an `extends` chain, which protects us against cycles.
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+/// Returns the options for resolving the specifiers inside the `extends`
+/// field.
+///
+/// Specifiers may be relative paths, with or without the `.json` extension,
+/// or refer to a configuration inside a package, in which case the package's
+/// `tsconfig.json` is used if no file inside the package is given.
+fn extends_resolve_options() -> ResolveOptions<'static> {
+    ResolveOptions::new()
+        .with_condition_names(&["default"])
+        .with_default_files(&["tsconfig"])
+        .with_extensions(&["json"])
+        .with_package_json(DiscoverableManifest::Off)
+        .with_tsconfig(DiscoverableManifest::Off)
+}
+
+/// Returns the `tsconfig.json` of the project that the file at the given
+/// `file_path` belongs to, with the configurations it extends applied.
+///
+/// This is the closest `tsconfig.json` in the ancestors of the file, unless
+/// that configuration doesn't include the file. In that case, its project
+/// references are searched for a project that does include the file, which
+/// is how TypeScript picks the project for a file in solution-style setups.
+/// If no referenced project includes the file, the closest configuration is
+/// returned regardless.
+pub fn find_tsconfig_for_file(
+    file_path: &Utf8Path,
+    fs: &dyn ResolverFsProxy,
+) -> Result<TsConfigJson, ResolveError> {
+    let search_dir = file_path.parent().ok_or(ResolveError::NotFound)?;
+    let tsconfig = apply_extends(fs.find_tsconfig_json(search_dir)?, fs, 0);
+    if owns_file(&tsconfig, file_path) {
+        return Ok(tsconfig);
+    }
+
+    let mut visited = vec![tsconfig.path.clone()];
+    Ok(find_referenced_project(&tsconfig, file_path, fs, &mut visited).unwrap_or(tsconfig))
+}
+
+/// Reads the `tsconfig.json` at the given `path`, and applies the
+/// configurations it extends.
+///
+/// Extended configurations that cannot be found are ignored.
+pub fn load_tsconfig(
+    path: &Utf8Path,
+    fs: &dyn ResolverFsProxy,
+) -> Result<TsConfigJson, ResolveError> {
+    fs.read_tsconfig_json(path)
+        .map(|tsconfig| apply_extends(tsconfig, fs, 0))
+}
+
+fn apply_extends(
+    mut tsconfig: TsConfigJson,
+    fs: &dyn ResolverFsProxy,
+    depth: usize,
+) -> TsConfigJson {
+    let Some(extends) = tsconfig.extends.clone() else {
+        return tsconfig;
+    };
+    if depth >= MAX_EXTENDS_DEPTH {
+        return tsconfig;
+    }
+
+    let options = extends_resolve_options();
+    let directory = tsconfig
+        .path
+        .parent()
+        .map_or_else(Utf8PathBuf::new, Utf8Path::to_path_buf);
+
+    // Settings from later entries override those from earlier ones, and
+    // inheriting only fills in missing settings, so we go in reverse.
+    for specifier in extends.as_slice().iter().rev() {
+        let Ok(path) = resolve(specifier, &directory, fs, &options) else {
+            continue;
+        };
+        if path == tsconfig.path {
+            continue;
+        }
+
+        if let Ok(base) = fs.read_tsconfig_json(&path) {
+            tsconfig.inherit_from(&apply_extends(base, fs, depth + 1));
+        }
+    }
+
+    tsconfig
+}
+
+fn find_referenced_project(
+    tsconfig: &TsConfigJson,
+    file_path: &Utf8Path,
+    fs: &dyn ResolverFsProxy,
+    visited: &mut Vec<Utf8PathBuf>,
+) -> Option<TsConfigJson> {
+    for path in tsconfig.reference_paths() {
+        if visited.contains(&path) {
+            continue;
+        }
+        visited.push(path.clone());
+
+        let Ok(reference) = load_tsconfig(&path, fs) else {
+            continue;
+        };
+        if owns_file(&reference, file_path) {
+            return Some(reference);
+        }
+        if let Some(project) = find_referenced_project(&reference, file_path, fs, visited) {
+            return Some(project);
+        }
+    }
+
+    None
+}
+
+fn owns_file(tsconfig: &TsConfigJson, file_path: &Utf8Path) -> bool {
+    !tsconfig.is_solution() && tsconfig.includes_path(file_path)
+}
@@ -0,0 +1 @@
+import { Hello } from '@/components/Hello';
@@ -0,0 +1 @@
+import { plugin } from '@/plugin';
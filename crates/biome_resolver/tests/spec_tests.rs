@@ -924,3 +924,59 @@ fn test_resolve_exports_pattern_specificity() {
         "null target should block resolution",
     );
 }
+
+#[test]
+fn test_resolve_with_tsconfig_of_referenced_project() {
+    let base_dir = get_fixtures_path("resolver_cases_10");
+    let fs = OsFileSystem::new(base_dir.clone());
+
+    let options = ResolveOptions {
+        default_files: &["index"],
+        extensions: &["ts", "js"],
+        ..Default::default()
+    };
+
+    // Files in `src/` are owned by `tsconfig.app.json`, which inherits its
+    // aliases from `tsconfig.base.json`.
+    let app_tsconfig = find_tsconfig_for_file(&base_dir.join("src/index.ts"), &fs).unwrap();
+    assert_eq!(app_tsconfig.path, base_dir.join("tsconfig.app.json"));
+    assert_eq!(
+        resolve(
+            "@/components/Hello",
+            &base_dir.join("src"),
+            &fs,
+            &options
+                .clone()
+                .with_tsconfig(DiscoverableManifest::Explicit {
+                    package_path: base_dir.clone(),
+                    manifest: &app_tsconfig,
+                })
+        ),
+        Ok(Utf8PathBuf::from(format!(
+            "{base_dir}/src/components/Hello.ts"
+        )))
+    );
+
+    // `vite.config.ts` is owned by `tsconfig.node.json`, which inherits its
+    // `baseUrl` from a configuration inside `node_modules/`.
+    let node_tsconfig = find_tsconfig_for_file(&base_dir.join("vite.config.ts"), &fs).unwrap();
+    assert_eq!(node_tsconfig.path, base_dir.join("tsconfig.node.json"));
+    assert_eq!(
+        node_tsconfig.compiler_options.base_url,
+        Some(base_dir.join("config"))
+    );
+    assert_eq!(
+        resolve(
+            "@/plugin",
+            &base_dir,
+            &fs,
+            &options
+                .clone()
+                .with_tsconfig(DiscoverableManifest::Explicit {
+                    package_path: base_dir.clone(),
+                    manifest: &node_tsconfig,
+                })
+        ),
+        Ok(Utf8PathBuf::from(format!("{base_dir}/config/plugin.ts")))
+    );
+}
@@ -18,6 +18,7 @@ pub mod no_async_promise_executor;
 pub mod no_autofocus;
 pub mod no_await_in_loops;
 pub mod no_banned_types;
+pub mod no_bare_urls;
 pub mod no_barrel_file;
 pub mod no_base_to_string;
 pub mod no_before_interactive_script_outside_document;
@@ -75,8 +76,10 @@ pub mod no_duplicate_field_definition_names;
 pub mod no_duplicate_fields;
 pub mod no_duplicate_font_names;
 pub mod no_duplicate_graphql_operation_name;
+pub mod no_duplicate_headings;
 pub mod no_duplicate_input_field_names;
 pub mod no_duplicate_jsx_props;
+pub mod no_duplicate_mapping_keys;
 pub mod no_duplicate_object_keys;
 pub mod no_duplicate_parameters;
 pub mod no_duplicate_properties;
@@ -145,7 +148,9 @@ pub mod no_interactive_element_to_noninteractive_role;
 pub mod no_invalid_builtin_instantiation;
 pub mod no_invalid_constructor_super;
 pub mod no_invalid_direction_in_linear_gradient;
+pub mod no_invalid_graphql_argument_value;
 pub mod no_invalid_grid_areas;
+pub mod no_invalid_link_fragments;
 pub mod no_invalid_position_at_import_rule;
 pub mod no_invalid_property_init_value;
 pub mod no_invalid_use_before_declaration;
@@ -257,16 +262,24 @@ pub mod no_ternary;
 pub mod no_then_property;
 pub mod no_this_in_static;
 pub mod no_top_level_literals;
+pub mod no_trailing_spaces;
+pub mod no_truthy_scalars;
 pub mod no_ts_ignore;
 pub mod no_unassigned_variables;
+pub mod no_undeclared_aliases;
 pub mod no_undeclared_classes;
 pub mod no_undeclared_custom_properties;
 pub mod no_undeclared_dependencies;
 pub mod no_undeclared_env_vars;
 pub mod no_undeclared_variables;
+pub mod no_undefined_graphql_fragment;
+pub mod no_undefined_graphql_variable;
 pub mod no_unknown_at_rules;
 pub mod no_unknown_attribute;
 pub mod no_unknown_function;
+pub mod no_unknown_graphql_argument;
+pub mod no_unknown_graphql_field;
+pub mod no_unknown_graphql_type;
 pub mod no_unknown_media_feature_name;
 pub mod no_unknown_property;
 pub mod no_unknown_pseudo_class;
@@ -277,6 +290,7 @@ pub mod no_unmatchable_anb_selector;
 pub mod no_unnecessary_conditions;
 pub mod no_unnecessary_template_expression;
 pub mod no_unreachable;
+pub mod no_unreachable_files;
 pub mod no_unreachable_super;
 pub mod no_unresolved_imports;
 pub mod no_unsafe_declaration_merging;
@@ -287,6 +301,8 @@ pub mod no_unsafe_plus_operands;
 pub mod no_unsafe_type_assertion;
 pub mod no_untrusted_licenses;
 pub mod no_unused_classes;
+pub mod no_unused_dependencies;
+pub mod no_unused_exports;
 pub mod no_unused_expressions;
 pub mod no_unused_function_parameters;
 pub mod no_unused_imports;
@@ -368,6 +384,7 @@ pub mod use_consistent_curly_braces;
 pub mod use_consistent_enum_value_type;
 pub mod use_consistent_graphql_descriptions;
 pub mod use_consistent_heading_level;
+pub mod use_consistent_list_marker;
 pub mod use_consistent_member_accessibility;
 pub mod use_consistent_method_signatures;
 pub mod use_consistent_object_definitions;
@@ -397,6 +414,7 @@ pub mod use_explicit_type;
 pub mod use_exponentiation_operator;
 pub mod use_export_type;
 pub mod use_exports_last;
+pub mod use_fenced_code_language;
 pub mod use_filenaming_convention;
 pub mod use_flat_map;
 pub mod use_focusable_interactive;
@@ -409,6 +427,7 @@ pub mod use_google_font_display;
 pub mod use_google_font_preconnect;
 pub mod use_graphql_named_operations;
 pub mod use_graphql_naming_convention;
+pub mod use_graphql_required_arguments;
 pub mod use_grouped_accessor_pairs;
 pub mod use_guard_for_in;
 pub mod use_heading_content;
@@ -416,6 +435,7 @@ pub mod use_hook_at_top_level;
 pub mod use_html_lang;
 pub mod use_iframe_sandbox;
 pub mod use_iframe_title;
+pub mod use_image_alt_text;
 pub mod use_image_size;
 pub mod use_import_extensions;
 pub mod use_import_type;
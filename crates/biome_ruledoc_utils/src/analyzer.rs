@@ -6,6 +6,7 @@ use biome_css_analyze::CssAnalyzerServices;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::DiagnosticExt;
+use biome_graphql_analyze::GraphqlAnalyzerServices;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_html_parser::HtmlParserOptions;
 use biome_html_syntax::HtmlLanguage;
@@ -18,8 +19,10 @@ use biome_languages::{
     DocumentFileSource, HtmlFileSource,
     javascript::{JsEmbeddingKind, JsFileSource},
 };
+use biome_markdown_analyze::MarkdownAnalyzerServices;
 use biome_markdown_syntax::MarkdownLanguage;
 use biome_rowan::Language;
+use biome_yaml_syntax::YamlLanguage;
 use camino::Utf8PathBuf;
 use std::slice;
 
@@ -137,6 +140,7 @@ pub fn analyze_rule_code(analyzer: RuleCodeAnalyzer) -> Result<()> {
                     file_source,
                     configuration_provider: None,
                     project_layout: None,
+                    module_db: None,
                 };
                 let result =
                     biome_json_analyze::analyze(&root, filter, &options, services, &[], |signal| {
@@ -186,9 +190,16 @@ pub fn analyze_rule_code(analyzer: RuleCodeAnalyzer) -> Result<()> {
                 let root = parse.tree();
                 let options =
                     code_block.create_analyzer_options::<GraphqlLanguage>(configuration)?;
-                let result = biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
-                    process_signal(signal, code, &file_path, writer)
-                });
+                let services = GraphqlAnalyzerServices::default()
+                    .with_schema(services_builder.graphql_schema());
+                let result = biome_graphql_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    services,
+                    &[],
+                    |signal| process_signal(signal, code, &file_path, writer),
+                );
                 propagate_break(result)?;
             }
         }
@@ -217,6 +228,7 @@ pub fn analyze_rule_code(analyzer: RuleCodeAnalyzer) -> Result<()> {
                     &options,
                     file_source,
                     services,
+                    &[],
                     |signal| process_signal(signal, code, &file_path, writer),
                 );
                 propagate_break(result)?;
@@ -237,14 +249,38 @@ pub fn analyze_rule_code(analyzer: RuleCodeAnalyzer) -> Result<()> {
                 let root = parse.tree();
                 let options =
                     code_block.create_analyzer_options::<MarkdownLanguage>(configuration)?;
-                let result = biome_markdown_analyze::analyze(&root, filter, &options, |signal| {
+                let result = biome_markdown_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    MarkdownAnalyzerServices::default(),
+                    &[],
+                    |signal| process_signal(signal, code, &file_path, writer),
+                );
+                propagate_break(result)?;
+            }
+        }
+        DocumentFileSource::Grit(_) => todo!("Grit analysis is not yet supported"),
+        DocumentFileSource::Yaml(_) => {
+            let parse = biome_yaml_parser::parse_yaml(code);
+
+            if parse.has_errors() {
+                for diagnostic in parse.into_diagnostics() {
+                    writer.write_parse_error(
+                        diagnostic
+                            .with_file_path(&file_path)
+                            .with_file_source_code(code),
+                    )?;
+                }
+            } else {
+                let root = parse.tree();
+                let options = code_block.create_analyzer_options::<YamlLanguage>(configuration)?;
+                let result = biome_yaml_analyze::analyze(&root, filter, &options, &[], |signal| {
                     process_signal(signal, code, &file_path, writer)
                 });
                 propagate_break(result)?;
             }
         }
-        DocumentFileSource::Grit(_) => todo!("Grit analysis is not yet supported"),
-        DocumentFileSource::Yaml(_) => todo!("Yaml analysis is not yet supported"),
         DocumentFileSource::Unknown | DocumentFileSource::Ignore => {}
     }
 
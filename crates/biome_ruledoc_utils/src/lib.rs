@@ -13,6 +13,7 @@ use biome_db::ParsedSource;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::DiagnosticExt;
 use biome_fs::{BiomePath, MemoryFileSystem};
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 use biome_html_analyze::HtmlAnalyzerServices;
 use biome_html_parser::HtmlParse;
 use biome_js_analyze::JsAnalyzerServices;
@@ -44,6 +45,7 @@ pub struct AnalyzerServicesBuilder {
     path_info_cache: PathInfoCache,
     project_layout: Arc<ProjectLayout>,
     semantic_model: Option<Arc<SemanticModel>>,
+    graphql_schema: Option<Arc<GraphqlSchema>>,
     enable_type_inference: bool,
 }
 
@@ -68,6 +70,7 @@ impl AnalyzerServicesBuilder {
                 path_info_cache: PathInfoCache::default(),
                 project_layout: Default::default(),
                 semantic_model: None,
+                graphql_schema: None,
                 enable_type_inference,
             };
         }
@@ -79,6 +82,7 @@ impl AnalyzerServicesBuilder {
         let mut js_paths = Vec::new();
         let mut css_paths = Vec::new();
         let mut html_paths = Vec::new();
+        let mut graphql_schema = None::<GraphqlSchemaBuilder>;
 
         for (path, src) in files {
             let path_buf = Utf8PathBuf::from(codeblock::normalize_file_path(&path));
@@ -112,6 +116,10 @@ impl AnalyzerServicesBuilder {
                     DocumentFileSource::Js(_) => js_paths.push(biome_path),
                     DocumentFileSource::Css(_) => css_paths.push(biome_path),
                     DocumentFileSource::Html(_) => html_paths.push(biome_path),
+                    // GraphQL files provide the schema that operations are validated against
+                    DocumentFileSource::Graphql(_) => graphql_schema
+                        .get_or_insert_default()
+                        .add_document(&biome_graphql_parser::parse_graphql(&src).tree()),
                     _ => unimplemented!(
                         "Unhandled file type: {biome_path}. Add a new branch once the module graph understands new module types"
                     ),
@@ -182,10 +190,16 @@ impl AnalyzerServicesBuilder {
             path_info_cache,
             project_layout: Arc::new(layout),
             semantic_model: None,
+            graphql_schema: graphql_schema.map(|builder| Arc::new(builder.build())),
             enable_type_inference,
         }
     }
 
+    /// Returns the schema built from the GraphQL files, if any.
+    pub fn graphql_schema(&self) -> Option<Arc<GraphqlSchema>> {
+        self.graphql_schema.clone()
+    }
+
     pub fn build_for_js_parse(
         &mut self,
         path: Utf8PathBuf,
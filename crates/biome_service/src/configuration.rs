@@ -42,6 +42,10 @@ use biome_markdown_analyze::METADATA as md_lint_metadata;
 use biome_markdown_syntax::MarkdownLanguage;
 use biome_resolver::{FsWithResolverProxy, ResolveOptions, is_relative_specifier, resolve};
 use biome_rowan::Language;
+#[cfg(feature = "lang_yaml")]
+use biome_yaml_analyze::METADATA as yaml_lint_metadata;
+#[cfg(feature = "lang_yaml")]
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use rustc_hash::FxHashSet;
 use std::fmt::Debug;
@@ -553,6 +557,8 @@ pub(crate) fn to_analyzer_rules_by_indices(
         push_to_analyzer_rules(rules, html_lint_metadata.deref(), &mut analyzer_rules);
         #[cfg(feature = "lang_md")]
         push_to_analyzer_rules(rules, md_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "lang_yaml")]
+        push_to_analyzer_rules(rules, yaml_lint_metadata.deref(), &mut analyzer_rules);
     }
     if let Some(rules) = settings.assist.actions.as_ref() {
         #[cfg(feature = "lang_js")]
@@ -566,6 +572,8 @@ pub(crate) fn to_analyzer_rules_by_indices(
         push_to_analyzer_assist(rules, html_lint_metadata.deref(), &mut analyzer_rules);
         #[cfg(feature = "lang_md")]
         push_to_analyzer_assist(rules, md_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "lang_yaml")]
+        push_to_analyzer_assist(rules, yaml_lint_metadata.deref(), &mut analyzer_rules);
     }
     settings
         .override_settings
@@ -875,6 +883,12 @@ impl<'a> ProjectScanComputer<'a> {
             }
         }
 
+        #[cfg(feature = "js_plugin")]
+        if self.has_js_plugins() {
+            self.requires_types = true;
+            self.requires_project_scan = true;
+        }
+
         #[cfg(feature = "lang_graphql")]
         biome_graphql_analyze::visit_registry(&mut self);
         #[cfg(feature = "lang_css")]
@@ -899,6 +913,38 @@ impl<'a> ProjectScanComputer<'a> {
         }
     }
 
+    /// Whether the configuration loads plugins that can define JavaScript rules.
+    ///
+    /// JavaScript rules can query the types of expressions with `getType()`, which
+    /// are only available after a type-aware scan. The plugins are loaded after the
+    /// scan kind is computed, so every plugin that isn't a GritQL file is assumed
+    /// to contain JavaScript rules, including the plugins with a manifest.
+    #[cfg(feature = "js_plugin")]
+    fn has_js_plugins(&self) -> bool {
+        if self
+            .configuration
+            .linter
+            .as_ref()
+            .is_some_and(|linter| !linter.is_enabled())
+        {
+            return false;
+        }
+
+        let override_plugins = self
+            .configuration
+            .overrides
+            .iter()
+            .flat_map(|overrides| overrides.0.iter())
+            .filter_map(|pattern| pattern.plugins.as_ref());
+
+        self.configuration
+            .plugins
+            .iter()
+            .chain(override_plugins)
+            .flat_map(|plugins| plugins.iter())
+            .any(|plugin| !plugin.path().ends_with(".grit"))
+    }
+
     fn check_rule<R, L>(&mut self)
     where
         L: Language,
@@ -987,6 +1033,17 @@ impl RegistryVisitor<MarkdownLanguage> for ProjectScanComputer<'_> {
         self.check_rule::<R, MarkdownLanguage>();
     }
 }
+
+#[cfg(feature = "lang_yaml")]
+impl RegistryVisitor<YamlLanguage> for ProjectScanComputer<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, YamlLanguage>();
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1158,6 +1215,42 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "js_plugin")]
+    fn should_return_type_aware_if_a_js_plugin_is_loaded() {
+        use biome_plugin_loader::{PluginConfiguration, Plugins};
+
+        let configuration = Configuration {
+            plugins: Some(Plugins(vec![PluginConfiguration::Path(
+                "./plugin.js".to_string(),
+            )])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ProjectScanComputer::new(&configuration).compute(),
+            ScanKind::TypeAware
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "js_plugin")]
+    fn should_not_require_types_for_grit_plugins() {
+        use biome_plugin_loader::{PluginConfiguration, Plugins};
+
+        let configuration = Configuration {
+            plugins: Some(Plugins(vec![PluginConfiguration::Path(
+                "./plugin.grit".to_string(),
+            )])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ProjectScanComputer::new(&configuration).compute(),
+            ScanKind::NoScanner
+        );
+    }
+
     #[test]
     fn should_return_type_aware_if_type_aware_domain_selector() {
         let configuration = Configuration::default();
@@ -698,14 +698,15 @@ impl WorkspaceDb {
         let update_root_settings =
             |mut root_settings: SettingsIdentity| -> Result<SettingsIdentity, WorkspaceError> {
                 let git_ignores = match root_settings.as_ref().vcs_settings.client_kind {
-                    Some(VcsClientKind::Git) => payload
+                    Some(VcsClientKind::Git | VcsClientKind::Jujutsu) => payload
                         .iter()
                         .map(|(path, patterns)| {
                             let patterns = patterns.iter().map(String::as_str).collect::<Vec<_>>();
                             VcsIgnoredPatterns::git_ignore(path.as_path(), patterns.as_slice())
                         })
                         .collect::<Result<Vec<_>, _>>()?,
-                    None => Vec::new(),
+                    // Mercurial only reads the `.hgignore` file at the root of the repository
+                    Some(VcsClientKind::Mercurial) | None => Vec::new(),
                 };
 
                 let settings = root_settings.make_mut();
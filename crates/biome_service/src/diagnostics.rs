@@ -756,6 +756,22 @@ pub struct ConfigurationOutsideProject {
     pub working_directory: String,
 }
 
+/// Emitted when a schema file listed in `graphql.schema` can't be read or parsed.
+#[derive(Debug, Diagnostic, Serialize, Deserialize)]
+#[diagnostic(
+    category = "configuration",
+    severity = Warning,
+    message(
+        message("Biome couldn't load the GraphQL schema from "<Emphasis>{self.path}</Emphasis>": "{self.reason}),
+        description = "Biome couldn't load the GraphQL schema from {path}: {reason}"
+    ),
+)]
+pub struct CantLoadGraphqlSchema {
+    #[location(resource)]
+    pub path: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Diagnostic, Serialize, Deserialize)]
 #[diagnostic(
     category = "project",
@@ -0,0 +1,57 @@
+use biome_languages::{
+    CssFileSource, DocumentFileSource, GraphqlFileSource, JsFileSource, JsonFileSource,
+};
+
+/// Language embedded in a fenced code block of a Markdown document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum GuestLanguage {
+    Js(JsFileSource),
+    Css,
+    Json { allow_comments: bool },
+    GraphQL,
+}
+
+impl GuestLanguage {
+    /// Resolves the guest language from the info string of a fenced code block.
+    ///
+    /// Only the first word of the info string is considered, so ```` ```js title="example.js" ````
+    /// is detected as JavaScript. Unknown languages return `None`, and their code blocks are
+    /// left untouched.
+    pub fn from_info_string(info_string: &str) -> Option<Self> {
+        let language = info_string.split_whitespace().next()?.to_ascii_lowercase();
+        let guest = match language.as_str() {
+            "js" | "javascript" | "mjs" => Self::Js(JsFileSource::js_module()),
+            "cjs" => Self::Js(JsFileSource::js_script()),
+            "jsx" => Self::Js(JsFileSource::jsx()),
+            "ts" | "typescript" => Self::Js(JsFileSource::ts()),
+            "mts" | "cts" => Self::Js(JsFileSource::ts_restricted()),
+            "tsx" => Self::Js(JsFileSource::tsx()),
+            "css" => Self::Css,
+            "json" => Self::Json {
+                allow_comments: false,
+            },
+            "jsonc" => Self::Json {
+                allow_comments: true,
+            },
+            "graphql" | "gql" => Self::GraphQL,
+            _ => return None,
+        };
+        Some(guest)
+    }
+}
+
+impl From<GuestLanguage> for DocumentFileSource {
+    fn from(value: GuestLanguage) -> Self {
+        match value {
+            GuestLanguage::Js(file_source) => file_source.into(),
+            GuestLanguage::Css => CssFileSource::css().into(),
+            GuestLanguage::Json {
+                allow_comments: false,
+            } => JsonFileSource::json().into(),
+            GuestLanguage::Json {
+                allow_comments: true,
+            } => JsonFileSource::json_allow_comments_and_trailing_commas("jsonc").into(),
+            GuestLanguage::GraphQL => GraphqlFileSource::graphql().into(),
+        }
+    }
+}
@@ -19,7 +19,9 @@ use crate::settings::{
     Settings, SettingsIdentity, SettingsWithEditor, check_feature_activity,
     check_override_feature_activity, finalize_analyzer_options,
 };
-use crate::workspace::{CodeAction, GetSyntaxTreeResult, PatternId, PullActionsResult};
+use crate::workspace::{
+    CodeAction, GetSyntaxTreeResult, PatternId, PatternMatches, PullActionsResult,
+};
 use crate::workspace::{FixFileMode, SearchQuery};
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{
@@ -508,6 +510,11 @@ impl ExtensionHandler for CssFileHandler {
             editors: EditorCapabilities {
                 resolve_binding: None,
                 resolve_definition: Some(resolve_definition),
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -762,6 +769,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs: _,
         language,
         only,
         skip,
@@ -1075,7 +1083,7 @@ fn search(
     settings: &SettingsWithEditor,
     pattern_id: PatternId,
     workspace_db: WorkspaceDb,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<PatternMatches, WorkspaceError> {
     let any_parse = parsed.any_parse(&workspace_db);
     provider.search(path, document, any_parse.clone(), settings, pattern_id)
 }
@@ -16,8 +16,10 @@ use crate::settings::{
     Settings, SettingsIdentity, SettingsWithEditor, check_feature_activity,
     check_override_feature_activity, finalize_analyzer_options,
 };
-use crate::workspace::FixFileMode;
-use crate::workspace::{CodeAction, GetSyntaxTreeResult, PullActionsResult};
+use crate::workspace::{
+    CodeAction, FixFileMode, GetSyntaxTreeResult, PatternId, PatternMatches, PullActionsResult,
+    SearchQuery,
+};
 use biome_analyze::{
     ActionFilter, AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
 };
@@ -31,16 +33,18 @@ use biome_formatter::{
     QuoteStyle, TrailingNewline,
 };
 use biome_fs::BiomePath;
-use biome_graphql_analyze::analyze;
+use biome_graphql_analyze::{GraphqlAnalyzerServices, analyze};
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
 use biome_graphql_parser::parse_graphql_with_cache;
+use biome_graphql_semantic::GraphqlSchema;
 use biome_graphql_syntax::{
     GraphqlLanguage, GraphqlRoot, GraphqlSyntaxKind, GraphqlSyntaxNode, TextRange, TextSize,
 };
 use biome_rowan::{AstNode, NodeCache, SyntaxKind, TokenAtOffset};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::borrow::Cow;
+use std::sync::Arc;
 use tracing::{debug_span, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -191,6 +195,19 @@ fn resolve_analyzer_options(
     finalize_analyzer_options(options, path, working_directory, suppression_reason)
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct GraphqlEnvironmentSettings {
+    /// Paths to the schema files, relative to the configuration file
+    pub schema_paths: Vec<Box<str>>,
+
+    /// Absolute paths of the files in [Self::schema_paths], used to reload the
+    /// schema when one of them changes
+    pub schema_files: Vec<Utf8PathBuf>,
+
+    /// The schema loaded from [Self::schema_paths]
+    pub schema: Option<Arc<GraphqlSchema>>,
+}
+
 impl ServiceLanguage for GraphqlLanguage {
     type FormatterSettings = GraphqlFormatterSettings;
     type LinterSettings = GraphqlLinterSettings;
@@ -199,14 +216,14 @@ impl ServiceLanguage for GraphqlLanguage {
     type ParserSettings = ();
     type ParserOptions = ();
 
-    type EnvironmentSettings = ();
+    type EnvironmentSettings = GraphqlEnvironmentSettings;
 
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
         &language.graphql
     }
 
-    fn resolve_environment(_settings: &Settings) -> Option<&Self::EnvironmentSettings> {
-        None
+    fn resolve_environment(global: &Settings) -> Option<&Self::EnvironmentSettings> {
+        Some(&global.languages.graphql.environment)
     }
 
     fn resolve_parse_options(
@@ -395,10 +412,17 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_on_type: Some(format_on_type),
                 format_embedded: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
             editors: EditorCapabilities {
                 resolve_binding: None,
                 resolve_definition: None,
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -420,6 +444,19 @@ fn search_enabled(_path: &Utf8Path, _settings: &SettingsWithEditor) -> bool {
     true
 }
 
+fn search(
+    path: &BiomePath,
+    document: &DocumentFileSource,
+    parsed: AnyParsedSource,
+    provider: &dyn SearchQuery,
+    settings: &SettingsWithEditor,
+    pattern_id: PatternId,
+    workspace_db: WorkspaceDb,
+) -> Result<PatternMatches, WorkspaceError> {
+    let any_parse = parsed.any_parse(&workspace_db);
+    provider.search(path, document, any_parse.clone(), settings, pattern_id)
+}
+
 fn parse(
     _biome_path: &BiomePath,
     file_source: DocumentFileSource,
@@ -563,6 +600,13 @@ fn format_on_type(
     Ok(printed)
 }
 
+/// Services shared by the rules, such as the schema that documents are validated against
+fn analyzer_services(settings: &SettingsWithEditor) -> GraphqlAnalyzerServices {
+    let settings: &Settings = settings.as_ref();
+    GraphqlAnalyzerServices::default()
+        .with_schema(settings.languages.graphql.environment.schema.clone())
+}
+
 fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting GraphQL file", path =? params.path, language =? params.language)
         .entered();
@@ -598,9 +642,14 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        analyzer_services(params.settings),
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     process_lint.into_result(
         params.parsed_source.serde_diagnostics(&params.workspace_db),
@@ -617,12 +666,13 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs: _,
         language,
         only,
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
         working_directory,
         compute_actions,
@@ -663,38 +713,46 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
     info!("GraphQL runs the analyzer");
 
-    analyze(&tree, filter, &analyzer_options, |signal| {
-        if compute_actions {
-            actions.extend(
-                signal
-                    .actions(ActionFilter::all())
-                    .into_code_action_iter()
-                    .map(|item| CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
+    let services = analyzer_services(settings);
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        services,
+        &plugins,
+        |signal| {
+            if compute_actions {
+                actions.extend(
+                    signal
+                        .actions(ActionFilter::all())
+                        .into_code_action_iter()
+                        .map(|item| CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            applicability: Some(item.suggestion.applicability),
+                            suggestion: Some(item.suggestion),
+                            offset: action_offset,
+                        }),
+                );
+            } else {
+                actions.extend(signal.actions_metadata().into_iter().map(|meta| {
+                    CodeAction {
+                        category: meta.category,
+                        rule_name: meta
                             .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        applicability: Some(item.suggestion.applicability),
-                        suggestion: Some(item.suggestion),
+                            .map(|(g, r)| (Cow::Borrowed(g), Cow::Borrowed(r))),
+                        applicability: Some(meta.applicability),
+                        suggestion: None,
                         offset: action_offset,
-                    }),
-            );
-        } else {
-            actions.extend(signal.actions_metadata().into_iter().map(|meta| {
-                CodeAction {
-                    category: meta.category,
-                    rule_name: meta
-                        .rule_name
-                        .map(|(g, r)| (Cow::Borrowed(g), Cow::Borrowed(r))),
-                    applicability: Some(meta.applicability),
-                    suggestion: None,
-                    offset: action_offset,
-                }
-            }));
-        }
+                    }
+                }));
+            }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -738,13 +796,20 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
         loop {
             let mut pending_actions = Vec::new();
 
-            let (_, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if params.collect_final_diagnostics {
-                    process_fix_all.collect_signal(signal, &mut pending_actions)
-                } else {
-                    process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
-                }
-            });
+            let (_, _) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                analyzer_services(params.settings),
+                &params.plugins,
+                |signal| {
+                    if params.collect_final_diagnostics {
+                        process_fix_all.collect_signal(signal, &mut pending_actions)
+                    } else {
+                        process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
+                    }
+                },
+            );
 
             let result = process_fix_all.process_batch_actions(pending_actions, |root| {
                 tree = match GraphqlRoot::cast(root) {
@@ -773,9 +838,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
     loop {
         let mut pending_actions = Vec::new();
 
-        let (_, _) = analyze(&tree, fixable_filter, &analyzer_options, |signal| {
-            process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
-        });
+        let (_, _) = analyze(
+            &tree,
+            fixable_filter,
+            &analyzer_options,
+            analyzer_services(params.settings),
+            &params.plugins,
+            |signal| process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions),
+        );
 
         let result = process_fix_all.process_batch_actions(pending_actions, |root| {
             tree = match GraphqlRoot::cast(root) {
@@ -792,9 +862,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
 
     // Phase 2: all rules for final diagnostics
     if params.collect_final_diagnostics {
-        let (_, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            process_fix_all.collect_diagnostic_only(signal)
-        });
+        let (_, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            analyzer_services(params.settings),
+            &params.plugins,
+            |signal| process_fix_all.collect_diagnostic_only(signal),
+        );
     }
 
     Ok(Some(
@@ -334,6 +334,11 @@ impl ExtensionHandler for GritFileHandler {
             editors: EditorCapabilities {
                 resolve_binding: None,
                 resolve_definition: None,
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -27,6 +27,7 @@ use crate::settings::{
 use crate::workspace::CodeAction;
 use crate::workspace::FixFileMode;
 use crate::workspace::PullActionsResult;
+use crate::workspace::{PatternId, PatternMatches, SearchQuery};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings},
@@ -478,10 +479,17 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_on_type: None,
                 format_embedded: Some(format_embedded),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
             editors: EditorCapabilities {
                 resolve_binding: Some(resolve_binding_html),
                 resolve_definition: Some(resolve_definition),
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -503,6 +511,19 @@ fn search_enabled(_path: &Utf8Path, _settings: &SettingsWithEditor) -> bool {
     true
 }
 
+fn search(
+    path: &BiomePath,
+    document: &DocumentFileSource,
+    parsed: AnyParsedSource,
+    provider: &dyn SearchQuery,
+    settings: &SettingsWithEditor,
+    pattern_id: PatternId,
+    workspace_db: WorkspaceDb,
+) -> Result<PatternMatches, WorkspaceError> {
+    let any_parse = parsed.any_parse(&workspace_db);
+    provider.search(path, document, any_parse.clone(), settings, pattern_id)
+}
+
 fn parse(
     biome_path: &BiomePath,
     file_source: DocumentFileSource,
@@ -773,6 +794,7 @@ fn lint(params: LintParams) -> LintResults {
         &analyzer_options,
         source_type,
         html_services,
+        &params.plugins,
         |signal| process_lint.process_signal(signal),
     );
 
@@ -790,12 +812,13 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs: _,
         language,
         only,
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
         working_directory,
         compute_actions,
@@ -858,6 +881,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         &analyzer_options,
         source_type,
         html_services,
+        &plugins,
         |signal| {
             if compute_actions {
                 actions.extend(
@@ -958,6 +982,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
                 &analyzer_options,
                 source_type,
                 html_services,
+                &params.plugins,
                 |signal| {
                     if params.collect_final_diagnostics {
                         process_fix_all.collect_signal(signal, &mut pending_actions)
@@ -1013,6 +1038,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
             &analyzer_options,
             source_type,
             html_services,
+            &params.plugins,
             |signal| process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions),
         );
 
@@ -1050,6 +1076,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
             &analyzer_options,
             source_type,
             html_services,
+            &params.plugins,
             |signal| process_fix_all.collect_diagnostic_only(signal),
         );
     }
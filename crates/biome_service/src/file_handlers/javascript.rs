@@ -1,4 +1,7 @@
 mod go_to;
+mod inlay_hints;
+mod navigation;
+mod rename;
 
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, AnalyzerVisitorResult, CodeActionsParams,
@@ -18,16 +21,19 @@ use crate::embed::js::{
 };
 use crate::file_handlers::FixAllParams;
 use crate::file_handlers::javascript::go_to::{resolve_binding, resolve_definition};
+use crate::file_handlers::javascript::inlay_hints::inlay_hints;
+use crate::file_handlers::javascript::navigation::{document_symbols, find_references, hover};
+use crate::file_handlers::javascript::rename::{prepare_rename, rename};
 use crate::settings::{
     OverrideSettings, Settings, SettingsIdentity, SettingsWithEditor, check_feature_activity,
     check_override_feature_activity, finalize_analyzer_options,
 };
 use crate::workspace::{FixFileMode, SearchQuery};
-use crate::workspace::{PatternId, PullDiagnosticsAndActionsResult};
+use crate::workspace::{PatternId, PatternMatches, PullDiagnosticsAndActionsResult};
 use crate::{
     WorkspaceError,
     settings::{FormatSettings, LanguageListSettings, LanguageSettings, ServiceLanguage},
-    workspace::{CodeAction, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{CodeAction, GetSyntaxTreeResult, PullActionsResult},
 };
 use biome_analyze::ActionFilter;
 use biome_analyze::options::{PreferredIndentation, PreferredQuote};
@@ -58,7 +64,6 @@ use biome_fs::BiomePath;
 use biome_graphql_parser::parse_graphql_with_offset_and_cache;
 #[cfg(all(feature = "js_embeds", feature = "lang_graphql"))]
 use biome_graphql_syntax::GraphqlLanguage;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use biome_js_analyze::{
     ControlFlowGraph, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
 };
@@ -105,7 +110,7 @@ use biome_rowan::AstNodeList;
 use biome_rowan::SyntaxKind;
 #[cfg(feature = "type_inference")]
 use biome_rowan::WalkEvent;
-use biome_rowan::{AstNode, BatchMutation, BatchMutationExt, Direction, NodeCache, SendNode};
+use biome_rowan::{AstNode, BatchMutation, NodeCache, SendNode};
 use camino::Utf8Path;
 #[cfg(feature = "js_embeds")]
 use rustc_hash::FxHashMap;
@@ -700,6 +705,11 @@ impl ExtensionHandler for JsFileHandler {
             editors: EditorCapabilities {
                 resolve_binding: Some(resolve_binding),
                 resolve_definition: Some(resolve_definition),
+                hover: Some(hover),
+                find_references: Some(find_references),
+                document_symbols: Some(document_symbols),
+                prepare_rename: Some(prepare_rename),
+                inlay_hints: Some(inlay_hints),
             },
         }
     }
@@ -1336,6 +1346,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs: _,
         language,
         only,
         skip,
@@ -1890,49 +1901,6 @@ pub(crate) fn pull_diagnostics_and_actions(
     process_pull_diagnostics_and_actions.finish()
 }
 
-fn rename(
-    path: &BiomePath,
-    parse: AnyParsedSource,
-    symbol_at: TextSize,
-    new_name: String,
-    workspace_db: WorkspaceDb,
-) -> Result<RenameResult, WorkspaceError> {
-    let root = parse.tree(&workspace_db);
-    let source_type = JsFileSource::try_from(path.as_path()).unwrap_or_default();
-    let model = semantic_model(&root, SemanticModelOptions::from(&source_type));
-
-    if let Some(node) = parse
-        .syntax(&workspace_db)
-        .descendants_tokens(Direction::Next)
-        .find(|token| token.text_range().contains(symbol_at))
-        .and_then(|token| token.parent())
-    {
-        let original_name = node.text_trimmed();
-        let range = node.text_range_with_trivia();
-        match node.try_into() {
-            Ok(node) => {
-                let mut batch = root.begin();
-                let result = batch.rename_any_renamable_node(&model, &node, &new_name);
-                if !result {
-                    Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
-                        original_name: original_name.to_string(),
-                        original_range: range,
-                        new_name,
-                    }))
-                } else {
-                    let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
-                }
-            }
-            Err(err) => Err(WorkspaceError::RenameError(err)),
-        }
-    } else {
-        Err(WorkspaceError::RenameError(
-            RenameError::CannotFindDeclaration(new_name),
-        ))
-    }
-}
-
 #[instrument(level = "debug", skip_all)]
 fn update_snippets(
     root: super::ParsedOrigin,
@@ -1973,7 +1941,7 @@ fn search(
     settings: &SettingsWithEditor,
     pattern_id: PatternId,
     workspace_db: WorkspaceDb,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<PatternMatches, WorkspaceError> {
     let any_parse = parsed.any_parse(&workspace_db);
     provider.search(path, document, any_parse.clone(), settings, pattern_id)
 }
@@ -0,0 +1,246 @@
+use crate::db::WorkspaceDb;
+#[cfg(feature = "type_inference")]
+use crate::workspace::InlayHint;
+use crate::workspace::{InlayHintKind, InlayHintsResult};
+use biome_db::AnyParsedSource;
+use biome_fs::BiomePath;
+#[cfg(feature = "type_inference")]
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsExpression, AnyJsFunction, AnyJsRoot, JsCallExpression, JsVariableDeclarator,
+};
+#[cfg(feature = "type_inference")]
+use biome_js_type_info::format_inferred_type;
+#[cfg(feature = "type_inference")]
+use biome_js_type_info::interned_types::{FunctionParameter, TypeData};
+#[cfg(feature = "type_inference")]
+use biome_module_graph::type_inference::{
+    FunctionReturnTypeRequest, NormalizedBindingTypeRequest, NormalizedExpressionTypeRequest,
+    TypeInferenceCaller, TypeInferenceRequest, TypeInferenceSource, execute_type_inference_request,
+};
+#[cfg(feature = "type_inference")]
+use biome_module_graph::{ModuleDb, ModuleInfo};
+use biome_rowan::TextRange;
+#[cfg(feature = "type_inference")]
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Returns the hints of the given `kinds` that are positioned inside `range`:
+///
+/// - the inferred type of variables declared with an initializer and without a
+///   type annotation, unless the initializer is a function, whose return type
+///   has its own hint;
+/// - the inferred return type of functions without a return type annotation.
+///   Arrow functions whose single parameter isn't wrapped in parentheses are
+///   skipped, because the hint would read like the type of the parameter;
+/// - the name of the parameter that receives each argument of a call, unless
+///   the argument is a variable with the same name.
+///
+/// Types that can't be inferred aren't shown.
+#[cfg(feature = "type_inference")]
+pub(crate) fn inlay_hints(
+    path: &BiomePath,
+    parsed_source: AnyParsedSource,
+    range: TextRange,
+    kinds: &[InlayHintKind],
+    workspace_db: WorkspaceDb,
+) -> InlayHintsResult {
+    let mut result = InlayHintsResult::default();
+    if kinds.is_empty() {
+        return result;
+    }
+    let Some(module) = workspace_db.module_for_path(path.as_path()) else {
+        return result;
+    };
+
+    let collector = HintCollector {
+        workspace_db: &workspace_db,
+        module,
+        range,
+    };
+    let root: AnyJsRoot = parsed_source.tree(&workspace_db);
+    for node in root.syntax().descendants() {
+        if kinds.contains(&InlayHintKind::VariableType)
+            && let Some(declarator) = JsVariableDeclarator::cast_ref(&node)
+        {
+            collector.variable_type(&declarator, &mut result);
+        } else if kinds.contains(&InlayHintKind::ReturnType)
+            && let Some(function) = AnyJsFunction::cast_ref(&node)
+        {
+            collector.return_type(&function, &mut result);
+        } else if kinds.contains(&InlayHintKind::ParameterName)
+            && let Some(call) = JsCallExpression::cast_ref(&node)
+        {
+            collector.parameter_names(&call, &mut result);
+        }
+    }
+
+    result.hints.sort_by_key(|hint| hint.position);
+    result
+}
+
+#[cfg(not(feature = "type_inference"))]
+pub(crate) fn inlay_hints(
+    _path: &BiomePath,
+    _parsed_source: AnyParsedSource,
+    _range: TextRange,
+    _kinds: &[InlayHintKind],
+    _workspace_db: WorkspaceDb,
+) -> InlayHintsResult {
+    InlayHintsResult::default()
+}
+
+#[cfg(feature = "type_inference")]
+struct HintCollector<'a> {
+    workspace_db: &'a WorkspaceDb,
+    module: ModuleInfo,
+    /// The range requested by the editor
+    range: TextRange,
+}
+
+#[cfg(feature = "type_inference")]
+impl HintCollector<'_> {
+    fn variable_type(
+        &self,
+        declarator: &JsVariableDeclarator,
+        result: &mut InlayHintsResult,
+    ) -> Option<()> {
+        if declarator.variable_annotation().is_some() {
+            return None;
+        }
+        let initializer = declarator.initializer()?.expression().ok()?;
+        if matches!(
+            initializer,
+            AnyJsExpression::JsArrowFunctionExpression(_)
+                | AnyJsExpression::JsFunctionExpression(_)
+        ) {
+            return None;
+        }
+        let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+            declarator.id().ok()?
+        else {
+            return None;
+        };
+        let binding_range = binding.range();
+        if !self.range.contains_inclusive(binding_range.end()) {
+            return None;
+        }
+
+        let ty = self.execute(NormalizedBindingTypeRequest::new(
+            self.module,
+            binding_range,
+            binding_range,
+        ))?;
+        self.push_type(result, InlayHintKind::VariableType, binding_range, ty);
+        Some(())
+    }
+
+    fn return_type(&self, function: &AnyJsFunction, result: &mut InlayHintsResult) -> Option<()> {
+        if function.return_type_annotation().is_some() {
+            return None;
+        }
+        let AnyJsArrowFunctionParameters::JsParameters(parameters) = function.parameters().ok()?
+        else {
+            return None;
+        };
+        let parameters_range = parameters.range();
+        if !self.range.contains_inclusive(parameters_range.end()) {
+            return None;
+        }
+
+        let source = match function {
+            AnyJsFunction::JsArrowFunctionExpression(_)
+            | AnyJsFunction::JsFunctionExpression(_) => {
+                TypeInferenceSource::Expression(function.range())
+            }
+            AnyJsFunction::JsFunctionDeclaration(_)
+            | AnyJsFunction::JsFunctionExportDefaultDeclaration(_) => match function.binding() {
+                Some(binding) => TypeInferenceSource::Binding(binding.range()),
+                None => TypeInferenceSource::DefaultExport,
+            },
+        };
+        let ty = self.execute(FunctionReturnTypeRequest::new(
+            self.module,
+            function.range(),
+            source,
+        ))?;
+        self.push_type(result, InlayHintKind::ReturnType, parameters_range, ty);
+        Some(())
+    }
+
+    fn parameter_names(
+        &self,
+        call: &JsCallExpression,
+        result: &mut InlayHintsResult,
+    ) -> Option<()> {
+        let arguments = call.arguments().ok()?;
+        if self.range.intersect(arguments.range()).is_none() {
+            return None;
+        }
+        let callee = call.callee().ok()?;
+        let ty = self.execute(NormalizedExpressionTypeRequest::new(
+            self.module,
+            callee.range(),
+        ))?;
+        let function = ty.callable_function(self.workspace_db)?;
+        let parameters = function.parameters(self.workspace_db);
+
+        for (argument, parameter) in arguments.args().iter().zip(parameters.iter()) {
+            // Past a spread argument, the parameter of each argument is unknown
+            let Ok(AnyJsCallArgument::AnyJsExpression(argument)) = argument else {
+                break;
+            };
+            let FunctionParameter::Named(parameter) = parameter else {
+                continue;
+            };
+            let is_same_name = matches!(argument, AnyJsExpression::JsIdentifierExpression(_))
+                && argument.syntax().text_trimmed() == *parameter.name;
+            let position = argument.range().start();
+            if !is_same_name && self.range.contains_inclusive(position) {
+                let label = if parameter.is_rest {
+                    format!("...{}:", &*parameter.name)
+                } else {
+                    format!("{}:", &*parameter.name)
+                };
+                result.hints.push(InlayHint {
+                    kind: InlayHintKind::ParameterName,
+                    position,
+                    label,
+                });
+            }
+            // A rest parameter receives all the remaining arguments
+            if parameter.is_rest {
+                break;
+            }
+        }
+        Some(())
+    }
+
+    fn execute<'db, R>(&'db self, request: R) -> R::Output
+    where
+        R: TypeInferenceRequest<'db>,
+    {
+        execute_type_inference_request(
+            self.workspace_db,
+            TypeInferenceCaller::new("editor", "inlayHints"),
+            request,
+        )
+    }
+
+    /// Adds a hint showing `ty` after `range`
+    fn push_type(
+        &self,
+        result: &mut InlayHintsResult,
+        kind: InlayHintKind,
+        range: TextRange,
+        ty: TypeData<'_>,
+    ) {
+        if matches!(ty, TypeData::Unknown) {
+            return;
+        }
+        result.hints.push(InlayHint {
+            kind,
+            position: range.end(),
+            label: format!(": {}", format_inferred_type(self.workspace_db, ty)),
+        });
+    }
+}
@@ -0,0 +1,573 @@
+use crate::db::WorkspaceDb;
+use crate::file_handlers::javascript::rename::renames_export;
+#[cfg(feature = "module_graph")]
+use crate::file_handlers::javascript::rename::{is_js_module, namespace_member};
+use crate::workspace::{
+    DocumentSymbol, DocumentSymbolKind, DocumentSymbolsResult, FindReferencesResult, HoverResult,
+};
+use biome_db::AnyParsedSource;
+use biome_fs::BiomePath;
+use biome_js_semantic::{Binding, JsDeclarationKind, SemanticModel, js_semantic_model};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+#[cfg(feature = "module_graph")]
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsLiteralExportName,
+    AnyJsNamedImportSpecifier, JsExportFromClause, JsExportNamedFromClause, JsModuleSource,
+    JsSyntaxToken,
+};
+use biome_js_syntax::{
+    AnyJsRoot, JsIdentifierAssignment, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode,
+    JsVariableDeclarator, JsxReferenceIdentifier,
+};
+#[cfg(feature = "module_graph")]
+use biome_module_graph::{ModuleDb, ModuleInfoKind};
+#[cfg(feature = "module_graph")]
+use biome_rowan::AstSeparatedList;
+use biome_rowan::{AstNode, TextRange, TextSize, TokenAtOffset};
+#[cfg(feature = "module_graph")]
+use camino::Utf8PathBuf;
+#[cfg(feature = "module_graph")]
+use rustc_hash::FxHashSet;
+#[cfg(feature = "module_graph")]
+use std::collections::BTreeMap;
+
+/// Describes the binding at `symbol_at`: how it's declared, its inferred type
+/// when available, and its JSDoc comment.
+pub(crate) fn hover(
+    path: &BiomePath,
+    parsed_source: AnyParsedSource,
+    symbol_at: TextSize,
+    workspace_db: WorkspaceDb,
+) -> Option<HoverResult> {
+    let semantic_model = js_semantic_model(&workspace_db, &parsed_source);
+    let root: AnyJsRoot = parsed_source.tree(&workspace_db);
+    let (range, binding) = binding_at_offset(semantic_model, &root, symbol_at)?;
+
+    let name = binding.syntax().text_trimmed().to_string();
+    let mut signature = format!("{} {name}", declaration_keyword(&binding));
+    // The type of classes, enums and types is the declaration itself
+    let is_value = matches!(
+        binding.declaration_kind(),
+        JsDeclarationKind::Function
+            | JsDeclarationKind::HoistedValue
+            | JsDeclarationKind::Import
+            | JsDeclarationKind::Using
+            | JsDeclarationKind::Value
+            | JsDeclarationKind::Unknown
+    );
+    if is_value && let Some(ty) = inferred_type(path, &workspace_db, range, &binding) {
+        signature.push_str(": ");
+        signature.push_str(&ty);
+    }
+
+    let mut contents = format!("```ts\n{signature}\n```");
+    if let Some(jsdoc) = binding.jsdoc() {
+        contents.push_str("\n\n");
+        contents.push_str(jsdoc.to_string().trim());
+    }
+
+    Some(HoverResult { range, contents })
+}
+
+/// Returns the declaration and the references of the binding at `symbol_at`.
+///
+/// When the binding is exported under its own name, the usages in the modules
+/// that import it are returned too. Modules that re-export the name without an
+/// alias are followed, like [rename](super::rename::rename) does.
+pub(crate) fn find_references(
+    path: &BiomePath,
+    parsed_source: AnyParsedSource,
+    symbol_at: TextSize,
+    include_declaration: bool,
+    workspace_db: WorkspaceDb,
+) -> Option<FindReferencesResult> {
+    let semantic_model = js_semantic_model(&workspace_db, &parsed_source);
+    let root: AnyJsRoot = parsed_source.tree(&workspace_db);
+    let (_, binding) = binding_at_offset(semantic_model, &root, symbol_at)?;
+
+    let mut result = FindReferencesResult::default();
+    if include_declaration {
+        result
+            .references
+            .push((path.clone(), binding.syntax().text_trimmed_range()));
+    }
+    result.references.extend(
+        binding
+            .all_references()
+            .map(|reference| (path.clone(), reference.syntax().text_trimmed_range())),
+    );
+    if renames_export(&binding) {
+        let name = binding.syntax().text_trimmed().to_string();
+        result
+            .references
+            .extend(references_in_dependents(path, &name, &workspace_db));
+    }
+
+    Some(result)
+}
+
+/// Returns the usages of `name` in the modules that import it from `path`.
+///
+/// The name is reported where it's imported or re-exported, and where its
+/// local binding, or the member of a namespace import, is referenced.
+#[cfg(feature = "module_graph")]
+fn references_in_dependents(
+    path: &BiomePath,
+    name: &str,
+    workspace_db: &WorkspaceDb,
+) -> Vec<(BiomePath, TextRange)> {
+    let modules = workspace_db.all_modules();
+    let mut references: BTreeMap<Utf8PathBuf, Vec<TextRange>> = BTreeMap::new();
+    let mut visited = FxHashSet::default();
+    let mut queue = vec![path.as_path().to_path_buf()];
+
+    while let Some(target) = queue.pop() {
+        if !visited.insert(target.clone()) {
+            continue;
+        }
+        for (dependent, module_info) in &modules {
+            let ModuleInfoKind::Js(module_info) = module_info else {
+                continue;
+            };
+            if dependent == &target || dependent == path.as_path() || !is_js_module(dependent) {
+                continue;
+            }
+            let specifiers: FxHashSet<&str> = module_info
+                .import_paths
+                .named_iter()
+                .filter(|(_, import_path)| import_path.as_path() == Some(target.as_path()))
+                .map(|(specifier, _)| specifier.text())
+                .collect();
+            if specifiers.is_empty() {
+                continue;
+            }
+            let Some(parsed_source) = workspace_db.get_parsed_source(dependent) else {
+                continue;
+            };
+            let parsed_source = AnyParsedSource::from(parsed_source);
+            let model = js_semantic_model(workspace_db, &parsed_source);
+            let root: AnyJsRoot = parsed_source.tree(workspace_db);
+
+            let mut finder = DependentReferences {
+                model,
+                name,
+                ranges: references.entry(dependent.clone()).or_default(),
+                re_exported: false,
+            };
+            for source in root.syntax().descendants().filter_map(JsModuleSource::cast) {
+                let is_target = source
+                    .inner_string_text()
+                    .is_ok_and(|specifier| specifiers.contains(specifier.text()));
+                if is_target && let Some(clause) = source.syntax().parent() {
+                    finder.find_in_clause(&clause);
+                }
+            }
+            if finder.re_exported {
+                queue.push(dependent.clone());
+            }
+        }
+    }
+
+    references
+        .into_iter()
+        .flat_map(|(path, mut ranges)| {
+            ranges.sort_unstable_by_key(|range| range.start());
+            ranges.dedup();
+            let path = BiomePath::new(path);
+            ranges.into_iter().map(move |range| (path.clone(), range))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "module_graph"))]
+fn references_in_dependents(
+    _path: &BiomePath,
+    _name: &str,
+    _workspace_db: &WorkspaceDb,
+) -> Vec<(BiomePath, TextRange)> {
+    Vec::new()
+}
+
+/// Collects the usages of an exported name in a module that imports it
+#[cfg(feature = "module_graph")]
+struct DependentReferences<'a> {
+    model: &'a SemanticModel,
+    name: &'a str,
+    ranges: &'a mut Vec<TextRange>,
+    /// Whether the module exports the name under the same name
+    re_exported: bool,
+}
+
+#[cfg(feature = "module_graph")]
+impl DependentReferences<'_> {
+    /// Collects the usages of the name in the import or export `clause` that
+    /// owns a module source
+    fn find_in_clause(&mut self, clause: &JsSyntaxNode) {
+        if let Some(clause) = AnyJsImportClause::cast_ref(clause) {
+            if let Some(specifiers) = clause.named_specifiers() {
+                for specifier in specifiers.specifiers().iter().flatten() {
+                    self.find_in_import_specifier(&specifier);
+                }
+            }
+            let namespace = match &clause {
+                AnyJsImportClause::JsImportNamespaceClause(clause) => clause
+                    .namespace_specifier()
+                    .and_then(|specifier| specifier.local_name())
+                    .ok(),
+                AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier() {
+                    Ok(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier)) => {
+                        specifier.local_name().ok()
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(AnyJsBinding::JsIdentifierBinding(namespace)) = namespace {
+                self.find_namespace_members(namespace.syntax());
+            }
+        } else if let Some(clause) = JsExportNamedFromClause::cast_ref(clause) {
+            for specifier in clause.specifiers().iter().flatten() {
+                let Ok(source_name) = specifier.source_name() else {
+                    continue;
+                };
+                if self.find_export_name(&source_name) && specifier.export_as().is_none() {
+                    self.re_exported = true;
+                }
+            }
+        } else if let Some(clause) = JsExportFromClause::cast_ref(clause) {
+            // `export * from "./module"` exports the name too
+            if clause.export_as().is_none() {
+                self.re_exported = true;
+            }
+        }
+    }
+
+    fn find_in_import_specifier(&mut self, specifier: &AnyJsNamedImportSpecifier) {
+        let is_imported = match specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(named) => {
+                named.name().is_ok_and(|name| self.find_export_name(&name))
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => true,
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => false,
+        };
+        let Some(local_name) = specifier.local_name() else {
+            return;
+        };
+        let Some(local_name) = local_name.as_js_identifier_binding() else {
+            return;
+        };
+        let is_shorthand = matches!(
+            specifier,
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_)
+        );
+        if !is_imported || (is_shorthand && local_name.syntax().text_trimmed() != self.name) {
+            return;
+        }
+        let range = local_name.syntax().text_trimmed_range();
+        if is_shorthand {
+            self.ranges.push(range);
+        }
+        let Some(binding) = self.model.as_binding_by_range(range) else {
+            return;
+        };
+        self.ranges.extend(
+            binding
+                .all_references()
+                .map(|reference| reference.syntax().text_trimmed_range()),
+        );
+        if is_shorthand && renames_export(&binding) {
+            self.re_exported = true;
+        }
+    }
+
+    /// Collects `name` if it's the exported name. Returns whether it was collected.
+    fn find_export_name(&mut self, name: &AnyJsLiteralExportName) -> bool {
+        let Some(token) = name
+            .as_js_literal_export_name()
+            .and_then(|name| name.value().ok())
+        else {
+            return false;
+        };
+        self.find_token(&token)
+    }
+
+    /// Collects the member accesses such as `namespace.name`
+    fn find_namespace_members(&mut self, namespace: &JsSyntaxNode) {
+        let Some(binding) = self
+            .model
+            .as_binding_by_range(namespace.text_trimmed_range())
+        else {
+            return;
+        };
+        for reference in binding.all_references() {
+            if let Some(member) = namespace_member(&reference.syntax()) {
+                self.find_token(&member);
+            }
+        }
+    }
+
+    fn find_token(&mut self, token: &JsSyntaxToken) -> bool {
+        if token.text_trimmed() != self.name {
+            return false;
+        }
+        self.ranges.push(token.text_trimmed_range());
+        true
+    }
+}
+
+/// Returns the outline of the document.
+///
+/// Function bodies aren't visited, so local variables and nested functions
+/// aren't part of the outline. Variables declared with destructuring
+/// patterns are also skipped.
+pub(crate) fn document_symbols(
+    parsed_source: AnyParsedSource,
+    workspace_db: WorkspaceDb,
+) -> DocumentSymbolsResult {
+    let root: AnyJsRoot = parsed_source.tree(&workspace_db);
+    let mut result = DocumentSymbolsResult::default();
+    collect_symbols(root.syntax(), &mut result.symbols);
+    result
+}
+
+/// Finds the binding declared or referenced by the identifier at `offset`,
+/// together with the range of that identifier.
+fn binding_at_offset(
+    semantic_model: &SemanticModel,
+    root: &AnyJsRoot,
+    offset: TextSize,
+) -> Option<(TextRange, Binding)> {
+    let token = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(_, right) => right,
+        TokenAtOffset::None => return None,
+    };
+
+    for ancestor in token.ancestors() {
+        if let Some(reference) = JsReferenceIdentifier::cast_ref(&ancestor) {
+            let binding = semantic_model.binding(&reference)?;
+            return Some((reference.syntax().text_trimmed_range(), binding));
+        }
+        if let Some(reference) = JsxReferenceIdentifier::cast_ref(&ancestor) {
+            let binding = semantic_model.binding(&reference)?;
+            return Some((reference.syntax().text_trimmed_range(), binding));
+        }
+        if let Some(assignment) = JsIdentifierAssignment::cast_ref(&ancestor) {
+            let binding = semantic_model.binding(&assignment)?;
+            return Some((assignment.syntax().text_trimmed_range(), binding));
+        }
+        if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&ancestor) {
+            let range = binding.syntax().text_trimmed_range();
+            return Some((range, semantic_model.as_binding_by_range(range)?));
+        }
+    }
+
+    None
+}
+
+/// The keyword shown in front of the name of a binding, such as `const` or
+/// `function`
+fn declaration_keyword(binding: &Binding) -> String {
+    let keyword = match binding.declaration_kind() {
+        JsDeclarationKind::Class => "class",
+        JsDeclarationKind::Enum => "enum",
+        JsDeclarationKind::Function => "function",
+        JsDeclarationKind::Generic => "type parameter",
+        JsDeclarationKind::Import | JsDeclarationKind::ImportType => "import",
+        JsDeclarationKind::Interface => "interface",
+        JsDeclarationKind::Module => "module",
+        JsDeclarationKind::Namespace => "namespace",
+        JsDeclarationKind::Type => "type",
+        JsDeclarationKind::HoistedValue
+        | JsDeclarationKind::Using
+        | JsDeclarationKind::Value
+        | JsDeclarationKind::Unknown => {
+            let declaration = binding
+                .syntax()
+                .ancestors()
+                .find_map(JsVariableDeclarator::cast)
+                .and_then(|declarator| declarator.declaration())
+                .and_then(|declaration| declaration.kind_token().ok());
+            return match declaration {
+                Some(kind) => kind.text_trimmed().to_string(),
+                None if is_parameter(&binding.syntax()) => "parameter".to_string(),
+                None => "let".to_string(),
+            };
+        }
+    };
+    keyword.to_string()
+}
+
+fn is_parameter(node: &JsSyntaxNode) -> bool {
+    node.ancestors().any(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_FORMAL_PARAMETER
+                | JsSyntaxKind::JS_REST_PARAMETER
+                | JsSyntaxKind::TS_PROPERTY_PARAMETER
+        )
+    })
+}
+
+/// Formats the type inferred for `binding` when read at `range`
+#[cfg(feature = "type_inference")]
+fn inferred_type(
+    path: &BiomePath,
+    workspace_db: &WorkspaceDb,
+    range: TextRange,
+    binding: &Binding,
+) -> Option<String> {
+    use biome_js_type_info::format_inferred_type;
+    use biome_module_graph::type_inference::{
+        NormalizedBindingTypeRequest, TypeInferenceCaller, execute_type_inference_request,
+    };
+
+    let module = workspace_db.module_for_path(path.as_path())?;
+    let ty = execute_type_inference_request(
+        workspace_db,
+        TypeInferenceCaller::new("editor", "hover"),
+        NormalizedBindingTypeRequest::new(
+            module,
+            range,
+            binding.tree().syntax().text_trimmed_range(),
+        ),
+    )?;
+
+    Some(format_inferred_type(workspace_db, ty))
+}
+
+#[cfg(not(feature = "type_inference"))]
+fn inferred_type(
+    _path: &BiomePath,
+    _workspace_db: &WorkspaceDb,
+    _range: TextRange,
+    _binding: &Binding,
+) -> Option<String> {
+    None
+}
+
+fn collect_symbols(parent: &JsSyntaxNode, symbols: &mut Vec<DocumentSymbol>) {
+    for node in parent.children() {
+        if let Some(kind) = symbol_kind(&node) {
+            let Some((name, selection_range)) = symbol_name(&node) else {
+                continue;
+            };
+            let mut children = Vec::new();
+            if let Some(members) = node.children().find(|child| {
+                matches!(
+                    child.kind(),
+                    JsSyntaxKind::JS_CLASS_MEMBER_LIST
+                        | JsSyntaxKind::TS_TYPE_MEMBER_LIST
+                        | JsSyntaxKind::TS_ENUM_MEMBER_LIST
+                        | JsSyntaxKind::TS_MODULE_BLOCK
+                )
+            }) {
+                collect_symbols(&members, &mut children);
+            }
+            symbols.push(DocumentSymbol {
+                name,
+                kind,
+                range: node.text_trimmed_range(),
+                selection_range,
+                children,
+            });
+        } else if !is_opaque(&node) {
+            collect_symbols(&node, symbols);
+        }
+    }
+}
+
+fn symbol_kind(node: &JsSyntaxNode) -> Option<DocumentSymbolKind> {
+    let kind = match node.kind() {
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+        | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+            DocumentSymbolKind::Function
+        }
+        JsSyntaxKind::JS_CLASS_DECLARATION | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+            DocumentSymbolKind::Class
+        }
+        JsSyntaxKind::TS_INTERFACE_DECLARATION => DocumentSymbolKind::Interface,
+        JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => DocumentSymbolKind::TypeAlias,
+        JsSyntaxKind::TS_ENUM_DECLARATION => DocumentSymbolKind::Enum,
+        JsSyntaxKind::TS_ENUM_MEMBER => DocumentSymbolKind::EnumMember,
+        JsSyntaxKind::TS_MODULE_DECLARATION | JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION => {
+            DocumentSymbolKind::Module
+        }
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+            let is_const = JsVariableDeclarator::cast_ref(node)
+                .and_then(|declarator| declarator.declaration())
+                .and_then(|declaration| declaration.kind_token().ok())
+                .is_some_and(|kind| kind.kind() == JsSyntaxKind::CONST_KW);
+            if is_const {
+                DocumentSymbolKind::Constant
+            } else {
+                DocumentSymbolKind::Variable
+            }
+        }
+        JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+        | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        | JsSyntaxKind::TS_CONSTRUCTOR_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_METHOD_SIGNATURE_TYPE_MEMBER => DocumentSymbolKind::Method,
+        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+        | JsSyntaxKind::TS_PROPERTY_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_INITIALIZED_PROPERTY_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+        | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+        | JsSyntaxKind::TS_GETTER_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_SETTER_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_PROPERTY_SIGNATURE_TYPE_MEMBER
+        | JsSyntaxKind::TS_GETTER_SIGNATURE_TYPE_MEMBER
+        | JsSyntaxKind::TS_SETTER_SIGNATURE_TYPE_MEMBER => DocumentSymbolKind::Property,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// The name of a declaration and its range.
+///
+/// Default exports without a name are called `default`, and their name
+/// range is the range of their first keyword.
+fn symbol_name(node: &JsSyntaxNode) -> Option<(String, TextRange)> {
+    let name = node.children().find(|child| {
+        matches!(
+            child.kind(),
+            JsSyntaxKind::JS_IDENTIFIER_BINDING
+                | JsSyntaxKind::TS_IDENTIFIER_BINDING
+                | JsSyntaxKind::TS_QUALIFIED_MODULE_NAME
+                | JsSyntaxKind::JS_LITERAL_MEMBER_NAME
+                | JsSyntaxKind::JS_PRIVATE_CLASS_MEMBER_NAME
+                | JsSyntaxKind::JS_COMPUTED_MEMBER_NAME
+                | JsSyntaxKind::JS_MODULE_SOURCE
+        )
+    });
+    match name {
+        Some(name) => Some((name.text_trimmed().to_string(), name.text_trimmed_range())),
+        None if matches!(
+            node.kind(),
+            JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+                | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+                | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        ) =>
+        {
+            let keyword = node.first_token()?;
+            Some(("default".to_string(), keyword.text_trimmed_range()))
+        }
+        None => None,
+    }
+}
+
+/// Whether the declarations inside `node` are left out of the outline
+fn is_opaque(node: &JsSyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        JsSyntaxKind::JS_FUNCTION_BODY
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+            | JsSyntaxKind::JS_OBJECT_EXPRESSION
+            | JsSyntaxKind::JS_CALL_EXPRESSION
+    )
+}
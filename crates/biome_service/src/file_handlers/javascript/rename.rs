@@ -0,0 +1,428 @@
+use crate::WorkspaceError;
+use crate::db::WorkspaceDb;
+use crate::workspace::{DependentRenameEdit, PrepareRenameResult, RenameResult};
+use biome_db::AnyParsedSource;
+use biome_fs::BiomePath;
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
+#[cfg(feature = "module_graph")]
+use biome_js_factory::make;
+use biome_js_semantic::{
+    Binding, SemanticModel, SemanticModelOptions, js_semantic_model, semantic_model,
+};
+#[cfg(feature = "module_graph")]
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsLiteralExportName,
+    AnyJsNamedImportSpecifier, JsExportFromClause, JsExportNamedFromClause, JsLanguage,
+    JsModuleSource, JsStaticMemberExpression, JsSyntaxToken, T, TsQualifiedName,
+};
+use biome_js_syntax::{AnyJsRoot, JsSyntaxKind, JsSyntaxNode};
+use biome_languages::JsFileSource;
+#[cfg(feature = "module_graph")]
+use biome_module_graph::{ModuleDb, ModuleInfoKind};
+use biome_rowan::{AstNode, BatchMutationExt, Direction, TextSize};
+#[cfg(feature = "module_graph")]
+use biome_rowan::{AstSeparatedList, BatchMutation, TriviaPieceKind};
+#[cfg(feature = "module_graph")]
+use camino::{Utf8Path, Utf8PathBuf};
+#[cfg(feature = "module_graph")]
+use rustc_hash::FxHashSet;
+#[cfg(feature = "module_graph")]
+use std::collections::BTreeMap;
+
+/// Renames the binding at `symbol_at` and all its references.
+///
+/// When the binding is exported under its own name, the modules that import
+/// it are updated too, and their edits are returned as dependents.
+pub(crate) fn rename(
+    path: &BiomePath,
+    parse: AnyParsedSource,
+    symbol_at: TextSize,
+    new_name: String,
+    workspace_db: WorkspaceDb,
+) -> Result<RenameResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree(&workspace_db);
+    let source_type = JsFileSource::try_from(path.as_path()).unwrap_or_default();
+    let model = semantic_model(&root, SemanticModelOptions::from(&source_type));
+
+    let Some(node) = renamable_node_at(root.syntax(), symbol_at) else {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(new_name),
+        ));
+    };
+    let original_name = node.text_trimmed().to_string();
+    let range = node.text_range_with_trivia();
+    let node = AnyJsRenamableDeclaration::try_from(node).map_err(WorkspaceError::RenameError)?;
+    let binding = binding_of(&model, &node);
+    if binding.as_ref().is_some_and(is_shorthand_import) {
+        return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            original_name,
+            original_range: range,
+            new_name,
+        }));
+    }
+
+    let mut batch = root.begin();
+    if !batch.rename_any_renamable_node(&model, &node, &new_name) {
+        return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            original_name,
+            original_range: range,
+            new_name,
+        }));
+    }
+    let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
+
+    let dependents = match binding {
+        Some(binding) if renames_export(&binding) => {
+            let old_name = binding.syntax().text_trimmed().to_string();
+            rename_in_dependents(path, &old_name, &new_name, &workspace_db)
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(RenameResult {
+        range,
+        indels,
+        dependents,
+    })
+}
+
+/// Checks that the symbol at `symbol_at` can be renamed, and returns its
+/// range and its current name.
+///
+/// Returns `None` when there's no identifier at `symbol_at`, or when the
+/// identifier refers to a global.
+pub(crate) fn prepare_rename(
+    _path: &BiomePath,
+    parse: AnyParsedSource,
+    symbol_at: TextSize,
+    workspace_db: WorkspaceDb,
+) -> Result<Option<PrepareRenameResult>, WorkspaceError> {
+    let model = js_semantic_model(&workspace_db, &parse);
+    let root: AnyJsRoot = parse.tree(&workspace_db);
+
+    let Some(node) = renamable_node_at(root.syntax(), symbol_at) else {
+        return Ok(None);
+    };
+    let range = node.text_trimmed_range();
+    let placeholder = node.text_trimmed().to_string();
+    let Ok(node) = AnyJsRenamableDeclaration::try_from(node) else {
+        return Ok(None);
+    };
+    let Some(binding) = binding_of(model, &node) else {
+        return Ok(None);
+    };
+    if is_shorthand_import(&binding) {
+        // The name is owned by the module that exports it
+        return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            new_name: placeholder.clone(),
+            original_name: placeholder,
+            original_range: range,
+        }));
+    }
+
+    Ok(Some(PrepareRenameResult { range, placeholder }))
+}
+
+/// Returns the parent of the token at `offset`
+fn renamable_node_at(root: &JsSyntaxNode, offset: TextSize) -> Option<JsSyntaxNode> {
+    root.descendants_tokens(Direction::Next)
+        .find(|token| token.text_range().contains(offset))
+        .and_then(|token| token.parent())
+}
+
+fn binding_of(model: &SemanticModel, node: &AnyJsRenamableDeclaration) -> Option<Binding> {
+    let binding = node.binding(model)?;
+    model.as_binding_by_range(binding.text_trimmed_range())
+}
+
+/// Whether `binding` is declared by an import specifier such as `{ a }`,
+/// where the local name is also the name exported by the other module.
+fn is_shorthand_import(binding: &Binding) -> bool {
+    binding
+        .syntax()
+        .parent()
+        .is_some_and(|parent| parent.kind() == JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER)
+}
+
+/// Whether renaming `binding` also changes a name exported by its module
+pub(super) fn renames_export(binding: &Binding) -> bool {
+    binding.exports().any(|node| {
+        let Some(parent) = node.parent() else {
+            return false;
+        };
+        match parent.kind() {
+            JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER => true,
+            // These declarations are exported as `default`
+            JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => false,
+            _ => node.kind() != JsSyntaxKind::JS_REFERENCE_IDENTIFIER,
+        }
+    })
+}
+
+/// Renames `old_name` to `new_name` in the modules that import it from `path`.
+///
+/// Modules that re-export the name without an alias are followed, so that
+/// the modules importing it through a barrel file are renamed too.
+#[cfg(feature = "module_graph")]
+fn rename_in_dependents(
+    path: &BiomePath,
+    old_name: &str,
+    new_name: &str,
+    workspace_db: &WorkspaceDb,
+) -> Vec<DependentRenameEdit> {
+    let modules = workspace_db.all_modules();
+    let mut mutations: BTreeMap<Utf8PathBuf, BatchMutation<JsLanguage>> = BTreeMap::new();
+    let mut visited = FxHashSet::default();
+    let mut queue = vec![path.as_path().to_path_buf()];
+
+    while let Some(target) = queue.pop() {
+        if !visited.insert(target.clone()) {
+            continue;
+        }
+        for (dependent, module_info) in &modules {
+            let ModuleInfoKind::Js(module_info) = module_info else {
+                continue;
+            };
+            if dependent == &target || dependent == path.as_path() || !is_js_module(dependent) {
+                continue;
+            }
+            let specifiers: FxHashSet<&str> = module_info
+                .import_paths
+                .named_iter()
+                .filter(|(_, import_path)| import_path.as_path() == Some(target.as_path()))
+                .map(|(specifier, _)| specifier.text())
+                .collect();
+            if specifiers.is_empty() {
+                continue;
+            }
+            let Some(parsed_source) = workspace_db.get_parsed_source(dependent) else {
+                continue;
+            };
+            let parsed_source = AnyParsedSource::from(parsed_source);
+            let model = js_semantic_model(workspace_db, &parsed_source);
+            let root: AnyJsRoot = parsed_source.tree(workspace_db);
+            let mutation = mutations
+                .entry(dependent.clone())
+                .or_insert_with(|| root.clone().begin());
+
+            let mut renamer = DependentRenamer {
+                model,
+                mutation,
+                old_name,
+                new_name,
+                re_exported: false,
+            };
+            for source in root.syntax().descendants().filter_map(JsModuleSource::cast) {
+                let is_target = source
+                    .inner_string_text()
+                    .is_ok_and(|specifier| specifiers.contains(specifier.text()));
+                if is_target && let Some(clause) = source.syntax().parent() {
+                    renamer.rename_in_clause(&clause);
+                }
+            }
+            if renamer.re_exported {
+                queue.push(dependent.clone());
+            }
+        }
+    }
+
+    mutations
+        .into_iter()
+        .filter_map(|(path, mutation)| {
+            let (_, indels) = mutation.to_text_range_and_edit()?;
+            Some(DependentRenameEdit {
+                path: BiomePath::new(path),
+                indels,
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "module_graph"))]
+fn rename_in_dependents(
+    _path: &BiomePath,
+    _old_name: &str,
+    _new_name: &str,
+    _workspace_db: &WorkspaceDb,
+) -> Vec<DependentRenameEdit> {
+    Vec::new()
+}
+
+/// Whether the file at `path` is parsed as a JavaScript module, rather than
+/// as a document with embedded scripts such as Vue components
+#[cfg(feature = "module_graph")]
+pub(super) fn is_js_module(path: &Utf8Path) -> bool {
+    JsFileSource::try_from(path)
+        .is_ok_and(|source| !source.is_embedded() || source.is_svelte_source_module())
+}
+
+/// Renames the usages of an exported name in a module that imports it
+#[cfg(feature = "module_graph")]
+struct DependentRenamer<'a> {
+    model: &'a SemanticModel,
+    mutation: &'a mut BatchMutation<JsLanguage>,
+    old_name: &'a str,
+    new_name: &'a str,
+    /// Whether the module exports the name under the new name as well
+    re_exported: bool,
+}
+
+#[cfg(feature = "module_graph")]
+impl DependentRenamer<'_> {
+    /// Renames the name in the import or export `clause` that owns a module source
+    fn rename_in_clause(&mut self, clause: &JsSyntaxNode) {
+        if let Some(clause) = AnyJsImportClause::cast_ref(clause) {
+            if let Some(specifiers) = clause.named_specifiers() {
+                for specifier in specifiers.specifiers().iter().flatten() {
+                    self.rename_import_specifier(specifier);
+                }
+            }
+            let namespace = match &clause {
+                AnyJsImportClause::JsImportNamespaceClause(clause) => clause
+                    .namespace_specifier()
+                    .and_then(|specifier| specifier.local_name())
+                    .ok(),
+                AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier() {
+                    Ok(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier)) => {
+                        specifier.local_name().ok()
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(AnyJsBinding::JsIdentifierBinding(namespace)) = namespace {
+                self.rename_namespace_members(namespace.syntax());
+            }
+        } else if let Some(clause) = JsExportNamedFromClause::cast_ref(clause) {
+            for specifier in clause.specifiers().iter().flatten() {
+                let Ok(source_name) = specifier.source_name() else {
+                    continue;
+                };
+                if self.rename_export_name(&source_name) && specifier.export_as().is_none() {
+                    self.re_exported = true;
+                }
+            }
+        } else if let Some(clause) = JsExportFromClause::cast_ref(clause) {
+            // `export * from "./module"` exports the new name instead of the old one
+            if clause.export_as().is_none() {
+                self.re_exported = true;
+            }
+        }
+    }
+
+    fn rename_import_specifier(&mut self, specifier: AnyJsNamedImportSpecifier) {
+        match &specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(named) => {
+                if let Ok(name) = named.name() {
+                    self.rename_export_name(&name);
+                }
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(shorthand) => {
+                let Some(local_name) = specifier.local_name() else {
+                    return;
+                };
+                let Some(local_name) = local_name.as_js_identifier_binding() else {
+                    return;
+                };
+                if local_name.syntax().text_trimmed() != self.old_name {
+                    return;
+                }
+                let binding = self
+                    .model
+                    .as_binding_by_range(local_name.syntax().text_trimmed_range());
+                if self
+                    .mutation
+                    .rename_node_declaration(self.model, local_name, self.new_name)
+                {
+                    if binding.is_some_and(|binding| renames_export(&binding)) {
+                        self.re_exported = true;
+                    }
+                } else {
+                    // The new name is already taken in this module, so the
+                    // binding keeps its name: `import { newName as oldName }`
+                    let mut aliased = make::js_named_import_specifier(
+                        AnyJsLiteralExportName::JsLiteralExportName(make::js_literal_export_name(
+                            make::ident(self.new_name),
+                        )),
+                        make::token_decorated_with_space(T![as]),
+                        AnyJsBinding::JsIdentifierBinding(make::js_identifier_binding(
+                            make::ident(self.old_name),
+                        )),
+                    );
+                    if shorthand.type_token().is_some() {
+                        aliased = aliased.with_type_token(
+                            make::token(T![type])
+                                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                        );
+                    }
+                    self.mutation.replace_node(
+                        specifier.clone(),
+                        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(aliased.build()),
+                    );
+                }
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+        }
+    }
+
+    /// Renames `name` if it's the old name. Returns whether it was renamed.
+    fn rename_export_name(&mut self, name: &AnyJsLiteralExportName) -> bool {
+        let Some(token) = name
+            .as_js_literal_export_name()
+            .and_then(|name| name.value().ok())
+        else {
+            return false;
+        };
+        self.rename_token(token)
+    }
+
+    /// Renames the member accesses such as `namespace.oldName`
+    fn rename_namespace_members(&mut self, namespace: &JsSyntaxNode) {
+        let Some(binding) = self
+            .model
+            .as_binding_by_range(namespace.text_trimmed_range())
+        else {
+            return;
+        };
+        for reference in binding.all_references() {
+            if let Some(member) = namespace_member(&reference.syntax()) {
+                self.rename_token(member);
+            }
+        }
+    }
+
+    fn rename_token(&mut self, token: JsSyntaxToken) -> bool {
+        if token.text_trimmed() != self.old_name {
+            return false;
+        }
+        self.mutation
+            .replace_token(token, make::ident(self.new_name));
+        true
+    }
+}
+
+/// Returns the name of the member accessed on the namespace `reference`,
+/// such as `b` in `a.b`, for both expressions and qualified type names.
+#[cfg(feature = "module_graph")]
+pub(super) fn namespace_member(reference: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    let parent = reference.parent()?;
+    if let Some(qualified_name) = TsQualifiedName::cast_ref(&parent) {
+        if qualified_name.left().ok()?.syntax() != reference {
+            return None;
+        }
+        return qualified_name.right().ok()?.value_token().ok();
+    }
+    let member_expression = JsStaticMemberExpression::cast(parent.parent()?)?;
+    if member_expression.object().ok()?.syntax() != &parent {
+        return None;
+    }
+    member_expression
+        .member()
+        .ok()?
+        .as_js_name()?
+        .value_token()
+        .ok()
+}
@@ -15,7 +15,9 @@ use crate::settings::{
     Settings, SettingsIdentity, SettingsWithEditor, check_feature_activity,
     check_override_feature_activity, finalize_analyzer_options,
 };
-use crate::workspace::{CodeAction, GetSyntaxTreeResult, PatternId, PullActionsResult};
+use crate::workspace::{
+    CodeAction, GetSyntaxTreeResult, PatternId, PatternMatches, PullActionsResult,
+};
 use crate::workspace::{FixFileMode, SearchQuery};
 use crate::{WorkspaceError, extension_error};
 use biome_analyze::options::PreferredQuote;
@@ -41,10 +43,12 @@ use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_languages::JsonFileSource;
+use biome_project_layout::ProjectLayout;
 use biome_rowan::{AstNode, NodeCache, SyntaxKind};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use camino::Utf8Path;
 use std::borrow::Cow;
+use std::sync::Arc;
 use tracing::{debug_span, error, instrument};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -469,6 +473,11 @@ impl ExtensionHandler for JsonFileHandler {
             editors: EditorCapabilities {
                 resolve_binding: None,
                 resolve_definition: None,
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -624,6 +633,32 @@ fn format_on_type(
     Ok(printed)
 }
 
+/// Returns the services of the JSON analyzer. The module graph is only available
+/// when Biome is built with the `module_graph` feature.
+fn analyze_services(
+    file_source: JsonFileSource,
+    settings: &SettingsWithEditor,
+    project_layout: Arc<ProjectLayout>,
+    workspace_db: &WorkspaceDb,
+) -> JsonAnalyzeServices {
+    #[cfg(feature = "module_graph")]
+    let module_db = Some(workspace_db.rc_module_db());
+    #[cfg(not(feature = "module_graph"))]
+    let module_db = {
+        let _ = workspace_db;
+        None
+    };
+
+    JsonAnalyzeServices {
+        file_source,
+        configuration_provider: settings
+            .full_source()
+            .map(|s| s as Arc<dyn ExtendedConfigurationProvider>),
+        project_layout: Some(project_layout),
+        module_db,
+    }
+}
+
 fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting JSON file", path =? params.path, language =? params.language)
         .entered();
@@ -666,14 +701,12 @@ fn lint(params: LintParams) -> LintResults {
     };
 
     let mut process_lint = ProcessLint::new(&params);
-    let services = JsonAnalyzeServices {
+    let services = analyze_services(
         file_source,
-        configuration_provider: params
-            .settings
-            .full_source()
-            .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
-        project_layout: Some(params.project_layout.clone()),
-    };
+        params.settings,
+        params.project_layout.clone(),
+        &params.workspace_db,
+    );
     let (_, analyze_diagnostics) = analyze(
         &root,
         filter,
@@ -710,6 +743,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs: _,
         language,
         skip,
         only,
@@ -758,13 +792,12 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         return PullActionsResult { actions: vec![] };
     };
     let action_offset = parsed_source.diagnostic_offset(&workspace_db);
-    let services = JsonAnalyzeServices {
+    let services = analyze_services(
         file_source,
-        configuration_provider: settings
-            .full_source()
-            .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
-        project_layout: Some(project_layout_for_services),
-    };
+        settings,
+        project_layout_for_services,
+        &workspace_db,
+    );
     analyze(
         &tree,
         filter,
@@ -853,14 +886,12 @@ fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, WorkspaceErr
 
     if matches!(params.fix_file_mode, FixFileMode::ApplySuppressions) {
         loop {
-            let services = JsonAnalyzeServices {
+            let services = analyze_services(
                 file_source,
-                configuration_provider: params
-                    .settings
-                    .full_source()
-                    .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
-                project_layout: Some(params.project_layout.clone()),
-            };
+                params.settings,
+                params.project_layout.clone(),
+                &params.workspace_db,
+            );
             let mut pending_actions = Vec::new();
 
             let (_, _) = analyze(
@@ -903,14 +934,12 @@ fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, WorkspaceErr
     };
 
     loop {
-        let services = JsonAnalyzeServices {
+        let services = analyze_services(
             file_source,
-            configuration_provider: params
-                .settings
-                .full_source()
-                .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
-            project_layout: Some(params.project_layout.clone()),
-        };
+            params.settings,
+            params.project_layout.clone(),
+            &params.workspace_db,
+        );
         let mut pending_actions = Vec::new();
 
         let (_, _) = analyze(
@@ -962,14 +991,12 @@ fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, WorkspaceErr
 
     // Phase 2: all rules for final diagnostics
     if params.collect_final_diagnostics {
-        let services = JsonAnalyzeServices {
+        let services = analyze_services(
             file_source,
-            configuration_provider: params
-                .settings
-                .full_source()
-                .map(|s| s as std::sync::Arc<dyn ExtendedConfigurationProvider>),
-            project_layout: Some(params.project_layout.clone()),
-        };
+            params.settings,
+            params.project_layout.clone(),
+            &params.workspace_db,
+        );
 
         let (_, _) = analyze(
             &tree,
@@ -994,7 +1021,7 @@ fn search(
     settings: &SettingsWithEditor,
     pattern_id: PatternId,
     workspace_db: WorkspaceDb,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<PatternMatches, WorkspaceError> {
     let any_parse = parsed.any_parse(&workspace_db);
     provider.search(path, document, any_parse.clone(), settings, pattern_id)
 }
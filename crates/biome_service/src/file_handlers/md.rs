@@ -2,17 +2,27 @@ use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, AnalyzerVisitorResult, Capabilities,
     CodeActionsParams, DebugCapabilities, DocumentFileSource, EditorCapabilities, EnabledForPath,
     ExtensionHandler, FixAllParams, FixedFileResult, FormatterCapabilities, LintParams,
-    LintResults, ParseResult, ParserCapabilities, ProcessFixAll, ProcessLint, SearchCapabilities,
+    LintResults, ParseEmbedResult, ParseEmbeddedParams, ParseResult, ParserCapabilities,
+    ProcessFixAll, ProcessLint, SearchCapabilities, UpdateSnippetsNodes,
 };
 use crate::WorkspaceError;
 use crate::configuration::to_analyzer_rules_by_indices;
 use crate::db::WorkspaceDb;
+#[cfg(feature = "md_embeds")]
+use crate::embed::EmbedContent;
+#[cfg(feature = "md_embeds")]
+use crate::embed::md::GuestLanguage;
+#[cfg(feature = "md_embeds")]
+use crate::file_handlers::{css, graphql, javascript, json};
 use crate::settings::{
     FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
     Settings, SettingsIdentity, SettingsWithEditor, check_feature_activity,
     check_override_feature_activity, finalize_analyzer_options,
 };
-use crate::workspace::{CodeAction, FixFileMode, GetSyntaxTreeResult, PullActionsResult};
+use crate::workspace::{
+    CodeAction, FixFileMode, GetSyntaxTreeResult, PatternId, PatternMatches, PullActionsResult,
+    SearchQuery,
+};
 use biome_analyze::{
     ActionFilter, AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
 };
@@ -21,19 +31,48 @@ use biome_configuration::markdown::{
     MarkdownFormatterConfiguration, MarkdownFormatterEnabled, MarkdownLinterEnabled,
     MarkdownParseFrontmatter, MarkdownParserConfiguration,
 };
+#[cfg(feature = "md_embeds")]
+use biome_css_parser::parse_css_with_offset_and_cache;
+#[cfg(feature = "md_embeds")]
+use biome_css_syntax::CssLanguage;
 use biome_db::AnyParsedSource;
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, TrailingNewline};
 use biome_fs::BiomePath;
-use biome_markdown_analyze::analyze;
+#[cfg(feature = "md_embeds")]
+use biome_graphql_parser::parse_graphql_with_offset_and_cache;
+#[cfg(feature = "md_embeds")]
+use biome_graphql_syntax::GraphqlLanguage;
+#[cfg(feature = "md_embeds")]
+use biome_js_parser::parse_js_with_offset_and_cache;
+#[cfg(feature = "md_embeds")]
+use biome_js_syntax::JsLanguage;
+#[cfg(feature = "md_embeds")]
+use biome_json_parser::parse_json_with_offset_and_cache;
+#[cfg(feature = "md_embeds")]
+use biome_json_syntax::JsonLanguage;
+#[cfg(feature = "md_embeds")]
+use biome_languages::CssFileSource;
+use biome_markdown_analyze::{MarkdownAnalyzerServices, analyze};
 use biome_markdown_formatter::context::{MdFormatOptions, ProseWrap};
 use biome_markdown_formatter::format_node;
 use biome_markdown_parser::{MarkdownParserOptions, parse_markdown_with_cache};
-use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdRoot};
+#[cfg(feature = "md_embeds")]
+use biome_markdown_syntax::{AnyMdInline, MdFencedCodeBlock};
+use biome_markdown_syntax::{
+    MarkdownLanguage, MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdCodeContent,
+    MdRoot,
+};
+#[cfg(feature = "md_embeds")]
+use biome_parser::AnyParse;
 use biome_parser::NodeParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, BatchMutation, NodeCache, SendNode};
+#[cfg(feature = "md_embeds")]
+use biome_rowan::{AstNodeList, TextRange, TextSize};
 use camino::Utf8Path;
+#[cfg(feature = "md_embeds")]
+use rustc_hash::FxHashMap;
 use std::borrow::Cow;
-use tracing::{debug, debug_span, error};
+use tracing::{debug, debug_span, error, instrument};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -351,11 +390,11 @@ impl ExtensionHandler for MarkdownFileHandler {
                 formatter: Some(formatter_enabled),
                 linter: Some(linter_enabled),
                 assist: Some(assist_enabled),
-                search: None,
+                search: Some(search_enabled),
             },
             parser: ParserCapabilities {
                 parse: Some(parse),
-                parse_embedded_nodes: None,
+                parse_embedded_nodes: Some(parse_embedded_nodes),
             },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
@@ -370,19 +409,26 @@ impl ExtensionHandler for MarkdownFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: None,
-                update_snippets: None,
+                update_snippets: Some(update_snippets),
                 pull_diagnostics_and_actions: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: None,
                 format_on_type: None,
-                format_embedded: None,
+                format_embedded: Some(format_embedded),
+            },
+            search: SearchCapabilities {
+                search: Some(search),
             },
-            search: SearchCapabilities { search: None },
             editors: EditorCapabilities {
                 resolve_binding: None,
                 resolve_definition: None,
+                hover: None,
+                find_references: None,
+                document_symbols: None,
+                prepare_rename: None,
+                inlay_hints: None,
             },
         }
     }
@@ -400,6 +446,23 @@ fn assist_enabled(path: &Utf8Path, settings: &SettingsWithEditor) -> bool {
     settings.assist_enabled_for_file_path::<MarkdownLanguage>(path)
 }
 
+fn search_enabled(_path: &Utf8Path, _settings: &SettingsWithEditor) -> bool {
+    true
+}
+
+fn search(
+    path: &BiomePath,
+    document: &DocumentFileSource,
+    parsed: AnyParsedSource,
+    provider: &dyn SearchQuery,
+    settings: &SettingsWithEditor,
+    pattern_id: PatternId,
+    workspace_db: WorkspaceDb,
+) -> Result<PatternMatches, WorkspaceError> {
+    let any_parse = parsed.any_parse(&workspace_db);
+    provider.search(path, document, any_parse.clone(), settings, pattern_id)
+}
+
 fn parse(
     _biome_path: &BiomePath,
     file_source: DocumentFileSource,
@@ -418,6 +481,118 @@ fn parse(
     }
 }
 
+#[cfg(not(feature = "md_embeds"))]
+fn parse_embedded_nodes(_params: ParseEmbeddedParams) -> ParseEmbedResult {
+    ParseEmbedResult::default()
+}
+
+#[cfg(feature = "md_embeds")]
+fn parse_embedded_nodes(params: ParseEmbeddedParams) -> ParseEmbedResult {
+    let ParseEmbeddedParams {
+        any_parse,
+        path,
+        file_source: _,
+        settings,
+        node_cache,
+    } = params;
+
+    let root: MdRoot = any_parse.tree();
+    let nodes = root
+        .syntax()
+        .descendants()
+        .filter_map(MdFencedCodeBlock::cast)
+        .filter_map(|block| {
+            let info_string = block.code_list().syntax().text_trimmed().to_string();
+            let guest = GuestLanguage::from_info_string(&info_string)?;
+            let content = build_fenced_code_block_content(&block)?;
+            Some(parse_md_matched_embed(
+                guest, content, node_cache, path, settings,
+            ))
+        })
+        .collect();
+
+    ParseEmbedResult { nodes }
+}
+
+/// Returns the code of a fenced code block that can be parsed as an embedded language.
+///
+/// Returns `None` if:
+/// - The opening fence is indented, because the indentation is stripped from each line
+/// - The block is nested inside a list or a quote, because its code is split per line
+/// - The block is empty
+#[cfg(feature = "md_embeds")]
+fn build_fenced_code_block_content(block: &MdFencedCodeBlock) -> Option<EmbedContent> {
+    if !block.indent().is_empty() {
+        return None;
+    }
+
+    let content = block.content();
+    let mut items = content.iter();
+    let (Some(AnyMdInline::MdCodeContent(code)), None) = (items.next(), items.next()) else {
+        return None;
+    };
+
+    let value_token = code.value_token().ok()?;
+    let token_range = value_token.text_range();
+    let token_text = value_token.token_text();
+    // The literal starts with the line break that ends the opening-fence line
+    let leading_line_break = TextSize::from(leading_line_break_len(token_text.text()) as u32);
+    let text = token_text.slice(TextRange::new(leading_line_break, token_range.len()));
+    if text.text().trim().is_empty() {
+        return None;
+    }
+
+    let content_range = TextRange::new(token_range.start() + leading_line_break, token_range.end());
+    Some(EmbedContent {
+        element_range: token_range,
+        content_range,
+        content_offset: content_range.start(),
+        text,
+    })
+}
+
+/// Parse the code of a fenced code block with the parser of its language.
+#[cfg(feature = "md_embeds")]
+fn parse_md_matched_embed(
+    guest: GuestLanguage,
+    content: EmbedContent,
+    cache: &mut NodeCache,
+    biome_path: &BiomePath,
+    settings: &SettingsWithEditor,
+) -> (AnyParse, EmbedContent, DocumentFileSource) {
+    let file_source = DocumentFileSource::from(guest);
+    let text = content.text.text();
+    let offset = content.content_offset;
+
+    let parse: AnyParse = match guest {
+        GuestLanguage::Js(js_source) => {
+            let options = settings.parse_options::<JsLanguage>(biome_path, &file_source);
+            parse_js_with_offset_and_cache(text, offset, js_source, options, cache).into()
+        }
+        GuestLanguage::Css => {
+            let options = settings.parse_options::<CssLanguage>(biome_path, &file_source);
+            parse_css_with_offset_and_cache(text, CssFileSource::css(), offset, cache, options)
+                .into()
+        }
+        GuestLanguage::Json { .. } => {
+            let options = settings.parse_options::<JsonLanguage>(biome_path, &file_source);
+            parse_json_with_offset_and_cache(text, offset, cache, options).into()
+        }
+        GuestLanguage::GraphQL => parse_graphql_with_offset_and_cache(text, offset, cache).into(),
+    };
+
+    (parse, content, file_source)
+}
+
+/// Returns the length of the line break at the start of a code literal.
+fn leading_line_break_len(text: &str) -> usize {
+    match text.as_bytes() {
+        [b'\r', b'\n', ..] => 2,
+        [b'\r' | b'\n', ..] => 1,
+        _ => 0,
+    }
+}
+
 fn debug_syntax_tree(
     _biome_path: &BiomePath,
     parse: AnyParsedSource,
@@ -441,7 +616,7 @@ fn debug_formatter_ir(
     let options = resolve_format_options(biome_path, document_file_source, settings, &workspace_db);
 
     let tree = parse.syntax(&workspace_db);
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_node(options, &tree, vec![])?;
 
     let root_element = formatted.into_document();
     Ok(root_element.to_string())
@@ -457,7 +632,100 @@ pub(crate) fn format(
     let options = resolve_format_options(biome_path, document_file_source, settings, &workspace_db);
     debug!("{:?}", &options);
     let tree = parse.syntax(&workspace_db);
-    let formatted = format_node(options, &tree)?;
+    let formatted = format_node(options, &tree, vec![])?;
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => {
+            error!("The file {} couldn't be formatted", biome_path.as_str());
+            Err(WorkspaceError::FormatError(error.into()))
+        }
+    }
+}
+
+#[cfg(feature = "md_embeds")]
+fn format_embedded(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: super::ParsedOrigin,
+    settings: &SettingsWithEditor,
+    embedded_nodes: Vec<super::ParsedSnippetOrigin>,
+    workspace_db: WorkspaceDb,
+) -> Result<Printed, WorkspaceError> {
+    let options = resolve_format_options(biome_path, document_file_source, settings, &workspace_db);
+    let tree = parse.syntax(&workspace_db);
+
+    // Hand the snippet ranges to the formatter, so it only emits embedded
+    // tags for code blocks that were actually parsed as embedded languages.
+    // Code blocks with syntax errors are printed as they are.
+    let snippets: FxHashMap<TextRange, super::ParsedSnippetOrigin> = embedded_nodes
+        .into_iter()
+        .filter(|snippet| !snippet.has_errors(&workspace_db))
+        .map(|snippet| (snippet.content_range(&workspace_db), snippet))
+        .collect();
+    let mut formatted = format_node(options, &tree, snippets.keys().copied().collect())?;
+
+    formatted.format_embedded(move |range| {
+        let snippet = snippets.get(&range)?;
+        let snippet_file_source = snippet.file_source(&workspace_db)?;
+        let parse = snippet.parsed_origin().parse(&workspace_db);
+
+        let document = match snippet_file_source {
+            DocumentFileSource::Js(_) => {
+                let js_options = javascript::resolve_format_options(
+                    biome_path,
+                    &snippet_file_source,
+                    settings,
+                    &workspace_db,
+                );
+                let node = parse.embedded_syntax::<JsLanguage>();
+                biome_js_formatter::format_node_with_offset(js_options, &node)
+                    .ok()?
+                    .into_document()
+            }
+            DocumentFileSource::Css(_) => {
+                let css_options = css::resolve_format_options(
+                    biome_path,
+                    &snippet_file_source,
+                    settings,
+                    &workspace_db,
+                );
+                let node = parse.embedded_syntax::<CssLanguage>();
+                biome_css_formatter::format_node_with_offset(css_options, &node)
+                    .ok()?
+                    .into_document()
+            }
+            DocumentFileSource::Json(_) => {
+                let json_options =
+                    json::resolve_format_options(&snippet_file_source, settings, &workspace_db);
+                let node = parse.embedded_syntax::<JsonLanguage>();
+                biome_json_formatter::format_node_with_offset(json_options, &node)
+                    .ok()?
+                    .into_document()
+            }
+            DocumentFileSource::Graphql(_) => {
+                let graphql_options = graphql::resolve_format_options(
+                    biome_path,
+                    &snippet_file_source,
+                    settings,
+                    &workspace_db,
+                );
+                let node = parse.embedded_syntax::<GraphqlLanguage>();
+                biome_graphql_formatter::format_node_with_offset(graphql_options, &node)
+                    .ok()?
+                    .into_document()
+            }
+            _ => return None,
+        };
+
+        // The fenced code block already breaks the line after the opening fence,
+        // and the closing fence is printed on a new line.
+        Some(document)
+    });
+
+    // Propagate expand flags again after inserting embedded content,
+    // so that groups inside the embedded documents properly expand.
+    formatted.propagate_expand();
+
     match formatted.print() {
         Ok(printed) => Ok(printed),
         Err(error) => {
@@ -467,6 +735,25 @@ pub(crate) fn format(
     }
 }
 
+#[cfg(not(feature = "md_embeds"))]
+fn format_embedded(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: super::ParsedOrigin,
+    settings: &SettingsWithEditor,
+    embedded_nodes: Vec<super::ParsedSnippetOrigin>,
+    workspace_db: WorkspaceDb,
+) -> Result<Printed, WorkspaceError> {
+    let _ = embedded_nodes;
+    format(
+        biome_path,
+        document_file_source,
+        parse,
+        settings,
+        workspace_db,
+    )
+}
+
 fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting Markdown file", path =? params.path, language =? params.language)
         .entered();
@@ -503,9 +790,16 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let md_services = MarkdownAnalyzerServices::default().with_file_system(params.fs.clone());
+
+    let (_, analyze_diagnostics) = analyze(
+        &root,
+        filter,
+        &analyzer_options,
+        md_services,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
 
     let diagnostics = params.parsed_source.serde_diagnostics(&params.workspace_db);
 
@@ -520,12 +814,13 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         path,
         workspace_db,
         project_layout,
+        fs,
         language,
         skip,
         only,
         enabled_rules: rules,
         suppression_reason,
-        plugins: _,
+        plugins,
         categories,
         working_directory,
         compute_actions,
@@ -563,38 +858,46 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     };
 
     let action_offset = parsed_source.diagnostic_offset(&workspace_db);
-    analyze(&tree, filter, &analyzer_options, |signal| {
-        if compute_actions {
-            actions.extend(
-                signal
-                    .actions(ActionFilter::all())
-                    .into_code_action_iter()
-                    .map(|item| CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
+    let md_services = MarkdownAnalyzerServices::default().with_file_system(fs);
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        md_services,
+        &plugins,
+        |signal| {
+            if compute_actions {
+                actions.extend(
+                    signal
+                        .actions(ActionFilter::all())
+                        .into_code_action_iter()
+                        .map(|item| CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            applicability: Some(item.suggestion.applicability),
+                            suggestion: Some(item.suggestion),
+                            offset: action_offset,
+                        }),
+                );
+            } else {
+                actions.extend(signal.actions_metadata().into_iter().map(|meta| {
+                    CodeAction {
+                        category: meta.category,
+                        rule_name: meta
                             .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        applicability: Some(item.suggestion.applicability),
-                        suggestion: Some(item.suggestion),
+                            .map(|(g, r)| (Cow::Borrowed(g), Cow::Borrowed(r))),
+                        applicability: Some(meta.applicability),
+                        suggestion: None,
                         offset: action_offset,
-                    }),
-            );
-        } else {
-            actions.extend(signal.actions_metadata().into_iter().map(|meta| {
-                CodeAction {
-                    category: meta.category,
-                    rule_name: meta
-                        .rule_name
-                        .map(|(g, r)| (Cow::Borrowed(g), Cow::Borrowed(r))),
-                    applicability: Some(meta.applicability),
-                    suggestion: None,
-                    offset: action_offset,
-                }
-            }));
-        }
+                    }
+                }));
+            }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -638,13 +941,20 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
         loop {
             let mut pending_actions = Vec::new();
 
-            let (_, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if params.collect_final_diagnostics {
-                    process_fix_all.collect_signal(signal, &mut pending_actions)
-                } else {
-                    process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
-                }
-            });
+            let (_, _) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                MarkdownAnalyzerServices::default().with_file_system(params.fs.clone()),
+                &params.plugins,
+                |signal| {
+                    if params.collect_final_diagnostics {
+                        process_fix_all.collect_signal(signal, &mut pending_actions)
+                    } else {
+                        process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
+                    }
+                },
+            );
 
             let result = process_fix_all.process_batch_actions(pending_actions, |root| {
                 tree = match MdRoot::cast(root) {
@@ -673,9 +983,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
     loop {
         let mut pending_actions = Vec::new();
 
-        let (_, _) = analyze(&tree, fixable_filter, &analyzer_options, |signal| {
-            process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions)
-        });
+        let (_, _) = analyze(
+            &tree,
+            fixable_filter,
+            &analyzer_options,
+            MarkdownAnalyzerServices::default().with_file_system(params.fs.clone()),
+            &params.plugins,
+            |signal| process_fix_all.collect_signal_fixes_only(signal, &mut pending_actions),
+        );
 
         let result = process_fix_all.process_batch_actions(pending_actions, |root| {
             tree = match MdRoot::cast(root) {
@@ -692,12 +1007,63 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<Option<FixedFileResult>, W
 
     // Phase 2: all rules for final diagnostics
     if params.collect_final_diagnostics {
-        let (_, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            process_fix_all.collect_diagnostic_only(signal)
-        });
+        let (_, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            MarkdownAnalyzerServices::default().with_file_system(params.fs.clone()),
+            &params.plugins,
+            |signal| process_fix_all.collect_diagnostic_only(signal),
+        );
     }
 
     Ok(Some(
         process_fix_all.finish(tree.syntax().as_send().unwrap()),
     ))
 }
+
+#[instrument(level = "debug", skip_all)]
+fn update_snippets(
+    root: super::ParsedOrigin,
+    workspace_db: WorkspaceDb,
+    new_snippets: Vec<UpdateSnippetsNodes>,
+) -> Result<SendNode, WorkspaceError> {
+    let tree: MdRoot = root.tree(&workspace_db);
+    let mut mutation = BatchMutation::new(tree.syntax().clone());
+    let iterator = tree.syntax().descendants().filter_map(MdCodeContent::cast);
+
+    for code in iterator {
+        let Ok(value_token) = code.value_token() else {
+            continue;
+        };
+        let Some(snippet) = new_snippets
+            .iter()
+            .find(|snippet| snippet.range == value_token.text_range())
+        else {
+            continue;
+        };
+
+        // Keep the line break that ends the opening-fence line, and make sure
+        // that the closing fence stays on its own line.
+        let text = value_token.text();
+        let mut new_text = format!(
+            "{}{}",
+            &text[..leading_line_break_len(text)],
+            snippet.new_code
+        );
+        if !new_text.ends_with('\n') {
+            new_text.push('\n');
+        }
+        let new_token = MarkdownSyntaxToken::new_detached(
+            MarkdownSyntaxKind::MD_CODE_LITERAL,
+            &new_text,
+            [],
+            [],
+        );
+        mutation.replace_token(value_token, new_token);
+    }
+
+    let root = mutation.commit();
+
+    Ok(root.as_send().unwrap())
+}
@@ -46,9 +46,10 @@ pub use crate::file_handlers::vue::VueFileHandler;
 use crate::settings::{Settings, SettingsIdentity, SettingsWithEditor};
 use crate::utils::growth_guard::GrowthGuard;
 use crate::workspace::{
-    CodeAction, DefinitionReference, FixAction, FixFileMode, GetSyntaxTreeResult,
-    GoToDefinitionResult, PatternId, PullActionsResult, PullDiagnosticsAndActionsResult,
-    RenameResult, SearchQuery,
+    CodeAction, DefinitionReference, DocumentSymbolsResult, FindReferencesResult, FixAction,
+    FixFileMode, GetSyntaxTreeResult, GoToDefinitionResult, HoverResult, InlayHintKind,
+    InlayHintsResult, PatternId, PatternMatches, PrepareRenameResult, PullActionsResult,
+    PullDiagnosticsAndActionsResult, RenameResult, SearchQuery,
 };
 use biome_analyze::options::JsxRuntime;
 use biome_analyze::{
@@ -66,7 +67,7 @@ use biome_css_syntax::CssLanguage;
 use biome_db::{AnyParsedSource, ParsedSnippet, ParsedSource};
 use biome_diagnostics::{Applicability, Diagnostic, DiagnosticExt, Error, Severity, category};
 use biome_formatter::Printed;
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, FileSystem};
 #[cfg(feature = "lang_graphql")]
 use biome_graphql_analyze::METADATA as graphql_metadata;
 #[cfg(feature = "lang_graphql")]
@@ -95,6 +96,8 @@ use biome_project_layout::ProjectLayout;
 use biome_rowan::TokenText;
 use biome_rowan::{BatchMutation, NodeCache, SendNode, SyntaxNode, TextRange, TextSize};
 use biome_text_edit::TextEdit;
+#[cfg(feature = "lang_yaml")]
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 #[cfg(feature = "lang_html")]
 use html::HtmlFileHandler;
@@ -131,6 +134,8 @@ pub struct FixAllParams<'a> {
     #[cfg(feature = "module_graph")]
     pub(crate) module_db: Rc<dyn ModuleDb>,
     pub(crate) project_layout: Arc<ProjectLayout>,
+    /// The file system, used by the rules that read the files linked by the document.
+    pub(crate) fs: Arc<dyn FileSystem>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: &'a [AnalyzerSelector],
     pub(crate) skip: &'a [AnalyzerSelector],
@@ -464,6 +469,8 @@ pub(crate) struct LintParams<'a> {
     #[cfg(feature = "module_graph")]
     pub(crate) module_db: Rc<dyn ModuleDb>,
     pub(crate) project_layout: Arc<ProjectLayout>,
+    /// The file system, used by the rules that read the files linked by the document.
+    pub(crate) fs: Arc<dyn FileSystem>,
     pub(crate) suppression_reason: Option<String>,
     pub(crate) enabled_selectors: &'a [AnalyzerSelector],
     pub(crate) plugins: AnalyzerPluginVec,
@@ -979,6 +986,8 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) path: &'a BiomePath,
     pub(crate) workspace_db: WorkspaceDb,
     pub(crate) project_layout: Arc<ProjectLayout>,
+    /// The file system, used by the rules that read the files linked by the document.
+    pub(crate) fs: Arc<dyn FileSystem>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: &'a [AnalyzerSelector],
     pub(crate) skip: &'a [AnalyzerSelector],
@@ -1027,7 +1036,7 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
-    /// It renames a binding inside a file
+    /// It renames a binding and its imports in other modules
     pub(crate) rename: Option<Rename>,
     /// It updates the snippets contained in the original root
     pub(crate) update_snippets: Option<UpdateSnippets>,
@@ -1100,7 +1109,7 @@ type Search = fn(
     &SettingsWithEditor,
     PatternId,
     WorkspaceDb,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<PatternMatches, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -1120,6 +1129,16 @@ pub(crate) struct EnabledForPath {
 pub(crate) struct EditorCapabilities {
     pub(crate) resolve_binding: Option<ResolveBinding>,
     pub(crate) resolve_definition: Option<ResolveDefinition>,
+    /// Describes the symbol under the cursor
+    pub(crate) hover: Option<Hover>,
+    /// Finds the usages of the symbol under the cursor
+    pub(crate) find_references: Option<FindReferences>,
+    /// Lists the declarations of a document
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// Checks that the symbol under the cursor can be renamed
+    pub(crate) prepare_rename: Option<PrepareRename>,
+    /// Lists the inferred types and parameter names shown inside the code
+    pub(crate) inlay_hints: Option<InlayHints>,
 }
 
 pub(crate) struct ResolveBindingParams {
@@ -1138,6 +1157,18 @@ pub(crate) struct ResolveDefinitionParams<'a> {
 
 type ResolveBinding = fn(ResolveBindingParams) -> Option<DefinitionReference>;
 type ResolveDefinition = fn(ResolveDefinitionParams) -> Option<GoToDefinitionResult>;
+type Hover = fn(&BiomePath, AnyParsedSource, TextSize, WorkspaceDb) -> Option<HoverResult>;
+type FindReferences =
+    fn(&BiomePath, AnyParsedSource, TextSize, bool, WorkspaceDb) -> Option<FindReferencesResult>;
+type DocumentSymbols = fn(AnyParsedSource, WorkspaceDb) -> DocumentSymbolsResult;
+type InlayHints =
+    fn(&BiomePath, AnyParsedSource, TextRange, &[InlayHintKind], WorkspaceDb) -> InlayHintsResult;
+type PrepareRename = fn(
+    &BiomePath,
+    AnyParsedSource,
+    TextSize,
+    WorkspaceDb,
+) -> Result<Option<PrepareRenameResult>, WorkspaceError>;
 
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
@@ -1751,6 +1782,31 @@ impl RegistryVisitor<HtmlLanguage> for LintVisitor<'_, '_> {
     }
 }
 
+#[cfg(feature = "lang_yaml")]
+impl RegistryVisitor<YamlLanguage> for LintVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = YamlLanguage>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>(
+            biome_yaml_analyze::METADATA
+                .find_rule(R::Group::NAME, R::METADATA.name)
+                .map(RuleFilter::from),
+        )
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 struct ManifestVisitorResult {
     enabled_rules: Vec<RuleFilter<'static>>,
@@ -2157,6 +2213,23 @@ impl RegistryVisitor<HtmlLanguage> for AssistsVisitor<'_, '_> {
     }
 }
 
+#[cfg(feature = "lang_yaml")]
+impl RegistryVisitor<YamlLanguage> for AssistsVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 struct AnalyzerVisitorComputedResult {
     syntax_rules: Vec<RuleFilter<'static>>,
@@ -2265,6 +2338,8 @@ fn compute_analyzer_visitor(
     biome_graphql_analyze::visit_registry(&mut lint);
     #[cfg(feature = "lang_html")]
     biome_html_analyze::visit_registry(&mut lint);
+    #[cfg(feature = "lang_yaml")]
+    biome_yaml_analyze::visit_registry(&mut lint);
     let (linter_enabled_rules, linter_disabled_rules, mut rules_with_fix) = lint.finish();
 
     let mut assist = AssistsVisitor::new(assist_actions.as_deref());
@@ -2278,6 +2353,8 @@ fn compute_analyzer_visitor(
     biome_graphql_analyze::visit_registry(&mut assist);
     #[cfg(feature = "lang_html")]
     biome_html_analyze::visit_registry(&mut assist);
+    #[cfg(feature = "lang_yaml")]
+    biome_yaml_analyze::visit_registry(&mut assist);
     let (assists_enabled_rules, assists_disabled_rules, assists_rules_with_fix) = assist.finish();
     rules_with_fix.extend(assists_rules_with_fix);
 
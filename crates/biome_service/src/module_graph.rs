@@ -8,7 +8,7 @@ pub(crate) use biome_module_graph::resolve_html_module;
 pub(crate) use biome_module_graph::resolve_js_module;
 #[cfg(feature = "module_graph")]
 pub(crate) use biome_module_graph::{
-    ModuleDb, ModuleDependencies, ModuleInfo, ModuleInfoKind, PathInfoCache,
+    ModuleDb, ModuleDependencies, ModuleInfo, ModuleInfoKind, PathInfoCache, js_unused_code,
 };
 
 #[cfg(not(feature = "module_graph"))]
@@ -73,6 +73,7 @@ use biome_plugin_loader::Plugins;
 use camino::{Utf8Path, Utf8PathBuf};
 use enumflags2::BitFlags;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use regex::Regex;
 use std::borrow::Cow;
 use std::ops::Deref;
 use std::sync::{Arc, RwLock};
@@ -613,6 +614,7 @@ pub type SettingsWithEditor<'a> = SettingsHandle<'a, SettingsEditorState>;
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum EditorFeature {
     GotoDefinition = 1 << 0,
+    Rename = 1 << 1,
 }
 
 #[derive(
@@ -1065,6 +1067,10 @@ impl From<biome_configuration::graphql::GraphqlConfiguration>
             language_setting.assist = assist.into();
         }
 
+        if let Some(schema) = graphql.schema {
+            language_setting.environment.schema_paths = schema;
+        }
+
         language_setting
     }
 }
@@ -1139,6 +1145,12 @@ impl From<biome_configuration::yaml::YamlConfiguration>
         if let Some(formatter) = yaml.formatter {
             language_setting.formatter = formatter.into();
         }
+        if let Some(linter) = yaml.linter {
+            language_setting.linter = linter.into();
+        }
+        if let Some(assist) = yaml.assist {
+            language_setting.assist = assist.into();
+        }
 
         language_setting
     }
@@ -1301,13 +1313,16 @@ impl VcsSettings {
         patterns: &[&str],
     ) -> Result<(), WorkspaceError> {
         match self.client_kind {
-            Some(VcsClientKind::Git) => {
+            Some(VcsClientKind::Git | VcsClientKind::Jujutsu) => {
                 let git_ignore = VcsIgnoredPatterns::git_ignore(path, patterns)?;
                 self.ignore_matches = Some(VcsIgnoredPatterns::Git {
                     root: git_ignore,
                     nested: vec![],
                 });
             }
+            Some(VcsClientKind::Mercurial) => {
+                self.ignore_matches = Some(VcsIgnoredPatterns::hg_ignore(path, patterns)?);
+            }
             None => {}
         };
 
@@ -1317,12 +1332,22 @@ impl VcsSettings {
 
 #[derive(Clone, Debug)]
 pub enum VcsIgnoredPatterns {
+    /// Used by Git and Jujutsu
     Git {
         /// Represents the `.gitignore` file at the root of the project
         root: Gitignore,
         /// The list of nested `.gitignore` files found inside the project
         nested: Vec<Gitignore>,
     },
+    /// Represents the `.hgignore` file at the root of a Mercurial repository.
+    ///
+    /// Mercurial doesn't support nested ignore files.
+    Mercurial {
+        /// The glob patterns, converted to Git ignore patterns
+        globs: Gitignore,
+        /// The regular expressions, matched against the path relative to the repository
+        regexes: Vec<Regex>,
+    },
 }
 
 impl VcsIgnoredPatterns {
@@ -1330,44 +1355,55 @@ impl VcsIgnoredPatterns {
     ///
     /// The `root_path` represents the root of the project, as we want to match all ignore files untile the root.
     pub fn is_ignored(&self, path: &Utf8Path, is_dir: bool, root_path: Option<&Utf8Path>) -> bool {
-        match self {
-            Self::Git { root, nested, .. } => {
-                match root_path {
-                    None => Self::is_git_ignore(root, nested.as_slice(), path, is_dir),
-                    Some(root_path) => {
-                        // NOTE: this could be a bug of the library, need to explore. Let's assume it isn't
-                        // When crawling the file system with the CLI, we correctly exclude ignored folders
-                        // such as `dist/` or `build/`, in case the path to match is `/Users/foo/project/dist`
-                        //
-                        // However, the LSP sends absolute file paths, e.g. `/Users/foo/project/dist/a.min.js`,
-                        // and they **don't** match globs such as `dist/`.
-                        // To work around this limitation, we crawl upwards the parents of the path, until
-                        // we arrive at the `root_path`.
-                        let mut current_path = path;
-                        loop {
-                            if current_path == root_path {
-                                break false;
-                            }
-                            if Self::is_git_ignore(
-                                root,
-                                nested.as_slice(),
-                                current_path,
-                                current_path.is_dir(),
-                            ) {
-                                break true;
-                            }
-                            if let Some(parent) = current_path.parent() {
-                                current_path = parent;
-                            } else {
-                                break false;
-                            }
-                        }
+        match root_path {
+            None => self.matches(path, is_dir),
+            Some(root_path) => {
+                // NOTE: this could be a bug of the library, need to explore. Let's assume it isn't
+                // When crawling the file system with the CLI, we correctly exclude ignored folders
+                // such as `dist/` or `build/`, in case the path to match is `/Users/foo/project/dist`
+                //
+                // However, the LSP sends absolute file paths, e.g. `/Users/foo/project/dist/a.min.js`,
+                // and they **don't** match globs such as `dist/`.
+                // To work around this limitation, we crawl upwards the parents of the path, until
+                // we arrive at the `root_path`.
+                let mut current_path = path;
+                loop {
+                    if current_path == root_path {
+                        break false;
+                    }
+                    if self.matches(current_path, current_path.is_dir()) {
+                        break true;
+                    }
+                    if let Some(parent) = current_path.parent() {
+                        current_path = parent;
+                    } else {
+                        break false;
                     }
                 }
             }
         }
     }
 
+    fn matches(&self, path: &Utf8Path, is_dir: bool) -> bool {
+        match self {
+            Self::Git { root, nested } => {
+                Self::is_git_ignore(root, nested.as_slice(), path, is_dir)
+            }
+            Self::Mercurial { globs, regexes } => {
+                let path = path.strip_prefix(globs.path()).unwrap_or(path);
+                if globs.matched(path, is_dir).is_ignore() {
+                    return true;
+                }
+                let mut path = path.as_str().replace('\\', "/");
+                if is_dir {
+                    // Allows patterns such as `^dist/` to match the directory itself
+                    path.push('/');
+                }
+                regexes.iter().any(|regex| regex.is_match(&path))
+            }
+        }
+    }
+
     fn is_git_ignore(
         root: &Gitignore,
         nested: &[Gitignore],
@@ -1395,6 +1431,7 @@ impl VcsIgnoredPatterns {
             Self::Git { nested, .. } => {
                 nested.push(git_ignore);
             }
+            Self::Mercurial { .. } => {}
         }
     }
 
@@ -1427,6 +1464,162 @@ impl VcsIgnoredPatterns {
         })?;
         Ok(gitignore)
     }
+
+    /// Creates an instance of [VcsIgnoredPatterns::Mercurial] for the lines of a `.hgignore`
+    /// file.
+    ///
+    /// Glob patterns are converted to Git ignore patterns. Regular expressions are kept as is.
+    /// Patterns that include other files aren't supported, and are skipped.
+    ///
+    /// ## Error
+    ///
+    /// If the patterns are invalid
+    pub(crate) fn hg_ignore(path: &Utf8Path, lines: &[&str]) -> Result<Self, WorkspaceError> {
+        let invalid_pattern = |message: String| {
+            WorkspaceError::from(BiomeDiagnostic::InvalidIgnorePattern(
+                InvalidIgnorePattern {
+                    message,
+                    file_path: Some(path.to_string()),
+                },
+            ))
+        };
+
+        let mut globs = Vec::new();
+        let mut regexes = Vec::new();
+        for pattern in HgIgnorePattern::parse(lines) {
+            match pattern {
+                HgIgnorePattern::Glob(glob) => globs.push(glob),
+                HgIgnorePattern::Regex(regex) => {
+                    regexes
+                        .push(Regex::new(&regex).map_err(|err| invalid_pattern(err.to_string()))?);
+                }
+            }
+        }
+        let globs = globs.iter().map(String::as_str).collect::<Vec<_>>();
+
+        Ok(Self::Mercurial {
+            globs: Self::git_ignore(path, &globs)?,
+            regexes,
+        })
+    }
+}
+
+/// A pattern of a `.hgignore` file.
+#[derive(Debug, Eq, PartialEq)]
+enum HgIgnorePattern {
+    /// A Git ignore pattern converted from a Mercurial glob
+    Glob(String),
+    /// A regular expression, which isn't rooted unless it starts with `^`
+    Regex(String),
+}
+
+impl HgIgnorePattern {
+    /// Parses the lines of a `.hgignore` file.
+    ///
+    /// See <https://www.mercurial-scm.org/doc/hgignore.5.html>.
+    fn parse(lines: &[&str]) -> Vec<Self> {
+        let mut is_glob_syntax = false;
+        let mut patterns = Vec::new();
+        for line in lines {
+            let line = Self::strip_comment(line);
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(syntax) = line.strip_prefix("syntax:") {
+                match syntax.trim() {
+                    "glob" => is_glob_syntax = true,
+                    "regexp" | "re" => is_glob_syntax = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            let pattern = if let Some(glob) = line
+                .strip_prefix("glob:")
+                .or_else(|| line.strip_prefix("relglob:"))
+            {
+                Self::Glob(Self::glob_to_git_ignore(glob))
+            } else if let Some(glob) = line.strip_prefix("rootglob:") {
+                Self::Glob(format!("/{}", glob.trim_start_matches('/')))
+            } else if let Some(path) = line.strip_prefix("path:") {
+                Self::Glob(format!(
+                    "/{}",
+                    Self::escape_glob(path.trim_start_matches('/'))
+                ))
+            } else if let Some(regex) = line
+                .strip_prefix("re:")
+                .or_else(|| line.strip_prefix("regexp:"))
+                .or_else(|| line.strip_prefix("relre:"))
+            {
+                Self::Regex(regex.to_string())
+            } else if [
+                "include:",
+                "subinclude:",
+                "listfile:",
+                "listfile0:",
+                "rootfilesin:",
+                "filepath:",
+                "set:",
+            ]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+            {
+                continue;
+            } else if is_glob_syntax {
+                Self::Glob(Self::glob_to_git_ignore(line))
+            } else {
+                Self::Regex(line.to_string())
+            };
+            patterns.push(pattern);
+        }
+
+        patterns
+    }
+
+    /// Removes the comment of the line. A `#` that is escaped with a backslash isn't a comment.
+    fn strip_comment(line: &str) -> String {
+        let mut result = String::with_capacity(line.len());
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if chars.peek() == Some(&'#') => {
+                    result.push('#');
+                    chars.next();
+                }
+                '#' => break,
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    /// Mercurial globs are never rooted, while Git ignore patterns that contain a slash are
+    /// rooted. Mercurial also doesn't support negated patterns.
+    fn glob_to_git_ignore(glob: &str) -> String {
+        let glob = glob.trim_start_matches('/');
+        let glob = if glob.starts_with('!') {
+            format!("\\{glob}")
+        } else {
+            glob.to_string()
+        };
+        if glob.trim_end_matches('/').contains('/') && !glob.starts_with("**/") {
+            format!("**/{glob}")
+        } else {
+            glob
+        }
+    }
+
+    fn escape_glob(path: &str) -> String {
+        let mut result = String::with_capacity(path.len());
+        for c in path.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\' | '!') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result
+    }
 }
 
 /// An optional list of globs with exceptions that first normalizes the tested paths before matching them against the globs.
@@ -1,7 +1,7 @@
 use crate::scanner::ScanKind;
 use crate::settings::{
-    LanguageSettings, ModuleGraphResolutionKind, ServiceLanguage, Settings,
-    to_json_language_settings,
+    HgIgnorePattern, LanguageSettings, ModuleGraphResolutionKind, ServiceLanguage, Settings,
+    VcsIgnoredPatterns, to_json_language_settings,
 };
 use biome_analyze::RuleFilter;
 use biome_configuration::analyzer::{GroupPlainConfiguration, SeverityOrGroup, Style};
@@ -444,3 +444,61 @@ fn assert_no_parse_diagnostics(source: &str, options: HtmlParserOptions) {
         parse.diagnostics()
     );
 }
+
+#[test]
+fn parses_hg_ignore_syntaxes() {
+    let lines = [
+        "# regexp is the default syntax",
+        r"\.orig$",
+        "syntax: glob",
+        "*.pyc  # trailing comment",
+        "build/output",
+        r"issue\#1.txt",
+        "rootglob:dist/*",
+        "re:^generated/",
+        "path:docs/[draft]",
+        "include:other.hgignore",
+    ];
+
+    assert_eq!(
+        HgIgnorePattern::parse(&lines),
+        [
+            HgIgnorePattern::Regex(r"\.orig$".to_string()),
+            HgIgnorePattern::Glob("*.pyc".to_string()),
+            HgIgnorePattern::Glob("**/build/output".to_string()),
+            HgIgnorePattern::Glob("issue#1.txt".to_string()),
+            HgIgnorePattern::Glob("/dist/*".to_string()),
+            HgIgnorePattern::Regex("^generated/".to_string()),
+            HgIgnorePattern::Glob(r"/docs/\[draft\]".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn matches_hg_ignore_patterns() {
+    let root = Utf8Path::new("/project");
+    let lines = [
+        r"\.orig$",
+        "^generated/",
+        "syntax: glob",
+        "*.log",
+        "cache/tmp",
+        "rootglob:dist",
+    ];
+    let patterns = VcsIgnoredPatterns::hg_ignore(root, &lines).unwrap();
+
+    assert!(patterns.is_ignored(Utf8Path::new("/project/src/a.js.orig"), false, None));
+    assert!(patterns.is_ignored(Utf8Path::new("/project/generated"), true, None));
+    assert!(patterns.is_ignored(Utf8Path::new("/project/src/debug.log"), false, None));
+    assert!(patterns.is_ignored(Utf8Path::new("/project/src/cache/tmp"), true, None));
+    assert!(patterns.is_ignored(Utf8Path::new("/project/dist"), true, None));
+    assert!(!patterns.is_ignored(Utf8Path::new("/project/src/dist"), true, None));
+    assert!(!patterns.is_ignored(Utf8Path::new("/project/src/generated"), true, None));
+    assert!(!patterns.is_ignored(Utf8Path::new("/project/src/a.js"), false, None));
+}
+
+#[test]
+fn reports_invalid_hg_ignore_regex() {
+    let result = VcsIgnoredPatterns::hg_ignore(Utf8Path::new("/project"), &["(unclosed"]);
+    assert!(result.is_err());
+}
@@ -96,7 +96,7 @@ use crate::settings::{EditorFeatures, ModuleGraphResolutionKind, SettingsWithEdi
 pub use client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
 #[cfg(feature = "lang_grit")]
 pub use search::grit::GritSearchQuery;
-pub use search::{NoopQueryProvider, SearchLanguage, SearchQuery};
+pub use search::{NoopQueryProvider, PatternMatches, SearchLanguage, SearchQuery};
 pub use server::OpenFileReason;
 
 /// Notification regarding a workspace's service data.
@@ -1298,6 +1298,37 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Edits of the other modules of the project that import the renamed
+    /// symbol
+    pub dependents: Vec<DependentRenameEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DependentRenameEdit {
+    pub path: BiomePath,
+    /// List of text edit operations to apply on the source code of this module
+    pub indels: TextEdit,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameResult {
+    /// Range of the symbol that would be renamed
+    pub range: TextRange,
+    /// Current name of the symbol
+    pub placeholder: String,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -1371,6 +1402,133 @@ pub enum LocalEmbeddedLanguage {
     Js,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverResult {
+    /// Range of the symbol being described
+    pub range: TextRange,
+    /// Description of the symbol, formatted as Markdown
+    pub contents: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+    /// Whether the declaration of the symbol is part of the result
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesResult {
+    pub references: Vec<(BiomePath, TextRange)>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolsResult {
+    /// The top-level declarations of the document
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+/// A declaration of a document, with the declarations nested inside it
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: DocumentSymbolKind,
+    /// Range of the whole declaration
+    pub range: TextRange,
+    /// Range of the name of the declaration
+    pub selection_range: TextRange,
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentSymbolKind {
+    Class,
+    Constant,
+    Enum,
+    EnumMember,
+    Function,
+    Interface,
+    Method,
+    Module,
+    Property,
+    TypeAlias,
+    Variable,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// Only the hints positioned inside this range are returned
+    pub range: TextRange,
+    /// The kinds of hints to return
+    pub kinds: Vec<InlayHintKind>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsResult {
+    /// The hints, sorted by position
+    pub hints: Vec<InlayHint>,
+}
+
+/// A label that the editor shows inside the code, without changing the document
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHint {
+    pub kind: InlayHintKind,
+    /// Position where the label is shown
+    pub position: TextSize,
+    pub label: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum InlayHintKind {
+    /// The inferred type of a variable declared without a type annotation
+    VariableType,
+    /// The inferred return type of a function declared without a return type
+    ReturnType,
+    /// The name of the parameter that receives an argument of a call
+    ParameterName,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -1469,6 +1627,9 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub path: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file with the rewrites of the pattern applied.
+    /// It's `None` when the pattern doesn't rewrite anything.
+    pub rewritten: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -1585,6 +1746,49 @@ pub struct CloseProjectParams {
     pub project_key: ProjectKey,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetUnusedCodeParams {
+    pub project_key: ProjectKey,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetUnusedCodeResult {
+    /// The files that can't be reached from the entry points of their package.
+    pub unreachable_files: Vec<BiomePath>,
+
+    /// The exports that aren't imported by any module of the project.
+    pub unused_exports: Vec<UnusedExport>,
+
+    /// The dependencies that aren't imported by any module of their package.
+    pub unused_dependencies: Vec<UnusedDependency>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedExport {
+    /// The path of the module exporting the symbol.
+    pub path: BiomePath,
+
+    /// The name of the export, `default` for default exports.
+    pub name: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedDependency {
+    /// The path of the `package.json` declaring the dependency.
+    pub manifest_path: BiomePath,
+
+    /// The name of the package.
+    pub name: String,
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -1598,6 +1802,21 @@ impl From<BiomePath> for FileExistsParams {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ListProjectFilesParams {
+    pub project_key: ProjectKey,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ListProjectFilesResult {
+    /// The paths of the files, in alphabetical order
+    pub paths: Vec<BiomePath>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -1673,6 +1892,17 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// `scan_project()`, it will also be unregistered.
     fn close_project(&self, params: CloseProjectParams) -> Result<(), WorkspaceError>;
 
+    /// Returns the files, exports and dependencies of the project that aren't
+    /// used, as reported by the `noUnreachableFiles`, `noUnusedExports` and
+    /// `noUnusedDependencies` rules.
+    ///
+    /// The report is computed from the module graph, so the project must have
+    /// been scanned with [ScanKind::Project] or [ScanKind::TypeAware].
+    fn get_unused_code(
+        &self,
+        params: GetUnusedCodeParams,
+    ) -> Result<GetUnusedCodeResult, WorkspaceError>;
+
     // #endregion
 
     // #region FILE-LEVEL METHODS
@@ -1692,6 +1922,15 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// It throws an error only if there's an issue with the client transport.
     fn file_exists(&self, params: FileExistsParams) -> Result<bool, WorkspaceError>;
 
+    /// Returns the files of a project that are stored in the workspace, either
+    /// because a client opened them or because the scanner indexed them.
+    ///
+    /// Files that belong to dependencies aren't returned.
+    fn list_project_files(
+        &self,
+        params: ListProjectFilesParams,
+    ) -> Result<ListProjectFilesResult, WorkspaceError>;
+
     /// Checks whether a certain feature is supported for the given file path.
     ///
     /// There are different conditions:
@@ -1757,7 +1996,16 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns the content of the file with all safe code actions applied.
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
-    /// Returns the content of the file after renaming a symbol.
+    /// Checks whether the symbol at the given position can be renamed.
+    ///
+    /// Returns `None` if there's no symbol at this position.
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<Option<PrepareRenameResult>, WorkspaceError>;
+
+    /// Returns the edits that rename a symbol, in its file and in the
+    /// modules of the project that import it.
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
     /// Navigates to the definition of the symbol at the given cursor position.
@@ -1769,6 +2017,33 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: GoToDefinitionParams,
     ) -> Result<Option<GoToDefinitionResult>, WorkspaceError>;
 
+    /// Describes the symbol at the given position, including its inferred
+    /// type when type inference is available.
+    ///
+    /// Returns `None` if there's no symbol at this position.
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, WorkspaceError>;
+
+    /// Returns the usages of the symbol at the given position in its file.
+    ///
+    /// Returns `None` if there's no symbol at this position.
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<Option<FindReferencesResult>, WorkspaceError>;
+
+    /// Returns the outline of the file: its declarations and the members
+    /// of its classes, interfaces, enums and namespaces.
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError>;
+
+    /// Returns the inlay hints of a file, which show the inferred types of
+    /// variables and functions, and the names of the parameters of calls.
+    ///
+    /// No hint is returned when type inference isn't available.
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1909,8 +2184,10 @@ impl<W: Workspace> Workspace for RetryingWorkspace<W> {
         fn scan_project(params: ScanProjectParams) -> Result<ScanProjectResult, WorkspaceError>;
         fn update_settings(params: UpdateSettingsParams) -> Result<UpdateSettingsResult, WorkspaceError>;
         fn close_project(params: CloseProjectParams) -> Result<(), WorkspaceError>;
+        fn get_unused_code(params: GetUnusedCodeParams) -> Result<GetUnusedCodeResult, WorkspaceError>;
         fn open_file(params: OpenFileParams) -> Result<OpenFileResult, WorkspaceError>;
         fn file_exists(params: FileExistsParams) -> Result<bool, WorkspaceError>;
+        fn list_project_files(params: ListProjectFilesParams) -> Result<ListProjectFilesResult, WorkspaceError>;
         fn file_features(params: SupportsFeatureParams) -> Result<FileFeaturesResult, WorkspaceError>;
         fn is_path_ignored(params: PathIsIgnoredParams) -> Result<bool, WorkspaceError>;
         fn get_file_content(params: GetFileContentParams) -> Result<String, WorkspaceError>;
@@ -1924,8 +2201,13 @@ impl<W: Workspace> Workspace for RetryingWorkspace<W> {
         fn format_range(params: FormatRangeParams) -> Result<Printed, WorkspaceError>;
         fn format_on_type(params: FormatOnTypeParams) -> Result<Printed, WorkspaceError>;
         fn fix_file(params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
+        fn prepare_rename(params: PrepareRenameParams) -> Result<Option<PrepareRenameResult>, WorkspaceError>;
         fn rename(params: RenameParams) -> Result<RenameResult, WorkspaceError>;
         fn go_to_definition(params: GoToDefinitionParams) -> Result<Option<GoToDefinitionResult>, WorkspaceError>;
+        fn hover(params: HoverParams) -> Result<Option<HoverResult>, WorkspaceError>;
+        fn find_references(params: FindReferencesParams) -> Result<Option<FindReferencesResult>, WorkspaceError>;
+        fn document_symbols(params: DocumentSymbolsParams) -> Result<DocumentSymbolsResult, WorkspaceError>;
+        fn inlay_hints(params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
         fn close_file(params: CloseFileParams) -> Result<(), WorkspaceError>;
         fn update_module_graph(params: UpdateModuleGraphParams) -> Result<(), WorkspaceError>;
         fn parse_pattern(params: ParsePatternParams) -> Result<ParsePatternResult, WorkspaceError>;
@@ -1,9 +1,11 @@
 use super::{
-    ChangeFileParams, ChangeFileResult, CloseFileParams, FileExistsParams, FixFileParams,
-    FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    ChangeFileParams, ChangeFileResult, CloseFileParams, DocumentSymbolsParams,
+    DocumentSymbolsResult, FileExistsParams, FindReferencesParams, FindReferencesResult,
+    FixFileParams, FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
     GetControlFlowGraphParams, GetFormatterIRParams, GetModuleGraphParams, GetModuleGraphResult,
     GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult, GoToDefinitionParams,
-    GoToDefinitionResult, OpenFileParams, OpenFileResult, ProcessFileParams, ProcessFileResult,
+    GoToDefinitionResult, HoverParams, HoverResult, OpenFileParams, OpenFileResult,
+    PrepareRenameParams, PrepareRenameResult, ProcessFileParams, ProcessFileResult,
     PullActionsParams, PullActionsResult, PullDiagnosticsAndActionsParams,
     PullDiagnosticsAndActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameParams,
     RenameResult, ScanProjectParams, ScanProjectResult, SearchPatternParams, SearchResults,
@@ -11,8 +13,10 @@ use super::{
 };
 use crate::workspace::{
     CheckFileSizeParams, CheckFileSizeResult, CloseProjectParams, FileFeaturesResult,
-    GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams, OpenProjectParams,
-    OpenProjectResult, PathIsIgnoredParams, RageParams, RageResult, ServerInfo,
+    GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams, GetUnusedCodeParams,
+    GetUnusedCodeResult, InlayHintsParams, InlayHintsResult, ListProjectFilesParams,
+    ListProjectFilesResult, OpenProjectParams, OpenProjectResult, PathIsIgnoredParams, RageParams,
+    RageResult, ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -128,6 +132,13 @@ where
         self.request("biome/close_project", params)
     }
 
+    fn get_unused_code(
+        &self,
+        params: GetUnusedCodeParams,
+    ) -> Result<GetUnusedCodeResult, WorkspaceError> {
+        self.request("biome/get_unused_code", params)
+    }
+
     fn open_file(&self, params: OpenFileParams) -> Result<OpenFileResult, WorkspaceError> {
         self.request("biome/open_file", params)
     }
@@ -136,6 +147,13 @@ where
         self.request("biome/file_exists", params)
     }
 
+    fn list_project_files(
+        &self,
+        params: ListProjectFilesParams,
+    ) -> Result<ListProjectFilesResult, WorkspaceError> {
+        self.request("biome/list_project_files", params)
+    }
+
     fn file_features(
         &self,
         params: SupportsFeatureParams,
@@ -200,6 +218,13 @@ where
         self.request("biome/fix_file", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<Option<PrepareRenameResult>, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError> {
         self.request("biome/rename", params)
     }
@@ -211,6 +236,28 @@ where
         self.request("biome/go_to_definition", params)
     }
 
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, WorkspaceError> {
+        self.request("biome/hover", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<Option<FindReferencesResult>, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/document_symbols", params)
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        self.request("biome/inlay_hints", params)
+    }
+
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
         self.request("biome/close_file", params)
     }
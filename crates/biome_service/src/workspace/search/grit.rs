@@ -1,11 +1,12 @@
 use crate::WorkspaceError;
 use crate::diagnostics::{QueryDiagnostic, SearchError};
 use crate::settings::SettingsWithEditor;
-use crate::workspace::{PatternId, SearchLanguage, SearchQuery};
+use crate::workspace::{PatternId, PatternMatches, SearchLanguage, SearchQuery};
 use biome_fs::BiomePath;
 use biome_grit_patterns::{
-    CompilePatternOptions, CssTargetLanguage, GritQuery, GritQueryEffect, GritTargetFile,
-    GritTargetLanguage, JsTargetLanguage, JsonTargetLanguage, compile_pattern_with_options,
+    CompilePatternOptions, CssTargetLanguage, GraphqlTargetLanguage, GritQuery, GritQueryEffect,
+    GritTargetFile, GritTargetLanguage, HtmlTargetLanguage, JsTargetLanguage, JsonTargetLanguage,
+    MarkdownTargetLanguage, YamlTargetLanguage, compile_pattern_with_options,
 };
 use biome_languages::DocumentFileSource;
 use biome_parser::AnyParse;
@@ -26,8 +27,12 @@ impl SearchQuery for GritSearchQuery {
     ) -> Result<PatternId, WorkspaceError> {
         let target_language = match target_language {
             SearchLanguage::Css => GritTargetLanguage::from(CssTargetLanguage),
+            SearchLanguage::Graphql => GritTargetLanguage::from(GraphqlTargetLanguage),
+            SearchLanguage::Html => GritTargetLanguage::from(HtmlTargetLanguage),
             SearchLanguage::Js => GritTargetLanguage::from(JsTargetLanguage),
             SearchLanguage::Json => GritTargetLanguage::from(JsonTargetLanguage),
+            SearchLanguage::Markdown => GritTargetLanguage::from(MarkdownTargetLanguage),
+            SearchLanguage::Yaml => GritTargetLanguage::from(YamlTargetLanguage),
         };
         let options = CompilePatternOptions::default().with_default_language(target_language);
         let pattern = compile_pattern_with_options(pattern, options)?;
@@ -48,7 +53,7 @@ impl SearchQuery for GritSearchQuery {
         parse: AnyParse,
         _settings: &SettingsWithEditor,
         pattern_id: PatternId,
-    ) -> Result<Vec<TextRange>, WorkspaceError> {
+    ) -> Result<PatternMatches, WorkspaceError> {
         let patterns = self.patterns.pin();
         let query = patterns
             .get(&pattern_id)
@@ -61,15 +66,23 @@ impl SearchQuery for GritSearchQuery {
                 )))
             })?;
 
-        let matches = result
-            .effects
-            .into_iter()
-            .flat_map(|result| match result {
+        let mut matches = PatternMatches::default();
+        for effect in result.effects {
+            let ranges = match effect {
                 GritQueryEffect::Match(m) => m.ranges,
-                _ => Vec::new(),
-            })
-            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-            .collect();
+                GritQueryEffect::Rewrite(rewrite) => {
+                    matches.rewritten = Some(rewrite.rewritten.content);
+                    rewrite.original.ranges
+                }
+                // Creating files isn't supported by the search
+                GritQueryEffect::CreateFile(_) => Vec::new(),
+            };
+            matches.matches.extend(
+                ranges
+                    .into_iter()
+                    .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into())),
+            );
+        }
 
         Ok(matches)
     }
@@ -16,9 +16,13 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 #[serde(rename_all = "camelCase")]
 pub enum SearchLanguage {
     Css,
+    Graphql,
+    Html,
     #[default]
     Js,
     Json,
+    Markdown,
+    Yaml,
 }
 
 impl FromStr for SearchLanguage {
@@ -27,13 +31,31 @@ impl FromStr for SearchLanguage {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "css" => Self::Css,
+            "graphql" => Self::Graphql,
+            "html" => Self::Html,
             "javascript" => Self::Js,
             "json" => Self::Json,
-            _ => return Err("Target language must be one of: css, javascript, json"),
+            "markdown" => Self::Markdown,
+            "yaml" => Self::Yaml,
+            _ => {
+                return Err(
+                    "Target language must be one of: css, graphql, html, javascript, json, markdown, yaml",
+                );
+            }
         })
     }
 }
 
+/// The outcome of running a pattern against a single file
+#[derive(Debug, Default)]
+pub struct PatternMatches {
+    /// The ranges matched by the pattern
+    pub matches: Vec<TextRange>,
+    /// The content of the file once the rewrites of the pattern are applied,
+    /// if the pattern contains any rewrite
+    pub rewritten: Option<String>,
+}
+
 pub trait SearchQuery: Send + Sync {
     fn parse_pattern(
         &self,
@@ -48,7 +70,7 @@ pub trait SearchQuery: Send + Sync {
         _parse: AnyParse,
         _settings: &SettingsWithEditor,
         _: PatternId,
-    ) -> Result<Vec<TextRange>, WorkspaceError>;
+    ) -> Result<PatternMatches, WorkspaceError>;
 
     fn generate_pattern_id(&self) -> PatternId {
         static COUNTER: AtomicUsize = AtomicUsize::new(1);
@@ -73,7 +95,7 @@ impl SearchQuery for NoopQueryProvider {
         _: AnyParse,
         _: &SettingsWithEditor,
         _: PatternId,
-    ) -> Result<Vec<TextRange>, WorkspaceError> {
+    ) -> Result<PatternMatches, WorkspaceError> {
         Err(WorkspaceError::feature_not_enabled())
     }
 }
@@ -2,6 +2,8 @@ use super::{document::Document, *};
 use crate::Watcher;
 use crate::configuration::{LoadedConfiguration, ProjectScanComputer, read_config};
 use crate::db::{DbReadGuard, DbState, WorkspaceDb};
+#[cfg(feature = "lang_graphql")]
+use crate::diagnostics::CantLoadGraphqlSchema;
 use crate::diagnostics::{FileTooLarge, NoIgnoreFileFound, VcsDiagnostic};
 use crate::embed::EmbedContent;
 #[cfg(feature = "lang_js")]
@@ -22,7 +24,7 @@ use crate::module_graph::resolve_html_module;
 #[cfg(all(feature = "module_graph", feature = "lang_js"))]
 use crate::module_graph::resolve_js_module;
 #[cfg(feature = "module_graph")]
-use crate::module_graph::{ModuleDb, ModuleInfoKind};
+use crate::module_graph::{ModuleDb, ModuleInfoKind, js_unused_code};
 use crate::projects::{GetFileFeaturesParams, ProjectDb, ProjectKey};
 use crate::scanner::{
     IndexRequestKind, IndexTrigger, ScanOptions, Scanner, ScannerWatcherBridge, WatcherInstruction,
@@ -34,24 +36,28 @@ use crate::settings::{
 };
 use crate::workspace::{
     ChangeFileParams, ChangeFileResult, CheckFileSizeParams, CheckFileSizeResult, CloseFileParams,
-    CloseProjectParams, DropPatternParams, FeaturesBuilder, FileContent, FileExistsParams,
-    FileFeaturesResult, FixFileParams, FixFileResult, FormatFileParams, FormatOnTypeParams,
+    CloseProjectParams, DocumentSymbolsParams, DocumentSymbolsResult, DropPatternParams,
+    FeaturesBuilder, FileContent, FileExistsParams, FileFeaturesResult, FindReferencesParams,
+    FindReferencesResult, FixFileParams, FixFileResult, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
     GetModuleGraphParams, GetModuleGraphResult, GetRegisteredTypesParams, GetSemanticModelParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, GetTypeInfoParams, GoToDefinitionParams,
-    GoToDefinitionResult, IgnoreKind, OpenFileParams, OpenFileResult, OpenProjectParams,
-    OpenProjectResult, ParsePatternParams, ParsePatternResult, PathIsIgnoredParams,
-    PullActionsParams, PullActionsResult, PullDiagnosticsAndActionsParams,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, GetTypeInfoParams, GetUnusedCodeParams,
+    GetUnusedCodeResult, GoToDefinitionParams, GoToDefinitionResult, HoverParams, HoverResult,
+    IgnoreKind, InlayHintsParams, InlayHintsResult, ListProjectFilesParams, ListProjectFilesResult,
+    OpenFileParams, OpenFileResult, OpenProjectParams, OpenProjectResult, ParsePatternParams,
+    ParsePatternResult, PathIsIgnoredParams, PatternMatches, PrepareRenameParams,
+    PrepareRenameResult, PullActionsParams, PullActionsResult, PullDiagnosticsAndActionsParams,
     PullDiagnosticsAndActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RageEntry,
     RageParams, RageResult, RenameParams, RenameResult, ScanKind, ScanProjectParams,
     ScanProjectResult, SearchPatternParams, SearchResults, ServerInfo, ServiceNotification,
     Settings, SupportsFeatureParams, UpdateModuleGraphParams, UpdateSettingsParams,
     UpdateSettingsResult,
 };
+#[cfg(feature = "module_graph")]
+use crate::workspace::{UnusedDependency, UnusedExport};
 use crate::{Workspace, WorkspaceError};
 use biome_configuration::bool::Bool;
 use biome_configuration::max_size::MaxSize;
-use biome_configuration::vcs::VcsClientKind;
 use biome_configuration::{BiomeDiagnostic, Configuration, ConfigurationPathHint};
 #[cfg(all(feature = "module_graph", feature = "lang_css"))]
 use biome_css_syntax::AnyCssRoot;
@@ -60,10 +66,15 @@ use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge};
 use biome_diagnostics::print_diagnostic_to_string;
 use biome_diagnostics::{
-    Diagnostic, DiagnosticExt, Error, Severity, serde::Diagnostic as SerdeDiagnostic,
+    Diagnostic, DiagnosticExt, Error, PrintDescription, Severity,
+    serde::Diagnostic as SerdeDiagnostic,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName, PathKind, normalize_path};
+#[cfg(feature = "lang_graphql")]
+use biome_graphql_parser::parse_graphql;
+#[cfg(feature = "lang_graphql")]
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 #[cfg(all(feature = "module_graph", feature = "lang_html"))]
 use biome_html_syntax::HtmlRoot;
 #[cfg(all(feature = "module_graph", feature = "lang_js"))]
@@ -82,7 +93,7 @@ use biome_languages::javascript::{JsEmbeddingKind, LanguageVariant, ModuleKind};
 use biome_languages::{DocumentFileSource, JsonFileSource, LanguageDb};
 #[cfg(feature = "lang_js")]
 use biome_package::PackageType;
-use biome_package::{Catalogs, PackageJson};
+use biome_package::{Catalogs, PackageJson, Workspaces};
 use biome_parser::AnyParse;
 use biome_parser::diagnostic::ParseDiagnostic;
 #[cfg(feature = "plugins")]
@@ -1324,6 +1335,7 @@ impl WorkspaceServerWithDb<'_> {
                     #[cfg(feature = "module_graph")]
                     module_db: module_db.clone(),
                     project_layout: self.project_layout.clone(),
+                    fs: self.fs.clone(),
                     document_file_source,
                     only: &only,
                     skip: &skip,
@@ -1417,6 +1429,7 @@ impl WorkspaceServerWithDb<'_> {
             #[cfg(feature = "module_graph")]
             module_db,
             project_layout: self.project_layout.clone(),
+            fs: self.fs.clone(),
             document_file_source: state.file_source,
             only: &only,
             skip: &skip,
@@ -1553,6 +1566,7 @@ impl WorkspaceServerWithDb<'_> {
                 #[cfg(feature = "module_graph")]
                 module_db: module_db.clone(),
                 project_layout: self.project_layout.clone(),
+                fs: self.fs.clone(),
                 suppression_reason: None,
                 enabled_selectors: &enabled_rules,
                 pull_code_actions,
@@ -1590,6 +1604,7 @@ impl WorkspaceServerWithDb<'_> {
                     #[cfg(feature = "module_graph")]
                     module_db: module_db.clone(),
                     project_layout: self.project_layout.clone(),
+                    fs: self.fs.clone(),
                     suppression_reason: None,
                     enabled_selectors: &enabled_rules,
                     pull_code_actions,
@@ -1657,7 +1672,8 @@ impl WorkspaceServerWithDb<'_> {
         for plugin_config in plugins.iter() {
             let plugin_path = plugin_config.path();
             let includes = plugin_config.includes();
-            match BiomePlugin::load(self.fs.clone(), plugin_path, base_path, includes) {
+            let rules = plugin_config.rules();
+            match BiomePlugin::load(self.fs.clone(), plugin_path, base_path, includes, rules) {
                 Ok((plugin, _)) => {
                     plugin_cache.insert_plugin(plugin_path.to_owned().into(), plugin);
                 }
@@ -1672,6 +1688,121 @@ impl WorkspaceServerWithDb<'_> {
         diagnostics
     }
 
+    /// Builds the GraphQL schema from the files listed in `graphql.schema`.
+    ///
+    /// Files that can't be read or parsed are skipped and reported.
+    #[cfg(feature = "lang_graphql")]
+    fn load_graphql_schema(
+        &self,
+        paths: &[Utf8PathBuf],
+    ) -> (Option<Arc<GraphqlSchema>>, Vec<CantLoadGraphqlSchema>) {
+        if paths.is_empty() {
+            return (None, Vec::new());
+        }
+
+        let mut diagnostics = Vec::new();
+        let mut builder = GraphqlSchemaBuilder::default();
+        for path in paths {
+            let content = match self.fs.read_file_from_path(&path) {
+                Ok(content) => content,
+                Err(diagnostic) => {
+                    diagnostics.push(CantLoadGraphqlSchema {
+                        path: path.to_string(),
+                        reason: PrintDescription(&diagnostic).to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            if path.extension() == Some("json") {
+                if let Err(error) = builder.add_introspection(&content) {
+                    diagnostics.push(CantLoadGraphqlSchema {
+                        path: path.to_string(),
+                        reason: error.to_string(),
+                    });
+                }
+            } else {
+                let parsed = parse_graphql(&content);
+                if parsed.has_errors() {
+                    diagnostics.push(CantLoadGraphqlSchema {
+                        path: path.to_string(),
+                        reason: "the file contains syntax errors".to_string(),
+                    });
+                } else {
+                    builder.add_document(&parsed.tree());
+                }
+            }
+        }
+
+        (Some(Arc::new(builder.build())), diagnostics)
+    }
+
+    /// Returns the root settings and the nested settings of the project whose
+    /// GraphQL schema is built from the file at `path`.
+    ///
+    /// Nested settings are returned with their directory, root settings
+    /// without.
+    #[cfg(feature = "lang_graphql")]
+    fn settings_with_graphql_schema_file(
+        &self,
+        project_key: ProjectKey,
+        path: &Utf8Path,
+    ) -> Vec<(Option<Utf8PathBuf>, Arc<Settings>)> {
+        let db = self.get_db();
+        let project_db: &dyn ProjectDb = db.deref();
+        let Some(project) = project_db.get_project(&project_key) else {
+            return Vec::new();
+        };
+
+        let root_settings = (None, project.root_settings(project_db).clone_arc());
+        let nested_settings =
+            project
+                .nested_settings(project_db)
+                .iter()
+                .map(|(directory, settings)| {
+                    (Some(directory.as_ref().to_path_buf()), settings.clone_arc())
+                });
+        std::iter::once(root_settings)
+            .chain(nested_settings)
+            .filter(|(_, settings)| {
+                settings
+                    .languages
+                    .graphql
+                    .environment
+                    .schema_files
+                    .iter()
+                    .any(|schema_file| schema_file == path)
+            })
+            .collect()
+    }
+
+    /// Rebuilds the GraphQL schema of the settings that load it from the file
+    /// at `path`, so operations are validated against its latest content.
+    #[cfg(feature = "lang_graphql")]
+    fn reload_graphql_schema(
+        &self,
+        project_key: ProjectKey,
+        path: &Utf8Path,
+    ) -> Vec<CantLoadGraphqlSchema> {
+        let mut diagnostics = Vec::new();
+        for (directory, settings) in self.settings_with_graphql_schema_file(project_key, path) {
+            let mut settings = (*settings).clone();
+            let environment = &mut settings.languages.graphql.environment;
+            let (schema, schema_diagnostics) = self.load_graphql_schema(&environment.schema_files);
+            environment.schema = schema;
+            diagnostics.extend(schema_diagnostics);
+
+            match directory {
+                Some(directory) => {
+                    self.project_set_nested_settings(project_key, directory, settings)
+                }
+                None => self.project_set_root_settings(project_key, settings),
+            }
+        }
+
+        diagnostics
+    }
+
     #[cfg(feature = "plugins")]
     fn get_analyzer_plugins_for_project(
         &self,
@@ -1780,6 +1911,27 @@ impl WorkspaceServerWithDb<'_> {
                     return Ok(true); // We never index symlinks.
                 }
 
+                // The TypeScript configurations that are extended or referenced
+                // by a `tsconfig.json` are tracked by the project layout, and
+                // the modules are resolved again when one of them changes, so
+                // they need to be indexed and watched.
+                if !matches!(scan_kind, ScanKind::NoScanner)
+                    && self.project_layout.is_tsconfig_file(&path)
+                {
+                    return Ok(false);
+                }
+
+                // The GraphQL schema is reloaded when one of its files changes,
+                // so they need to be indexed and watched.
+                #[cfg(feature = "lang_graphql")]
+                if !matches!(scan_kind, ScanKind::NoScanner)
+                    && !self
+                        .settings_with_graphql_schema_file(project_key, &path)
+                        .is_empty()
+                {
+                    return Ok(false);
+                }
+
                 match scan_kind {
                     ScanKind::KnownFiles | ScanKind::TargetedKnownFiles { .. } => match ignore_kind
                     {
@@ -1854,8 +2006,26 @@ impl WorkspaceServerWithDb<'_> {
         None
     }
 
+    /// Reads the workspace package patterns from the `pnpm-workspace.yaml`
+    /// inside `package_dir`, if any.
+    fn load_pnpm_workspace_packages(&self, package_dir: &Utf8Path) -> Option<Workspaces> {
+        let workspace_file = package_dir.join("pnpm-workspace.yaml");
+        if !self.fs.path_is_file(&workspace_file) {
+            return None;
+        }
+
+        self.fs
+            .read_file_from_path(&workspace_file)
+            .ok()
+            .and_then(|content| PackageJson::parse_pnpm_workspace_packages(&content))
+    }
+
     /// Applies (or clears) pnpm workspace catalogs for the `package.json`
     /// manifest stored at `package_path`, based on the current project settings.
+    ///
+    /// The workspace packages declared in a `pnpm-workspace.yaml` next to the
+    /// manifest are applied regardless of the settings, because they are needed
+    /// for resolving imports between the packages of the workspace.
     fn apply_pnpm_workspace_catalog_to_package(
         &self,
         project_key: ProjectKey,
@@ -1874,6 +2044,7 @@ impl WorkspaceServerWithDb<'_> {
             } else {
                 manifest.catalog = None;
             }
+            manifest.pnpm_workspace_packages = self.load_pnpm_workspace_packages(package_path);
 
             self.project_layout
                 .insert_node_manifest(package_path.to_path_buf(), manifest);
@@ -2083,6 +2254,50 @@ impl WorkspaceServerWithDb<'_> {
         }
     }
 
+    /// Resolves the imports of the modules of the project with the given
+    /// `project_key` again.
+    ///
+    /// This is needed when a TypeScript configuration that is extended or
+    /// referenced by a `tsconfig.json` changes, because it may change the path
+    /// aliases of any module that uses the `tsconfig.json`.
+    #[cfg(feature = "module_graph")]
+    fn resolve_modules_again(&self, project_key: ProjectKey) -> Vec<Error> {
+        let db = self.get_db();
+        let mut paths = Vec::new();
+        db.for_each_module(&mut |module| {
+            // Stylesheets don't resolve their imports with `tsconfig.json`.
+            if !matches!(module.kind(&*db), ModuleInfoKind::Css(_)) {
+                paths.push(module.path(&*db).to_path_buf());
+            }
+        });
+        drop(db);
+
+        let mut diagnostics = Vec::new();
+        for path in paths {
+            let (Ok(parsed), Some(settings)) = (
+                self.get_parse(&path),
+                self.project_get_settings_for_path(project_key, &path),
+            ) else {
+                continue;
+            };
+
+            let update_kind = UpdateKind::AddedOrChanged(
+                OpenFileReason::Index(IndexTrigger::Update),
+                parsed.into(),
+            );
+            match self.update_module_graph_internal(
+                &BiomePath::new(&path),
+                update_kind,
+                settings.module_graph_resolution_kind.is_modules_and_types(),
+            ) {
+                Ok((_, module_diagnostics)) => diagnostics.extend(module_diagnostics),
+                Err(error) => diagnostics.push(error.into()),
+            }
+        }
+
+        diagnostics
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     #[cfg(not(feature = "module_graph"))]
     fn update_module_graph_internal(
@@ -2114,6 +2329,14 @@ impl WorkspaceServerWithDb<'_> {
         if path.is_manifest() {
             self.update_project_layout(&path, &update_kind, project_key)?;
         }
+        #[cfg(feature = "lang_graphql")]
+        let schema_diagnostics = self.reload_graphql_schema(project_key, &path);
+        #[cfg(feature = "module_graph")]
+        let tsconfig_diagnostics = if self.project_layout.remove_tsconfig_file(&path) {
+            self.resolve_modules_again(project_key)
+        } else {
+            Vec::new()
+        };
         let settings = self
             .project_get_settings_for_path(project_key, &path)
             .ok_or_else(WorkspaceError::no_project)?;
@@ -2126,11 +2349,21 @@ impl WorkspaceServerWithDb<'_> {
                 let _ = self.notification_tx.send(ServiceNotification::IndexUpdated);
             }
         }
-        let result = self.update_module_graph_internal(
+        #[cfg_attr(
+            not(any(feature = "lang_graphql", feature = "module_graph")),
+            allow(unused_mut)
+        )]
+        let mut result = self.update_module_graph_internal(
             &path,
             update_kind,
             settings.module_graph_resolution_kind.is_modules_and_types(),
         )?;
+        #[cfg(feature = "lang_graphql")]
+        result
+            .1
+            .extend(schema_diagnostics.into_iter().map(Error::from));
+        #[cfg(feature = "module_graph")]
+        result.1.extend(tsconfig_diagnostics);
 
         Ok(result)
     }
@@ -2440,6 +2673,7 @@ impl Workspace for LocalWorkspace {
         fn close_project(params: CloseProjectParams) -> Result<(), WorkspaceError>;
         fn open_file(params: OpenFileParams) -> Result<OpenFileResult, WorkspaceError>;
         fn file_exists(params: FileExistsParams) -> Result<bool, WorkspaceError>;
+        fn list_project_files(params: ListProjectFilesParams) -> Result<ListProjectFilesResult, WorkspaceError>;
         fn is_path_ignored(params: PathIsIgnoredParams) -> Result<bool, WorkspaceError>;
         fn get_file_content(params: GetFileContentParams) -> Result<String, WorkspaceError>;
         fn check_file_size(params: CheckFileSizeParams) -> Result<CheckFileSizeResult, WorkspaceError>;
@@ -2462,8 +2696,13 @@ impl Workspace for LocalWorkspace {
         fn pull_diagnostics(params: PullDiagnosticsParams) -> Result<PullDiagnosticsResult, WorkspaceError>;
         fn pull_actions(params: PullActionsParams) -> Result<PullActionsResult, WorkspaceError>;
         fn pull_diagnostics_and_actions(params: PullDiagnosticsAndActionsParams) -> Result<PullDiagnosticsAndActionsResult, WorkspaceError>;
+        fn prepare_rename(params: PrepareRenameParams) -> Result<Option<PrepareRenameResult>, WorkspaceError>;
         fn rename(params: RenameParams) -> Result<RenameResult, WorkspaceError>;
         fn go_to_definition(params: GoToDefinitionParams) -> Result<Option<GoToDefinitionResult>, WorkspaceError>;
+        fn hover(params: HoverParams) -> Result<Option<HoverResult>, WorkspaceError>;
+        fn find_references(params: FindReferencesParams) -> Result<Option<FindReferencesResult>, WorkspaceError>;
+        fn document_symbols(params: DocumentSymbolsParams) -> Result<DocumentSymbolsResult, WorkspaceError>;
+        fn inlay_hints(params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
         fn get_syntax_tree(params: GetSyntaxTreeParams) -> Result<GetSyntaxTreeResult, WorkspaceError>;
         fn get_control_flow_graph(params: GetControlFlowGraphParams) -> Result<String, WorkspaceError>;
         fn get_formatter_ir(params: GetFormatterIRParams) -> Result<String, WorkspaceError>;
@@ -2471,6 +2710,7 @@ impl Workspace for LocalWorkspace {
         fn get_registered_types(params: GetRegisteredTypesParams) -> Result<String, WorkspaceError>;
         fn get_semantic_model(params: GetSemanticModelParams) -> Result<String, WorkspaceError>;
         fn get_module_graph(params: GetModuleGraphParams) -> Result<GetModuleGraphResult, WorkspaceError>;
+        fn get_unused_code(params: GetUnusedCodeParams) -> Result<GetUnusedCodeResult, WorkspaceError>;
     }
 
     fn fs(&self) -> &dyn FsWithResolverProxy {
@@ -2617,6 +2857,20 @@ impl Workspace for WorkspaceServerWithDb<'_> {
             );
         }
 
+        #[cfg(feature = "lang_graphql")]
+        {
+            let environment = &mut settings.languages.graphql.environment;
+            let base_path = workspace_directory.clone().unwrap_or_default();
+            environment.schema_files = environment
+                .schema_paths
+                .iter()
+                .map(|path| base_path.join(path.as_ref()))
+                .collect();
+            let (schema, schema_diagnostics) = self.load_graphql_schema(&environment.schema_files);
+            environment.schema = schema;
+            diagnostics.extend(schema_diagnostics.into_iter().map(SerdeDiagnostic::new));
+        }
+
         if !is_root {
             let nested_workspace_directory = workspace_directory.clone().unwrap_or_default();
             self.project_set_nested_settings(
@@ -2636,46 +2890,44 @@ impl Workspace for WorkspaceServerWithDb<'_> {
                     .to_base_path(workspace_directory.as_deref())
                     .map(|p| normalize_path(&p))
                     .unwrap_or_default();
-                match settings.vcs_settings.client_kind {
-                    None => {}
-                    Some(VcsClientKind::Git) => {
-                        let gitignore = directory.join(".gitignore");
-                        let ignore = directory.join(".ignore");
-                        let mut ignore_file_contents = Vec::new();
-                        let git_info_exclude =
-                            read_git_info_exclude_patterns(self.fs.as_ref(), directory.as_ref());
-
-                        let result = self
-                            .fs
-                            .read_file_from_path(gitignore.as_ref())
-                            .ok()
-                            .or_else(|| self.fs.read_file_from_path(ignore.as_ref()).ok());
-                        if let Some(content) = result {
-                            ignore_file_contents.push(content);
-                        }
+                if let Some(client_kind) = settings.vcs_settings.client_kind {
+                    let mut ignore_file_contents = Vec::new();
+                    let git_info_exclude = if client_kind.uses_git_info_exclude() {
+                        read_git_info_exclude_patterns(self.fs.as_ref(), directory.as_ref())
+                    } else {
+                        None
+                    };
 
-                        let mut ignore_file_patterns = ignore_file_contents
-                            .iter()
-                            .flat_map(|content| content.lines())
-                            .collect::<Vec<_>>();
-                        if let Some(git_info_exclude) = git_info_exclude.as_ref() {
-                            ignore_file_patterns
-                                .extend(git_info_exclude.iter().map(String::as_str));
-                        }
+                    // Git reads `.ignore` only when `.gitignore` doesn't exist
+                    let result = client_kind.ignore_files().iter().find_map(|file_name| {
+                        self.fs
+                            .read_file_from_path(directory.join(file_name).as_ref())
+                            .ok()
+                    });
+                    if let Some(content) = result {
+                        ignore_file_contents.push(content);
+                    }
 
-                        if ignore_file_patterns.is_empty() {
-                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(
-                                VcsDiagnostic::NoIgnoreFileFound(NoIgnoreFileFound {
-                                    path: directory.to_string(),
-                                }),
-                            ));
-                        } else {
-                            settings.vcs_settings.store_root_ignore_patterns(
-                                directory.as_ref(),
-                                ignore_file_patterns.as_slice(),
-                            )?;
-                        };
+                    let mut ignore_file_patterns = ignore_file_contents
+                        .iter()
+                        .flat_map(|content| content.lines())
+                        .collect::<Vec<_>>();
+                    if let Some(git_info_exclude) = git_info_exclude.as_ref() {
+                        ignore_file_patterns.extend(git_info_exclude.iter().map(String::as_str));
                     }
+
+                    if ignore_file_patterns.is_empty() {
+                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(
+                            VcsDiagnostic::NoIgnoreFileFound(NoIgnoreFileFound {
+                                path: directory.to_string(),
+                            }),
+                        ));
+                    } else {
+                        settings.vcs_settings.store_root_ignore_patterns(
+                            directory.as_ref(),
+                            ignore_file_patterns.as_slice(),
+                        )?;
+                    };
                 }
             }
 
@@ -2718,6 +2970,49 @@ impl Workspace for WorkspaceServerWithDb<'_> {
         Ok(())
     }
 
+    fn get_unused_code(
+        &self,
+        params: GetUnusedCodeParams,
+    ) -> Result<GetUnusedCodeResult, WorkspaceError> {
+        let project_path = self
+            .project_get_path(params.project_key)
+            .ok_or_else(WorkspaceError::no_project)?;
+
+        #[cfg(feature = "module_graph")]
+        {
+            let db = self.get_db();
+            let unused_code = js_unused_code(&*db, &self.project_layout, &project_path);
+            Ok(GetUnusedCodeResult {
+                unreachable_files: unused_code
+                    .unreachable_files
+                    .into_iter()
+                    .map(BiomePath::new)
+                    .collect(),
+                unused_exports: unused_code
+                    .unused_exports
+                    .into_iter()
+                    .map(|(path, name)| UnusedExport {
+                        path: BiomePath::new(path),
+                        name,
+                    })
+                    .collect(),
+                unused_dependencies: unused_code
+                    .unused_dependencies
+                    .into_iter()
+                    .map(|(manifest_path, name)| UnusedDependency {
+                        manifest_path: BiomePath::new(manifest_path),
+                        name,
+                    })
+                    .collect(),
+            })
+        }
+        #[cfg(not(feature = "module_graph"))]
+        {
+            let _ = project_path;
+            Ok(GetUnusedCodeResult::default())
+        }
+    }
+
     fn open_file(&self, params: OpenFileParams) -> Result<OpenFileResult, WorkspaceError> {
         let diagnostics = self
             .open_file_internal(OpenFileReason::ClientRequest, params)
@@ -2738,6 +3033,32 @@ impl Workspace for WorkspaceServerWithDb<'_> {
             .contains_key(params.file_path.as_path()))
     }
 
+    fn list_project_files(
+        &self,
+        params: ListProjectFilesParams,
+    ) -> Result<ListProjectFilesResult, WorkspaceError> {
+        let project_path = self
+            .project_get_path(params.project_key)
+            .ok_or_else(WorkspaceError::no_project)?;
+
+        let mut paths: Vec<Utf8PathBuf> = self
+            .documents
+            .pin()
+            .keys()
+            .filter(|path| path.starts_with(&project_path))
+            .cloned()
+            .collect();
+        paths.sort_unstable();
+
+        Ok(ListProjectFilesResult {
+            paths: paths
+                .into_iter()
+                .map(BiomePath::new)
+                .filter(|path| !path.is_dependency())
+                .collect(),
+        })
+    }
+
     fn file_features(
         &self,
         params: SupportsFeatureParams,
@@ -3483,6 +3804,7 @@ impl Workspace for WorkspaceServerWithDb<'_> {
             path: &path,
             workspace_db: workspace_db.clone_untracked_db(),
             project_layout: self.project_layout.clone(),
+            fs: self.fs.clone(),
             language,
             only: &only,
             skip: &skip,
@@ -3512,6 +3834,7 @@ impl Workspace for WorkspaceServerWithDb<'_> {
                 path: &path,
                 workspace_db: workspace_db.clone_untracked_db(),
                 project_layout: self.project_layout.clone(),
+                fs: self.fs.clone(),
                 language: file_source,
                 only: &only,
                 skip: &skip,
@@ -3751,6 +4074,32 @@ impl Workspace for WorkspaceServerWithDb<'_> {
         })
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<Option<PrepareRenameResult>, WorkspaceError> {
+        let settings = self
+            .project_get_settings_for_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let prepare_rename = capabilities
+            .editors
+            .prepare_rename
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let workspace_db = self.get_db();
+        prepare_rename(
+            &params.path,
+            parse.into(),
+            params.symbol_at,
+            workspace_db.into_untracked_db(),
+        )
+    }
+
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError> {
         let settings = self
             .project_get_settings_for_path(params.project_key, &params.path)
@@ -3867,6 +4216,104 @@ impl Workspace for WorkspaceServerWithDb<'_> {
         }))
     }
 
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, WorkspaceError> {
+        let settings = self
+            .project_get_settings_for_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let hover = capabilities
+            .editors
+            .hover
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let workspace_db = self.get_db();
+        Ok(hover(
+            &params.path,
+            parse.into(),
+            params.symbol_at,
+            workspace_db.into_untracked_db(),
+        ))
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<Option<FindReferencesResult>, WorkspaceError> {
+        let settings = self
+            .project_get_settings_for_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let find_references = capabilities
+            .editors
+            .find_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let workspace_db = self.get_db();
+        Ok(find_references(
+            &params.path,
+            parse.into(),
+            params.symbol_at,
+            params.include_declaration,
+            workspace_db.into_untracked_db(),
+        ))
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        let settings = self
+            .project_get_settings_for_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let document_symbols = capabilities
+            .editors
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let workspace_db = self.get_db();
+        Ok(document_symbols(
+            parse.into(),
+            workspace_db.into_untracked_db(),
+        ))
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        let settings = self
+            .project_get_settings_for_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+        let capabilities = self.get_file_capabilities(
+            &params.path,
+            settings.experimental_full_html_support_enabled(),
+        );
+        let inlay_hints = capabilities
+            .editors
+            .inlay_hints
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let workspace_db = self.get_db();
+        Ok(inlay_hints(
+            &params.path,
+            parse.into(),
+            params.range,
+            &params.kinds,
+            workspace_db.into_untracked_db(),
+        ))
+    }
+
     /// Closes a file opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -3960,7 +4407,7 @@ impl Workspace for WorkspaceServerWithDb<'_> {
             self.get_file_source(&path, settings.experimental_full_html_support_enabled());
         let settings = self.settings_handle_with_query(&settings, EditorFeatures::default(), query);
         let provider = self.search_provider.clone();
-        let matches = search(
+        let PatternMatches { matches, rewritten } = search(
             &path,
             &document_file_source,
             parse.into(),
@@ -3970,7 +4417,11 @@ impl Workspace for WorkspaceServerWithDb<'_> {
             workspace_db.into_untracked_db(),
         )?;
 
-        Ok(SearchResults { path, matches })
+        Ok(SearchResults {
+            path,
+            matches,
+            rewritten,
+        })
     }
 
     fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError> {
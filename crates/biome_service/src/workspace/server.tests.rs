@@ -4,10 +4,11 @@ use crate::test_utils::setup_workspace_and_open_project;
 use crate::workspace::UpdateSettingsParams;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_configuration::{
-    FormatterConfiguration, HtmlConfiguration, JsConfiguration,
+    FormatterConfiguration, HtmlConfiguration, JsConfiguration, MarkdownConfiguration,
     analyzer::AnalyzerSelector,
     javascript::{JsFormatterConfiguration, JsParserConfiguration, JsResolverConfiguration},
     json::{JsonConfiguration, JsonFormatterConfiguration},
+    markdown::MarkdownFormatterConfiguration,
 };
 use biome_css_syntax::CssLanguage;
 use biome_formatter::{IndentStyle, LineWidth, QuoteStyle};
@@ -996,6 +997,127 @@ fn pnpm_workspace_update_reapplies_catalogs() {
     assert_eq!(updated_react, Some("18.3.1"));
 }
 
+#[test]
+fn pnpm_workspace_packages_are_discovered() {
+    const ROOT_PACKAGE_JSON: &[u8] = br#"{ "name": "root" }"#;
+    const WORKSPACE: &[u8] = br#"packages:
+  - "packages/*"
+"#;
+    const APP_PACKAGE_JSON: &[u8] = br#"{
+  "name": "@acme/app",
+  "dependencies": {
+    "@acme/shared": "workspace:*"
+  }
+}"#;
+    const SHARED_PACKAGE_JSON: &[u8] = br#"{ "name": "@acme/shared" }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/package.json"),
+        ROOT_PACKAGE_JSON,
+    );
+    fs.insert(Utf8PathBuf::from("/project/pnpm-workspace.yaml"), WORKSPACE);
+    fs.insert(
+        Utf8PathBuf::from("/project/packages/app/package.json"),
+        APP_PACKAGE_JSON,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/packages/shared/package.json"),
+        SHARED_PACKAGE_JSON,
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    let (root_path, _) = workspace
+        .project_layout
+        .find_workspace_root_for_path(Utf8Path::new("/project/packages/app"))
+        .expect("workspace root should be found");
+    assert_eq!(root_path, Utf8PathBuf::from("/project"));
+
+    let shared_path = workspace
+        .project_layout
+        .find_workspace_package(Utf8Path::new("/project/packages/app"), "@acme/shared")
+        .expect("workspace package should be found");
+    assert_eq!(shared_path, Utf8PathBuf::from("/project/packages/shared"));
+}
+
+#[test]
+fn modules_are_resolved_again_when_an_extended_tsconfig_changes() {
+    const BASE_V1: &str = r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#;
+    const BASE_V2: &str = r#"{ "compilerOptions": { "paths": { "@/*": ["./lib/*"] } } }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/package.json"), "{}");
+    fs.insert(
+        Utf8PathBuf::from("/project/tsconfig.json"),
+        r#"{ "extends": "./tsconfig.base.json" }"#,
+    );
+    fs.insert(Utf8PathBuf::from("/project/tsconfig.base.json"), BASE_V1);
+    fs.insert(
+        Utf8PathBuf::from("/project/index.ts"),
+        "import { foo } from \"@/foo\";\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/src/foo.ts"),
+        "export const foo = 1;\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/lib/foo.ts"),
+        "export const foo = 2;\n",
+    );
+
+    let fs_for_updates = MemoryFileSystem::from_files(fs.files.0.clone());
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    let resolved_foo = || {
+        let module_graph = workspace.get_module_graph(GetModuleGraphParams {}).unwrap();
+        module_graph.data["/project/index.ts"]
+            .as_js_module_info()
+            .expect("index.ts should be a JS module")
+            .static_import_paths["@/foo"]
+            .clone()
+    };
+    assert_eq!(resolved_foo(), "/project/src/foo.ts");
+
+    fs_for_updates.insert(Utf8PathBuf::from("/project/tsconfig.base.json"), BASE_V2);
+    workspace
+        .open_file_internal(
+            OpenFileReason::Index(IndexTrigger::Update),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("/project/tsconfig.base.json"),
+                content: FileContent::FromServer,
+                document_file_source: None,
+                persist_node_cache: false,
+                inline_config: None,
+                editor_features: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(resolved_foo(), "/project/lib/foo.ts");
+}
+
 #[test]
 fn store_embedded_nodes_with_current_ranges() {
     const FILE_CONTENT: &str = r#"<html>
@@ -2252,22 +2374,26 @@ const Baz = graphql`
 }
 
 #[test]
-fn issue_9131() {
-    const FILE_PATH: &str = "/project/file.js";
-    const FILE_CONTENT: &str = r#"
-const bulkUpsertTransactionsMutation = graphql(`
-  mutation test(
-    $input: Test!
-  ) {
-    test(input: $input) {
-      apple
-    }
-  }
-`);
-
-console.log(`test`) // plain template as call argument
-
-const highlight = foo`some tagged template` // unknown tagged template
+fn format_markdown_with_embedded_code_blocks() {
+    const FILE_PATH: &str = "/project/README.md";
+    const FILE_CONTENT: &str = r#"# Example
+
+```js
+const   x = {a:1,
+b:2}
+```
+
+```css
+a{color:red}
+```
+
+```json
+{"a":1,"b":[1,2]}
+```
+
+```python
+x  =  1
+```
 "#;
 
     let fs = MemoryFileSystem::default();
@@ -2280,8 +2406,11 @@ const highlight = foo`some tagged template` // unknown tagged template
             project_key,
             workspace_directory: None,
             configuration: Configuration {
-                javascript: Some(JsConfiguration {
-                    experimental_embedded_snippets_enabled: Some(true.into()),
+                markdown: Some(MarkdownConfiguration {
+                    formatter: Some(MarkdownFormatterConfiguration {
+                        enabled: Some(true.into()),
+                        ..Default::default()
+                    }),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -2311,53 +2440,53 @@ const highlight = foo`some tagged template` // unknown tagged template
         })
         .unwrap();
 
-    insta::assert_snapshot!(result.as_code());
+    insta::assert_snapshot!(result.as_code(), @r#"
+    # Example
+
+    ```js
+    const x = { a: 1, b: 2 };
+    ```
+
+    ```css
+    a {
+    	color: red;
+    }
+    ```
+
+    ```json
+    { "a": 1, "b": [1, 2] }
+    ```
+
+    ```python
+    x  =  1
+    ```
+    "#);
 }
 
-/// Parenthesized expressions before a graphql tagged template used to crash
-/// the formatter because the syntax rewriter removes parentheses, shifting
-/// text ranges. The embedding service stores original ranges but the formatter
-/// used transformed ranges, causing a mismatch that left orphaned
-/// StartEmbedded tags in the document.
-///
-/// See: https://github.com/biomejs/biome/issues/9484
 #[test]
-fn issue_9484_parens_before_graphql_call() {
-    const FILE_PATH: &str = "/project/file.js";
-    const FILE_CONTENT: &str = r#"import {graphql} from "@generated/gql.js";
+fn pull_diagnostics_maps_markdown_code_blocks_to_the_document() {
+    const FILE_PATH: &str = "/project/README.md";
+    const FILE_CONTENT: &str = r#"# Example
 
-const a = {}
-console.log((a))
+```js
+debugger;
+```
 
-const fetchFileUploadUrlQuery =
-graphql(`
-  query Q {
-    field
-  }
-`);
+```python
+debugger;
+```
+
+- Nested blocks aren't parsed yet
+
+  ```js
+  debugger;
+  ```
 "#;
 
     let fs = MemoryFileSystem::default();
     fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
-
     let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
 
-    workspace
-        .update_settings(UpdateSettingsParams {
-            project_key,
-            workspace_directory: None,
-            configuration: Configuration {
-                javascript: Some(JsConfiguration {
-                    experimental_embedded_snippets_enabled: Some(true.into()),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            extended_configurations: vec![],
-            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
-        })
-        .unwrap();
-
     workspace
         .open_file(OpenFileParams {
             project_key,
@@ -2370,51 +2499,43 @@ graphql(`
         })
         .unwrap();
 
+    let no_debugger = AnalyzerSelector::from_str("lint/suspicious/noDebugger").unwrap();
     let result = workspace
-        .format_file(FormatFileParams {
+        .pull_diagnostics(PullDiagnosticsParams {
             project_key,
-            path: Utf8PathBuf::from(FILE_PATH).into(),
+            path: BiomePath::new(FILE_PATH),
+            categories: RuleCategoriesBuilder::default().with_lint().build(),
+            only: vec![no_debugger],
+            skip: vec![],
+            enabled_rules: vec![no_debugger],
+            include_code_fix: false,
             inline_config: None,
+            max_diagnostics: None,
+            diagnostic_level: Severity::Hint,
+            enforce_assist: false,
         })
         .unwrap();
 
-    insta::assert_snapshot!(result.as_code());
+    assert_eq!(result.parse_errors, 0);
+    assert_eq!(
+        result.diagnostics.len(),
+        1,
+        "Expected one diagnostic, got: {:#?}",
+        result.diagnostics
+    );
+    let span = result.diagnostics[0].location().span.unwrap();
+    assert_eq!(span, TextRange::new(TextSize::from(17), TextSize::from(26)));
+    assert_eq!(&FILE_CONTENT[span], "debugger;");
 }
 
-/// After `format_embedded()` replaces StartEmbedded tags with embedded content
-/// containing hard line breaks, `propagate_expand()` must be called again so
-/// that enclosing groups learn they need to expand. Without it, elements like
-/// `IndentIfGroupBreaks` around the call arguments would not indent because
-/// the group mode would still be flat.
 #[test]
-fn issue_9484_propagate_expand_after_embed() {
-    const FILE_PATH: &str = "/project/file.js";
-    // Short call where graphql fits on one line without embedding,
-    // but embedded formatting inserts hard lines that must expand the group.
-    const FILE_CONTENT: &str = r#"const x = foo(graphql`query { a }`, b)
-"#;
+fn process_file_applies_fixes_inside_markdown_code_blocks() {
+    const FILE_PATH: &str = "/project/README.md";
+    const FILE_CONTENT: &str = "# Example\n\n```js\ndebugger;\nconsole.log(1);\n```\n";
 
     let fs = MemoryFileSystem::default();
     fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
-
-    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
-
-    workspace
-        .update_settings(UpdateSettingsParams {
-            project_key,
-            workspace_directory: None,
-            configuration: Configuration {
-                javascript: Some(JsConfiguration {
-                    experimental_embedded_snippets_enabled: Some(true.into()),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            extended_configurations: vec![],
-            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
-        })
-        .unwrap();
-
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
     workspace
         .open_file(OpenFileParams {
             project_key,
@@ -2427,61 +2548,324 @@ fn issue_9484_propagate_expand_after_embed() {
         })
         .unwrap();
 
+    let no_debugger = AnalyzerSelector::from_str("lint/suspicious/noDebugger").unwrap();
     let result = workspace
-        .format_file(FormatFileParams {
+        .process_file(ProcessFileParams {
             project_key,
-            path: Utf8PathBuf::from(FILE_PATH).into(),
-            inline_config: None,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            categories: RuleCategoriesBuilder::default()
+                .with_syntax()
+                .with_lint()
+                .build(),
+            only: vec![no_debugger],
+            skip: vec![],
+            enabled_rules: vec![no_debugger],
+            fix_file_mode: Some(FixFileMode::SafeAndUnsafeFixes),
+            suppression_reason: None,
+            format: false,
+            write: true,
+            include_code_fix: true,
+            max_diagnostics: None,
+            diagnostic_level: Severity::Hint,
+            enforce_assist: false,
+            skip_parse_errors: false,
         })
         .unwrap();
 
-    insta::assert_snapshot!(result.as_code());
+    let output = result.output.unwrap();
+    assert_eq!(result.applied_fixes, 1);
+    assert!(!output.contains("debugger"), "{output}");
+    assert!(output.starts_with("# Example\n\n```js\n"), "{output}");
+    assert!(output.ends_with("console.log(1);\n```\n"), "{output}");
 }
 
-/// Parenthesized JSX return combined with a graphql tagged template literal
-/// triggered the same range mismatch as issue_9484_parens_before_graphql_call.
-///
-/// See: https://github.com/biomejs/biome/issues/9484
 #[test]
-fn issue_9484_parens_jsx_with_graphql_tag() {
-    const FILE_PATH: &str = "/project/file.tsx";
-    const FILE_CONTENT: &str = r#"import { graphql, useLazyLoadQuery } from 'react-relay';
-
-export const Page = () => {
-  return (<div></div>);
-};
+fn pull_diagnostics_resolves_markdown_links_to_other_files() {
+    const FILE_PATH: &str = "/project/README.md";
+    const FILE_CONTENT: &str = "# Readme
 
-const Table = () => {
-  const query = useLazyLoadQuery(graphql`
-      query Q {
-        field
-      }
-    `, {});
-  return <div></div>;
-};
-"#;
+- [Install](./docs/guide.md#install)
+- [Configure](./docs/guide.md#configure)
+- [Changelog](./CHANGELOG.md)
+";
 
     let fs = MemoryFileSystem::default();
     fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
-
-    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+    fs.insert(
+        Utf8PathBuf::from("/project/docs/guide.md"),
+        "# Guide\n\n## Install\n",
+    );
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
 
     workspace
-        .update_settings(UpdateSettingsParams {
+        .open_file(OpenFileParams {
             project_key,
-            workspace_directory: None,
-            configuration: Configuration {
-                javascript: Some(JsConfiguration {
-                    experimental_embedded_snippets_enabled: Some(true.into()),
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            extended_configurations: vec![],
-            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
-        })
-        .unwrap();
-
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let rule = AnalyzerSelector::from_str("lint/nursery/noInvalidLinkFragments").unwrap();
+    let result = workspace
+        .pull_diagnostics(PullDiagnosticsParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            categories: RuleCategoriesBuilder::default().with_lint().build(),
+            only: vec![rule],
+            skip: vec![],
+            enabled_rules: vec![rule],
+            include_code_fix: false,
+            inline_config: None,
+            max_diagnostics: None,
+            diagnostic_level: Severity::Hint,
+            enforce_assist: false,
+        })
+        .unwrap();
+
+    let targets: Vec<_> = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| &FILE_CONTENT[diagnostic.location().span.unwrap()])
+        .collect();
+    assert_eq!(targets, ["./docs/guide.md#configure", "./CHANGELOG.md"]);
+}
+
+#[test]
+fn issue_9131() {
+    const FILE_PATH: &str = "/project/file.js";
+    const FILE_CONTENT: &str = r#"
+const bulkUpsertTransactionsMutation = graphql(`
+  mutation test(
+    $input: Test!
+  ) {
+    test(input: $input) {
+      apple
+    }
+  }
+`);
+
+console.log(`test`) // plain template as call argument
+
+const highlight = foo`some tagged template` // unknown tagged template
+"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: None,
+            configuration: Configuration {
+                javascript: Some(JsConfiguration {
+                    experimental_embedded_snippets_enabled: Some(true.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let result = workspace
+        .format_file(FormatFileParams {
+            project_key,
+            path: Utf8PathBuf::from(FILE_PATH).into(),
+            inline_config: None,
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(result.as_code());
+}
+
+/// Parenthesized expressions before a graphql tagged template used to crash
+/// the formatter because the syntax rewriter removes parentheses, shifting
+/// text ranges. The embedding service stores original ranges but the formatter
+/// used transformed ranges, causing a mismatch that left orphaned
+/// StartEmbedded tags in the document.
+///
+/// See: https://github.com/biomejs/biome/issues/9484
+#[test]
+fn issue_9484_parens_before_graphql_call() {
+    const FILE_PATH: &str = "/project/file.js";
+    const FILE_CONTENT: &str = r#"import {graphql} from "@generated/gql.js";
+
+const a = {}
+console.log((a))
+
+const fetchFileUploadUrlQuery =
+graphql(`
+  query Q {
+    field
+  }
+`);
+"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: None,
+            configuration: Configuration {
+                javascript: Some(JsConfiguration {
+                    experimental_embedded_snippets_enabled: Some(true.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let result = workspace
+        .format_file(FormatFileParams {
+            project_key,
+            path: Utf8PathBuf::from(FILE_PATH).into(),
+            inline_config: None,
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(result.as_code());
+}
+
+/// After `format_embedded()` replaces StartEmbedded tags with embedded content
+/// containing hard line breaks, `propagate_expand()` must be called again so
+/// that enclosing groups learn they need to expand. Without it, elements like
+/// `IndentIfGroupBreaks` around the call arguments would not indent because
+/// the group mode would still be flat.
+#[test]
+fn issue_9484_propagate_expand_after_embed() {
+    const FILE_PATH: &str = "/project/file.js";
+    // Short call where graphql fits on one line without embedding,
+    // but embedded formatting inserts hard lines that must expand the group.
+    const FILE_CONTENT: &str = r#"const x = foo(graphql`query { a }`, b)
+"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: None,
+            configuration: Configuration {
+                javascript: Some(JsConfiguration {
+                    experimental_embedded_snippets_enabled: Some(true.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let result = workspace
+        .format_file(FormatFileParams {
+            project_key,
+            path: Utf8PathBuf::from(FILE_PATH).into(),
+            inline_config: None,
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(result.as_code());
+}
+
+/// Parenthesized JSX return combined with a graphql tagged template literal
+/// triggered the same range mismatch as issue_9484_parens_before_graphql_call.
+///
+/// See: https://github.com/biomejs/biome/issues/9484
+#[test]
+fn issue_9484_parens_jsx_with_graphql_tag() {
+    const FILE_PATH: &str = "/project/file.tsx";
+    const FILE_CONTENT: &str = r#"import { graphql, useLazyLoadQuery } from 'react-relay';
+
+export const Page = () => {
+  return (<div></div>);
+};
+
+const Table = () => {
+  const query = useLazyLoadQuery(graphql`
+      query Q {
+        field
+      }
+    `, {});
+  return <div></div>;
+};
+"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: None,
+            configuration: Configuration {
+                javascript: Some(JsConfiguration {
+                    experimental_embedded_snippets_enabled: Some(true.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+
     workspace
         .open_file(OpenFileParams {
             project_key,
@@ -3053,11 +3437,179 @@ fn no_unused_classes_passes_with_transitive_css_import() {
             })
             .unwrap();
 
-        assert!(
-            result.diagnostics.is_empty(),
-            "Expected no diagnostics for {path} — all classes are transitively referenced"
-        );
-    }
+        assert!(
+            result.diagnostics.is_empty(),
+            "Expected no diagnostics for {path} — all classes are transitively referenced"
+        );
+    }
+}
+
+#[test]
+fn rename_updates_dependent_modules() {
+    const UTILS_CONTENT: &str = "export function greet() { return 'hello'; }\n";
+    const INDEX_CONTENT: &str = "export { greet } from './utils.js';\n";
+    const MAIN_CONTENT: &str = "import { greet } from './index.js';\nimport * as utils from './utils.js';\ngreet();\nutils.greet();\n";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/utils.js"),
+        UTILS_CONTENT.as_bytes(),
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/index.js"),
+        INDEX_CONTENT.as_bytes(),
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/main.js"),
+        MAIN_CONTENT.as_bytes(),
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    // Cursor on `greet` in `export function greet()`
+    let prepared = workspace
+        .prepare_rename(PrepareRenameParams {
+            project_key,
+            path: BiomePath::new("/project/utils.js"),
+            symbol_at: TextSize::from(16),
+        })
+        .unwrap()
+        .expect("the function can be renamed");
+    assert_eq!(prepared.placeholder, "greet");
+    assert_eq!(
+        prepared.range,
+        TextRange::new(TextSize::from(16), TextSize::from(21))
+    );
+
+    let result = workspace
+        .rename(RenameParams {
+            project_key,
+            path: BiomePath::new("/project/utils.js"),
+            symbol_at: TextSize::from(16),
+            new_name: "welcome".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        result.indels.new_string(UTILS_CONTENT),
+        "export function welcome() { return 'hello'; }\n"
+    );
+    let dependents: Vec<_> = result
+        .dependents
+        .iter()
+        .map(|dependent| dependent.path.as_path().as_str())
+        .collect();
+    assert_eq!(dependents, ["/project/index.js", "/project/main.js"]);
+    assert_eq!(
+        result.dependents[0].indels.new_string(INDEX_CONTENT),
+        "export { welcome } from './utils.js';\n"
+    );
+    assert_eq!(
+        result.dependents[1].indels.new_string(MAIN_CONTENT),
+        "import { welcome } from './index.js';\nimport * as utils from './utils.js';\nwelcome();\nutils.welcome();\n"
+    );
+}
+
+#[test]
+fn find_references_includes_dependent_modules() {
+    const UTILS_CONTENT: &str = "export function greet() { return 'hello'; }\n";
+    const INDEX_CONTENT: &str = "export { greet } from './utils.js';\n";
+    const MAIN_CONTENT: &str = "import { greet } from './index.js';\nimport * as utils from './utils.js';\ngreet();\nutils.greet();\n";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/utils.js"),
+        UTILS_CONTENT.as_bytes(),
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/index.js"),
+        INDEX_CONTENT.as_bytes(),
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/main.js"),
+        MAIN_CONTENT.as_bytes(),
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    // Cursor on `greet` in `export function greet()`
+    let result = workspace
+        .find_references(FindReferencesParams {
+            project_key,
+            path: BiomePath::new("/project/utils.js"),
+            symbol_at: TextSize::from(16),
+            include_declaration: true,
+        })
+        .unwrap()
+        .expect("the function has references");
+
+    let references: Vec<_> = result
+        .references
+        .iter()
+        .map(|(path, range)| (path.as_path().as_str(), u32::from(range.start())))
+        .collect();
+    assert_eq!(
+        references,
+        [
+            ("/project/utils.js", 16),
+            ("/project/index.js", 9),
+            ("/project/main.js", 9),
+            ("/project/main.js", 73),
+            ("/project/main.js", 88),
+        ]
+    );
+}
+
+#[test]
+fn prepare_rename_rejects_named_import() {
+    const MAIN_CONTENT: &str = "import { greet } from './utils.js';\ngreet();\n";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/main.js"),
+        MAIN_CONTENT.as_bytes(),
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/");
+
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    // Cursor on `greet` in `greet();`
+    let result = workspace.prepare_rename(PrepareRenameParams {
+        project_key,
+        path: BiomePath::new("/project/main.js"),
+        symbol_at: TextSize::from(37),
+    });
+
+    assert!(matches!(result, Err(WorkspaceError::RenameError(_))));
 }
 
 #[test]
@@ -4136,6 +4688,96 @@ const x = 1;
     );
 }
 
+#[test]
+#[cfg(feature = "plugins")]
+fn suppressions_use_the_name_of_plugin_rules_declared_in_the_manifest() {
+    use biome_diagnostics::Diagnostic;
+    use biome_plugin_loader::{PluginConfiguration, Plugins};
+
+    const FILE_PATH: &str = "/project/file.js";
+    // The rule is named after its file, `hello`, unless the manifest renames it.
+    const FILE_CONTENT: &str = r#"// biome-ignore lint/plugin/noHello: renamed by the manifest
+hello();
+// biome-ignore lint/plugin/hello: the name of the file isn't the name of the rule
+hello();
+hello();
+"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/my-plugin/biome-manifest.jsonc"),
+        r#"{
+    "version": 1,
+    "rules": [{ "name": "noHello", "path": "rules/hello.grit" }]
+}"#,
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/my-plugin/rules/hello.grit"),
+        r#"`hello()` as $call where {
+    register_diagnostic(span=$call, message="Don't say hello.")
+}"#,
+    );
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: Some(BiomePath::new("/project")),
+            configuration: Configuration {
+                plugins: Some(Plugins(vec![PluginConfiguration::Path(
+                    "./my-plugin".to_string(),
+                )])),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let result = workspace
+        .pull_diagnostics(PullDiagnosticsParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            categories: RuleCategoriesBuilder::default().with_lint().build(),
+            only: vec![],
+            skip: vec![],
+            enabled_rules: vec![],
+            include_code_fix: false,
+            inline_config: None,
+            max_diagnostics: None,
+            diagnostic_level: Severity::Hint,
+            enforce_assist: false,
+        })
+        .unwrap();
+
+    let plugin_diagnostics = result
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            diagnostic.category().map(|category| category.name()) == Some("plugin")
+        })
+        .count();
+    assert_eq!(
+        plugin_diagnostics, 2,
+        "Expected the diagnostics of the last two calls, got: {:?}",
+        result.diagnostics
+    );
+}
+
 #[test]
 #[cfg(feature = "js_plugin")]
 fn typescript_plugin_reports_diagnostics_through_the_workspace() {
@@ -4222,3 +4864,254 @@ export const noTopLevelVar = defineRule({
         "Expected a diagnostic from the TypeScript plugin, got: {diagnostics}"
     );
 }
+
+#[test]
+#[cfg(feature = "js_plugin")]
+fn js_plugin_resolves_types_through_the_module_graph() {
+    use biome_plugin_loader::{PluginConfiguration, Plugins};
+
+    const PLUGIN_PATH: &str = "/project/plugin.js";
+    const PLUGIN_SOURCE: &str = r#"import { ast, defineRule, getType, registerDiagnostic } from "@biomejs/plugin-api";
+
+export const reportTypes = defineRule({
+    query: ast("JS_CALL_EXPRESSION"),
+    run(call) {
+        registerDiagnostic(call, "information", `call of type ${getType(call)}`);
+    },
+});"#;
+    const FILE_PATH: &str = "/project/file.ts";
+    const FILE_CONTENT: &str = "function answer(): number {\n    return 42;\n}\nanswer();\n";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from(PLUGIN_PATH), PLUGIN_SOURCE);
+    fs.insert(Utf8PathBuf::from(FILE_PATH), FILE_CONTENT);
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    let configuration = Configuration {
+        plugins: Some(Plugins(vec![PluginConfiguration::Path(
+            "plugin.js".to_string(),
+        )])),
+        ..Default::default()
+    };
+    let scan_kind = ProjectScanComputer::new(&configuration).compute();
+    assert_eq!(scan_kind, ScanKind::TypeAware);
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: Some(BiomePath::new("/project")),
+            configuration,
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::from(&scan_kind),
+        })
+        .unwrap();
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind,
+            verbose: false,
+        })
+        .unwrap();
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let result = workspace
+        .pull_diagnostics(PullDiagnosticsParams {
+            project_key,
+            path: BiomePath::new(FILE_PATH),
+            categories: RuleCategories::default(),
+            only: vec![],
+            skip: vec![],
+            enabled_rules: vec![],
+            include_code_fix: false,
+            inline_config: None,
+            max_diagnostics: None,
+            diagnostic_level: Severity::Hint,
+            enforce_assist: false,
+        })
+        .unwrap();
+
+    let diagnostics = format!("{:?}", result.diagnostics);
+    assert!(
+        diagnostics.contains("call of type number"),
+        "Expected the type inferred for the call, got: {diagnostics}"
+    );
+}
+
+#[test]
+fn get_unused_code_reports_the_whole_project() {
+    const MANIFEST_CONTENT: &[u8] =
+        br#"{ "name": "app", "main": "index.js", "dependencies": { "lodash": "1.0.0" } }"#;
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/package.json"), MANIFEST_CONTENT);
+    fs.insert(
+        Utf8PathBuf::from("/project/index.js"),
+        "import { used } from \"./lib.js\";\nused();\nexport const api = 1;\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/lib.js"),
+        "export function used() {}\nexport function unused() {}\n",
+    );
+    // The import of an unreachable module doesn't make the dependency used
+    fs.insert(
+        Utf8PathBuf::from("/project/orphan.js"),
+        "import { sum } from \"lodash\";\nexport const orphan = sum([1]);\n",
+    );
+
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: Some(BiomePath::new("/project")),
+            configuration: Configuration::default(),
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::Modules,
+        })
+        .unwrap();
+    workspace
+        .scan_project(ScanProjectParams {
+            project_key,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+            verbose: false,
+        })
+        .unwrap();
+
+    let result = workspace
+        .get_unused_code(GetUnusedCodeParams { project_key })
+        .unwrap();
+
+    let unreachable_files: Vec<_> = result
+        .unreachable_files
+        .iter()
+        .map(|path| path.as_str())
+        .collect();
+    assert_eq!(unreachable_files, ["/project/orphan.js"]);
+
+    let unused_exports: Vec<_> = result
+        .unused_exports
+        .iter()
+        .map(|export| (export.path.as_str(), export.name.as_str()))
+        .collect();
+    assert_eq!(unused_exports, [("/project/lib.js", "unused")]);
+
+    let unused_dependencies: Vec<_> = result
+        .unused_dependencies
+        .iter()
+        .map(|dependency| (dependency.manifest_path.as_str(), dependency.name.as_str()))
+        .collect();
+    assert_eq!(unused_dependencies, [("/project/package.json", "lodash")]);
+}
+
+#[test]
+fn graphql_schema_is_reloaded_when_its_file_changes() {
+    use biome_configuration::graphql::GraphqlConfiguration;
+
+    const SCHEMA_V1: &str = "type Query { hello: String }";
+    const SCHEMA_V2: &str = "type Query { hello: String, goodbye: String }";
+    const QUERY_PATH: &str = "/project/query.graphql";
+
+    let fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/schema.gql"), SCHEMA_V1);
+    fs.insert(Utf8PathBuf::from(QUERY_PATH), "query { goodbye }");
+
+    let fs_for_updates = MemoryFileSystem::from_files(fs.files.0.clone());
+    let (workspace, project_key) = setup_workspace_and_open_project(fs, "/project");
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            workspace_directory: Some(BiomePath::new("/project")),
+            configuration: Configuration {
+                graphql: Some(GraphqlConfiguration {
+                    schema: Some(vec!["schema.gql".into()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            extended_configurations: vec![],
+            module_graph_resolution_kind: ModuleGraphResolutionKind::None,
+        })
+        .unwrap();
+    let is_schema_ignored = WorkspaceScannerBridge::is_ignored(
+        &workspace,
+        project_key,
+        &ScanKind::KnownFiles,
+        Utf8Path::new("/project/schema.gql"),
+        IndexRequestKind::Explicit(IndexTrigger::InitialScan),
+        None,
+    )
+    .unwrap();
+    assert!(
+        !is_schema_ignored,
+        "the schema should be indexed so the watcher picks up its changes"
+    );
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new(QUERY_PATH),
+            content: FileContent::FromServer,
+            document_file_source: None,
+            persist_node_cache: false,
+            inline_config: None,
+            editor_features: None,
+        })
+        .unwrap();
+
+    let no_unknown_field =
+        AnalyzerSelector::from_str("lint/nursery/noUnknownGraphqlField").unwrap();
+    let pull_diagnostics = || {
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                project_key,
+                path: BiomePath::new(QUERY_PATH),
+                categories: RuleCategoriesBuilder::default().with_lint().build(),
+                only: vec![no_unknown_field],
+                skip: vec![],
+                enabled_rules: vec![no_unknown_field],
+                include_code_fix: false,
+                inline_config: None,
+                max_diagnostics: None,
+                diagnostic_level: Severity::Hint,
+                enforce_assist: false,
+            })
+            .unwrap()
+            .diagnostics
+            .len()
+    };
+    assert_eq!(pull_diagnostics(), 1);
+
+    fs_for_updates.insert(Utf8PathBuf::from("/project/schema.gql"), SCHEMA_V2);
+    workspace
+        .open_file_internal(
+            OpenFileReason::Index(IndexTrigger::Update),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("/project/schema.gql"),
+                content: FileContent::FromServer,
+                document_file_source: None,
+                persist_node_cache: false,
+                inline_config: None,
+                editor_features: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(pull_diagnostics(), 0);
+}
@@ -797,6 +797,7 @@ pub fn methods() -> [WorkspaceMethod; 31] {
         workspace_method!(get_registered_types),
         workspace_method!(get_semantic_model),
         workspace_method!(get_module_graph),
+        workspace_method!(get_unused_code),
         workspace_method!(pull_diagnostics),
         workspace_method!(process_file),
         workspace_method!(pull_actions),
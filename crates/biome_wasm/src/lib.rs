@@ -7,10 +7,10 @@ use biome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, DropPatternParams, FileExistsParams, FixFileParams,
     FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
     GetFileContentParams, GetFormatterIRParams, GetModuleGraphParams, GetRegisteredTypesParams,
-    GetSemanticModelParams, GetSyntaxTreeParams, GetTypeInfoParams, OpenProjectParams,
-    ParsePatternParams, PathIsIgnoredParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, ScanProjectParams, SearchPatternParams, UpdateModuleGraphParams,
-    UpdateSettingsParams,
+    GetSemanticModelParams, GetSyntaxTreeParams, GetTypeInfoParams, GetUnusedCodeParams,
+    OpenProjectParams, ParsePatternParams, PathIsIgnoredParams, PullActionsParams,
+    PullDiagnosticsParams, RenameParams, ScanProjectParams, SearchPatternParams,
+    UpdateModuleGraphParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 use camino::{Utf8Path, Utf8PathBuf};
@@ -250,6 +250,20 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = getUnusedCode)]
+    pub fn get_unused_code(
+        &self,
+        params: IGetUnusedCodeParams,
+    ) -> Result<IGetUnusedCodeResult, Error> {
+        let params: GetUnusedCodeParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+
+        let result = self.inner.get_unused_code(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetUnusedCodeResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = pullDiagnostics)]
     pub fn pull_diagnostics(
         &self,
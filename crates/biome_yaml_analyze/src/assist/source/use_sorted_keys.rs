@@ -0,0 +1,351 @@
+use crate::YamlRuleAction;
+use crate::utils::{AnyYamlMapping, block_map_entry_key, flow_map_entry_key};
+use biome_analyze::utils::{is_separated_list_sorted_by, sorted_separated_list_by};
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleAction, RuleDiagnostic, RuleSource, context::RuleContext,
+    declare_source_rule,
+};
+use biome_console::markup;
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TextRange, TriviaPieceKind};
+use biome_rule_options::use_sorted_keys::{SortOrder, UseSortedKeysOptions};
+use biome_string_case::comparable_token::ComparableToken;
+use biome_yaml_factory::make;
+use biome_yaml_syntax::{
+    AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlFlowMapEntry, AnyYamlFlowNode,
+    AnyYamlJsonContent, T, YamlBlockMapEntryList, YamlBlockMapping, YamlSyntaxToken,
+};
+use std::cmp::Ordering;
+
+declare_source_rule! {
+    /// Sort the keys of a YAML mapping in natural order.
+    ///
+    /// [Natural order](https://en.wikipedia.org/wiki/Natural_sort_order) means
+    /// that uppercase letters come before lowercase letters (e.g. `A` < `a` <
+    /// `B` < `b`) and numbers are compared in a human way (e.g. `9` < `10`).
+    ///
+    /// Keys that aren't plain or quoted scalars, such as aliases or collections,
+    /// split the mapping into groups of keys that are sorted separately.
+    /// Comments above a key are moved together with it.
+    ///
+    /// ## Examples
+    ///
+    /// ```yaml,expect_diff
+    /// vase: fancy
+    /// nested:
+    ///   omega: bar
+    ///   alpha: foo
+    /// ```
+    ///
+    /// ## Options
+    /// This actions accepts following options
+    ///
+    /// ### `sortOrder`
+    /// This options supports `natural` and `lexicographic` values. Where as `natural` is the default.
+    ///
+    /// Following will apply the lexicographic sort order.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sortOrder": "lexicographic"
+    ///     }
+    /// }
+    /// ```
+    /// ```yaml,use_options,expect_diff
+    /// val13: 1
+    /// val1: 1
+    /// val2: 1
+    /// val21: 1
+    /// val11: 1
+    /// ```
+    ///
+    /// ### `groupByNesting`
+    /// When enabled, groups keys by their value's nesting depth before sorting alphabetically.
+    /// Simple values (scalars, single-line flow sequences, and single-line flow mappings) are sorted first,
+    /// followed by nested values (block collections and multi-line flow collections).
+    ///
+    /// > Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "groupByNesting": true
+    ///     }
+    /// }
+    /// ```
+    /// ```yaml,use_options,expect_diagnostic
+    /// name: Sample
+    /// details:
+    ///   description: nested
+    /// id: 123
+    /// ```
+    ///
+    pub UseSortedKeys {
+        version: "next",
+        name: "useSortedKeys",
+        language: "yaml",
+        fix_kind: FixKind::Safe,
+        sources: &[RuleSource::EslintYml("sort-keys").same()],
+    }
+}
+
+impl Rule for UseSortedKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = UseSortedKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let options = ctx.options();
+        let comparator = comparator(options);
+        let group_by_nesting = options.group_by_nesting.unwrap_or(false);
+        let compare =
+            |(d1, n1): &SortKey, (d2, n2): &SortKey| d1.cmp(d2).then_with(|| comparator(n1, n2));
+
+        let is_sorted = match ctx.query() {
+            AnyYamlMapping::YamlBlockMapping(mapping) => {
+                if has_properties_on_first_key(mapping) {
+                    // The anchor and the tag of the mapping are attached to its first key:
+                    // moving that key would move them as well.
+                    return None;
+                }
+                let mut previous_key = None;
+                let mut is_sorted = true;
+                for entry in mapping.entries() {
+                    let key = block_entry_sort_key(&entry, group_by_nesting);
+                    if let (Some(previous_key), Some(key)) = (&previous_key, &key)
+                        && compare(previous_key, key).is_gt()
+                    {
+                        is_sorted = false;
+                        break;
+                    }
+                    previous_key = key;
+                }
+                is_sorted
+            }
+            AnyYamlMapping::YamlFlowMapping(mapping) => is_separated_list_sorted_by(
+                &mapping.entries(),
+                |entry| flow_entry_sort_key(entry, group_by_nesting),
+                compare,
+            )
+            .ok()?,
+        };
+        (!is_sorted).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = if ctx.options().group_by_nesting.unwrap_or(false) {
+            markup! {
+                "The keys are not sorted by nesting level and name."
+            }
+        } else {
+            markup! {
+                "The keys are not sorted."
+            }
+        };
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            Self::text_range(ctx, state),
+            message,
+        ))
+    }
+
+    fn text_range(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<TextRange> {
+        match ctx.query() {
+            // The range of a block mapping also covers the line break that closes it
+            AnyYamlMapping::YamlBlockMapping(mapping) => Some(mapping.entries().range()),
+            AnyYamlMapping::YamlFlowMapping(mapping) => Some(mapping.range()),
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<YamlRuleAction> {
+        let options = ctx.options();
+        let comparator = comparator(options);
+        let group_by_nesting = options.group_by_nesting.unwrap_or(false);
+        let compare =
+            |(d1, n1): &SortKey, (d2, n2): &SortKey| d1.cmp(d2).then_with(|| comparator(n1, n2));
+
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            AnyYamlMapping::YamlBlockMapping(mapping) => {
+                let list = mapping.entries();
+                let sorted = sorted_block_map_entries(
+                    &list,
+                    |entry| block_entry_sort_key(entry, group_by_nesting),
+                    compare,
+                )?;
+                mutation.replace_node_discard_trivia(list, sorted);
+            }
+            AnyYamlMapping::YamlFlowMapping(mapping) => {
+                let list = mapping.entries();
+                let make_separator: fn() -> YamlSyntaxToken = if mapping
+                    .syntax()
+                    .text_trimmed()
+                    .contains_char('\n')
+                {
+                    || make::token(T![,])
+                } else {
+                    || make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+                };
+                let sorted = sorted_separated_list_by(
+                    &list,
+                    |entry| flow_entry_sort_key(entry, group_by_nesting),
+                    make_separator,
+                    compare,
+                )
+                .ok()?;
+                mutation.replace_node_discard_trivia(list, sorted);
+            }
+        }
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! {
+                "Sort the keys."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// The nesting depth of the value of an entry and the name of its key
+type SortKey = (Ordering, ComparableToken);
+
+fn comparator(
+    options: &UseSortedKeysOptions,
+) -> fn(&ComparableToken, &ComparableToken) -> Ordering {
+    match options.sort_order.unwrap_or_default() {
+        SortOrder::Natural => ComparableToken::ascii_nat_cmp,
+        SortOrder::Lexicographic => ComparableToken::lexicographic_cmp,
+    }
+}
+
+/// Returns the nesting depth and the name of the key of `entry`.
+/// The depth is always [Ordering::Equal] when `group_by_nesting` is disabled.
+fn block_entry_sort_key(entry: &AnyYamlBlockMapEntry, group_by_nesting: bool) -> Option<SortKey> {
+    let (name, _) = block_map_entry_key(entry)?;
+    let depth = if group_by_nesting {
+        let value = match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => entry.value(),
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => entry.value(),
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => None,
+        };
+        value.map_or(Ordering::Equal, |value| block_nesting_depth(&value))
+    } else {
+        Ordering::Equal
+    };
+    Some((depth, ComparableToken::new(name)))
+}
+
+/// Returns the nesting depth and the name of the key of `entry`.
+/// The depth is always [Ordering::Equal] when `group_by_nesting` is disabled.
+fn flow_entry_sort_key(entry: &AnyYamlFlowMapEntry, group_by_nesting: bool) -> Option<SortKey> {
+    let (name, _) = flow_map_entry_key(entry)?;
+    let depth = if group_by_nesting {
+        let value = match entry {
+            AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => entry.value(),
+            AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry.value(),
+        };
+        value.map_or(Ordering::Equal, |value| flow_nesting_depth(&value))
+    } else {
+        Ordering::Equal
+    };
+    Some((depth, ComparableToken::new(name)))
+}
+
+/// Block collections are considered nested (depth 1), and so are flow collections in them.
+/// Scalars and aliases are considered simple (depth 0).
+fn block_nesting_depth(value: &AnyYamlBlockNode) -> Ordering {
+    match value {
+        AnyYamlBlockNode::YamlFlowInBlockNode(node) => node
+            .flow()
+            .map_or(Ordering::Equal, |flow| flow_nesting_depth(&flow)),
+        _ if value.is_nested_block_collection() => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+/// Multi-line flow sequences and multi-line flow mappings are considered nested (depth 1).
+/// Scalars, aliases, and single-line flow collections are considered simple (depth 0).
+fn flow_nesting_depth(value: &AnyYamlFlowNode) -> Ordering {
+    let AnyYamlFlowNode::YamlFlowJsonNode(node) = value else {
+        return Ordering::Equal;
+    };
+    match node.content() {
+        Ok(
+            content @ (AnyYamlJsonContent::YamlFlowSequence(_)
+            | AnyYamlJsonContent::YamlFlowMapping(_)),
+        ) if content.syntax().text_trimmed().contains_char('\n') => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+fn has_properties_on_first_key(mapping: &YamlBlockMapping) -> bool {
+    mapping
+        .entries()
+        .first()
+        .and_then(|entry| entry.as_yaml_block_map_implicit_entry()?.key())
+        .is_some_and(|key| key.enclosing_mapping_property_count() > 0)
+}
+
+/// Returns a copy of `list` sorted by `get_key`.
+///
+/// Like [sorted_separated_list_by], an entry without key acts as a chunk
+/// delimiter and chunks are sorted separately.
+///
+/// The entries of a block mapping are separated by the line break in the
+/// leading trivia of their first token, except the first entry which has none.
+/// The line breaks are moved accordingly when the first entry changes.
+fn sorted_block_map_entries<Key>(
+    list: &YamlBlockMapEntryList,
+    get_key: impl Fn(&AnyYamlBlockMapEntry) -> Option<Key>,
+    comparator: impl Fn(&Key, &Key) -> Ordering,
+) -> Option<YamlBlockMapEntryList> {
+    let mut entries: Vec<_> = list.iter().map(|entry| (get_key(&entry), entry)).collect();
+    let first = entries.first()?.1.clone();
+    // The line break and the indentation that precede the second entry
+    let separator = entries.get(1).and_then(|(_, entry)| {
+        let token = entry.syntax().first_token()?;
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        let line_break = pieces.iter().rposition(|piece| piece.is_newline())?;
+        Some(pieces[line_break..].to_vec())
+    })?;
+
+    for chunk in entries.split_mut(|(key, _)| key.is_none()) {
+        chunk.sort_by(|(key1, _), (key2, _)| match (key1, key2) {
+            (Some(k1), Some(k2)) => comparator(k1, k2),
+            _ => Ordering::Equal,
+        });
+    }
+
+    let mut sorted = Vec::with_capacity(entries.len());
+    for (index, (_, entry)) in entries.into_iter().enumerate() {
+        let entry = if index == 0 && entry != first {
+            // Remove the line break, but keep the comments above the entry
+            let leading_trivia = entry.syntax().first_leading_trivia()?;
+            let pieces: Vec<_> = leading_trivia
+                .pieces()
+                .skip_while(|piece| {
+                    matches!(
+                        piece.kind(),
+                        TriviaPieceKind::Newline | TriviaPieceKind::Whitespace
+                    )
+                })
+                .collect();
+            AnyYamlBlockMapEntry::unwrap_cast(
+                entry.into_syntax().with_leading_trivia_pieces(pieces)?,
+            )
+        } else if index != 0 && entry == first {
+            AnyYamlBlockMapEntry::unwrap_cast(
+                entry
+                    .into_syntax()
+                    .prepend_trivia_pieces(separator.iter().cloned())?,
+            )
+        } else {
+            entry
+        };
+        sorted.push(entry);
+    }
+    Some(make::yaml_block_map_entry_list(sorted))
+}
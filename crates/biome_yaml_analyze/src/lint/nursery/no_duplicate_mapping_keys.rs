@@ -0,0 +1,111 @@
+use crate::utils::AnyYamlMapping;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{TextRange, TokenText};
+use biome_rule_options::no_duplicate_mapping_keys::NoDuplicateMappingKeysOptions;
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow two keys with the same name inside a mapping.
+    ///
+    /// The YAML specification requires the keys of a mapping to be unique.
+    /// Depending on the tool, a duplicated key is either rejected or silently
+    /// overrides the previous one, which makes the earlier value dead configuration.
+    ///
+    /// Plain and quoted keys with the same content are considered the same key.
+    /// Merge keys (`<<`) are ignored because they are commonly repeated to merge several anchors.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// name: build
+    /// runs-on: ubuntu-latest
+    /// name: test
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// env: { NODE_ENV: production, "NODE_ENV": test }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// name: build
+    /// runs-on: ubuntu-latest
+    /// ```
+    ///
+    pub NoDuplicateMappingKeys {
+        version: "next",
+        name: "noDuplicateMappingKeys",
+        language: "yaml",
+        recommended: true,
+        severity: Severity::Error,
+        sources: &[RuleSource::Yamllint("key-duplicates").same()],
+    }
+}
+
+pub struct DuplicatedKey {
+    name: TokenText,
+    first: TextRange,
+    duplicates: Vec<TextRange>,
+}
+
+impl Rule for NoDuplicateMappingKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = DuplicatedKey;
+    type Signals = Box<[Self::State]>;
+    type Options = NoDuplicateMappingKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut first_keys = FxHashMap::<TokenText, TextRange>::default();
+        let mut duplicated_keys = Vec::<DuplicatedKey>::new();
+        for (name, range) in ctx.query().scalar_keys() {
+            if name.text() == "<<" {
+                continue;
+            }
+            if let Some(first) = first_keys.get(&name) {
+                if let Some(duplicated_key) = duplicated_keys
+                    .iter_mut()
+                    .find(|duplicated_key| duplicated_key.first == *first)
+                {
+                    duplicated_key.duplicates.push(range);
+                } else {
+                    duplicated_keys.push(DuplicatedKey {
+                        name,
+                        first: *first,
+                        duplicates: vec![range],
+                    });
+                }
+            } else {
+                first_keys.insert(name, range);
+            }
+        }
+        duplicated_keys.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.first,
+            markup! {
+                "The key "<Emphasis>{state.name.text()}</Emphasis>" was already declared."
+            },
+        );
+        for range in &state.duplicates {
+            diagnostic = diagnostic.detail(
+                range,
+                markup! {
+                    "This is where a duplicated key was declared again."
+                },
+            );
+        }
+        Some(diagnostic.note(markup! {
+            "Mapping keys must be unique. Depending on the tool reading this file, the duplicated key is either rejected or overrides the previous definition."
+        }))
+    }
+}
@@ -0,0 +1,187 @@
+use crate::YamlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleAction, RuleDiagnostic, RuleSource, context::RuleContext,
+    declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TextRange, TextSize, TriviaPieceKind};
+use biome_rule_options::no_truthy_scalars::NoTruthyScalarsOptions;
+use biome_yaml_factory::make;
+use biome_yaml_syntax::{
+    AnyYamlBlockMapEntry, AnyYamlFlowMapEntry, AnyYamlFlowNode, AnyYamlJsonContent,
+    AnyYamlProperty, YamlFlowYamlNode, YamlSyntaxKind, YamlSyntaxNode,
+};
+
+declare_lint_rule! {
+    /// Disallow the plain scalars `yes`, `no`, `on` and `off`.
+    ///
+    /// YAML 1.1 loaders, such as PyYAML or the Go `yaml.v2` package, read these
+    /// scalars as the booleans `true` and `false`, while YAML 1.2 loaders read them as strings.
+    /// The same file can then be loaded differently depending on the tool,
+    /// which is a common source of bugs (the [Norway problem](https://www.bram.us/2022/01/11/yaml-the-norway-problem/)).
+    ///
+    /// Use `true` or `false` when a boolean is intended, or quote the value when a string is intended.
+    ///
+    /// Only values are checked: keys such as the `on` key of GitHub Actions workflows are allowed.
+    /// Values with an explicit tag, such as `!!str yes`, are also allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// enabled: yes
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// countries: [FR, NO, SE]
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// enabled: true
+    /// ```
+    ///
+    /// ```yaml
+    /// countries: [FR, "NO", SE]
+    /// ```
+    ///
+    /// ```yaml
+    /// on:
+    ///   push:
+    ///     branches: [main]
+    /// ```
+    ///
+    pub NoTruthyScalars {
+        version: "next",
+        name: "noTruthyScalars",
+        language: "yaml",
+        recommended: true,
+        severity: Severity::Warning,
+        sources: &[RuleSource::Yamllint("truthy").same()],
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// The plain scalars that YAML 1.1 reads as booleans and YAML 1.2 reads as strings
+const TRUTHY_SCALARS: [&str; 12] = [
+    "NO", "No", "OFF", "ON", "Off", "On", "YES", "Yes", "no", "off", "on", "yes",
+];
+
+impl Rule for NoTruthyScalars {
+    type Query = Ast<YamlFlowYamlNode>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = NoTruthyScalarsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let scalar = node.content()?;
+        let token = scalar.value_token().ok()?;
+        // The blanks before a `,` or a closing bracket are part of the token
+        let text = token.text_trimmed().trim_end();
+        if TRUTHY_SCALARS.binary_search(&text).is_err() {
+            return None;
+        }
+        let has_tag = node
+            .properties()
+            .iter()
+            .any(|property| matches!(property, AnyYamlProperty::YamlTagProperty(_)));
+        if has_tag || is_mapping_key(node.syntax()) {
+            return None;
+        }
+        Some(TextRange::at(
+            token.text_trimmed_range().start(),
+            TextSize::of(text),
+        ))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state,
+                markup! {
+                    "This value is read as a boolean by YAML 1.1 loaders and as a string by YAML 1.2 loaders."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>"true"</Emphasis>" or "<Emphasis>"false"</Emphasis>" if a boolean is intended, or quote the value if a string is intended."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<YamlRuleAction> {
+        let node = ctx.query();
+        let token = node.content()?.value_token().ok()?;
+        let text = token.text_trimmed();
+        let value = text.trim_end();
+        let trailing_pieces: Vec<_> = token.trailing_trivia().pieces().collect();
+        // The blanks trimmed from the token text become trivia of the quoted scalar
+        let blanks =
+            (value.len() < text.len()).then(|| (TriviaPieceKind::Whitespace, &text[value.len()..]));
+        let literal = make::double_quoted_literal(value)
+            .with_leading_trivia_pieces(token.leading_trivia().pieces())
+            .with_trailing_trivia(
+                blanks
+                    .into_iter()
+                    .chain(
+                        trailing_pieces
+                            .iter()
+                            .map(|piece| (piece.kind(), piece.text())),
+                    )
+                    .collect::<Vec<_>>(),
+            );
+        let quoted = make::yaml_flow_json_node(
+            node.properties(),
+            AnyYamlJsonContent::YamlDoubleQuotedScalar(make::yaml_double_quoted_scalar(literal)),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(
+            AnyYamlFlowNode::YamlFlowYamlNode(node.clone()),
+            AnyYamlFlowNode::YamlFlowJsonNode(quoted),
+        );
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Quote the value to keep it as a string." },
+            mutation,
+        ))
+    }
+}
+
+/// Whether `node` is the key of a mapping entry, including the single pairs
+/// of flow sequences such as `[on: push]`
+fn is_mapping_key(node: &YamlSyntaxNode) -> bool {
+    // The explicit keys of block mappings are wrapped in a flow-in-block node
+    let node = match node.parent() {
+        Some(parent) if parent.kind() == YamlSyntaxKind::YAML_FLOW_IN_BLOCK_NODE => parent,
+        _ => node.clone(),
+    };
+    let Some(entry) = node.parent() else {
+        return false;
+    };
+    if let Some(entry) = AnyYamlBlockMapEntry::cast_ref(&entry) {
+        match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+                entry.key().is_some_and(|key| key.syntax() == &node)
+            }
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                entry.key().is_some_and(|key| key.syntax() == &node)
+            }
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => false,
+        }
+    } else if let Some(entry) = AnyYamlFlowMapEntry::cast_ref(&entry) {
+        let key = match entry {
+            AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => entry.key(),
+            AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry.key(),
+        };
+        key.is_some_and(|key| key.syntax() == &node)
+    } else {
+        false
+    }
+}
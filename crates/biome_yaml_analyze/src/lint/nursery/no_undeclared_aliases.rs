@@ -0,0 +1,111 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, Direction, TextRange, TokenText};
+use biome_rule_options::no_undeclared_aliases::NoUndeclaredAliasesOptions;
+use biome_yaml_syntax::{YamlDocument, YamlSyntaxKind};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow aliases that refer to an anchor that wasn't declared before.
+    ///
+    /// An alias (`*name`) reuses the node marked by an anchor (`&name`).
+    /// The anchor must be declared earlier in the same document, otherwise
+    /// the document can't be loaded.
+    ///
+    /// Anchors can't be shared across documents of the same stream:
+    /// each document separated by `---` has its own set of anchors.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// job:
+    ///   <<: *defaults
+    ///   script: test
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// test: *base
+    /// base: &base
+    ///   image: node
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// base: &base
+    ///   image: node
+    /// test: *base
+    /// ```
+    ///
+    pub NoUndeclaredAliases {
+        version: "next",
+        name: "noUndeclaredAliases",
+        language: "yaml",
+        recommended: true,
+        severity: Severity::Error,
+        sources: &[RuleSource::Yamllint("anchors").inspired()],
+    }
+}
+
+pub struct UndeclaredAlias {
+    name: TokenText,
+    range: TextRange,
+}
+
+impl Rule for NoUndeclaredAliases {
+    type Query = Ast<YamlDocument>;
+    type State = UndeclaredAlias;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUndeclaredAliasesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let document = ctx.query();
+        let mut anchors = FxHashSet::<TokenText>::default();
+        let mut undeclared_aliases = Vec::new();
+        // Tokens are visited in source order, so an anchor is only known
+        // to the aliases that follow it.
+        for token in document.syntax().descendants_tokens(Direction::Next) {
+            match token.kind() {
+                YamlSyntaxKind::ANCHOR_PROPERTY_LITERAL => {
+                    anchors.insert(name_of(&token.token_text_trimmed()));
+                }
+                YamlSyntaxKind::ALIAS_LITERAL => {
+                    let name = name_of(&token.token_text_trimmed());
+                    if !anchors.contains(&name) {
+                        undeclared_aliases.push(UndeclaredAlias {
+                            name,
+                            range: token.text_trimmed_range(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        undeclared_aliases.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The alias "<Emphasis>"*"{state.name.text()}</Emphasis>" refers to an anchor that isn't declared before it."
+                },
+            )
+            .note(markup! {
+                "Declare the anchor "<Emphasis>"&"{state.name.text()}</Emphasis>" before this alias, in the same document."
+            }),
+        )
+    }
+}
+
+/// Strips the `&` or `*` indicator from an anchor or an alias
+fn name_of(text: &TokenText) -> TokenText {
+    text.clone().slice(TextRange::new(1.into(), text.len()))
+}
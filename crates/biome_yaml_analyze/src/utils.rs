@@ -0,0 +1,73 @@
+use biome_rowan::{
+    AstNode, AstNodeList, AstSeparatedList, TextRange, TextSize, TokenText, declare_node_union,
+};
+use biome_yaml_syntax::{
+    AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlFlowMapEntry, AnyYamlMappingImplicitKey,
+    YamlBlockMapping, YamlFlowMapping, YamlSyntaxNode,
+};
+
+declare_node_union! {
+    pub AnyYamlMapping = YamlBlockMapping | YamlFlowMapping
+}
+
+impl AnyYamlMapping {
+    /// The keys of this mapping that are plain or quoted scalars, together
+    /// with the range of each scalar.
+    ///
+    /// Keys that are aliases or collections are skipped.
+    pub fn scalar_keys(&self) -> Vec<(TokenText, TextRange)> {
+        match self {
+            Self::YamlBlockMapping(mapping) => mapping
+                .entries()
+                .iter()
+                .filter_map(|entry| block_map_entry_key(&entry))
+                .collect(),
+            Self::YamlFlowMapping(mapping) => mapping
+                .entries()
+                .iter()
+                .flatten()
+                .filter_map(|entry| flow_map_entry_key(&entry))
+                .collect(),
+        }
+    }
+}
+
+/// The text and the range of the key of a block mapping entry, when that key
+/// is a plain or quoted scalar
+pub(crate) fn block_map_entry_key(entry: &AnyYamlBlockMapEntry) -> Option<(TokenText, TextRange)> {
+    match entry {
+        AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => implicit_key(&entry.key()?),
+        AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+            let AnyYamlBlockNode::YamlFlowInBlockNode(key) = entry.key()? else {
+                return None;
+            };
+            let key = key.flow().ok()?;
+            let text = key.inner_string_text()?;
+            Some((text, scalar_range(key.syntax())?))
+        }
+        AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => None,
+    }
+}
+
+/// The text and the range of the key of a flow mapping entry, when that key
+/// is a plain or quoted scalar
+pub(crate) fn flow_map_entry_key(entry: &AnyYamlFlowMapEntry) -> Option<(TokenText, TextRange)> {
+    match entry {
+        AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => implicit_key(&entry.key()?),
+        AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => implicit_key(&entry.key()?),
+    }
+}
+
+fn implicit_key(key: &AnyYamlMappingImplicitKey) -> Option<(TokenText, TextRange)> {
+    let text = key.inner_string_text()?;
+    Some((text, scalar_range(key.syntax())?))
+}
+
+/// The range of the scalar of a key. The key itself may also contain anchor
+/// and tag properties, which aren't part of its name.
+fn scalar_range(key: &YamlSyntaxNode) -> Option<TextRange> {
+    let token = key.last_token()?;
+    // The blanks before a `:` are part of a plain scalar's token
+    let len = TextSize::of(token.text_trimmed().trim_end());
+    Some(TextRange::at(token.text_trimmed_range().start(), len))
+}
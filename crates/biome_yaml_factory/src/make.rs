@@ -1 +1,25 @@
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxToken};
+
 pub use crate::generated::node_factory::*;
+
+/// Create a new token with the specified syntax kind and no attached trivia
+pub fn token(kind: YamlSyntaxKind) -> YamlSyntaxToken {
+    if let Some(text) = kind.to_string() {
+        YamlSyntaxToken::new_detached(kind, text, [], [])
+    } else {
+        panic!("token kind {kind:?} cannot be transformed to text")
+    }
+}
+
+/// Create a new double-quoted scalar token with no attached trivia.
+///
+/// The text is wrapped in quotes as is, it must not contain characters that
+/// need to be escaped.
+pub fn double_quoted_literal(text: &str) -> YamlSyntaxToken {
+    YamlSyntaxToken::new_detached(
+        YamlSyntaxKind::DOUBLE_QUOTED_LITERAL,
+        &format!("\"{text}\""),
+        [],
+        [],
+    )
+}
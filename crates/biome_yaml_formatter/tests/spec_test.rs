@@ -16,6 +16,7 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
                 enabled: Some(true.into()),
                 ..Default::default()
             }),
+            ..Default::default()
         }),
         ..Default::default()
     };
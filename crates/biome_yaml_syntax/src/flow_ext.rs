@@ -1,4 +1,8 @@
-use crate::{AnyYamlFlowNode, AnyYamlJsonContent, AnyYamlMappingImplicitKey};
+use crate::{
+    AnyYamlFlowNode, AnyYamlJsonContent, AnyYamlMappingImplicitKey, YamlFlowJsonNode,
+    YamlFlowYamlNode,
+};
+use biome_rowan::{TextRange, TextSize, TokenText};
 
 impl AnyYamlFlowNode {
     /// Whether this node is a flow collection (`[...]` or `{...}`)
@@ -12,6 +16,16 @@ impl AnyYamlFlowNode {
             )
         )
     }
+
+    /// The text of this node when it's a plain or quoted scalar, see
+    /// [YamlFlowJsonNode::inner_string_text]
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        match self {
+            Self::YamlFlowJsonNode(node) => node.inner_string_text(),
+            Self::YamlFlowYamlNode(node) => node.inner_string_text(),
+            Self::YamlAliasNode(_) | Self::YamlBogusFlowNode(_) => None,
+        }
+    }
 }
 
 impl AnyYamlMappingImplicitKey {
@@ -28,4 +42,50 @@ impl AnyYamlMappingImplicitKey {
             )
         )
     }
+
+    /// The text of this key when it's a plain or quoted scalar, see
+    /// [YamlFlowJsonNode::inner_string_text]
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        match self {
+            Self::YamlFlowJsonNode(node) => node.inner_string_text(),
+            Self::YamlFlowYamlNode(node) => node.inner_string_text(),
+            Self::YamlAliasNode(_) => None,
+        }
+    }
+}
+
+impl YamlFlowYamlNode {
+    /// The text of the plain scalar of this node, if any.
+    ///
+    /// The blanks between a plain scalar and a following `:`, `,` or closing
+    /// bracket are part of the scalar's token, they aren't part of the text.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        let token = self.content()?.value_token().ok()?;
+        let text = token.token_text_trimmed();
+        let len = TextSize::of(text.trim_end());
+        Some(text.slice(TextRange::up_to(len)))
+    }
+}
+
+impl YamlFlowJsonNode {
+    /// The text of the quoted scalar of this node without its quotes.
+    ///
+    /// Escape sequences are kept as they are written, so `"a"` and `a` have
+    /// the same text while `"\x61"` and `a` don't.
+    pub fn inner_string_text(&self) -> Option<TokenText> {
+        let token = match self.content().ok()? {
+            AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar) => scalar.value_token().ok()?,
+            AnyYamlJsonContent::YamlSingleQuotedScalar(scalar) => scalar.value_token().ok()?,
+            AnyYamlJsonContent::YamlFlowMapping(_) | AnyYamlJsonContent::YamlFlowSequence(_) => {
+                return None;
+            }
+        };
+        let text = token.token_text_trimmed();
+        // An unterminated scalar doesn't end with its opening quote
+        if text.len() < TextSize::from(2) || !text.ends_with(&text[..1]) {
+            return None;
+        }
+        let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
+        Some(text.slice(range))
+    }
 }
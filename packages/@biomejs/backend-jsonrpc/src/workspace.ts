@@ -251,6 +251,12 @@ export interface GraphqlConfiguration {
 	 * GraphQL linter options.
 	 */
 	linter?: GraphqlLinterConfiguration;
+	/**
+	* Paths to the schema of the GraphQL service, relative to the configuration file.
+
+Files with the `.json` extension contain the result of an introspection query, other files contain SDL. Operations are validated against the schema by the schema-aware lint rules. 
+	 */
+	schema?: string[];
 }
 /**
  * Options applied to GritQL files.
@@ -403,8 +409,11 @@ diagnostic.
 	 */
 	root?: string;
 	/**
-	* When `true`, Biome ignores files listed in `.gitignore`, `.ignore`, and Git's local
-exclude file. 
+	* When `true`, Biome ignores files listed in the ignore files of the version control client.
+
+With Git, these are `.gitignore`, `.ignore`, and Git's local exclude file. With Jujutsu,
+these are `.gitignore` and, in colocated repositories, Git's local exclude file. With
+Mercurial, this is the `.hgignore` file at the root of the repository. 
 	 */
 	useIgnoreFile?: Bool;
 }
@@ -1073,13 +1082,14 @@ Can be either a plain path string or an object with path and options:
 {
   "plugins": [
     "simple-plugin.grit",
-    { "path": "scoped-plugin.grit", "includes": ["src/**\/*.ts"] }
+    { "path": "scoped-plugin.grit", "includes": ["src/**\/*.ts"] },
+    { "path": "./my-plugin", "rules": { "noFoo": "warn", "useBar": { "level": "error", "options": { "max": 2 } } } }
   ]
 }
 ``` 
 	 */
 export type PluginConfiguration = string | PluginWithOptions;
-export type VcsClientKind = "git";
+export type VcsClientKind = "git" | "jujutsu" | "mercurial";
 /**
  * A preset configuration for enabling a set of rules.
  */
@@ -1347,6 +1357,29 @@ these patterns. Use negated globs (e.g., `!**\/*.test.ts`) for exclusions.
 	 * The path to the plugin.
 	 */
 	path: string;
+	/**
+	 * The configuration of the rules of the plugin, by rule name.
+	 */
+	rules?: Record<string, PluginRuleConfiguration>;
+}
+/**
+	* Configuration of a plugin rule.
+
+Can be either a severity, or an object with the severity and the options of
+the rule, like the configuration of the built-in rules. 
+	 */
+export type PluginRuleConfiguration = PluginRuleSeverity | PluginRuleWithOptions;
+export type PluginRuleSeverity = "off" | "on" | "info" | "warn" | "error";
+export interface PluginRuleWithOptions {
+	/**
+	 * The severity of the diagnostics of the rule.
+	 */
+	level: PluginRuleSeverity;
+	/**
+	* The options of the rule. They're validated against the options the
+plugin manifest declares for the rule, if any. 
+	 */
+	options?: any;
 }
 export type NoDuplicateClassesConfiguration =
 	| RuleAssistPlainConfiguration
@@ -2363,6 +2396,11 @@ See https://biomejs.dev/linter/rules/no-astro-set-html-directive
 	 */
 	noAstroSetHtmlDirective?: NoAstroSetHtmlDirectiveConfiguration;
 	/**
+	* Disallow URLs that aren't written as links.
+See https://biomejs.dev/linter/rules/no-bare-urls 
+	 */
+	noBareUrls?: NoBareUrlsConfiguration;
+	/**
 	* Require stringification to avoid values that only use the default object representation.
 See https://biomejs.dev/linter/rules/no-base-to-string 
 	 */
@@ -2393,6 +2431,16 @@ See https://biomejs.dev/linter/rules/no-duplicate-field-definition-names
 	 */
 	noDuplicateFieldDefinitionNames?: NoDuplicateFieldDefinitionNamesConfiguration;
 	/**
+	* Disallow multiple headings with the same content.
+See https://biomejs.dev/linter/rules/no-duplicate-headings 
+	 */
+	noDuplicateHeadings?: NoDuplicateHeadingsConfiguration;
+	/**
+	* Disallow two keys with the same name inside a mapping.
+See https://biomejs.dev/linter/rules/no-duplicate-mapping-keys 
+	 */
+	noDuplicateMappingKeys?: NoDuplicateMappingKeysConfiguration;
+	/**
 	* Disallow duplicate selectors.
 See https://biomejs.dev/linter/rules/no-duplicate-selectors 
 	 */
@@ -2438,6 +2486,16 @@ See https://biomejs.dev/linter/rules/no-inline-styles
 	 */
 	noInlineStyles?: NoInlineStylesConfiguration;
 	/**
+	* Disallow argument values that don't match the type of the argument.
+See https://biomejs.dev/linter/rules/no-invalid-graphql-argument-value 
+	 */
+	noInvalidGraphqlArgumentValue?: NoInvalidGraphqlArgumentValueConfiguration;
+	/**
+	* Disallow links to fragments that don't exist in the document.
+See https://biomejs.dev/linter/rules/no-invalid-link-fragments 
+	 */
+	noInvalidLinkFragments?: NoInvalidLinkFragmentsConfiguration;
+	/**
 	* Checks that the initial-value of an @property rule follows the value format declared by its syntax.
 See https://biomejs.dev/linter/rules/no-invalid-property-init-value 
 	 */
@@ -2578,6 +2636,21 @@ See https://biomejs.dev/linter/rules/no-top-level-literals
 	 */
 	noTopLevelLiterals?: NoTopLevelLiteralsConfiguration;
 	/**
+	* Disallow trailing whitespace at the end of lines.
+See https://biomejs.dev/linter/rules/no-trailing-spaces 
+	 */
+	noTrailingSpaces?: NoTrailingSpacesConfiguration;
+	/**
+	* Disallow the plain scalars `yes`, `no`, `on` and `off`.
+See https://biomejs.dev/linter/rules/no-truthy-scalars 
+	 */
+	noTruthyScalars?: NoTruthyScalarsConfiguration;
+	/**
+	* Disallow aliases that refer to an anchor that wasn't declared before.
+See https://biomejs.dev/linter/rules/no-undeclared-aliases 
+	 */
+	noUndeclaredAliases?: NoUndeclaredAliasesConfiguration;
+	/**
 	* Reports CSS class names in HTML class attributes that are not defined in any \<style> block or linked stylesheet available to the file.
 See https://biomejs.dev/linter/rules/no-undeclared-classes 
 	 */
@@ -2588,11 +2661,41 @@ See https://biomejs.dev/linter/rules/no-undeclared-custom-properties
 	 */
 	noUndeclaredCustomProperties?: NoUndeclaredCustomPropertiesConfiguration;
 	/**
+	* Disallow spreads of fragments that aren't defined in the document.
+See https://biomejs.dev/linter/rules/no-undefined-graphql-fragment 
+	 */
+	noUndefinedGraphqlFragment?: NoUndefinedGraphqlFragmentConfiguration;
+	/**
+	* Disallow variables that aren't defined by the operation using them.
+See https://biomejs.dev/linter/rules/no-undefined-graphql-variable 
+	 */
+	noUndefinedGraphqlVariable?: NoUndefinedGraphqlVariableConfiguration;
+	/**
+	* Disallow arguments that aren't defined on the field or directive they're passed to.
+See https://biomejs.dev/linter/rules/no-unknown-graphql-argument 
+	 */
+	noUnknownGraphqlArgument?: NoUnknownGraphqlArgumentConfiguration;
+	/**
+	* Disallow fields that aren't defined on the type they're selected on.
+See https://biomejs.dev/linter/rules/no-unknown-graphql-field 
+	 */
+	noUnknownGraphqlField?: NoUnknownGraphqlFieldConfiguration;
+	/**
+	* Disallow types that aren't defined in the GraphQL schema.
+See https://biomejs.dev/linter/rules/no-unknown-graphql-type 
+	 */
+	noUnknownGraphqlType?: NoUnknownGraphqlTypeConfiguration;
+	/**
 	* Disallow unnecessary template expressions.
 See https://biomejs.dev/linter/rules/no-unnecessary-template-expression 
 	 */
 	noUnnecessaryTemplateExpression?: NoUnnecessaryTemplateExpressionConfiguration;
 	/**
+	* Disallow files that can't be reached from any entry point of the package.
+See https://biomejs.dev/linter/rules/no-unreachable-files 
+	 */
+	noUnreachableFiles?: NoUnreachableFilesConfiguration;
+	/**
 	* Disallow + operations with operands that are known to be unsafe.
 See https://biomejs.dev/linter/rules/no-unsafe-plus-operands 
 	 */
@@ -2613,6 +2716,16 @@ See https://biomejs.dev/linter/rules/no-unused-classes
 	 */
 	noUnusedClasses?: NoUnusedClassesConfiguration;
 	/**
+	* Disallow dependencies that aren't imported by any module of the package.
+See https://biomejs.dev/linter/rules/no-unused-dependencies 
+	 */
+	noUnusedDependencies?: NoUnusedDependenciesConfiguration;
+	/**
+	* Disallow exports that aren't imported by any other module of the project.
+See https://biomejs.dev/linter/rules/no-unused-exports 
+	 */
+	noUnusedExports?: NoUnusedExportsConfiguration;
+	/**
 	* Disallow type conversions that do not change the type of an expression.
 See https://biomejs.dev/linter/rules/no-useless-type-conversion 
 	 */
@@ -2661,6 +2774,11 @@ See https://biomejs.dev/linter/rules/use-baseline
 	 */
 	useBaseline?: UseBaselineConfiguration;
 	/**
+	* Enforce the same marker for all the unordered lists of a document.
+See https://biomejs.dev/linter/rules/use-consistent-list-marker 
+	 */
+	useConsistentListMarker?: UseConsistentListMarkerConfiguration;
+	/**
 	* Enforce consistent use of it or test for test functions.
 See https://biomejs.dev/linter/rules/use-consistent-test-it 
 	 */
@@ -2706,11 +2824,26 @@ See https://biomejs.dev/linter/rules/use-explicit-type
 	 */
 	useExplicitType?: UseExplicitTypeConfiguration;
 	/**
+	* Require a language for fenced code blocks.
+See https://biomejs.dev/linter/rules/use-fenced-code-language 
+	 */
+	useFencedCodeLanguage?: UseFencedCodeLanguageConfiguration;
+	/**
+	* Require the arguments that a field or a directive can't be used without.
+See https://biomejs.dev/linter/rules/use-graphql-required-arguments 
+	 */
+	useGraphqlRequiredArguments?: UseGraphqlRequiredArgumentsConfiguration;
+	/**
 	* Enforce the 'sandbox' attribute for 'iframe' elements.
 See https://biomejs.dev/linter/rules/use-iframe-sandbox 
 	 */
 	useIframeSandbox?: UseIframeSandboxConfiguration;
 	/**
+	* Require an alternative text for images.
+See https://biomejs.dev/linter/rules/use-image-alt-text 
+	 */
+	useImageAltText?: UseImageAltTextConfiguration;
+	/**
 	* Enforce that all imports appear at the top of the module.
 See https://biomejs.dev/linter/rules/use-imports-first 
 	 */
@@ -4709,6 +4842,9 @@ export type UseYieldConfiguration =
 export type NoAstroSetHtmlDirectiveConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoAstroSetHtmlDirectiveOptions;
+export type NoBareUrlsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoBareUrlsOptions;
 export type NoBaseToStringConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoBaseToStringOptions;
@@ -4727,6 +4863,12 @@ export type NoDrizzleUpdateWithoutWhereConfiguration =
 export type NoDuplicateFieldDefinitionNamesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoDuplicateFieldDefinitionNamesOptions;
+export type NoDuplicateHeadingsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoDuplicateHeadingsOptions;
+export type NoDuplicateMappingKeysConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoDuplicateMappingKeysOptions;
 export type NoDuplicateSelectorsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoDuplicateSelectorsOptions;
@@ -4754,6 +4896,12 @@ export type NoImpliedEvalConfiguration =
 export type NoInlineStylesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoInlineStylesOptions;
+export type NoInvalidGraphqlArgumentValueConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoInvalidGraphqlArgumentValueOptions;
+export type NoInvalidLinkFragmentsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoInvalidLinkFragmentsOptions;
 export type NoInvalidPropertyInitValueConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoInvalidPropertyInitValueOptions;
@@ -4838,15 +4986,42 @@ export type NoTailwindArbitraryValueConfiguration =
 export type NoTopLevelLiteralsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoTopLevelLiteralsOptions;
+export type NoTrailingSpacesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoTrailingSpacesOptions;
+export type NoTruthyScalarsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoTruthyScalarsOptions;
+export type NoUndeclaredAliasesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUndeclaredAliasesOptions;
 export type NoUndeclaredClassesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUndeclaredClassesOptions;
 export type NoUndeclaredCustomPropertiesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUndeclaredCustomPropertiesOptions;
+export type NoUndefinedGraphqlFragmentConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUndefinedGraphqlFragmentOptions;
+export type NoUndefinedGraphqlVariableConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUndefinedGraphqlVariableOptions;
+export type NoUnknownGraphqlArgumentConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnknownGraphqlArgumentOptions;
+export type NoUnknownGraphqlFieldConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnknownGraphqlFieldOptions;
+export type NoUnknownGraphqlTypeConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnknownGraphqlTypeOptions;
 export type NoUnnecessaryTemplateExpressionConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUnnecessaryTemplateExpressionOptions;
+export type NoUnreachableFilesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnreachableFilesOptions;
 export type NoUnsafePlusOperandsConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUnsafePlusOperandsOptions;
@@ -4859,6 +5034,12 @@ export type NoUntrustedLicensesConfiguration =
 export type NoUnusedClassesConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUnusedClassesOptions;
+export type NoUnusedDependenciesConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnusedDependenciesOptions;
+export type NoUnusedExportsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithNoUnusedExportsOptions;
 export type NoUselessTypeConversionConfiguration =
 	| RulePlainConfiguration
 	| RuleWithNoUselessTypeConversionOptions;
@@ -4883,6 +5064,9 @@ export type UseAwaitThenableConfiguration =
 export type UseBaselineConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseBaselineOptions;
+export type UseConsistentListMarkerConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseConsistentListMarkerOptions;
 export type UseConsistentTestItConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseConsistentTestItOptions;
@@ -4910,9 +5094,18 @@ export type UseExplicitReturnTypeConfiguration =
 export type UseExplicitTypeConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseExplicitTypeOptions;
+export type UseFencedCodeLanguageConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseFencedCodeLanguageOptions;
+export type UseGraphqlRequiredArgumentsConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseGraphqlRequiredArgumentsOptions;
 export type UseIframeSandboxConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseIframeSandboxOptions;
+export type UseImageAltTextConfiguration =
+	| RulePlainConfiguration
+	| RuleWithUseImageAltTextOptions;
 export type UseImportsFirstConfiguration =
 	| RulePlainConfiguration
 	| RuleWithUseImportsFirstOptions;
@@ -6590,6 +6783,11 @@ export interface RuleWithNoAstroSetHtmlDirectiveOptions {
 	level: RulePlainConfiguration;
 	options?: NoAstroSetHtmlDirectiveOptions;
 }
+export interface RuleWithNoBareUrlsOptions {
+	fix?: FixKind;
+	level: RulePlainConfiguration;
+	options?: NoBareUrlsOptions;
+}
 export interface RuleWithNoBaseToStringOptions {
 	level: RulePlainConfiguration;
 	options?: NoBaseToStringOptions;
@@ -6614,6 +6812,14 @@ export interface RuleWithNoDuplicateFieldDefinitionNamesOptions {
 	level: RulePlainConfiguration;
 	options?: NoDuplicateFieldDefinitionNamesOptions;
 }
+export interface RuleWithNoDuplicateHeadingsOptions {
+	level: RulePlainConfiguration;
+	options?: NoDuplicateHeadingsOptions;
+}
+export interface RuleWithNoDuplicateMappingKeysOptions {
+	level: RulePlainConfiguration;
+	options?: NoDuplicateMappingKeysOptions;
+}
 export interface RuleWithNoDuplicateSelectorsOptions {
 	level: RulePlainConfiguration;
 	options?: NoDuplicateSelectorsOptions;
@@ -6652,6 +6858,14 @@ export interface RuleWithNoInlineStylesOptions {
 	level: RulePlainConfiguration;
 	options?: NoInlineStylesOptions;
 }
+export interface RuleWithNoInvalidGraphqlArgumentValueOptions {
+	level: RulePlainConfiguration;
+	options?: NoInvalidGraphqlArgumentValueOptions;
+}
+export interface RuleWithNoInvalidLinkFragmentsOptions {
+	level: RulePlainConfiguration;
+	options?: NoInvalidLinkFragmentsOptions;
+}
 export interface RuleWithNoInvalidPropertyInitValueOptions {
 	level: RulePlainConfiguration;
 	options?: NoInvalidPropertyInitValueOptions;
@@ -6772,6 +6986,20 @@ export interface RuleWithNoTopLevelLiteralsOptions {
 	level: RulePlainConfiguration;
 	options?: NoTopLevelLiteralsOptions;
 }
+export interface RuleWithNoTrailingSpacesOptions {
+	fix?: FixKind;
+	level: RulePlainConfiguration;
+	options?: NoTrailingSpacesOptions;
+}
+export interface RuleWithNoTruthyScalarsOptions {
+	fix?: FixKind;
+	level: RulePlainConfiguration;
+	options?: NoTruthyScalarsOptions;
+}
+export interface RuleWithNoUndeclaredAliasesOptions {
+	level: RulePlainConfiguration;
+	options?: NoUndeclaredAliasesOptions;
+}
 export interface RuleWithNoUndeclaredClassesOptions {
 	level: RulePlainConfiguration;
 	options?: NoUndeclaredClassesOptions;
@@ -6780,11 +7008,35 @@ export interface RuleWithNoUndeclaredCustomPropertiesOptions {
 	level: RulePlainConfiguration;
 	options?: NoUndeclaredCustomPropertiesOptions;
 }
+export interface RuleWithNoUndefinedGraphqlFragmentOptions {
+	level: RulePlainConfiguration;
+	options?: NoUndefinedGraphqlFragmentOptions;
+}
+export interface RuleWithNoUndefinedGraphqlVariableOptions {
+	level: RulePlainConfiguration;
+	options?: NoUndefinedGraphqlVariableOptions;
+}
+export interface RuleWithNoUnknownGraphqlArgumentOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnknownGraphqlArgumentOptions;
+}
+export interface RuleWithNoUnknownGraphqlFieldOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnknownGraphqlFieldOptions;
+}
+export interface RuleWithNoUnknownGraphqlTypeOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnknownGraphqlTypeOptions;
+}
 export interface RuleWithNoUnnecessaryTemplateExpressionOptions {
 	fix?: FixKind;
 	level: RulePlainConfiguration;
 	options?: NoUnnecessaryTemplateExpressionOptions;
 }
+export interface RuleWithNoUnreachableFilesOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnreachableFilesOptions;
+}
 export interface RuleWithNoUnsafePlusOperandsOptions {
 	level: RulePlainConfiguration;
 	options?: NoUnsafePlusOperandsOptions;
@@ -6801,6 +7053,14 @@ export interface RuleWithNoUnusedClassesOptions {
 	level: RulePlainConfiguration;
 	options?: NoUnusedClassesOptions;
 }
+export interface RuleWithNoUnusedDependenciesOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnusedDependenciesOptions;
+}
+export interface RuleWithNoUnusedExportsOptions {
+	level: RulePlainConfiguration;
+	options?: NoUnusedExportsOptions;
+}
 export interface RuleWithNoUselessTypeConversionOptions {
 	level: RulePlainConfiguration;
 	options?: NoUselessTypeConversionOptions;
@@ -6834,6 +7094,11 @@ export interface RuleWithUseBaselineOptions {
 	level: RulePlainConfiguration;
 	options?: UseBaselineOptions;
 }
+export interface RuleWithUseConsistentListMarkerOptions {
+	fix?: FixKind;
+	level: RulePlainConfiguration;
+	options?: UseConsistentListMarkerOptions;
+}
 export interface RuleWithUseConsistentTestItOptions {
 	fix?: FixKind;
 	level: RulePlainConfiguration;
@@ -6875,10 +7140,22 @@ export interface RuleWithUseExplicitTypeOptions {
 	level: RulePlainConfiguration;
 	options?: UseExplicitTypeOptions;
 }
+export interface RuleWithUseFencedCodeLanguageOptions {
+	level: RulePlainConfiguration;
+	options?: UseFencedCodeLanguageOptions;
+}
+export interface RuleWithUseGraphqlRequiredArgumentsOptions {
+	level: RulePlainConfiguration;
+	options?: UseGraphqlRequiredArgumentsOptions;
+}
 export interface RuleWithUseIframeSandboxOptions {
 	level: RulePlainConfiguration;
 	options?: UseIframeSandboxOptions;
 }
+export interface RuleWithUseImageAltTextOptions {
+	level: RulePlainConfiguration;
+	options?: UseImageAltTextOptions;
+}
 export interface RuleWithUseImportsFirstOptions {
 	level: RulePlainConfiguration;
 	options?: UseImportsFirstOptions;
@@ -8433,6 +8710,7 @@ export type UseVueValidVPreOptions = {};
 export type UseVueValidVTextOptions = {};
 export type UseYieldOptions = {};
 export type NoAstroSetHtmlDirectiveOptions = {};
+export type NoBareUrlsOptions = {};
 export interface NoBaseToStringOptions {
 	ignoredTypeNames?: string[];
 }
@@ -8451,6 +8729,8 @@ export interface NoDrizzleUpdateWithoutWhereOptions {
 	drizzleObjectName?: string[];
 }
 export type NoDuplicateFieldDefinitionNamesOptions = {};
+export type NoDuplicateHeadingsOptions = {};
+export type NoDuplicateMappingKeysOptions = {};
 export type NoDuplicateSelectorsOptions = {};
 export type NoEmptyObjectKeysOptions = {};
 export interface NoExcessiveNestedCallbacksOptions {
@@ -8482,6 +8762,8 @@ export type NoFloatingPromisesOptions = {};
 export type NoIdenticalTestTitleOptions = {};
 export type NoImpliedEvalOptions = {};
 export type NoInlineStylesOptions = {};
+export type NoInvalidGraphqlArgumentValueOptions = {};
+export type NoInvalidLinkFragmentsOptions = {};
 export type NoInvalidPropertyInitValueOptions = {};
 export interface NoJsRestrictedPropertiesOptions {
 	/**
@@ -8552,12 +8834,21 @@ export interface NoTailwindArbitraryValueOptions {
 	functions?: string[];
 }
 export type NoTopLevelLiteralsOptions = {};
+export type NoTrailingSpacesOptions = {};
+export type NoTruthyScalarsOptions = {};
+export type NoUndeclaredAliasesOptions = {};
 /**
  * Options for the `noUndeclaredClasses` rule.
  */
 export type NoUndeclaredClassesOptions = {};
 export type NoUndeclaredCustomPropertiesOptions = {};
+export type NoUndefinedGraphqlFragmentOptions = {};
+export type NoUndefinedGraphqlVariableOptions = {};
+export type NoUnknownGraphqlArgumentOptions = {};
+export type NoUnknownGraphqlFieldOptions = {};
+export type NoUnknownGraphqlTypeOptions = {};
 export type NoUnnecessaryTemplateExpressionOptions = {};
+export type NoUnreachableFilesOptions = {};
 export type NoUnsafePlusOperandsOptions = {};
 export type NoUnsafeTypeAssertionOptions = {};
 export interface NoUntrustedLicensesOptions {
@@ -8595,6 +8886,8 @@ Defaults to `false`.
 	requireOsiApproved?: boolean;
 }
 export type NoUnusedClassesOptions = {};
+export type NoUnusedDependenciesOptions = {};
+export type NoUnusedExportsOptions = {};
 export type NoUselessTypeConversionOptions = {};
 export type NoVueImportCompilerMacrosOptions = {};
 export type NoVueRefAsOperandOptions = {};
@@ -8635,6 +8928,7 @@ export interface UseBaselineOptions {
 	 */
 	available?: AvailabilityTarget;
 }
+export type UseConsistentListMarkerOptions = {};
 /**
  * Options for the `useConsistentTestIt` rule
  */
@@ -8685,7 +8979,10 @@ When `true`, only declarations (function statements and class methods) are check
 	allowedNames?: string[];
 }
 export type UseExplicitTypeOptions = {};
+export type UseFencedCodeLanguageOptions = {};
+export type UseGraphqlRequiredArgumentsOptions = {};
 export type UseIframeSandboxOptions = {};
+export type UseImageAltTextOptions = {};
 export type UseImportsFirstOptions = {};
 /**
  * Options for the `useIncludes` rule.
@@ -9956,6 +10253,7 @@ export type Category =
 	| "lint/correctness/useVueValidVText"
 	| "lint/correctness/useYield"
 	| "lint/nursery/noAstroSetHtmlDirective"
+	| "lint/nursery/noBareUrls"
 	| "lint/nursery/noBaseToString"
 	| "lint/nursery/noColorInvalidHex"
 	| "lint/nursery/noComponentHookFactories"
@@ -9963,6 +10261,8 @@ export type Category =
 	| "lint/nursery/noDrizzleDeleteWithoutWhere"
 	| "lint/nursery/noDrizzleUpdateWithoutWhere"
 	| "lint/nursery/noDuplicateFieldDefinitionNames"
+	| "lint/nursery/noDuplicateHeadings"
+	| "lint/nursery/noDuplicateMappingKeys"
 	| "lint/nursery/noDuplicateSelectors"
 	| "lint/nursery/noEmptyObjectKeys"
 	| "lint/nursery/noExcessiveNestedCallbacks"
@@ -9973,6 +10273,8 @@ export type Category =
 	| "lint/nursery/noImplicitCoercion"
 	| "lint/nursery/noImpliedEval"
 	| "lint/nursery/noInlineStyles"
+	| "lint/nursery/noInvalidGraphqlArgumentValue"
+	| "lint/nursery/noInvalidLinkFragments"
 	| "lint/nursery/noInvalidPropertyInitValue"
 	| "lint/nursery/noJsRestrictedProperties"
 	| "lint/nursery/noJsxLeakedDollar"
@@ -10002,13 +10304,24 @@ export type Category =
 	| "lint/nursery/noSvelteUnnecessaryStateWrap"
 	| "lint/nursery/noTailwindArbitraryValue"
 	| "lint/nursery/noTopLevelLiterals"
+	| "lint/nursery/noTrailingSpaces"
+	| "lint/nursery/noTruthyScalars"
+	| "lint/nursery/noUndeclaredAliases"
 	| "lint/nursery/noUndeclaredClasses"
 	| "lint/nursery/noUndeclaredCustomProperties"
+	| "lint/nursery/noUndefinedGraphqlFragment"
+	| "lint/nursery/noUndefinedGraphqlVariable"
+	| "lint/nursery/noUnknownGraphqlArgument"
+	| "lint/nursery/noUnknownGraphqlField"
+	| "lint/nursery/noUnknownGraphqlType"
 	| "lint/nursery/noUnnecessaryTemplateExpression"
+	| "lint/nursery/noUnreachableFiles"
 	| "lint/nursery/noUnsafePlusOperands"
 	| "lint/nursery/noUnsafeTypeAssertion"
 	| "lint/nursery/noUntrustedLicenses"
 	| "lint/nursery/noUnusedClasses"
+	| "lint/nursery/noUnusedDependencies"
+	| "lint/nursery/noUnusedExports"
 	| "lint/nursery/noUnwantedPolyfillio"
 	| "lint/nursery/noUselessBackrefInRegex"
 	| "lint/nursery/noUselessTypeConversion"
@@ -10021,6 +10334,7 @@ export type Category =
 	| "lint/nursery/useBaseline"
 	| "lint/nursery/useBiomeSuppressionComment"
 	| "lint/nursery/useConsistentHeadingLevel"
+	| "lint/nursery/useConsistentListMarker"
 	| "lint/nursery/useConsistentObjectDefinition"
 	| "lint/nursery/useConsistentTestIt"
 	| "lint/nursery/useControlLabel"
@@ -10032,9 +10346,12 @@ export type Category =
 	| "lint/nursery/useExplicitFunctionReturnType"
 	| "lint/nursery/useExplicitReturnType"
 	| "lint/nursery/useExplicitType"
+	| "lint/nursery/useFencedCodeLanguage"
 	| "lint/nursery/useFind"
 	| "lint/nursery/useGlobalThis"
+	| "lint/nursery/useGraphqlRequiredArguments"
 	| "lint/nursery/useIframeSandbox"
+	| "lint/nursery/useImageAltText"
 	| "lint/nursery/useImportRestrictions"
 	| "lint/nursery/useImportsFirst"
 	| "lint/nursery/useIncludes"
@@ -10340,6 +10657,7 @@ export type Category =
 	| "init"
 	| "configuration"
 	| "assist"
+	| "baseline"
 	| "migrate"
 	| "upgrade"
 	| "deserialize"
@@ -10607,7 +10925,7 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
-export type EditorFeature = "gotoDefinition";
+export type EditorFeature = "gotoDefinition" | "rename";
 export type JsEmbeddingKind =
 	| "None"
 	| {
@@ -10898,6 +11216,43 @@ export interface SerializedHtmlModuleInfo {
 	 */
 	styleClasses: string[];
 }
+export interface GetUnusedCodeParams {
+	projectKey: ProjectKey;
+}
+export interface GetUnusedCodeResult {
+	/**
+	 * The files that can't be reached from the entry points of their package.
+	 */
+	unreachableFiles: BiomePath[];
+	/**
+	 * The dependencies that aren't imported by any module of their package.
+	 */
+	unusedDependencies: UnusedDependency[];
+	/**
+	 * The exports that aren't imported by any module of the project.
+	 */
+	unusedExports: UnusedExport[];
+}
+export interface UnusedDependency {
+	/**
+	 * The path of the `package.json` declaring the dependency.
+	 */
+	manifestPath: BiomePath;
+	/**
+	 * The name of the package.
+	 */
+	name: string;
+}
+export interface UnusedExport {
+	/**
+	 * The name of the export, `default` for default exports.
+	 */
+	name: string;
+	/**
+	 * The path of the module exporting the symbol.
+	 */
+	path: BiomePath;
+}
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	/**
@@ -11161,6 +11516,10 @@ export interface RenameParams {
 	symbolAt: TextSize;
 }
 export interface RenameResult {
+	/**
+	 * Edits of the other modules of the project that import the renamed symbol
+	 */
+	dependents: DependentRenameEdit[];
 	/**
 	 * List of text edit operations to apply on the source code
 	 */
@@ -11170,11 +11529,25 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface DependentRenameEdit {
+	/**
+	 * List of text edit operations to apply on the source code of this module
+	 */
+	indels: TextEdit;
+	path: BiomePath;
+}
 export interface ParsePatternParams {
 	defaultLanguage: SearchLanguage;
 	pattern: string;
 }
-export type SearchLanguage = "css" | "js" | "json";
+export type SearchLanguage =
+	| "css"
+	| "graphql"
+	| "html"
+	| "js"
+	| "json"
+	| "markdown"
+	| "yaml";
 export interface ParsePatternResult {
 	patternId: PatternId;
 }
@@ -11187,6 +11560,10 @@ export interface SearchPatternParams {
 export interface SearchResults {
 	matches: TextRange[];
 	path: BiomePath;
+	/**
+	 * The content of the file with the rewrites of the pattern applied. It's `None` when the pattern doesn't rewrite anything.
+	 */
+	rewritten?: string;
 }
 export interface DropPatternParams {
 	pattern: PatternId;
@@ -11211,6 +11588,7 @@ export interface Workspace {
 	getRegisteredTypes(params: GetRegisteredTypesParams): Promise<string>;
 	getSemanticModel(params: GetSemanticModelParams): Promise<string>;
 	getModuleGraph(params: GetModuleGraphParams): Promise<GetModuleGraphResult>;
+	getUnusedCode(params: GetUnusedCodeParams): Promise<GetUnusedCodeResult>;
 	pullDiagnostics(
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
@@ -11288,6 +11666,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		getModuleGraph(params) {
 			return transport.request("biome/get_module_graph", params);
 		},
+		getUnusedCode(params) {
+			return transport.request("biome/get_unused_code", params);
+		},
 		pullDiagnostics(params) {
 			return transport.request("biome/pull_diagnostics", params);
 		},
@@ -22,16 +22,22 @@ export interface AstQuery<N extends JsAstNode> {
  * A lint rule, created with {@link defineRule} and exported from the plugin
  * with `export const`. The name of the export is used as the rule name.
  */
-export interface Rule<N extends JsAstNode> {
+export interface Rule<
+	N extends JsAstNode,
+	O extends Readonly<Record<string, unknown>> = Readonly<Record<string, unknown>>,
+> {
 	/**
 	 * The query selecting the nodes the rule runs on.
 	 */
 	readonly query: AstQuery<N>;
 
 	/**
-	 * Called with every node matching the query.
+	 * Called with every node matching the query, and the options of the rule:
+	 * the defaults declared in the plugin manifest, overridden by the options
+	 * configured in `biome.json`. Options are an empty object when there
+	 * aren't any.
 	 */
-	run(node: N): void;
+	run(node: N, options: O): void;
 }
 
 /**
@@ -45,10 +51,95 @@ export function ast<K extends readonly (keyof JsNodeByKind)[]>(
  * Defines a lint rule. Export the returned rule with `export const` to
  * register it to the analyzer.
  */
-export function defineRule<N extends JsAstNode>(rule: Rule<N>): Rule<N>;
+export function defineRule<
+	N extends JsAstNode,
+	O extends Readonly<Record<string, unknown>> = Readonly<Record<string, unknown>>,
+>(rule: Rule<N, O>): Rule<N, O>;
 
+/**
+ * Replaces the text of `node` with `text`.
+ */
+export interface NodeReplacement {
+	readonly node: JsAstNode;
+	readonly text: string;
+}
+
+/**
+ * Replaces the text between the offsets of `range` with `text`. The offsets
+ * are UTF-8 byte offsets in the analyzed file, like {@link JsAstNode#range}.
+ */
+export interface TextEdit {
+	readonly range: readonly [start: number, end: number];
+	readonly text: string;
+}
+
+/**
+ * A code fix attached to a diagnostic.
+ */
+export interface Fix {
+	/**
+	 * Describes the fix to the user.
+	 */
+	readonly message?: string;
+
+	/**
+	 * Safe fixes are applied by `--write`, unsafe fixes require `--unsafe`.
+	 * Defaults to `"unsafe"`.
+	 */
+	readonly kind?: "safe" | "unsafe";
+
+	/**
+	 * The edits of the fix. They must not overlap.
+	 */
+	readonly edits: readonly (NodeReplacement | TextEdit)[];
+}
+
+/**
+ * Reports a diagnostic on `node`, optionally with a fix.
+ */
 export function registerDiagnostic(
 	node: JsAstNode,
 	severity: Severity,
 	message: string,
+	fix?: Fix,
 ): void;
+
+/**
+ * A reference to a binding, as returned by {@link getReferences}.
+ */
+export interface Reference {
+	/**
+	 * The identifier referencing the binding.
+	 */
+	readonly node: JsAstNode;
+
+	/**
+	 * Whether the reference reads or writes the binding.
+	 */
+	readonly kind: "read" | "write";
+}
+
+/**
+ * Resolves an identifier to the binding declaring it, such as the
+ * `JS_IDENTIFIER_BINDING` of a variable declaration or an import.
+ *
+ * Accepts identifier references, identifier expressions, and bindings, which
+ * resolve to themselves. Returns `undefined` for globals and unresolved
+ * references.
+ */
+export function getBinding(node: JsAstNode): JsAstNode | undefined;
+
+/**
+ * Lists the references to a binding returned by {@link getBinding}.
+ */
+export function getReferences(binding: JsAstNode): Reference[];
+
+/**
+ * Returns the type inferred for an expression, printed like in TypeScript
+ * (e.g. `string` or `Promise<number>`).
+ *
+ * Types are inferred from the module graph, so they're only available when
+ * Biome scans the project, e.g. when a rule of the `types` domain is enabled.
+ * Returns `undefined` otherwise.
+ */
+export function getType(node: JsAstNode): string | undefined;
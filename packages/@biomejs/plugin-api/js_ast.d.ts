@@ -3,6 +3,7 @@
 export interface JsAstNode {
 	readonly kind: string;
 	readonly text: string;
+	readonly range: readonly [start: number, end: number];
 }
 export interface JsAccessorModifier extends JsAstNode {
 	readonly kind: "JS_ACCESSOR_MODIFIER";
@@ -236,6 +236,26 @@ impl LanguageConfig {
                 syntax_module: "biome_json_syntax",
                 legacy_patterns: JSON_TREESITTER_PATTERNS,
             },
+            LanguageKind::Graphql => Self {
+                syntax_kind_type: "GraphqlSyntaxKind",
+                syntax_module: "biome_graphql_syntax",
+                legacy_patterns: &[],
+            },
+            LanguageKind::Html => Self {
+                syntax_kind_type: "HtmlSyntaxKind",
+                syntax_module: "biome_html_syntax",
+                legacy_patterns: &[],
+            },
+            LanguageKind::Markdown => Self {
+                syntax_kind_type: "MarkdownSyntaxKind",
+                syntax_module: "biome_markdown_syntax",
+                legacy_patterns: &[],
+            },
+            LanguageKind::Yaml => Self {
+                syntax_kind_type: "YamlSyntaxKind",
+                syntax_module: "biome_yaml_syntax",
+                legacy_patterns: &[],
+            },
             _ => unimplemented!("Grit mappings are not supported for {:?}", language_kind),
         }
     }
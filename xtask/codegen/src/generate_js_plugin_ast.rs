@@ -4,7 +4,8 @@ use biome_js_factory::make;
 use biome_js_formatter::{context::JsFormatOptions, format_node};
 use biome_js_syntax::{
     AnyJsDeclarationClause, AnyJsExportClause, AnyJsModuleItem, AnyJsObjectMemberName, AnyTsName,
-    AnyTsType, AnyTsTypeMember, JsSyntaxToken, T, TriviaPieceKind, TsReferenceType,
+    AnyTsTupleTypeElement, AnyTsType, AnyTsTypeMember, JsSyntaxToken, T, TriviaPieceKind,
+    TsReferenceType,
 };
 use biome_languages::JsFileSource;
 use biome_rowan::AstNode;
@@ -148,6 +149,7 @@ fn generate_typescript(ast: &AstSrc) -> String {
         [
             property("kind", string_type()),
             property("text", string_type()),
+            property("range", text_range_type()),
         ],
     )];
 
@@ -353,6 +355,28 @@ fn string_literal_type(value: &str) -> AnyTsType {
     make::ts_string_literal_type(make::js_string_literal(value)).into()
 }
 
+/// Returns `readonly [start: number, end: number]`, the type of the range of a
+/// node in the source file.
+fn text_range_type() -> AnyTsType {
+    let element = |name: &str| {
+        AnyTsTupleTypeElement::TsNamedTupleTypeElement(
+            make::ts_named_tuple_type_element(
+                make::js_name(make::ident(name)),
+                make::token(T![:]),
+                make::ts_number_type(make::token(T![number])).into(),
+            )
+            .build(),
+        )
+    };
+    let tuple_type = make::ts_tuple_type(
+        make::token(T!['[']),
+        make::ts_tuple_type_element_list([element("start"), element("end")], [make::token(T![,])]),
+        make::token(T![']']),
+    );
+
+    make::ts_type_operator_type(make::token(T![readonly]), tuple_type.into()).into()
+}
+
 fn undefined_type() -> AnyTsType {
     make::ts_undefined_type(make::token(T![undefined])).into()
 }
@@ -389,8 +413,8 @@ fn property_name(field: &Field) -> String {
     let name = Case::Camel.convert(&method_name.to_string());
 
     match (name.as_str(), field) {
-        ("kind" | "text", Field::Token { .. }) => format!("{name}Token"),
-        ("kind" | "text", Field::Node { .. }) => format!("{name}Node"),
+        ("kind" | "text" | "range", Field::Token { .. }) => format!("{name}Token"),
+        ("kind" | "text" | "range", Field::Node { .. }) => format!("{name}Node"),
         _ => name,
     }
 }
@@ -0,0 +1,125 @@
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleSourceKind,
+    RuleSourceWithKind,
+};
+use biome_rowan::syntax::Language;
+use biome_string_case::Case;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use xtask_codegen::update;
+use xtask_glue::*;
+
+fn generate_mapping(stylelint_name: Box<str>, mapped_rules: Vec<RuleMapping>) -> TokenStream {
+    let rules = mapped_rules.iter().map(|RuleMapping{source_kind, rule_name, group_name}| {
+        let name_ident = format_ident!("{}", Case::Snake.convert(rule_name));
+        let group_ident = format_ident!("{group_name}");
+        let check_inspired = if source_kind.is_inspired() {
+            quote! {
+                if !options.include_inspired {
+                    results.add(stylelint_name, stylelint_to_biome::RuleMigrationResult::Inspired);
+                    return false;
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let check_nursery = if *group_name == "nursery" {
+            quote! {
+                if !options.include_nursery {
+                    results.add(stylelint_name, stylelint_to_biome::RuleMigrationResult::Nursery);
+                    return false;
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #check_inspired
+            #check_nursery
+            let group = rules.#group_ident.get_or_insert_with(Default::default);
+            let rule = group.unwrap_group_as_mut().#name_ident.get_or_insert(Default::default());
+            rule.set_level(rule.level().max(rule_severity.into()));
+        }
+    });
+
+    quote! {
+        #stylelint_name => {
+            #( #rules )*
+        }
+    }
+}
+
+pub(crate) fn generate_migrate_stylelint(mode: Mode) -> Result<()> {
+    let mut visitor = StylelintLintRulesVisitor::default();
+    biome_css_analyze::visit_registry(&mut visitor);
+    let mut lines = Vec::with_capacity(visitor.0.len());
+    for (stylelint_name, mapped_rules) in visitor.0 {
+        if mapped_rules.is_empty() {
+            continue;
+        }
+        lines.push(generate_mapping(stylelint_name, mapped_rules));
+    }
+    let tokens = xtask_glue::reformat(quote! {
+        use super::{stylelint, stylelint_to_biome};
+        pub(crate) fn migrate_stylelint_any_rule(
+            rules: &mut biome_configuration::Rules,
+            stylelint_name: &str,
+            rule_severity: stylelint::Severity,
+            options: &stylelint_to_biome::MigrationOptions,
+            results: &mut stylelint_to_biome::MigrationResults,
+        ) -> bool {
+            match stylelint_name {
+                #( #lines )*
+                _ => {
+                    results.add(stylelint_name, stylelint_to_biome::RuleMigrationResult::Unsupported);
+                    return false;
+                }
+            }
+            results.add(stylelint_name, stylelint_to_biome::RuleMigrationResult::Migrated);
+            true
+        }
+    });
+    let file_path =
+        project_root().join("crates/biome_cli/src/execute/migrate/stylelint_any_rule_to_biome.rs");
+    update(&file_path, &tokens?, &mode)?;
+    Ok(())
+}
+
+#[derive(Default)]
+struct StylelintLintRulesVisitor(BTreeMap<Box<str>, Vec<RuleMapping>>);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct RuleMapping {
+    group_name: &'static str,
+    rule_name: &'static str,
+    source_kind: RuleSourceKind,
+}
+
+impl<L: Language> RegistryVisitor<L> for StylelintLintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R: Rule + 'static>(&mut self)
+    where
+        R::Query: Queryable<Language = L>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        for RuleSourceWithKind { kind, source } in R::METADATA.sources {
+            if !source.is_stylelint() {
+                continue;
+            }
+            self.0
+                .entry(source.as_rule_name().into())
+                .or_default()
+                .push(RuleMapping {
+                    source_kind: *kind,
+                    group_name: <R::Group as RuleGroup>::NAME,
+                    rule_name: R::METADATA.name,
+                });
+        }
+    }
+}
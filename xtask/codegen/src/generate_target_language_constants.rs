@@ -31,22 +31,37 @@ pub fn generate_target_language_constants(
                 .collect::<Vec<_>>()
         })
         .collect();
+    // Only glob-import the conditions and kinds when they're referenced, so
+    // languages without disregarded slots don't end up with unused imports.
+    let glob = if disregarded_slots.is_empty() {
+        ""
+    } else {
+        ", *"
+    };
     let disregarded_slots = disregarded_slots.join("\n    ");
 
     let syntax_kind = match language_kind {
         LanguageKind::Css => "CssSyntaxKind",
+        LanguageKind::Graphql => "GraphqlSyntaxKind",
+        LanguageKind::Html => "HtmlSyntaxKind",
         LanguageKind::Js => "JsSyntaxKind",
+        LanguageKind::Markdown => "MarkdownSyntaxKind",
+        LanguageKind::Yaml => "YamlSyntaxKind",
         _ => unimplemented!(),
     };
     let syntax_kind_module = match language_kind {
         LanguageKind::Css => "biome_css_syntax",
+        LanguageKind::Graphql => "biome_graphql_syntax",
+        LanguageKind::Html => "biome_html_syntax",
         LanguageKind::Js => "biome_js_syntax",
+        LanguageKind::Markdown => "biome_markdown_syntax",
+        LanguageKind::Yaml => "biome_yaml_syntax",
         _ => unimplemented!(),
     };
 
     let result = format!(
-        "use crate::grit_target_language::DisregardedSlotCondition::{{self, *}};
-use {syntax_kind_module}::{syntax_kind}::{{self, *}};
+        "use crate::grit_target_language::DisregardedSlotCondition::{{self{glob}}};
+use {syntax_kind_module}::{syntax_kind}::{{self{glob}}};
 
 pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[({syntax_kind}, u32, DisregardedSlotCondition)] = &[
     {disregarded_slots}
@@ -50,6 +50,9 @@ pub const GRIT_KINDS_SRC: KindsSrc = KindsSrc {
         "json",
         "grit",
         "html",
+        "graphql",
+        "markdown",
+        "yaml",
         // language flavors:
         "typescript",
         "jsx",
@@ -170,6 +170,9 @@ impl LanguageKind {
     }
 
     pub fn supports_grit(&self) -> bool {
-        matches!(self, Self::Css | Self::Js)
+        matches!(
+            self,
+            Self::Css | Self::Graphql | Self::Html | Self::Js | Self::Markdown | Self::Yaml
+        )
     }
 }